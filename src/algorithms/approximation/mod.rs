@@ -0,0 +1,3 @@
+/// Find short tours that visit every node of the graph: approximate solutions to the
+/// traveling salesman problem.
+pub mod tsp;
@@ -0,0 +1,422 @@
+use crate::algorithms::community::louvain::get_rng;
+use crate::algorithms::matching::max_weight_matching_indexes;
+use crate::algorithms::tree::{self, SpanningTreeAlgorithm};
+use crate::{CsrGraph, Error, ErrorKind, Graph};
+use rand::Rng;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// A route that visits every node of a graph once and returns to where it started.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tour<T> {
+    /// The nodes in the order they are visited. The tour returns from the last node to the
+    /// first, which isn't repeated at the end.
+    pub nodes: Vec<T>,
+    /// The total weight of the edges of the tour, including the edge back to the first node.
+    pub weight: f64,
+}
+
+/**
+Finds a tour of a complete graph with the nearest neighbor heuristic: starting at
+`source`, the tour repeatedly moves to the closest node it hasn't visited yet. Ties are
+broken by node name.
+
+The tour is found quickly but can be much longer than the shortest one; it makes a good
+starting point for [simulated_annealing](fn.simulated_annealing.html).
+
+# Arguments
+
+* `graph`: a complete [Graph](../../../struct.Graph.html) instance, which can be directed
+  or undirected
+* `weighted`: set to `true` to use edge weights; otherwise every edge has a weight of `1.0`
+* `source`: the node to start the tour at; if `None` the node with the lowest name is used
+
+# Returns
+
+The [Tour](struct.Tour.html). An `Error` with a `kind` of `ErrorKind::InvalidArgument` is
+returned if the graph has no nodes or isn't complete.
+
+# Examples
+
+```
+use graphrs::{algorithms::approximation::tsp, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("a", "b", 1.0),
+    Edge::with_weight("a", "c", 3.0),
+    Edge::with_weight("a", "d", 2.0),
+    Edge::with_weight("b", "c", 2.0),
+    Edge::with_weight("b", "d", 3.0),
+    Edge::with_weight("c", "d", 1.0),
+]);
+let tour = tsp::greedy(&graph, true, Some("a")).unwrap();
+assert_eq!(tour.nodes, vec!["a", "b", "c", "d"]);
+assert_eq!(tour.weight, 6.0);
+```
+*/
+pub fn greedy<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    source: Option<T>,
+) -> Result<Tour<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let distances = Distances::new(graph, weighted)?;
+    let start = match source {
+        None => 0,
+        Some(name) => distances.csr.get_node_indexes(&[name])?[0] as usize,
+    };
+    let tour = distances.get_nearest_neighbor_tour(start);
+    Ok(distances.to_tour(tour))
+}
+
+/**
+Improves a tour of a complete graph with simulated annealing.
+
+Each iteration reverses a random section of the tour (a "2-opt" move). Moves that make
+the tour shorter are always kept, and moves that make it longer are kept with a
+probability that falls as the temperature cools. The temperature starts at the average
+weight of the edges of the initial tour and cools geometrically to a thousandth of that
+over the iterations. The shortest tour seen is returned, so it is never longer than the
+initial tour.
+
+# Arguments
+
+* `graph`: a complete [Graph](../../../struct.Graph.html) instance, which can be directed
+  or undirected
+* `weighted`: set to `true` to use edge weights; otherwise every edge has a weight of `1.0`
+* `initial_tour`: the nodes of the tour to start from, in the order they are visited;
+  if `None` the tour found by [greedy](fn.greedy.html) is used
+* `iterations`: the number of moves to try
+* `seed`: a seed for the random number generator, to make the result reproducible
+
+# Returns
+
+The [Tour](struct.Tour.html). An `Error` with a `kind` of `ErrorKind::InvalidArgument` is
+returned if the graph has no nodes or isn't complete, or if `initial_tour` doesn't
+contain every node exactly once.
+
+# Examples
+
+```
+use graphrs::{algorithms::approximation::tsp, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("a", "b", 1.0),
+    Edge::with_weight("a", "c", 3.0),
+    Edge::with_weight("a", "d", 2.0),
+    Edge::with_weight("b", "c", 2.0),
+    Edge::with_weight("b", "d", 3.0),
+    Edge::with_weight("c", "d", 1.0),
+]);
+let initial_tour = Some(vec!["a", "c", "b", "d"]);
+let tour = tsp::simulated_annealing(&graph, true, initial_tour, 100, Some(1)).unwrap();
+assert_eq!(tour.weight, 6.0);
+```
+
+# References
+
+1. S. Kirkpatrick, C. D. Gelatt and M. P. Vecchi, Optimization by Simulated Annealing,
+   Science, 220(4598), 671-680, 1983.
+*/
+pub fn simulated_annealing<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    initial_tour: Option<Vec<T>>,
+    iterations: usize,
+    seed: Option<u64>,
+) -> Result<Tour<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let distances = Distances::new(graph, weighted)?;
+    let mut tour = match initial_tour {
+        None => distances.get_nearest_neighbor_tour(0),
+        Some(nodes) => distances.get_tour_indexes(&nodes)?,
+    };
+    let n = tour.len();
+    if n < 3 {
+        return Ok(distances.to_tour(tour));
+    }
+    let mut rng = get_rng(seed);
+    let mut weight = distances.get_tour_weight(&tour);
+    let mut best = (tour.clone(), weight);
+    let initial_temperature = weight / n as f64;
+    for iteration in 0..iterations {
+        let temperature =
+            initial_temperature * 0.001_f64.powf(iteration as f64 / iterations as f64);
+        let (a, b) = (rng.gen_range(1..n), rng.gen_range(1..n));
+        if a == b {
+            continue;
+        }
+        let (i, j) = (a.min(b), a.max(b));
+        let delta = distances.get_reversal_delta(&tour, i, j);
+        if delta <= 0.0 || rng.gen::<f64>() < (-delta / temperature).exp() {
+            tour[i..=j].reverse();
+            weight += delta;
+            if weight < best.1 {
+                best = (tour.clone(), weight);
+            }
+        }
+    }
+    Ok(distances.to_tour(best.0))
+}
+
+/**
+Finds a tour of a complete, undirected graph with the Christofides algorithm.
+
+A minimum spanning tree of the graph is found, then a minimum weight perfect matching
+of the nodes that have an odd degree in the tree. Together their edges form a
+multigraph in which every node has an even degree, so it has an Eulerian circuit. The
+tour visits the nodes in the order they first appear in that circuit.
+
+If the edge weights are metric (they satisfy the triangle inequality), the tour is at
+most 1.5 times as long as the shortest one.
+
+# Arguments
+
+* `graph`: a complete, undirected [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights; otherwise every edge has a weight of `1.0`
+
+# Returns
+
+The [Tour](struct.Tour.html). An `Error` with a `kind` of `ErrorKind::WrongMethod` is
+returned if the graph is directed, and one with a `kind` of
+`ErrorKind::InvalidArgument` if the graph has no nodes or isn't complete.
+
+# Examples
+
+```
+use graphrs::{algorithms::approximation::tsp, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("a", "b", 1.0),
+    Edge::with_weight("a", "c", 3.0),
+    Edge::with_weight("a", "d", 2.0),
+    Edge::with_weight("b", "c", 2.0),
+    Edge::with_weight("b", "d", 3.0),
+    Edge::with_weight("c", "d", 1.0),
+]);
+let tour = tsp::christofides(&graph, true).unwrap();
+assert_eq!(tour.nodes.len(), 4);
+assert!(tour.weight <= 9.0);
+```
+
+# References
+
+1. N. Christofides, Worst-case analysis of a new heuristic for the travelling salesman
+   problem, Report 388, Graduate School of Industrial Administration, Carnegie Mellon
+   University, 1976.
+*/
+pub fn christofides<T, A>(graph: &Graph<T, A>, weighted: bool) -> Result<Tour<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let distances = Distances::new(graph, weighted)?;
+    let n = distances.csr.number_of_nodes();
+    let mut edges: Vec<(usize, usize)> =
+        tree::minimum_spanning_edges(graph, SpanningTreeAlgorithm::Prim, weighted, false)?
+            .map(|e| {
+                let u = distances.csr.get_node_index(&e.u).unwrap() as usize;
+                let v = distances.csr.get_node_index(&e.v).unwrap() as usize;
+                (u, v)
+            })
+            .collect();
+    let mut degrees = vec![0; n];
+    for (u, v) in edges.iter() {
+        degrees[*u] += 1;
+        degrees[*v] += 1;
+    }
+    let odd: Vec<usize> = (0..n).filter(|v| degrees[*v] % 2 == 1).collect();
+    edges.extend(get_min_weight_perfect_matching(&distances, &odd));
+    let mut visited = vec![false; n];
+    let tour = get_eulerian_circuit(n, &edges, 0)
+        .into_iter()
+        .filter(|v| !std::mem::replace(&mut visited[*v], true))
+        .collect();
+    Ok(distances.to_tour(tour))
+}
+
+/// The weights of the edges between every pair of nodes of a complete graph.
+struct Distances<T> {
+    csr: CsrGraph<T>,
+    /// the weight of the edge from `u` to `v` is at `u * n + v`
+    weights: Vec<f64>,
+}
+
+impl<T> Distances<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    /// Returns an `Err` if the graph has no nodes or isn't complete.
+    fn new<A>(graph: &Graph<T, A>, weighted: bool) -> Result<Self, Error>
+    where
+        A: Clone + Send + Sync,
+    {
+        let csr = graph.to_csr();
+        if weighted {
+            csr.ensure_weighted()?;
+        }
+        let n = csr.number_of_nodes();
+        if n == 0 {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: "The graph has no nodes.".to_string(),
+            });
+        }
+        let mut weights = vec![f64::NAN; n * n];
+        for u in 0..n {
+            weights[u * n + u] = 0.0;
+            let successors = csr.get_successors_or_neighbors(u as u32);
+            for (v, w) in successors.iter().zip(csr.get_weights(u as u32)) {
+                if *v as usize != u {
+                    weights[u * n + *v as usize] = if weighted { *w } else { 1.0 };
+                }
+            }
+        }
+        if let Some(i) = weights.iter().position(|w| w.is_nan()) {
+            let names = csr.get_node_names();
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!(
+                    "The graph must be complete but there is no edge from {} to {}.",
+                    names[i / n],
+                    names[i % n]
+                ),
+            });
+        }
+        Ok(Distances { csr, weights })
+    }
+
+    /// Returns the weight of the edge from `u` to `v`.
+    fn get(&self, u: usize, v: usize) -> f64 {
+        self.weights[u * self.csr.number_of_nodes() + v]
+    }
+
+    /// Returns the total weight of the edges of `tour`.
+    fn get_tour_weight(&self, tour: &[usize]) -> f64 {
+        (0..tour.len()).map(|i| self.get(tour[i], tour[(i + 1) % tour.len()])).sum()
+    }
+
+    /// Returns the change in the weight of `tour` if `tour[i..=j]` is reversed.
+    fn get_reversal_delta(&self, tour: &[usize], i: usize, j: usize) -> f64 {
+        let before = tour[i - 1];
+        let after = tour[(j + 1) % tour.len()];
+        let mut delta = self.get(before, tour[j]) + self.get(tour[i], after)
+            - self.get(before, tour[i])
+            - self.get(tour[j], after);
+        if self.csr.is_directed() {
+            // the edges within the section are traversed in the opposite direction
+            delta += (i..j)
+                .map(|k| self.get(tour[k + 1], tour[k]) - self.get(tour[k], tour[k + 1]))
+                .sum::<f64>();
+        }
+        delta
+    }
+
+    /// Returns the tour from `start` that always moves to the closest unvisited node.
+    fn get_nearest_neighbor_tour(&self, start: usize) -> Vec<usize> {
+        let n = self.csr.number_of_nodes();
+        let mut visited = vec![false; n];
+        visited[start] = true;
+        let mut tour = vec![start];
+        while tour.len() < n {
+            let current = *tour.last().unwrap();
+            let next = (0..n)
+                .filter(|v| !visited[*v])
+                .min_by(|a, b| self.get(current, *a).partial_cmp(&self.get(current, *b)).unwrap())
+                .unwrap();
+            visited[next] = true;
+            tour.push(next);
+        }
+        tour
+    }
+
+    /// Returns the indexes of `nodes`, or an `Err` if they aren't every node exactly once.
+    fn get_tour_indexes(&self, nodes: &[T]) -> Result<Vec<usize>, Error> {
+        let tour: Vec<usize> =
+            self.csr.get_node_indexes(nodes)?.into_iter().map(|i| i as usize).collect();
+        let mut seen = vec![false; self.csr.number_of_nodes()];
+        let duplicated = tour.iter().any(|v| std::mem::replace(&mut seen[*v], true));
+        if duplicated || tour.len() != seen.len() {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: "The initial tour must contain every node exactly once.".to_string(),
+            });
+        }
+        Ok(tour)
+    }
+
+    fn to_tour(&self, tour: Vec<usize>) -> Tour<T> {
+        Tour {
+            weight: self.get_tour_weight(&tour),
+            nodes: tour.into_iter().map(|v| self.csr.get_node_names()[v].clone()).collect(),
+        }
+    }
+}
+
+/**
+Returns the edges of a perfect matching of the `nodes` with the smallest total weight.
+Finds the maximum weight matching with the most edges, after turning each weight `w`
+into `max - w + 1`, where `max` is the largest weight.
+*/
+fn get_min_weight_perfect_matching<T>(
+    distances: &Distances<T>,
+    nodes: &[usize],
+) -> Vec<(usize, usize)>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    let mut edges = vec![];
+    for a in 0..nodes.len() {
+        for b in (a + 1)..nodes.len() {
+            edges.push((a, b, distances.get(nodes[a], nodes[b])));
+        }
+    }
+    let max = edges.iter().map(|(_a, _b, w)| *w).fold(0.0, f64::max);
+    for edge in edges.iter_mut() {
+        edge.2 = max - edge.2 + 1.0;
+    }
+    max_weight_matching_indexes(nodes.len(), &edges, true)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(a, mate)| match mate {
+            Some(b) if a < b => Some((nodes[a], nodes[b])),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns an Eulerian circuit, from `start`, of the multigraph with `edges`.
+fn get_eulerian_circuit(n: usize, edges: &[(usize, usize)], start: usize) -> Vec<usize> {
+    let mut adjacency: Vec<Vec<(usize, usize)>> = vec![vec![]; n];
+    for (i, (u, v)) in edges.iter().enumerate() {
+        adjacency[*u].push((*v, i));
+        adjacency[*v].push((*u, i));
+    }
+    let mut used = vec![false; edges.len()];
+    let mut stack = vec![start];
+    let mut circuit = Vec::with_capacity(edges.len() + 1);
+    while let Some(v) = stack.last().copied() {
+        match adjacency[v].pop() {
+            Some((u, i)) => {
+                if !std::mem::replace(&mut used[i], true) {
+                    stack.push(u);
+                }
+            }
+            None => {
+                circuit.push(v);
+                stack.pop();
+            }
+        }
+    }
+    circuit
+}
@@ -0,0 +1,255 @@
+use crate::{Error, ErrorKind, Graph, Node};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Computes the degree assortativity of the graph: the Pearson correlation coefficient
+of the degrees of the nodes at either end of each edge.
+
+For a directed graph the out-degree of the source of each edge is correlated with
+the in-degree of the target. For an undirected graph each edge is counted in both
+directions.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `weighted`: if `true` the weighted degree of each node (the sum of its edge weights)
+  is used instead of the number of edges
+
+# Returns
+
+The coefficient is between -1 and 1, or NaN if every edge joins nodes with the same
+degree. An `Error` with a `kind` of `ErrorKind::InvalidArgument` is returned if the
+graph has no edges.
+
+# Examples
+
+```
+use graphrs::{algorithms::assortativity, generators};
+let graph = generators::social::karate_club_graph();
+let result = assortativity::degree_assortativity_coefficient(&graph, false).unwrap();
+assert!((result - -0.47561309768461).abs() < 1e-12);
+```
+
+# References
+
+1. M. E. J. Newman, Mixing patterns in networks, Physical Review E, 67 026126, 2003.
+*/
+pub fn degree_assortativity_coefficient<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let degrees = |in_degree: bool| -> HashMap<T, f64> {
+        graph
+            .get_all_nodes()
+            .into_iter()
+            .map(|n| {
+                let name = n.name.clone();
+                let degree = match (graph.specs.directed, in_degree, weighted) {
+                    (false, _, false) => graph.get_node_degree(name).unwrap() as f64,
+                    (false, _, true) => graph.get_node_weighted_degree(name).unwrap(),
+                    (true, false, false) => graph.get_node_out_degree(name).unwrap() as f64,
+                    (true, false, true) => graph.get_node_weighted_out_degree(name).unwrap(),
+                    (true, true, false) => graph.get_node_in_degree(name).unwrap() as f64,
+                    (true, true, true) => graph.get_node_weighted_in_degree(name).unwrap(),
+                };
+                (n.name.clone(), degree)
+            })
+            .collect()
+    };
+    let source_degrees = degrees(false);
+    let target_degrees = match graph.specs.directed {
+        true => degrees(true),
+        false => source_degrees.clone(),
+    };
+    let pairs = get_endpoint_pairs(graph)?
+        .into_iter()
+        .map(|(u, v)| (source_degrees[u], target_degrees[v]))
+        .collect::<Vec<(f64, f64)>>();
+    Ok(get_pearson_correlation(&pairs))
+}
+
+/**
+Computes the assortativity of the graph for a categorical node attribute: how much
+more often edges join nodes with the same attribute value than would be expected
+if edges were placed at random.
+
+For an undirected graph each edge is counted in both directions.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `attribute`: a function that returns the attribute value of a node
+
+# Returns
+
+The coefficient is 1 when edges only join nodes with the same value, 0 when mixing is
+random, and negative when edges tend to join nodes with different values. It is NaN
+if every node has the same value. An `Error` with a `kind` of
+`ErrorKind::InvalidArgument` is returned if the graph has no edges.
+
+# Examples
+
+```
+use graphrs::{algorithms::assortativity, Edge, Graph, GraphSpecs, Node};
+
+let mut graph = Graph::<&str, &str>::new(GraphSpecs::undirected());
+graph.add_nodes(vec![
+    Node::from_name_and_attributes("n1", "red"),
+    Node::from_name_and_attributes("n2", "red"),
+    Node::from_name_and_attributes("n3", "blue"),
+    Node::from_name_and_attributes("n4", "blue"),
+]);
+graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n3", "n4")]).unwrap();
+let colour = |node: &Node<&str, &str>| node.attributes.unwrap().to_string();
+let result = assortativity::attribute_assortativity_coefficient(&graph, colour).unwrap();
+assert_eq!(result, 1.0);
+```
+
+# References
+
+1. M. E. J. Newman, Mixing patterns in networks, Physical Review E, 67 026126, 2003.
+*/
+pub fn attribute_assortativity_coefficient<T, A, K, F>(
+    graph: &Graph<T, A>,
+    attribute: F,
+) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+    K: Hash + Eq,
+    F: Fn(&Node<T, A>) -> K,
+{
+    let values = get_attribute_values(graph, attribute);
+    let pairs = get_endpoint_pairs(graph)?;
+    let total = pairs.len() as f64;
+    // the fraction of edge ends with each value at the source and at the target
+    let mut source_fractions: HashMap<&K, f64> = HashMap::new();
+    let mut target_fractions: HashMap<&K, f64> = HashMap::new();
+    let mut same_fraction = 0.0;
+    for (u, v) in pairs {
+        let (x, y) = (&values[u], &values[v]);
+        *source_fractions.entry(x).or_insert(0.0) += 1.0 / total;
+        *target_fractions.entry(y).or_insert(0.0) += 1.0 / total;
+        if x == y {
+            same_fraction += 1.0 / total;
+        }
+    }
+    let expected_fraction: f64 =
+        source_fractions.iter().map(|(k, a)| a * target_fractions.get(k).unwrap_or(&0.0)).sum();
+    Ok((same_fraction - expected_fraction) / (1.0 - expected_fraction))
+}
+
+/**
+Computes the assortativity of the graph for a numeric node attribute: the Pearson
+correlation coefficient of the attribute values of the nodes at either end of each
+edge.
+
+For an undirected graph each edge is counted in both directions.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `attribute`: a function that returns the attribute value of a node
+
+# Returns
+
+The coefficient is between -1 and 1, or NaN if every edge joins nodes with the same
+value. An `Error` with a `kind` of `ErrorKind::InvalidArgument` is returned if the
+graph has no edges.
+
+# Examples
+
+```
+use graphrs::{algorithms::assortativity, Edge, Graph, GraphSpecs, Node};
+
+let mut graph = Graph::<&str, f64>::new(GraphSpecs::directed());
+graph.add_nodes(vec![
+    Node::from_name_and_attributes("n1", 1.0),
+    Node::from_name_and_attributes("n2", 2.0),
+    Node::from_name_and_attributes("n3", 3.0),
+]);
+graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n2", "n3")]).unwrap();
+let age = |node: &Node<&str, f64>| node.attributes.unwrap();
+let result = assortativity::numeric_assortativity_coefficient(&graph, age).unwrap();
+assert_eq!(result, 1.0);
+```
+
+# References
+
+1. M. E. J. Newman, Mixing patterns in networks, Physical Review E, 67 026126, 2003.
+*/
+pub fn numeric_assortativity_coefficient<T, A, F>(
+    graph: &Graph<T, A>,
+    attribute: F,
+) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+    F: Fn(&Node<T, A>) -> f64,
+{
+    let values = get_attribute_values(graph, attribute);
+    let pairs = get_endpoint_pairs(graph)?
+        .into_iter()
+        .map(|(u, v)| (values[u], values[v]))
+        .collect::<Vec<(f64, f64)>>();
+    Ok(get_pearson_correlation(&pairs))
+}
+
+/// Returns the attribute value of every node.
+fn get_attribute_values<T, A, K, F>(graph: &Graph<T, A>, attribute: F) -> HashMap<&T, K>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+    F: Fn(&Node<T, A>) -> K,
+{
+    graph.get_all_nodes().into_iter().map(|n| (&n.name, attribute(n))).collect()
+}
+
+/**
+Returns the (source, target) pair of every edge; for an undirected graph every edge
+other than a self-loop is returned in both directions.
+*/
+fn get_endpoint_pairs<T, A>(graph: &Graph<T, A>) -> Result<Vec<(&T, &T)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let edges = graph.get_all_edges();
+    if edges.is_empty() {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The graph has no edges.".to_string(),
+        });
+    }
+    let mut pairs = Vec::with_capacity(edges.len() * 2);
+    for edge in edges {
+        pairs.push((&edge.u, &edge.v));
+        if !graph.specs.directed && edge.u != edge.v {
+            pairs.push((&edge.v, &edge.u));
+        }
+    }
+    Ok(pairs)
+}
+
+/// Returns the Pearson correlation coefficient of `pairs`.
+fn get_pearson_correlation(pairs: &[(f64, f64)]) -> f64 {
+    let n = pairs.len() as f64;
+    let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (x, y) in pairs {
+        cov += (x - mean_x) * (y - mean_y);
+        var_x += (x - mean_x) * (x - mean_x);
+        var_y += (y - mean_y) * (y - mean_y);
+    }
+    cov / (var_x * var_y).sqrt()
+}
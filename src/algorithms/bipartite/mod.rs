@@ -0,0 +1,374 @@
+use crate::{Edge, Error, ErrorKind, Graph, GraphSpecs, Node};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns `true` if the graph is bipartite: if its nodes can be split into two sets so that
+every edge joins a node in one set to a node in the other. The direction of edges is
+ignored. A graph with a self-loop isn't bipartite.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, generators};
+
+assert!(bipartite::is_bipartite(&generators::classic::cycle_graph(6, false)));
+assert!(!bipartite::is_bipartite(&generators::classic::cycle_graph(5, false)));
+```
+*/
+pub fn is_bipartite<T, A>(graph: &Graph<T, A>) -> bool
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let (_nodes, neighbors) = get_neighbors(graph);
+    two_color(&neighbors).is_ok()
+}
+
+/**
+Colors the nodes of a bipartite graph with two colors, `0` and `1`, so that every edge
+joins two nodes with different colors. The direction of edges is ignored.
+
+The nodes of each connected component are colored by a breadth-first search from the
+component's smallest node, which gets the color `0`; so the result is deterministic.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+A `HashMap` of node names to colors. An `Error` with a `kind` of
+`ErrorKind::GraphNotBipartite` is returned if the graph isn't bipartite.
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, generators};
+
+let graph = generators::classic::path_graph(4, false);
+let colors = bipartite::color(&graph).unwrap();
+assert_eq!(colors[&0], 0);
+assert_eq!(colors[&1], 1);
+assert_eq!(colors[&2], 0);
+assert_eq!(colors[&3], 1);
+```
+*/
+pub fn color<T, A>(graph: &Graph<T, A>) -> Result<HashMap<T, usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let (nodes, neighbors) = get_neighbors(graph);
+    match two_color(&neighbors) {
+        Ok(colors) => Ok(nodes.into_iter().cloned().zip(colors).collect()),
+        Err((u, v)) => Err(get_not_bipartite_error(nodes[u], nodes[v])),
+    }
+}
+
+/**
+Returns the two sets of nodes of a bipartite graph.
+
+If `top_nodes` is `None` the sets are the nodes colored `0` and `1` by
+[color](fn.color.html). A graph that isn't connected can be split in more than one way, so
+for such a graph it's best to specify `top_nodes`: the first set is then `top_nodes` and
+the second set is all the other nodes.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `top_nodes`: the nodes of the first set, if known
+
+# Returns
+
+The two sets, each a sorted `Vec` of node names. An `Error` with a `kind` of
+`ErrorKind::GraphNotBipartite` is returned if the graph isn't bipartite, or if an edge joins
+two nodes in the same set. One with a `kind` of `ErrorKind::NodeNotFound` is returned if one
+of `top_nodes` doesn't exist.
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edge_tuples(vec![("ann", "rust"), ("bob", "rust"), ("bob", "go")]).unwrap();
+let (top, bottom) = bipartite::sets(&graph, Some(&["go", "rust"])).unwrap();
+assert_eq!(top, vec!["go", "rust"]);
+assert_eq!(bottom, vec!["ann", "bob"]);
+```
+*/
+pub fn sets<T, A>(graph: &Graph<T, A>, top_nodes: Option<&[T]>) -> Result<(Vec<T>, Vec<T>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let colors = match top_nodes {
+        None => color(graph)?,
+        Some(top) => {
+            for node in top {
+                graph.ensure_node(node)?;
+            }
+            let top: HashSet<&T> = top.iter().collect();
+            let colors: HashMap<T, usize> = graph
+                .get_all_node_names()
+                .into_iter()
+                .map(|n| (n.clone(), !top.contains(n) as usize))
+                .collect();
+            if let Some(edge) =
+                graph.get_all_edges().into_iter().find(|e| colors[&e.u] == colors[&e.v])
+            {
+                return Err(get_not_bipartite_error(&edge.u, &edge.v));
+            }
+            colors
+        }
+    };
+    let mut sets = (vec![], vec![]);
+    for (node, color) in colors {
+        match color {
+            0 => sets.0.push(node),
+            _ => sets.1.push(node),
+        }
+    }
+    sets.0.sort_unstable();
+    sets.1.sort_unstable();
+    Ok(sets)
+}
+
+/**
+Projects a bipartite graph onto one of its sets of nodes: the returned graph contains
+`nodes`, and joins two of them with an edge if they share a neighbor in the original
+graph. If the graph is directed, an edge is added from `u` to `w` if there's a path
+`u -> v -> w` in the original graph.
+
+The nodes keep their attributes; the edges don't have a weight or attributes. See
+[weighted_projected_graph](fn.weighted_projected_graph.html) to count the shared neighbors.
+Nodes in `nodes` are never shared neighbors, so if `nodes` isn't one of the two sets of a
+bipartite graph, only paths through nodes outside `nodes` create edges.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `nodes`: the nodes to project onto
+
+# Returns
+
+A new [Graph](../../struct.Graph.html), directed if `graph` is directed, that doesn't have
+multiple edges between nodes. An `Error` with a `kind` of `ErrorKind::NodeNotFound` is
+returned if one of `nodes` doesn't exist.
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edge_tuples(vec![("ann", "rust"), ("bob", "rust"), ("bob", "go"), ("cat", "go")])
+    .unwrap();
+let people = bipartite::projected_graph(&graph, &["ann", "bob", "cat"]).unwrap();
+assert_eq!(people.get_all_nodes().len(), 3);
+assert_eq!(people.get_all_edges().len(), 2);
+assert!(people.get_edge("ann", "bob").is_ok());
+assert!(people.get_edge("ann", "cat").is_err());
+```
+*/
+pub fn projected_graph<T, A>(graph: &Graph<T, A>, nodes: &[T]) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let (new_nodes, shared) = get_shared_neighbor_counts(graph, nodes)?;
+    let new_edges = shared.into_iter().map(|((u, v), _count)| Edge::new(u, v)).collect();
+    Graph::new_from_nodes_and_edges(new_nodes, new_edges, get_projection_specs(graph))
+}
+
+/**
+Projects a bipartite graph onto one of its sets of nodes, like
+[projected_graph](fn.projected_graph.html), and weights each edge by the number of
+neighbors its two nodes share in the original graph.
+
+If `ratio` is `true` the number of shared neighbors is divided by the number of nodes
+in the original graph that aren't in `nodes`: the largest number of neighbors that two
+nodes can share.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `nodes`: the nodes to project onto
+* `ratio`: set to `true` to divide the weights by the number of other nodes
+
+# Returns
+
+A new [Graph](../../struct.Graph.html), directed if `graph` is directed, that doesn't have
+multiple edges between nodes. An `Error` with a `kind` of `ErrorKind::NodeNotFound` is
+returned if one of `nodes` doesn't exist.
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edge_tuples(vec![
+    ("ann", "rust"), ("bob", "rust"), ("ann", "go"), ("bob", "go"), ("cat", "go")
+]).unwrap();
+let people = bipartite::weighted_projected_graph(&graph, &["ann", "bob", "cat"], false)
+    .unwrap();
+assert_eq!(people.get_edge("ann", "bob").unwrap().weight, 2.0);
+assert_eq!(people.get_edge("bob", "cat").unwrap().weight, 1.0);
+let people = bipartite::weighted_projected_graph(&graph, &["ann", "bob", "cat"], true)
+    .unwrap();
+assert_eq!(people.get_edge("bob", "cat").unwrap().weight, 0.5);
+```
+*/
+pub fn weighted_projected_graph<T, A>(
+    graph: &Graph<T, A>,
+    nodes: &[T],
+    ratio: bool,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let (new_nodes, shared) = get_shared_neighbor_counts(graph, nodes)?;
+    let num_other_nodes = graph.get_all_nodes().len() - new_nodes.len();
+    let new_edges = shared
+        .into_iter()
+        .map(|((u, v), count)| {
+            let weight = match ratio {
+                true => count as f64 / num_other_nodes as f64,
+                false => count as f64,
+            };
+            Edge::with_weight(u, v, weight)
+        })
+        .collect();
+    Graph::new_from_nodes_and_edges(new_nodes, new_edges, get_projection_specs(graph))
+}
+
+/**
+Returns the nodes of the graph, sorted, and the indexes of the neighbors of each node,
+ignoring the direction of edges.
+*/
+fn get_neighbors<T, A>(graph: &Graph<T, A>) -> (Vec<&T>, Vec<Vec<usize>>)
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let mut nodes = graph.get_all_node_names();
+    nodes.sort_unstable();
+    let indexes: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let mut neighbors = vec![vec![]; nodes.len()];
+    for edge in graph.get_all_edges() {
+        let u = indexes[&edge.u];
+        let v = indexes[&edge.v];
+        neighbors[u].push(v);
+        neighbors[v].push(u);
+    }
+    (nodes, neighbors)
+}
+
+/**
+Colors the nodes with a breadth-first search from each uncolored node in turn. Returns
+the two nodes of an edge whose nodes have the same color if the graph isn't bipartite.
+*/
+fn two_color(neighbors: &[Vec<usize>]) -> Result<Vec<usize>, (usize, usize)> {
+    let mut colors: Vec<Option<usize>> = vec![None; neighbors.len()];
+    let mut queue = VecDeque::new();
+    for start in 0..neighbors.len() {
+        if colors[start].is_some() {
+            continue;
+        }
+        colors[start] = Some(0);
+        queue.push_back(start);
+        while let Some(u) = queue.pop_front() {
+            let u_color = colors[u].unwrap();
+            for v in neighbors[u].iter() {
+                match colors[*v] {
+                    None => {
+                        colors[*v] = Some(1 - u_color);
+                        queue.push_back(*v);
+                    }
+                    Some(v_color) if v_color == u_color => return Err((u, *v)),
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    Ok(colors.into_iter().map(|c| c.unwrap()).collect())
+}
+
+/**
+Returns the nodes to project onto, with their attributes, and the number of neighbors
+shared by each pair of them that has at least one. For an undirected graph each pair
+appears once, with the smaller node first.
+*/
+#[allow(clippy::type_complexity)]
+fn get_shared_neighbor_counts<T, A>(
+    graph: &Graph<T, A>,
+    nodes: &[T],
+) -> Result<(Vec<Node<T, A>>, HashMap<(T, T), usize>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    for node in nodes {
+        graph.ensure_node(node)?;
+    }
+    let node_set: HashSet<&T> = nodes.iter().collect();
+    let mut shared = HashMap::new();
+    for u in node_set.iter() {
+        let via: HashSet<&T> = graph
+            .get_successors_or_neighbors((*u).clone())
+            .into_iter()
+            .map(|n| &n.name)
+            .filter(|n| !node_set.contains(n))
+            .collect();
+        let mut counts: HashMap<&T, usize> = HashMap::new();
+        for v in via {
+            let ws: HashSet<&T> = graph
+                .get_successors_or_neighbors(v.clone())
+                .into_iter()
+                .map(|n| &n.name)
+                .filter(|w| w != u && node_set.contains(w))
+                .collect();
+            for w in ws {
+                *counts.entry(w).or_insert(0) += 1;
+            }
+        }
+        for (w, count) in counts {
+            if graph.specs.directed || *u < w {
+                shared.insert(((*u).clone(), w.clone()), count);
+            }
+        }
+    }
+    let new_nodes =
+        node_set.into_iter().map(|n| graph.get_node(n.clone()).unwrap().clone()).collect();
+    Ok((new_nodes, shared))
+}
+
+fn get_projection_specs<T, A>(graph: &Graph<T, A>) -> GraphSpecs
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    GraphSpecs {
+        multi_edges: false,
+        self_loops: false,
+        ..graph.specs.clone()
+    }
+}
+
+fn get_not_bipartite_error<T: Display>(u: &T, v: &T) -> Error {
+    Error {
+        kind: ErrorKind::GraphNotBipartite,
+        message: format!(
+            "The graph isn't bipartite: the ({}, {}) edge joins two nodes in the same set.",
+            u, v
+        ),
+    }
+}
@@ -1,13 +1,18 @@
-use crate::algorithms::shortest_path::dijkstra;
-use crate::algorithms::shortest_path::ShortestPathInfo;
-use crate::{Error, Graph, Node};
-use std::collections::HashMap;
+use crate::algorithms::shortest_path::dijkstra::push_fringe_node;
+use crate::{CsrGraph, Error, ErrorKind, Graph, ParallelOptions, ProgressHandle};
+use rand::prelude::*;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use std::collections::{BinaryHeap, HashMap};
 use std::fmt::Display;
 use std::hash::Hash;
 
 /**
 Compute the shortest-path (Dijkstra) betweenness centrality for nodes.
 
+Uses Brandes' algorithm, which accumulates the dependencies of each source node on the
+other nodes without storing the shortest paths. The sources are processed in parallel.
+
 # Arguments
 
 * `graph`: a [Graph](../../../struct.Graph.html) instance
@@ -35,59 +40,568 @@ where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
-    let all_pairs = dijkstra::all_pairs(graph, weighted, None, false);
-    match all_pairs {
-        Err(e) => Err(e),
-        Ok(ap) => {
-            let mut between_counts = get_between_counts(&ap);
-            add_missing_nodes_to_between_counts(&mut between_counts, &graph.get_all_nodes());
-            let rescaled = rescale(
-                between_counts,
-                graph.get_all_nodes().len(),
-                normalized,
-                graph.specs.directed,
-            );
-            Ok(rescaled)
-        }
+    betweenness_centrality_with_progress(graph, weighted, normalized, &ProgressHandle::new())
+}
+
+/**
+Compute the shortest-path (Dijkstra) betweenness centrality for nodes, like
+[betweenness_centrality](./fn.betweenness_centrality.html), reporting the progress of the
+computation through `progress` and stopping if it is cancelled.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `normalized`: set to `true` to normalize the node centrality values
+* `progress`: a [ProgressHandle](../../../struct.ProgressHandle.html); each source node
+  that Brandes' algorithm has been run from is a unit of work
+
+# Returns
+
+An `Error` with a `kind` of `ErrorKind::Cancelled` is returned if `progress` was cancelled.
+
+# Examples
+
+```
+use graphrs::{algorithms::centrality::betweenness, generators, ProgressHandle};
+let graph = generators::social::karate_club_graph();
+let progress = ProgressHandle::new();
+let centralities = betweenness::betweenness_centrality_with_progress(&graph, false, true, &progress);
+assert_eq!(progress.get_progress().completed, 34);
+```
+*/
+pub fn betweenness_centrality_with_progress<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    progress: &ProgressHandle,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let options = ParallelOptions {
+        progress: progress.clone(),
+        ..ParallelOptions::default()
+    };
+    betweenness_centrality_with_options(graph, weighted, normalized, &options)
+}
+
+/**
+Compute the shortest-path (Dijkstra) betweenness centrality for nodes, like
+[betweenness_centrality](./fn.betweenness_centrality.html), with the threads and
+[ProgressHandle](../../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `normalized`: set to `true` to normalize the node centrality values
+* `options`: a [ParallelOptions](../../../struct.ParallelOptions.html); each source node
+  that Brandes' algorithm has been run from is a unit of work for its `progress`
+
+# Returns
+
+An `Error` with a `kind` of `ErrorKind::Cancelled` is returned if `options.progress` was
+cancelled, and one with a `kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't
+be built for `options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::centrality::betweenness, generators, Parallelism, ParallelOptions};
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let centralities = betweenness::betweenness_centrality_with_options(&graph, false, true, &options);
+assert_eq!(centralities.unwrap().len(), 34);
+```
+*/
+pub fn betweenness_centrality_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    get_betweenness_centrality(&graph.to_csr(), weighted, normalized, options)
+}
+
+/**
+Compute the shortest-path (Dijkstra) betweenness centrality for nodes in a
+[CsrGraph](../../../struct.CsrGraph.html). This is the same as
+[betweenness_centrality](./fn.betweenness_centrality.html) but avoids converting the
+graph on every call.
+
+# Arguments
+
+* `csr`: a [CsrGraph](../../../struct.CsrGraph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `normalized`: set to `true` to normalize the node centrality values
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{betweenness}}, generators};
+let graph = generators::social::karate_club_graph();
+let csr = graph.to_csr();
+let centralities = betweenness::betweenness_centrality_csr(&csr, false, true).unwrap();
+assert!(centralities.get(&0).unwrap() > centralities.get(&1).unwrap());
+```
+*/
+pub fn betweenness_centrality_csr<T>(
+    csr: &CsrGraph<T>,
+    weighted: bool,
+    normalized: bool,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    get_betweenness_centrality(csr, weighted, normalized, &ParallelOptions::default())
+}
+
+fn get_betweenness_centrality<T>(
+    csr: &CsrGraph<T>,
+    weighted: bool,
+    normalized: bool,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    if weighted {
+        csr.ensure_weighted()?;
+    }
+    let sources: Vec<u32> = (0..csr.number_of_nodes() as u32).collect();
+    let between_counts = get_between_counts(csr, weighted, &sources, options)?;
+    let rescaled = rescale(
+        between_counts,
+        csr.number_of_nodes(),
+        normalized,
+        csr.is_directed(),
+    );
+    Ok(rescaled)
+}
+
+/**
+Approximate the shortest-path (Dijkstra) betweenness centrality for nodes.
+
+Brandes' algorithm is run from a random sample of `k_samples` source nodes, rather than
+from every node, and the results are scaled up by `n / k_samples`. This makes it feasible
+to estimate the betweenness centrality of very large graphs.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `normalized`: set to `true` to normalize the node centrality values
+* `k_samples`: the number of source nodes to sample; if it's greater than or equal to the
+  number of nodes the exact betweenness centrality is computed
+* `seed`: setting the `seed` causes the same source nodes to be sampled on every call
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{betweenness}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities = betweenness::betweenness_sampled(&graph, false, true, 10, Some(1));
+```
+
+# References
+
+1. Ulrik Brandes and Christian Pich: Centrality Estimation in Large Networks.
+   International Journal of Bifurcation and Chaos 17(7):2303-2318, 2007.
+*/
+pub fn betweenness_sampled<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    k_samples: usize,
+    seed: Option<u64>,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    if k_samples == 0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "`k_samples` must be greater than 0.".to_string(),
+        });
+    }
+    let csr = graph.to_csr();
+    let node_names = csr.get_node_names();
+    let num_nodes = node_names.len();
+    if k_samples >= num_nodes {
+        return betweenness_centrality_csr(&csr, weighted, normalized);
+    }
+    let mut rng = get_rng(seed);
+    let sources: Vec<T> = node_names.choose_multiple(&mut rng, k_samples).cloned().collect();
+    let sources = csr.get_node_indexes(&sources)?;
+    let between_counts =
+        get_between_counts(&csr, weighted, &sources, &ParallelOptions::default())?;
+    let scale = get_scale(num_nodes, normalized, graph.specs.directed).unwrap_or(1.0)
+        * num_nodes as f64
+        / k_samples as f64;
+    Ok(between_counts.into_iter().map(|(k, v)| (k, v * scale)).collect())
+}
+
+/**
+Compute the shortest-path (Dijkstra) betweenness centrality for edges.
+
+The betweenness centrality of an edge is the sum, over all pairs of nodes, of the
+fraction of shortest paths between the pair that pass through the edge. For undirected
+graphs the keys of the returned `HashMap` have the smaller node name first. Multiple
+edges between the same pair of nodes share a single value.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `normalized`: set to `true` to normalize the edge centrality values
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{betweenness}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities = betweenness::edge_betweenness_centrality(&graph, false, true).unwrap();
+assert!(centralities.get(&(0, 31)).unwrap() > centralities.get(&(0, 1)).unwrap());
+```
+
+# References
+
+1. Ulrik Brandes: On Variants of Shortest-Path Betweenness Centrality and their Generic
+   Computation. Social Networks 30(2):136-145, 2008.
+*/
+pub fn edge_betweenness_centrality<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+) -> Result<HashMap<(T, T), f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    edge_betweenness_centrality_with_progress(graph, weighted, normalized, &ProgressHandle::new())
+}
+
+/**
+Compute the shortest-path (Dijkstra) betweenness centrality for edges, like
+[edge_betweenness_centrality](./fn.edge_betweenness_centrality.html), reporting the
+progress of the computation through `progress` and stopping if it is cancelled.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `normalized`: set to `true` to normalize the edge centrality values
+* `progress`: a [ProgressHandle](../../../struct.ProgressHandle.html); each source node
+  that Brandes' algorithm has been run from is a unit of work
+
+# Returns
+
+An `Error` with a `kind` of `ErrorKind::Cancelled` is returned if `progress` was cancelled.
+*/
+pub fn edge_betweenness_centrality_with_progress<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    progress: &ProgressHandle,
+) -> Result<HashMap<(T, T), f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let options = ParallelOptions {
+        progress: progress.clone(),
+        ..ParallelOptions::default()
+    };
+    edge_betweenness_centrality_with_options(graph, weighted, normalized, &options)
+}
+
+/**
+Compute the shortest-path (Dijkstra) betweenness centrality for edges, like
+[edge_betweenness_centrality](./fn.edge_betweenness_centrality.html), with the threads and
+[ProgressHandle](../../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `normalized`: set to `true` to normalize the edge centrality values
+* `options`: a [ParallelOptions](../../../struct.ParallelOptions.html); each source node
+  that Brandes' algorithm has been run from is a unit of work for its `progress`
+
+# Returns
+
+An `Error` with a `kind` of `ErrorKind::Cancelled` is returned if `options.progress` was
+cancelled, and one with a `kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't
+be built for `options.parallelism`.
+*/
+pub fn edge_betweenness_centrality_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    options: &ParallelOptions,
+) -> Result<HashMap<(T, T), f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let csr = graph.to_csr();
+    if weighted {
+        csr.ensure_weighted()?;
     }
+    let sources: Vec<u32> = (0..csr.number_of_nodes() as u32).collect();
+    let between_counts = get_edge_between_counts(&csr, weighted, &sources, options)?;
+    let num_nodes = csr.number_of_nodes();
+    let scale = match normalized {
+        true => match num_nodes <= 1 {
+            true => None,
+            false => Some(1.0 / (num_nodes as f64 * (num_nodes as f64 - 1.0))),
+        },
+        false => get_scale(num_nodes, normalized, graph.specs.directed),
+    };
+    Ok(match scale {
+        None => between_counts,
+        Some(s) => between_counts.into_iter().map(|(k, v)| (k, v * s)).collect(),
+    })
 }
 
-fn add_missing_nodes_to_between_counts<T, A>(
-    between_counts: &mut HashMap<T, f64>,
-    nodes: &[&Node<T, A>],
-) where
+/**
+Runs Brandes' algorithm from each of the `sources`, with the threads of `options`, and
+sums the dependencies of every node. The sources that are left once `options.progress` is
+cancelled are skipped.
+*/
+fn get_between_counts<T>(
+    csr: &CsrGraph<T>,
+    weighted: bool,
+    sources: &[u32],
+    options: &ParallelOptions,
+) -> Result<HashMap<T, f64>, Error>
+where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
 {
-    for node in nodes {
-        between_counts.entry(node.name.clone()).or_insert(0.0);
+    let num_nodes = csr.number_of_nodes();
+    let progress = &options.progress;
+    progress.start(Some(sources.len()))?;
+    let counts = options.map_reduce(
+        sources.len(),
+        |index| match progress.is_cancelled() {
+            true => vec![],
+            false => {
+                let dependencies = get_dependencies(csr, weighted, sources[index]);
+                progress.advance();
+                dependencies
+            }
+        },
+        || vec![0.0; num_nodes],
+        |mut acc, dependencies| {
+            acc.iter_mut().zip(dependencies).for_each(|(a, d)| *a += d);
+            acc
+        },
+    )?;
+    progress.check()?;
+    Ok(csr.get_node_names().iter().cloned().zip(counts).collect())
+}
+
+/**
+Computes the dependency of `source` on every other node: the sum, over all targets,
+of the fraction of shortest paths from `source` to the target that pass through the node.
+*/
+fn get_dependencies<T>(csr: &CsrGraph<T>, weighted: bool, source: u32) -> Vec<f64>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    let (settled, preds, sigma) = single_source_shortest_path_counts(csr, weighted, source);
+    let mut delta = vec![0.0; csr.number_of_nodes()];
+    for w in settled.iter().rev() {
+        let w = *w as usize;
+        let coefficient = (1.0 + delta[w]) / sigma[w];
+        for v in preds[w].iter() {
+            delta[*v as usize] += sigma[*v as usize] * coefficient;
+        }
     }
+    delta[source as usize] = 0.0;
+    delta
 }
 
-fn get_between_counts<T>(pairs: &HashMap<T, HashMap<T, ShortestPathInfo<T>>>) -> HashMap<T, f64>
+/**
+Runs Brandes' algorithm from each of the `sources`, with the threads of `options`, and
+sums the dependencies of every edge. Every edge is included, even if its dependency is
+zero. The sources that are left once `options.progress` is cancelled are skipped.
+*/
+fn get_edge_between_counts<T>(
+    csr: &CsrGraph<T>,
+    weighted: bool,
+    sources: &[u32],
+    options: &ParallelOptions,
+) -> Result<HashMap<(T, T), f64>, Error>
 where
-    T: Hash + Eq + Clone + Ord + Display,
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
 {
-    let short_paths = pairs.values().flat_map(|x| x.values());
-    short_paths.flat_map(|sp| get_node_counts(&sp.paths)).fold(
-        HashMap::<T, f64>::new(),
-        |mut acc, (node, count)| {
-            *acc.entry(node).or_insert(0.0) += count;
+    let progress = &options.progress;
+    progress.start(Some(sources.len()))?;
+    let mut counts = options.map_reduce(
+        sources.len(),
+        |index| match progress.is_cancelled() {
+            true => HashMap::new(),
+            false => {
+                let dependencies = get_edge_dependencies(csr, weighted, sources[index]);
+                progress.advance();
+                dependencies
+            }
+        },
+        HashMap::new,
+        |mut acc, dependencies| {
+            for (edge, dependency) in dependencies {
+                *acc.entry(edge).or_insert(0.0) += dependency;
+            }
             acc
         },
-    )
+    )?;
+    progress.check()?;
+    for u in 0..csr.number_of_nodes() as u32 {
+        for v in csr.get_successors_or_neighbors(u) {
+            counts.entry(get_edge_key(u, *v, csr.is_directed())).or_insert(0.0);
+        }
+    }
+    let names = csr.get_node_names();
+    Ok(counts
+        .into_iter()
+        .map(|((u, v), count)| {
+            (
+                (names[u as usize].clone(), names[v as usize].clone()),
+                count,
+            )
+        })
+        .collect())
+}
+
+/**
+Computes the dependency of `source` on every edge: the sum, over all targets,
+of the fraction of shortest paths from `source` to the target that pass through the edge.
+*/
+fn get_edge_dependencies<T>(
+    csr: &CsrGraph<T>,
+    weighted: bool,
+    source: u32,
+) -> HashMap<(u32, u32), f64>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    let (settled, preds, sigma) = single_source_shortest_path_counts(csr, weighted, source);
+    let mut delta = vec![0.0; csr.number_of_nodes()];
+    let mut edge_delta: HashMap<(u32, u32), f64> = HashMap::new();
+    for w in settled.iter().rev() {
+        let coefficient = (1.0 + delta[*w as usize]) / sigma[*w as usize];
+        for v in preds[*w as usize].iter() {
+            let c = sigma[*v as usize] * coefficient;
+            let key = get_edge_key(*v, *w, csr.is_directed());
+            *edge_delta.entry(key).or_insert(0.0) += c;
+            delta[*v as usize] += c;
+        }
+    }
+    edge_delta
+}
+
+/**
+Returns the key for the edge between `u` and `v`; undirected edges have the smaller node first.
+Node indexes are in the same order as node names, so this works for either.
+*/
+fn get_edge_key<T>(u: T, v: T, directed: bool) -> (T, T)
+where
+    T: Ord,
+{
+    match !directed && v < u {
+        true => (v, u),
+        false => (u, v),
+    }
 }
 
-fn get_node_counts<T>(paths: &[Vec<T>]) -> Vec<(T, f64)>
+/**
+Finds the shortest paths from `source`, returning the nodes in the order they were
+settled (in non-decreasing distance), the shortest-path predecessors of each node,
+and the number of shortest paths to each node.
+*/
+#[allow(clippy::type_complexity)]
+fn single_source_shortest_path_counts<T>(
+    csr: &CsrGraph<T>,
+    weighted: bool,
+    source: u32,
+) -> (Vec<u32>, Vec<Vec<u32>>, Vec<f64>)
 where
-    T: Clone,
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
 {
-    let paths_count = paths.len() as f64;
-    paths
-        .iter()
-        .filter(|path| path.len() > 2)
-        .flat_map(|path| &path[1..(path.len() - 1)])
-        .map(|node| (node.clone(), 1.0 / paths_count))
-        .collect()
+    let num_nodes = csr.number_of_nodes();
+    let mut settled = vec![];
+    let mut preds: Vec<Vec<u32>> = vec![vec![]; num_nodes];
+    let mut sigma = vec![0.0; num_nodes];
+    let mut dist: Vec<Option<f64>> = vec![None; num_nodes];
+    let mut seen: Vec<Option<f64>> = vec![None; num_nodes];
+    let mut fringe = BinaryHeap::new();
+    let mut count = 0;
+
+    sigma[source as usize] = 1.0;
+    seen[source as usize] = Some(0.0);
+    push_fringe_node(&mut count, &mut fringe, source, 0.0);
+
+    while let Some(fringe_item) = fringe.pop() {
+        let d = -fringe_item.distance;
+        let v = fringe_item.node_name;
+        if dist[v as usize].is_some() {
+            continue;
+        }
+        dist[v as usize] = Some(d);
+        settled.push(v);
+        let v_sigma = sigma[v as usize];
+        let neighbors = csr.get_successors_or_neighbors(v).iter();
+        for (&w, &weight) in neighbors.zip(csr.get_weights(v)) {
+            let cost = match weighted {
+                true => weight,
+                false => 1.0,
+            };
+            let vw_dist = d + cost;
+            if dist[w as usize].is_some() {
+                continue;
+            }
+            match seen[w as usize] {
+                Some(w_dist) if vw_dist > w_dist => {}
+                Some(w_dist) if vw_dist == w_dist => {
+                    sigma[w as usize] += v_sigma;
+                    preds[w as usize].push(v);
+                }
+                _ => {
+                    seen[w as usize] = Some(vw_dist);
+                    push_fringe_node(&mut count, &mut fringe, w, vw_dist);
+                    sigma[w as usize] = v_sigma;
+                    preds[w as usize] = vec![v];
+                }
+            }
+        }
+    }
+
+    (settled, preds, sigma)
+}
+
+/// Returns a random number generator (RNG), optionally seeded.
+fn get_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        None => {
+            let mut trng = thread_rng();
+            StdRng::seed_from_u64(trng.next_u64())
+        }
+        Some(s) => StdRng::seed_from_u64(s),
+    }
 }
 
 fn rescale<T>(
@@ -102,10 +616,7 @@ where
     let scale = get_scale(num_nodes, normalized, directed);
     match scale {
         None => node_counts,
-        Some(s) => node_counts
-            .iter()
-            .map(|(k, v)| (k.clone(), v * s))
-            .collect(),
+        Some(s) => node_counts.iter().map(|(k, v)| (k.clone(), v * s)).collect(),
     }
 }
 
@@ -123,90 +634,12 @@ fn get_scale(num_nodes: usize, normalized: bool, directed: bool) -> Option<f64>
 }
 
 // tests for private methods only; other tests are in:
-// tests/test_algorithms_centrality_betweenness
+// tests/test_algorithms_centrality_betweenness.rs
 #[cfg(test)]
 mod tests {
 
     use super::*;
-
-    #[test]
-    fn test_get_node_counts_1() {
-        let result = get_node_counts::<&str>(&[]);
-        assert_eq!(result, vec![]);
-    }
-
-    #[test]
-    fn test_get_node_counts_2() {
-        let result = get_node_counts(&[vec!["n1", "n3"]]);
-        assert_eq!(result, vec![]);
-    }
-
-    #[test]
-    fn test_get_node_counts_3() {
-        let result = get_node_counts(&[vec!["n1", "n2", "n3"]]);
-        assert_eq!(result, vec![("n2", 1.0)]);
-    }
-
-    #[test]
-    fn test_get_node_counts_4() {
-        let result = get_node_counts(&[vec!["n1", "n3"], vec!["n1", "n2", "n3"]]);
-        assert_eq!(result, vec![("n2", 0.5)]);
-    }
-
-    #[test]
-    fn test_get_node_counts_5() {
-        let result = get_node_counts(&[vec!["n1", "n2", "n3", "n4", "n5"]]);
-        assert_eq!(result, vec![("n2", 1.0), ("n3", 1.0), ("n4", 1.0)]);
-    }
-
-    #[test]
-    fn test_get_node_counts_6() {
-        let result = get_node_counts(&[
-            vec!["n1", "n2", "n3", "n4", "n5"],
-            vec!["n1", "n2", "n6", "n5"],
-        ]);
-        assert_eq!(
-            result,
-            vec![
-                ("n2", 0.5),
-                ("n3", 0.5),
-                ("n4", 0.5),
-                ("n2", 0.5),
-                ("n6", 0.5)
-            ]
-        );
-    }
-
-    #[test]
-    fn test_get_between_counts_1() {
-        let mut pairs: HashMap<&str, HashMap<&str, ShortestPathInfo<&str>>> = HashMap::new();
-        let mut hm1: HashMap<&str, ShortestPathInfo<&str>> = HashMap::new();
-        hm1.insert(
-            "n3",
-            ShortestPathInfo {
-                distance: 3.0,
-                paths: vec![vec!["n1", "n2", "n3"], vec!["n1", "n4", "n3"]],
-            },
-        );
-        pairs.insert("n1", hm1);
-        let mut hm1: HashMap<&str, ShortestPathInfo<&str>> = HashMap::new();
-        hm1.insert(
-            "n9",
-            ShortestPathInfo {
-                distance: 3.0,
-                paths: vec![vec!["n7", "n8", "n9"], vec!["n7", "n2", "n9"]],
-            },
-        );
-        pairs.insert("n7", hm1);
-        let result = get_between_counts(&pairs);
-        assert!(result.get("n1").is_none());
-        assert_eq!(result.get("n2").unwrap(), &1.0);
-        assert!(result.get("n3").is_none());
-        assert_eq!(result.get("n4").unwrap(), &0.5);
-        assert!(result.get("n7").is_none());
-        assert_eq!(result.get("n8").unwrap(), &0.5);
-        assert!(result.get("n9").is_none());
-    }
+    use crate::{Edge, GraphSpecs, Node};
 
     #[test]
     fn test_get_scale_1() {
@@ -239,17 +672,17 @@ mod tests {
     }
 
     #[test]
-    fn test_add_missing_nodes_to_between_counts() {
-        let mut between_counts: HashMap<&str, f64> = HashMap::new();
-        between_counts.insert("n1", 1.0);
-        between_counts.insert("n4", 4.0);
-        let n2 = Node::from_name("n2");
-        let n3 = Node::from_name("n3");
-        let nodes: Vec<&Node<&str, ()>> = vec![&n2, &n3];
-        add_missing_nodes_to_between_counts(&mut between_counts, &nodes);
-        assert_eq!(between_counts.get("n1").unwrap(), &1.0);
-        assert_eq!(between_counts.get("n2").unwrap(), &0.0);
+    fn test_get_between_counts_includes_every_node() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n2", "n3")]).unwrap();
+        graph.add_node(Node::from_name("n4"));
+        let csr = graph.to_csr();
+        let options = ParallelOptions::default();
+        let between_counts = get_between_counts(&csr, false, &[0, 1, 2, 3], &options).unwrap();
+        assert_eq!(between_counts.len(), 4);
+        assert_eq!(between_counts.get("n1").unwrap(), &0.0);
+        assert_eq!(between_counts.get("n2").unwrap(), &1.0);
         assert_eq!(between_counts.get("n3").unwrap(), &0.0);
-        assert_eq!(between_counts.get("n4").unwrap(), &4.0);
+        assert_eq!(between_counts.get("n4").unwrap(), &0.0);
     }
 }
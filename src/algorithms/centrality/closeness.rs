@@ -1,5 +1,4 @@
 use crate::algorithms::shortest_path::dijkstra;
-use crate::algorithms::shortest_path::ShortestPathInfo;
 use crate::{Error, Graph};
 use std::collections::HashMap;
 use std::fmt::Display;
@@ -51,7 +50,7 @@ where
         the_graph = &x;
     }
     let num_nodes = the_graph.get_all_nodes().len();
-    let all_pairs = dijkstra::all_pairs(the_graph, weighted, None, false);
+    let all_pairs = dijkstra::all_pairs_distances(the_graph, weighted, None, None);
     match all_pairs {
         Err(e) => Err(e),
         Ok(ap) => {
@@ -67,17 +66,17 @@ where
 /// Gets the closeness centrality for a single node.
 fn get_closeness_centrality_for_node<T>(
     node_name: T,
-    shortest_paths: HashMap<T, ShortestPathInfo<T>>,
+    distances: HashMap<T, f64>,
     num_nodes: usize,
     wf_improved: bool,
 ) -> (T, f64) {
-    let total: f64 = shortest_paths.values().map(|sp| sp.distance).sum();
+    let total: f64 = distances.values().sum();
     let centrality: f64 = match total > 0.0 && num_nodes > 1 {
         false => 0.0,
         true => {
-            let mut x = (shortest_paths.len() as f64 - 1.0) / total;
+            let mut x = (distances.len() as f64 - 1.0) / total;
             if wf_improved {
-                let s = (shortest_paths.len() as f64 - 1.0) / ((num_nodes - 1) as f64);
+                let s = (distances.len() as f64 - 1.0) / ((num_nodes - 1) as f64);
                 x *= s;
             }
             x
@@ -0,0 +1,131 @@
+use crate::{Error, ErrorKind, Graph};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Compute the HITS hub and authority scores for nodes.
+
+The HITS (Hyperlink-Induced Topic Search) algorithm computes two values for each node.
+A good hub is a node that points to many good authorities, and a good authority is a node
+that is pointed to by many good hubs. Undirected graphs are treated as directed graphs
+with edges in both directions, in which case the hub and authority scores are the same.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the scores
+* `max_iter`: the maximum number of iterations in power method; use `None` to use the default
+  value of `100`
+* `tolerance`: the error tolerance used to check convergence in power method iteration; use `None` to
+  use the default value of `1.0e-8`
+* `normalized`: set to `true` to normalize the results so that the scores of each type sum to `1.0`
+
+# Returns
+
+A tuple of two `HashMap`s: the hub scores and the authority scores.
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{hits}}, Edge, Graph, GraphSpecs};
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("n1", "n3"),
+    Edge::new("n2", "n3"),
+    Edge::new("n2", "n4"),
+]);
+let (hubs, authorities) = hits::hits(&graph, false, None, None, true).unwrap();
+assert!(hubs.get("n2").unwrap() > hubs.get("n1").unwrap());
+assert!(authorities.get("n3").unwrap() > authorities.get("n4").unwrap());
+```
+
+# References
+
+1. Jon Kleinberg. Authoritative sources in a hyperlinked environment.
+   Journal of the ACM 46(5):604-632, 1999.
+   <https://www.cs.cornell.edu/home/kleinber/auth.pdf>
+*/
+#[allow(clippy::type_complexity)]
+pub fn hits<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    max_iter: Option<u32>,
+    tolerance: Option<f64>,
+    normalized: bool,
+) -> Result<(HashMap<T, f64>, HashMap<T, f64>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let _max_iter = max_iter.unwrap_or(100);
+    let _tolerance = tolerance.unwrap_or(1.0e-8);
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+
+    let nodes = graph.get_all_node_names();
+    let nnodes = nodes.len();
+    if nnodes == 0 {
+        return Ok((HashMap::new(), HashMap::new()));
+    }
+    let node_indexes: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let mut arcs: Vec<(usize, usize, f64)> = vec![];
+    for edge in graph.get_all_edges() {
+        let weight = match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+        let u = *node_indexes.get(&edge.u).unwrap();
+        let v = *node_indexes.get(&edge.v).unwrap();
+        arcs.push((u, v, weight));
+        if !graph.specs.directed && u != v {
+            arcs.push((v, u, weight));
+        }
+    }
+
+    let mut h = vec![1.0 / nnodes as f64; nnodes];
+    for _i in 0.._max_iter {
+        let hlast = h;
+        let mut a = vec![0.0; nnodes];
+        for (u, v, w) in arcs.iter() {
+            a[*v] += hlast[*u] * w;
+        }
+        h = vec![0.0; nnodes];
+        for (u, v, w) in arcs.iter() {
+            h[*u] += a[*v] * w;
+        }
+        scale_to_max(&mut h);
+        scale_to_max(&mut a);
+        let err: f64 = h.iter().zip(hlast.iter()).map(|(x, y)| (x - y).abs()).sum();
+        if err < _tolerance {
+            if normalized {
+                scale_to_sum(&mut h);
+                scale_to_sum(&mut a);
+            }
+            let hubs = nodes.iter().cloned().cloned().zip(h).collect();
+            let authorities = nodes.into_iter().cloned().zip(a).collect();
+            return Ok((hubs, authorities));
+        }
+    }
+    Err(Error {
+        kind: ErrorKind::PowerIterationFailedConvergence,
+        message: "failed to converge to the specified tolerance within the specified number of iterations.".to_string(),
+    })
+}
+
+/// Divides every value by the largest value, unless the largest value is zero.
+fn scale_to_max(values: &mut [f64]) {
+    let max = values.iter().cloned().fold(0.0, f64::max);
+    if max > 0.0 {
+        values.iter_mut().for_each(|v| *v /= max);
+    }
+}
+
+/// Divides every value by the sum of the values, unless the sum is zero.
+fn scale_to_sum(values: &mut [f64]) {
+    let sum: f64 = values.iter().sum();
+    if sum > 0.0 {
+        values.iter_mut().for_each(|v| *v /= sum);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{Error, ErrorKind, Graph};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Compute the Katz centrality for nodes.
+
+Katz centrality computes the centrality for a node based on the centrality of its
+neighbors. It is a generalization of eigenvector centrality where every node is also
+given a small amount of centrality, `beta`, regardless of its neighbors.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the Katz centrality
+* `alpha`: the attenuation factor; use `None` to use the default value of `0.1`;
+  it should be less than the inverse of the largest eigenvalue of the adjacency matrix
+  for the power iteration to converge
+* `beta`: the weight attributed to the immediate neighborhood; use `None` to use the default
+  value of `1.0`
+* `max_iter`: the maximum number of iterations in power method; use `None` to use the default
+  value of `1000`
+* `tolerance`: the error tolerance used to check convergence in power method iteration; use `None` to
+  use the default value of `1.0e-6`
+* `normalized`: set to `true` to normalize the resulting values
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{katz}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities = katz::katz_centrality(&graph, false, None, None, None, None, true);
+```
+
+# References
+
+1. Leo Katz. "A new status index derived from sociometric analysis."
+   *Psychometrika* 18(1):39–43, 1953
+   <https://link.springer.com/content/pdf/10.1007/BF02289026.pdf>
+
+2. Mark E. J. Newman.
+   *Networks: An Introduction.*
+   Oxford University Press, USA, 2010, p. 720.
+*/
+pub fn katz_centrality<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    alpha: Option<f64>,
+    beta: Option<f64>,
+    max_iter: Option<u32>,
+    tolerance: Option<f64>,
+    normalized: bool,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let _alpha = alpha.unwrap_or(0.1);
+    let _beta = beta.unwrap_or(1.0);
+    let _max_iter = max_iter.unwrap_or(1000);
+    let _tolerance = tolerance.unwrap_or(1.0e-6);
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let nnodes = graph.get_all_nodes().len();
+    let mut x: HashMap<T, f64> =
+        graph.get_all_nodes().iter().map(|n| (n.name.clone(), 0.0)).collect();
+    for _i in 0.._max_iter {
+        let xlast = x;
+        x = xlast.keys().map(|k| (k.clone(), 0.0)).collect();
+        for edge in graph.get_all_edges() {
+            let w = match !weighted || edge.weight.is_nan() {
+                true => 1.0,
+                false => edge.weight,
+            };
+            *x.get_mut(&edge.v).unwrap() += xlast.get(&edge.u).unwrap() * w;
+            if !graph.specs.directed && edge.u != edge.v {
+                *x.get_mut(&edge.u).unwrap() += xlast.get(&edge.v).unwrap() * w;
+            }
+        }
+        x.values_mut().for_each(|v| *v = _alpha * *v + _beta);
+        let y: f64 = x.iter().map(|(k, v)| (v - xlast.get(k).unwrap()).abs()).sum();
+        if y < (nnodes as f64 * _tolerance) {
+            if normalized {
+                let mut norm: f64 = x.values().map(|v| v.powf(2.0)).sum();
+                norm = norm.sqrt();
+                norm = match norm == 0.0 {
+                    true => 1.0,
+                    false => norm,
+                };
+                x.values_mut().for_each(|v| *v /= norm);
+            }
+            return Ok(x);
+        }
+    }
+    Err(Error {
+        kind: ErrorKind::PowerIterationFailedConvergence,
+        message: "failed to converge to the specified tolerance within the specified number of iterations.".to_string(),
+    })
+}
@@ -9,3 +9,15 @@ pub mod degree;
 
 /// Compute eigenvector centrality of nodes and edges.
 pub mod eigenvector;
+
+/// Compute the HITS hub and authority scores of nodes.
+pub mod hits;
+
+/// Compute Katz centrality of nodes.
+pub mod katz;
+
+/// Compute the PageRank of nodes.
+pub mod pagerank;
+
+/// Compute the personalized PageRank (random walk with restart) of nodes.
+pub mod personalized_pagerank;
@@ -0,0 +1,271 @@
+use crate::{CsrGraph, Error, ErrorKind, Graph};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Compute the PageRank of the nodes in the graph.
+
+PageRank ranks the nodes of a graph according to the structure of the incoming links.
+A "random surfer" follows an outgoing edge with probability `damping` and jumps to a
+random node with probability `1 - damping`. Nodes without any outgoing edges ("dangling"
+nodes) distribute their rank in the same way as random jumps.
+
+Undirected graphs are treated as directed graphs with edges in both directions.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the PageRank
+* `damping`: the probability of following an edge rather than jumping to a random
+  node; commonly `0.85`
+* `personalization`: the relative probability of jumping to each node; nodes that aren't
+  in the map have a probability of `0`; use `None` to give every node the same probability
+* `max_iter`: the maximum number of iterations in power method; use `None` to use the default
+  value of `100`
+* `tolerance`: the error tolerance used to check convergence in power method iteration; use `None` to
+  use the default value of `1.0e-6`
+
+# Returns
+
+A `HashMap` of node names to PageRank values; the values sum to `1.0`.
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{pagerank}}, generators};
+let graph = generators::social::karate_club_graph();
+let ranks = pagerank::pagerank(&graph, false, 0.85, None, None, None).unwrap();
+let total: f64 = ranks.values().sum();
+assert!((total - 1.0).abs() < 1.0e-6);
+```
+
+# References
+
+1. A. Langville and C. Meyer. "A survey of eigenvector methods of web information retrieval."
+   <http://citeseer.ist.psu.edu/713792.html>
+
+2. Page, Lawrence; Brin, Sergey; Motwani, Rajeev and Winograd, Terry.
+   The PageRank citation ranking: Bringing order to the Web. 1999
+   <http://dbpubs.stanford.edu:8090/pub/showDoc.Fulltext?lang=en&doc=1999-66&format=pdf>
+*/
+pub fn pagerank<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    damping: f64,
+    personalization: Option<HashMap<T, f64>>,
+    max_iter: Option<u32>,
+    tolerance: Option<f64>,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    check_damping(damping)?;
+
+    let nodes = graph.get_all_node_names();
+    let nnodes = nodes.len();
+    if nnodes == 0 {
+        return Ok(HashMap::new());
+    }
+    let node_indexes: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let jump = get_jump_probabilities(&node_indexes, personalization)?;
+
+    // arcs[v] holds (u, w) for every arc u -> v; out_weights[u] is the total weight leaving u
+    let mut arcs: Vec<Vec<(usize, f64)>> = vec![vec![]; nnodes];
+    let mut out_weights = vec![0.0; nnodes];
+    for edge in graph.get_all_edges() {
+        let weight = match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+        let u = *node_indexes.get(&edge.u).unwrap();
+        let v = *node_indexes.get(&edge.v).unwrap();
+        arcs[v].push((u, weight));
+        out_weights[u] += weight;
+        if !graph.specs.directed && u != v {
+            arcs[u].push((v, weight));
+            out_weights[v] += weight;
+        }
+    }
+
+    let x = power_iterate(&arcs, &out_weights, &jump, damping, max_iter, tolerance)?;
+    Ok(nodes.into_iter().cloned().zip(x).collect())
+}
+
+/**
+Compute the PageRank of the nodes in a [CsrGraph](../../../struct.CsrGraph.html).
+
+This is the same as [pagerank](./fn.pagerank.html) but avoids converting the graph on
+every call. Because a `CsrGraph` stores multiple edges between the same two nodes as a
+single edge with the smallest weight, the results for graphs with multiple edges can
+differ from those of `pagerank`.
+
+# Arguments
+
+* `csr`: a [CsrGraph](../../../struct.CsrGraph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the PageRank
+* `damping`: the probability of following an edge rather than jumping to a random
+  node; commonly `0.85`
+* `personalization`: the relative probability of jumping to each node; nodes that aren't
+  in the map have a probability of `0`; use `None` to give every node the same probability
+* `max_iter`: the maximum number of iterations in power method; use `None` to use the default
+  value of `100`
+* `tolerance`: the error tolerance used to check convergence in power method iteration; use `None` to
+  use the default value of `1.0e-6`
+
+# Returns
+
+A `HashMap` of node names to PageRank values; the values sum to `1.0`.
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{pagerank}}, generators};
+let graph = generators::social::karate_club_graph();
+let csr = graph.to_csr();
+let ranks = pagerank::pagerank_csr(&csr, false, 0.85, None, None, None).unwrap();
+let expected = pagerank::pagerank(&graph, false, 0.85, None, None, None).unwrap();
+assert!((ranks.get(&33).unwrap() - expected.get(&33).unwrap()).abs() < 1.0e-12);
+```
+*/
+pub fn pagerank_csr<T>(
+    csr: &CsrGraph<T>,
+    weighted: bool,
+    damping: f64,
+    personalization: Option<HashMap<T, f64>>,
+    max_iter: Option<u32>,
+    tolerance: Option<f64>,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    if weighted {
+        csr.ensure_weighted()?;
+    }
+    check_damping(damping)?;
+
+    let nodes = csr.get_node_names();
+    let nnodes = nodes.len();
+    if nnodes == 0 {
+        return Ok(HashMap::new());
+    }
+    let node_indexes: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    let jump = get_jump_probabilities(&node_indexes, personalization)?;
+
+    let mut arcs: Vec<Vec<(usize, f64)>> = vec![vec![]; nnodes];
+    let mut out_weights = vec![0.0; nnodes];
+    for (u, out_weight) in out_weights.iter_mut().enumerate() {
+        let successors = csr.get_successors_or_neighbors(u as u32);
+        for (v, w) in successors.iter().zip(csr.get_weights(u as u32)) {
+            let weight = match weighted {
+                true => *w,
+                false => 1.0,
+            };
+            arcs[*v as usize].push((u, weight));
+            *out_weight += weight;
+        }
+    }
+
+    let x = power_iterate(&arcs, &out_weights, &jump, damping, max_iter, tolerance)?;
+    Ok(nodes.iter().cloned().zip(x).collect())
+}
+
+/// Returns an `Err` if `damping` isn't between 0 and 1.
+fn check_damping(damping: f64) -> Result<(), Error> {
+    if !(0.0..=1.0).contains(&damping) {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!(
+                "`damping` was {} but it must be between 0.0 and 1.0.",
+                damping
+            ),
+        });
+    }
+    Ok(())
+}
+
+/**
+Runs the power method until the PageRank values converge. `arcs[v]` holds `(u, w)` for
+every arc u -> v and `out_weights[u]` is the total weight leaving u.
+*/
+fn power_iterate(
+    arcs: &[Vec<(usize, f64)>],
+    out_weights: &[f64],
+    jump: &[f64],
+    damping: f64,
+    max_iter: Option<u32>,
+    tolerance: Option<f64>,
+) -> Result<Vec<f64>, Error> {
+    let _max_iter = max_iter.unwrap_or(100);
+    let _tolerance = tolerance.unwrap_or(1.0e-6);
+    let nnodes = arcs.len();
+    let mut x = vec![1.0 / nnodes as f64; nnodes];
+    for _i in 0.._max_iter {
+        let dangling_sum: f64 = (0..nnodes).filter(|u| out_weights[*u] == 0.0).map(|u| x[u]).sum();
+        let xlast = x;
+        x = (0..nnodes)
+            .map(|v| {
+                let linked: f64 =
+                    arcs[v].iter().map(|(u, w)| xlast[*u] * w / out_weights[*u]).sum();
+                damping * linked + (damping * dangling_sum + 1.0 - damping) * jump[v]
+            })
+            .collect();
+        let err: f64 = x.iter().zip(xlast.iter()).map(|(a, b)| (a - b).abs()).sum();
+        if err < nnodes as f64 * _tolerance {
+            return Ok(x);
+        }
+    }
+    Err(Error {
+        kind: ErrorKind::PowerIterationFailedConvergence,
+        message: "failed to converge to the specified tolerance within the specified number of iterations.".to_string(),
+    })
+}
+
+/// Returns the normalized probability of jumping to each node.
+fn get_jump_probabilities<T>(
+    node_indexes: &HashMap<&T, usize>,
+    personalization: Option<HashMap<T, f64>>,
+) -> Result<Vec<f64>, Error>
+where
+    T: Hash + Eq + Display,
+{
+    let nnodes = node_indexes.len();
+    let personalization = match personalization {
+        None => return Ok(vec![1.0 / nnodes as f64; nnodes]),
+        Some(p) => p,
+    };
+    let mut jump = vec![0.0; nnodes];
+    for (node_name, value) in personalization {
+        let index = match node_indexes.get(&node_name) {
+            None => {
+                return Err(Error {
+                    kind: ErrorKind::NodeNotFound,
+                    message: format!("Requested node '{}' was not found in the graph.", node_name),
+                })
+            }
+            Some(i) => *i,
+        };
+        if value.is_nan() || value < 0.0 {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!(
+                    "The personalization value for node '{}' was {} but it must not be negative.",
+                    node_name, value
+                ),
+            });
+        }
+        jump[index] = value;
+    }
+    let total: f64 = jump.iter().sum();
+    if total == 0.0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The personalization values must not all be zero.".to_string(),
+        });
+    }
+    Ok(jump.into_iter().map(|j| j / total).collect())
+}
@@ -0,0 +1,312 @@
+use crate::algorithms::centrality::pagerank;
+use crate::algorithms::community::louvain::get_rng;
+use crate::{Error, ErrorKind, Graph, ParallelOptions};
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// The number of Monte Carlo walks that share a random number generator.
+const WALKS_PER_BATCH: usize = 1000;
+
+/// How [personalized_pagerank](fn.personalized_pagerank.html) computes the probabilities.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PersonalizedPageRankMethod {
+    /**
+    Runs the power method until the probabilities converge, as in
+    [pagerank](../pagerank/fn.pagerank.html). `max_iter` defaults to `100` and `tolerance`
+    to `1.0e-6`.
+    */
+    PowerIteration {
+        max_iter: Option<u32>,
+        tolerance: Option<f64>,
+    },
+    /**
+    Estimates the probabilities from the share of visits each node gets in `num_walks`
+    random walks with restart. The error shrinks with the square root of `num_walks`, but
+    each walk only touches the part of the graph near the seeds, so this can be much faster
+    than the power method on very large graphs. The random number generator can be seeded.
+    */
+    MonteCarlo { num_walks: usize, seed: Option<u64> },
+}
+
+/**
+Computes personalized PageRank, also known as random walk with restart: the probability
+of finding a random walker at each node, when at every step the walker jumps back to one
+of the `seeds` with probability `restart_prob` and otherwise follows an outgoing edge.
+A walker at a node without any outgoing edges jumps back to the seeds. Nodes close to the
+seeds get high probabilities, which makes this a measure of proximity to the seeds.
+
+Undirected graphs are treated as directed graphs with edges in both directions.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to follow edges with a probability proportional to their weight
+* `restart_prob`: the probability of jumping back to the seeds at each step; commonly `0.15`
+* `seeds`: the nodes the walker restarts from, each chosen with the same probability; a
+  node that's repeated is chosen more often
+* `method`: the [PersonalizedPageRankMethod](enum.PersonalizedPageRankMethod.html) to use
+
+# Returns
+
+A `HashMap` of node names to probabilities; the probabilities sum to `1.0`. An `Error`
+with a `kind` of `ErrorKind::InvalidArgument` is returned if `restart_prob` isn't greater
+than `0.0` and at most `1.0`, if `seeds` is empty, if `num_walks` is `0`, or if `weighted`
+is `true` and an edge has a negative weight. One with a `kind` of `ErrorKind::NodeNotFound`
+is returned if a seed doesn't exist.
+
+# Examples
+
+```
+use graphrs::{algorithms::centrality::personalized_pagerank::{
+    personalized_pagerank, PersonalizedPageRankMethod
+}, generators};
+
+let graph = generators::social::karate_club_graph();
+let method = PersonalizedPageRankMethod::PowerIteration { max_iter: None, tolerance: None };
+let exact = personalized_pagerank(&graph, false, 0.15, &[33], method).unwrap();
+assert!(exact[&32] > exact[&0]);
+let method = PersonalizedPageRankMethod::MonteCarlo { num_walks: 100_000, seed: Some(1) };
+let estimate = personalized_pagerank(&graph, false, 0.15, &[33], method).unwrap();
+assert!((exact[&32] - estimate[&32]).abs() < 0.01);
+```
+
+# References
+
+1. G. Jeh and J. Widom. Scaling Personalized Web Search. Proceedings of the 12th
+   International Conference on World Wide Web, 271–279, 2003.
+2. K. Avrachenkov, N. Litvak, D. Nemirovsky and N. Osipova. Monte Carlo Methods in
+   PageRank Computation: When One Iteration is Sufficient. SIAM Journal on Numerical
+   Analysis, 45(2), 890–904, 2007.
+*/
+pub fn personalized_pagerank<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    restart_prob: f64,
+    seeds: &[T],
+    method: PersonalizedPageRankMethod,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let options = ParallelOptions::default();
+    personalized_pagerank_with_options(graph, weighted, restart_prob, seeds, method, &options)
+}
+
+/**
+Computes personalized PageRank, like [personalized_pagerank](fn.personalized_pagerank.html),
+with the threads and [ProgressHandle](../../../struct.ProgressHandle.html) given in
+`options`. Only the `MonteCarlo` method runs in parallel; the `PowerIteration` method
+always runs on the calling thread and doesn't report progress.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to follow edges with a probability proportional to their weight
+* `restart_prob`: the probability of jumping back to the seeds at each step; commonly `0.15`
+* `seeds`: the nodes the walker restarts from, each chosen with the same probability; a
+  node that's repeated is chosen more often
+* `method`: the [PersonalizedPageRankMethod](enum.PersonalizedPageRankMethod.html) to use
+* `options`: a [ParallelOptions](../../../struct.ParallelOptions.html); each batch of
+  1,000 Monte Carlo walks is a unit of work for its `progress`
+
+# Returns
+
+The same as [personalized_pagerank](fn.personalized_pagerank.html); the estimates are the
+same whichever threads are used. An `Error` with a `kind` of `ErrorKind::Cancelled` is
+returned if `options.progress` was cancelled, and one with a `kind` of
+`ErrorKind::InvalidArgument` if a thread pool couldn't be built for `options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::centrality::personalized_pagerank::{
+    personalized_pagerank_with_options, PersonalizedPageRankMethod
+}, generators, Parallelism, ParallelOptions};
+
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let method = PersonalizedPageRankMethod::MonteCarlo { num_walks: 10_000, seed: Some(1) };
+let estimate = personalized_pagerank_with_options(&graph, false, 0.15, &[33], method, &options);
+assert!(estimate.unwrap()[&32] > 0.0);
+```
+*/
+pub fn personalized_pagerank_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    restart_prob: f64,
+    seeds: &[T],
+    method: PersonalizedPageRankMethod,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if restart_prob.is_nan() || restart_prob <= 0.0 || restart_prob > 1.0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!(
+                "`restart_prob` was {} but it must be greater than 0.0 and at most 1.0.",
+                restart_prob
+            ),
+        });
+    }
+    if seeds.is_empty() {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "At least one seed node must be given.".to_string(),
+        });
+    }
+    for seed in seeds {
+        graph.ensure_node(seed)?;
+    }
+    if weighted {
+        graph.ensure_weighted()?;
+        if let Some(edge) = graph.get_all_edges().into_iter().find(|e| e.weight < 0.0) {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!(
+                    "The ({}, {}) edge has a negative weight; personalized PageRank requires \
+                    non-negative edge weights.",
+                    edge.u, edge.v
+                ),
+            });
+        }
+    }
+    match method {
+        PersonalizedPageRankMethod::PowerIteration {
+            max_iter,
+            tolerance,
+        } => {
+            let mut personalization = HashMap::new();
+            for seed in seeds {
+                *personalization.entry(seed.clone()).or_insert(0.0) += 1.0;
+            }
+            pagerank::pagerank(
+                graph,
+                weighted,
+                1.0 - restart_prob,
+                Some(personalization),
+                max_iter,
+                tolerance,
+            )
+        }
+        PersonalizedPageRankMethod::MonteCarlo { num_walks, seed } => monte_carlo(
+            graph,
+            weighted,
+            restart_prob,
+            seeds,
+            num_walks,
+            seed,
+            options,
+        ),
+    }
+}
+
+/// Estimates personalized PageRank from the visits of `num_walks` random walks with restart,
+/// with the threads of `options`.
+fn monte_carlo<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    restart_prob: f64,
+    seeds: &[T],
+    num_walks: usize,
+    seed: Option<u64>,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if num_walks == 0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "`num_walks` must be greater than 0.".to_string(),
+        });
+    }
+    let nodes = graph.get_all_node_names();
+    let node_indexes: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    // successors[u] holds (v, w) for every arc u -> v, where w is the running total weight
+    let mut successors: Vec<Vec<(usize, f64)>> = vec![vec![]; nodes.len()];
+    for edge in graph.get_all_edges() {
+        let weight = match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+        let u = node_indexes[&edge.u];
+        let v = node_indexes[&edge.v];
+        successors[u].push((v, weight));
+        if !graph.specs.directed && u != v {
+            successors[v].push((u, weight));
+        }
+    }
+    for arcs in successors.iter_mut() {
+        let mut total = 0.0;
+        for (_v, w) in arcs.iter_mut() {
+            total += *w;
+            *w = total;
+        }
+    }
+    let starts: Vec<usize> = seeds.iter().map(|s| node_indexes[s]).collect();
+
+    // seeding each batch's generator in turn keeps the estimate independent of the threads
+    let mut rng = get_rng(seed);
+    let batches: Vec<(usize, u64)> = (0..num_walks)
+        .step_by(WALKS_PER_BATCH)
+        .map(|first| (WALKS_PER_BATCH.min(num_walks - first), rng.next_u64()))
+        .collect();
+    let progress = &options.progress;
+    progress.start(Some(batches.len()))?;
+    let visits = options.map_reduce(
+        batches.len(),
+        |i| {
+            progress.check()?;
+            let (batch_size, batch_seed) = batches[i];
+            let mut visits = vec![0u64; nodes.len()];
+            let mut rng = StdRng::seed_from_u64(batch_seed);
+            for _walk in 0..batch_size {
+                let mut u = starts[rng.gen_range(0..starts.len())];
+                loop {
+                    visits[u] += 1;
+                    if rng.gen::<f64>() < restart_prob {
+                        break;
+                    }
+                    match choose_successor(&successors[u], &mut rng) {
+                        None => break,
+                        Some(v) => u = v,
+                    }
+                }
+            }
+            progress.advance();
+            Ok(visits)
+        },
+        || Ok(vec![0u64; nodes.len()]),
+        |a: Result<Vec<u64>, Error>, b| Ok(a?.into_iter().zip(b?).map(|(x, y)| x + y).collect()),
+    )??;
+    progress.check()?;
+    let total: u64 = visits.iter().sum();
+    Ok(nodes
+        .into_iter()
+        .cloned()
+        .zip(visits.into_iter().map(|v| v as f64 / total as f64))
+        .collect())
+}
+
+/// Chooses a successor with a probability proportional to its weight, given `arcs` with
+/// running total weights. Returns `None` if the total weight is zero.
+fn choose_successor(arcs: &[(usize, f64)], rng: &mut StdRng) -> Option<usize> {
+    let total = match arcs.last() {
+        Some((_v, total)) if *total > 0.0 => *total,
+        _ => return None,
+    };
+    let r = rng.gen::<f64>() * total;
+    let i = arcs.partition_point(|(_v, w)| *w <= r).min(arcs.len() - 1);
+    Some(arcs[i].0)
+}
@@ -0,0 +1,275 @@
+use crate::{Error, Graph};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+An iterator over the maximal cliques of a graph.
+Created by [find_cliques](./fn.find_cliques.html).
+*/
+pub struct CliquesIter<T> {
+    names: Vec<T>,
+    neighbors: Vec<Vec<u32>>,
+    order: Vec<u32>,
+    positions: Vec<usize>,
+    index: usize,
+    stack: Vec<CliqueSearch>,
+}
+
+impl<T> Iterator for CliquesIter<T>
+where
+    T: Clone,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let clique = match self.stack.last_mut() {
+                None => self.start_next_search()?,
+                Some(search) => match search.candidates.pop() {
+                    None => {
+                        self.stack.pop();
+                        None
+                    }
+                    Some(v) => {
+                        let v_neighbors = &self.neighbors[v as usize];
+                        let p = get_intersection(&search.p, v_neighbors);
+                        let x = get_intersection(&search.x, v_neighbors);
+                        // all the maximal cliques containing `v` have now been found
+                        let i = search.p.binary_search(&v).unwrap();
+                        search.p.remove(i);
+                        let i = search.x.binary_search(&v).unwrap_err();
+                        search.x.insert(i, v);
+                        let mut clique = search.clique.clone();
+                        clique.push(v);
+                        self.explore(clique, p, x)
+                    }
+                },
+            };
+            if let Some(clique) = clique {
+                return Some(clique.into_iter().map(|i| self.names[i as usize].clone()).collect());
+            }
+        }
+    }
+}
+
+impl<T> CliquesIter<T> {
+    /**
+    Starts the search for the cliques whose earliest node, in degeneracy order, is the
+    next node in that order. Returns `None` when there are no more nodes, otherwise
+    `Some` clique if the node is isolated.
+    */
+    fn start_next_search(&mut self) -> Option<Option<Vec<u32>>> {
+        let v = *self.order.get(self.index)?;
+        self.index += 1;
+        let (p, x): (Vec<u32>, Vec<u32>) = self.neighbors[v as usize]
+            .iter()
+            .partition(|u| self.positions[**u as usize] > self.positions[v as usize]);
+        Some(self.explore(vec![v], p, x))
+    }
+
+    /**
+    Returns `clique` if it is maximal, otherwise pushes the search for the cliques that
+    extend it onto the stack.
+    */
+    fn explore(&mut self, clique: Vec<u32>, p: Vec<u32>, x: Vec<u32>) -> Option<Vec<u32>> {
+        if p.is_empty() {
+            return match x.is_empty() {
+                true => Some(clique),
+                false => None,
+            };
+        }
+        // only the nodes not adjacent to the pivot need to be tried
+        let pivot = p
+            .iter()
+            .chain(x.iter())
+            .max_by_key(|u| get_intersection(&p, &self.neighbors[**u as usize]).len())
+            .unwrap();
+        let pivot_neighbors = &self.neighbors[*pivot as usize];
+        let candidates =
+            p.iter().filter(|u| pivot_neighbors.binary_search(u).is_err()).copied().collect();
+        self.stack.push(CliqueSearch {
+            clique,
+            p,
+            x,
+            candidates,
+        });
+        None
+    }
+}
+
+/**
+Finds the maximal cliques of an undirected graph: the sets of nodes that are all
+adjacent to each other and that are not contained in any larger such set.
+
+Uses the Bron–Kerbosch algorithm with pivoting, starting from each node in
+degeneracy order. The cliques are found as the iterator is advanced, so they don't all
+need to be held in memory at once. Self-loops are ignored.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+An iterator of cliques, each of which is a `Vec` of node names. An `Error` with a
+`kind` of `ErrorKind::WrongMethod` is returned if the graph is directed.
+
+# Examples
+
+```
+use graphrs::{algorithms::clique, generators};
+let graph = generators::social::karate_club_graph();
+let cliques = clique::find_cliques(&graph).unwrap();
+assert_eq!(cliques.filter(|c| c.len() == 5).count(), 2);
+```
+
+# References
+
+1. C. Bron and J. Kerbosch, Algorithm 457: Finding All Cliques of an Undirected Graph,
+   Communications of the ACM, 16(9), 575-577, 1973.
+2. E. Tomita, A. Tanaka and H. Takahashi, The worst-case time complexity for generating
+   all maximal cliques and computational experiments, Theoretical Computer Science,
+   363(1), 28-42, 2006.
+3. D. Eppstein, M. Löffler and D. Strash, Listing All Maximal Cliques in Sparse Graphs
+   in Near-optimal Time, ISAAC 2010.
+*/
+pub fn find_cliques<T, A>(graph: &Graph<T, A>) -> Result<CliquesIter<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let csr = graph.to_csr();
+    let neighbors: Vec<Vec<u32>> = (0..csr.number_of_nodes() as u32)
+        .map(|v| csr.get_successors_or_neighbors(v).iter().copied().filter(|u| *u != v).collect())
+        .collect();
+    let order = get_degeneracy_order(&neighbors);
+    let mut positions = vec![0; order.len()];
+    for (position, v) in order.iter().enumerate() {
+        positions[*v as usize] = position;
+    }
+    Ok(CliquesIter {
+        names: csr.get_node_names().to_vec(),
+        neighbors,
+        order,
+        positions,
+        index: 0,
+        stack: vec![],
+    })
+}
+
+/**
+Finds a largest clique of an undirected graph. When there are several the first one
+found by [find_cliques](./fn.find_cliques.html) is returned.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+The node names of the clique, which is empty if the graph has no nodes. An `Error`
+with a `kind` of `ErrorKind::WrongMethod` is returned if the graph is directed.
+
+# Examples
+
+```
+use graphrs::{algorithms::clique, generators};
+let graph = generators::social::karate_club_graph();
+let mut result = clique::max_clique(&graph).unwrap();
+result.sort();
+assert_eq!(result[..4], [0, 1, 2, 3]);
+```
+*/
+pub fn max_clique<T, A>(graph: &Graph<T, A>) -> Result<Vec<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut largest = vec![];
+    for clique in find_cliques(graph)? {
+        if clique.len() > largest.len() {
+            largest = clique;
+        }
+    }
+    Ok(largest)
+}
+
+/**
+Returns the clique number of an undirected graph: the number of nodes in its largest
+clique.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned if the graph is directed.
+
+# Examples
+
+```
+use graphrs::{algorithms::clique, generators};
+let graph = generators::social::karate_club_graph();
+assert_eq!(clique::clique_number(&graph).unwrap(), 5);
+```
+*/
+pub fn clique_number<T, A>(graph: &Graph<T, A>) -> Result<usize, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    Ok(find_cliques(graph)?.map(|clique| clique.len()).max().unwrap_or(0))
+}
+
+/// The state of the search for the maximal cliques that extend `clique`.
+struct CliqueSearch {
+    clique: Vec<u32>,
+    /// nodes that can extend `clique`, in ascending order
+    p: Vec<u32>,
+    /// nodes that could extend `clique` but have already been explored, in ascending order
+    x: Vec<u32>,
+    /// the nodes of `p` still to be explored
+    candidates: Vec<u32>,
+}
+
+/**
+Returns the nodes in degeneracy order: repeatedly removing the node with the fewest
+remaining neighbors, choosing the lowest index when there is a tie.
+*/
+pub(crate) fn get_degeneracy_order(neighbors: &[Vec<u32>]) -> Vec<u32> {
+    let mut degrees: Vec<usize> = neighbors.iter().map(|n| n.len()).collect();
+    let mut remaining: BTreeSet<(usize, u32)> =
+        degrees.iter().enumerate().map(|(v, d)| (*d, v as u32)).collect();
+    let mut order = Vec::with_capacity(neighbors.len());
+    while let Some((_, v)) = remaining.pop_first() {
+        order.push(v);
+        for u in neighbors[v as usize].iter() {
+            if remaining.remove(&(degrees[*u as usize], *u)) {
+                degrees[*u as usize] -= 1;
+                remaining.insert((degrees[*u as usize], *u));
+            }
+        }
+    }
+    order
+}
+
+/// Returns the values that are in both of the sorted slices, in ascending order.
+fn get_intersection(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (mut i, mut j) = (0, 0);
+    let mut intersection = vec![];
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                intersection.push(a[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    intersection
+}
@@ -0,0 +1,139 @@
+use crate::{CsrGraph, Error, Graph, ParallelOptions};
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+pub struct TriangleCount<T> {
+    pub node_name: T,
+    pub degree: usize,
+    pub triangles: usize,
+}
+
+/**
+Returns a `Vec<TriangleCount>` for a given undirected graph and list of nodes.
+Unlike `get_triangles_and_degrees` this function does not count triangles twice.
+
+Each edge is oriented from the endpoint with the lower (degree, index) rank to the
+one with the higher rank, so every triangle is found exactly once, from its
+lowest-ranked node, by intersecting two short sorted lists.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `node_names`: Node names that optionally define a subset of the graph to work with
+* `options`: the threads to use and the `ProgressHandle` to report each node to
+*/
+pub fn get_triangle_counts<T, A>(
+    graph: &Graph<T, A>,
+    node_names: Option<&[T]>,
+    options: &ParallelOptions,
+) -> Result<Vec<TriangleCount<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let csr = graph.to_csr();
+    let nodes: Vec<u32> = match node_names {
+        Some(names) if !names.is_empty() => csr.get_node_indexes(names)?,
+        _ => (0..csr.number_of_nodes() as u32).collect(),
+    };
+    let degrees: Vec<usize> =
+        (0..csr.number_of_nodes() as u32).map(|v| get_neighbors_without(&csr, v).count()).collect();
+    let ranks_higher = |u: u32, v: u32| (degrees[u as usize], u) > (degrees[v as usize], v);
+    let forward: Vec<Vec<u32>> = options.map_collect(csr.number_of_nodes(), |v| {
+        let v = v as u32;
+        get_neighbors_without(&csr, v).filter(|u| ranks_higher(*u, v)).collect()
+    })?;
+    let triangles: Vec<AtomicUsize> =
+        (0..csr.number_of_nodes()).map(|_| AtomicUsize::new(0)).collect();
+    options.map_collect_with_progress(csr.number_of_nodes(), |v| {
+        for u in forward[v].iter() {
+            let found = count_common(&forward[v], &forward[*u as usize], |w| {
+                triangles[w as usize].fetch_add(1, AtomicOrdering::Relaxed);
+            });
+            if found > 0 {
+                triangles[v].fetch_add(found, AtomicOrdering::Relaxed);
+                triangles[*u as usize].fetch_add(found, AtomicOrdering::Relaxed);
+            }
+        }
+    })?;
+    Ok(nodes
+        .into_iter()
+        .map(|v| TriangleCount {
+            node_name: csr.get_node_name(v).unwrap().clone(),
+            degree: degrees[v as usize],
+            triangles: triangles[v as usize].load(AtomicOrdering::Relaxed),
+        })
+        .collect())
+}
+
+/// Returns the neighbors of `v`, excluding `v` itself.
+fn get_neighbors_without<T>(csr: &CsrGraph<T>, v: u32) -> impl Iterator<Item = u32> + '_
+where
+    T: Hash + Eq + Clone + Display,
+{
+    csr.get_successors_or_neighbors(v).iter().copied().filter(move |u| *u != v)
+}
+
+/// Calls `on_common` for each value in both of the sorted slices and returns how many there were.
+fn count_common<F>(a: &[u32], b: &[u32], mut on_common: F) -> usize
+where
+    F: FnMut(u32),
+{
+    let (mut i, mut j, mut count) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                on_common(a[i]);
+                count += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{Edge, Graph, GraphSpecs, Node};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_get_triangle_counts_1() {
+        let edges = vec![
+            Edge::with_weight("n0", "n1", 1.1),
+            Edge::with_weight("n0", "n2", 1.3),
+            Edge::with_weight("n0", "n3", 1.4),
+            Edge::with_weight("n3", "n2", 1.5),
+            Edge::new("n3", "n3"),
+        ];
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected_create_missing()
+        };
+        let mut graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        graph.add_node(Node::from_name("n4"));
+        let options = ParallelOptions::default();
+        let result = get_triangle_counts(&graph, None, &options).unwrap();
+        assert_eq!(result.len(), 5);
+        let hm: HashMap<&str, (usize, usize)> = result
+            .into_iter()
+            .map(|item| (item.node_name, (item.degree, item.triangles)))
+            .collect();
+        assert_eq!(hm.get("n0"), Some(&(3, 1)));
+        assert_eq!(hm.get("n1"), Some(&(1, 0)));
+        assert_eq!(hm.get("n2"), Some(&(2, 1)));
+        assert_eq!(hm.get("n3"), Some(&(2, 1)));
+        assert_eq!(hm.get("n4"), Some(&(0, 0)));
+        let result = get_triangle_counts(&graph, Some(&["n1", "n2"]), &options).unwrap();
+        assert_eq!(result.len(), 2);
+        assert!(get_triangle_counts(&graph, Some(&["n5"]), &options).is_err());
+    }
+}
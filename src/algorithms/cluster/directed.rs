@@ -1,5 +1,5 @@
 use super::utility::get_adjacent_nodes_without;
-use crate::Graph;
+use crate::{Error, Graph, ParallelOptions};
 use std::fmt::Display;
 use std::hash::Hash;
 
@@ -18,11 +18,13 @@ Unlike `get_triangles_and_degrees` this function does not count triangles twice.
 
 * `graph`: a [Graph](../../struct.Graph.html) instance
 * `node_names`: Node names that optionally define a subset of the graph to work with
+* `options`: the threads to use and the `ProgressHandle` to report each node to
 */
 pub fn get_directed_triangles_and_degrees<T, A>(
     graph: &Graph<T, A>,
     node_names: Option<&[T]>,
-) -> Vec<DirectedTrianglesAndDegree<T>>
+    options: &ParallelOptions,
+) -> Result<Vec<DirectedTrianglesAndDegree<T>>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
@@ -31,35 +33,34 @@ where
         None => graph.get_all_nodes().into_iter().map(|n| n.name.clone()).collect(),
         Some(names) => names.to_vec(),
     };
-    ns.into_iter()
-        .map(|i| {
-            let ipreds = get_adjacent_nodes_without(graph, &i, true);
-            let isuccs = get_adjacent_nodes_without(graph, &i, false);
-            let directed_triangles = &ipreds
-                .iter()
-                .chain(&isuccs)
-                .map(|j| {
-                    let jpreds = get_adjacent_nodes_without(graph, j, true);
-                    let jsuccs = get_adjacent_nodes_without(graph, j, false);
-                    ipreds
-                        .clone()
-                        .intersection(&jpreds)
-                        .chain(ipreds.intersection(&jsuccs))
-                        .chain(isuccs.intersection(&jpreds))
-                        .chain(isuccs.intersection(&jsuccs))
-                        .count()
-                })
-                .sum::<usize>();
-            let total_degree = ipreds.len() + isuccs.len();
-            let reciprocal_degree = ipreds.intersection(&isuccs).count();
-            DirectedTrianglesAndDegree {
-                node_name: i,
-                total_degree,
-                reciprocal_degree,
-                directed_triangles: *directed_triangles,
-            }
-        })
-        .collect()
+    options.map_collect_with_progress(ns.len(), |index| {
+        let i = ns[index].clone();
+        let ipreds = get_adjacent_nodes_without(graph, &i, true);
+        let isuccs = get_adjacent_nodes_without(graph, &i, false);
+        let directed_triangles = &ipreds
+            .iter()
+            .chain(&isuccs)
+            .map(|j| {
+                let jpreds = get_adjacent_nodes_without(graph, j, true);
+                let jsuccs = get_adjacent_nodes_without(graph, j, false);
+                ipreds
+                    .clone()
+                    .intersection(&jpreds)
+                    .chain(ipreds.intersection(&jsuccs))
+                    .chain(isuccs.intersection(&jpreds))
+                    .chain(isuccs.intersection(&jsuccs))
+                    .count()
+            })
+            .sum::<usize>();
+        let total_degree = ipreds.len() + isuccs.len();
+        let reciprocal_degree = ipreds.intersection(&isuccs).count();
+        DirectedTrianglesAndDegree {
+            node_name: i,
+            total_degree,
+            reciprocal_degree,
+            directed_triangles: *directed_triangles,
+        }
+    })
 }
 
 #[cfg(test)]
@@ -80,7 +81,8 @@ mod tests {
         let graph: Graph<&str, ()> =
             Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
                 .unwrap();
-        let result = get_directed_triangles_and_degrees(&graph, None);
+        let result =
+            get_directed_triangles_and_degrees(&graph, None, &ParallelOptions::default()).unwrap();
         assert_eq!(result.len(), 4);
         let hm: HashMap<&str, DirectedTrianglesAndDegree<&str>> =
             result.into_iter().map(|item| (item.node_name, item)).collect();
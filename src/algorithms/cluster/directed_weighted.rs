@@ -1,5 +1,5 @@
 use super::utility::{get_adjacent_nodes_without, get_normalized_edge_weight};
-use crate::Graph;
+use crate::{Error, Graph, ParallelOptions};
 use std::collections::HashSet;
 use std::fmt::Display;
 use std::hash::Hash;
@@ -14,7 +14,8 @@ pub struct DirectedWeightedTrianglesAndDegree<T> {
 pub fn get_directed_weighted_triangles_and_degrees<T, A>(
     graph: &Graph<T, A>,
     node_names: Option<&[T]>,
-) -> Vec<DirectedWeightedTrianglesAndDegree<T>>
+    options: &ParallelOptions,
+) -> Result<Vec<DirectedWeightedTrianglesAndDegree<T>>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
@@ -28,24 +29,23 @@ where
         None => graph.get_all_nodes().into_iter().map(|n| n.name.clone()).collect(),
         Some(names) => names.to_vec(),
     };
-    ns.into_iter()
-        .map(|i| {
-            let ipreds = get_adjacent_nodes_without(graph, &i, true);
-            let isuccs = get_adjacent_nodes_without(graph, &i, false);
-            let directed_triangles_ipreds =
-                get_all_directed_triangles(&i, &ipreds, &isuccs, true, graph, &max_weight);
-            let directed_triangles_isuccs =
-                get_all_directed_triangles(&i, &ipreds, &isuccs, false, graph, &max_weight);
-            let total_degree = ipreds.len() + isuccs.len();
-            let reciprocal_degree = ipreds.intersection(&isuccs).count();
-            DirectedWeightedTrianglesAndDegree {
-                node_name: i,
-                total_degree,
-                reciprocal_degree,
-                directed_triangles: directed_triangles_ipreds + directed_triangles_isuccs,
-            }
-        })
-        .collect()
+    options.map_collect_with_progress(ns.len(), |index| {
+        let i = ns[index].clone();
+        let ipreds = get_adjacent_nodes_without(graph, &i, true);
+        let isuccs = get_adjacent_nodes_without(graph, &i, false);
+        let directed_triangles_ipreds =
+            get_all_directed_triangles(&i, &ipreds, &isuccs, true, graph, &max_weight);
+        let directed_triangles_isuccs =
+            get_all_directed_triangles(&i, &ipreds, &isuccs, false, graph, &max_weight);
+        let total_degree = ipreds.len() + isuccs.len();
+        let reciprocal_degree = ipreds.intersection(&isuccs).count();
+        DirectedWeightedTrianglesAndDegree {
+            node_name: i,
+            total_degree,
+            reciprocal_degree,
+            directed_triangles: directed_triangles_ipreds + directed_triangles_isuccs,
+        }
+    })
 }
 
 #[inline]
@@ -1,10 +1,12 @@
+mod degree_ordered;
 mod directed;
 mod directed_weighted;
 mod square;
 mod undirected;
 mod undirected_weighted;
 mod utility;
-use crate::{Error, Graph};
+use crate::{Error, Graph, ParallelOptions};
+use degree_ordered::get_triangle_counts;
 use directed::get_directed_triangles_and_degrees;
 use directed_weighted::get_directed_weighted_triangles_and_degrees;
 use std::collections::HashMap;
@@ -39,7 +41,59 @@ where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
-    let c = clustering(graph, weighted, node_names)?;
+    average_clustering_with_options(
+        graph,
+        weighted,
+        node_names,
+        count_zeros,
+        &ParallelOptions::default(),
+    )
+}
+
+/**
+Returns the average clustering coefficient for nodes in a graph, like
+[average_clustering](./fn.average_clustering.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `node_names`: node names that optionally define a subset of the graph to work with
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each node whose
+  clustering coefficient has been found is a unit of work for its `progress`
+
+# Returns
+
+The same as [average_clustering](./fn.average_clustering.html). An `Error` with a `kind`
+of `ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::cluster, generators, Parallelism, ParallelOptions};
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let result = cluster::average_clustering_with_options(&graph, false, None, true, &options);
+assert!((result.unwrap() - 0.5706).abs() < 1.0e-4);
+```
+*/
+pub fn average_clustering_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    node_names: Option<&[T]>,
+    count_zeros: bool,
+    options: &ParallelOptions,
+) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let c = clustering_with_options(graph, weighted, node_names, options)?;
     let vs = c.values().into_iter().filter(|v| count_zeros || v.abs() > 0.0).collect::<Vec<&f64>>();
     Ok(vs.iter().cloned().sum::<f64>() / vs.len() as f64)
 }
@@ -65,6 +119,51 @@ pub fn clustering<T, A>(
     weighted: bool,
     node_names: Option<&[T]>,
 ) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    clustering_with_options(graph, weighted, node_names, &ParallelOptions::default())
+}
+
+/**
+Returns the clustering coefficient for nodes in a graph, like
+[clustering](./fn.clustering.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `node_names`: node names that optionally define a subset of the graph to work with
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each node whose
+  clustering coefficient has been found is a unit of work for its `progress`
+
+# Returns
+
+The same as [clustering](./fn.clustering.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::cluster, generators, Parallelism, ParallelOptions};
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::MaxThreads(2),
+    ..ParallelOptions::default()
+};
+let result = cluster::clustering_with_options(&graph, false, None, &options);
+assert_eq!(result.unwrap().len(), 34);
+```
+*/
+pub fn clustering_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    node_names: Option<&[T]>,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, f64>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
@@ -74,16 +173,16 @@ where
         true => match weighted {
             true => {
                 graph.ensure_weighted()?;
-                Ok(get_clustering_directed_weighted(graph, node_names))
+                get_clustering_directed_weighted(graph, node_names, options)
             }
-            false => Ok(get_clustering_directed(graph, node_names)),
+            false => get_clustering_directed(graph, node_names, options),
         },
         false => match weighted {
             true => {
                 graph.ensure_weighted()?;
-                Ok(get_clustering_undirected_weighted(graph, node_names))
+                get_clustering_undirected_weighted(graph, node_names, options)
             }
-            false => Ok(get_clustering_undirected(graph, node_names)),
+            false => get_clustering_undirected(graph, node_names, options),
         },
     }
 }
@@ -111,12 +210,56 @@ pub fn generalized_degree<T, A>(
     graph: &Graph<T, A>,
     node_names: Option<&[T]>,
 ) -> Result<HashMap<T, HashMap<usize, usize>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    generalized_degree_with_options(graph, node_names, &ParallelOptions::default())
+}
+
+/**
+Returns the generalized degree for nodes, like
+[generalized_degree](./fn.generalized_degree.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `node_names`: node names that optionally define a subset of the graph to work with
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each node whose
+  generalized degree has been found is a unit of work for its `progress`
+
+# Returns
+
+The same as [generalized_degree](./fn.generalized_degree.html). An `Error` with a `kind`
+of `ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::cluster, generators, Parallelism, ParallelOptions};
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let result = cluster::generalized_degree_with_options(&graph, None, &options);
+assert_eq!(result.unwrap().len(), 34);
+```
+*/
+pub fn generalized_degree_with_options<T, A>(
+    graph: &Graph<T, A>,
+    node_names: Option<&[T]>,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, HashMap<usize, usize>>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
     graph.ensure_undirected()?;
-    let tads = get_triangles_and_degrees(graph, node_names);
+    let tads = get_triangles_and_degrees(graph, node_names, options)?;
     Ok(tads.into_iter().map(|item| (item.node_name, item.generalized_degree)).collect())
 }
 
@@ -139,6 +282,47 @@ assert_eq!(result, 0.2556818181818182);
 ```
 */
 pub fn transitivity<T, A>(graph: &Graph<T, A>) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    transitivity_with_options(graph, &ParallelOptions::default())
+}
+
+/**
+Returns a graph's transitivity, like [transitivity](./fn.transitivity.html), with the
+threads and [ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each node whose
+  triangles have been counted is a unit of work for its `progress`
+
+# Returns
+
+The same as [transitivity](./fn.transitivity.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::cluster, generators, Parallelism, ParallelOptions};
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let result = cluster::transitivity_with_options(&graph, &options).unwrap();
+assert_eq!(result, 0.2556818181818182);
+```
+*/
+pub fn transitivity_with_options<T, A>(
+    graph: &Graph<T, A>,
+    options: &ParallelOptions,
+) -> Result<f64, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
@@ -147,9 +331,10 @@ where
     if graph.get_all_nodes().is_empty() {
         return Ok(0.0);
     }
-    let tads = get_triangles_and_degrees(graph, None);
-    let triangles = tads.iter().map(|item| (item.number_of_triangles)).sum::<usize>() as f64;
-    let contri = tads.iter().map(|item| item.degree * (item.degree - 1)).sum::<usize>() as f64;
+    let counts = get_triangle_counts(graph, None, options)?;
+    let triangles = counts.iter().map(|item| 2 * item.triangles).sum::<usize>() as f64;
+    let contri =
+        counts.iter().map(|item| item.degree * item.degree.saturating_sub(1)).sum::<usize>() as f64;
     match triangles == 0.0 {
         true => Ok(0.0),
         false => Ok(triangles / contri),
@@ -177,26 +362,73 @@ pub fn triangles<T, A>(
     graph: &Graph<T, A>,
     node_names: Option<&[T]>,
 ) -> Result<HashMap<T, usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    triangles_with_options(graph, node_names, &ParallelOptions::default())
+}
+
+/**
+Finds the number of triangles in a graph, like [triangles](./fn.triangles.html), with the
+threads and [ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `node_names`: node names that optionally define a subset of the graph to work with
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each node whose
+  triangles have been counted is a unit of work for its `progress`
+
+# Returns
+
+The same as [triangles](./fn.triangles.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::cluster, generators, Parallelism, ParallelOptions};
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let result = cluster::triangles_with_options(&graph, Some(&[0]), &options);
+assert_eq!(result.unwrap().get(&0), Some(&18));
+```
+*/
+pub fn triangles_with_options<T, A>(
+    graph: &Graph<T, A>,
+    node_names: Option<&[T]>,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, usize>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
     graph.ensure_undirected()?;
-    let tads = get_triangles_and_degrees(graph, node_names);
-    Ok(tads.into_iter().map(|item| (item.node_name, item.number_of_triangles / 2)).collect())
+    let counts = get_triangle_counts(graph, node_names, options)?;
+    Ok(counts.into_iter().map(|item| (item.node_name, item.triangles)).collect())
 }
 
 ///////////////////////
 /// PRIVATE METHODS ///
 ///////////////////////
 
-fn get_clustering_directed<T, A>(graph: &Graph<T, A>, node_names: Option<&[T]>) -> HashMap<T, f64>
+fn get_clustering_directed<T, A>(
+    graph: &Graph<T, A>,
+    node_names: Option<&[T]>,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, f64>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
-    let clusterc = get_directed_triangles_and_degrees(graph, node_names);
-    clusterc
+    let clusterc = get_directed_triangles_and_degrees(graph, node_names, options)?;
+    Ok(clusterc
         .into_iter()
         .map(|o| {
             (
@@ -212,19 +444,20 @@ where
                 },
             )
         })
-        .collect::<HashMap<T, f64>>()
+        .collect::<HashMap<T, f64>>())
 }
 
 fn get_clustering_directed_weighted<T, A>(
     graph: &Graph<T, A>,
     node_names: Option<&[T]>,
-) -> HashMap<T, f64>
+    options: &ParallelOptions,
+) -> Result<HashMap<T, f64>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
-    let clusterc = get_directed_weighted_triangles_and_degrees(graph, node_names);
-    clusterc
+    let clusterc = get_directed_weighted_triangles_and_degrees(graph, node_names, options)?;
+    Ok(clusterc
         .into_iter()
         .map(|o| {
             (
@@ -240,41 +473,44 @@ where
                 },
             )
         })
-        .collect::<HashMap<T, f64>>()
+        .collect::<HashMap<T, f64>>())
 }
 
-fn get_clustering_undirected<T, A>(graph: &Graph<T, A>, node_names: Option<&[T]>) -> HashMap<T, f64>
+fn get_clustering_undirected<T, A>(
+    graph: &Graph<T, A>,
+    node_names: Option<&[T]>,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, f64>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
-    let clusterc = get_triangles_and_degrees(graph, node_names);
-    clusterc
+    let clusterc = get_triangle_counts(graph, node_names, options)?;
+    Ok(clusterc
         .into_iter()
         .map(|o| {
             (
                 o.node_name,
-                match o.number_of_triangles == 0 {
+                match o.triangles == 0 {
                     true => 0.0,
-                    false => {
-                        o.number_of_triangles as f64 / (o.degree as f64 * (o.degree as f64 - 1.0))
-                    }
+                    false => 2.0 * o.triangles as f64 / (o.degree as f64 * (o.degree as f64 - 1.0)),
                 },
             )
         })
-        .collect::<HashMap<T, f64>>()
+        .collect::<HashMap<T, f64>>())
 }
 
 fn get_clustering_undirected_weighted<T, A>(
     graph: &Graph<T, A>,
     node_names: Option<&[T]>,
-) -> HashMap<T, f64>
+    options: &ParallelOptions,
+) -> Result<HashMap<T, f64>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
-    let clusterc = get_weighted_triangles_and_degrees(graph, node_names);
-    clusterc
+    let clusterc = get_weighted_triangles_and_degrees(graph, node_names, options)?;
+    Ok(clusterc
         .into_iter()
         .map(|o| {
             (
@@ -287,5 +523,5 @@ where
                 },
             )
         })
-        .collect::<HashMap<T, f64>>()
+        .collect::<HashMap<T, f64>>())
 }
@@ -1,7 +1,7 @@
 use super::utility::get_neighbors_of_nodes;
 use crate::{
     ext::{hashset::HashSetExt, iterator::IteratorExt},
-    Graph,
+    Error, Graph, ParallelOptions,
 };
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
@@ -10,40 +10,41 @@ use std::hash::Hash;
 
 pub struct TrianglesAndDegrees<T> {
     pub node_name: T,
-    pub degree: usize,
-    pub number_of_triangles: usize,
     pub generalized_degree: HashMap<usize, usize>,
 }
 
 /**
 Returns a `Vec<TrianglesAndDegrees>` for a given graph and list of nodes.
-This double counts triangles so you may want to divide by 2.
+The generalized degree of a node gives the number of its edges that take part
+in each number of triangles.
 
 # Arguments
 
 * `graph`: a [Graph](../../struct.Graph.html) instance
 * `node_names`: Node names that optionally define a subset of the graph to work with
+* `options`: the threads to use and the `ProgressHandle` to report each node to
 */
 pub fn get_triangles_and_degrees<T, A>(
     graph: &Graph<T, A>,
     node_names: Option<&[T]>,
-) -> Vec<TrianglesAndDegrees<T>>
+    options: &ParallelOptions,
+) -> Result<Vec<TrianglesAndDegrees<T>>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
     let neighbors_map = get_neighbors_of_nodes(node_names, graph);
-    neighbors_map
-        .clone()
-        .into_iter()
-        .map(|(v, v_nbrs)| get_triangles_and_degrees_for_node(v, v_nbrs, &neighbors_map))
-        .collect()
+    let nodes: Vec<(&T, &HashSet<T>)> = neighbors_map.iter().collect();
+    options.map_collect_with_progress(nodes.len(), |index| {
+        let (v, v_nbrs) = nodes[index];
+        get_triangles_and_degrees_for_node(v.clone(), v_nbrs, &neighbors_map)
+    })
 }
 
 /// Returns a `TrianglesAndDegrees` struct for a given node `v`.
 fn get_triangles_and_degrees_for_node<T>(
     n: T,
-    n_nbrs: HashSet<T>,
+    n_nbrs: &HashSet<T>,
     neighbors_map: &HashMap<T, HashSet<T>>,
 ) -> TrianglesAndDegrees<T>
 where
@@ -59,11 +60,8 @@ where
         .sorted()
         .group_by_count()
         .collect();
-    let ntriangles: usize = generalized_degree.iter().map(|(k, val)| k * val).sum();
     TrianglesAndDegrees {
         node_name: n,
-        degree: nbrs.len(),
-        number_of_triangles: ntriangles,
         generalized_degree,
     }
 }
@@ -86,28 +84,20 @@ mod tests {
         let graph: Graph<&str, ()> =
             Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
                 .unwrap();
-        let result = get_triangles_and_degrees(&graph, None);
+        let result = get_triangles_and_degrees(&graph, None, &ParallelOptions::default()).unwrap();
         assert_eq!(result.len(), 4);
         let hm: HashMap<&str, TrianglesAndDegrees<&str>> =
             result.into_iter().map(|item| (item.node_name, item)).collect();
         let mut tad = hm.get("n0").unwrap();
-        assert_eq!(tad.degree, 3);
-        assert_eq!(tad.number_of_triangles, 2);
         assert_eq!(
             tad.generalized_degree,
             vec![(1, 2), (0, 1)].into_iter().collect()
         );
         tad = hm.get("n1").unwrap();
-        assert_eq!(tad.degree, 1);
-        assert_eq!(tad.number_of_triangles, 0);
         assert_eq!(tad.generalized_degree, vec![(0, 1)].into_iter().collect());
         tad = hm.get("n2").unwrap();
-        assert_eq!(tad.degree, 2);
-        assert_eq!(tad.number_of_triangles, 2);
         assert_eq!(tad.generalized_degree, vec![(1, 2)].into_iter().collect());
         tad = hm.get("n3").unwrap();
-        assert_eq!(tad.degree, 2);
-        assert_eq!(tad.number_of_triangles, 2);
         assert_eq!(tad.generalized_degree, vec![(1, 2)].into_iter().collect());
     }
 }
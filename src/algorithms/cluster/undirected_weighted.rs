@@ -1,5 +1,5 @@
 use super::utility::{get_neighbors_of_nodes, get_normalized_edge_weight};
-use crate::{ext::hashset::HashSetExt, Graph};
+use crate::{ext::hashset::HashSetExt, Error, Graph, ParallelOptions};
 use std::collections::HashSet;
 use std::fmt::Display;
 use std::hash::Hash;
@@ -18,11 +18,13 @@ Unlike `get_triangles_and_degrees` this function does not count triangles twice.
 
 * `graph`: a [Graph](../../struct.Graph.html) instance
 * `node_names`: Node names that optionally define a subset of the graph to work with
+* `options`: the threads to use and the `ProgressHandle` to report each node to
 */
 pub fn get_weighted_triangles_and_degrees<T, A>(
     graph: &Graph<T, A>,
     node_names: Option<&[T]>,
-) -> Vec<DegreesAndWeightedTriangles<T>>
+    options: &ParallelOptions,
+) -> Result<Vec<DegreesAndWeightedTriangles<T>>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
@@ -32,18 +34,17 @@ where
         true => 1.0,
         false => edges.iter().map(|e| e.weight).reduce(f64::max).unwrap(),
     };
-    let nodes_nbrs = get_neighbors_of_nodes(node_names, graph);
-    nodes_nbrs
-        .into_iter()
-        .map(|(n, n_nbrs)| {
-            get_weighted_triangles_and_degrees_for_node(n, n_nbrs, graph, max_weight)
-        })
-        .collect()
+    let nodes_nbrs: Vec<(T, HashSet<T>)> =
+        get_neighbors_of_nodes(node_names, graph).into_iter().collect();
+    options.map_collect_with_progress(nodes_nbrs.len(), |index| {
+        let (n, n_nbrs) = &nodes_nbrs[index];
+        get_weighted_triangles_and_degrees_for_node(n.clone(), n_nbrs, graph, max_weight)
+    })
 }
 
 fn get_weighted_triangles_and_degrees_for_node<T, A>(
     n: T,
-    n_nbrs: HashSet<T>,
+    n_nbrs: &HashSet<T>,
     graph: &Graph<T, A>,
     max_weight: f64,
 ) -> DegreesAndWeightedTriangles<T>
@@ -100,7 +101,8 @@ mod tests {
         let graph: Graph<&str, ()> =
             Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
                 .unwrap();
-        let result = get_weighted_triangles_and_degrees(&graph, None);
+        let result =
+            get_weighted_triangles_and_degrees(&graph, None, &ParallelOptions::default()).unwrap();
         assert_eq!(result.len(), 4);
         let hm: HashMap<&str, DegreesAndWeightedTriangles<&str>> =
             result.into_iter().map(|item| (item.node_name, item)).collect();
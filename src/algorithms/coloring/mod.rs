@@ -0,0 +1,135 @@
+use crate::algorithms::clique::get_degeneracy_order;
+use crate::algorithms::community::louvain::get_rng;
+use crate::{Error, Graph};
+use rand::seq::SliceRandom;
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// The order in which [greedy_color](fn.greedy_color.html) colors the nodes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColoringStrategy {
+    /// Colors the nodes in descending order of degree.
+    LargestFirst,
+    /// Repeatedly removes the node with the smallest degree, then colors the nodes in the
+    /// reverse of the order they were removed.
+    SmallestLast,
+    /// Colors the nodes in a random order. The random number generator can be seeded.
+    RandomSequential(Option<u64>),
+    /// Repeatedly colors the node whose neighbors have the most distinct colors, breaking
+    /// ties by degree (the DSATUR algorithm).
+    Dsatur,
+}
+
+/**
+Colors the nodes of an undirected graph so that no two adjacent nodes have the same
+color, by giving each node in turn the lowest color not used by its neighbors.
+
+The number of colors used depends on the order the nodes are colored in, which is set
+by `strategy`; no strategy is guaranteed to use the fewest colors possible. Ties are
+broken by node name, so apart from `RandomSequential` without a seed the result is
+deterministic. Self-loops are ignored.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `strategy`: the [ColoringStrategy](enum.ColoringStrategy.html) that determines the
+  order the nodes are colored in
+
+# Returns
+
+A `HashMap` of node names to colors, numbered from 0. An `Error` with a `kind` of
+`ErrorKind::WrongMethod` is returned if the graph is directed.
+
+# Examples
+
+```
+use graphrs::{algorithms::coloring::{self, ColoringStrategy}, generators};
+let graph = generators::classic::cycle_graph(6, false);
+let colors = coloring::greedy_color(&graph, ColoringStrategy::Dsatur).unwrap();
+assert_eq!(colors.values().max(), Some(&1));
+assert_ne!(colors.get(&0), colors.get(&1));
+```
+
+# References
+
+1. D. Brélaz, New methods to color the vertices of a graph, Communications of the ACM,
+   22(4), 251-256, 1979.
+2. D. W. Matula and L. L. Beck, Smallest-last ordering and clustering and graph coloring
+   algorithms, Journal of the ACM, 30(3), 417-427, 1983.
+*/
+pub fn greedy_color<T, A>(
+    graph: &Graph<T, A>,
+    strategy: ColoringStrategy,
+) -> Result<HashMap<T, usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let csr = graph.to_csr();
+    let neighbors: Vec<Vec<u32>> = (0..csr.number_of_nodes() as u32)
+        .map(|v| csr.get_successors_or_neighbors(v).iter().copied().filter(|u| *u != v).collect())
+        .collect();
+    let colors = match strategy {
+        ColoringStrategy::LargestFirst => {
+            let mut order: Vec<u32> = (0..neighbors.len() as u32).collect();
+            order.sort_by_key(|v| Reverse(neighbors[*v as usize].len()));
+            color_in_order(&neighbors, order)
+        }
+        ColoringStrategy::SmallestLast => {
+            let mut order = get_degeneracy_order(&neighbors);
+            order.reverse();
+            color_in_order(&neighbors, order)
+        }
+        ColoringStrategy::RandomSequential(seed) => {
+            let mut order: Vec<u32> = (0..neighbors.len() as u32).collect();
+            order.shuffle(&mut get_rng(seed));
+            color_in_order(&neighbors, order)
+        }
+        ColoringStrategy::Dsatur => color_dsatur(&neighbors),
+    };
+    Ok(csr.get_node_names().iter().cloned().zip(colors).collect())
+}
+
+/// Gives each node in `order` the lowest color not used by its neighbors.
+fn color_in_order(neighbors: &[Vec<u32>], order: Vec<u32>) -> Vec<usize> {
+    let mut colors: Vec<Option<usize>> = vec![None; neighbors.len()];
+    for v in order {
+        colors[v as usize] = Some(get_lowest_free_color(&neighbors[v as usize], &colors));
+    }
+    colors.into_iter().map(|c| c.unwrap()).collect()
+}
+
+/**
+Colors the nodes with the DSATUR algorithm: the next node to be colored is the one
+with the most distinct colors among its neighbors, then the one with the highest degree,
+then the one with the lowest index.
+*/
+fn color_dsatur(neighbors: &[Vec<u32>]) -> Vec<usize> {
+    let mut colors: Vec<Option<usize>> = vec![None; neighbors.len()];
+    let mut neighbor_colors: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); neighbors.len()];
+    let key = |v: u32, saturation: usize| (saturation, neighbors[v as usize].len(), Reverse(v));
+    let mut uncolored: BTreeSet<(usize, usize, Reverse<u32>)> =
+        (0..neighbors.len() as u32).map(|v| key(v, 0)).collect();
+    while let Some((_, _, Reverse(v))) = uncolored.pop_last() {
+        let color = get_lowest_free_color(&neighbors[v as usize], &colors);
+        colors[v as usize] = Some(color);
+        for u in neighbors[v as usize].iter() {
+            let u_colors = &mut neighbor_colors[*u as usize];
+            if colors[*u as usize].is_none() && !u_colors.contains(&color) {
+                uncolored.remove(&key(*u, u_colors.len()));
+                u_colors.insert(color);
+                uncolored.insert(key(*u, u_colors.len()));
+            }
+        }
+    }
+    colors.into_iter().map(|c| c.unwrap()).collect()
+}
+
+/// Returns the lowest color not used by any of `nbrs`.
+fn get_lowest_free_color(nbrs: &[u32], colors: &[Option<usize>]) -> usize {
+    let used: BTreeSet<usize> = nbrs.iter().filter_map(|u| colors[*u as usize]).collect();
+    (0..).find(|c| !used.contains(c)).unwrap()
+}
@@ -0,0 +1,179 @@
+use crate::algorithms::centrality::betweenness;
+use crate::algorithms::community::partitions;
+use crate::algorithms::components;
+use crate::{Error, Graph};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+An iterator over the partitions found by the Girvan–Newman algorithm.
+Each partition has more communities than the one before it.
+Created by [girvan_newman_partitions](fn.girvan_newman_partitions.html).
+*/
+pub struct GirvanNewmanPartitions<T, A>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph: Graph<T, A>,
+    weighted: bool,
+    num_components: usize,
+}
+
+impl<T, A> Iterator for GirvanNewmanPartitions<T, A>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    type Item = Vec<HashSet<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.graph.get_all_edges().is_empty() {
+            self.remove_most_valuable_edge();
+            let components = get_components(&self.graph);
+            if components.len() > self.num_components {
+                self.num_components = components.len();
+                return Some(components);
+            }
+        }
+        None
+    }
+}
+
+impl<T, A> GirvanNewmanPartitions<T, A>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    /// Removes the edge with the highest betweenness centrality; ties go to the smallest edge.
+    fn remove_most_valuable_edge(&mut self) {
+        let edge_betweenness =
+            betweenness::edge_betweenness_centrality(&self.graph, self.weighted, false).unwrap();
+        let (u, v) = edge_betweenness
+            .into_iter()
+            .max_by(|(k1, v1), (k2, v2)| v1.partial_cmp(v2).unwrap().then_with(|| k2.cmp(k1)))
+            .unwrap()
+            .0;
+        let directed = self.graph.specs.directed;
+        let nodes = self.graph.get_all_nodes().into_iter().cloned().collect();
+        let edges = self
+            .graph
+            .get_all_edges()
+            .into_iter()
+            .filter(|e| !((e.u == u && e.v == v) || (!directed && e.u == v && e.v == u)))
+            .cloned()
+            .collect();
+        self.graph =
+            Graph::new_from_nodes_and_edges(nodes, edges, self.graph.specs.clone()).unwrap();
+    }
+}
+
+/**
+Finds communities in a graph using the Girvan–Newman method.
+
+The edge with the highest betweenness centrality is removed from the graph repeatedly.
+Each time the removal of an edge increases the number of connected components, the
+connected components are returned as the next partition. For directed graphs weakly
+connected components are used.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing edge betweenness centrality
+
+# Examples
+
+```
+use graphrs::{algorithms::{community}, generators};
+let graph = generators::social::karate_club_graph();
+let mut partitions = community::girvan_newman::girvan_newman_partitions(&graph, false).unwrap();
+assert_eq!(partitions.next().unwrap().len(), 2);
+assert_eq!(partitions.next().unwrap().len(), 3);
+```
+
+# References
+
+1. M. Girvan and M. E. J. Newman. Community structure in social and biological networks.
+   Proceedings of the National Academy of Sciences 99(12):7821–7826, 2002.
+*/
+pub fn girvan_newman_partitions<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+) -> Result<GirvanNewmanPartitions<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let nodes = graph.get_all_nodes().into_iter().cloned().collect();
+    let edges = graph.get_all_edges().into_iter().cloned().collect();
+    let graph = Graph::new_from_nodes_and_edges(nodes, edges, graph.specs.clone())?;
+    let num_components = get_components(&graph).len();
+    Ok(GirvanNewmanPartitions {
+        graph,
+        weighted,
+        num_components,
+    })
+}
+
+/**
+Finds the communities in a graph using the Girvan–Newman method, stopping at the partition
+with the highest modularity.
+
+All the partitions produced by [girvan_newman_partitions](fn.girvan_newman_partitions.html)
+are computed, so this can be slow for large graphs.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing edge betweenness centrality
+  and modularity
+
+# Examples
+
+```
+use graphrs::{algorithms::{community}, generators};
+let graph = generators::social::karate_club_graph();
+let communities = community::girvan_newman::girvan_newman_communities(&graph, false).unwrap();
+assert_eq!(communities.len(), 5);
+```
+*/
+pub fn girvan_newman_communities<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+) -> Result<Vec<HashSet<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let gn_partitions = girvan_newman_partitions(graph, weighted)?;
+    let mut best = get_components(graph);
+    if graph.get_all_edges().is_empty() {
+        return Ok(best);
+    }
+    let mut best_modularity = partitions::modularity(graph, &best, weighted, None)?;
+    for partition in gn_partitions {
+        let modularity = partitions::modularity(graph, &partition, weighted, None)?;
+        if modularity > best_modularity {
+            best = partition;
+            best_modularity = modularity;
+        }
+    }
+    Ok(best)
+}
+
+/// Returns the connected components, or weakly connected components, of `graph`.
+fn get_components<T, A>(graph: &Graph<T, A>) -> Vec<HashSet<T>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let components = match graph.specs.directed {
+        true => components::weakly_connected_components(graph),
+        false => components::connected_components(graph),
+    };
+    components.unwrap().into_iter().map(|c| c.into_iter().collect()).collect()
+}
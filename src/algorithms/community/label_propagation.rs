@@ -0,0 +1,154 @@
+use crate::algorithms::community::louvain::get_rng;
+use crate::{Error, Graph};
+use rand::seq::SliceRandom;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the communities of a graph, found by asynchronous label propagation.
+
+Every node starts with its own label. Then, visiting the nodes in a random order,
+each node adopts the label that is most common among its neighbors (weighted by edge
+weight when `weighted` is `true`). A node keeps its label if it is one of the most
+common labels; other ties are broken randomly. The algorithm stops when every node has
+one of the most common labels among its neighbors, and the nodes that share a label
+form a community. Each round takes time linear in the number of edges, so this is
+suitable for very large graphs.
+
+For directed graphs the labels of a node's successors are used.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when determining communities
+* `seed`: Setting the `seed` causes the nodes to be visited, and ties to be broken, in the
+  same way on every call.
+* `max_iter`: The maximum number of rounds of label updates; use `None` for the default
+  value of `100`.
+
+# Examples
+
+```
+use graphrs::{algorithms::{community}, generators};
+let graph = generators::social::karate_club_graph();
+let communities = community::label_propagation::label_propagation_communities(
+    &graph, false, Some(1), None
+).unwrap();
+assert!(communities.len() > 1);
+```
+
+# References
+
+1. Raghavan, Usha Nandini, Réka Albert, and Soundar Kumara. "Near linear time algorithm
+   to detect community structures in large-scale networks." Physical Review E 76, 036106, 2007.
+*/
+pub fn label_propagation_communities<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    seed: Option<u64>,
+    max_iter: Option<u32>,
+) -> Result<Vec<HashSet<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let _max_iter = max_iter.unwrap_or(100);
+    let mut rng = get_rng(seed);
+
+    let mut node_names: Vec<T> = graph.get_all_node_names().into_iter().cloned().collect();
+    node_names.sort();
+    let adjacency = get_adjacency(graph, weighted, &node_names);
+    let mut labels: Vec<usize> = (0..node_names.len()).collect();
+    let mut order: Vec<usize> = (0..node_names.len()).collect();
+
+    for _i in 0.._max_iter {
+        order.shuffle(&mut rng);
+        let mut changed = false;
+        for v in order.iter() {
+            let best = get_best_labels(&adjacency[*v], &labels);
+            if best.is_empty() || best.contains(&labels[*v]) {
+                continue;
+            }
+            labels[*v] = *best.choose(&mut rng).unwrap();
+            changed = true;
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut communities: BTreeMap<usize, Vec<T>> = BTreeMap::new();
+    for (name, label) in node_names.into_iter().zip(labels) {
+        communities.entry(label).or_default().push(name);
+    }
+    let mut communities: Vec<Vec<T>> = communities.into_values().collect();
+    communities.sort();
+    Ok(communities.into_iter().map(|c| c.into_iter().collect()).collect())
+}
+
+/// Returns, for each node, its neighbors (excluding itself) and the weights of the edges to them.
+fn get_adjacency<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    node_names: &[T],
+) -> Vec<Vec<(usize, f64)>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let node_indexes: HashMap<&T, usize> =
+        node_names.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    let mut adjacency: Vec<Vec<(usize, f64)>> = vec![vec![]; node_names.len()];
+    for edge in graph.get_all_edges() {
+        let weight = match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+        let u = *node_indexes.get(&edge.u).unwrap();
+        let v = *node_indexes.get(&edge.v).unwrap();
+        if u == v {
+            continue;
+        }
+        adjacency[u].push((v, weight));
+        if !graph.specs.directed {
+            adjacency[v].push((u, weight));
+        }
+    }
+    adjacency
+}
+
+/// Returns the labels with the highest total weight among `neighbors`, in ascending order.
+fn get_best_labels(neighbors: &[(usize, f64)], labels: &[usize]) -> Vec<usize> {
+    let mut label_weights: BTreeMap<usize, f64> = BTreeMap::new();
+    for (u, w) in neighbors {
+        *label_weights.entry(labels[*u]).or_insert(0.0) += w;
+    }
+    let max_weight = label_weights.values().cloned().fold(f64::MIN, f64::max);
+    label_weights.into_iter().filter(|(_, w)| *w == max_weight).map(|(label, _)| label).collect()
+}
+
+// tests for private methods only; other tests are in:
+// tests/test_algorithms_community_label_propagation.rs
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_get_best_labels() {
+        let labels = vec![0, 1, 1, 2];
+        assert_eq!(
+            get_best_labels(&[(1, 1.0), (2, 1.0), (3, 1.5)], &labels),
+            vec![1]
+        );
+        assert_eq!(
+            get_best_labels(&[(1, 1.0), (2, 1.0), (3, 2.0)], &labels),
+            vec![1, 2]
+        );
+        assert!(get_best_labels(&[], &labels).is_empty());
+    }
+}
@@ -0,0 +1,394 @@
+use crate::algorithms::community::louvain::get_rng;
+use crate::{Error, Graph};
+use rand::prelude::*;
+use rand::seq::SliceRandom;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the best partition of a graph, using the Leiden algorithm.
+
+The Leiden algorithm improves on the Louvain algorithm by adding a refinement phase
+between the local moving of nodes and the aggregation of the graph. The refinement
+phase splits communities that are badly connected, so every community returned
+by the Leiden algorithm is connected.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when determining communities
+* `resolution`: If less than 1.0 larger communities are favoured. If greater than 1.0 smaller
+  communities are favoured. Use `None` for the default value of `1.0`.
+* `randomness`: Determines how randomly nodes are merged in the refinement phase. Lower values
+  make the algorithm more greedy. Use `None` for the default value of `0.01`.
+* `seed`: The Leiden algorithm implemented uses random number generators. Setting the `seed`
+  causes consistent behaviour.
+
+# Examples
+
+```
+use graphrs::{algorithms::{community}, generators};
+let graph = generators::social::karate_club_graph();
+let communities = community::leiden::leiden_communities(&graph, false, None, None, Some(1));
+assert_eq!(communities.unwrap().len(), 4);
+```
+
+# References
+
+1. V. A. Traag, L. Waltman and N. J. van Eck. From Louvain to Leiden: guaranteeing
+   well-connected communities. Scientific Reports 9, 5233, 2019.
+*/
+pub fn leiden_communities<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    resolution: Option<f64>,
+    randomness: Option<f64>,
+    seed: Option<u64>,
+) -> Result<Vec<HashSet<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let resolution = resolution.unwrap_or(1.0);
+    let randomness = randomness.unwrap_or(0.01);
+    let mut rng = get_rng(seed);
+
+    let mut node_names: Vec<T> = graph.get_all_node_names().into_iter().cloned().collect();
+    node_names.sort();
+    let mut aggregate = AggregateGraph::new(graph, weighted, &node_names);
+    let two_m: f64 = aggregate.degrees.iter().sum();
+    if two_m == 0.0 {
+        return Ok(node_names.into_iter().map(|n| vec![n].into_iter().collect()).collect());
+    }
+
+    let mut partition: Vec<usize> = (0..node_names.len()).collect();
+    loop {
+        move_nodes_fast(&aggregate, &mut partition, two_m, resolution, &mut rng);
+        let num_communities = renumber(&mut partition);
+        if num_communities == aggregate.len() {
+            break;
+        }
+        let mut refined = refine_partition(
+            &aggregate, &partition, two_m, resolution, randomness, &mut rng,
+        );
+        if renumber(&mut refined) == aggregate.len() {
+            // nothing was merged, so aggregate the unrefined partition to make progress
+            refined = partition.clone();
+        }
+        let (new_aggregate, new_partition) = aggregate.aggregate(&refined, &partition);
+        aggregate = new_aggregate;
+        partition = new_partition;
+    }
+
+    let mut communities: Vec<Vec<T>> = vec![vec![]; aggregate.len()];
+    for (v, c) in partition.iter().enumerate() {
+        for member in aggregate.members[v].iter() {
+            communities[*c].push(node_names[*member].clone());
+        }
+    }
+    communities.iter_mut().for_each(|c| c.sort());
+    communities.sort();
+    Ok(
+        communities
+            .into_iter()
+            .filter(|c| !c.is_empty())
+            .map(|c| c.into_iter().collect())
+            .collect(),
+    )
+}
+
+/// A graph where each node is a set of the nodes of the original graph.
+struct AggregateGraph {
+    /// The neighbors of each node, and the total weight of the edges to them; excludes self-loops.
+    adjacency: Vec<Vec<(usize, f64)>>,
+    /// The weighted degree of each node, where self-loops count twice.
+    degrees: Vec<f64>,
+    /// The indexes of the original nodes that each node contains.
+    members: Vec<Vec<usize>>,
+}
+
+impl AggregateGraph {
+    /// Creates an `AggregateGraph` with a node for each of the nodes in `graph`.
+    fn new<T, A>(graph: &Graph<T, A>, weighted: bool, node_names: &[T]) -> AggregateGraph
+    where
+        T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+        A: Clone,
+    {
+        let node_indexes: HashMap<&T, usize> =
+            node_names.iter().enumerate().map(|(i, n)| (n, i)).collect();
+        let mut weights: Vec<HashMap<usize, f64>> = vec![HashMap::new(); node_names.len()];
+        let mut degrees = vec![0.0; node_names.len()];
+        for edge in graph.get_all_edges() {
+            let weight = match weighted {
+                true => edge.weight,
+                false => 1.0,
+            };
+            let u = *node_indexes.get(&edge.u).unwrap();
+            let v = *node_indexes.get(&edge.v).unwrap();
+            degrees[u] += weight;
+            degrees[v] += weight;
+            if u != v {
+                *weights[u].entry(v).or_insert(0.0) += weight;
+                *weights[v].entry(u).or_insert(0.0) += weight;
+            }
+        }
+        AggregateGraph {
+            adjacency: get_sorted_adjacency(weights),
+            degrees,
+            members: (0..node_names.len()).map(|i| vec![i]).collect(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.degrees.len()
+    }
+
+    /**
+    Creates a new `AggregateGraph` with a node for each of the `refined` communities.
+    Also returns the initial partition of the new graph, in which each node is in
+    the community of `partition` that contains it.
+    */
+    fn aggregate(&self, refined: &[usize], partition: &[usize]) -> (AggregateGraph, Vec<usize>) {
+        let num_nodes = refined.iter().max().map_or(0, |m| m + 1);
+        let mut weights: Vec<HashMap<usize, f64>> = vec![HashMap::new(); num_nodes];
+        let mut degrees = vec![0.0; num_nodes];
+        let mut members: Vec<Vec<usize>> = vec![vec![]; num_nodes];
+        let mut new_partition = vec![0; num_nodes];
+        for v in 0..self.len() {
+            let r = refined[v];
+            degrees[r] += self.degrees[v];
+            members[r].extend(self.members[v].iter().cloned());
+            new_partition[r] = partition[v];
+            for (u, w) in self.adjacency[v].iter() {
+                if refined[*u] != r {
+                    *weights[r].entry(refined[*u]).or_insert(0.0) += w;
+                }
+            }
+        }
+        let aggregate = AggregateGraph {
+            adjacency: get_sorted_adjacency(weights),
+            degrees,
+            members,
+        };
+        (aggregate, new_partition)
+    }
+
+    /// Returns the total weight of the edges from `v` to each community in `partition`.
+    fn get_community_weights(&self, v: usize, partition: &[usize]) -> Vec<(usize, f64)> {
+        let mut weights: Vec<(usize, f64)> = vec![];
+        let mut indexes: HashMap<usize, usize> = HashMap::new();
+        for (u, w) in self.adjacency[v].iter() {
+            let c = partition[*u];
+            match indexes.get(&c) {
+                Some(i) => weights[*i].1 += w,
+                None => {
+                    indexes.insert(c, weights.len());
+                    weights.push((c, *w));
+                }
+            }
+        }
+        weights
+    }
+}
+
+/// Converts neighbor weight maps to adjacency lists, ordered by neighbor.
+fn get_sorted_adjacency(weights: Vec<HashMap<usize, f64>>) -> Vec<Vec<(usize, f64)>> {
+    weights
+        .into_iter()
+        .map(|hm| {
+            let mut neighbors: Vec<(usize, f64)> = hm.into_iter().collect();
+            neighbors.sort_by_key(|(u, _)| *u);
+            neighbors
+        })
+        .collect()
+}
+
+/**
+Moves nodes to the community that gives the largest increase in modularity, revisiting
+only the neighbors of nodes that have moved, until no move increases the modularity.
+*/
+fn move_nodes_fast(
+    graph: &AggregateGraph,
+    partition: &mut [usize],
+    two_m: f64,
+    resolution: f64,
+    rng: &mut StdRng,
+) {
+    let num_nodes = graph.len();
+    let mut totals = vec![0.0; num_nodes];
+    let mut sizes = vec![0; num_nodes];
+    for v in 0..num_nodes {
+        totals[partition[v]] += graph.degrees[v];
+        sizes[partition[v]] += 1;
+    }
+    let mut empty: Vec<usize> = (0..num_nodes).filter(|c| sizes[*c] == 0).collect();
+
+    let mut order: Vec<usize> = (0..num_nodes).collect();
+    order.shuffle(rng);
+    let mut queue: VecDeque<usize> = order.into_iter().collect();
+    let mut queued = vec![true; num_nodes];
+
+    while let Some(v) = queue.pop_front() {
+        queued[v] = false;
+        let current = partition[v];
+        let k_v = graph.degrees[v];
+        totals[current] -= k_v;
+        sizes[current] -= 1;
+        if sizes[current] == 0 {
+            empty.push(current);
+        }
+
+        let community_weights = graph.get_community_weights(v, partition);
+        let gain = |c: usize, w: f64| w - resolution * k_v * totals[c] / two_m;
+        let current_weight =
+            community_weights.iter().find(|(c, _)| *c == current).map_or(0.0, |(_, w)| *w);
+        let mut best = current;
+        let mut best_gain = gain(current, current_weight);
+        for (c, w) in community_weights.iter() {
+            let g = gain(*c, *w);
+            if g > best_gain {
+                best = *c;
+                best_gain = g;
+            }
+        }
+        if best_gain < 0.0 {
+            // moving to an empty community has no gain
+            best = *empty.last().unwrap();
+        }
+
+        if sizes[best] == 0 {
+            empty.retain(|c| *c != best);
+        }
+        totals[best] += k_v;
+        sizes[best] += 1;
+        if best != current {
+            partition[v] = best;
+            for (u, _) in graph.adjacency[v].iter() {
+                if !queued[*u] && partition[*u] != best {
+                    queued[*u] = true;
+                    queue.push_back(*u);
+                }
+            }
+        }
+    }
+}
+
+/**
+Refines `partition` by starting from singleton communities and merging nodes only
+within the communities of `partition`. A node is only merged if it is well connected
+to the rest of its community, and it is merged into a well-connected community
+chosen randomly, in proportion to the increase in modularity.
+*/
+fn refine_partition(
+    graph: &AggregateGraph,
+    partition: &[usize],
+    two_m: f64,
+    resolution: f64,
+    randomness: f64,
+    rng: &mut StdRng,
+) -> Vec<usize> {
+    let num_nodes = graph.len();
+    let mut refined: Vec<usize> = (0..num_nodes).collect();
+    let mut refined_totals = graph.degrees.clone();
+    let mut refined_sizes = vec![1; num_nodes];
+    let num_communities = partition.iter().max().map_or(0, |m| m + 1);
+    let mut community_totals = vec![0.0; num_communities];
+    let mut community_nodes: Vec<Vec<usize>> = vec![vec![]; num_communities];
+    for v in 0..num_nodes {
+        community_totals[partition[v]] += graph.degrees[v];
+        community_nodes[partition[v]].push(v);
+    }
+    // the weight of the edges from each refined community to the rest of its community
+    let mut external_weights: Vec<f64> = (0..num_nodes)
+        .map(|v| {
+            graph.adjacency[v]
+                .iter()
+                .filter(|(u, _)| partition[*u] == partition[v])
+                .map(|(_, w)| w)
+                .sum()
+        })
+        .collect();
+
+    for nodes in community_nodes.iter_mut() {
+        nodes.shuffle(rng);
+        for v in nodes.iter() {
+            let v = *v;
+            let community_total = community_totals[partition[v]];
+            let is_well_connected = |r: usize, totals: &[f64], external: &[f64]| {
+                external[r] >= resolution * totals[r] * (community_total - totals[r]) / two_m
+            };
+            if refined_sizes[refined[v]] != 1
+                || !is_well_connected(v, &refined_totals, &external_weights)
+            {
+                continue;
+            }
+            let k_v = graph.degrees[v];
+            let mut candidates: Vec<(usize, f64, f64)> = vec![(refined[v], 0.0, 0.0)];
+            // a refined community `r` always contains node `r`, so it is in community `partition[r]`
+            for (r, w) in graph.get_community_weights(v, &refined) {
+                if r == refined[v]
+                    || partition[r] != partition[v]
+                    || !is_well_connected(r, &refined_totals, &external_weights)
+                {
+                    continue;
+                }
+                let gain = w - resolution * k_v * refined_totals[r] / two_m;
+                if gain >= 0.0 {
+                    candidates.push((r, w, gain));
+                }
+            }
+            let (r, w) = choose_candidate(&candidates, randomness, rng);
+            if r == refined[v] {
+                continue;
+            }
+            let old = refined[v];
+            refined[v] = r;
+            refined_sizes[old] -= 1;
+            refined_sizes[r] += 1;
+            refined_totals[old] -= k_v;
+            refined_totals[r] += k_v;
+            external_weights[r] += external_weights[old] - 2.0 * w;
+            external_weights[old] = 0.0;
+        }
+    }
+    refined
+}
+
+/**
+Randomly chooses one of the `candidates` (community, weight, gain), with probabilities
+proportional to `exp(gain / randomness)`. Returns the community and weight.
+*/
+fn choose_candidate(
+    candidates: &[(usize, f64, f64)],
+    randomness: f64,
+    rng: &mut StdRng,
+) -> (usize, f64) {
+    let max_gain = candidates.iter().map(|c| c.2).fold(f64::MIN, f64::max);
+    let probabilities: Vec<f64> =
+        candidates.iter().map(|c| ((c.2 - max_gain) / randomness).exp()).collect();
+    let total: f64 = probabilities.iter().sum();
+    let mut x = rng.gen::<f64>() * total;
+    for (candidate, probability) in candidates.iter().zip(probabilities) {
+        if x < probability {
+            return (candidate.0, candidate.1);
+        }
+        x -= probability;
+    }
+    let last = candidates.last().unwrap();
+    (last.0, last.1)
+}
+
+/// Renumbers the communities in `partition` so they are `0..n`; returns `n`.
+fn renumber(partition: &mut [usize]) -> usize {
+    let mut new_ids: HashMap<usize, usize> = HashMap::new();
+    for c in partition.iter_mut() {
+        let next_id = new_ids.len();
+        *c = *new_ids.entry(*c).or_insert(next_id);
+    }
+    new_ids.len()
+}
@@ -1,6 +1,6 @@
 use crate::{
     algorithms::community::partitions, Edge, EdgeDedupeStrategy, Error, ErrorKind, Graph,
-    GraphSpecs, Node,
+    GraphSpecs, Node, ProgressHandle,
 };
 use itertools::Itertools;
 use rand::prelude::*;
@@ -41,7 +41,57 @@ where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
-    let mut partitions = louvain_partitions(graph, weighted, resolution, threshold, seed)?;
+    let progress = ProgressHandle::new();
+    louvain_communities_with_progress(graph, weighted, resolution, threshold, seed, &progress)
+}
+
+/**
+Returns the best partition of a graph, using the Louvain algorithm, like
+[louvain_communities](./fn.louvain_communities.html), reporting the progress of the
+algorithm through `progress` and stopping if it is cancelled.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when determining communities
+* `resolution`: If less than 1.0 larger communities are favoured. If greater than 1.0 smaller communities are favoured.
+* `threshold`: Determines how quickly the algorithms stops trying to find partitions with higher modularity. Higher values cause the algorithm to give up more quickly.
+* `seed`: The Louvain algorithm implemented uses random number generators. Setting the `seed` causes consistent behaviour.
+* `progress`: a [ProgressHandle](../../../struct.ProgressHandle.html); each pass over the
+  nodes, when they are moved between communities, is a unit of work. The number of passes
+  isn't known in advance, so the `total` of the progress is `None`.
+
+# Returns
+
+An `Error` with a `kind` of `ErrorKind::Cancelled` is returned if `progress` was cancelled.
+
+# Examples
+
+```
+use graphrs::{algorithms::community, generators, ProgressHandle};
+let graph = generators::social::karate_club_graph();
+let progress = ProgressHandle::new().with_callback(|p| println!("pass {}", p.completed));
+let communities = community::louvain::louvain_communities_with_progress(
+    &graph, false, None, None, Some(1), &progress,
+);
+assert_eq!(communities.unwrap().len(), 4);
+assert!(progress.get_progress().completed > 0);
+```
+*/
+pub fn louvain_communities_with_progress<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    resolution: Option<f64>,
+    threshold: Option<f64>,
+    seed: Option<u64>,
+    progress: &ProgressHandle,
+) -> Result<Vec<HashSet<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut partitions =
+        louvain_partitions_with_progress(graph, weighted, resolution, threshold, seed, progress)?;
     match partitions.is_empty() {
         false => Ok(partitions.pop().unwrap()),
         true => Err(Error {
@@ -81,6 +131,42 @@ where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
+    let progress = ProgressHandle::new();
+    louvain_partitions_with_progress(graph, weighted, resolution, threshold, seed, &progress)
+}
+
+/**
+Returns the best partitions of a graph, using the Louvain algorithm, like
+[louvain_partitions](./fn.louvain_partitions.html), reporting the progress of the
+algorithm through `progress` and stopping if it is cancelled.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when determining communities
+* `resolution`: If less than 1.0 larger communities are favoured. If greater than 1.0 smaller communities are favoured.
+* `threshold`: Determines how quickly the algorithms stops trying to find partitions with higher modularity. Higher values cause the algorithm to give up more quickly.
+* `seed`: The Louvain algorithm implemented uses random number generators. Setting the `seed` causes consistent behaviour.
+* `progress`: a [ProgressHandle](../../../struct.ProgressHandle.html); each pass over the
+  nodes is a unit of work, and the `total` of the progress is `None`.
+
+# Returns
+
+An `Error` with a `kind` of `ErrorKind::Cancelled` is returned if `progress` was cancelled.
+*/
+pub fn louvain_partitions_with_progress<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    resolution: Option<f64>,
+    threshold: Option<f64>,
+    seed: Option<u64>,
+    progress: &ProgressHandle,
+) -> Result<Vec<Vec<HashSet<T>>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    progress.start(None)?;
     let _threshold = threshold.unwrap_or(0.0000001);
     let node_map = graph
         .get_all_nodes()
@@ -95,7 +181,7 @@ where
     let mut modularity = partitions::modularity(&graphu, &partition, weighted, resolution).unwrap();
     let m = graphu.size(weighted);
     let (mut partition, mut inner_partition, _improvement) =
-        compute_one_level(&graphu, m, &partition, resolution.unwrap_or(1.0), seed);
+        compute_one_level(&graphu, m, &partition, resolution.unwrap_or(1.0), seed, progress)?;
     let mut improvement = true;
     let mut partitions: Vec<Vec<HashSet<usize>>> = vec![];
     while improvement {
@@ -107,7 +193,8 @@ where
         }
         modularity = new_mod;
         graphu = generate_graph(&graphu, inner_partition);
-        let z = compute_one_level(&graphu, m, &partition, resolution.unwrap_or(1.0), seed);
+        let z =
+            compute_one_level(&graphu, m, &partition, resolution.unwrap_or(1.0), seed, progress)?;
         partition = z.0;
         inner_partition = z.1;
         improvement = z.2;
@@ -142,15 +229,17 @@ where
         .collect()
 }
 
-/// Calculate one level of the Louvain partitions tree.
-#[allow(clippy::ptr_arg)]
+/// Calculate one level of the Louvain partitions tree, advancing `progress` after each
+/// pass over the nodes.
+#[allow(clippy::ptr_arg, clippy::type_complexity)]
 fn compute_one_level(
     graph: &Graph<usize, HashSet<usize>>,
     m: f64,
     partition: &Vec<HashSet<usize>>,
     resolution: f64,
     seed: Option<u64>,
-) -> (Vec<HashSet<usize>>, Vec<HashSet<usize>>, bool) {
+    progress: &ProgressHandle,
+) -> Result<(Vec<HashSet<usize>>, Vec<HashSet<usize>>, bool), Error> {
     let mut _partition = partition.clone();
     let mut node2com: HashMap<usize, usize> = graph
         .get_all_nodes()
@@ -166,6 +255,7 @@ fn compute_one_level(
     let mut nb_moves = 1;
     let mut improvement = false;
     while nb_moves > 0 {
+        progress.check()?;
         nb_moves = 0;
         for u in &shuffled_nodes {
             let mut best_mod = 0.0;
@@ -193,6 +283,7 @@ fn compute_one_level(
                 nb_moves += 1;
             }
         }
+        progress.advance();
     }
     let new_partition: Vec<HashSet<usize>> = _partition
         .into_iter()
@@ -203,11 +294,11 @@ fn compute_one_level(
         .filter(|part| !part.is_empty())
         .collect();
 
-    (new_partition, new_inner_partition, improvement)
+    Ok((new_partition, new_inner_partition, improvement))
 }
 
 /// Returns a random number generator (RNG), optionally seeded.
-fn get_rng(seed: Option<u64>) -> StdRng {
+pub(crate) fn get_rng(seed: Option<u64>) -> StdRng {
     match seed {
         None => {
             let mut trng = thread_rng();
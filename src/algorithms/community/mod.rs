@@ -1,5 +1,17 @@
+// Find communities using the Girvan–Newman method.
+pub mod girvan_newman;
+
+// Find communities using label propagation.
+pub mod label_propagation;
+
+// Find communities using the Leiden community detection algorithm.
+pub mod leiden;
+
 // Find communities using the Louvain community detection algorithm.
 pub mod louvain;
 
 // Measure the quality of community partitions.
 pub mod partitions;
+
+// Measure the quality of community partitions: modularity, coverage and performance.
+pub mod quality;
@@ -0,0 +1,172 @@
+use crate::algorithms::community::partitions::is_partition;
+use crate::{Error, ErrorKind, Graph};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+pub use crate::algorithms::community::partitions::modularity;
+
+/**
+Compute the coverage of a partition: the fraction of the edges of the graph
+that are within communities.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `communities`: a `Vec` of `HashSet`s of node names
+* `weighted`: set to `true` to compute the fraction of the total edge weight rather than
+  the fraction of edges
+
+# Examples
+
+```
+use graphrs::{algorithms::community::quality, Edge, Graph, GraphSpecs};
+use std::collections::HashSet;
+let edges = vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n4"),
+];
+let graph: Graph<&str, ()> =
+    Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+        .unwrap();
+let hs1: HashSet<&str> = vec!["n1", "n2"].into_iter().collect();
+let hs2: HashSet<&str> = vec!["n3", "n4"].into_iter().collect();
+let coverage = quality::coverage(&graph, &vec![hs1, hs2], false).unwrap();
+assert_eq!(coverage, 2.0 / 3.0);
+```
+
+# References
+
+1. Santo Fortunato. "Community Detection in Graphs".
+   *Physical Reports*, Volume 486, Issue 3--5 pp. 75--174
+   <https://arxiv.org/abs/0906.0612>
+*/
+pub fn coverage<T, A>(
+    graph: &Graph<T, A>,
+    communities: &[HashSet<T>],
+    weighted: bool,
+) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let node_communities = get_node_communities(graph, communities)?;
+    let edges = graph.get_all_edges();
+    if edges.is_empty() {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "Coverage is not defined for a graph without edges.".to_string(),
+        });
+    }
+    let get_weight = |w: f64| match weighted {
+        true => w,
+        false => 1.0,
+    };
+    let total: f64 = edges.iter().map(|e| get_weight(e.weight)).sum();
+    let intra: f64 = edges
+        .iter()
+        .filter(|e| node_communities.get(&e.u) == node_communities.get(&e.v))
+        .map(|e| get_weight(e.weight))
+        .sum();
+    Ok(intra / total)
+}
+
+/**
+Compute the performance of a partition: the number of intra-community edges plus
+the number of inter-community non-edges, divided by the number of pairs of nodes.
+
+Self-loops are ignored. Performance is not defined for graphs with multiple edges
+between the same nodes.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `communities`: a `Vec` of `HashSet`s of node names
+
+# Examples
+
+```
+use graphrs::{algorithms::community::quality, Edge, Graph, GraphSpecs};
+use std::collections::HashSet;
+let edges = vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n4"),
+];
+let graph: Graph<&str, ()> =
+    Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+        .unwrap();
+let hs1: HashSet<&str> = vec!["n1", "n2"].into_iter().collect();
+let hs2: HashSet<&str> = vec!["n3", "n4"].into_iter().collect();
+let performance = quality::performance(&graph, &vec![hs1, hs2]).unwrap();
+assert_eq!(performance, 5.0 / 6.0);
+```
+
+# References
+
+1. Santo Fortunato. "Community Detection in Graphs".
+   *Physical Reports*, Volume 486, Issue 3--5 pp. 75--174
+   <https://arxiv.org/abs/0906.0612>
+*/
+pub fn performance<T, A>(graph: &Graph<T, A>, communities: &[HashSet<T>]) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_not_multi_edges()?;
+    let node_communities = get_node_communities(graph, communities)?;
+    let num_nodes = graph.get_all_nodes().len();
+    if num_nodes < 2 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "Performance is not defined for a graph with fewer than two nodes."
+                .to_string(),
+        });
+    }
+    let pair_multiplier = match graph.specs.directed {
+        true => 2,
+        false => 1,
+    };
+    let mut inter_pairs = 0;
+    let mut remaining = num_nodes;
+    for community in communities {
+        remaining -= community.len();
+        inter_pairs += community.len() * remaining * pair_multiplier;
+    }
+    let total_pairs = num_nodes * (num_nodes - 1) * pair_multiplier / 2;
+    let mut intra_edges = 0;
+    let mut inter_non_edges = inter_pairs;
+    for edge in graph.get_all_edges().into_iter().filter(|e| e.u != e.v) {
+        match node_communities.get(&edge.u) == node_communities.get(&edge.v) {
+            true => intra_edges += 1,
+            false => inter_non_edges -= 1,
+        }
+    }
+    Ok((intra_edges + inter_non_edges) as f64 / total_pairs as f64)
+}
+
+/// Checks that `communities` is a partition of `graph` and maps each node to its community.
+fn get_node_communities<'a, T, A>(
+    graph: &Graph<T, A>,
+    communities: &'a [HashSet<T>],
+) -> Result<HashMap<&'a T, usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if !is_partition(graph, communities) {
+        return Err(Error {
+            kind: ErrorKind::NotAPartition,
+            message: "The specified communities did not form a partition of a Graph.".to_string(),
+        });
+    }
+    Ok(communities
+        .iter()
+        .enumerate()
+        .flat_map(|(i, community)| community.iter().map(move |n| (n, i)))
+        .collect())
+}
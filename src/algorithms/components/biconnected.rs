@@ -0,0 +1,275 @@
+use crate::{CsrGraph, Error, Graph};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Finds the articulation points (or cut vertices) of an undirected graph: the nodes whose
+removal would increase the number of connected components.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+A sorted `Vec` of node names. An `Error` with a `kind` of `ErrorKind::WrongMethod` is
+returned if the graph is directed.
+
+# Examples
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::new(1, 2),
+    Edge::new(2, 3),
+    Edge::new(3, 1),
+    Edge::new(3, 4),
+    Edge::new(4, 5),
+]);
+assert_eq!(components::articulation_points(&graph).unwrap(), vec![3, 4]);
+```
+
+# References
+
+1. J. Hopcroft and R. Tarjan. Algorithm 447: Efficient algorithms for graph manipulation.
+   Communications of the ACM, 16(6):372–378, 1973.
+*/
+pub fn articulation_points<T, A>(graph: &Graph<T, A>) -> Result<Vec<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let csr = graph.to_csr();
+    let search = LowpointSearch::new(&csr);
+    Ok(csr
+        .get_node_names()
+        .iter()
+        .zip(search.is_articulation_point)
+        .filter(|(_name, is_articulation_point)| *is_articulation_point)
+        .map(|(name, _)| name.clone())
+        .collect())
+}
+
+/**
+Finds the bridges of an undirected graph: the edges whose removal would increase the
+number of connected components. An edge that has a parallel edge is never a bridge.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+A sorted `Vec` of `(u, v)` tuples of node names, where `u` is less than `v`. An `Error`
+with a `kind` of `ErrorKind::WrongMethod` is returned if the graph is directed.
+
+# Examples
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::new(1, 2),
+    Edge::new(2, 3),
+    Edge::new(3, 1),
+    Edge::new(3, 4),
+    Edge::new(4, 5),
+]);
+assert_eq!(components::bridges(&graph).unwrap(), vec![(3, 4), (4, 5)]);
+```
+
+# References
+
+1. R. Tarjan. A note on finding the bridges of a graph.
+   Information Processing Letters, 2(6):160–161, 1974.
+*/
+pub fn bridges<T, A>(graph: &Graph<T, A>) -> Result<Vec<(T, T)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let csr = graph.to_csr();
+    let search = LowpointSearch::new(&csr);
+    let names = csr.get_node_names();
+    let mut bridges: Vec<(T, T)> = search
+        .bridges
+        .into_iter()
+        .map(|(u, v)| {
+            (
+                names[u.min(v) as usize].clone(),
+                names[u.max(v) as usize].clone(),
+            )
+        })
+        .filter(|(u, v)| {
+            !graph.specs.multi_edges || graph.get_edges(u.clone(), v.clone()).unwrap().len() == 1
+        })
+        .collect();
+    bridges.sort();
+    Ok(bridges)
+}
+
+/**
+Finds the biconnected components of an undirected graph: the maximal sets of nodes
+that remain connected if any one node is removed. Two biconnected components can share
+a node, which is then an articulation point. A bridge and its two nodes form a
+biconnected component, and nodes without any edges (other than self-loops) are not in
+any component.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+A `Vec` of components, each of which is a sorted `Vec` of node names. The components
+are sorted. An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned if the graph
+is directed.
+
+# Examples
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::new(1, 2),
+    Edge::new(2, 3),
+    Edge::new(3, 1),
+    Edge::new(3, 4),
+    Edge::new(4, 5),
+]);
+let result = components::biconnected_components(&graph).unwrap();
+assert_eq!(result, vec![vec![1, 2, 3], vec![3, 4], vec![4, 5]]);
+```
+
+# References
+
+1. J. Hopcroft and R. Tarjan. Algorithm 447: Efficient algorithms for graph manipulation.
+   Communications of the ACM, 16(6):372–378, 1973.
+*/
+pub fn biconnected_components<T, A>(graph: &Graph<T, A>) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let csr = graph.to_csr();
+    let search = LowpointSearch::new(&csr);
+    let names = csr.get_node_names();
+    let mut components: Vec<Vec<T>> = search
+        .components
+        .into_iter()
+        .map(|mut component| {
+            component.sort_unstable();
+            component.dedup();
+            component.into_iter().map(|v| names[v as usize].clone()).collect()
+        })
+        .collect();
+    components.sort();
+    Ok(components)
+}
+
+/// The results of a depth-first search that finds the lowpoint of every node.
+struct LowpointSearch {
+    is_articulation_point: Vec<bool>,
+    /// the tree edges `(parent, child)` that are bridges
+    bridges: Vec<(u32, u32)>,
+    /// the nodes of each biconnected component, which may contain duplicates
+    components: Vec<Vec<u32>>,
+}
+
+impl LowpointSearch {
+    /**
+    Searches the graph depth-first from each unvisited node in turn. The lowpoint of a
+    node is the earliest discovery time of any node that can be reached from its
+    subtree with a single back edge; a child whose lowpoint isn't earlier than its
+    parent's discovery time is cut off from the rest of the graph by the parent.
+    */
+    fn new<T>(csr: &CsrGraph<T>) -> Self
+    where
+        T: Hash + Eq + Clone + Display,
+    {
+        let n = csr.number_of_nodes();
+        let mut discovery: Vec<Option<usize>> = vec![None; n];
+        let mut lowpoints = vec![0; n];
+        let mut search = LowpointSearch {
+            is_articulation_point: vec![false; n],
+            bridges: vec![],
+            components: vec![],
+        };
+        let mut time = 0;
+        // the edges of the biconnected component that is being found
+        let mut edge_stack: Vec<(u32, u32)> = vec![];
+        for root in 0..n as u32 {
+            if discovery[root as usize].is_some() {
+                continue;
+            }
+            discovery[root as usize] = Some(time);
+            lowpoints[root as usize] = time;
+            time += 1;
+            let mut root_children = 0;
+            // an explicit call stack avoids overflowing the thread's stack on large graphs
+            let mut call_stack: Vec<(u32, Option<u32>, usize)> = vec![(root, None, 0)];
+            while let Some((v, parent, i)) = call_stack.last_mut() {
+                let (v, parent) = (*v, *parent);
+                let neighbors = csr.get_successors_or_neighbors(v);
+                if *i < neighbors.len() {
+                    let w = neighbors[*i];
+                    *i += 1;
+                    if w == v || Some(w) == parent {
+                        continue;
+                    }
+                    match discovery[w as usize] {
+                        None => {
+                            discovery[w as usize] = Some(time);
+                            lowpoints[w as usize] = time;
+                            time += 1;
+                            edge_stack.push((v, w));
+                            call_stack.push((w, Some(v), 0));
+                        }
+                        Some(w_discovery) => {
+                            if w_discovery < discovery[v as usize].unwrap() {
+                                edge_stack.push((v, w));
+                                lowpoints[v as usize] = lowpoints[v as usize].min(w_discovery);
+                            }
+                        }
+                    }
+                    continue;
+                }
+                call_stack.pop();
+                let parent = match parent {
+                    None => continue,
+                    Some(parent) => parent,
+                };
+                let (w, v) = (v, parent);
+                lowpoints[v as usize] = lowpoints[v as usize].min(lowpoints[w as usize]);
+                let v_discovery = discovery[v as usize].unwrap();
+                if lowpoints[w as usize] > v_discovery {
+                    search.bridges.push((v, w));
+                }
+                if lowpoints[w as usize] >= v_discovery {
+                    match v == root {
+                        true => root_children += 1,
+                        false => search.is_articulation_point[v as usize] = true,
+                    }
+                    let position = edge_stack.iter().rposition(|e| *e == (v, w)).unwrap();
+                    let component = edge_stack
+                        .split_off(position)
+                        .into_iter()
+                        .flat_map(|(a, b)| [a, b])
+                        .collect();
+                    search.components.push(component);
+                }
+            }
+            if root_children > 1 {
+                search.is_articulation_point[root as usize] = true;
+            }
+        }
+        search
+    }
+}
@@ -0,0 +1,374 @@
+mod biconnected;
+pub(crate) mod union_find;
+use crate::algorithms::traversal::{bfs, get_sorted_successors_or_neighbors};
+use crate::{Edge, Error, Graph, GraphSpecs, Node};
+pub use biconnected::{articulation_points, biconnected_components, bridges};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Finds the connected components of an undirected graph: the maximal sets of nodes
+where every node can be reached from every other node.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+A `Vec` of components, each of which is a sorted `Vec` of node names. The components
+are sorted by their first node.
+
+# Examples
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs, Node};
+
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![Edge::new(1, 2), Edge::new(3, 2), Edge::new(4, 5)]);
+graph.add_node(Node::from_name(6));
+
+let result = components::connected_components(&graph).unwrap();
+assert_eq!(result, vec![vec![1, 2, 3], vec![4, 5], vec![6]]);
+```
+*/
+pub fn connected_components<T, A>(graph: &Graph<T, A>) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    Ok(get_components(graph))
+}
+
+/**
+Finds the weakly connected components of a directed graph: the maximal sets of nodes
+that are connected when the direction of the edges is ignored.
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+A `Vec` of components, each of which is a sorted `Vec` of node names. The components
+are sorted by their first node.
+
+# Examples
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![Edge::new(1, 2), Edge::new(3, 2), Edge::new(4, 5)]);
+
+let result = components::weakly_connected_components(&graph).unwrap();
+assert_eq!(result, vec![vec![1, 2, 3], vec![4, 5]]);
+```
+*/
+pub fn weakly_connected_components<T, A>(graph: &Graph<T, A>) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    Ok(get_components(graph))
+}
+
+/**
+Returns the number of connected components in an undirected graph.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+
+# Examples
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![Edge::new(1, 2), Edge::new(3, 2), Edge::new(4, 5)]);
+assert_eq!(components::number_connected_components(&graph).unwrap(), 2);
+```
+*/
+pub fn number_connected_components<T, A>(graph: &Graph<T, A>) -> Result<usize, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    Ok(connected_components(graph)?.len())
+}
+
+/**
+Returns the nodes in the connected component of an undirected graph that contains `node_name`.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `node_name`: the node whose component is returned
+
+# Returns
+
+A sorted `Vec` of node names, including `node_name`.
+
+# Examples
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![Edge::new(1, 2), Edge::new(3, 2), Edge::new(4, 5)]);
+assert_eq!(components::node_connected_component(&graph, 3).unwrap(), vec![1, 2, 3]);
+```
+*/
+pub fn node_connected_component<T, A>(graph: &Graph<T, A>, node_name: T) -> Result<Vec<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let mut component: Vec<T> = bfs::breadth_first_nodes(graph, node_name)?.collect();
+    component.sort();
+    Ok(component)
+}
+
+/**
+Uses a union-find structure to group the nodes of `graph` into components,
+ignoring the direction of any edges.
+*/
+fn get_components<T, A>(graph: &Graph<T, A>) -> Vec<Vec<T>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let mut nodes: Vec<T> = graph.get_all_node_names().into_iter().cloned().collect();
+    nodes.sort();
+    let node_indexes: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    let mut union_find = union_find::UnionFind::new(nodes.len());
+    for edge in graph.get_all_edges() {
+        union_find.union(
+            *node_indexes.get(&edge.u).unwrap(),
+            *node_indexes.get(&edge.v).unwrap(),
+        );
+    }
+    // nodes are sorted so each component is created by, and sorted after, its smallest node
+    let mut components: Vec<Vec<T>> = vec![];
+    let mut component_indexes = HashMap::<usize, usize>::new();
+    for (i, node) in nodes.iter().enumerate() {
+        let root = union_find.find(i);
+        let next_index = components.len();
+        let component_index = *component_indexes.entry(root).or_insert(next_index);
+        if component_index == next_index {
+            components.push(vec![]);
+        }
+        components[component_index].push(node.clone());
+    }
+    components
+}
+
+/**
+Finds the strongly connected components of a directed graph: the maximal sets of nodes
+where every node can be reached from every other node.
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+A `Vec` of components, each of which is a sorted `Vec` of node names. The components
+are in reverse topological order: if there is an edge from a node in one component to a
+node in another component, the second component comes first.
+
+# Examples
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n1"),
+    Edge::new("n3", "n4"),
+    Edge::new("n4", "n5"),
+    Edge::new("n5", "n4"),
+]);
+
+let result = components::strongly_connected_components(&graph).unwrap();
+assert_eq!(result, vec![vec!["n4", "n5"], vec!["n1", "n2", "n3"]]);
+```
+
+# References
+
+1. Robert Tarjan. Depth-first search and linear graph algorithms.
+   SIAM Journal on Computing, 1(2):146–160, 1972.
+*/
+pub fn strongly_connected_components<T, A>(graph: &Graph<T, A>) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+
+    let mut nodes: Vec<T> = graph.get_all_node_names().into_iter().cloned().collect();
+    nodes.sort();
+
+    let mut state = TarjanState {
+        next_index: 0,
+        indexes: HashMap::new(),
+        lowlinks: HashMap::new(),
+        stack: vec![],
+        on_stack: HashSet::new(),
+    };
+    let mut components = vec![];
+
+    for root in nodes {
+        if state.indexes.contains_key(&root) {
+            continue;
+        }
+        // an explicit call stack avoids overflowing the thread's stack on large graphs
+        let mut call_stack = vec![state.push(graph, root)];
+        while let Some((v, successors, i)) = call_stack.last_mut() {
+            if *i < successors.len() {
+                let w = successors[*i].clone();
+                *i += 1;
+                let v = v.clone();
+                match state.indexes.get(&w) {
+                    None => call_stack.push(state.push(graph, w)),
+                    Some(w_index) => {
+                        if state.on_stack.contains(&w) {
+                            state.lower_lowlink(v, *w_index);
+                        }
+                    }
+                }
+                continue;
+            }
+            let v = v.clone();
+            call_stack.pop();
+            let v_lowlink = *state.lowlinks.get(&v).unwrap();
+            if let Some((parent, _, _)) = call_stack.last() {
+                state.lower_lowlink(parent.clone(), v_lowlink);
+            }
+            if v_lowlink == *state.indexes.get(&v).unwrap() {
+                let position = state.stack.iter().rposition(|w| *w == v).unwrap();
+                let mut component = state.stack.split_off(position);
+                for w in component.iter() {
+                    state.on_stack.remove(w);
+                }
+                component.sort();
+                components.push(component);
+            }
+        }
+    }
+
+    Ok(components)
+}
+
+/**
+Finds the condensation of a directed graph: the graph that has a node for each strongly
+connected component, and an edge from one component to another if there is an edge from
+a node in the first to a node in the second. The condensation is always a directed
+acyclic graph.
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+A tuple of the condensation and a `HashMap` from each node of `graph` to the ID of its
+component. The components are numbered in topological order from 0, so every edge of
+the condensation goes from a lower ID to a higher one. Each node of the condensation is
+named by the component ID and its attributes are the sorted names of the nodes in the
+component.
+
+# Examples
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n1"),
+    Edge::new("n3", "n4"),
+    Edge::new("n4", "n5"),
+    Edge::new("n5", "n4"),
+]);
+
+let (condensation, mapping) = components::condensation(&graph).unwrap();
+assert_eq!(condensation.get_node(0).unwrap().attributes, Some(vec!["n1", "n2", "n3"]));
+assert_eq!(mapping.get("n5"), Some(&1));
+assert!(condensation.get_edge(0, 1).is_ok());
+```
+*/
+#[allow(clippy::type_complexity)]
+pub fn condensation<T, A>(
+    graph: &Graph<T, A>,
+) -> Result<(Graph<usize, Vec<T>>, HashMap<T, usize>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut components = strongly_connected_components(graph)?;
+    components.reverse();
+    let mapping: HashMap<T, usize> = components
+        .iter()
+        .enumerate()
+        .flat_map(|(i, component)| component.iter().map(move |n| (n.clone(), i)))
+        .collect();
+    let mut edges: Vec<(usize, usize)> = graph
+        .get_all_edges()
+        .into_iter()
+        .map(|e| (mapping[&e.u], mapping[&e.v]))
+        .filter(|(u, v)| u != v)
+        .collect();
+    edges.sort_unstable();
+    edges.dedup();
+    let nodes = components
+        .into_iter()
+        .enumerate()
+        .map(|(i, component)| Node::from_name_and_attributes(i, component))
+        .collect();
+    let edges = edges.into_iter().map(|(u, v)| Edge::new(u, v)).collect();
+    let condensation = Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::directed())?;
+    Ok((condensation, mapping))
+}
+
+/// The state of Tarjan's strongly connected components algorithm.
+struct TarjanState<T> {
+    next_index: usize,
+    indexes: HashMap<T, usize>,
+    lowlinks: HashMap<T, usize>,
+    stack: Vec<T>,
+    on_stack: HashSet<T>,
+}
+
+impl<T> TarjanState<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    /// Assigns the next index to `node` and pushes it onto the stack. Returns the entry
+    /// for the call stack: the node, its successors, and the next successor to visit.
+    fn push<A: Clone>(&mut self, graph: &Graph<T, A>, node: T) -> (T, Vec<T>, usize) {
+        self.indexes.insert(node.clone(), self.next_index);
+        self.lowlinks.insert(node.clone(), self.next_index);
+        self.next_index += 1;
+        self.stack.push(node.clone());
+        self.on_stack.insert(node.clone());
+        let successors = get_sorted_successors_or_neighbors(graph, &node);
+        (node, successors, 0)
+    }
+
+    /// Sets the lowlink of `node` to `lowlink` if that is lower than its current value.
+    fn lower_lowlink(&mut self, node: T, lowlink: usize) {
+        let current = self.lowlinks.get_mut(&node).unwrap();
+        if lowlink < *current {
+            *current = lowlink;
+        }
+    }
+}
@@ -0,0 +1,96 @@
+/**
+A disjoint-set (union-find) structure over the integers `0..n`, with path compression
+and union by rank.
+*/
+pub(crate) struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    /// The number of elements in the set of each representative element.
+    sizes: Vec<usize>,
+}
+
+impl UnionFind {
+    /// Creates a `UnionFind` where each of the `n` elements is in its own set.
+    pub fn new(n: usize) -> UnionFind {
+        UnionFind {
+            parents: (0..n).collect(),
+            ranks: vec![0; n],
+            sizes: vec![1; n],
+        }
+    }
+
+    /// Adds an element, in its own set, and returns it.
+    pub fn push(&mut self) -> usize {
+        let x = self.parents.len();
+        self.parents.push(x);
+        self.ranks.push(0);
+        self.sizes.push(1);
+        x
+    }
+
+    /// Returns the number of elements in the set that contains `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.sizes[root]
+    }
+
+    /// Returns the representative element of the set that contains `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut x = x;
+        while self.parents[x] != root {
+            let next = self.parents[x];
+            self.parents[x] = root;
+            x = next;
+        }
+        root
+    }
+
+    /// Merges the sets that contain `x` and `y`.
+    /// Returns `false` if they were already in the same set.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let x_root = self.find(x);
+        let y_root = self.find(y);
+        if x_root == y_root {
+            return false;
+        }
+        let (root, child) = match self.ranks[x_root].cmp(&self.ranks[y_root]) {
+            std::cmp::Ordering::Less => (y_root, x_root),
+            std::cmp::Ordering::Greater => (x_root, y_root),
+            std::cmp::Ordering::Equal => {
+                self.ranks[x_root] += 1;
+                (x_root, y_root)
+            }
+        };
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        true
+    }
+}
+
+// tests for private methods only; other tests are in:
+// tests/test_algorithms_components.rs
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut uf = UnionFind::new(5);
+        uf.union(0, 1);
+        uf.union(3, 4);
+        uf.union(1, 4);
+        assert_eq!(uf.find(0), uf.find(3));
+        assert_ne!(uf.find(0), uf.find(2));
+        assert_eq!(uf.size(4), 4);
+        assert!(!uf.union(0, 3));
+        let x = uf.push();
+        assert_eq!(x, 5);
+        assert!(uf.union(x, 2));
+        assert_eq!(uf.size(2), 2);
+    }
+}
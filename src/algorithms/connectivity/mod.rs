@@ -0,0 +1,443 @@
+use crate::algorithms::flow::FlowNetwork;
+use crate::{CsrGraph, Error, ErrorKind, Graph};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the node connectivity of a graph: the smallest number of nodes that must be
+removed to disconnect it. Or, if a `pair` of nodes is given, the local node connectivity
+between them: the number of paths from the source to the target that don't share any
+nodes other than their ends (if the nodes are adjacent, the edge between them counts as
+one of the paths).
+
+The connectivity of the whole graph is found with Even's algorithm, which finds the
+local node connectivity between a few pairs of non-adjacent nodes with a maximum flow.
+A complete graph with `n` nodes has a node connectivity of `n - 1`.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `pair`: the `(source, target)` nodes to find the local node connectivity between; if
+  `None` the node connectivity of the whole graph is found
+
+# Returns
+
+An `Error` with a `kind` of `ErrorKind::NodeNotFound` is returned if a node in `pair`
+doesn't exist, and one with a `kind` of `ErrorKind::InvalidArgument` if they are the
+same node.
+
+# Examples
+
+```
+use graphrs::{algorithms::connectivity, generators};
+let graph = generators::social::karate_club_graph();
+assert_eq!(connectivity::node_connectivity(&graph, None).unwrap(), 1);
+assert_eq!(connectivity::node_connectivity(&graph, Some((0, 33))).unwrap(), 6);
+```
+
+# References
+
+1. S. Even. An algorithm for determining whether the connectivity of a graph is at
+   least k. SIAM Journal on Computing, 4(3):393–396, 1975.
+2. A. Kammer and H. Täubig. Graph Connectivity. In U. Brandes and T. Erlebach (eds.),
+   Network Analysis: Methodological Foundations, LNCS 3418, 143–177, Springer, 2005.
+*/
+pub fn node_connectivity<T, A>(graph: &Graph<T, A>, pair: Option<(T, T)>) -> Result<usize, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let connectivity_graph = ConnectivityGraph::new(graph);
+    match pair {
+        None => Ok(connectivity_graph.get_min_node_separation().0),
+        Some((source, target)) => {
+            let (s, t) = connectivity_graph.get_pair_indexes(source, target)?;
+            Ok(connectivity_graph.get_node_flow(s, t).0)
+        }
+    }
+}
+
+/**
+Returns the edge connectivity of a graph: the smallest number of edges that must be
+removed to disconnect it. Or, if a `pair` of nodes is given, the local edge connectivity
+between them: the number of paths from the source to the target that don't share any
+edges.
+
+The connectivity of the whole graph is found with a maximum flow between one node and
+every other node. Each of multiple edges between the same nodes counts separately.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `pair`: the `(source, target)` nodes to find the local edge connectivity between; if
+  `None` the edge connectivity of the whole graph is found
+
+# Returns
+
+An `Error` with a `kind` of `ErrorKind::NodeNotFound` is returned if a node in `pair`
+doesn't exist, and one with a `kind` of `ErrorKind::InvalidArgument` if they are the
+same node.
+
+# Examples
+
+```
+use graphrs::{algorithms::connectivity, generators};
+let graph = generators::social::karate_club_graph();
+assert_eq!(connectivity::edge_connectivity(&graph, None).unwrap(), 1);
+assert_eq!(connectivity::edge_connectivity(&graph, Some((0, 33))).unwrap(), 10);
+```
+*/
+pub fn edge_connectivity<T, A>(graph: &Graph<T, A>, pair: Option<(T, T)>) -> Result<usize, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let connectivity_graph = ConnectivityGraph::new(graph);
+    match pair {
+        None => Ok(connectivity_graph.get_min_edge_separation().0),
+        Some((source, target)) => {
+            let (s, t) = connectivity_graph.get_pair_indexes(source, target)?;
+            Ok(connectivity_graph.get_edge_flow(s, t).0)
+        }
+    }
+}
+
+/**
+Finds a minimum node cut of a graph: a smallest set of nodes whose removal disconnects
+it. Or, if a `pair` of nodes is given, a smallest set of other nodes whose removal leaves
+no path from the source to the target.
+
+The number of nodes in the cut is the [node_connectivity](fn.node_connectivity.html).
+If the graph is complete, or is only disconnected by removing all the neighbors of a
+node, those neighbors are returned. If the graph is already disconnected the cut is
+empty.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `pair`: the `(source, target)` nodes to separate; if `None` the whole graph is
+  disconnected
+
+# Returns
+
+A sorted `Vec` of node names. An `Error` with a `kind` of `ErrorKind::NodeNotFound` is
+returned if a node in `pair` doesn't exist, and one with a `kind` of
+`ErrorKind::InvalidArgument` if they are the same node or if there is an edge from the
+source to the target.
+
+# Examples
+
+```
+use graphrs::{algorithms::connectivity, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::new("a", "b"),
+    Edge::new("a", "c"),
+    Edge::new("b", "d"),
+    Edge::new("c", "d"),
+    Edge::new("d", "e"),
+    Edge::new("d", "f"),
+    Edge::new("e", "f"),
+]);
+assert_eq!(connectivity::minimum_node_cut(&graph, None).unwrap(), vec!["d"]);
+let result = connectivity::minimum_node_cut(&graph, Some(("a", "d"))).unwrap();
+assert_eq!(result, vec!["b", "c"]);
+```
+*/
+pub fn minimum_node_cut<T, A>(graph: &Graph<T, A>, pair: Option<(T, T)>) -> Result<Vec<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let connectivity_graph = ConnectivityGraph::new(graph);
+    let cut = match pair {
+        None => match connectivity_graph.get_min_node_separation().1 {
+            None => connectivity_graph.get_min_degree_neighbors().to_vec(),
+            Some((s, t)) => connectivity_graph.get_node_cut(s, t),
+        },
+        Some((source, target)) => {
+            let (s, t) = connectivity_graph.get_pair_indexes(source.clone(), target.clone())?;
+            if connectivity_graph.is_adjacent(s, t) {
+                return Err(Error {
+                    kind: ErrorKind::InvalidArgument,
+                    message: format!(
+                        "There is an edge from {} to {} so no set of nodes separates them.",
+                        source, target
+                    ),
+                });
+            }
+            connectivity_graph.get_node_cut(s, t)
+        }
+    };
+    let names = connectivity_graph.csr.get_node_names();
+    // indexes are in the same order as names, so the cut is already sorted
+    Ok(cut.into_iter().map(|v| names[v as usize].clone()).collect())
+}
+
+/**
+Finds a minimum edge cut of a graph: a smallest set of edges whose removal disconnects
+it. Or, if a `pair` of nodes is given, a smallest set of edges whose removal leaves no
+path from the source to the target.
+
+The number of edges in the cut is the [edge_connectivity](fn.edge_connectivity.html). If
+the graph is already disconnected the cut is empty.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `pair`: the `(source, target)` nodes to separate; if `None` the whole graph is
+  disconnected
+
+# Returns
+
+A sorted `Vec` of `(u, v)` tuples of node names. `u` is on the same side of the cut as
+the source, or as the node with the lowest name when `pair` is `None`. An `Error` with a
+`kind` of `ErrorKind::NodeNotFound` is returned if a node in `pair` doesn't exist, and one
+with a `kind` of `ErrorKind::InvalidArgument` if they are the same node.
+
+# Examples
+
+```
+use graphrs::{algorithms::connectivity, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::new("a", "b"),
+    Edge::new("a", "c"),
+    Edge::new("b", "c"),
+    Edge::new("c", "d"),
+    Edge::new("d", "e"),
+    Edge::new("d", "f"),
+    Edge::new("e", "f"),
+]);
+assert_eq!(connectivity::minimum_edge_cut(&graph, None).unwrap(), vec![("c", "d")]);
+let result = connectivity::minimum_edge_cut(&graph, Some(("a", "c"))).unwrap();
+assert_eq!(result, vec![("a", "b"), ("a", "c")]);
+```
+*/
+pub fn minimum_edge_cut<T, A>(
+    graph: &Graph<T, A>,
+    pair: Option<(T, T)>,
+) -> Result<Vec<(T, T)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let connectivity_graph = ConnectivityGraph::new(graph);
+    let (s, t) = match pair {
+        None => match connectivity_graph.get_min_edge_separation().1 {
+            None => return Ok(vec![]),
+            Some(pair) => pair,
+        },
+        Some((source, target)) => connectivity_graph.get_pair_indexes(source, target)?,
+    };
+    let (_flow, network) = connectivity_graph.get_edge_flow(s, t);
+    let reachable = network.get_reachable(s as usize);
+    let names = connectivity_graph.csr.get_node_names();
+    let mut cut = vec![];
+    for (u, v) in connectivity_graph.edges.iter() {
+        let (u, v) = (*u as usize, *v as usize);
+        if reachable[u] && !reachable[v] {
+            cut.push((names[u].clone(), names[v].clone()));
+        } else if !connectivity_graph.csr.is_directed() && reachable[v] && !reachable[u] {
+            cut.push((names[v].clone(), names[u].clone()));
+        }
+    }
+    cut.sort();
+    Ok(cut)
+}
+
+/// The adjacency of a graph, without self-loops, for finding its connectivity.
+struct ConnectivityGraph<T> {
+    csr: CsrGraph<T>,
+    /// the successors (or neighbors) of each node, in ascending order
+    successors: Vec<Vec<u32>>,
+    /// the predecessors (or neighbors) of each node, in ascending order
+    predecessors: Vec<Vec<u32>>,
+    /// every edge, including each of multiple edges between the same nodes
+    edges: Vec<(u32, u32)>,
+}
+
+impl<T> ConnectivityGraph<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    fn new<A>(graph: &Graph<T, A>) -> Self
+    where
+        A: Clone + Send + Sync,
+    {
+        let csr = graph.to_csr();
+        let n = csr.number_of_nodes();
+        let successors: Vec<Vec<u32>> = (0..n as u32)
+            .map(|v| {
+                csr.get_successors_or_neighbors(v).iter().copied().filter(|u| *u != v).collect()
+            })
+            .collect();
+        let mut predecessors = vec![vec![]; n];
+        for (u, u_successors) in successors.iter().enumerate() {
+            for v in u_successors.iter() {
+                predecessors[*v as usize].push(u as u32);
+            }
+        }
+        let edges = graph
+            .get_all_edges()
+            .into_iter()
+            .filter(|e| e.u != e.v)
+            .map(|e| {
+                (
+                    csr.get_node_index(&e.u).unwrap(),
+                    csr.get_node_index(&e.v).unwrap(),
+                )
+            })
+            .collect();
+        ConnectivityGraph {
+            csr,
+            successors,
+            predecessors,
+            edges,
+        }
+    }
+
+    /// Returns the indexes of `source` and `target`, or an `Err` if they aren't valid.
+    fn get_pair_indexes(&self, source: T, target: T) -> Result<(u32, u32), Error> {
+        let indexes = self.csr.get_node_indexes(&[source.clone(), target])?;
+        if indexes[0] == indexes[1] {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!("The source and target were both '{}'.", source),
+            });
+        }
+        Ok((indexes[0], indexes[1]))
+    }
+
+    /// Returns `true` if there is an edge from `u` to `v`.
+    fn is_adjacent(&self, u: u32, v: u32) -> bool {
+        self.successors[u as usize].binary_search(&v).is_ok()
+    }
+
+    /**
+    Returns the smallest set of successors or predecessors of any node. Removing them
+    cuts the node off from the rest of the graph, so the node connectivity is at most
+    their number.
+    */
+    fn get_min_degree_neighbors(&self) -> &[u32] {
+        self.successors
+            .iter()
+            .chain(self.predecessors.iter())
+            .min_by_key(|neighbors| neighbors.len())
+            .map(|neighbors| neighbors.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /**
+    Finds the node connectivity with Even's algorithm. If `k` is the node connectivity,
+    one of the first `k + 1` nodes isn't in a minimum node cut, so the local node
+    connectivity between it and some other non-adjacent node is `k`. Returns the node
+    connectivity and the pair of nodes that has it, or `None` if no pair has a lower
+    local node connectivity than the smallest degree.
+    */
+    fn get_min_node_separation(&self) -> (usize, Option<(u32, u32)>) {
+        let n = self.csr.number_of_nodes() as u32;
+        let mut best = (self.get_min_degree_neighbors().len(), None);
+        let mut i = 0;
+        while (i as usize) <= best.0 && i < n {
+            for j in (i + 1)..n {
+                let pairs = match self.csr.is_directed() {
+                    true => vec![(i, j), (j, i)],
+                    false => vec![(i, j)],
+                };
+                for (s, t) in pairs {
+                    if self.is_adjacent(s, t) {
+                        continue;
+                    }
+                    let (flow, _network) = self.get_node_flow(s, t);
+                    if flow < best.0 {
+                        best = (flow, Some((s, t)));
+                    }
+                }
+            }
+            i += 1;
+        }
+        best
+    }
+
+    /**
+    Finds the edge connectivity: the first node is on one side of a minimum edge cut, so
+    the local edge connectivity between it and some other node is the edge connectivity.
+    Returns the edge connectivity and the pair of nodes that has it, or `None` if the
+    graph has fewer than two nodes.
+    */
+    fn get_min_edge_separation(&self) -> (usize, Option<(u32, u32)>) {
+        let n = self.csr.number_of_nodes() as u32;
+        let mut best = (0, None);
+        for v in 1..n {
+            let pairs = match self.csr.is_directed() {
+                true => vec![(0, v), (v, 0)],
+                false => vec![(0, v)],
+            };
+            for (s, t) in pairs {
+                let (flow, _network) = self.get_edge_flow(s, t);
+                if best.1.is_none() || flow < best.0 {
+                    best = (flow, Some((s, t)));
+                }
+            }
+        }
+        best
+    }
+
+    /**
+    Finds the maximum flow from `s` to `t` in a network where each node `v` is split into
+    an in-node `2v` and an out-node `2v + 1`, joined by an arc with a capacity of one, so
+    that the flow is the local node connectivity. The arcs for the edges have a capacity
+    larger than any node cut, so a minimum cut only contains arcs between in-nodes and
+    out-nodes, apart from an edge from `s` to `t`, which is a single path. Returns the flow
+    and the network.
+    */
+    fn get_node_flow(&self, s: u32, t: u32) -> (usize, FlowNetwork) {
+        let n = self.csr.number_of_nodes();
+        let mut network = FlowNetwork::new(2 * n);
+        for v in 0..n {
+            network.add_arc(2 * v, 2 * v + 1, 1.0, 0.0);
+        }
+        for (u, u_successors) in self.successors.iter().enumerate() {
+            for v in u_successors.iter() {
+                let capacity = match (u as u32, *v) == (s, t) {
+                    true => 1.0,
+                    false => n as f64,
+                };
+                network.add_arc(2 * u + 1, 2 * *v as usize, capacity, 0.0);
+            }
+        }
+        let flow = network.dinic(2 * s as usize + 1, 2 * t as usize);
+        (flow as usize, network)
+    }
+
+    /// Returns the nodes of a minimum node cut between the non-adjacent nodes `s` and `t`.
+    fn get_node_cut(&self, s: u32, t: u32) -> Vec<u32> {
+        let (_flow, network) = self.get_node_flow(s, t);
+        let reachable = network.get_reachable(2 * s as usize + 1);
+        // the cut is the nodes whose in-node can be reached but whose out-node can't
+        (0..self.csr.number_of_nodes() as u32)
+            .filter(|v| reachable[2 * *v as usize] && !reachable[2 * *v as usize + 1])
+            .collect()
+    }
+
+    /**
+    Finds the maximum flow from `s` to `t` in a network where every edge has a capacity
+    of one, so that the flow is the local edge connectivity. Returns the flow and the
+    network.
+    */
+    fn get_edge_flow(&self, s: u32, t: u32) -> (usize, FlowNetwork) {
+        let mut network = FlowNetwork::new(self.csr.number_of_nodes());
+        let reverse_capacity = match self.csr.is_directed() {
+            true => 0.0,
+            false => 1.0,
+        };
+        for (u, v) in self.edges.iter() {
+            network.add_arc(*u as usize, *v as usize, 1.0, reverse_capacity);
+        }
+        let flow = network.dinic(s as usize, t as usize);
+        (flow as usize, network)
+    }
+}
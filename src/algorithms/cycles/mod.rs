@@ -0,0 +1,370 @@
+use crate::algorithms::traversal::dfs::{self, DfsEdgeType};
+use crate::{Error, Graph};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+An iterator over the simple cycles of a directed graph.
+Created by [simple_cycles](./fn.simple_cycles.html).
+*/
+pub struct SimpleCyclesIter<T> {
+    names: Vec<T>,
+    successors: Vec<Vec<u32>>,
+    predecessors: Vec<Vec<u32>>,
+    /// the next node to find the cycles starting from
+    next_start: u32,
+    search: Option<CycleSearch>,
+    // per-node state, which is reset for the nodes of each search's component
+    in_component: Vec<bool>,
+    blocked: Vec<bool>,
+    closed: Vec<bool>,
+    blocked_by: Vec<HashSet<u32>>,
+}
+
+impl<T> Iterator for SimpleCyclesIter<T>
+where
+    T: Clone,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.search.is_none() {
+                if self.next_start as usize >= self.names.len() {
+                    return None;
+                }
+                self.start_search(self.next_start);
+                self.next_start += 1;
+            }
+            if let Some(cycle) = self.continue_search() {
+                return Some(cycle.iter().map(|i| self.names[*i as usize].clone()).collect());
+            }
+        }
+    }
+}
+
+impl<T> SimpleCyclesIter<T> {
+    /**
+    Starts the search for the cycles whose lowest node is `start`. They are all in the
+    strongly connected component of `start` in the subgraph of nodes no lower than `start`.
+    */
+    fn start_search(&mut self, start: u32) {
+        let forward = get_reachable(start, &self.successors, |v| v >= start);
+        for v in forward.iter() {
+            self.in_component[*v as usize] = true;
+        }
+        let in_forward = &self.in_component;
+        let component = get_reachable(start, &self.predecessors, |v| in_forward[v as usize]);
+        for v in forward.iter() {
+            self.in_component[*v as usize] = false;
+        }
+        for v in component.iter() {
+            self.in_component[*v as usize] = true;
+        }
+        self.blocked[start as usize] = true;
+        self.search = Some(CycleSearch {
+            start,
+            component,
+            path: vec![start],
+            stack: vec![(start, 0)],
+        });
+    }
+
+    /**
+    Continues Johnson's search until the next cycle is found, or the search from the
+    current start node is finished.
+    */
+    fn continue_search(&mut self) -> Option<Vec<u32>> {
+        let search = self.search.as_mut().unwrap();
+        while let Some((u, index)) = search.stack.last_mut() {
+            let u = *u;
+            if let Some(v) = self.successors[u as usize].get(*index).copied() {
+                *index += 1;
+                if !self.in_component[v as usize] {
+                    continue;
+                }
+                if v == search.start {
+                    for w in search.path.iter() {
+                        self.closed[*w as usize] = true;
+                    }
+                    return Some(search.path.clone());
+                }
+                if !self.blocked[v as usize] {
+                    search.path.push(v);
+                    search.stack.push((v, 0));
+                    self.closed[v as usize] = false;
+                    self.blocked[v as usize] = true;
+                }
+                continue;
+            }
+            // all the successors of `u` have been explored
+            if self.closed[u as usize] {
+                unblock(u, &mut self.blocked, &mut self.blocked_by);
+            } else {
+                for v in self.successors[u as usize].iter() {
+                    if self.in_component[*v as usize] {
+                        self.blocked_by[*v as usize].insert(u);
+                    }
+                }
+            }
+            search.stack.pop();
+            search.path.pop();
+        }
+        for v in search.component.iter() {
+            let v = *v as usize;
+            self.in_component[v] = false;
+            self.blocked[v] = false;
+            self.closed[v] = false;
+            self.blocked_by[v].clear();
+        }
+        self.search = None;
+        None
+    }
+}
+
+/**
+Finds the simple cycles of a directed graph: the closed paths that don't visit any node
+more than once. Self-loops are cycles with one node.
+
+Uses Johnson's algorithm. The cycles are found as the iterator is advanced, so they
+don't all need to be held in memory at once; there can be exponentially many of them.
+Each cycle is a `Vec` of node names that starts with its lowest node; the last node has
+an edge to the first.
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned if the graph is undirected.
+
+# Examples
+
+```
+use graphrs::{algorithms::cycles, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n1"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n1"),
+]);
+let cycles: Vec<Vec<&str>> = cycles::simple_cycles(&graph).unwrap().collect();
+assert_eq!(cycles, vec![vec!["n1", "n2"], vec!["n1", "n2", "n3"]]);
+```
+
+# References
+
+1. D. B. Johnson, Finding all the elementary circuits of a directed graph,
+   SIAM Journal on Computing, 4(1), 77-84, 1975.
+*/
+pub fn simple_cycles<T, A>(graph: &Graph<T, A>) -> Result<SimpleCyclesIter<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    let csr = graph.to_csr();
+    let n = csr.number_of_nodes();
+    let successors: Vec<Vec<u32>> =
+        (0..n as u32).map(|u| csr.get_successors_or_neighbors(u).to_vec()).collect();
+    let mut predecessors: Vec<Vec<u32>> = vec![vec![]; n];
+    for (u, vs) in successors.iter().enumerate() {
+        for v in vs {
+            predecessors[*v as usize].push(u as u32);
+        }
+    }
+    Ok(SimpleCyclesIter {
+        names: csr.get_node_names().to_vec(),
+        successors,
+        predecessors,
+        next_start: 0,
+        search: None,
+        in_component: vec![false; n],
+        blocked: vec![false; n],
+        closed: vec![false; n],
+        blocked_by: vec![HashSet::new(); n],
+    })
+}
+
+/**
+Uses a depth-first search to find a cycle in the graph. For an undirected graph the
+cycle must have at least three nodes, unless it is a self-loop.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `source`: the node to start the search from. If `None` every node is searched from,
+  in sorted order, until a cycle is found.
+
+# Returns
+
+The nodes of the cycle, starting from the one discovered first by the search; the last
+node has an edge to the first. `None` is returned if no cycle was found. An `Error`
+is returned if `source` is not in the graph.
+
+# Examples
+
+```
+use graphrs::{algorithms::cycles, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n4"),
+    Edge::new("n4", "n2"),
+]);
+let result = cycles::find_cycle(&graph, Some("n1")).unwrap();
+assert_eq!(result, Some(vec!["n2", "n3", "n4"]));
+```
+*/
+pub fn find_cycle<T, A>(graph: &Graph<T, A>, source: Option<T>) -> Result<Option<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let result = dfs::depth_first_search(graph, source)?;
+    let (u, v) = match result.edges.iter().find(|e| e.2 == DfsEdgeType::Back) {
+        None => return Ok(None),
+        Some((u, v, _)) => (u, v),
+    };
+    // the back edge goes from `u` to its ancestor `v`
+    let mut cycle = vec![u.clone()];
+    while cycle.last().unwrap() != v {
+        cycle.push(result.parents.get(cycle.last().unwrap()).unwrap().clone());
+    }
+    cycle.reverse();
+    Ok(Some(cycle))
+}
+
+/**
+Finds a cycle basis of an undirected graph: a minimal set of cycles from which every
+cycle in the graph can be formed, by combining their edges with symmetric differences.
+
+A spanning tree is grown from a root node of each connected component and each edge
+not in the tree gives one cycle. The roots and the neighbors of each node are visited
+in sorted order, so the result is deterministic.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `root`: the node to grow the first spanning tree from; if `None` the lowest node is used
+
+# Returns
+
+The cycles, each a `Vec` of node names where the last node has an edge to the first.
+An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned if the graph is
+directed, and one with a `kind` of `ErrorKind::NodeNotFound` if `root` is not in the graph.
+
+# Examples
+
+```
+use graphrs::{algorithms::cycles, generators};
+let graph = generators::classic::grid_2d_graph(3, 3, false);
+let basis = cycles::cycle_basis(&graph, None).unwrap();
+assert_eq!(basis.len(), 4);
+```
+
+# References
+
+1. K. Paton, An algorithm for finding a fundamental set of cycles of a graph,
+   Communications of the ACM, 12(9), 514-518, 1969.
+*/
+pub fn cycle_basis<T, A>(graph: &Graph<T, A>, root: Option<T>) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let csr = graph.to_csr();
+    let n = csr.number_of_nodes() as u32;
+    let mut roots = match root {
+        None => vec![],
+        Some(r) => csr.get_node_indexes(&[r])?,
+    };
+    roots.extend(0..n);
+    let mut parents: Vec<Option<u32>> = vec![None; n as usize];
+    // the neighbors each node has been joined to by a tree edge or a cycle found so far
+    let mut used: Vec<Option<HashSet<u32>>> = vec![None; n as usize];
+    let mut cycles = vec![];
+    for root in roots {
+        if used[root as usize].is_some() {
+            continue;
+        }
+        parents[root as usize] = Some(root);
+        used[root as usize] = Some(HashSet::new());
+        let mut stack = vec![root];
+        while let Some(z) = stack.pop() {
+            for nbr in csr.get_successors_or_neighbors(z) {
+                let nbr = *nbr;
+                if used[nbr as usize].is_none() {
+                    parents[nbr as usize] = Some(z);
+                    used[nbr as usize] = Some(vec![z].into_iter().collect());
+                    stack.push(nbr);
+                } else if nbr == z {
+                    cycles.push(vec![z]);
+                } else if !used[z as usize].as_ref().unwrap().contains(&nbr) {
+                    // walk up the tree from `z` until reaching a node joined to `nbr`
+                    let nbr_used = used[nbr as usize].as_ref().unwrap();
+                    let mut cycle = vec![nbr, z];
+                    let mut p = parents[z as usize].unwrap();
+                    while !nbr_used.contains(&p) {
+                        cycle.push(p);
+                        p = parents[p as usize].unwrap();
+                    }
+                    cycle.push(p);
+                    cycles.push(cycle);
+                    used[nbr as usize].as_mut().unwrap().insert(z);
+                }
+            }
+        }
+    }
+    Ok(cycles
+        .into_iter()
+        .map(|cycle| cycle.into_iter().map(|i| csr.get_node_name(i).unwrap().clone()).collect())
+        .collect())
+}
+
+/// The state of Johnson's search for the cycles through `start`.
+struct CycleSearch {
+    start: u32,
+    /// the nodes of the strongly connected component being searched
+    component: Vec<u32>,
+    path: Vec<u32>,
+    /// the nodes of `path` and the index of the next of their successors to explore
+    stack: Vec<(u32, usize)>,
+}
+
+/// Returns the nodes that can be reached from `start` via `adjacent`, only passing through `allowed` nodes.
+fn get_reachable<F>(start: u32, adjacent: &[Vec<u32>], allowed: F) -> Vec<u32>
+where
+    F: Fn(u32) -> bool,
+{
+    let mut seen: HashSet<u32> = vec![start].into_iter().collect();
+    let mut reached = vec![start];
+    let mut index = 0;
+    while index < reached.len() {
+        for v in adjacent[reached[index] as usize].iter() {
+            if allowed(*v) && seen.insert(*v) {
+                reached.push(*v);
+            }
+        }
+        index += 1;
+    }
+    reached
+}
+
+/// Unblocks `u`, and the nodes that were blocked because they could only reach `u`.
+fn unblock(u: u32, blocked: &mut [bool], blocked_by: &mut [HashSet<u32>]) {
+    let mut stack = vec![u];
+    while let Some(v) = stack.pop() {
+        if blocked[v as usize] {
+            blocked[v as usize] = false;
+            stack.extend(blocked_by[v as usize].drain());
+        }
+    }
+}
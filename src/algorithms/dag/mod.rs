@@ -0,0 +1,550 @@
+use crate::algorithms::cycles;
+use crate::{Edge, Error, ErrorKind, Graph};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns `true` if the graph is directed and contains no cycles, `false` otherwise.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+
+# Examples
+
+```
+use graphrs::{algorithms::dag, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n2", "n3")]);
+assert!(dag::is_directed_acyclic_graph(&graph));
+
+graph.add_edge(Edge::new("n3", "n1"));
+assert!(!dag::is_directed_acyclic_graph(&graph));
+```
+*/
+pub fn is_directed_acyclic_graph<T, A>(graph: &Graph<T, A>) -> bool
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    graph.specs.directed && topological_sort(graph).is_ok()
+}
+
+/**
+Returns the nodes of a directed acyclic graph in topological order: for every edge
+(`u`, `v`), `u` comes before `v`. When there is a choice the smallest node is placed
+first, so the order is deterministic.
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+An `Error` with a `kind` of `ErrorKind::GraphContainsCycle` is returned if the graph
+contains a cycle; the nodes of the cycle are listed in the error's `message`.
+
+# Examples
+
+```
+use graphrs::{algorithms::dag, Edge, ErrorKind, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("shirt", "tie"),
+    Edge::new("tie", "jacket"),
+    Edge::new("trousers", "shoes"),
+    Edge::new("trousers", "belt"),
+    Edge::new("belt", "jacket"),
+    Edge::new("shirt", "belt"),
+]);
+let order = dag::topological_sort(&graph).unwrap();
+assert_eq!(order, vec!["shirt", "tie", "trousers", "belt", "jacket", "shoes"]);
+
+graph.add_edge(Edge::new("jacket", "shirt"));
+let error = dag::topological_sort(&graph).unwrap_err();
+assert!(matches!(error.kind, ErrorKind::GraphContainsCycle));
+assert_eq!(error.message, "The graph contains a cycle: [belt, jacket, shirt].");
+```
+
+# References
+
+1. A. B. Kahn. Topological sorting of large networks. Communications of the ACM,
+   5(11):558–562, 1962.
+*/
+pub fn topological_sort<T, A>(graph: &Graph<T, A>) -> Result<Vec<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    if !graph.specs.directed {
+        return Err(Error {
+            kind: ErrorKind::WrongMethod,
+            message: "Topological sort is not defined for undirected graphs.".to_string(),
+        });
+    }
+
+    let successors = graph.get_successors_map();
+    let mut in_degrees: HashMap<&T, usize> =
+        graph.get_all_node_names().into_iter().map(|n| (n, 0)).collect();
+    for targets in successors.values() {
+        for v in targets {
+            *in_degrees.get_mut(v).unwrap() += 1;
+        }
+    }
+
+    let mut ready: BTreeSet<&T> =
+        in_degrees.iter().filter(|(_n, d)| **d == 0).map(|(n, _d)| *n).collect();
+    let mut order = Vec::with_capacity(in_degrees.len());
+    while let Some(u) = ready.iter().next().cloned() {
+        ready.remove(u);
+        order.push(u.clone());
+        if let Some(targets) = successors.get(u) {
+            for v in targets {
+                let in_degree = in_degrees.get_mut(v).unwrap();
+                *in_degree -= 1;
+                if *in_degree == 0 {
+                    ready.insert(v);
+                }
+            }
+        }
+    }
+
+    if order.len() < in_degrees.len() {
+        return Err(get_cycle_error(graph));
+    }
+
+    Ok(order)
+}
+
+/**
+Returns the nodes of a directed acyclic graph in topological generations: the first
+generation is the nodes without predecessors, and each later generation is the nodes
+whose predecessors are all in earlier generations. No two nodes in the same generation
+are connected by an edge.
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+A `Vec` of generations, each of which is a sorted `Vec` of node names. An `Error` with a
+`kind` of `ErrorKind::GraphContainsCycle` is returned if the graph contains a cycle.
+
+# Examples
+
+```
+use graphrs::{algorithms::dag, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("shirt", "tie"),
+    Edge::new("tie", "jacket"),
+    Edge::new("trousers", "shoes"),
+    Edge::new("trousers", "belt"),
+    Edge::new("belt", "jacket"),
+    Edge::new("shirt", "belt"),
+]);
+let generations = dag::topological_generations(&graph).unwrap();
+assert_eq!(
+    generations,
+    vec![vec!["shirt", "trousers"], vec!["belt", "shoes", "tie"], vec!["jacket"]]
+);
+```
+*/
+pub fn topological_generations<T, A>(graph: &Graph<T, A>) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    let successors = graph.get_successors_map();
+    let mut in_degrees: HashMap<&T, usize> =
+        graph.get_all_node_names().into_iter().map(|n| (n, 0)).collect();
+    for targets in successors.values() {
+        for v in targets {
+            *in_degrees.get_mut(v).unwrap() += 1;
+        }
+    }
+    let mut generation: Vec<&T> =
+        in_degrees.iter().filter(|(_n, d)| **d == 0).map(|(n, _d)| *n).collect();
+    let mut generations = vec![];
+    let mut count = 0;
+    while !generation.is_empty() {
+        let mut next = vec![];
+        for u in generation.iter() {
+            if let Some(targets) = successors.get(*u) {
+                for v in targets {
+                    let in_degree = in_degrees.get_mut(v).unwrap();
+                    *in_degree -= 1;
+                    if *in_degree == 0 {
+                        next.push(v);
+                    }
+                }
+            }
+        }
+        generation.sort();
+        count += generation.len();
+        generations.push(generation.into_iter().cloned().collect());
+        generation = next;
+    }
+    if count < in_degrees.len() {
+        return Err(get_cycle_error(graph));
+    }
+    Ok(generations)
+}
+
+/**
+Returns the ancestors of a node in a directed graph: the nodes that have a path to it.
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance
+* `node_name`: the node whose ancestors are returned
+
+# Returns
+
+A sorted `Vec` of node names, not including `node_name` itself. An `Error` with a `kind`
+of `ErrorKind::NodeNotFound` is returned if `node_name` doesn't exist.
+
+# Examples
+
+```
+use graphrs::{algorithms::dag, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("shirt", "tie"),
+    Edge::new("tie", "jacket"),
+    Edge::new("trousers", "shoes"),
+    Edge::new("trousers", "belt"),
+    Edge::new("belt", "jacket"),
+    Edge::new("shirt", "belt"),
+]);
+let ancestors = dag::ancestors(&graph, "jacket").unwrap();
+assert_eq!(ancestors, vec!["belt", "shirt", "tie", "trousers"]);
+```
+*/
+pub fn ancestors<T, A>(graph: &Graph<T, A>, node_name: T) -> Result<Vec<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    graph.ensure_node(&node_name)?;
+    Ok(get_reachable(graph.get_predecessors_map(), node_name))
+}
+
+/**
+Returns the descendants of a node in a directed graph: the nodes that it has a path to.
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance
+* `node_name`: the node whose descendants are returned
+
+# Returns
+
+A sorted `Vec` of node names, not including `node_name` itself. An `Error` with a `kind`
+of `ErrorKind::NodeNotFound` is returned if `node_name` doesn't exist.
+
+# Examples
+
+```
+use graphrs::{algorithms::dag, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("shirt", "tie"),
+    Edge::new("tie", "jacket"),
+    Edge::new("trousers", "shoes"),
+    Edge::new("trousers", "belt"),
+    Edge::new("belt", "jacket"),
+    Edge::new("shirt", "belt"),
+]);
+let descendants = dag::descendants(&graph, "shirt").unwrap();
+assert_eq!(descendants, vec!["belt", "jacket", "tie"]);
+```
+*/
+pub fn descendants<T, A>(graph: &Graph<T, A>, node_name: T) -> Result<Vec<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    graph.ensure_node(&node_name)?;
+    Ok(get_reachable(graph.get_successors_map(), node_name))
+}
+
+/**
+Finds the longest path in a directed acyclic graph: the path whose edges have the
+largest total weight, or the most edges when `weighted` is `false`. This is the critical
+path when the edges are tasks and their weights are durations.
+
+Nodes are processed in topological order, so the path is found in linear time. Where
+there are multiple edges between the same nodes the one with the largest weight is
+used. When several paths are equally long, the path found first in the order given by
+[topological_sort](fn.topological_sort.html) is returned.
+
+# Arguments
+
+* `graph`: a directed acyclic [Graph](../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights; otherwise every edge has a weight of `1.0`
+
+# Returns
+
+A tuple of the nodes of the path, from first to last, and the total weight of its
+edges. If the graph has no edges the path is a single node and its length is `0.0`; if
+it has no nodes the path is empty. An `Error` with a `kind` of
+`ErrorKind::GraphContainsCycle` is returned if the graph contains a cycle.
+
+# Examples
+
+```
+use graphrs::{algorithms::dag, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("design", "build", 5.0),
+    Edge::with_weight("design", "document", 2.0),
+    Edge::with_weight("build", "test", 3.0),
+    Edge::with_weight("document", "release", 1.0),
+    Edge::with_weight("test", "release", 1.0),
+]);
+let (path, length) = dag::longest_path(&graph, true).unwrap();
+assert_eq!(path, vec!["design", "build", "test", "release"]);
+assert_eq!(length, 9.0);
+```
+*/
+pub fn longest_path<T, A>(graph: &Graph<T, A>, weighted: bool) -> Result<(Vec<T>, f64), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let order = topological_sort(graph)?;
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let csr = graph.to_csr_with_costs(|u, v| match (weighted, graph.specs.multi_edges) {
+        (false, _) => 1.0,
+        (true, false) => graph.get_edge(u.clone(), v.clone()).unwrap().weight,
+        (true, true) => graph
+            .get_edges(u.clone(), v.clone())
+            .unwrap()
+            .iter()
+            .map(|e| e.weight)
+            .fold(f64::NEG_INFINITY, f64::max),
+    });
+    let order: Vec<u32> = order.iter().map(|n| csr.get_node_index(n).unwrap()).collect();
+    // the length of the longest path ending at each node, and the node before it
+    let mut lengths = vec![0.0; order.len()];
+    let mut predecessors: Vec<Option<u32>> = vec![None; order.len()];
+    for u in order.iter() {
+        let successors = csr.get_successors_or_neighbors(*u);
+        for (v, weight) in successors.iter().zip(csr.get_weights(*u)) {
+            let length = lengths[*u as usize] + weight;
+            if length > lengths[*v as usize] {
+                lengths[*v as usize] = length;
+                predecessors[*v as usize] = Some(*u);
+            }
+        }
+    }
+    let mut last = match order.first() {
+        None => return Ok((vec![], 0.0)),
+        Some(first) => *first,
+    };
+    for v in order.iter() {
+        if lengths[*v as usize] > lengths[last as usize] {
+            last = *v;
+        }
+    }
+    let mut path = vec![last];
+    while let Some(u) = predecessors[*path.last().unwrap() as usize] {
+        path.push(u);
+    }
+    let names = csr.get_node_names();
+    Ok((
+        path.into_iter().rev().map(|v| names[v as usize].clone()).collect(),
+        lengths[last as usize],
+    ))
+}
+
+/// Returns the sorted nodes, other than `source`, that can be reached from it in `adjacency`.
+fn get_reachable<T>(adjacency: &HashMap<T, HashSet<T>>, source: T) -> Vec<T>
+where
+    T: Hash + Eq + Clone + Ord,
+{
+    let mut reached: HashSet<&T> = HashSet::new();
+    let mut stack = vec![&source];
+    while let Some(u) = stack.pop() {
+        if let Some(targets) = adjacency.get(u) {
+            for v in targets {
+                if reached.insert(v) {
+                    stack.push(v);
+                }
+            }
+        }
+    }
+    reached.remove(&source);
+    let mut reached: Vec<T> = reached.into_iter().cloned().collect();
+    reached.sort();
+    reached
+}
+
+/// Returns the error for a graph that contains a cycle, listing the nodes of a cycle.
+fn get_cycle_error<T, A>(graph: &Graph<T, A>) -> Error
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let cycle = cycles::find_cycle(graph, None).unwrap().unwrap();
+    Error {
+        kind: ErrorKind::GraphContainsCycle,
+        message: format!(
+            "The graph contains a cycle: [{}].",
+            cycle.iter().map(|n| n.to_string()).collect::<Vec<String>>().join(", ")
+        ),
+    }
+}
+
+/**
+Finds the transitive closure of a directed graph: a graph with the same nodes, where
+there is an edge from `u` to `v` if there is a path from `u` to `v` in the original
+graph. Nodes on a cycle are not linked to themselves.
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+A new `Graph` with the same nodes and specs as `graph`. It contains all the edges of
+`graph`, plus an edge without a weight or attributes for every other path. An `Error`
+with a `kind` of `ErrorKind::WrongMethod` is returned if the graph is undirected.
+
+# Examples
+
+```
+use graphrs::{algorithms::dag, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![Edge::new("a", "b"), Edge::new("b", "c"), Edge::new("c", "d")]);
+let closure = dag::transitive_closure(&graph).unwrap();
+assert_eq!(closure.get_all_edges().len(), 6);
+assert!(closure.get_edge("a", "d").is_ok());
+```
+*/
+pub fn transitive_closure<T, A>(graph: &Graph<T, A>) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    let csr = graph.to_csr();
+    let names = csr.get_node_names();
+    let n = csr.number_of_nodes();
+    let mut edges: Vec<Edge<T, A>> = graph.get_all_edges().into_iter().cloned().collect();
+    let mut reached = vec![usize::MAX; n];
+    for u in 0..n {
+        reached[u] = u;
+        let mut queue = VecDeque::from(vec![u as u32]);
+        while let Some(v) = queue.pop_front() {
+            for w in csr.get_successors_or_neighbors(v) {
+                if reached[*w as usize] != u {
+                    reached[*w as usize] = u;
+                    queue.push_back(*w);
+                    if csr.get_successors_or_neighbors(u as u32).binary_search(w).is_err() {
+                        edges.push(Edge::new(names[u].clone(), names[*w as usize].clone()));
+                    }
+                }
+            }
+        }
+    }
+    let nodes = graph.get_all_nodes().into_iter().cloned().collect();
+    Graph::new_from_nodes_and_edges(nodes, edges, graph.specs.clone())
+}
+
+/**
+Finds the transitive reduction of a directed acyclic graph: the graph with the same
+nodes and the fewest edges that has a path from `u` to `v` whenever the original graph
+does. It is unique, and its edges are the edges (`u`, `v`) of the original graph for
+which there is no other path from `u` to `v`.
+
+# Arguments
+
+* `graph`: a directed acyclic [Graph](../../struct.Graph.html) instance
+
+# Returns
+
+A new `Graph` with the same nodes and specs as `graph`, and the edges of `graph` that
+are kept; where there are multiple edges between the same nodes only the first is kept.
+An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned if the graph is
+undirected, and one with a `kind` of `ErrorKind::GraphContainsCycle` if it contains a
+cycle.
+
+# Examples
+
+```
+use graphrs::{algorithms::dag, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("a", "b"),
+    Edge::new("b", "c"),
+    Edge::new("a", "c"),
+    Edge::new("c", "d"),
+    Edge::new("a", "d"),
+]);
+let reduction = dag::transitive_reduction(&graph).unwrap();
+assert_eq!(reduction.get_all_edges().len(), 3);
+assert!(reduction.get_edge("a", "c").is_err());
+```
+
+# References
+
+1. A. V. Aho, M. R. Garey and J. D. Ullman. The transitive reduction of a directed graph.
+   SIAM Journal on Computing, 1(2):131–137, 1972.
+*/
+pub fn transitive_reduction<T, A>(graph: &Graph<T, A>) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let order = topological_sort(graph)?;
+    let csr = graph.to_csr();
+    let n = csr.number_of_nodes();
+    let words = n.div_ceil(64);
+    // a bit set of the descendants of each node, found in reverse topological order
+    let mut descendants: Vec<Vec<u64>> = vec![vec![0; words]; n];
+    // a bit set, for each node, of the nodes that can be reached through one of its children
+    let mut indirect: Vec<Vec<u64>> = vec![vec![0; words]; n];
+    for name in order.iter().rev() {
+        let u = csr.get_node_index(name).unwrap() as usize;
+        for v in csr.get_successors_or_neighbors(u as u32) {
+            let v = *v as usize;
+            for w in 0..words {
+                let bits = descendants[v][w];
+                indirect[u][w] |= bits;
+                descendants[u][w] |= bits;
+            }
+            descendants[u][v / 64] |= 1 << (v % 64);
+        }
+    }
+    let mut edges: Vec<&Edge<T, A>> = graph.get_all_edges();
+    edges.sort_by(|e1, e2| (&e1.u, &e1.v).cmp(&(&e2.u, &e2.v)));
+    edges.dedup_by(|e2, e1| (&e1.u, &e1.v) == (&e2.u, &e2.v));
+    let edges = edges
+        .into_iter()
+        .filter(|e| {
+            let u = csr.get_node_index(&e.u).unwrap() as usize;
+            let v = csr.get_node_index(&e.v).unwrap() as usize;
+            indirect[u][v / 64] & (1 << (v % 64)) == 0
+        })
+        .cloned()
+        .collect();
+    let nodes = graph.get_all_nodes().into_iter().cloned().collect();
+    Graph::new_from_nodes_and_edges(nodes, edges, graph.specs.clone())
+}
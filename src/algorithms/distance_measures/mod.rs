@@ -0,0 +1,757 @@
+use crate::algorithms::shortest_path::dijkstra;
+use crate::{CsrGraph, Error, ErrorKind, Graph, ParallelOptions};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Computes the eccentricity of each node: the greatest distance from the node to any
+other node.
+
+# Arguments
+
+* `graph`: a connected [Graph](../../struct.Graph.html) instance; a directed graph must be
+  strongly connected
+* `weighted`: set to `true` to use edge weights as distances, or `false` to count edges
+
+# Returns
+
+A `HashMap` of node names to eccentricities. An error is returned if the graph isn't
+connected, because some distances are then infinite.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators};
+let graph = generators::social::karate_club_graph();
+let eccentricities = distance_measures::eccentricity(&graph, false).unwrap();
+assert_eq!(eccentricities.get(&0), Some(&3.0));
+assert_eq!(eccentricities.get(&14), Some(&5.0));
+```
+*/
+pub fn eccentricity<T, A>(graph: &Graph<T, A>, weighted: bool) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    eccentricity_with_options(graph, weighted, &ParallelOptions::default())
+}
+
+/**
+Like [eccentricity](./fn.eccentricity.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a connected [Graph](../../struct.Graph.html) instance; a directed graph must be
+  strongly connected
+* `weighted`: set to `true` to use edge weights as distances, or `false` to count edges
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each node that
+  distances have been found from is a unit of work for its `progress`
+
+# Returns
+
+The same as [eccentricity](./fn.eccentricity.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators, Parallelism, ParallelOptions};
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let eccentricities = distance_measures::eccentricity_with_options(&graph, false, &options);
+assert_eq!(eccentricities.unwrap().get(&0), Some(&3.0));
+```
+*/
+pub fn eccentricity_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    Ok(get_eccentricities(graph, weighted, options)?.into_iter().collect())
+}
+
+/**
+Computes the diameter of the graph: the greatest eccentricity of any node.
+
+# Arguments
+
+* `graph`: a connected [Graph](../../struct.Graph.html) instance; a directed graph must be
+  strongly connected
+* `weighted`: set to `true` to use edge weights as distances, or `false` to count edges
+
+# Returns
+
+An error is returned if the graph has no nodes or isn't connected.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators};
+let graph = generators::social::karate_club_graph();
+assert_eq!(distance_measures::diameter(&graph, false).unwrap(), 5.0);
+```
+*/
+pub fn diameter<T, A>(graph: &Graph<T, A>, weighted: bool) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    diameter_with_options(graph, weighted, &ParallelOptions::default())
+}
+
+/**
+Like [diameter](./fn.diameter.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a connected [Graph](../../struct.Graph.html) instance; a directed graph must be
+  strongly connected
+* `weighted`: set to `true` to use edge weights as distances, or `false` to count edges
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each node that
+  distances have been found from is a unit of work for its `progress`
+
+# Returns
+
+The same as [diameter](./fn.diameter.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators, Parallelism, ParallelOptions};
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let diameter = distance_measures::diameter_with_options(&graph, false, &options);
+assert_eq!(diameter.unwrap(), 5.0);
+```
+*/
+pub fn diameter_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    options: &ParallelOptions,
+) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let eccentricities = get_eccentricities(graph, weighted, options)?;
+    get_extreme_eccentricity(&eccentricities, f64::max)
+}
+
+/**
+Computes the radius of the graph: the smallest eccentricity of any node.
+
+# Arguments
+
+* `graph`: a connected [Graph](../../struct.Graph.html) instance; a directed graph must be
+  strongly connected
+* `weighted`: set to `true` to use edge weights as distances, or `false` to count edges
+
+# Returns
+
+An error is returned if the graph has no nodes or isn't connected.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators};
+let graph = generators::social::karate_club_graph();
+assert_eq!(distance_measures::radius(&graph, false).unwrap(), 3.0);
+```
+*/
+pub fn radius<T, A>(graph: &Graph<T, A>, weighted: bool) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    radius_with_options(graph, weighted, &ParallelOptions::default())
+}
+
+/**
+Like [radius](./fn.radius.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a connected [Graph](../../struct.Graph.html) instance; a directed graph must be
+  strongly connected
+* `weighted`: set to `true` to use edge weights as distances, or `false` to count edges
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each node that
+  distances have been found from is a unit of work for its `progress`
+
+# Returns
+
+The same as [radius](./fn.radius.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators, Parallelism, ParallelOptions};
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let radius = distance_measures::radius_with_options(&graph, false, &options);
+assert_eq!(radius.unwrap(), 3.0);
+```
+*/
+pub fn radius_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    options: &ParallelOptions,
+) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let eccentricities = get_eccentricities(graph, weighted, options)?;
+    get_extreme_eccentricity(&eccentricities, f64::min)
+}
+
+/**
+Finds the center of the graph: the nodes whose eccentricity is equal to the radius.
+
+# Arguments
+
+* `graph`: a connected [Graph](../../struct.Graph.html) instance; a directed graph must be
+  strongly connected
+* `weighted`: set to `true` to use edge weights as distances, or `false` to count edges
+
+# Returns
+
+The names of the center nodes, in sorted order. An error is returned if the graph has no
+nodes or isn't connected.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators};
+let graph = generators::social::karate_club_graph();
+let center = distance_measures::center(&graph, false).unwrap();
+assert_eq!(center, vec![0, 1, 2, 3, 8, 13, 19, 31]);
+```
+*/
+pub fn center<T, A>(graph: &Graph<T, A>, weighted: bool) -> Result<Vec<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    center_with_options(graph, weighted, &ParallelOptions::default())
+}
+
+/**
+Like [center](./fn.center.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a connected [Graph](../../struct.Graph.html) instance; a directed graph must be
+  strongly connected
+* `weighted`: set to `true` to use edge weights as distances, or `false` to count edges
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each node that
+  distances have been found from is a unit of work for its `progress`
+
+# Returns
+
+The same as [center](./fn.center.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators, Parallelism, ParallelOptions};
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let center = distance_measures::center_with_options(&graph, false, &options);
+assert_eq!(center.unwrap(), vec![0, 1, 2, 3, 8, 13, 19, 31]);
+```
+*/
+pub fn center_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    options: &ParallelOptions,
+) -> Result<Vec<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let eccentricities = get_eccentricities(graph, weighted, options)?;
+    let radius = get_extreme_eccentricity(&eccentricities, f64::min)?;
+    Ok(get_nodes_with_eccentricity(eccentricities, radius))
+}
+
+/**
+Finds the periphery of the graph: the nodes whose eccentricity is equal to the diameter.
+
+# Arguments
+
+* `graph`: a connected [Graph](../../struct.Graph.html) instance; a directed graph must be
+  strongly connected
+* `weighted`: set to `true` to use edge weights as distances, or `false` to count edges
+
+# Returns
+
+The names of the periphery nodes, in sorted order. An error is returned if the graph has
+no nodes or isn't connected.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators};
+let graph = generators::social::karate_club_graph();
+let periphery = distance_measures::periphery(&graph, false).unwrap();
+assert_eq!(periphery, vec![14, 15, 16, 18, 20, 22, 23, 26, 29]);
+```
+*/
+pub fn periphery<T, A>(graph: &Graph<T, A>, weighted: bool) -> Result<Vec<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    periphery_with_options(graph, weighted, &ParallelOptions::default())
+}
+
+/**
+Like [periphery](./fn.periphery.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a connected [Graph](../../struct.Graph.html) instance; a directed graph must be
+  strongly connected
+* `weighted`: set to `true` to use edge weights as distances, or `false` to count edges
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each node that
+  distances have been found from is a unit of work for its `progress`
+
+# Returns
+
+The same as [periphery](./fn.periphery.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators, Parallelism, ParallelOptions};
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let periphery = distance_measures::periphery_with_options(&graph, false, &options);
+assert_eq!(periphery.unwrap(), vec![14, 15, 16, 18, 20, 22, 23, 26, 29]);
+```
+*/
+pub fn periphery_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    options: &ParallelOptions,
+) -> Result<Vec<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let eccentricities = get_eccentricities(graph, weighted, options)?;
+    let diameter = get_extreme_eccentricity(&eccentricities, f64::max)?;
+    Ok(get_nodes_with_eccentricity(eccentricities, diameter))
+}
+
+/**
+Computes the average shortest path length: the sum of the distances between every
+ordered pair of distinct nodes, divided by the number of such pairs.
+
+# Arguments
+
+* `graph`: a connected [Graph](../../struct.Graph.html) instance; a directed graph must be
+  strongly connected
+* `weighted`: set to `true` to use edge weights as distances, or `false` to count edges
+
+# Returns
+
+The average shortest path length, which is `0.0` for a graph with a single node. An error
+is returned if the graph has no nodes or isn't connected.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators};
+let graph = generators::social::karate_club_graph();
+let average = distance_measures::average_shortest_path_length(&graph, false).unwrap();
+assert!((average - 2.4082).abs() < 1.0e-4);
+```
+*/
+pub fn average_shortest_path_length<T, A>(graph: &Graph<T, A>, weighted: bool) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    average_shortest_path_length_with_options(graph, weighted, &ParallelOptions::default())
+}
+
+/**
+Like [average_shortest_path_length](./fn.average_shortest_path_length.html), with the
+threads and [ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a connected [Graph](../../struct.Graph.html) instance; a directed graph must be
+  strongly connected
+* `weighted`: set to `true` to use edge weights as distances, or `false` to count edges
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each node that
+  distances have been found from is a unit of work for its `progress`
+
+# Returns
+
+The same as [average_shortest_path_length](./fn.average_shortest_path_length.html). An
+`Error` with a `kind` of `ErrorKind::Cancelled` is returned if `options.progress` was
+cancelled, and one with a `kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't
+be built for `options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators, Parallelism, ParallelOptions};
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let average =
+    distance_measures::average_shortest_path_length_with_options(&graph, false, &options);
+assert!((average.unwrap() - 2.4082).abs() < 1.0e-4);
+```
+*/
+pub fn average_shortest_path_length_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    options: &ParallelOptions,
+) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let totals = map_source_distances(graph, weighted, options, |distances| {
+        distances.values().sum::<f64>()
+    })?;
+    let num_nodes = totals.len();
+    match num_nodes {
+        0 => Err(get_no_nodes_error()),
+        1 => Ok(0.0),
+        _ => {
+            let total: f64 = totals.into_iter().map(|(_, t)| t).sum();
+            Ok(total / (num_nodes * (num_nodes - 1)) as f64)
+        }
+    }
+}
+
+/**
+Computes lower and upper bounds for the diameter of an undirected, unweighted graph, using
+far fewer breadth-first searches than [diameter](./fn.diameter.html) on most real-world
+graphs.
+
+A "double sweep" finds a node `a` that is far from a high-degree node, and then a node `b`
+that is as far as possible from `a`; the distance between them is a lower bound for the
+diameter. The iFUB ("iterative Fringe Upper Bound") algorithm then searches from the
+nodes that are furthest from the middle of the `a` to `b` path, a level at a time, raising
+the lower bound and lowering the upper bound until they meet.
+
+# Arguments
+
+* `graph`: a connected, undirected [Graph](../../struct.Graph.html) instance; edge weights
+  are ignored
+* `max_searches`: the greatest number of breadth-first searches to run before returning
+  the bounds that have been found; the first three are always run. Use `None` to keep
+  searching until the exact diameter is known.
+
+# Returns
+
+A tuple with the lower and upper bounds for the diameter; they are equal if the exact
+diameter was found. An error is returned if the graph is directed, has no nodes or isn't
+connected.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators};
+let graph = generators::social::karate_club_graph();
+let (lower, upper) = distance_measures::diameter_approx(&graph, None).unwrap();
+assert_eq!((lower, upper), (5.0, 5.0));
+let (lower, upper) = distance_measures::diameter_approx(&graph, Some(3)).unwrap();
+assert!(lower <= 5.0 && upper >= 5.0);
+```
+
+# References
+
+1. P. Crescenzi, R. Grossi, M. Habib, L. Lanzi and A. Marino. On computing the diameter
+   of real-world undirected graphs. Theoretical Computer Science 514:84–95, 2013.
+*/
+pub fn diameter_approx<T, A>(
+    graph: &Graph<T, A>,
+    max_searches: Option<usize>,
+) -> Result<(f64, f64), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    diameter_approx_with_options(graph, max_searches, &ParallelOptions::default())
+}
+
+/**
+Like [diameter_approx](./fn.diameter_approx.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a connected, undirected [Graph](../../struct.Graph.html) instance; edge weights
+  are ignored
+* `max_searches`: the greatest number of breadth-first searches to run before returning
+  the bounds that have been found; the first three are always run. Use `None` to keep
+  searching until the exact diameter is known.
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each breadth-first
+  search from a fringe node is a unit of work for its `progress`, whose total isn't known
+
+# Returns
+
+The same as [diameter_approx](./fn.diameter_approx.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::distance_measures, generators, Parallelism, ParallelOptions};
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let bounds = distance_measures::diameter_approx_with_options(&graph, None, &options);
+assert_eq!(bounds.unwrap(), (5.0, 5.0));
+```
+*/
+pub fn diameter_approx_with_options<T, A>(
+    graph: &Graph<T, A>,
+    max_searches: Option<usize>,
+    options: &ParallelOptions,
+) -> Result<(f64, f64), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let csr = graph.to_csr();
+    let num_nodes = csr.number_of_nodes();
+    if num_nodes == 0 {
+        return Err(get_no_nodes_error());
+    }
+
+    // double sweep, starting from a node with the highest degree
+    let start = (0..num_nodes as u32)
+        .max_by_key(|i| {
+            (
+                csr.get_successors_or_neighbors(*i).len(),
+                std::cmp::Reverse(*i),
+            )
+        })
+        .unwrap();
+    let (start_distances, _) = breadth_first_distances(&csr, start);
+    if start_distances.contains(&usize::MAX) {
+        return Err(get_not_connected_error(false));
+    }
+    let a = get_furthest_node(&start_distances);
+    let (a_distances, a_parents) = breadth_first_distances(&csr, a);
+    let b = get_furthest_node(&a_distances);
+    let mut lower = a_distances[b as usize];
+
+    // the middle of the path from a to b
+    let mut middle = b;
+    for _ in 0..lower / 2 {
+        middle = a_parents[middle as usize];
+    }
+    let (middle_distances, _) = breadth_first_distances(&csr, middle);
+    let mut level = *middle_distances.iter().max().unwrap();
+    lower = lower.max(level);
+    let mut upper = 2 * level;
+    let mut remaining_searches = max_searches.map(|m| m.saturating_sub(3));
+    let progress = &options.progress;
+    progress.start(None)?;
+
+    while upper > lower && level > 0 {
+        let fringe: Vec<u32> =
+            (0..num_nodes as u32).filter(|i| middle_distances[*i as usize] == level).collect();
+        let searched = match remaining_searches {
+            None => fringe.len(),
+            Some(remaining) => remaining.min(fringe.len()),
+        };
+        let fringe_eccentricity = options.map_reduce(
+            searched,
+            |index| {
+                progress.check()?;
+                let eccentricity =
+                    *breadth_first_distances(&csr, fringe[index]).0.iter().max().unwrap();
+                progress.advance();
+                Ok(eccentricity)
+            },
+            || Ok(0),
+            |e1: Result<usize, Error>, e2| Ok(e1?.max(e2?)),
+        )??;
+        lower = lower.max(fringe_eccentricity);
+        if searched < fringe.len() {
+            break;
+        }
+        if let Some(remaining) = remaining_searches.as_mut() {
+            *remaining -= searched;
+        }
+        // the nodes that are closer to the middle are all within 2 * (level - 1) of each other
+        upper = lower.max(2 * (level - 1)).min(upper);
+        level -= 1;
+    }
+
+    Ok((lower as f64, upper as f64))
+}
+
+/**
+Returns the number of edges on the shortest path from `source` to each node, which is
+`usize::MAX` for nodes that can't be reached, and the node before each node on one of those
+paths.
+*/
+fn breadth_first_distances<T, W>(csr: &CsrGraph<T, W>, source: u32) -> (Vec<usize>, Vec<u32>)
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Copy + PartialOrd,
+{
+    let num_nodes = csr.number_of_nodes();
+    let mut distances = vec![usize::MAX; num_nodes];
+    let mut parents: Vec<u32> = (0..num_nodes as u32).collect();
+    let mut queue = VecDeque::from(vec![source]);
+    distances[source as usize] = 0;
+    while let Some(v) = queue.pop_front() {
+        for u in csr.get_successors_or_neighbors(v) {
+            if distances[*u as usize] == usize::MAX {
+                distances[*u as usize] = distances[v as usize] + 1;
+                parents[*u as usize] = v;
+                queue.push_back(*u);
+            }
+        }
+    }
+    (distances, parents)
+}
+
+/// Returns the first of the nodes with the greatest distance.
+fn get_furthest_node(distances: &[usize]) -> u32 {
+    let max = distances.iter().max().unwrap();
+    distances.iter().position(|d| d == max).unwrap() as u32
+}
+
+/// Returns the eccentricity of each node, in the order of the node names.
+fn get_eccentricities<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    options: &ParallelOptions,
+) -> Result<Vec<(T, f64)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    map_source_distances(graph, weighted, options, |distances| {
+        distances.values().cloned().fold(0.0, f64::max)
+    })
+}
+
+/**
+Computes the distances from every node to every other node with the threads of `options`,
+and reduces the distances from each node with `f` so that only one search's distances are
+held in memory by each thread. Returns the results in the order of the node names, or an
+error if any node can't reach every other node.
+*/
+fn map_source_distances<T, A, R, F>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    options: &ParallelOptions,
+    f: F,
+) -> Result<Vec<(T, R)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+    R: Send,
+    F: Fn(&HashMap<T, f64>) -> R + Sync,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let csr = graph.to_csr();
+    let num_nodes = csr.number_of_nodes();
+    let names = csr.get_node_names();
+    let results = options.map_collect_with_progress(names.len(), |index| {
+        let source = &names[index];
+        let distances =
+            dijkstra::single_source_distances_csr(&csr, weighted, source.clone(), None, None)?;
+        if distances.len() < num_nodes {
+            return Err(get_not_connected_error(graph.specs.directed));
+        }
+        Ok((source.clone(), f(&distances)))
+    })?;
+    results.into_iter().collect()
+}
+
+/// Returns the maximum or minimum eccentricity, depending on `pick`.
+fn get_extreme_eccentricity<T>(
+    eccentricities: &[(T, f64)],
+    pick: fn(f64, f64) -> f64,
+) -> Result<f64, Error> {
+    eccentricities.iter().map(|(_, e)| *e).reduce(pick).ok_or_else(get_no_nodes_error)
+}
+
+/// Returns the names of the nodes whose eccentricity is `value`.
+fn get_nodes_with_eccentricity<T>(eccentricities: Vec<(T, f64)>, value: f64) -> Vec<T> {
+    eccentricities.into_iter().filter(|(_, e)| *e == value).map(|(n, _)| n).collect()
+}
+
+fn get_no_nodes_error() -> Error {
+    Error {
+        kind: ErrorKind::InvalidArgument,
+        message: "The graph has no nodes.".to_string(),
+    }
+}
+
+fn get_not_connected_error(directed: bool) -> Error {
+    let connected = match directed {
+        true => "strongly connected",
+        false => "connected",
+    };
+    Error {
+        kind: ErrorKind::GraphNotConnected,
+        message: format!(
+            "Found an infinite path length because the graph is not {}.",
+            connected
+        ),
+    }
+}
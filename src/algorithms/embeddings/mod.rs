@@ -0,0 +1,320 @@
+use crate::algorithms::community::louvain::get_rng;
+use crate::algorithms::random_walk::{check_bias, generate_index_walks, get_walk_csr};
+use crate::{Error, ErrorKind, Graph, ParallelOptions};
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Options for learning node embeddings with [node2vec](fn.node2vec.html) and
+[deepwalk](fn.deepwalk.html).
+
+# Examples
+
+```
+use graphrs::algorithms::embeddings::EmbeddingOptions;
+
+let options = EmbeddingOptions {
+    dimensions: 32,
+    p: 0.5,
+    q: 2.0,
+    seed: Some(1),
+    ..EmbeddingOptions::default()
+};
+```
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmbeddingOptions {
+    /// The number of values in each node's embedding.
+    pub dimensions: usize,
+    /// The maximum number of nodes in each random walk, including the start node.
+    pub walk_length: usize,
+    /// The number of random walks that start at each node.
+    pub walks_per_node: usize,
+    /// The number of nodes either side of a node in a walk that are treated as its context.
+    pub window: usize,
+    /// The number of passes the skip-gram trainer makes over the walks.
+    pub epochs: usize,
+    /// The number of negative samples drawn for each (node, context) pair.
+    pub negative_samples: usize,
+    /// The initial learning rate, which decays linearly towards zero during training.
+    pub learning_rate: f32,
+    /// The node2vec return parameter; ignored by [deepwalk](fn.deepwalk.html).
+    pub p: f64,
+    /// The node2vec in-out parameter; ignored by [deepwalk](fn.deepwalk.html).
+    pub q: f64,
+    /// If `true` the random walks choose each step with a probability proportional to the
+    /// edge weight.
+    pub weighted: bool,
+    /// A seed for the random number generator.
+    pub seed: Option<u64>,
+}
+
+impl Default for EmbeddingOptions {
+    /// Returns `EmbeddingOptions` with 64 dimensions and ten unweighted walks of length 80
+    /// per node, trained for one epoch with a window of 5 and 5 negative samples.
+    fn default() -> Self {
+        EmbeddingOptions {
+            dimensions: 64,
+            walk_length: 80,
+            walks_per_node: 10,
+            window: 5,
+            epochs: 1,
+            negative_samples: 5,
+            learning_rate: 0.025,
+            p: 1.0,
+            q: 1.0,
+            weighted: false,
+            seed: None,
+        }
+    }
+}
+
+/**
+Learns node embeddings with node2vec: biased random walks, generated by
+[biased_walks](../random_walk/fn.biased_walks.html) using the `p` and `q` options, are
+treated as sentences and fed to a skip-gram model trained with negative sampling. Nodes
+that appear near each other in the walks end up with similar embeddings.
+
+Training is sequential so that, given a `seed`, the embeddings are reproducible.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `options`: the [EmbeddingOptions](struct.EmbeddingOptions.html) to use
+
+# Returns
+
+A `HashMap` of each node's embedding. An `Error` with a `kind` of `ErrorKind::InvalidArgument`
+is returned if `dimensions`, `window` or `learning_rate` isn't positive, if `p` or `q` isn't
+positive, or if `weighted` is `true` and an edge has a negative weight.
+
+# Examples
+
+```
+use graphrs::{algorithms::embeddings::{self, EmbeddingOptions}, generators};
+
+let graph = generators::social::karate_club_graph();
+let options = EmbeddingOptions {
+    dimensions: 16,
+    walk_length: 20,
+    p: 0.5,
+    q: 2.0,
+    seed: Some(1),
+    ..EmbeddingOptions::default()
+};
+let result = embeddings::node2vec(&graph, &options).unwrap();
+assert_eq!(result.len(), 34);
+assert_eq!(result[&0].len(), 16);
+```
+
+# References
+
+1. A. Grover and J. Leskovec. node2vec: Scalable Feature Learning for Networks.
+   Proceedings of the 22nd ACM SIGKDD International Conference on Knowledge Discovery
+   and Data Mining, 855–864, 2016.
+2. T. Mikolov, I. Sutskever, K. Chen, G. Corrado and J. Dean. Distributed Representations
+   of Words and Phrases and their Compositionality. Advances in Neural Information
+   Processing Systems 26, 3111–3119, 2013.
+*/
+pub fn node2vec<T, A>(
+    graph: &Graph<T, A>,
+    options: &EmbeddingOptions,
+) -> Result<HashMap<T, Vec<f32>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    check_bias(options.p, options.q)?;
+    embed(graph, options, Some((options.p, options.q)))
+}
+
+/**
+Learns node embeddings with DeepWalk: uniform random walks, generated by
+[uniform_walks](../random_walk/fn.uniform_walks.html), are fed to a skip-gram model trained
+with negative sampling. This is the same as [node2vec](fn.node2vec.html) with `p` and `q`
+equal to `1.0`; the `p` and `q` options are ignored.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `options`: the [EmbeddingOptions](struct.EmbeddingOptions.html) to use
+
+# Returns
+
+A `HashMap` of each node's embedding. An `Error` with a `kind` of `ErrorKind::InvalidArgument`
+is returned if `dimensions`, `window` or `learning_rate` isn't positive, or if `weighted` is
+`true` and an edge has a negative weight.
+
+# Examples
+
+```
+use graphrs::{algorithms::embeddings::{self, EmbeddingOptions}, generators};
+
+let graph = generators::social::karate_club_graph();
+let options = EmbeddingOptions {
+    dimensions: 8,
+    walk_length: 20,
+    seed: Some(1),
+    ..EmbeddingOptions::default()
+};
+let result = embeddings::deepwalk(&graph, &options).unwrap();
+assert_eq!(result[&33].len(), 8);
+```
+
+# References
+
+1. B. Perozzi, R. Al-Rfou and S. Skiena. DeepWalk: Online Learning of Social
+   Representations. Proceedings of the 20th ACM SIGKDD International Conference on
+   Knowledge Discovery and Data Mining, 701–710, 2014.
+*/
+pub fn deepwalk<T, A>(
+    graph: &Graph<T, A>,
+    options: &EmbeddingOptions,
+) -> Result<HashMap<T, Vec<f32>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    embed(graph, options, None)
+}
+
+/// Generates the walks, biased by `(p, q)` if given, and trains embeddings on them.
+fn embed<T, A>(
+    graph: &Graph<T, A>,
+    options: &EmbeddingOptions,
+    bias: Option<(f64, f64)>,
+) -> Result<HashMap<T, Vec<f32>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if options.dimensions == 0
+        || options.window == 0
+        || options.learning_rate.is_nan()
+        || options.learning_rate <= 0.0
+    {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!(
+                "The `dimensions`, `window` and `learning_rate` options must be positive, \
+                but were {}, {} and {}.",
+                options.dimensions, options.window, options.learning_rate
+            ),
+        });
+    }
+    let csr = get_walk_csr(graph, options.weighted)?;
+    let num_nodes = csr.number_of_nodes();
+    // one round of walks from every node at a time, so each round covers the whole graph
+    let starts: Vec<u32> = (0..options.walks_per_node).flat_map(|_| 0..num_nodes as u32).collect();
+    let mut rng = get_rng(options.seed);
+    let walks = generate_index_walks(
+        &csr,
+        starts,
+        options.walk_length,
+        options.weighted,
+        bias,
+        Some(rng.gen()),
+        &ParallelOptions::default(),
+    )?;
+    let embeddings = train_skip_gram(&walks, num_nodes, options, &mut rng);
+    Ok(csr
+        .get_node_names()
+        .iter()
+        .cloned()
+        .zip(embeddings.chunks(options.dimensions).map(|e| e.to_vec()))
+        .collect())
+}
+
+/**
+Trains a skip-gram model with negative sampling on `walks` and returns the input vectors,
+`dimensions` values per node, laid out one node after another.
+*/
+fn train_skip_gram(
+    walks: &[Vec<u32>],
+    num_nodes: usize,
+    options: &EmbeddingOptions,
+    rng: &mut StdRng,
+) -> Vec<f32> {
+    let dimensions = options.dimensions;
+    let mut inputs: Vec<f32> =
+        (0..num_nodes * dimensions).map(|_| (rng.gen::<f32>() - 0.5) / dimensions as f32).collect();
+    let mut outputs = vec![0.0f32; num_nodes * dimensions];
+    let noise = get_noise_distribution(walks, num_nodes);
+    let total_steps = (options.epochs * walks.iter().map(|w| w.len()).sum::<usize>()).max(1);
+    let mut step = 0;
+    let mut gradient = vec![0.0f32; dimensions];
+    for _epoch in 0..options.epochs {
+        for walk in walks {
+            for (i, center) in walk.iter().enumerate() {
+                let learning_rate =
+                    options.learning_rate * (1.0 - step as f32 / total_steps as f32).max(0.0001);
+                step += 1;
+                let input = *center as usize * dimensions;
+                let first = i.saturating_sub(options.window);
+                let last = (i + options.window + 1).min(walk.len());
+                for (j, context) in walk.iter().enumerate().take(last).skip(first) {
+                    if j == i {
+                        continue;
+                    }
+                    gradient.iter_mut().for_each(|g| *g = 0.0);
+                    for k in 0..=options.negative_samples {
+                        let (target, label) = match k {
+                            0 => (*context, 1.0),
+                            _ => match sample(&noise, rng) {
+                                t if t == *context => continue,
+                                t => (t, 0.0),
+                            },
+                        };
+                        let output = target as usize * dimensions;
+                        let dot: f32 = inputs[input..input + dimensions]
+                            .iter()
+                            .zip(&outputs[output..output + dimensions])
+                            .map(|(a, b)| a * b)
+                            .sum();
+                        let g = (label - sigmoid(dot)) * learning_rate;
+                        for d in 0..dimensions {
+                            gradient[d] += g * outputs[output + d];
+                            outputs[output + d] += g * inputs[input + d];
+                        }
+                    }
+                    for d in 0..dimensions {
+                        inputs[input + d] += gradient[d];
+                    }
+                }
+            }
+        }
+    }
+    inputs
+}
+
+/**
+Returns the cumulative distribution that negative samples are drawn from: each node's
+number of appearances in `walks` raised to the power of 0.75.
+*/
+fn get_noise_distribution(walks: &[Vec<u32>], num_nodes: usize) -> Vec<f64> {
+    let mut counts = vec![0usize; num_nodes];
+    for v in walks.iter().flatten() {
+        counts[*v as usize] += 1;
+    }
+    counts
+        .into_iter()
+        .scan(0.0, |total, count| {
+            *total += (count as f64).powf(0.75);
+            Some(*total)
+        })
+        .collect()
+}
+
+/// Draws a node from the cumulative distribution `noise`.
+fn sample(noise: &[f64], rng: &mut StdRng) -> u32 {
+    let total = noise.last().cloned().unwrap_or(0.0);
+    let r = rng.gen::<f64>() * total;
+    noise.partition_point(|c| *c <= r).min(noise.len() - 1) as u32
+}
+
+fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x.clamp(-6.0, 6.0)).exp())
+}
@@ -0,0 +1,348 @@
+mod network;
+mod stoer_wagner;
+use crate::{Edge, Error, ErrorKind, Graph};
+pub(crate) use network::FlowNetwork;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+pub use stoer_wagner::stoer_wagner_min_cut;
+
+/// The result of a maximum flow computation.
+pub struct MaximumFlow<T> {
+    /// The total amount of flow from the source to the sink.
+    pub flow_value: f64,
+    /**
+    The flow along each edge, keyed by `(u, v)`. For undirected graphs the key is in
+    the direction of the flow.
+    */
+    pub edge_flows: HashMap<(T, T), f64>,
+}
+
+/// The result of a minimum cost flow computation.
+pub struct MinCostFlow<T> {
+    /// The total amount of flow from the source to the sink.
+    pub flow_value: f64,
+    /// The total cost of the flow: the sum of each edge's flow multiplied by its cost.
+    pub flow_cost: f64,
+    /// The flow along each edge, keyed by `(u, v)`.
+    pub edge_flows: HashMap<(T, T), f64>,
+}
+
+/// The result of a minimum cut computation.
+pub struct MinimumCut<T> {
+    /// The total weight of the edges that cross the cut.
+    pub cut_value: f64,
+    /**
+    The two sets of nodes that the cut separates, each sorted. For an s-t cut the
+    first set contains the source.
+    */
+    pub partition: (Vec<T>, Vec<T>),
+}
+
+/**
+Finds the maximum flow from `source` to `sink`, using Dinic's algorithm. The weight of
+each edge is its capacity. In an undirected graph flow can go in either direction
+along an edge, but not in both.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance where all edges have a weight;
+  graphs with multiple edges between the same nodes aren't supported
+* `source`: the node that flow starts from
+* `sink`: the node that flow ends at
+
+# Returns
+
+A [MaximumFlow](struct.MaximumFlow.html) with the value of the flow and the flow along
+every edge. An error is returned if an edge has a negative capacity or if there is a
+path of edges with infinite capacity from `source` to `sink`.
+
+# Examples
+
+```
+use graphrs::{algorithms::flow, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("s", "a", 3.0),
+    Edge::with_weight("s", "b", 2.0),
+    Edge::with_weight("a", "b", 5.0),
+    Edge::with_weight("a", "t", 2.0),
+    Edge::with_weight("b", "t", 3.0),
+]);
+
+let result = flow::maximum_flow(&graph, "s", "t").unwrap();
+assert_eq!(result.flow_value, 5.0);
+assert_eq!(result.edge_flows.get(&("a", "b")).unwrap(), &1.0);
+```
+
+# References
+
+1. E. A. Dinic. Algorithm for solution of a problem of maximum flow in a network with
+   power estimation. Soviet Math. Doklady, 11:1277–1280, 1970.
+*/
+pub fn maximum_flow<T, A>(graph: &Graph<T, A>, source: T, sink: T) -> Result<MaximumFlow<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut flow_graph = FlowGraph::new(graph, &source, &sink)?;
+    let flow_value = flow_graph.run()?;
+    Ok(MaximumFlow {
+        flow_value,
+        edge_flows: flow_graph.get_edge_flows(),
+    })
+}
+
+/**
+Finds the maximum flow from `source` to `sink` that has the lowest total cost, using
+successive shortest augmenting paths. The weight of each edge is its capacity, and the
+cost of sending one unit of flow along an edge is given by the `cost` function.
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance where all edges have a
+  weight; graphs with multiple edges between the same nodes aren't supported
+* `source`: the node that flow starts from
+* `sink`: the node that flow ends at
+* `cost`: a function that takes an `Edge` and returns the cost per unit of flow along it;
+  costs may be negative as long as no cycle of edges has a negative total cost
+
+# Returns
+
+A [MinCostFlow](struct.MinCostFlow.html) with the value and cost of the flow and the flow
+along every edge. An error is returned if an edge has a negative capacity, if there is a
+path of edges with infinite capacity from `source` to `sink` or if there is a cycle of
+edges with a negative total cost.
+
+# Examples
+
+```
+use graphrs::{algorithms::flow, Edge, Graph, GraphSpecs};
+
+#[derive(Clone)]
+struct Route {
+    cost: f64,
+}
+
+let route = |u, v, capacity, cost| Edge {
+    u,
+    v,
+    attributes: Some(Route { cost }),
+    weight: capacity,
+};
+let mut graph = Graph::<&str, Route>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    route("factory", "port", 4.0, 1.0),
+    route("factory", "depot", 2.0, 3.0),
+    route("port", "depot", 3.0, 1.0),
+    route("port", "store", 2.0, 6.0),
+    route("depot", "store", 4.0, 2.0),
+]);
+
+let cost = |edge: &Edge<&str, Route>| edge.attributes.as_ref().unwrap().cost;
+let result = flow::min_cost_flow(&graph, "factory", "store", cost).unwrap();
+assert_eq!(result.flow_value, 6.0);
+assert_eq!(result.flow_cost, 32.0);
+assert_eq!(result.edge_flows.get(&("port", "depot")).unwrap(), &2.0);
+```
+
+# References
+
+1. R. K. Ahuja, T. L. Magnanti and J. B. Orlin. Network Flows: Theory, Algorithms, and
+   Applications. Prentice Hall, 1993.
+*/
+pub fn min_cost_flow<T, A, F>(
+    graph: &Graph<T, A>,
+    source: T,
+    sink: T,
+    cost: F,
+) -> Result<MinCostFlow<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+    F: Fn(&Edge<T, A>) -> f64,
+{
+    graph.ensure_directed()?;
+    let mut flow_graph = FlowGraph::new(graph, &source, &sink)?;
+    for (edge, arc) in flow_graph.edges.iter().zip(flow_graph.arcs.iter()) {
+        flow_graph.network.set_cost(*arc, cost(edge));
+    }
+    let (flow_value, flow_cost) = match flow_graph
+        .network
+        .min_cost_flow(flow_graph.source, flow_graph.sink)
+    {
+        None => {
+            return Err(Error {
+                kind: ErrorKind::NegativeCycle,
+                message: "There is a cycle of edges with a negative total cost.".to_string(),
+            })
+        }
+        Some((flow_value, _)) if flow_value.is_infinite() => return Err(get_infinite_flow_error()),
+        Some(result) => result,
+    };
+    Ok(MinCostFlow {
+        flow_value,
+        flow_cost,
+        edge_flows: flow_graph.get_edge_flows(),
+    })
+}
+
+/**
+Finds the minimum cut between `source` and `sink`: the set of edges with the smallest total
+weight whose removal leaves no path from `source` to `sink`. By the max-flow min-cut theorem
+the value of the cut is the value of the maximum flow.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance where all edges have a weight;
+  graphs with multiple edges between the same nodes aren't supported
+* `source`: the node on one side of the cut
+* `sink`: the node on the other side of the cut
+
+# Returns
+
+A [MinimumCut](struct.MinimumCut.html). The first set of nodes in the partition is the
+nodes that can be reached from `source` in the residual network of the maximum flow.
+
+# Examples
+
+```
+use graphrs::{algorithms::flow, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("s", "a", 3.0),
+    Edge::with_weight("s", "b", 2.0),
+    Edge::with_weight("a", "b", 5.0),
+    Edge::with_weight("a", "t", 2.0),
+    Edge::with_weight("b", "t", 3.0),
+]);
+
+let result = flow::minimum_cut(&graph, "s", "t").unwrap();
+assert_eq!(result.cut_value, 5.0);
+assert_eq!(result.partition, (vec!["s"], vec!["a", "b", "t"]));
+```
+*/
+pub fn minimum_cut<T, A>(graph: &Graph<T, A>, source: T, sink: T) -> Result<MinimumCut<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut flow_graph = FlowGraph::new(graph, &source, &sink)?;
+    let cut_value = flow_graph.run()?;
+    let reachable = flow_graph.network.get_reachable(flow_graph.source);
+    let mut partition = (vec![], vec![]);
+    for (node_name, reachable) in flow_graph.node_names.into_iter().zip(reachable) {
+        match reachable {
+            true => partition.0.push(node_name.clone()),
+            false => partition.1.push(node_name.clone()),
+        }
+    }
+    Ok(MinimumCut {
+        cut_value,
+        partition,
+    })
+}
+
+/// A `FlowNetwork` created from the edges of a graph.
+struct FlowGraph<'a, T, A>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    edges: Vec<&'a Edge<T, A>>,
+    /// The arc for each of the `edges`.
+    arcs: Vec<usize>,
+    network: FlowNetwork,
+    /// The node names, sorted; node `i` of the `network` is `node_names[i]`.
+    node_names: Vec<&'a T>,
+    source: usize,
+    sink: usize,
+}
+
+impl<'a, T, A> FlowGraph<'a, T, A>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    /// Creates a `FlowGraph`, checking that the graph and the `source` and `sink` are valid.
+    fn new(graph: &'a Graph<T, A>, source: &T, sink: &T) -> Result<FlowGraph<'a, T, A>, Error> {
+        graph.ensure_weighted()?;
+        graph.ensure_not_multi_edges()?;
+        graph.ensure_node(source)?;
+        graph.ensure_node(sink)?;
+        if source == sink {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!("The source and sink were both '{}'.", source),
+            });
+        }
+        let mut node_names = graph.get_all_node_names();
+        node_names.sort();
+        let node_indexes: HashMap<&T, usize> =
+            node_names.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+        let mut edges = graph.get_all_edges();
+        edges.sort_by(|e1, e2| (&e1.u, &e1.v).cmp(&(&e2.u, &e2.v)));
+        let mut network = FlowNetwork::new(node_names.len());
+        let mut arcs = vec![];
+        for edge in edges.iter() {
+            if edge.weight < 0.0 {
+                return Err(Error {
+                    kind: ErrorKind::InvalidArgument,
+                    message: format!("The ({}, {}) edge has a negative capacity.", edge.u, edge.v),
+                });
+            }
+            let reverse_capacity = match graph.specs.directed {
+                true => 0.0,
+                false => edge.weight,
+            };
+            let u = *node_indexes.get(&edge.u).unwrap();
+            let v = *node_indexes.get(&edge.v).unwrap();
+            arcs.push(network.add_arc(u, v, edge.weight, reverse_capacity));
+        }
+        Ok(FlowGraph {
+            edges,
+            arcs,
+            network,
+            source: *node_indexes.get(source).unwrap(),
+            sink: *node_indexes.get(sink).unwrap(),
+            node_names,
+        })
+    }
+
+    /// Pushes the maximum flow through the network, returning the flow value.
+    fn run(&mut self) -> Result<f64, Error> {
+        let flow_value = self.network.dinic(self.source, self.sink);
+        if flow_value.is_infinite() {
+            return Err(get_infinite_flow_error());
+        }
+        Ok(flow_value)
+    }
+
+    /**
+    Returns the flow along each edge. For undirected graphs the key is in the direction
+    of the flow.
+    */
+    fn get_edge_flows(&self) -> HashMap<(T, T), f64> {
+        self.edges
+            .iter()
+            .zip(self.arcs.iter())
+            .map(|(edge, arc)| {
+                let flow = edge.weight - self.network.get_residual_capacity(*arc);
+                match flow < 0.0 {
+                    true => ((edge.v.clone(), edge.u.clone()), -flow),
+                    false => ((edge.u.clone(), edge.v.clone()), flow),
+                }
+            })
+            .collect()
+    }
+}
+
+fn get_infinite_flow_error() -> Error {
+    Error {
+        kind: ErrorKind::InvalidArgument,
+        message: "There is a path of edges with infinite capacity from the source to the sink."
+            .to_string(),
+    }
+}
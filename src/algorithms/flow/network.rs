@@ -0,0 +1,316 @@
+use crate::algorithms::shortest_path::dijkstra::FringeNode;
+use std::collections::{BinaryHeap, VecDeque};
+
+/**
+A residual network over the nodes `0..n`. Arcs are stored in pairs so that the
+reverse of arc `a` is arc `a ^ 1`.
+*/
+pub(crate) struct FlowNetwork {
+    heads: Vec<usize>,
+    capacities: Vec<f64>,
+    /// The cost of sending one unit of flow along each arc.
+    costs: Vec<f64>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl FlowNetwork {
+    /// Creates a `FlowNetwork` with `n` nodes and no arcs.
+    pub fn new(n: usize) -> FlowNetwork {
+        FlowNetwork {
+            heads: vec![],
+            capacities: vec![],
+            costs: vec![],
+            adjacency: vec![vec![]; n],
+        }
+    }
+
+    /**
+    Adds an arc from `u` to `v` with `capacity`, and its reverse arc with
+    `reverse_capacity`. Returns the index of the arc from `u` to `v`.
+    */
+    pub fn add_arc(&mut self, u: usize, v: usize, capacity: f64, reverse_capacity: f64) -> usize {
+        let arc = self.heads.len();
+        self.heads.push(v);
+        self.capacities.push(capacity);
+        self.costs.push(0.0);
+        self.adjacency[u].push(arc);
+        self.heads.push(u);
+        self.capacities.push(reverse_capacity);
+        self.costs.push(0.0);
+        self.adjacency[v].push(arc + 1);
+        arc
+    }
+
+    /// Sets the cost of `arc` to `cost`, and the cost of its reverse arc to `-cost`.
+    pub fn set_cost(&mut self, arc: usize, cost: f64) {
+        self.costs[arc] = cost;
+        self.costs[arc ^ 1] = -cost;
+    }
+
+    /// Returns the remaining capacity of `arc`.
+    pub fn get_residual_capacity(&self, arc: usize) -> f64 {
+        self.capacities[arc]
+    }
+
+    /// Uses Dinic's algorithm to push as much flow as possible from `source` to `sink`.
+    pub fn dinic(&mut self, source: usize, sink: usize) -> f64 {
+        let mut flow = 0.0;
+        while let Some(mut levels) = self.get_levels(source, sink) {
+            let mut next_arcs = vec![0; self.adjacency.len()];
+            loop {
+                let pushed = self.augment(source, sink, &mut levels, &mut next_arcs);
+                if pushed == 0.0 {
+                    break;
+                }
+                flow += pushed;
+                if pushed.is_infinite() {
+                    return flow;
+                }
+            }
+        }
+        flow
+    }
+
+    /**
+    Uses successive shortest augmenting paths to push as much flow as possible from
+    `source` to `sink` at the lowest total cost. Returns the flow and its cost, or `None`
+    if there is a cycle of arcs with remaining capacity whose total cost is negative.
+    */
+    pub fn min_cost_flow(&mut self, source: usize, sink: usize) -> Option<(f64, f64)> {
+        let mut potentials = self.get_initial_potentials()?;
+        let mut flow = 0.0;
+        let mut cost = 0.0;
+        loop {
+            let (distances, pred_arcs) = self.get_reduced_distances(source, &potentials);
+            if distances[sink].is_infinite() {
+                break;
+            }
+            for (potential, distance) in potentials.iter_mut().zip(distances.iter()) {
+                if distance.is_finite() {
+                    *potential += distance;
+                }
+            }
+            let mut path = vec![];
+            let mut v = sink;
+            while v != source {
+                let arc = pred_arcs[v];
+                path.push(arc);
+                v = self.heads[arc ^ 1];
+            }
+            let pushed = path.iter().map(|a| self.capacities[*a]).fold(f64::INFINITY, f64::min);
+            flow += pushed;
+            if pushed.is_infinite() {
+                return Some((flow, cost));
+            }
+            for arc in path {
+                self.capacities[arc] -= pushed;
+                self.capacities[arc ^ 1] += pushed;
+                cost += pushed * self.costs[arc];
+            }
+        }
+        Some((flow, cost))
+    }
+
+    /// Returns, for each node, whether it can be reached from `source` in the residual network.
+    pub fn get_reachable(&self, source: usize) -> Vec<bool> {
+        let mut reachable = vec![false; self.adjacency.len()];
+        reachable[source] = true;
+        let mut queue = VecDeque::from(vec![source]);
+        while let Some(u) = queue.pop_front() {
+            for arc in self.adjacency[u].iter() {
+                let v = self.heads[*arc];
+                if self.capacities[*arc] > 0.0 && !reachable[v] {
+                    reachable[v] = true;
+                    queue.push_back(v);
+                }
+            }
+        }
+        reachable
+    }
+
+    /**
+    Uses the Bellman-Ford algorithm to find node potentials that make the reduced cost of
+    every arc with remaining capacity non-negative. Returns `None` if there is a cycle of
+    such arcs with a negative total cost.
+    */
+    fn get_initial_potentials(&self) -> Option<Vec<f64>> {
+        let mut potentials = vec![0.0; self.adjacency.len()];
+        for _ in 0..=self.adjacency.len() {
+            let mut updated = false;
+            for (u, arcs) in self.adjacency.iter().enumerate() {
+                for arc in arcs.iter().filter(|a| self.capacities[**a] > 0.0) {
+                    let v = self.heads[*arc];
+                    let potential = potentials[u] + self.costs[*arc];
+                    if potential < potentials[v] {
+                        potentials[v] = potential;
+                        updated = true;
+                    }
+                }
+            }
+            if !updated {
+                return Some(potentials);
+            }
+        }
+        None
+    }
+
+    /**
+    Uses Dijkstra's algorithm, with costs reduced by `potentials`, to find the distance of
+    every node from `source` along arcs with remaining capacity. Returns the distances and
+    the arc used to reach each node.
+    */
+    fn get_reduced_distances(&self, source: usize, potentials: &[f64]) -> (Vec<f64>, Vec<usize>) {
+        let mut distances = vec![f64::INFINITY; self.adjacency.len()];
+        let mut pred_arcs = vec![usize::MAX; self.adjacency.len()];
+        let mut done = vec![false; self.adjacency.len()];
+        let mut fringe = BinaryHeap::new();
+        distances[source] = 0.0;
+        fringe.push(FringeNode {
+            node_name: source,
+            count: 0,
+            distance: -0.0,
+        });
+        while let Some(fringe_node) = fringe.pop() {
+            let u = fringe_node.node_name;
+            if done[u] {
+                continue;
+            }
+            done[u] = true;
+            for arc in self.adjacency[u].iter().filter(|a| self.capacities[**a] > 0.0) {
+                let v = self.heads[*arc];
+                let reduced_cost = (self.costs[*arc] + potentials[u] - potentials[v]).max(0.0);
+                let distance = distances[u] + reduced_cost;
+                if !done[v] && distance < distances[v] {
+                    distances[v] = distance;
+                    pred_arcs[v] = *arc;
+                    fringe.push(FringeNode {
+                        node_name: v,
+                        count: 0,
+                        distance: -distance,
+                    });
+                }
+            }
+        }
+        (distances, pred_arcs)
+    }
+
+    /**
+    Finds the distance, in arcs with remaining capacity, of every node from `source`.
+    Returns `None` if `sink` can't be reached.
+    */
+    fn get_levels(&self, source: usize, sink: usize) -> Option<Vec<usize>> {
+        let mut levels = vec![usize::MAX; self.adjacency.len()];
+        levels[source] = 0;
+        let mut queue = VecDeque::from(vec![source]);
+        while let Some(u) = queue.pop_front() {
+            for arc in self.adjacency[u].iter() {
+                let v = self.heads[*arc];
+                if self.capacities[*arc] > 0.0 && levels[v] == usize::MAX {
+                    levels[v] = levels[u] + 1;
+                    queue.push_back(v);
+                }
+            }
+        }
+        match levels[sink] {
+            usize::MAX => None,
+            _ => Some(levels),
+        }
+    }
+
+    /**
+    Finds a path from `source` to `sink` through the level graph and pushes as much flow
+    along it as possible. Returns the amount of flow pushed, which is zero when the flow
+    is blocking. Nodes that lead to dead ends are removed from the level graph.
+    */
+    fn augment(
+        &mut self,
+        source: usize,
+        sink: usize,
+        levels: &mut [usize],
+        next_arcs: &mut [usize],
+    ) -> f64 {
+        let mut path: Vec<usize> = vec![];
+        let mut u = source;
+        loop {
+            if u == sink {
+                let pushed = path.iter().map(|a| self.capacities[*a]).fold(f64::INFINITY, f64::min);
+                for arc in path {
+                    self.capacities[arc] -= pushed;
+                    self.capacities[arc ^ 1] += pushed;
+                }
+                return pushed;
+            }
+            let mut advanced = false;
+            while next_arcs[u] < self.adjacency[u].len() {
+                let arc = self.adjacency[u][next_arcs[u]];
+                let v = self.heads[arc];
+                if self.capacities[arc] > 0.0 && levels[v] == levels[u] + 1 {
+                    path.push(arc);
+                    u = v;
+                    advanced = true;
+                    break;
+                }
+                next_arcs[u] += 1;
+            }
+            if !advanced {
+                levels[u] = usize::MAX;
+                match path.pop() {
+                    None => return 0.0,
+                    Some(arc) => {
+                        u = self.heads[arc ^ 1];
+                        next_arcs[u] += 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// tests for private methods only; other tests are in:
+// tests/test_algorithms_flow.rs
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_dinic() {
+        let mut network = FlowNetwork::new(4);
+        let a01 = network.add_arc(0, 1, 3.0, 0.0);
+        network.add_arc(0, 2, 2.0, 0.0);
+        network.add_arc(1, 2, 5.0, 0.0);
+        network.add_arc(1, 3, 2.0, 0.0);
+        network.add_arc(2, 3, 3.0, 0.0);
+        assert_eq!(network.dinic(0, 3), 5.0);
+        assert_eq!(network.get_residual_capacity(a01), 0.0);
+        assert_eq!(network.get_reachable(0), vec![true, false, false, false]);
+    }
+
+    #[test]
+    fn test_min_cost_flow() {
+        let mut network = FlowNetwork::new(4);
+        let a01 = network.add_arc(0, 1, 2.0, 0.0);
+        network.set_cost(a01, 1.0);
+        let a02 = network.add_arc(0, 2, 2.0, 0.0);
+        network.set_cost(a02, 4.0);
+        let a12 = network.add_arc(1, 2, 1.0, 0.0);
+        network.set_cost(a12, -2.0);
+        let a13 = network.add_arc(1, 3, 1.0, 0.0);
+        network.set_cost(a13, 5.0);
+        let a23 = network.add_arc(2, 3, 2.0, 0.0);
+        network.set_cost(a23, 1.0);
+        assert_eq!(network.min_cost_flow(0, 3), Some((3.0, 11.0)));
+        assert_eq!(network.get_residual_capacity(a12), 0.0);
+    }
+
+    #[test]
+    fn test_min_cost_flow_negative_cycle() {
+        let mut network = FlowNetwork::new(3);
+        network.add_arc(0, 1, 1.0, 0.0);
+        let a12 = network.add_arc(1, 2, 1.0, 0.0);
+        network.set_cost(a12, -2.0);
+        let a21 = network.add_arc(2, 1, 1.0, 0.0);
+        network.set_cost(a21, 1.0);
+        assert_eq!(network.min_cost_flow(0, 2), None);
+    }
+}
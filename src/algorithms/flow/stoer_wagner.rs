@@ -0,0 +1,173 @@
+use super::MinimumCut;
+use crate::algorithms::shortest_path::dijkstra::FringeNode;
+use crate::{Error, ErrorKind, Graph};
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Finds the global minimum cut of an undirected graph, using the Stoer–Wagner algorithm:
+the set of edges with the smallest total weight whose removal disconnects the graph.
+
+If the graph is not connected the cut value is `0.0` and one side of the partition
+is a connected component.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance with at least two nodes
+* `weighted`: set to `true` to use edge weights; otherwise every edge has a weight of `1.0`
+
+# Returns
+
+A [MinimumCut](struct.MinimumCut.html).
+
+# Examples
+
+```
+use graphrs::{algorithms::flow, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("a", "b", 3.0),
+    Edge::with_weight("b", "c", 3.0),
+    Edge::with_weight("c", "a", 3.0),
+    Edge::with_weight("c", "d", 1.0),
+    Edge::with_weight("d", "e", 3.0),
+    Edge::with_weight("e", "c", 1.5),
+]);
+
+let result = flow::stoer_wagner_min_cut(&graph, true).unwrap();
+assert_eq!(result.cut_value, 2.5);
+assert_eq!(result.partition, (vec!["a", "b", "c"], vec!["d", "e"]));
+```
+
+# References
+
+1. M. Stoer and F. Wagner. A simple min-cut algorithm. Journal of the ACM 44(4):585–591, 1997.
+*/
+pub fn stoer_wagner_min_cut<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+) -> Result<MinimumCut<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let mut node_names = graph.get_all_node_names();
+    node_names.sort();
+    let num_nodes = node_names.len();
+    if num_nodes < 2 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The graph must have at least two nodes to be cut.".to_string(),
+        });
+    }
+    let node_indexes: HashMap<&T, usize> =
+        node_names.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let mut adjacency: Vec<HashMap<usize, f64>> = vec![HashMap::new(); num_nodes];
+    for edge in graph.get_all_edges() {
+        let weight = match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+        if weight < 0.0 {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!("The ({}, {}) edge has a negative weight.", edge.u, edge.v),
+            });
+        }
+        let u = *node_indexes.get(&edge.u).unwrap();
+        let v = *node_indexes.get(&edge.v).unwrap();
+        if u != v {
+            *adjacency[u].entry(v).or_insert(0.0) += weight;
+            *adjacency[v].entry(u).or_insert(0.0) += weight;
+        }
+    }
+
+    let mut members: Vec<Vec<usize>> = (0..num_nodes).map(|i| vec![i]).collect();
+    let mut active: Vec<usize> = (0..num_nodes).collect();
+    let mut best_value = f64::INFINITY;
+    let mut best_members: Vec<usize> = vec![];
+    while active.len() > 1 {
+        let (s, t, cut_of_phase) = minimum_cut_phase(&adjacency, &active);
+        if cut_of_phase < best_value {
+            best_value = cut_of_phase;
+            best_members = members[t].clone();
+        }
+        // merge `t` into `s`
+        let t_neighbors: Vec<(usize, f64)> = adjacency[t].drain().collect();
+        for (v, weight) in t_neighbors {
+            adjacency[v].remove(&t);
+            if v != s {
+                *adjacency[s].entry(v).or_insert(0.0) += weight;
+                *adjacency[v].entry(s).or_insert(0.0) += weight;
+            }
+        }
+        let t_members = std::mem::take(&mut members[t]);
+        members[s].extend(t_members);
+        active.retain(|v| *v != t);
+    }
+
+    let mut in_cut = vec![false; num_nodes];
+    best_members.into_iter().for_each(|i| in_cut[i] = true);
+    let (cut_side, other_side): (Vec<usize>, Vec<usize>) = (0..num_nodes).partition(|i| in_cut[*i]);
+    let names = |indexes: Vec<usize>| -> Vec<T> {
+        indexes.into_iter().map(|i| node_names[i].clone()).collect()
+    };
+    let partition = match other_side.contains(&0) {
+        true => (names(other_side), names(cut_side)),
+        false => (names(cut_side), names(other_side)),
+    };
+    Ok(MinimumCut {
+        cut_value: best_value,
+        partition,
+    })
+}
+
+/**
+Runs one phase of the Stoer–Wagner algorithm: starting from any `active` node,
+repeatedly adds the node most tightly connected to the nodes added so far. Returns
+the last two nodes added, `s` and `t`, and the "cut of the phase": the total weight
+of the edges between `t` and the other nodes.
+*/
+fn minimum_cut_phase(adjacency: &[HashMap<usize, f64>], active: &[usize]) -> (usize, usize, f64) {
+    let mut weights: HashMap<usize, f64> = active.iter().map(|v| (*v, 0.0)).collect();
+    let mut added = vec![false; adjacency.len()];
+    let mut heap: BinaryHeap<FringeNode<usize>> = active
+        .iter()
+        .map(|v| FringeNode {
+            node_name: *v,
+            count: 0,
+            distance: 0.0,
+        })
+        .collect();
+    let mut order: Vec<usize> = vec![];
+    let mut last_weight = 0.0;
+    while let Some(fringe_node) = heap.pop() {
+        let u = fringe_node.node_name;
+        if added[u] || fringe_node.distance != *weights.get(&u).unwrap() {
+            continue;
+        }
+        added[u] = true;
+        order.push(u);
+        last_weight = fringe_node.distance;
+        for (v, weight) in adjacency[u].iter() {
+            if !added[*v] {
+                let w = weights.get_mut(v).unwrap();
+                *w += weight;
+                heap.push(FringeNode {
+                    node_name: *v,
+                    count: 0,
+                    distance: *w,
+                });
+            }
+        }
+    }
+    let t = order[order.len() - 1];
+    let s = order[order.len() - 2];
+    (s, t, last_weight)
+}
@@ -0,0 +1,247 @@
+use crate::algorithms::dag;
+use crate::{Error, ErrorKind, Graph};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Finds the lowest common ancestor of two nodes in a directed acyclic graph: a node that
+has a path to both of them and that has no path to any other such node. A node counts
+as its own ancestor. This is for a single pair of nodes; use [TreeLca](struct.TreeLca.html)
+to answer many queries on a tree.
+
+When there are several lowest common ancestors, the last one in the order given by
+[topological_sort](../dag/fn.topological_sort.html) is returned.
+
+# Arguments
+
+* `graph`: a directed acyclic [Graph](../../struct.Graph.html) instance
+* `node1`: the first node
+* `node2`: the second node
+
+# Returns
+
+The lowest common ancestor, or `None` if the nodes have no common ancestor. An `Error` with
+a `kind` of `ErrorKind::NodeNotFound` is returned if either node doesn't exist, and one with
+a `kind` of `ErrorKind::GraphContainsCycle` if the graph contains a cycle.
+
+# Examples
+
+```
+use graphrs::{algorithms::lca, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("grandparent", "parent"),
+    Edge::new("parent", "child1"),
+    Edge::new("parent", "child2"),
+    Edge::new("grandparent", "aunt"),
+]);
+let result = lca::lowest_common_ancestor(&graph, "child1", "child2").unwrap();
+assert_eq!(result, Some("parent"));
+let result = lca::lowest_common_ancestor(&graph, "child1", "aunt").unwrap();
+assert_eq!(result, Some("grandparent"));
+```
+*/
+pub fn lowest_common_ancestor<T, A>(
+    graph: &Graph<T, A>,
+    node1: T,
+    node2: T,
+) -> Result<Option<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut ancestors1: HashSet<T> = dag::ancestors(graph, node1.clone())?.into_iter().collect();
+    ancestors1.insert(node1);
+    let mut ancestors2: HashSet<T> = dag::ancestors(graph, node2.clone())?.into_iter().collect();
+    ancestors2.insert(node2);
+    // a common ancestor that comes after every other one in topological order can't
+    // have a path to any of them
+    Ok(dag::topological_sort(graph)?
+        .into_iter()
+        .rev()
+        .find(|n| ancestors1.contains(n) && ancestors2.contains(n)))
+}
+
+/**
+A rooted tree that has been preprocessed to answer lowest common ancestor queries.
+
+Preprocessing records the depth of every node and, using binary lifting, its ancestors
+`1`, `2`, `4`, `8`, ... levels up, which takes O(n log n) time and space. A query then
+lifts the deeper node to the depth of the other and lifts both nodes together, in
+decreasing steps, to just below their lowest common ancestor, which takes O(log n) time.
+
+# Examples
+
+```
+use graphrs::{algorithms::lca::TreeLca, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::new(0, 1),
+    Edge::new(0, 2),
+    Edge::new(1, 3),
+    Edge::new(1, 4),
+    Edge::new(4, 5),
+]);
+let tree = TreeLca::build(&graph, 0).unwrap();
+assert_eq!(tree.query(3, 5).unwrap(), 1);
+assert_eq!(tree.query(5, 2).unwrap(), 0);
+assert_eq!(tree.query(4, 5).unwrap(), 4);
+assert_eq!(tree.get_depth(5).unwrap(), 3);
+```
+
+# References
+
+1. M. A. Bender and M. Farach-Colton. The LCA problem revisited. LATIN 2000,
+   LNCS 1776:88–94, 2000.
+*/
+pub struct TreeLca<T> {
+    nodes: Vec<T>,
+    node_indexes: HashMap<T, usize>,
+    depths: Vec<usize>,
+    /// `ancestors[k][v]` is the ancestor `2^k` levels above `v`, or the root if there
+    /// are fewer levels above `v`.
+    ancestors: Vec<Vec<usize>>,
+}
+
+impl<T> TreeLca<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    /**
+    Preprocesses a tree for lowest common ancestor queries.
+
+    # Arguments
+
+    * `graph`: a tree; either an undirected [Graph](../../struct.Graph.html) instance, or
+      a directed one in which every edge points away from `root`
+    * `root`: the root of the tree
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::NodeNotFound` is returned if `root` doesn't
+    exist, and one with a `kind` of `ErrorKind::InvalidArgument` if the graph isn't a tree
+    with `root` as its root.
+    */
+    pub fn build<A>(graph: &Graph<T, A>, root: T) -> Result<TreeLca<T>, Error>
+    where
+        A: Clone + Send + Sync,
+    {
+        graph.ensure_node(&root)?;
+        let csr = graph.to_csr();
+        let n = csr.number_of_nodes();
+        let root_index = csr.get_node_index(&root).unwrap();
+        let mut parents: Vec<Option<u32>> = vec![None; n];
+        let mut depths = vec![0; n];
+        let mut visited = vec![false; n];
+        visited[root_index as usize] = true;
+        let mut num_visited = 1;
+        let mut queue = VecDeque::from([root_index]);
+        while let Some(u) = queue.pop_front() {
+            for v in csr.get_successors_or_neighbors(u) {
+                if !graph.specs.directed && Some(*v) == parents[u as usize] {
+                    continue;
+                }
+                if visited[*v as usize] {
+                    return Err(get_not_a_tree_error(&root));
+                }
+                visited[*v as usize] = true;
+                num_visited += 1;
+                parents[*v as usize] = Some(u);
+                depths[*v as usize] = depths[u as usize] + 1;
+                queue.push_back(*v);
+            }
+        }
+        if num_visited < n {
+            return Err(get_not_a_tree_error(&root));
+        }
+
+        let mut ancestors: Vec<Vec<usize>> =
+            vec![parents.iter().map(|p| p.unwrap_or(root_index) as usize).collect()];
+        let max_depth = depths.iter().max().cloned().unwrap_or(0);
+        while 1 << ancestors.len() <= max_depth {
+            let previous = ancestors.last().unwrap();
+            ancestors.push(previous.iter().map(|a| previous[*a]).collect());
+        }
+
+        let nodes = csr.get_node_names().to_vec();
+        let node_indexes = nodes.iter().enumerate().map(|(i, n)| (n.clone(), i)).collect();
+        Ok(TreeLca {
+            nodes,
+            node_indexes,
+            depths,
+            ancestors,
+        })
+    }
+
+    /**
+    Finds the lowest common ancestor of two nodes: the deepest node that is an ancestor
+    of both of them. A node counts as its own ancestor.
+
+    # Arguments
+
+    * `node1`: the first node
+    * `node2`: the second node
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::NodeNotFound` is returned if either node
+    doesn't exist.
+    */
+    pub fn query(&self, node1: T, node2: T) -> Result<T, Error> {
+        let mut u = self.get_node_index(&node1)?;
+        let mut v = self.get_node_index(&node2)?;
+        if self.depths[u] < self.depths[v] {
+            std::mem::swap(&mut u, &mut v);
+        }
+        let difference = self.depths[u] - self.depths[v];
+        for (k, level) in self.ancestors.iter().enumerate() {
+            if difference & (1 << k) != 0 {
+                u = level[u];
+            }
+        }
+        if u == v {
+            return Ok(self.nodes[u].clone());
+        }
+        for level in self.ancestors.iter().rev() {
+            if level[u] != level[v] {
+                u = level[u];
+                v = level[v];
+            }
+        }
+        Ok(self.nodes[self.ancestors[0][u]].clone())
+    }
+
+    /**
+    Returns the depth of a node: the number of edges between it and the root.
+
+    # Arguments
+
+    * `node_name`: the node whose depth is returned
+    */
+    pub fn get_depth(&self, node_name: T) -> Result<usize, Error> {
+        Ok(self.depths[self.get_node_index(&node_name)?])
+    }
+
+    fn get_node_index(&self, node_name: &T) -> Result<usize, Error> {
+        match self.node_indexes.get(node_name) {
+            Some(index) => Ok(*index),
+            None => Err(Error {
+                kind: ErrorKind::NodeNotFound,
+                message: format!("Requested node '{}' was not found in the tree.", node_name),
+            }),
+        }
+    }
+}
+
+fn get_not_a_tree_error<T>(root: &T) -> Error
+where
+    T: Display,
+{
+    Error {
+        kind: ErrorKind::InvalidArgument,
+        message: format!("The graph is not a tree with root {}.", root),
+    }
+}
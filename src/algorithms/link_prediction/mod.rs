@@ -0,0 +1,517 @@
+use crate::{CsrGraph, Error, Graph, ParallelOptions};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Computes the Jaccard coefficient of pairs of nodes: the number of neighbors they have in
+common divided by the number of nodes that are a neighbor of either of them.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `pairs`: the pairs of nodes to score; if `None` every pair of nodes that aren't joined by
+  an edge is scored
+* `max_degree`: when `pairs` is `None`, only pairs where both nodes have at most this many
+  neighbors are scored; high-degree nodes can make the number of pairs very large
+
+# Returns
+
+An iterator of `(u, v, score)` tuples, in the order of `pairs`, or ordered by `u` and then
+`v` if `pairs` is `None`. An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned
+if the graph is directed, and one with a `kind` of `ErrorKind::NodeNotFound` if a node in
+`pairs` doesn't exist.
+
+# Examples
+
+```
+use graphrs::{algorithms::link_prediction, generators};
+
+let graph = generators::classic::complete_graph(5, false);
+let scores: Vec<(i32, i32, f64)> =
+    link_prediction::jaccard_coefficient(&graph, Some(&[(0, 1), (2, 3)]), None)
+        .unwrap()
+        .collect();
+assert_eq!(scores, vec![(0, 1, 0.6), (2, 3, 0.6)]);
+```
+
+# References
+
+1. D. Liben-Nowell and J. Kleinberg. The Link Prediction Problem for Social Networks.
+   Journal of the American Society for Information Science and Technology,
+   58(7), 1019–1031, 2007.
+*/
+pub fn jaccard_coefficient<T, A>(
+    graph: &Graph<T, A>,
+    pairs: Option<&[(T, T)]>,
+    max_degree: Option<usize>,
+) -> Result<impl Iterator<Item = (T, T, f64)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    jaccard_coefficient_with_options(graph, pairs, max_degree, &ParallelOptions::default())
+}
+
+/**
+Like [jaccard_coefficient](./fn.jaccard_coefficient.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `pairs`: the pairs of nodes to score; if `None` every pair of nodes that aren't joined by
+  an edge is scored
+* `max_degree`: when `pairs` is `None`, only pairs where both nodes have at most this many
+  neighbors are scored
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each pair that has
+  been scored is a unit of work for its `progress`
+
+# Returns
+
+The same as [jaccard_coefficient](./fn.jaccard_coefficient.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::link_prediction, generators, Parallelism, ParallelOptions};
+
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let graph = generators::classic::complete_graph(5, false);
+let scores: Vec<(i32, i32, f64)> = link_prediction::jaccard_coefficient_with_options(
+    &graph,
+    Some(&[(0, 1), (2, 3)]),
+    None,
+    &options,
+)
+.unwrap()
+.collect();
+assert_eq!(scores, vec![(0, 1, 0.6), (2, 3, 0.6)]);
+```
+*/
+pub fn jaccard_coefficient_with_options<T, A>(
+    graph: &Graph<T, A>,
+    pairs: Option<&[(T, T)]>,
+    max_degree: Option<usize>,
+    options: &ParallelOptions,
+) -> Result<impl Iterator<Item = (T, T, f64)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    score_pairs(graph, pairs, max_degree, options, |csr, u, v| {
+        let common = get_common_neighbors(csr, u, v).count();
+        let union = csr.get_successors_or_neighbors(u).len()
+            + csr.get_successors_or_neighbors(v).len()
+            - common;
+        match union {
+            0 => 0.0,
+            _ => common as f64 / union as f64,
+        }
+    })
+}
+
+/**
+Computes the Adamic–Adar index of pairs of nodes: the sum of `1 / ln(d)` over the neighbors
+they have in common, where `d` is the degree of the common neighbor. Common neighbors with
+few neighbors of their own count for more.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `pairs`: the pairs of nodes to score; if `None` every pair of nodes that aren't joined by
+  an edge is scored
+* `max_degree`: when `pairs` is `None`, only pairs where both nodes have at most this many
+  neighbors are scored
+
+# Returns
+
+An iterator of `(u, v, score)` tuples, in the order of `pairs`, or ordered by `u` and then
+`v` if `pairs` is `None`. An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned
+if the graph is directed, and one with a `kind` of `ErrorKind::NodeNotFound` if a node in
+`pairs` doesn't exist.
+
+# Examples
+
+```
+use graphrs::{algorithms::link_prediction, generators};
+
+let graph = generators::classic::complete_graph(5, false);
+let (u, v, score) = link_prediction::adamic_adar_index(&graph, Some(&[(0, 1)]), None)
+    .unwrap()
+    .next()
+    .unwrap();
+assert!((score - 3.0 / 4.0_f64.ln()).abs() < 1e-10);
+```
+
+# References
+
+1. L. A. Adamic and E. Adar. Friends and neighbors on the Web. Social Networks,
+   25(3), 211–230, 2003.
+*/
+pub fn adamic_adar_index<T, A>(
+    graph: &Graph<T, A>,
+    pairs: Option<&[(T, T)]>,
+    max_degree: Option<usize>,
+) -> Result<impl Iterator<Item = (T, T, f64)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    adamic_adar_index_with_options(graph, pairs, max_degree, &ParallelOptions::default())
+}
+
+/**
+Like [adamic_adar_index](./fn.adamic_adar_index.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `pairs`: the pairs of nodes to score; if `None` every pair of nodes that aren't joined by
+  an edge is scored
+* `max_degree`: when `pairs` is `None`, only pairs where both nodes have at most this many
+  neighbors are scored
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each pair that has
+  been scored is a unit of work for its `progress`
+
+# Returns
+
+The same as [adamic_adar_index](./fn.adamic_adar_index.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::link_prediction, generators, Parallelism, ParallelOptions};
+
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let graph = generators::classic::complete_graph(5, false);
+let (u, v, score) =
+    link_prediction::adamic_adar_index_with_options(&graph, Some(&[(0, 1)]), None, &options)
+        .unwrap()
+        .next()
+        .unwrap();
+assert!((score - 3.0 / 4.0_f64.ln()).abs() < 1e-10);
+```
+*/
+pub fn adamic_adar_index_with_options<T, A>(
+    graph: &Graph<T, A>,
+    pairs: Option<&[(T, T)]>,
+    max_degree: Option<usize>,
+    options: &ParallelOptions,
+) -> Result<impl Iterator<Item = (T, T, f64)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    score_pairs(graph, pairs, max_degree, options, |csr, u, v| {
+        get_common_neighbors(csr, u, v)
+            .map(|w| 1.0 / (csr.get_successors_or_neighbors(w).len() as f64).ln())
+            .sum()
+    })
+}
+
+/**
+Computes the preferential attachment score of pairs of nodes: the product of their degrees.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `pairs`: the pairs of nodes to score; if `None` every pair of nodes that aren't joined by
+  an edge is scored
+* `max_degree`: when `pairs` is `None`, only pairs where both nodes have at most this many
+  neighbors are scored
+
+# Returns
+
+An iterator of `(u, v, score)` tuples, in the order of `pairs`, or ordered by `u` and then
+`v` if `pairs` is `None`. An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned
+if the graph is directed, and one with a `kind` of `ErrorKind::NodeNotFound` if a node in
+`pairs` doesn't exist.
+
+# Examples
+
+```
+use graphrs::{algorithms::link_prediction, generators};
+
+let graph = generators::classic::star_graph(4, false);
+let scores: Vec<(i32, i32, f64)> =
+    link_prediction::preferential_attachment(&graph, None, None).unwrap().collect();
+assert_eq!(scores.len(), 6);
+assert!(scores.iter().all(|(_u, _v, score)| *score == 1.0));
+```
+
+# References
+
+1. A.-L. Barabási and R. Albert. Emergence of Scaling in Random Networks.
+   Science, 286(5439), 509–512, 1999.
+*/
+pub fn preferential_attachment<T, A>(
+    graph: &Graph<T, A>,
+    pairs: Option<&[(T, T)]>,
+    max_degree: Option<usize>,
+) -> Result<impl Iterator<Item = (T, T, f64)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    preferential_attachment_with_options(graph, pairs, max_degree, &ParallelOptions::default())
+}
+
+/**
+Like [preferential_attachment](./fn.preferential_attachment.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `pairs`: the pairs of nodes to score; if `None` every pair of nodes that aren't joined by
+  an edge is scored
+* `max_degree`: when `pairs` is `None`, only pairs where both nodes have at most this many
+  neighbors are scored
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each pair that has
+  been scored is a unit of work for its `progress`
+
+# Returns
+
+The same as [preferential_attachment](./fn.preferential_attachment.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::link_prediction, generators, Parallelism, ParallelOptions};
+
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let graph = generators::classic::star_graph(4, false);
+let scores: Vec<(i32, i32, f64)> =
+    link_prediction::preferential_attachment_with_options(&graph, None, None, &options)
+        .unwrap()
+        .collect();
+assert_eq!(scores.len(), 6);
+```
+*/
+pub fn preferential_attachment_with_options<T, A>(
+    graph: &Graph<T, A>,
+    pairs: Option<&[(T, T)]>,
+    max_degree: Option<usize>,
+    options: &ParallelOptions,
+) -> Result<impl Iterator<Item = (T, T, f64)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    score_pairs(graph, pairs, max_degree, options, |csr, u, v| {
+        (csr.get_successors_or_neighbors(u).len() * csr.get_successors_or_neighbors(v).len()) as f64
+    })
+}
+
+/**
+Computes the resource allocation index of pairs of nodes: the sum of `1 / d` over the
+neighbors they have in common, where `d` is the degree of the common neighbor. It penalizes
+high-degree common neighbors more heavily than the
+[Adamic–Adar index](fn.adamic_adar_index.html).
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `pairs`: the pairs of nodes to score; if `None` every pair of nodes that aren't joined by
+  an edge is scored
+* `max_degree`: when `pairs` is `None`, only pairs where both nodes have at most this many
+  neighbors are scored
+
+# Returns
+
+An iterator of `(u, v, score)` tuples, in the order of `pairs`, or ordered by `u` and then
+`v` if `pairs` is `None`. An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned
+if the graph is directed, and one with a `kind` of `ErrorKind::NodeNotFound` if a node in
+`pairs` doesn't exist.
+
+# Examples
+
+```
+use graphrs::{algorithms::link_prediction, generators};
+
+let graph = generators::classic::complete_graph(5, false);
+let scores: Vec<(i32, i32, f64)> =
+    link_prediction::resource_allocation_index(&graph, Some(&[(0, 1)]), None)
+        .unwrap()
+        .collect();
+assert_eq!(scores, vec![(0, 1, 0.75)]);
+```
+
+# References
+
+1. T. Zhou, L. Lü and Y.-C. Zhang. Predicting missing links via local information.
+   The European Physical Journal B, 71(4), 623–630, 2009.
+*/
+pub fn resource_allocation_index<T, A>(
+    graph: &Graph<T, A>,
+    pairs: Option<&[(T, T)]>,
+    max_degree: Option<usize>,
+) -> Result<impl Iterator<Item = (T, T, f64)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    resource_allocation_index_with_options(graph, pairs, max_degree, &ParallelOptions::default())
+}
+
+/**
+Like [resource_allocation_index](./fn.resource_allocation_index.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `pairs`: the pairs of nodes to score; if `None` every pair of nodes that aren't joined by
+  an edge is scored
+* `max_degree`: when `pairs` is `None`, only pairs where both nodes have at most this many
+  neighbors are scored
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each pair that has
+  been scored is a unit of work for its `progress`
+
+# Returns
+
+The same as [resource_allocation_index](./fn.resource_allocation_index.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::link_prediction, generators, Parallelism, ParallelOptions};
+
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let graph = generators::classic::complete_graph(5, false);
+let scores: Vec<(i32, i32, f64)> = link_prediction::resource_allocation_index_with_options(
+    &graph,
+    Some(&[(0, 1)]),
+    None,
+    &options,
+)
+.unwrap()
+.collect();
+assert_eq!(scores, vec![(0, 1, 0.75)]);
+```
+*/
+pub fn resource_allocation_index_with_options<T, A>(
+    graph: &Graph<T, A>,
+    pairs: Option<&[(T, T)]>,
+    max_degree: Option<usize>,
+    options: &ParallelOptions,
+) -> Result<impl Iterator<Item = (T, T, f64)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    score_pairs(graph, pairs, max_degree, options, |csr, u, v| {
+        get_common_neighbors(csr, u, v)
+            .map(|w| 1.0 / csr.get_successors_or_neighbors(w).len() as f64)
+            .sum()
+    })
+}
+
+/// Scores `pairs`, or the non-edges if `pairs` is `None`, with `score` and the threads
+/// of `options`.
+fn score_pairs<T, A, F>(
+    graph: &Graph<T, A>,
+    pairs: Option<&[(T, T)]>,
+    max_degree: Option<usize>,
+    options: &ParallelOptions,
+    score: F,
+) -> Result<impl Iterator<Item = (T, T, f64)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+    F: Fn(&CsrGraph<T>, u32, u32) -> f64 + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let csr = graph.to_csr();
+    let index_pairs: Vec<(u32, u32)> = match pairs {
+        Some(pairs) => {
+            let us: Vec<T> = pairs.iter().map(|(u, _v)| u.clone()).collect();
+            let vs: Vec<T> = pairs.iter().map(|(_u, v)| v.clone()).collect();
+            csr.get_node_indexes(&us)?.into_iter().zip(csr.get_node_indexes(&vs)?).collect()
+        }
+        None => get_non_edges(&csr, max_degree.unwrap_or(usize::MAX), options)?,
+    };
+    let names = csr.get_node_names();
+    let scores: Vec<(T, T, f64)> = options.map_collect_with_progress(index_pairs.len(), |i| {
+        let (u, v) = index_pairs[i];
+        (
+            names[u as usize].clone(),
+            names[v as usize].clone(),
+            score(&csr, u, v),
+        )
+    })?;
+    Ok(scores.into_iter())
+}
+
+/// Returns the pairs `(u, v)`, with `u < v`, that aren't joined by an edge and where both
+/// nodes have at most `max_degree` neighbors.
+fn get_non_edges<T>(
+    csr: &CsrGraph<T>,
+    max_degree: usize,
+    options: &ParallelOptions,
+) -> Result<Vec<(u32, u32)>, Error>
+where
+    T: Hash + Eq + Clone + Display + Send + Sync,
+{
+    let candidates: Vec<u32> = (0..csr.number_of_nodes() as u32)
+        .filter(|v| csr.get_successors_or_neighbors(*v).len() <= max_degree)
+        .collect();
+    let non_edges: Vec<Vec<(u32, u32)>> = options.map_collect(candidates.len(), |i| {
+        let u = candidates[i];
+        let neighbors = csr.get_successors_or_neighbors(u);
+        candidates[i + 1..]
+            .iter()
+            .filter(|v| neighbors.binary_search(v).is_err())
+            .map(|v| (u, *v))
+            .collect()
+    })?;
+    Ok(non_edges.into_iter().flatten().collect())
+}
+
+/// Returns the neighbors that `u` and `v` have in common, by merging their sorted neighbors.
+fn get_common_neighbors<'a, T>(
+    csr: &'a CsrGraph<T>,
+    u: u32,
+    v: u32,
+) -> impl Iterator<Item = u32> + 'a
+where
+    T: Hash + Eq + Clone + Display,
+{
+    let u_neighbors = csr.get_successors_or_neighbors(u);
+    let v_neighbors = csr.get_successors_or_neighbors(v);
+    let mut j = 0;
+    u_neighbors.iter().filter_map(move |w| {
+        while j < v_neighbors.len() && v_neighbors[j] < *w {
+            j += 1;
+        }
+        match j < v_neighbors.len() && v_neighbors[j] == *w {
+            true => Some(*w),
+            false => None,
+        }
+    })
+}
@@ -0,0 +1,570 @@
+/**
+Edmonds' blossom algorithm for maximum weight matching in general graphs, with the
+primal-dual method of Galil. This follows the structure of Joris van Rantwijk's
+`mwmatching` implementation.
+
+Nodes are numbered `0..n` and blossoms `n..2n`. Each edge `k` has two endpoints,
+numbered `2k` and `2k + 1`; `endpoint[p]` is the node at endpoint `p`, and `p ^ 1` is
+the other end of the same edge.
+*/
+const NONE: usize = usize::MAX;
+
+/**
+Finds a maximum weight matching of a graph with nodes `0..num_nodes` and `edges` of
+(`u`, `v`, weight). If `max_cardinality` is `true` only matchings with the largest
+possible number of edges are considered.
+
+Returns the node each node is matched with, if any.
+*/
+pub fn max_weight_matching(
+    num_nodes: usize,
+    edges: &[(usize, usize, f64)],
+    max_cardinality: bool,
+) -> Vec<Option<usize>> {
+    if edges.is_empty() {
+        return vec![None; num_nodes];
+    }
+    let mut state = BlossomState::new(num_nodes, edges);
+    state.solve(max_cardinality);
+    state
+        .mate
+        .iter()
+        .map(|p| match *p {
+            NONE => None,
+            p => Some(state.endpoint[p]),
+        })
+        .collect()
+}
+
+struct BlossomState<'a> {
+    n: usize,
+    edges: &'a [(usize, usize, f64)],
+    endpoint: Vec<usize>,
+    /// the remote endpoints of the edges of each node
+    neighbend: Vec<Vec<usize>>,
+    /// the remote endpoint of the matched edge of each node
+    mate: Vec<usize>,
+    /// 0 if unlabeled, 1 if S (outer), 2 if T (inner), 5 while being scanned
+    label: Vec<i32>,
+    /// the endpoint through which each labeled node or blossom got its label
+    labelend: Vec<usize>,
+    /// the top-level blossom each node belongs to
+    inblossom: Vec<usize>,
+    blossomparent: Vec<usize>,
+    blossomchilds: Vec<Vec<usize>>,
+    blossombase: Vec<usize>,
+    blossomendps: Vec<Vec<usize>>,
+    /// the least-slack edge to a different S-blossom, or to an S-node for unlabeled nodes
+    bestedge: Vec<usize>,
+    blossombestedges: Vec<Option<Vec<usize>>>,
+    unusedblossoms: Vec<usize>,
+    dualvar: Vec<f64>,
+    allowedge: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+impl<'a> BlossomState<'a> {
+    fn new(n: usize, edges: &'a [(usize, usize, f64)]) -> BlossomState<'a> {
+        let max_weight = edges.iter().map(|e| e.2).fold(0.0, f64::max);
+        let mut neighbend = vec![vec![]; n];
+        for (k, (i, j, _)) in edges.iter().enumerate() {
+            neighbend[*i].push(2 * k + 1);
+            neighbend[*j].push(2 * k);
+        }
+        BlossomState {
+            n,
+            edges,
+            endpoint: (0..2 * edges.len())
+                .map(|p| match p % 2 {
+                    0 => edges[p / 2].0,
+                    _ => edges[p / 2].1,
+                })
+                .collect(),
+            neighbend,
+            mate: vec![NONE; n],
+            label: vec![0; 2 * n],
+            labelend: vec![NONE; 2 * n],
+            inblossom: (0..n).collect(),
+            blossomparent: vec![NONE; 2 * n],
+            blossomchilds: vec![vec![]; 2 * n],
+            blossombase: (0..n).chain((0..n).map(|_| NONE)).collect(),
+            blossomendps: vec![vec![]; 2 * n],
+            bestedge: vec![NONE; 2 * n],
+            blossombestedges: vec![None; 2 * n],
+            unusedblossoms: (n..2 * n).collect(),
+            dualvar: (0..2 * n)
+                .map(|i| match i < n {
+                    true => max_weight,
+                    false => 0.0,
+                })
+                .collect(),
+            allowedge: vec![false; edges.len()],
+            queue: vec![],
+        }
+    }
+
+    fn slack(&self, k: usize) -> f64 {
+        let (i, j, wt) = self.edges[k];
+        self.dualvar[i] + self.dualvar[j] - 2.0 * wt
+    }
+
+    /// Returns the nodes contained in blossom `b`.
+    fn blossom_leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = vec![];
+        let mut stack = vec![b];
+        while let Some(t) = stack.pop() {
+            match t < self.n {
+                true => leaves.push(t),
+                false => stack.extend(self.blossomchilds[t].iter().rev()),
+            }
+        }
+        leaves
+    }
+
+    /// Assigns label `t` to the top-level blossom containing node `w`, via endpoint `p`.
+    fn assign_label(&mut self, w: usize, t: i32, p: usize) {
+        let mut next = Some((w, t, p));
+        while let Some((w, t, p)) = next.take() {
+            let b = self.inblossom[w];
+            self.label[w] = t;
+            self.label[b] = t;
+            self.labelend[w] = p;
+            self.labelend[b] = p;
+            self.bestedge[w] = NONE;
+            self.bestedge[b] = NONE;
+            if t == 1 {
+                let leaves = self.blossom_leaves(b);
+                self.queue.extend(leaves);
+            } else if t == 2 {
+                // the mate of the base of an inner blossom becomes outer
+                let base = self.blossombase[b];
+                let mate = self.mate[base];
+                next = Some((self.endpoint[mate], 1, mate ^ 1));
+            }
+        }
+    }
+
+    /**
+    Traces back from nodes `v` and `w` to find a new blossom or an augmenting path.
+    Returns the base node of the new blossom, or `NONE` if an augmenting path was found.
+    */
+    fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+        let mut path = vec![];
+        let mut base = NONE;
+        while v != NONE || w != NONE {
+            let mut b = self.inblossom[v];
+            if self.label[b] & 4 != 0 {
+                base = self.blossombase[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = 5;
+            if self.labelend[b] == NONE {
+                // reached a single node, so stop tracing this path
+                v = NONE;
+            } else {
+                v = self.endpoint[self.labelend[b]];
+                b = self.inblossom[v];
+                v = self.endpoint[self.labelend[b]];
+            }
+            if w != NONE {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in path {
+            self.label[b] = 1;
+        }
+        base
+    }
+
+    /// Constructs a new blossom with base `base`, through S-nodes joined by edge `k`.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (mut v, mut w, _) = self.edges[k];
+        let bb = self.inblossom[base];
+        let mut bv = self.inblossom[v];
+        let mut bw = self.inblossom[w];
+        let b = self.unusedblossoms.pop().unwrap();
+        self.blossombase[b] = base;
+        self.blossomparent[b] = NONE;
+        self.blossomparent[bb] = b;
+        let mut path = vec![];
+        let mut endps = vec![];
+        while bv != bb {
+            self.blossomparent[bv] = b;
+            path.push(bv);
+            endps.push(self.labelend[bv]);
+            v = self.endpoint[self.labelend[bv]];
+            bv = self.inblossom[v];
+        }
+        path.push(bb);
+        path.reverse();
+        endps.reverse();
+        endps.push(2 * k);
+        while bw != bb {
+            self.blossomparent[bw] = b;
+            path.push(bw);
+            endps.push(self.labelend[bw] ^ 1);
+            w = self.endpoint[self.labelend[bw]];
+            bw = self.inblossom[w];
+        }
+        self.label[b] = 1;
+        self.labelend[b] = self.labelend[bb];
+        self.dualvar[b] = 0.0;
+        for v in self.blossom_leaves_of(&path) {
+            if self.label[self.inblossom[v]] == 2 {
+                // T-nodes inside the new blossom become S-nodes
+                self.queue.push(v);
+            }
+            self.inblossom[v] = b;
+        }
+        // find the least-slack edges from the new blossom to each other S-blossom
+        let mut bestedgeto = vec![NONE; 2 * self.n];
+        for bv in path.iter() {
+            let nblists: Vec<Vec<usize>> = match self.blossombestedges[*bv].take() {
+                None => self
+                    .blossom_leaves(*bv)
+                    .iter()
+                    .map(|v| self.neighbend[*v].iter().map(|p| p / 2).collect())
+                    .collect(),
+                Some(list) => vec![list],
+            };
+            for nblist in nblists {
+                for k in nblist {
+                    let (mut i, mut j, _) = self.edges[k];
+                    if self.inblossom[j] == b {
+                        std::mem::swap(&mut i, &mut j);
+                    }
+                    let bj = self.inblossom[j];
+                    if bj != b
+                        && self.label[bj] == 1
+                        && (bestedgeto[bj] == NONE || self.slack(k) < self.slack(bestedgeto[bj]))
+                    {
+                        bestedgeto[bj] = k;
+                    }
+                }
+            }
+            self.bestedge[*bv] = NONE;
+        }
+        let best_edges: Vec<usize> = bestedgeto.into_iter().filter(|k| *k != NONE).collect();
+        self.bestedge[b] = NONE;
+        for k in best_edges.iter() {
+            if self.bestedge[b] == NONE || self.slack(*k) < self.slack(self.bestedge[b]) {
+                self.bestedge[b] = *k;
+            }
+        }
+        self.blossombestedges[b] = Some(best_edges);
+        self.blossomchilds[b] = path;
+        self.blossomendps[b] = endps;
+    }
+
+    fn blossom_leaves_of(&self, blossoms: &[usize]) -> Vec<usize> {
+        blossoms.iter().flat_map(|b| self.blossom_leaves(*b)).collect()
+    }
+
+    /// Expands blossom `b`, either during a stage or, if `endstage`, after it.
+    fn expand_blossom(&mut self, b: usize, endstage: bool) {
+        let childs = self.blossomchilds[b].clone();
+        for s in childs.iter() {
+            let s = *s;
+            self.blossomparent[s] = NONE;
+            if s < self.n {
+                self.inblossom[s] = s;
+            } else if endstage && self.dualvar[s] == 0.0 {
+                self.expand_blossom(s, endstage);
+            } else {
+                for v in self.blossom_leaves(s) {
+                    self.inblossom[v] = s;
+                }
+            }
+        }
+        if !endstage && self.label[b] == 2 {
+            // relabel the sub-blossoms on the even-length path through the blossom
+            let entrychild = self.inblossom[self.endpoint[self.labelend[b] ^ 1]];
+            let len = childs.len() as isize;
+            let mut j = childs.iter().position(|c| *c == entrychild).unwrap() as isize;
+            let (jstep, endptrick): (isize, usize) = match j & 1 {
+                1 => {
+                    j -= len;
+                    (1, 0)
+                }
+                _ => (-1, 1),
+            };
+            let endps = self.blossomendps[b].clone();
+            let at = |v: &[usize], i: isize| v[i.rem_euclid(len) as usize];
+            let mut p = self.labelend[b];
+            while j != 0 {
+                self.label[self.endpoint[p ^ 1]] = 0;
+                self.label[self.endpoint[at(&endps, j - endptrick as isize) ^ endptrick ^ 1]] = 0;
+                self.assign_label(self.endpoint[p ^ 1], 2, p);
+                self.allowedge[at(&endps, j - endptrick as isize) / 2] = true;
+                j += jstep;
+                p = at(&endps, j - endptrick as isize) ^ endptrick;
+                self.allowedge[p / 2] = true;
+                j += jstep;
+            }
+            let bv = at(&childs, j);
+            self.label[self.endpoint[p ^ 1]] = 2;
+            self.label[bv] = 2;
+            self.labelend[self.endpoint[p ^ 1]] = p;
+            self.labelend[bv] = p;
+            self.bestedge[bv] = NONE;
+            j += jstep;
+            while at(&childs, j) != entrychild {
+                let bv = at(&childs, j);
+                if self.label[bv] == 1 {
+                    j += jstep;
+                    continue;
+                }
+                let labeled = self.blossom_leaves(bv).into_iter().find(|v| self.label[*v] != 0);
+                if let Some(v) = labeled {
+                    self.label[v] = 0;
+                    self.label[self.endpoint[self.mate[self.blossombase[bv]]]] = 0;
+                    self.assign_label(v, 2, self.labelend[v]);
+                }
+                j += jstep;
+            }
+        }
+        self.label[b] = -1;
+        self.labelend[b] = NONE;
+        self.blossomchilds[b] = vec![];
+        self.blossomendps[b] = vec![];
+        self.blossombase[b] = NONE;
+        self.blossombestedges[b] = None;
+        self.bestedge[b] = NONE;
+        self.unusedblossoms.push(b);
+    }
+
+    /// Swaps the matched and unmatched edges on the path through blossom `b` from `v` to its base.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.blossomparent[t] != b {
+            t = self.blossomparent[t];
+        }
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+        let childs = self.blossomchilds[b].clone();
+        let endps = self.blossomendps[b].clone();
+        let len = childs.len() as isize;
+        let at = |v: &[usize], i: isize| v[i.rem_euclid(len) as usize];
+        let i = childs.iter().position(|c| *c == t).unwrap();
+        let mut j = i as isize;
+        let (jstep, endptrick): (isize, usize) = match j & 1 {
+            1 => {
+                j -= len;
+                (1, 0)
+            }
+            _ => (-1, 1),
+        };
+        while j != 0 {
+            j += jstep;
+            let t = at(&childs, j);
+            let p = at(&endps, j - endptrick as isize) ^ endptrick;
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+            j += jstep;
+            let t = at(&childs, j);
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+            self.mate[self.endpoint[p]] = p ^ 1;
+            self.mate[self.endpoint[p ^ 1]] = p;
+        }
+        // rotate the blossom so that the new base is first
+        self.blossomchilds[b] = childs[i..].iter().chain(childs[..i].iter()).copied().collect();
+        self.blossomendps[b] = endps[i..].iter().chain(endps[..i].iter()).copied().collect();
+        self.blossombase[b] = self.blossombase[self.blossomchilds[b][0]];
+    }
+
+    /// Swaps the matched and unmatched edges on the augmenting path through edge `k`.
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        for (s, p) in [(v, 2 * k + 1), (w, 2 * k)].iter() {
+            let (mut s, mut p) = (*s, *p);
+            loop {
+                let bs = self.inblossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = p;
+                if self.labelend[bs] == NONE {
+                    // reached a single node
+                    break;
+                }
+                let t = self.endpoint[self.labelend[bs]];
+                let bt = self.inblossom[t];
+                s = self.endpoint[self.labelend[bt]];
+                let j = self.endpoint[self.labelend[bt] ^ 1];
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = self.labelend[bt];
+                p = self.labelend[bt] ^ 1;
+            }
+        }
+    }
+
+    fn solve(&mut self, max_cardinality: bool) {
+        let n = self.n;
+        for _ in 0..n {
+            // each stage finds an augmenting path and increases the matching by one edge
+            self.label.iter_mut().for_each(|l| *l = 0);
+            self.bestedge.iter_mut().for_each(|e| *e = NONE);
+            self.blossombestedges[n..].iter_mut().for_each(|e| *e = None);
+            self.allowedge.iter_mut().for_each(|a| *a = false);
+            self.queue.clear();
+            for v in 0..n {
+                if self.mate[v] == NONE && self.label[self.inblossom[v]] == 0 {
+                    self.assign_label(v, 1, NONE);
+                }
+            }
+            let mut augmented = false;
+            loop {
+                while !augmented {
+                    let v = match self.queue.pop() {
+                        None => break,
+                        Some(v) => v,
+                    };
+                    for p in self.neighbend[v].clone() {
+                        let k = p / 2;
+                        let w = self.endpoint[p];
+                        if self.inblossom[v] == self.inblossom[w] {
+                            continue;
+                        }
+                        let mut kslack = 0.0;
+                        if !self.allowedge[k] {
+                            kslack = self.slack(k);
+                            if kslack <= 0.0 {
+                                self.allowedge[k] = true;
+                            }
+                        }
+                        if self.allowedge[k] {
+                            if self.label[self.inblossom[w]] == 0 {
+                                self.assign_label(w, 2, p ^ 1);
+                            } else if self.label[self.inblossom[w]] == 1 {
+                                let base = self.scan_blossom(v, w);
+                                if base != NONE {
+                                    self.add_blossom(base, k);
+                                } else {
+                                    self.augment_matching(k);
+                                    augmented = true;
+                                    break;
+                                }
+                            } else if self.label[w] == 0 {
+                                self.label[w] = 2;
+                                self.labelend[w] = p ^ 1;
+                            }
+                        } else if self.label[self.inblossom[w]] == 1 {
+                            let b = self.inblossom[v];
+                            if self.bestedge[b] == NONE || kslack < self.slack(self.bestedge[b]) {
+                                self.bestedge[b] = k;
+                            }
+                        } else if self.label[w] == 0
+                            && (self.bestedge[w] == NONE || kslack < self.slack(self.bestedge[w]))
+                        {
+                            self.bestedge[w] = k;
+                        }
+                    }
+                }
+                if augmented {
+                    break;
+                }
+                // no augmenting path was found, so update the dual variables
+                let mut delta_type = 0;
+                let mut delta = 0.0;
+                let mut delta_edge = NONE;
+                let mut delta_blossom = NONE;
+                if !max_cardinality {
+                    delta_type = 1;
+                    delta = self.dualvar[..n].iter().cloned().fold(f64::INFINITY, f64::min);
+                }
+                for v in 0..n {
+                    if self.label[self.inblossom[v]] == 0 && self.bestedge[v] != NONE {
+                        let d = self.slack(self.bestedge[v]);
+                        if delta_type == 0 || d < delta {
+                            delta = d;
+                            delta_type = 2;
+                            delta_edge = self.bestedge[v];
+                        }
+                    }
+                }
+                for b in 0..2 * n {
+                    if self.blossomparent[b] == NONE
+                        && self.label[b] == 1
+                        && self.bestedge[b] != NONE
+                    {
+                        let d = self.slack(self.bestedge[b]) / 2.0;
+                        if delta_type == 0 || d < delta {
+                            delta = d;
+                            delta_type = 3;
+                            delta_edge = self.bestedge[b];
+                        }
+                    }
+                }
+                for b in n..2 * n {
+                    if self.blossombase[b] != NONE
+                        && self.blossomparent[b] == NONE
+                        && self.label[b] == 2
+                        && (delta_type == 0 || self.dualvar[b] < delta)
+                    {
+                        delta = self.dualvar[b];
+                        delta_type = 4;
+                        delta_blossom = b;
+                    }
+                }
+                if delta_type == 0 {
+                    // no further improvement is possible with max cardinality
+                    delta_type = 1;
+                    delta =
+                        self.dualvar[..n].iter().cloned().fold(f64::INFINITY, f64::min).max(0.0);
+                }
+                for v in 0..n {
+                    match self.label[self.inblossom[v]] {
+                        1 => self.dualvar[v] -= delta,
+                        2 => self.dualvar[v] += delta,
+                        _ => {}
+                    }
+                }
+                for b in n..2 * n {
+                    if self.blossombase[b] != NONE && self.blossomparent[b] == NONE {
+                        match self.label[b] {
+                            1 => self.dualvar[b] += delta,
+                            2 => self.dualvar[b] -= delta,
+                            _ => {}
+                        }
+                    }
+                }
+                match delta_type {
+                    1 => break,
+                    2 => {
+                        self.allowedge[delta_edge] = true;
+                        let (mut i, j, _) = self.edges[delta_edge];
+                        if self.label[self.inblossom[i]] == 0 {
+                            i = j;
+                        }
+                        self.queue.push(i);
+                    }
+                    3 => {
+                        self.allowedge[delta_edge] = true;
+                        self.queue.push(self.edges[delta_edge].0);
+                    }
+                    _ => self.expand_blossom(delta_blossom, false),
+                }
+            }
+            if !augmented {
+                break;
+            }
+            // expand the outer blossoms whose dual variable has dropped to zero
+            for b in n..2 * n {
+                if self.blossomparent[b] == NONE
+                    && self.blossombase[b] != NONE
+                    && self.label[b] == 1
+                    && self.dualvar[b] == 0.0
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+    }
+}
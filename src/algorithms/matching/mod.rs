@@ -0,0 +1,409 @@
+mod blossom;
+use crate::{Error, ErrorKind, Graph};
+pub(crate) use blossom::max_weight_matching as max_weight_matching_indexes;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Finds a maximum matching of a bipartite graph, using the Hopcroft–Karp algorithm.
+A matching is a set of edges that have no nodes in common.
+
+# Arguments
+
+* `graph`: an undirected, bipartite [Graph](../../struct.Graph.html) instance
+* `left_nodes`: the nodes on one side of the bipartite graph; every other node that is
+  connected to them is on the other side
+
+# Returns
+
+A `HashMap` from each matched node to the node it is matched with. Every matched pair of
+nodes appears twice: once for each node. An error is returned if two of the `left_nodes`
+are connected to each other.
+
+# Examples
+
+```
+use graphrs::{algorithms::matching, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::new("a", "x"),
+    Edge::new("a", "y"),
+    Edge::new("b", "x"),
+]);
+
+let matching = matching::hopcroft_karp(&graph, &["a", "b"]).unwrap();
+assert_eq!(matching.len(), 4);
+assert_eq!(matching.get("a"), Some(&"y"));
+assert_eq!(matching.get("x"), Some(&"b"));
+```
+
+# References
+
+1. J. E. Hopcroft and R. M. Karp. An n^5/2 algorithm for maximum matchings in bipartite
+   graphs. SIAM Journal on Computing 2(4):225–231, 1973.
+*/
+pub fn hopcroft_karp<T, A>(graph: &Graph<T, A>, left_nodes: &[T]) -> Result<HashMap<T, T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let mut left_names: Vec<&T> = left_nodes.iter().collect();
+    left_names.sort();
+    left_names.dedup();
+    for name in left_names.iter() {
+        graph.ensure_node(name)?;
+    }
+    let left_set: HashSet<&T> = left_names.iter().cloned().collect();
+    let mut right_names: Vec<&T> = vec![];
+    let mut right_indexes: HashMap<&T, usize> = HashMap::new();
+    let mut adjacency: Vec<Vec<usize>> = vec![];
+    for name in left_names.iter() {
+        let mut neighbors: Vec<&T> = graph
+            .get_successors_or_neighbors((*name).clone())
+            .into_iter()
+            .map(|n| &n.name)
+            .collect();
+        neighbors.sort();
+        let mut right = vec![];
+        for neighbor in neighbors {
+            if left_set.contains(neighbor) {
+                return Err(Error {
+                    kind: ErrorKind::InvalidArgument,
+                    message: format!(
+                        "Nodes {} and {} are both in `left_nodes` but are connected.",
+                        name, neighbor
+                    ),
+                });
+            }
+            let index = *right_indexes.entry(neighbor).or_insert_with(|| {
+                right_names.push(neighbor);
+                right_names.len() - 1
+            });
+            right.push(index);
+        }
+        adjacency.push(right);
+    }
+    let mut matcher = BipartiteMatcher::new(adjacency, right_names.len());
+    matcher.run();
+    Ok(matcher
+        .left_matches
+        .iter()
+        .enumerate()
+        .filter_map(|(u, v)| v.map(|v| (left_names[u], right_names[v])))
+        .flat_map(|(u, v)| vec![(u.clone(), v.clone()), (v.clone(), u.clone())])
+        .collect())
+}
+
+/**
+Finds a maximum weight matching of a graph: a matching where the sum of the weights of
+the edges is as large as possible. Uses Edmonds' blossom algorithm, which works for any
+undirected graph, not just bipartite ones.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `weighted`: if `false` every edge has a weight of 1, so a maximum cardinality matching
+  is found
+* `max_cardinality`: if `true` only matchings with the largest possible number of edges
+  are considered, and the one of those with the largest weight is returned
+
+# Returns
+
+A `HashMap` from each matched node to the node it is matched with. Every matched pair of
+nodes appears twice: once for each node. Self-loops are ignored.
+
+# Examples
+
+```
+use graphrs::{algorithms::matching, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("a", "b", 2.0),
+    Edge::with_weight("b", "c", 5.0),
+    Edge::with_weight("c", "d", 2.0),
+]);
+
+let matching = matching::max_weight_matching(&graph, true, false).unwrap();
+assert_eq!(matching.get("b"), Some(&"c"));
+assert_eq!(matching.len(), 2);
+let matching = matching::max_weight_matching(&graph, true, true).unwrap();
+assert_eq!(matching.get("a"), Some(&"b"));
+assert_eq!(matching.len(), 4);
+```
+
+# References
+
+1. J. Edmonds. Paths, trees, and flowers. Canadian Journal of Mathematics 17:449–467, 1965.
+2. Z. Galil. Efficient algorithms for finding maximum matching in graphs. ACM Computing
+   Surveys 18(1):23–38, 1986.
+*/
+pub fn max_weight_matching<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    max_cardinality: bool,
+) -> Result<HashMap<T, T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let mut names: Vec<&T> = graph.get_all_node_names();
+    names.sort();
+    let indexes: HashMap<&T, usize> = names.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let edges: Vec<(usize, usize, f64)> = graph
+        .get_all_edges()
+        .into_iter()
+        .filter(|e| e.u != e.v)
+        .map(|e| {
+            let weight = match weighted {
+                true => e.weight,
+                false => 1.0,
+            };
+            (indexes[&e.u], indexes[&e.v], weight)
+        })
+        .collect();
+    let mates = max_weight_matching_indexes(names.len(), &edges, max_cardinality);
+    Ok(mates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(u, v)| v.map(|v| (names[u].clone(), names[v].clone())))
+        .collect())
+}
+
+/**
+Determines whether `matching` is a maximal matching of `graph`: a matching that can't
+have another edge added to it.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `matching`: a `HashMap` from nodes to the nodes they are matched with, as returned by
+  [hopcroft_karp](fn.hopcroft_karp.html); each pair of nodes may appear once or twice
+
+# Returns
+
+`false` if `matching` isn't a matching of `graph`. An error is returned if `matching`
+contains a node that isn't in `graph`.
+
+# Examples
+
+```
+use graphrs::{algorithms::matching, Edge, Graph, GraphSpecs};
+use std::collections::HashMap;
+
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![Edge::new(1, 2), Edge::new(2, 3), Edge::new(3, 4)]);
+
+let matching: HashMap<i32, i32> = vec![(2, 3)].into_iter().collect();
+assert!(matching::is_maximal_matching(&graph, &matching).unwrap());
+let matching: HashMap<i32, i32> = vec![(1, 2)].into_iter().collect();
+assert!(!matching::is_maximal_matching(&graph, &matching).unwrap());
+```
+*/
+pub fn is_maximal_matching<T, A>(
+    graph: &Graph<T, A>,
+    matching: &HashMap<T, T>,
+) -> Result<bool, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let matched = match get_matched_nodes(graph, matching)? {
+        None => return Ok(false),
+        Some(matched) => matched,
+    };
+    Ok(graph
+        .get_all_edges()
+        .into_iter()
+        .all(|e| e.u == e.v || matched.contains(&e.u) || matched.contains(&e.v)))
+}
+
+/**
+Determines whether `matching` is a perfect matching of `graph`: a matching that includes
+every node.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `matching`: a `HashMap` from nodes to the nodes they are matched with, as returned by
+  [hopcroft_karp](fn.hopcroft_karp.html); each pair of nodes may appear once or twice
+
+# Returns
+
+`false` if `matching` isn't a matching of `graph`. An error is returned if `matching`
+contains a node that isn't in `graph`.
+
+# Examples
+
+```
+use graphrs::{algorithms::matching, Edge, Graph, GraphSpecs};
+use std::collections::HashMap;
+
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![Edge::new(1, 2), Edge::new(2, 3), Edge::new(3, 4)]);
+
+let matching: HashMap<i32, i32> = vec![(1, 2), (3, 4)].into_iter().collect();
+assert!(matching::is_perfect_matching(&graph, &matching).unwrap());
+let matching: HashMap<i32, i32> = vec![(2, 3)].into_iter().collect();
+assert!(!matching::is_perfect_matching(&graph, &matching).unwrap());
+```
+*/
+pub fn is_perfect_matching<T, A>(
+    graph: &Graph<T, A>,
+    matching: &HashMap<T, T>,
+) -> Result<bool, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let matched = match get_matched_nodes(graph, matching)? {
+        None => return Ok(false),
+        Some(matched) => matched,
+    };
+    Ok(matched.len() == graph.get_all_nodes().len())
+}
+
+/**
+Checks that `matching` is a matching of `graph` and returns the matched nodes, or `None`
+if it isn't a matching.
+*/
+fn get_matched_nodes<'a, T, A>(
+    graph: &Graph<T, A>,
+    matching: &'a HashMap<T, T>,
+) -> Result<Option<HashSet<&'a T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let mut pairs: HashSet<(&T, &T)> = HashSet::new();
+    for (u, v) in matching.iter() {
+        graph.ensure_node(u)?;
+        graph.ensure_node(v)?;
+        if u == v || graph.get_edge(u.clone(), v.clone()).is_err() {
+            return Ok(None);
+        }
+        pairs.insert(match u < v {
+            true => (u, v),
+            false => (v, u),
+        });
+    }
+    let mut matched: HashSet<&T> = HashSet::new();
+    for (u, v) in pairs {
+        if !matched.insert(u) || !matched.insert(v) {
+            return Ok(None);
+        }
+    }
+    Ok(Some(matched))
+}
+
+/// Finds a maximum matching between left nodes `0..n` and right nodes `0..m`.
+struct BipartiteMatcher {
+    /// The right nodes adjacent to each left node.
+    adjacency: Vec<Vec<usize>>,
+    left_matches: Vec<Option<usize>>,
+    right_matches: Vec<Option<usize>>,
+    /// The layer of each left node in the current phase.
+    layers: Vec<usize>,
+    /// The index of the next adjacent right node to try for each left node.
+    next_edges: Vec<usize>,
+}
+
+impl BipartiteMatcher {
+    fn new(adjacency: Vec<Vec<usize>>, num_right: usize) -> BipartiteMatcher {
+        let num_left = adjacency.len();
+        BipartiteMatcher {
+            adjacency,
+            left_matches: vec![None; num_left],
+            right_matches: vec![None; num_right],
+            layers: vec![usize::MAX; num_left],
+            next_edges: vec![0; num_left],
+        }
+    }
+
+    /// Repeatedly augments the matching along shortest augmenting paths until there are none.
+    fn run(&mut self) {
+        while self.set_layers() {
+            self.next_edges.iter_mut().for_each(|n| *n = 0);
+            for u in 0..self.adjacency.len() {
+                if self.left_matches[u].is_none() {
+                    self.augment(u);
+                }
+            }
+        }
+    }
+
+    /**
+    Uses a breadth-first search from the unmatched left nodes to put the left nodes into
+    layers. Returns `true` if an augmenting path exists.
+    */
+    fn set_layers(&mut self) -> bool {
+        let mut queue = VecDeque::new();
+        for u in 0..self.adjacency.len() {
+            match self.left_matches[u] {
+                None => {
+                    self.layers[u] = 0;
+                    queue.push_back(u);
+                }
+                Some(_) => self.layers[u] = usize::MAX,
+            }
+        }
+        let mut found = false;
+        while let Some(u) = queue.pop_front() {
+            for v in self.adjacency[u].iter() {
+                match self.right_matches[*v] {
+                    None => found = true,
+                    Some(w) => {
+                        if self.layers[w] == usize::MAX {
+                            self.layers[w] = self.layers[u] + 1;
+                            queue.push_back(w);
+                        }
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    /**
+    Uses a depth-first search through the layers to find an augmenting path starting at
+    the unmatched left node `root`, and flips the matching along it. Left nodes that lead
+    to dead ends are removed from the layers.
+    */
+    fn augment(&mut self, root: usize) {
+        let mut stack = vec![root];
+        while let Some(&u) = stack.last() {
+            if self.next_edges[u] == self.adjacency[u].len() {
+                self.layers[u] = usize::MAX;
+                stack.pop();
+                continue;
+            }
+            let v = self.adjacency[u][self.next_edges[u]];
+            self.next_edges[u] += 1;
+            match self.right_matches[v] {
+                None => {
+                    let mut v = Some(v);
+                    while let Some(u) = stack.pop() {
+                        let previous = self.left_matches[u];
+                        self.left_matches[u] = v;
+                        self.right_matches[v.unwrap()] = Some(u);
+                        v = previous;
+                    }
+                    return;
+                }
+                Some(w) => {
+                    if self.layers[w] == self.layers[u] + 1 {
+                        stack.push(w);
+                    }
+                }
+            }
+        }
+    }
+}
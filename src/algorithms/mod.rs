@@ -1,11 +1,71 @@
+/// Find approximate solutions to problems that are hard to solve exactly.
+pub mod approximation;
+
+/// Measure the tendency of nodes to connect to similar nodes.
+pub mod assortativity;
+
+/// Detect bipartite graphs, find their two sets of nodes and project them onto one set.
+pub mod bipartite;
+
 /// Compute the centrality of nodes and edges in the graph.
 pub mod centrality;
 
+/// Find the cliques of the graph.
+pub mod clique;
+
 /// Compute clustering metrics.
 pub mod cluster;
 
+/// Color the nodes of the graph so that adjacent nodes have different colors.
+pub mod coloring;
+
 /// Find community sub-structure.
 pub mod community;
 
+/// Find the connected components of the graph.
+pub mod components;
+
+/// Find how many nodes or edges must be removed to disconnect the graph.
+pub mod connectivity;
+
+/// Find the cycles of the graph.
+pub mod cycles;
+
+/// Algorithms for directed acyclic graphs.
+pub mod dag;
+
+/// Compute distance measures such as the diameter, radius and eccentricity of the graph.
+pub mod distance_measures;
+
+/// Learn vector embeddings of the nodes from random walks over the graph.
+pub mod embeddings;
+
+/// Find maximum flows and minimum cuts in networks.
+pub mod flow;
+
+/// Find the lowest common ancestors of nodes in trees and directed acyclic graphs.
+pub mod lca;
+
+/// Score pairs of nodes by how likely they are to be joined by an edge.
+pub mod link_prediction;
+
+/// Find matchings: sets of edges that have no nodes in common.
+pub mod matching;
+
+/// Partition the graph into parts of similar size with few edges between them.
+pub mod partition;
+
+/// Find all the simple paths between two nodes of the graph.
+pub mod paths;
+
+/// Generate random walks over the graph.
+pub mod random_walk;
+
 /// Compute the shortest paths and path lengths between nodes in the graph.
 pub mod shortest_path;
+
+/// Find spanning trees of the graph.
+pub mod tree;
+
+/// Traverse the nodes and edges of the graph.
+pub mod traversal;
@@ -0,0 +1,406 @@
+use crate::algorithms::community::louvain::get_rng;
+use crate::linalg::{lanczos_smallest, SparseMatrix};
+use crate::{Error, ErrorKind, Graph};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// The result of partitioning a graph.
+#[derive(Clone, Debug)]
+pub struct Partition<T> {
+    /// The part, numbered from `0`, that each node is in.
+    pub parts: HashMap<T, usize>,
+    /// The total weight of the edges between nodes in different parts.
+    pub cut_value: f64,
+}
+
+/**
+Splits an undirected graph into two parts of equal size, or sizes that differ by one, with
+the Kernighan–Lin algorithm. Starting from a random split, each pass tentatively swaps
+pairs of nodes between the parts, greedily choosing the swap that reduces the cut the most
+(or increases it the least), and then keeps the sequence of swaps that reduced the cut the
+most. Passes are repeated until one doesn't reduce the cut.
+
+The result is a local optimum, which depends on the starting split; try several `seed`s to
+find smaller cuts. Self-loops are ignored.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `weighted`: set to `true` to minimize the total weight of the cut edges rather than
+  their number; multiple edges between two nodes are summed
+* `max_iter`: the maximum number of passes; use `None` to use the default value of `10`
+* `seed`: a seed for the random number generator that chooses the starting split
+
+# Returns
+
+A [Partition](struct.Partition.html) with parts `0` and `1`. An `Error` with a `kind` of
+`ErrorKind::WrongMethod` is returned if the graph is directed, and one with a `kind` of
+`ErrorKind::EdgeWeightNotSpecified` if `weighted` is `true` and an edge doesn't have a
+weight.
+
+# Examples
+
+```
+use graphrs::{algorithms::partition, Edge, Graph, GraphSpecs};
+
+// two squares joined by a single edge
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::new(0, 1), Edge::new(1, 2), Edge::new(2, 3), Edge::new(3, 0),
+    Edge::new(4, 5), Edge::new(5, 6), Edge::new(6, 7), Edge::new(7, 4),
+    Edge::new(3, 4),
+]);
+let result = partition::kernighan_lin_bisection(&graph, false, None, Some(0)).unwrap();
+assert_eq!(result.cut_value, 1.0);
+assert_eq!(result.parts[&0], result.parts[&3]);
+assert_ne!(result.parts[&3], result.parts[&4]);
+```
+
+# References
+
+1. B. W. Kernighan and S. Lin. An efficient heuristic procedure for partitioning graphs.
+   The Bell System Technical Journal, 49(2), 291–307, 1970.
+*/
+pub fn kernighan_lin_bisection<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    max_iter: Option<u32>,
+    seed: Option<u64>,
+) -> Result<Partition<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let (nodes, adjacency) = get_adjacency(graph, weighted)?;
+    let mut rng = get_rng(seed);
+    let mut order: Vec<usize> = (0..nodes.len()).collect();
+    order.shuffle(&mut rng);
+    let mut sides = vec![false; nodes.len()];
+    for v in order.into_iter().skip(nodes.len() / 2) {
+        sides[v] = true;
+    }
+    kernighan_lin(&adjacency, &mut sides, max_iter.unwrap_or(10));
+    let labels: Vec<usize> = sides.into_iter().map(|s| s as usize).collect();
+    Ok(get_partition(nodes, &adjacency, &labels))
+}
+
+/**
+Splits an undirected graph into `num_parts` parts whose sizes differ by at most one, while
+trying to cut as few edges as possible, by recursive spectral bisection. The nodes are
+ordered by their values in the Fiedler vector of the Laplacian and split at the point
+that gives the parts the right sizes; the split is refined with the Kernighan–Lin
+algorithm, and each part is then split again until there are `num_parts` parts.
+
+Self-loops are ignored. A part that isn't connected is ordered by the eigenvector of its
+Laplacian's second smallest eigenvalue, which is `0`, and so tends to be split between its
+connected components.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `num_parts`: the number of parts, which must be at least `1` and at most the number of
+  nodes
+* `weighted`: set to `true` to minimize the total weight of the cut edges rather than
+  their number; multiple edges between two nodes are summed
+* `seed`: a seed for the random number generator that chooses the starting vectors of the
+  eigenvector computations
+
+# Returns
+
+A [Partition](struct.Partition.html) with parts `0` to `num_parts - 1`. An `Error` with a
+`kind` of `ErrorKind::WrongMethod` is returned if the graph is directed, one with a `kind`
+of `ErrorKind::InvalidArgument` if `num_parts` is out of range, one with a `kind` of
+`ErrorKind::EdgeWeightNotSpecified` if `weighted` is `true` and an edge doesn't have a
+weight, and one with a `kind` of `ErrorKind::PowerIterationFailedConvergence` if an
+eigenvector computation doesn't converge.
+
+# Examples
+
+```
+use graphrs::{algorithms::partition, generators};
+
+let graph = generators::classic::grid_2d_graph(4, 8, false);
+let result = partition::spectral_partition(&graph, 4, false, Some(1)).unwrap();
+for part in 0..4 {
+    assert_eq!(result.parts.values().filter(|p| **p == part).count(), 8);
+}
+assert_eq!(result.cut_value, 12.0);
+```
+
+# References
+
+1. A. Pothen, H. D. Simon and K.-P. Liou. Partitioning Sparse Matrices with Eigenvectors
+   of Graphs. SIAM Journal on Matrix Analysis and Applications, 11(3), 430–452, 1990.
+*/
+pub fn spectral_partition<T, A>(
+    graph: &Graph<T, A>,
+    num_parts: usize,
+    weighted: bool,
+    seed: Option<u64>,
+) -> Result<Partition<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let (nodes, adjacency) = get_adjacency(graph, weighted)?;
+    if num_parts == 0 || num_parts > nodes.len() {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!(
+                "`num_parts` was {} but it must be between 1 and the number of nodes, {}.",
+                num_parts,
+                nodes.len()
+            ),
+        });
+    }
+    let mut rng = get_rng(seed);
+    let mut labels = vec![0; nodes.len()];
+    split(
+        &adjacency,
+        (0..nodes.len()).collect(),
+        num_parts,
+        0,
+        &mut labels,
+        &mut rng,
+    )?;
+    Ok(get_partition(nodes, &adjacency, &labels))
+}
+
+/// Labels `subset` with the `num_parts` labels starting at `first_label`, bisecting it
+/// recursively.
+fn split(
+    adjacency: &[Vec<(usize, f64)>],
+    subset: Vec<usize>,
+    num_parts: usize,
+    first_label: usize,
+    labels: &mut [usize],
+    rng: &mut StdRng,
+) -> Result<(), Error> {
+    if num_parts == 1 {
+        for v in subset {
+            labels[v] = first_label;
+        }
+        return Ok(());
+    }
+    let n = subset.len();
+    let parts1 = num_parts / 2;
+    let parts2 = num_parts - parts1;
+    // give each half a number of nodes in proportion to its number of parts
+    let size1 = (n * parts1 + num_parts / 2) / num_parts;
+    let local = get_local_adjacency(adjacency, &subset);
+    let laplacian = get_laplacian(&local);
+    let null = vec![1.0 / (n as f64).sqrt(); n];
+    let (_eigenvalue, fiedler) = lanczos_smallest(&laplacian, &null, 1.0e-6, rng)?;
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|a, b| fiedler[*a].partial_cmp(&fiedler[*b]).unwrap().then(a.cmp(b)));
+    let mut sides = vec![false; n];
+    for i in order.into_iter().skip(size1) {
+        sides[i] = true;
+    }
+    kernighan_lin(&local, &mut sides, 10);
+    let subset1 = subset.iter().zip(&sides).filter(|(_v, side)| !**side).map(|(v, _)| *v);
+    let subset2 = subset.iter().zip(&sides).filter(|(_v, side)| **side).map(|(v, _)| *v);
+    let (subset1, subset2) = (subset1.collect(), subset2.collect());
+    split(adjacency, subset1, parts1, first_label, labels, rng)?;
+    split(
+        adjacency,
+        subset2,
+        parts2,
+        first_label + parts1,
+        labels,
+        rng,
+    )
+}
+
+/**
+Improves the split of the nodes of `adjacency` into two sides with the Kernighan–Lin
+algorithm, for at most `max_iter` passes. The number of nodes on each side doesn't change.
+*/
+fn kernighan_lin(adjacency: &[Vec<(usize, f64)>], sides: &mut [bool], max_iter: u32) {
+    let n = adjacency.len();
+    for _iteration in 0..max_iter {
+        // gains[v] is how much the cut shrinks if v moves to the other side
+        let mut gains: Vec<f64> = (0..n)
+            .map(|v| {
+                adjacency[v]
+                    .iter()
+                    .map(|(u, w)| match sides[*u] == sides[v] {
+                        true => -w,
+                        false => *w,
+                    })
+                    .sum()
+            })
+            .collect();
+        let mut locked = vec![false; n];
+        let mut swaps: Vec<(usize, usize)> = vec![];
+        let mut total_gain = 0.0;
+        let mut best_gain = 0.0;
+        let mut best_swaps = 0;
+        loop {
+            let mut side1: Vec<usize> = (0..n).filter(|v| !locked[*v] && !sides[*v]).collect();
+            let mut side2: Vec<usize> = (0..n).filter(|v| !locked[*v] && sides[*v]).collect();
+            if side1.is_empty() || side2.is_empty() {
+                break;
+            }
+            side1.sort_by(|a, b| gains[*b].partial_cmp(&gains[*a]).unwrap().then(a.cmp(b)));
+            side2.sort_by(|a, b| gains[*b].partial_cmp(&gains[*a]).unwrap().then(a.cmp(b)));
+            // swapping a and b gains gains[a] + gains[b] - 2w(a, b), which is at most
+            // gains[a] + gains[b], so the scan can stop once that can't beat the best swap
+            let mut best: Option<(f64, usize, usize)> = None;
+            for a in side1.iter() {
+                if let Some((gain, _a, _b)) = best {
+                    if gains[*a] + gains[side2[0]] <= gain {
+                        break;
+                    }
+                }
+                for b in side2.iter() {
+                    if let Some((gain, _a, _b)) = best {
+                        if gains[*a] + gains[*b] <= gain {
+                            break;
+                        }
+                    }
+                    let gain = gains[*a] + gains[*b] - 2.0 * get_weight(adjacency, *a, *b);
+                    match best {
+                        Some((g, _a, _b)) if g >= gain => {}
+                        _ => best = Some((gain, *a, *b)),
+                    }
+                }
+            }
+            let (gain, a, b) = best.unwrap();
+            locked[a] = true;
+            locked[b] = true;
+            // moving a and b changes the gains of their neighbors
+            for (moved, to_side) in [(a, !sides[a]), (b, !sides[b])] {
+                for (u, w) in adjacency[moved].iter() {
+                    match sides[*u] == to_side {
+                        true => gains[*u] -= 2.0 * w,
+                        false => gains[*u] += 2.0 * w,
+                    }
+                }
+            }
+            sides.swap(a, b);
+            swaps.push((a, b));
+            total_gain += gain;
+            if total_gain > best_gain + 1.0e-12 {
+                best_gain = total_gain;
+                best_swaps = swaps.len();
+            }
+        }
+        // undo the swaps after the best point
+        for (a, b) in swaps.drain(best_swaps..).rev() {
+            sides.swap(a, b);
+        }
+        if best_swaps == 0 {
+            break;
+        }
+    }
+}
+
+/// Returns the weight of the edge between `u` and `v`, or `0.0` if there isn't one.
+fn get_weight(adjacency: &[Vec<(usize, f64)>], u: usize, v: usize) -> f64 {
+    match adjacency[u].binary_search_by_key(&v, |(x, _w)| *x) {
+        Ok(i) => adjacency[u][i].1,
+        Err(_) => 0.0,
+    }
+}
+
+/**
+Returns the sorted node names and, for each node, its neighbors, sorted, with the total
+weight of the edges to them. Self-loops are left out.
+*/
+#[allow(clippy::type_complexity)]
+fn get_adjacency<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+) -> Result<(Vec<T>, Vec<Vec<(usize, f64)>>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let mut nodes: Vec<T> = graph.get_all_node_names().into_iter().cloned().collect();
+    nodes.sort_unstable();
+    let indexes: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    let mut weights: Vec<HashMap<usize, f64>> = vec![HashMap::new(); nodes.len()];
+    for edge in graph.get_all_edges() {
+        let u = indexes[&edge.u];
+        let v = indexes[&edge.v];
+        if u == v {
+            continue;
+        }
+        let weight = match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+        *weights[u].entry(v).or_insert(0.0) += weight;
+        *weights[v].entry(u).or_insert(0.0) += weight;
+    }
+    let adjacency = weights
+        .into_iter()
+        .map(|w| {
+            let mut neighbors: Vec<(usize, f64)> = w.into_iter().collect();
+            neighbors.sort_unstable_by_key(|(v, _w)| *v);
+            neighbors
+        })
+        .collect();
+    Ok((nodes, adjacency))
+}
+
+/// Returns the adjacency of the nodes in `subset`, renumbered by their position in it.
+fn get_local_adjacency(
+    adjacency: &[Vec<(usize, f64)>],
+    subset: &[usize],
+) -> Vec<Vec<(usize, f64)>> {
+    let positions: HashMap<usize, usize> =
+        subset.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+    subset
+        .iter()
+        .map(|v| {
+            let mut neighbors: Vec<(usize, f64)> = adjacency[*v]
+                .iter()
+                .filter_map(|(u, w)| positions.get(u).map(|i| (*i, *w)))
+                .collect();
+            neighbors.sort_unstable_by_key(|(i, _w)| *i);
+            neighbors
+        })
+        .collect()
+}
+
+fn get_laplacian(adjacency: &[Vec<(usize, f64)>]) -> SparseMatrix {
+    let mut triplets = vec![];
+    for (u, neighbors) in adjacency.iter().enumerate() {
+        for (v, w) in neighbors {
+            triplets.push((u, *v, -w));
+            triplets.push((u, u, *w));
+        }
+    }
+    SparseMatrix::from_triplets(adjacency.len(), adjacency.len(), triplets)
+}
+
+/// Builds a `Partition` from the label of each node.
+fn get_partition<T>(
+    nodes: Vec<T>,
+    adjacency: &[Vec<(usize, f64)>],
+    labels: &[usize],
+) -> Partition<T>
+where
+    T: Hash + Eq,
+{
+    let cut_value = adjacency
+        .iter()
+        .enumerate()
+        .flat_map(|(u, neighbors)| neighbors.iter().map(move |(v, w)| (u, *v, *w)))
+        .filter(|(u, v, _w)| u < v && labels[*u] != labels[*v])
+        .map(|(_u, _v, w)| w)
+        .sum();
+    Partition {
+        parts: nodes.into_iter().zip(labels.iter().cloned()).collect(),
+        cut_value,
+    }
+}
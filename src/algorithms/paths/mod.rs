@@ -0,0 +1,268 @@
+use crate::algorithms::traversal::get_sorted_successors_or_neighbors;
+use crate::{Error, Graph};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+An iterator over the simple paths between two nodes of a graph.
+Created by [all_simple_paths](./fn.all_simple_paths.html).
+*/
+pub struct AllSimplePathsIter<'a, T, A>
+where
+    T: PartialOrd + Send,
+    A: Clone,
+{
+    search: SimplePathSearch<'a, T, A>,
+}
+
+impl<'a, T, A> Iterator for AllSimplePathsIter<'a, T, A>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let path = self.search.next_path()?;
+        Some(path.into_iter().map(|(node, _key)| node).collect())
+    }
+}
+
+/**
+An iterator over the simple paths between two nodes of a graph, as the edges that the
+paths follow. Created by [all_simple_edge_paths](./fn.all_simple_edge_paths.html).
+*/
+pub struct AllSimpleEdgePathsIter<'a, T, A>
+where
+    T: PartialOrd + Send,
+    A: Clone,
+{
+    search: SimplePathSearch<'a, T, A>,
+}
+
+impl<'a, T, A> Iterator for AllSimpleEdgePathsIter<'a, T, A>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    type Item = Vec<(T, T, usize)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let path = self.search.next_path()?;
+        let edges =
+            path.windows(2).map(|pair| (pair[0].0.clone(), pair[1].0.clone(), pair[1].1)).collect();
+        Some(edges)
+    }
+}
+
+/**
+Finds the simple paths from `source` to `target`: the paths that don't visit any node
+more than once.
+
+The paths are found with a depth-first search as the iterator is advanced, so they don't
+all need to be held in memory at once; there can be exponentially many of them. The
+neighbors of each node are visited in sorted order, so the order of the paths is
+deterministic. In a multigraph each path is returned once, however many parallel edges it
+could follow; use [all_simple_edge_paths](./fn.all_simple_edge_paths.html) to tell them apart.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `source`: the node to start the paths from
+* `target`: the node to end the paths at
+* `cutoff`: if `Some`, only the paths with at most this many edges are found
+
+# Returns
+
+Each path is a `Vec` of node names that starts with `source` and ends with `target`. No
+paths are returned if `source` and `target` are the same node. An `Error` with a `kind` of
+`ErrorKind::NodeNotFound` is returned if `source` or `target` isn't in the graph.
+
+# Examples
+
+```
+use graphrs::{algorithms::paths, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n1", "n3"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n4"),
+]);
+let all: Vec<Vec<&str>> = paths::all_simple_paths(&graph, "n1", "n4", None).unwrap().collect();
+assert_eq!(all, vec![vec!["n1", "n2", "n3", "n4"], vec!["n1", "n3", "n4"]]);
+let short: Vec<Vec<&str>> = paths::all_simple_paths(&graph, "n1", "n4", Some(2)).unwrap().collect();
+assert_eq!(short, vec![vec!["n1", "n3", "n4"]]);
+```
+*/
+pub fn all_simple_paths<T, A>(
+    graph: &Graph<T, A>,
+    source: T,
+    target: T,
+    cutoff: Option<usize>,
+) -> Result<AllSimplePathsIter<'_, T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let search = SimplePathSearch::new(graph, source, target, cutoff, false)?;
+    Ok(AllSimplePathsIter { search })
+}
+
+/**
+Finds the simple paths from `source` to `target`, like
+[all_simple_paths](./fn.all_simple_paths.html), but as the edges that each path follows.
+In a multigraph a path is returned once for each combination of the parallel edges that
+it could follow.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `source`: the node to start the paths from
+* `target`: the node to end the paths at
+* `cutoff`: if `Some`, only the paths with at most this many edges are found
+
+# Returns
+
+Each path is a `Vec` of `(u, v, key)` tuples, where `u` and `v` are in the order that the
+path follows the edge, and `key` is the key of the edge as used by
+[Graph::get_edge_by_key](../../struct.Graph.html#method.get_edge_by_key). The `key` is
+always 0 if the graph isn't a multigraph. No paths are returned if `source` and `target`
+are the same node. An `Error` with a `kind` of `ErrorKind::NodeNotFound` is returned if
+`source` or `target` isn't in the graph.
+
+# Examples
+
+```
+use graphrs::{algorithms::paths, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs {
+    multi_edges: true,
+    ..GraphSpecs::undirected_create_missing()
+});
+graph.add_edges(vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n1", "n2"),
+    Edge::new("n3", "n2"),
+]);
+let all: Vec<Vec<(&str, &str, usize)>> =
+    paths::all_simple_edge_paths(&graph, "n1", "n3", None).unwrap().collect();
+assert_eq!(
+    all,
+    vec![
+        vec![("n1", "n2", 0), ("n2", "n3", 0)],
+        vec![("n1", "n2", 1), ("n2", "n3", 0)],
+    ]
+);
+```
+*/
+pub fn all_simple_edge_paths<T, A>(
+    graph: &Graph<T, A>,
+    source: T,
+    target: T,
+    cutoff: Option<usize>,
+) -> Result<AllSimpleEdgePathsIter<'_, T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let parallel_edges = graph.specs.multi_edges;
+    let search = SimplePathSearch::new(graph, source, target, cutoff, parallel_edges)?;
+    Ok(AllSimpleEdgePathsIter { search })
+}
+
+/**
+The state of a depth-first search for the simple paths to `target`. Each node on the
+`path` is paired with the key of the edge that the path follows to reach it.
+*/
+struct SimplePathSearch<'a, T, A>
+where
+    T: PartialOrd + Send,
+    A: Clone,
+{
+    graph: &'a Graph<T, A>,
+    target: T,
+    cutoff: usize,
+    /// whether each of the parallel edges to a neighbor is followed, rather than just one
+    parallel_edges: bool,
+    path: Vec<(T, usize)>,
+    on_path: HashSet<T>,
+    /// the neighbors, and edge keys, that are still to be followed from each node on the path
+    stack: Vec<std::vec::IntoIter<(T, usize)>>,
+}
+
+impl<'a, T, A> SimplePathSearch<'a, T, A>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    fn new(
+        graph: &'a Graph<T, A>,
+        source: T,
+        target: T,
+        cutoff: Option<usize>,
+        parallel_edges: bool,
+    ) -> Result<SimplePathSearch<'a, T, A>, Error> {
+        graph.ensure_node(&source)?;
+        graph.ensure_node(&target)?;
+        let mut search = SimplePathSearch {
+            graph,
+            target,
+            cutoff: cutoff.unwrap_or(usize::MAX),
+            parallel_edges,
+            path: vec![],
+            on_path: HashSet::new(),
+            stack: vec![],
+        };
+        if search.cutoff > 0 && source != search.target {
+            search.push(source, 0);
+        }
+        Ok(search)
+    }
+
+    /// Continues the search until the next path to `target` is found.
+    fn next_path(&mut self) -> Option<Vec<(T, usize)>> {
+        while let Some(children) = self.stack.last_mut() {
+            let (child, key) = match children.next() {
+                None => {
+                    self.stack.pop();
+                    let (node, _key) = self.path.pop().unwrap();
+                    self.on_path.remove(&node);
+                    continue;
+                }
+                Some(next) => next,
+            };
+            if self.on_path.contains(&child) {
+                continue;
+            }
+            if child == self.target {
+                let mut path = self.path.clone();
+                path.push((child, key));
+                return Some(path);
+            }
+            // the path to a child of `child` would have `self.path.len() + 1` edges
+            if self.path.len() < self.cutoff {
+                self.push(child, key);
+            }
+        }
+        None
+    }
+
+    fn push(&mut self, node: T, key: usize) {
+        let mut children = vec![];
+        for neighbor in get_sorted_successors_or_neighbors(self.graph, &node) {
+            match self.parallel_edges {
+                false => children.push((neighbor, 0)),
+                true => {
+                    let keys = self.graph.get_edge_keys(node.clone(), neighbor.clone()).unwrap();
+                    children.extend(keys.into_iter().map(|k| (neighbor.clone(), k)));
+                }
+            }
+        }
+        self.on_path.insert(node.clone());
+        self.path.push((node, key));
+        self.stack.push(children.into_iter());
+    }
+}
@@ -0,0 +1,422 @@
+use crate::algorithms::community::louvain::get_rng;
+use crate::{CsrGraph, Error, ErrorKind, Graph, ParallelOptions};
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Generates random walks that start at the given nodes. At each step the walk moves to a
+successor (or neighbor, for undirected graphs) of the current node, chosen uniformly at
+random, or with a probability proportional to the edge weight when `weighted` is `true`.
+
+A walk ends early if it reaches a node that has no successors. The walks are generated
+in parallel, but each walk has its own random number generator, seeded from `seed`, so
+the walks are reproducible.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `start_nodes`: the node that each walk starts at; a node can be repeated to generate
+  several walks from it
+* `walk_length`: the maximum number of nodes in each walk, including the start node
+* `weighted`: set to `true` to choose each step with a probability proportional to the
+  edge weight; where there are multiple edges between two nodes, the lowest weight is used
+* `seed`: a seed for the random number generator
+
+# Returns
+
+A `Vec` of walks, one for each of `start_nodes` and in the same order. An `Error` with a
+`kind` of `ErrorKind::NodeNotFound` is returned if a start node doesn't exist, and one with
+a `kind` of `ErrorKind::InvalidArgument` if `weighted` is `true` and an edge has a negative
+weight.
+
+# Examples
+
+```
+use graphrs::{algorithms::random_walk, generators};
+
+let graph = generators::social::karate_club_graph();
+let walks = random_walk::uniform_walks(&graph, &[0, 0, 33], 10, false, Some(1)).unwrap();
+assert_eq!(walks.len(), 3);
+assert_eq!(walks[2][0], 33);
+assert_eq!(walks[2].len(), 10);
+for walk in walks {
+    for pair in walk.windows(2) {
+        assert!(graph.get_edge(pair[0], pair[1]).is_ok());
+    }
+}
+```
+*/
+pub fn uniform_walks<T, A>(
+    graph: &Graph<T, A>,
+    start_nodes: &[T],
+    walk_length: usize,
+    weighted: bool,
+    seed: Option<u64>,
+) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let options = ParallelOptions::default();
+    uniform_walks_with_options(graph, start_nodes, walk_length, weighted, seed, &options)
+}
+
+/**
+Generates random walks that start at the given nodes, like
+[uniform_walks](fn.uniform_walks.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`. The walks are the
+same whichever threads are used.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `start_nodes`: the node that each walk starts at; a node can be repeated to generate
+  several walks from it
+* `walk_length`: the maximum number of nodes in each walk, including the start node
+* `weighted`: set to `true` to choose each step with a probability proportional to the
+  edge weight; where there are multiple edges between two nodes, the lowest weight is used
+* `seed`: a seed for the random number generator
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each walk that has
+  been generated is a unit of work for its `progress`
+
+# Returns
+
+The same as [uniform_walks](fn.uniform_walks.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::random_walk, generators, Parallelism, ParallelOptions};
+
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let walks =
+    random_walk::uniform_walks_with_options(&graph, &[0, 33], 10, false, Some(1), &options);
+let walks = walks.unwrap();
+assert_eq!(walks, random_walk::uniform_walks(&graph, &[0, 33], 10, false, Some(1)).unwrap());
+```
+*/
+pub fn uniform_walks_with_options<T, A>(
+    graph: &Graph<T, A>,
+    start_nodes: &[T],
+    walk_length: usize,
+    weighted: bool,
+    seed: Option<u64>,
+    options: &ParallelOptions,
+) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    generate_walks(
+        graph,
+        start_nodes,
+        walk_length,
+        weighted,
+        None,
+        seed,
+        options,
+    )
+}
+
+/**
+Generates the biased second-order random walks of node2vec, starting at the given nodes.
+The probability of stepping from the current node `v` to `x`, having arrived at `v` from
+`t`, is proportional to the weight of the `(v, x)` edge (or `1.0` when `weighted` is
+`false`) multiplied by:
+
+* `1 / p` if `x` is `t`; that is, the walk returns to the node it just left
+* `1` if `x` is a successor (or neighbor) of `t`
+* `1 / q` otherwise; that is, the walk moves further away from `t`
+
+A low `p` keeps walks close to where they started, like a breadth-first search, and a low
+`q` pushes them outwards, like a depth-first search. With `p` and `q` equal to `1.0` the
+walks are the same as those of [uniform_walks](fn.uniform_walks.html).
+
+A walk ends early if it reaches a node that has no successors. The walks are generated
+in parallel, but each walk has its own random number generator, seeded from `seed`, so
+the walks are reproducible.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `start_nodes`: the node that each walk starts at; a node can be repeated to generate
+  several walks from it
+* `walk_length`: the maximum number of nodes in each walk, including the start node
+* `p`: the return parameter, which must be positive
+* `q`: the in-out parameter, which must be positive
+* `weighted`: set to `true` to use edge weights; where there are multiple edges between
+  two nodes, the lowest weight is used
+* `seed`: a seed for the random number generator
+
+# Returns
+
+A `Vec` of walks, one for each of `start_nodes` and in the same order. An `Error` with a
+`kind` of `ErrorKind::NodeNotFound` is returned if a start node doesn't exist, and one with
+a `kind` of `ErrorKind::InvalidArgument` if `p` or `q` isn't positive, or if `weighted` is
+`true` and an edge has a negative weight.
+
+# Examples
+
+```
+use graphrs::{algorithms::random_walk, generators};
+
+let graph = generators::social::karate_club_graph();
+let walks = random_walk::biased_walks(&graph, &[0, 33], 20, 0.5, 2.0, false, Some(1)).unwrap();
+assert_eq!(walks.len(), 2);
+assert_eq!(walks[0][0], 0);
+assert_eq!(walks[0].len(), 20);
+```
+
+# References
+
+1. A. Grover and J. Leskovec. node2vec: Scalable Feature Learning for Networks.
+   Proceedings of the 22nd ACM SIGKDD International Conference on Knowledge Discovery
+   and Data Mining, 855–864, 2016.
+*/
+pub fn biased_walks<T, A>(
+    graph: &Graph<T, A>,
+    start_nodes: &[T],
+    walk_length: usize,
+    p: f64,
+    q: f64,
+    weighted: bool,
+    seed: Option<u64>,
+) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let options = ParallelOptions::default();
+    biased_walks_with_options(
+        graph,
+        start_nodes,
+        walk_length,
+        p,
+        q,
+        weighted,
+        seed,
+        &options,
+    )
+}
+
+/**
+Generates the biased second-order random walks of node2vec, like
+[biased_walks](fn.biased_walks.html), with the threads and
+[ProgressHandle](../../struct.ProgressHandle.html) given in `options`. The walks are the
+same whichever threads are used.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `start_nodes`: the node that each walk starts at; a node can be repeated to generate
+  several walks from it
+* `walk_length`: the maximum number of nodes in each walk, including the start node
+* `p`: the return parameter, which must be positive
+* `q`: the in-out parameter, which must be positive
+* `weighted`: set to `true` to use edge weights; where there are multiple edges between
+  two nodes, the lowest weight is used
+* `seed`: a seed for the random number generator
+* `options`: a [ParallelOptions](../../struct.ParallelOptions.html); each walk that has
+  been generated is a unit of work for its `progress`
+
+# Returns
+
+The same as [biased_walks](fn.biased_walks.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::random_walk, generators, Parallelism, ParallelOptions};
+
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::MaxThreads(2),
+    ..ParallelOptions::default()
+};
+let walks =
+    random_walk::biased_walks_with_options(&graph, &[0, 33], 20, 0.5, 2.0, false, Some(1), &options);
+assert_eq!(walks.unwrap()[1][0], 33);
+```
+*/
+#[allow(clippy::too_many_arguments)]
+pub fn biased_walks_with_options<T, A>(
+    graph: &Graph<T, A>,
+    start_nodes: &[T],
+    walk_length: usize,
+    p: f64,
+    q: f64,
+    weighted: bool,
+    seed: Option<u64>,
+    options: &ParallelOptions,
+) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    check_bias(p, q)?;
+    generate_walks(
+        graph,
+        start_nodes,
+        walk_length,
+        weighted,
+        Some((p, q)),
+        seed,
+        options,
+    )
+}
+
+/// Generates a walk from each of `start_nodes`, biased by `(p, q)` if given.
+fn generate_walks<T, A>(
+    graph: &Graph<T, A>,
+    start_nodes: &[T],
+    walk_length: usize,
+    weighted: bool,
+    bias: Option<(f64, f64)>,
+    seed: Option<u64>,
+    options: &ParallelOptions,
+) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let csr = get_walk_csr(graph, weighted)?;
+    let starts = csr.get_node_indexes(start_nodes)?;
+    let names = csr.get_node_names();
+    let walks = generate_index_walks(&csr, starts, walk_length, weighted, bias, seed, options)?;
+    options.map_collect(walks.len(), |i| {
+        walks[i].iter().map(|v| names[*v as usize].clone()).collect()
+    })
+}
+
+/// Returns an error if the node2vec `p` or `q` parameter isn't positive.
+pub(crate) fn check_bias(p: f64, q: f64) -> Result<(), Error> {
+    if p.is_nan() || p <= 0.0 || q.is_nan() || q <= 0.0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!(
+                "The `p` and `q` parameters must be positive, but were {} and {}.",
+                p, q
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Converts `graph` to a `CsrGraph` to walk over, checking the edge weights if `weighted`.
+pub(crate) fn get_walk_csr<T, A>(graph: &Graph<T, A>, weighted: bool) -> Result<CsrGraph<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+        if let Some(edge) = graph.get_all_edges().into_iter().find(|e| e.weight < 0.0) {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!(
+                    "The ({}, {}) edge has a negative weight; random walks require \
+                    non-negative edge weights.",
+                    edge.u, edge.v
+                ),
+            });
+        }
+    }
+    Ok(graph.to_csr())
+}
+
+/// Generates a walk of node indexes from each of `starts`, biased by `(p, q)` if given,
+/// with the threads of `options`.
+pub(crate) fn generate_index_walks<T>(
+    csr: &CsrGraph<T>,
+    starts: Vec<u32>,
+    walk_length: usize,
+    weighted: bool,
+    bias: Option<(f64, f64)>,
+    seed: Option<u64>,
+    options: &ParallelOptions,
+) -> Result<Vec<Vec<u32>>, Error>
+where
+    T: Hash + Eq + Clone + Display + Send + Sync,
+{
+    // seeding each walk's generator in turn keeps the walks independent of the threads
+    let mut rng = get_rng(seed);
+    let seeds: Vec<u64> = starts.iter().map(|_| rng.next_u64()).collect();
+    options.map_collect_with_progress(starts.len(), |i| {
+        let mut rng = StdRng::seed_from_u64(seeds[i]);
+        walk(csr, starts[i], walk_length, weighted, bias, &mut rng)
+    })
+}
+
+/// Walks from `start` until the walk has `walk_length` nodes or reaches a dead end.
+fn walk<T>(
+    csr: &CsrGraph<T>,
+    start: u32,
+    walk_length: usize,
+    weighted: bool,
+    bias: Option<(f64, f64)>,
+    rng: &mut StdRng,
+) -> Vec<u32>
+where
+    T: Hash + Eq + Clone + Display,
+{
+    let mut walk = Vec::with_capacity(walk_length);
+    if walk_length == 0 {
+        return walk;
+    }
+    walk.push(start);
+    let mut probabilities = vec![];
+    while walk.len() < walk_length {
+        let v = *walk.last().unwrap();
+        let successors = csr.get_successors_or_neighbors(v);
+        probabilities.clear();
+        probabilities.extend(csr.get_weights(v).iter().map(|w| match weighted {
+            true => *w,
+            false => 1.0,
+        }));
+        if let (Some((p, q)), Some(t)) = (bias, walk.len().checked_sub(2).map(|i| walk[i])) {
+            let t_successors = csr.get_successors_or_neighbors(t);
+            for (x, probability) in successors.iter().zip(probabilities.iter_mut()) {
+                if *x == t {
+                    *probability /= p;
+                } else if t_successors.binary_search(x).is_err() {
+                    *probability /= q;
+                }
+            }
+        }
+        match choose(&probabilities, rng) {
+            None => break,
+            Some(i) => walk.push(successors[i]),
+        }
+    }
+    walk
+}
+
+/// Chooses an index with a probability proportional to its value in `probabilities`.
+/// Returns `None` if the values sum to zero.
+fn choose(probabilities: &[f64], rng: &mut StdRng) -> Option<usize> {
+    let total: f64 = probabilities.iter().sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut remaining = rng.gen::<f64>() * total;
+    for (i, probability) in probabilities.iter().enumerate() {
+        if remaining < *probability {
+            return Some(i);
+        }
+        remaining -= probability;
+    }
+    // rounding can leave a little over; choose the last index that can be chosen
+    probabilities.iter().rposition(|p| *p > 0.0)
+}
@@ -0,0 +1,149 @@
+use crate::algorithms::shortest_path::dijkstra::{
+    get_cost_multi, get_cost_single, push_weighted_fringe_node, with_path_edge_keys,
+};
+use crate::algorithms::shortest_path::shortest_path_info::ParallelEdgeKeys;
+use crate::algorithms::shortest_path::ShortestPathInfo;
+use crate::{Error, ErrorKind, Graph};
+use num_traits::Num;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Uses the A* ("A-star") algorithm to find the shortest weighted path between
+`source` and `target`.
+
+The search is guided by a `heuristic` function that estimates the distance from a
+node to the `target`. When the heuristic is admissible (it never overestimates the
+distance) and consistent the path found is a shortest path. A heuristic that always
+returns zero makes A* equivalent to Dijkstra's algorithm.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
+* `source`: The starting node.
+* `target`: The ending node.
+* `heuristic`: A function that takes a node name and returns an estimate of the
+  distance from that node to the `target`, of the same type as the edge weights.
+
+# Returns
+
+`Ok(None)` if `target` cannot be reached from `source`, otherwise `Ok(Some(...))` with a
+single shortest path.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{astar}}};
+
+// nodes are points on a 2 x 2 grid, named `x * 10 + y`
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight(0, 1, 1.0),
+    Edge::with_weight(1, 11, 1.0),
+    Edge::with_weight(0, 10, 1.0),
+    Edge::with_weight(10, 11, 1.5),
+]);
+
+// manhattan distance to the target, node 11
+let heuristic = |n: i32| ((1 - n / 10).abs() + (1 - n % 10).abs()) as f64;
+let result = astar::single_source_target(&graph, 0, 11, heuristic).unwrap();
+let info = result.unwrap();
+assert_eq!(info.distance, 2.0);
+assert_eq!(info.paths, vec![vec![0, 1, 11]]);
+```
+
+# References
+
+1. P. E. Hart, N. J. Nilsson and B. Raphael. A Formal Basis for the Heuristic Determination
+   of Minimum Cost Paths. IEEE Transactions on Systems Science and Cybernetics, 4(2):100–107, 1968.
+*/
+pub fn single_source_target<T, A, W, F>(
+    graph: &Graph<T, A, W>,
+    source: T,
+    target: T,
+    heuristic: F,
+) -> Result<Option<ShortestPathInfo<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+    F: Fn(T) -> W,
+{
+    graph.ensure_weighted()?;
+    for node_name in [&source, &target] {
+        if !graph.has_node(node_name) {
+            return Err(Error {
+                kind: ErrorKind::NodeNotFound,
+                message: format!("Requested node '{}' was not found in the graph.", node_name),
+            });
+        }
+    }
+
+    let get_cost = |u, v| match graph.specs.multi_edges {
+        false => get_cost_single(graph, u, v),
+        true => get_cost_multi(graph, u, v),
+    };
+
+    let mut distances = HashMap::<T, W>::new();
+    let mut parents = HashMap::<T, T>::new();
+    let mut explored = HashSet::<T>::new();
+    let mut fringe = BinaryHeap::new();
+    let mut count = 0;
+
+    distances.insert(source.clone(), W::zero());
+    let estimate = heuristic(source.clone());
+    push_weighted_fringe_node(&mut count, &mut fringe, source, estimate);
+
+    while let Some(fringe_item) = fringe.pop() {
+        let v = fringe_item.node_name;
+        if explored.contains(&v) {
+            continue;
+        }
+        if v == target {
+            let info = ShortestPathInfo {
+                distance: *distances.get(&v).unwrap(),
+                paths: vec![get_path(&parents, target)],
+                edge_keys: None,
+            };
+            return Ok(Some(with_path_edge_keys(
+                info,
+                ParallelEdgeKeys::new(graph, true).as_ref(),
+            )));
+        }
+        explored.insert(v.clone());
+        let v_dist = *distances.get(&v).unwrap();
+        for node in graph.get_successors_or_neighbors(v.clone()) {
+            let u = node.name.clone();
+            if explored.contains(&u) {
+                continue;
+            }
+            let vu_dist = v_dist + get_cost(v.clone(), u.clone());
+            if let Some(u_dist) = distances.get(&u) {
+                if vu_dist >= *u_dist {
+                    continue;
+                }
+            }
+            distances.insert(u.clone(), vu_dist);
+            parents.insert(u.clone(), v.clone());
+            let estimate = vu_dist + heuristic(u.clone());
+            push_weighted_fringe_node(&mut count, &mut fringe, u, estimate);
+        }
+    }
+
+    Ok(None)
+}
+
+/// Follows the `parents` back from `target` to build the path.
+fn get_path<T>(parents: &HashMap<T, T>, target: T) -> Vec<T>
+where
+    T: Hash + Eq + Clone,
+{
+    let mut path = vec![target];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
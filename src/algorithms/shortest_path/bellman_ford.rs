@@ -0,0 +1,477 @@
+use crate::algorithms::shortest_path::dijkstra::{collect_source_results, with_edge_keys};
+use crate::algorithms::shortest_path::shortest_path_info::ParallelEdgeKeys;
+use crate::algorithms::shortest_path::ShortestPathInfo;
+use crate::{Error, ErrorKind, Graph, ParallelOptions};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Uses the Bellman-Ford algorithm to find shortest weighted paths between all pairs
+of nodes. Unlike Dijkstra's algorithm, Bellman-Ford supports negative edge weights.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `first_only`: If `true` returns the first shortest path found for each source and target,
+  if `false` returns all shortest paths found between sources and targets.
+
+# Returns
+
+A `HashMap` of `HashMaps`. The keys to the first one are the starting nodes
+and the keys to the second are the target nodes. If a negative cycle is reachable
+from any node an `Error` with a `kind` of `ErrorKind::NegativeCycle` is returned for
+the first such node in sorted order.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{bellman_ford}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 4.0),
+    Edge::with_weight("n1", "n3", 1.0),
+    Edge::with_weight("n2", "n3", -5.0),
+]);
+
+let all_pairs = bellman_ford::all_pairs(&graph, true, false);
+assert_eq!(all_pairs.unwrap().get("n1").unwrap().get("n3").unwrap().distance, -1.0);
+```
+
+# References
+
+1. Richard Bellman. On a routing problem. Quarterly of Applied Mathematics, 16:87–90, 1958.
+*/
+pub fn all_pairs<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    first_only: bool,
+) -> Result<HashMap<T, HashMap<T, ShortestPathInfo<T>>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    all_pairs_with_options(graph, weighted, first_only, &ParallelOptions::default())
+}
+
+/**
+Uses the Bellman-Ford algorithm to find shortest weighted paths between all pairs
+of nodes, like [all_pairs](./fn.all_pairs.html), with the threads and
+[ProgressHandle](../../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `first_only`: If `true` returns the first shortest path found for each source and target,
+  if `false` returns all shortest paths found between sources and targets.
+* `options`: a [ParallelOptions](../../../struct.ParallelOptions.html); each starting node
+  that has been searched from is a unit of work for its `progress`
+
+# Returns
+
+The same as [all_pairs](./fn.all_pairs.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::shortest_path::bellman_ford, generators, Parallelism, ParallelOptions};
+
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let all_pairs = bellman_ford::all_pairs_with_options(&graph, false, true, &options);
+assert_eq!(all_pairs.unwrap().len(), 34);
+```
+*/
+#[allow(clippy::type_complexity)]
+pub fn all_pairs_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    first_only: bool,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, HashMap<T, ShortestPathInfo<T>>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut names: Vec<T> = graph.get_all_node_names().into_iter().cloned().collect();
+    names.sort();
+    let progress = &options.progress;
+    progress.start(Some(names.len()))?;
+    let results = options.map_collect(names.len(), |index| {
+        progress.check()?;
+        let result = single_source(graph, weighted, names[index].clone(), None, first_only);
+        progress.advance();
+        result
+    })?;
+    progress.check()?;
+    collect_source_results(&names, results)
+}
+
+/**
+Uses the Bellman-Ford algorithm to find shortest weighted paths from a single source node.
+Edges may have negative weights.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `source`: The starting node.
+* `target`: The ending node. If `None` then the shortest paths between `source` and
+  all other nodes will be found.
+* `first_only`: If `true` returns the first shortest path found for each target, if
+  `false` returns all shortest paths found between source and targets.
+
+# Returns
+
+If a negative cycle is reachable from `source` an `Error` with a `kind` of
+`ErrorKind::NegativeCycle` is returned; the error message lists the nodes of the cycle
+when they can be found.
+
+# Examples
+
+```
+use graphrs::{Edge, ErrorKind, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{bellman_ford}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n3", -2.0),
+    Edge::with_weight("n3", "n1", 0.5),
+]);
+
+let result = bellman_ford::single_source(&graph, true, "n1", None, false);
+assert!(matches!(result.err().unwrap().kind, ErrorKind::NegativeCycle));
+```
+
+# References
+
+1. Richard Bellman. On a routing problem. Quarterly of Applied Mathematics, 16:87–90, 1958.
+*/
+pub fn single_source<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    source: T,
+    target: Option<T>,
+    first_only: bool,
+) -> Result<HashMap<T, ShortestPathInfo<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    multi_source(graph, weighted, vec![source], target, first_only)
+}
+
+/**
+Uses the Bellman-Ford algorithm to find shortest weighted paths from multiple source nodes.
+Edges may have negative weights.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `sources`: The starting nodes. The shortest path will be found that can start
+  for any of the `sources` and ends at the `target`.
+* `target`: The ending node. If `None` then the shortest paths between `sources` and
+  all other nodes will be found.
+* `first_only`: If `true` returns the first shortest path found for each target, if
+  `false` returns all shortest paths found between sources and targets.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{bellman_ford}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", -1.0),
+]);
+
+let result = bellman_ford::multi_source(&graph, true, vec!["n1", "n2"], Some("n3"), false);
+assert_eq!(result.unwrap().get("n3").unwrap().distance, -1.0);
+```
+*/
+pub fn multi_source<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    sources: Vec<T>,
+    target: Option<T>,
+    first_only: bool,
+) -> Result<HashMap<T, ShortestPathInfo<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let (distances, preds) = bellman_ford_multisource(graph, weighted, sources)?;
+    let infos = distances
+        .into_iter()
+        .filter(|(k, _v)| target.is_none() || k == target.as_ref().unwrap())
+        .map(|(k, distance)| {
+            let paths = get_paths(k.clone(), &preds, first_only);
+            let info = ShortestPathInfo {
+                distance,
+                paths,
+                edge_keys: None,
+            };
+            (k, info)
+        })
+        .collect();
+    Ok(with_edge_keys(
+        infos,
+        ParallelEdgeKeys::new(graph, weighted).as_ref(),
+    ))
+}
+
+/**
+Runs the Bellman-Ford algorithm from multiple sources, returning the distances to,
+and the shortest-path predecessors of, every node reachable from the sources.
+*/
+#[allow(clippy::type_complexity)]
+pub(crate) fn bellman_ford_multisource<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    sources: Vec<T>,
+) -> Result<(HashMap<T, f64>, HashMap<T, Vec<T>>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    for source in &sources {
+        if !graph.has_node(source) {
+            return Err(Error {
+                kind: ErrorKind::NodeNotFound,
+                message: format!("Requested node '{}' was not found in the graph.", source),
+            });
+        }
+    }
+
+    let arcs = get_arcs(graph, weighted);
+    let num_nodes = graph.get_all_nodes().len();
+    let mut dist: HashMap<T, f64> = sources.iter().map(|s| (s.clone(), 0.0)).collect();
+    let mut preds: HashMap<T, Vec<T>> = sources.iter().map(|s| (s.clone(), vec![])).collect();
+    let mut parent: HashMap<T, T> = HashMap::new();
+
+    // a shortest path has at most `num_nodes - 1` edges, so if relaxations are still
+    // happening in pass `num_nodes` then a negative cycle is reachable
+    for i in 0..(2 * num_nodes) {
+        let changed = relax_arcs(&arcs, &mut dist, &mut preds, &mut parent);
+        if !changed {
+            return Ok((dist, preds));
+        }
+        if i + 1 >= num_nodes {
+            if let Some(cycle) = find_cycle_in_parents(&parent) {
+                return Err(get_negative_cycle_error(&cycle));
+            }
+        }
+    }
+    // the parents didn't form a cycle after any of the passes, so the nodes of the
+    // negative cycle aren't known
+    Err(Error {
+        kind: ErrorKind::NegativeCycle,
+        message: "A negative cycle was found.".to_string(),
+    })
+}
+
+/**
+Relaxes every arc once, updating the distances, predecessors and parents.
+Returns `true` if any distance was reduced. Sources are only given predecessors when
+their distance is reduced below zero.
+*/
+#[inline]
+fn relax_arcs<T>(
+    arcs: &[(T, T, f64)],
+    dist: &mut HashMap<T, f64>,
+    preds: &mut HashMap<T, Vec<T>>,
+    parent: &mut HashMap<T, T>,
+) -> bool
+where
+    T: Hash + Eq + Clone,
+{
+    let mut changed = false;
+    for (u, v, w) in arcs {
+        let du = match dist.get(u) {
+            None => continue,
+            Some(du) => *du,
+        };
+        let new_dist = du + w;
+        match dist.get(v) {
+            Some(dv) if new_dist > *dv => {}
+            Some(dv) if new_dist == *dv => {
+                // a node with no predecessors is a source that no path has improved on; a
+                // zero-weight cycle back to it mustn't give it any, or it would have no paths
+                let v_preds = preds.entry(v.clone()).or_default();
+                if !v_preds.is_empty() && !v_preds.contains(u) {
+                    v_preds.push(u.clone());
+                }
+            }
+            _ => {
+                dist.insert(v.clone(), new_dist);
+                preds.insert(v.clone(), vec![u.clone()]);
+                parent.insert(v.clone(), u.clone());
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+/**
+Returns all the (u, v, weight) arcs of the graph. For an undirected graph
+each edge is returned in both directions.
+*/
+fn get_arcs<T, A>(graph: &Graph<T, A>, weighted: bool) -> Vec<(T, T, f64)>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    graph
+        .get_all_edges()
+        .into_iter()
+        .flat_map(|e| {
+            let w = match weighted {
+                true => e.weight,
+                false => 1.0,
+            };
+            let mut arcs = vec![(e.u.clone(), e.v.clone(), w)];
+            if !graph.specs.directed && e.u != e.v {
+                arcs.push((e.v.clone(), e.u.clone(), w));
+            }
+            arcs
+        })
+        .collect()
+}
+
+/**
+Follows the `parent` pointers looking for a cycle. Returns the nodes
+of the first cycle found, in path order.
+*/
+fn find_cycle_in_parents<T>(parent: &HashMap<T, T>) -> Option<Vec<T>>
+where
+    T: Hash + Eq + Clone + Ord,
+{
+    let mut done: HashSet<&T> = HashSet::new();
+    let mut starts: Vec<&T> = parent.keys().collect();
+    starts.sort();
+    for start in starts {
+        let mut walk: Vec<&T> = vec![];
+        let mut on_walk: HashSet<&T> = HashSet::new();
+        let mut current = Some(start);
+        while let Some(node) = current {
+            if done.contains(node) {
+                break;
+            }
+            if on_walk.contains(node) {
+                let pos = walk.iter().position(|n| *n == node).unwrap();
+                let mut cycle: Vec<T> = walk[pos..].iter().map(|n| (*n).clone()).collect();
+                // the walk follows parents backwards; reverse it so the cycle is in path order
+                cycle.reverse();
+                return Some(cycle);
+            }
+            walk.push(node);
+            on_walk.insert(node);
+            current = parent.get(node);
+        }
+        done.extend(walk);
+    }
+    None
+}
+
+/**
+Builds the paths that lead to `target` from the shortest-path predecessors.
+Nodes already on a path are not revisited, which protects against zero-weight cycles.
+*/
+fn get_paths<T>(target: T, preds: &HashMap<T, Vec<T>>, first_only: bool) -> Vec<Vec<T>>
+where
+    T: Hash + Eq + Clone,
+{
+    let mut paths = vec![];
+    let mut stack = vec![vec![target]];
+    while let Some(reversed_path) = stack.pop() {
+        let node = reversed_path.last().unwrap();
+        let node_preds = preds.get(node).unwrap();
+        if node_preds.is_empty() {
+            paths.push(reversed_path.into_iter().rev().collect());
+            if first_only {
+                break;
+            }
+            continue;
+        }
+        for pred in node_preds.iter().rev() {
+            if !reversed_path.contains(pred) {
+                let mut new_path = reversed_path.clone();
+                new_path.push(pred.clone());
+                stack.push(new_path);
+            }
+        }
+    }
+    paths
+}
+
+/// Returns the `Error` object for a negative-cycle error.
+fn get_negative_cycle_error<T>(cycle: &[T]) -> Error
+where
+    T: Display,
+{
+    let nodes = cycle.iter().map(|n| n.to_string()).collect::<Vec<String>>();
+    Error {
+        kind: ErrorKind::NegativeCycle,
+        message: format!("A negative cycle was found: [{}].", nodes.join(", ")),
+    }
+}
+
+// tests for private methods only; other tests are in:
+// tests/test_algorithms_shortest_path_bellman_ford.rs
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_find_cycle_in_parents_1() {
+        let parent: HashMap<&str, &str> =
+            vec![("n2", "n1"), ("n3", "n2"), ("n4", "n3")].into_iter().collect();
+        assert!(find_cycle_in_parents(&parent).is_none());
+    }
+
+    #[test]
+    fn test_find_cycle_in_parents_2() {
+        let parent: HashMap<&str, &str> =
+            vec![("n2", "n4"), ("n3", "n2"), ("n4", "n3"), ("n5", "n4")].into_iter().collect();
+        let cycle = find_cycle_in_parents(&parent).unwrap();
+        assert_eq!(cycle.len(), 3);
+        let pos = cycle.iter().position(|n| *n == "n2").unwrap();
+        assert_eq!(cycle[(pos + 1) % 3], "n3");
+        assert_eq!(cycle[(pos + 2) % 3], "n4");
+    }
+
+    #[test]
+    fn test_get_paths_1() {
+        let preds: HashMap<&str, Vec<&str>> = vec![
+            ("n1", vec![]),
+            ("n2", vec!["n1"]),
+            ("n3", vec!["n1"]),
+            ("n4", vec!["n2", "n3"]),
+        ]
+        .into_iter()
+        .collect();
+        let paths = get_paths("n4", &preds, false);
+        assert_eq!(paths, vec![vec!["n1", "n2", "n4"], vec!["n1", "n3", "n4"]]);
+        let paths = get_paths("n4", &preds, true);
+        assert_eq!(paths, vec![vec!["n1", "n2", "n4"]]);
+    }
+}
@@ -0,0 +1,452 @@
+use crate::algorithms::shortest_path::dijkstra::{push_fringe_node, FringeNode};
+use crate::algorithms::shortest_path::ShortestPathInfo;
+use crate::{Error, ErrorKind, Graph};
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// The maximum number of nodes settled by a witness search during preprocessing.
+/// Stopping a witness search early only adds unnecessary shortcuts.
+const WITNESS_SEARCH_SETTLE_LIMIT: usize = 500;
+
+/**
+A contraction hierarchy: a preprocessed form of a graph that answers point-to-point
+shortest path queries much faster than Dijkstra's algorithm.
+
+Preprocessing contracts the nodes one at a time, from least to most important, adding
+"shortcut" edges that preserve the shortest path distances between the remaining nodes.
+A query then only has to search "upwards" in the hierarchy from both the source and the
+target, which visits a small fraction of the graph on road-network-like graphs.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{contraction_hierarchy::ContractionHierarchy}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+]);
+
+let ch = ContractionHierarchy::build(&graph, true).unwrap();
+let info = ch.query("n1", "n3").unwrap().unwrap();
+assert_eq!(info.distance, 2.1);
+assert_eq!(info.paths, vec![vec!["n1", "n2", "n3"]]);
+assert!(ch.query("n3", "n1").unwrap().is_none());
+```
+
+# References
+
+1. R. Geisberger, P. Sanders, D. Schultes and D. Delling. Contraction Hierarchies: Faster and
+   Simpler Hierarchical Routing in Road Networks. WEA 2008, LNCS 5038:319–333, 2008.
+*/
+pub struct ContractionHierarchy<T> {
+    nodes: Vec<T>,
+    node_indexes: HashMap<T, usize>,
+    /// Arcs that lead from a node to a more important node.
+    upward: Vec<Vec<(usize, f64)>>,
+    /// Arcs that lead to a node from a more important node, stored reversed.
+    downward: Vec<Vec<(usize, f64)>>,
+    /// For each shortcut arc, the contracted node that it bypasses.
+    middles: HashMap<(usize, usize), usize>,
+}
+
+impl<T> ContractionHierarchy<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    /**
+    Builds a contraction hierarchy for `graph`. Edge weights must not be negative.
+
+    # Arguments
+
+    * `graph`: a [Graph](../../../struct.Graph.html) instance
+    * `weighted`: determines if shortest paths are determined with edge weight, or not
+    */
+    pub fn build<A>(graph: &Graph<T, A>, weighted: bool) -> Result<ContractionHierarchy<T>, Error>
+    where
+        A: Clone,
+    {
+        if weighted {
+            graph.ensure_weighted()?;
+        }
+
+        let mut nodes = graph.get_all_node_names().into_iter().cloned().collect::<Vec<T>>();
+        nodes.sort();
+        let node_indexes: HashMap<T, usize> =
+            nodes.iter().enumerate().map(|(i, n)| (n.clone(), i)).collect();
+
+        let mut arcs = Arcs::new(nodes.len());
+        for edge in graph.get_all_edges() {
+            let weight = match weighted {
+                true => edge.weight,
+                false => 1.0,
+            };
+            if weight < 0.0 {
+                return Err(Error {
+                    kind: ErrorKind::InvalidArgument,
+                    message: format!(
+                        "The ({}, {}) edge has a negative weight; contraction hierarchies \
+                        require non-negative edge weights.",
+                        edge.u, edge.v
+                    ),
+                });
+            }
+            let u = *node_indexes.get(&edge.u).unwrap();
+            let v = *node_indexes.get(&edge.v).unwrap();
+            arcs.add(u, v, weight);
+            if !graph.specs.directed {
+                arcs.add(v, u, weight);
+            }
+        }
+
+        let ranks = contract_nodes(&mut arcs);
+
+        let upward = (0..nodes.len())
+            .map(|u| {
+                arcs.outgoing[u]
+                    .iter()
+                    .filter(|(v, _w)| ranks[**v] > ranks[u])
+                    .map(|(v, w)| (*v, *w))
+                    .collect()
+            })
+            .collect();
+        let downward = (0..nodes.len())
+            .map(|v| {
+                arcs.incoming[v]
+                    .iter()
+                    .filter(|(u, _w)| ranks[**u] > ranks[v])
+                    .map(|(u, w)| (*u, *w))
+                    .collect()
+            })
+            .collect();
+
+        Ok(ContractionHierarchy {
+            nodes,
+            node_indexes,
+            upward,
+            downward,
+            middles: arcs.middles,
+        })
+    }
+
+    /**
+    Finds the shortest path between `source` and `target`.
+
+    # Arguments
+
+    * `source`: The starting node.
+    * `target`: The ending node.
+
+    # Returns
+
+    `Ok(None)` if `target` cannot be reached from `source`, otherwise `Ok(Some(...))` with a
+    single shortest path.
+    */
+    pub fn query(&self, source: T, target: T) -> Result<Option<ShortestPathInfo<T>>, Error> {
+        let mut indexes = vec![];
+        for node_name in [&source, &target] {
+            match self.node_indexes.get(node_name) {
+                None => {
+                    return Err(Error {
+                        kind: ErrorKind::NodeNotFound,
+                        message: format!(
+                            "Requested node '{}' was not found in the graph.",
+                            node_name
+                        ),
+                    });
+                }
+                Some(index) => indexes.push(*index),
+            }
+        }
+
+        let (forward_dist, forward_parents) = upward_search(&self.upward, indexes[0]);
+        let (backward_dist, backward_parents) = upward_search(&self.downward, indexes[1]);
+
+        let meeting = forward_dist
+            .iter()
+            .filter_map(|(n, fd)| backward_dist.get(n).map(|bd| (*n, fd + bd)))
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap().then_with(|| a.0.cmp(&b.0)));
+        let (meeting_node, distance) = match meeting {
+            None => return Ok(None),
+            Some(m) => m,
+        };
+
+        // the arcs of the path through the hierarchy, which may include shortcuts
+        let mut hierarchy_path = vec![meeting_node];
+        while let Some(parent) = forward_parents.get(hierarchy_path.last().unwrap()) {
+            hierarchy_path.push(*parent);
+        }
+        hierarchy_path.reverse();
+        let mut node = meeting_node;
+        while let Some(parent) = backward_parents.get(&node) {
+            hierarchy_path.push(*parent);
+            node = *parent;
+        }
+
+        let mut path = vec![hierarchy_path[0]];
+        for pair in hierarchy_path.windows(2) {
+            self.unpack(pair[0], pair[1], &mut path);
+        }
+        let path = remove_loops(path);
+
+        Ok(Some(ShortestPathInfo {
+            distance,
+            paths: vec![path.into_iter().map(|i| self.nodes[i].clone()).collect()],
+            edge_keys: None,
+        }))
+    }
+
+    /// Returns the number of shortcut edges that were added while building the hierarchy.
+    pub fn get_shortcut_count(&self) -> usize {
+        self.middles.len()
+    }
+
+    /// Appends the nodes of the (`u`, `v`) arc, after `u`, to `path`,
+    /// replacing shortcuts with the arcs they bypass.
+    fn unpack(&self, u: usize, v: usize, path: &mut Vec<usize>) {
+        match self.middles.get(&(u, v)) {
+            None => path.push(v),
+            Some(middle) => {
+                self.unpack(u, *middle, path);
+                self.unpack(*middle, v, path);
+            }
+        }
+    }
+}
+
+/**
+Removes the loops from an unpacked `path`, so that no node is visited twice. The shortest
+path through the hierarchy can loop back on itself when there are zero-weight edges, but
+since the path is a shortest path every loop has a weight of zero and can be cut out.
+*/
+fn remove_loops(path: Vec<usize>) -> Vec<usize> {
+    let mut positions: HashMap<usize, usize> = HashMap::new();
+    let mut simple_path: Vec<usize> = vec![];
+    for node in path {
+        match positions.get(&node) {
+            Some(position) => {
+                for removed in simple_path.drain(position + 1..) {
+                    positions.remove(&removed);
+                }
+            }
+            None => {
+                positions.insert(node, simple_path.len());
+                simple_path.push(node);
+            }
+        }
+    }
+    simple_path
+}
+
+/// The arcs of the graph as it is being contracted, including shortcuts.
+struct Arcs {
+    outgoing: Vec<HashMap<usize, f64>>,
+    incoming: Vec<HashMap<usize, f64>>,
+    middles: HashMap<(usize, usize), usize>,
+}
+
+impl Arcs {
+    fn new(num_nodes: usize) -> Arcs {
+        Arcs {
+            outgoing: vec![HashMap::new(); num_nodes],
+            incoming: vec![HashMap::new(); num_nodes],
+            middles: HashMap::new(),
+        }
+    }
+
+    /// Adds the (`u`, `v`) arc if there isn't already a lower weight one.
+    /// Returns `true` if the arc was added.
+    fn add(&mut self, u: usize, v: usize, weight: f64) -> bool {
+        if u == v {
+            return false;
+        }
+        if let Some(existing) = self.outgoing[u].get(&v) {
+            if *existing <= weight {
+                return false;
+            }
+        }
+        self.outgoing[u].insert(v, weight);
+        self.incoming[v].insert(u, weight);
+        self.middles.remove(&(u, v));
+        true
+    }
+
+    /// Adds a shortcut arc, from `u` to `v`, that bypasses `middle`.
+    fn add_shortcut(&mut self, u: usize, v: usize, weight: f64, middle: usize) {
+        if self.add(u, v, weight) {
+            self.middles.insert((u, v), middle);
+        }
+    }
+}
+
+/**
+Contracts all the nodes, in order of their "edge difference" (the number of shortcuts
+that contracting the node would add minus the number of arcs that would be removed),
+adding shortcuts to `arcs`. Returns the rank (contraction order) of each node.
+*/
+fn contract_nodes(arcs: &mut Arcs) -> Vec<usize> {
+    let num_nodes = arcs.outgoing.len();
+    let mut contracted = vec![false; num_nodes];
+    let mut contracted_neighbors = vec![0; num_nodes];
+    let mut ranks = vec![0; num_nodes];
+
+    let mut queue = BinaryHeap::new();
+    let mut count = 0;
+    for v in 0..num_nodes {
+        let priority = get_priority(arcs, &contracted, &contracted_neighbors, v);
+        push_fringe_node(&mut count, &mut queue, v, priority);
+    }
+
+    let mut rank = 0;
+    while let Some(item) = queue.pop() {
+        let v = item.node_name;
+        if contracted[v] {
+            continue;
+        }
+        // priorities go stale as neighbors are contracted; re-check before contracting
+        let priority = get_priority(arcs, &contracted, &contracted_neighbors, v);
+        if let Some(next) = queue.peek() {
+            if priority > -next.distance {
+                push_fringe_node(&mut count, &mut queue, v, priority);
+                continue;
+            }
+        }
+        for (u, x, weight) in get_shortcuts(arcs, &contracted, v) {
+            arcs.add_shortcut(u, x, weight, v);
+        }
+        contracted[v] = true;
+        ranks[v] = rank;
+        rank += 1;
+        let neighbors: Vec<usize> =
+            arcs.outgoing[v].keys().chain(arcs.incoming[v].keys()).cloned().collect();
+        for n in neighbors {
+            contracted_neighbors[n] += 1;
+        }
+    }
+
+    ranks
+}
+
+/// Returns the contraction priority of `v`; lower values are contracted first.
+fn get_priority(arcs: &Arcs, contracted: &[bool], contracted_neighbors: &[usize], v: usize) -> f64 {
+    let removed =
+        arcs.outgoing[v].keys().chain(arcs.incoming[v].keys()).filter(|n| !contracted[**n]).count();
+    let shortcuts = get_shortcuts(arcs, contracted, v).len();
+    shortcuts as f64 - removed as f64 + contracted_neighbors[v] as f64
+}
+
+/**
+Finds the shortcuts, as (`u`, `x`, weight) tuples, that are needed to preserve shortest
+path distances if `v` is contracted. A shortcut from `u` to `x` is needed unless a
+"witness" path that avoids `v` and is no longer than `u -> v -> x` exists.
+*/
+fn get_shortcuts(arcs: &Arcs, contracted: &[bool], v: usize) -> Vec<(usize, usize, f64)> {
+    let mut shortcuts = vec![];
+    let outgoing: Vec<(usize, f64)> =
+        arcs.outgoing[v].iter().filter(|(x, _w)| !contracted[**x]).map(|(x, w)| (*x, *w)).collect();
+    if outgoing.is_empty() {
+        return shortcuts;
+    }
+    let max_outgoing = outgoing.iter().map(|(_x, w)| *w).fold(0.0, f64::max);
+    for (u, uv_weight) in arcs.incoming[v].iter() {
+        if contracted[*u] {
+            continue;
+        }
+        let witness_dist = witness_search(arcs, contracted, *u, v, uv_weight + max_outgoing);
+        for (x, vx_weight) in outgoing.iter() {
+            if x == u {
+                continue;
+            }
+            let via_v = uv_weight + vx_weight;
+            match witness_dist.get(x) {
+                Some(d) if *d <= via_v => {}
+                _ => shortcuts.push((*u, *x, via_v)),
+            }
+        }
+    }
+    shortcuts
+}
+
+/**
+Runs a limited Dijkstra search from `u`, among the nodes that haven't been contracted,
+that avoids `excluded` and stops at `max_distance`.
+*/
+fn witness_search(
+    arcs: &Arcs,
+    contracted: &[bool],
+    u: usize,
+    excluded: usize,
+    max_distance: f64,
+) -> HashMap<usize, f64> {
+    let mut dist = HashMap::new();
+    let mut seen = HashMap::new();
+    let mut fringe = BinaryHeap::new();
+    let mut count = 0;
+    seen.insert(u, 0.0);
+    push_fringe_node(&mut count, &mut fringe, u, 0.0);
+    while let Some(fringe_item) = fringe.pop() {
+        let d = -fringe_item.distance;
+        let v = fringe_item.node_name;
+        if dist.contains_key(&v) {
+            continue;
+        }
+        dist.insert(v, d);
+        if d > max_distance || dist.len() >= WITNESS_SEARCH_SETTLE_LIMIT {
+            break;
+        }
+        for (x, weight) in arcs.outgoing[v].iter() {
+            if *x == excluded || contracted[*x] || dist.contains_key(x) {
+                continue;
+            }
+            let vx_dist = d + weight;
+            if vx_dist < *seen.get(x).unwrap_or(&f64::INFINITY) {
+                seen.insert(*x, vx_dist);
+                push_fringe_node(&mut count, &mut fringe, *x, vx_dist);
+            }
+        }
+    }
+    dist
+}
+
+/**
+Runs a Dijkstra search from `source` that only follows the arcs in `arcs`.
+Returns the distances to, and the parents of, the nodes that were reached.
+*/
+fn upward_search(
+    arcs: &[Vec<(usize, f64)>],
+    source: usize,
+) -> (HashMap<usize, f64>, HashMap<usize, usize>) {
+    let mut dist = HashMap::new();
+    let mut seen = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut fringe = BinaryHeap::new();
+    seen.insert(source, 0.0);
+    fringe.push(FringeNode {
+        node_name: source,
+        count: 0,
+        distance: -0.0,
+    });
+    let mut count = 0;
+    while let Some(fringe_item) = fringe.pop() {
+        let d = -fringe_item.distance;
+        let v = fringe_item.node_name;
+        if dist.contains_key(&v) {
+            continue;
+        }
+        dist.insert(v, d);
+        for (x, weight) in arcs[v].iter() {
+            let vx_dist = d + weight;
+            if vx_dist < *seen.get(x).unwrap_or(&f64::INFINITY) {
+                seen.insert(*x, vx_dist);
+                parents.insert(*x, v);
+                push_fringe_node(&mut count, &mut fringe, *x, vx_dist);
+            }
+        }
+    }
+    (dist, parents)
+}
@@ -0,0 +1,308 @@
+use crate::{Error, ErrorKind, Graph, ParallelOptions};
+use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Uses the delta-stepping algorithm to find the shortest path distances from a single
+source node to all the nodes that can be reached from it.
+
+Nodes are placed into "buckets" of width `delta` according to their tentative distance,
+and all the edges leaving the nodes in a bucket are relaxed in parallel. This makes it
+possible to use several threads within a single-source computation, which works best on
+large graphs where the range of the edge weights is small. Edge weights must not be negative.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `source`: The starting node.
+* `delta`: The width of each bucket. If `None` the mean edge weight is used.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{delta_stepping}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+]);
+
+let distances = delta_stepping::single_source_distances(&graph, true, "n1", Some(1.0)).unwrap();
+assert_eq!(distances.get("n3").unwrap(), &2.1);
+```
+
+# References
+
+1. U. Meyer and P. Sanders. Δ-stepping: a parallelizable shortest path algorithm.
+   Journal of Algorithms, 49(1):114–152, 2003.
+*/
+pub fn single_source_distances<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    source: T,
+    delta: Option<f64>,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    single_source_distances_with_options(
+        graph,
+        weighted,
+        source,
+        delta,
+        &ParallelOptions::default(),
+    )
+}
+
+/**
+Uses the delta-stepping algorithm to find the shortest path distances from a single
+source node, like [single_source_distances](./fn.single_source_distances.html), with the
+threads and [ProgressHandle](../../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `source`: The starting node.
+* `delta`: The width of each bucket. If `None` the mean edge weight is used.
+* `options`: a [ParallelOptions](../../../struct.ParallelOptions.html); each bucket
+  that has been emptied is a unit of work for its `progress`, whose total isn't known
+
+# Returns
+
+The same as [single_source_distances](./fn.single_source_distances.html). An `Error` with
+a `kind` of `ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and
+one with a `kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::shortest_path::delta_stepping, generators};
+use graphrs::{Parallelism, ParallelOptions};
+
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::MaxThreads(2),
+    ..ParallelOptions::default()
+};
+let distances =
+    delta_stepping::single_source_distances_with_options(&graph, false, 0, None, &options);
+assert_eq!(distances.unwrap().len(), 34);
+```
+*/
+pub fn single_source_distances_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    source: T,
+    delta: Option<f64>,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    if !graph.has_node(&source) {
+        return Err(Error {
+            kind: ErrorKind::NodeNotFound,
+            message: format!("Requested node '{}' was not found in the graph.", source),
+        });
+    }
+
+    let nodes = graph.get_all_node_names();
+    let node_indexes: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let mut adjacency: Vec<Vec<(usize, f64)>> = vec![vec![]; nodes.len()];
+    for edge in graph.get_all_edges() {
+        let weight = match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+        if weight < 0.0 {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!(
+                    "The ({}, {}) edge has a negative weight; delta-stepping requires \
+                    non-negative edge weights.",
+                    edge.u, edge.v
+                ),
+            });
+        }
+        let u = *node_indexes.get(&edge.u).unwrap();
+        let v = *node_indexes.get(&edge.v).unwrap();
+        adjacency[u].push((v, weight));
+        if !graph.specs.directed {
+            adjacency[v].push((u, weight));
+        }
+    }
+
+    let delta = match delta {
+        Some(d) => d,
+        None => get_mean_weight(&adjacency),
+    };
+    if delta.is_nan() || delta <= 0.0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!("`delta` was {} but it must be greater than 0.0.", delta),
+        });
+    }
+
+    let mut tentative = vec![f64::INFINITY; nodes.len()];
+    let mut buckets: BTreeMap<usize, HashSet<usize>> = BTreeMap::new();
+    relax(
+        *node_indexes.get(&source).unwrap(),
+        0.0,
+        delta,
+        &mut tentative,
+        &mut buckets,
+    );
+
+    let progress = &options.progress;
+    progress.start(None)?;
+    options.install(|parallel| {
+        while let Some(bucket_index) = buckets.keys().next().cloned() {
+            progress.check()?;
+            let mut settled: HashSet<usize> = HashSet::new();
+            // relaxing light edges can put nodes back into the current bucket
+            while let Some(bucket) = buckets.remove(&bucket_index) {
+                let requests =
+                    get_requests(&bucket, &adjacency, &tentative, |w| w <= delta, parallel);
+                settled.extend(bucket);
+                for (v, distance) in requests {
+                    relax(v, distance, delta, &mut tentative, &mut buckets);
+                }
+            }
+            let requests = get_requests(&settled, &adjacency, &tentative, |w| w > delta, parallel);
+            for (v, distance) in requests {
+                relax(v, distance, delta, &mut tentative, &mut buckets);
+            }
+            progress.advance();
+        }
+        Ok::<(), Error>(())
+    })??;
+
+    Ok(nodes
+        .into_iter()
+        .zip(tentative)
+        .filter(|(_n, d)| d.is_finite())
+        .map(|(n, d)| (n.clone(), d))
+        .collect())
+}
+
+/// Returns the mean weight of all the arcs in `adjacency`, or 1.0 if there are none.
+fn get_mean_weight(adjacency: &[Vec<(usize, f64)>]) -> f64 {
+    let weights: Vec<f64> = adjacency.iter().flatten().map(|(_v, w)| *w).collect();
+    let total: f64 = weights.iter().sum();
+    match total > 0.0 {
+        true => total / weights.len() as f64,
+        false => 1.0,
+    }
+}
+
+/**
+Finds the tentative distances to the nodes that can be reached from the `nodes`, along
+arcs whose weight satisfies `filter`, in parallel if `parallel` is `true`. Only the
+shortest request for each node is returned.
+*/
+fn get_requests<F>(
+    nodes: &HashSet<usize>,
+    adjacency: &[Vec<(usize, f64)>],
+    tentative: &[f64],
+    filter: F,
+    parallel: bool,
+) -> HashMap<usize, f64>
+where
+    F: Fn(f64) -> bool + Sync,
+{
+    let add_requests = |mut requests: HashMap<usize, f64>, u: &usize| {
+        for (v, weight) in adjacency[*u].iter().filter(|(_v, w)| filter(*w)) {
+            add_request(&mut requests, *v, tentative[*u] + weight);
+        }
+        requests
+    };
+    if !parallel {
+        return nodes.iter().fold(HashMap::new(), add_requests);
+    }
+    nodes.par_iter().fold(HashMap::new, add_requests).reduce(HashMap::new, |mut requests, other| {
+        for (v, distance) in other {
+            add_request(&mut requests, v, distance);
+        }
+        requests
+    })
+}
+
+/// Adds a request to `requests` if it is shorter than any existing request for `v`.
+#[inline]
+fn add_request(requests: &mut HashMap<usize, f64>, v: usize, distance: f64) {
+    let existing = requests.entry(v).or_insert(distance);
+    if distance < *existing {
+        *existing = distance;
+    }
+}
+
+/// If `distance` improves on the tentative distance of `v`, moves `v` to the bucket
+/// for its new distance.
+fn relax(
+    v: usize,
+    distance: f64,
+    delta: f64,
+    tentative: &mut [f64],
+    buckets: &mut BTreeMap<usize, HashSet<usize>>,
+) {
+    if distance >= tentative[v] {
+        return;
+    }
+    if tentative[v].is_finite() {
+        let old_index = (tentative[v] / delta) as usize;
+        if let Some(bucket) = buckets.get_mut(&old_index) {
+            bucket.remove(&v);
+            if bucket.is_empty() {
+                buckets.remove(&old_index);
+            }
+        }
+    }
+    tentative[v] = distance;
+    buckets.entry((distance / delta) as usize).or_default().insert(v);
+}
+
+// tests for private methods only; other tests are in:
+// tests/test_algorithms_shortest_path_delta_stepping.rs
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_relax() {
+        let mut tentative = vec![f64::INFINITY; 3];
+        let mut buckets = BTreeMap::new();
+        relax(1, 2.5, 1.0, &mut tentative, &mut buckets);
+        assert_eq!(tentative[1], 2.5);
+        assert!(buckets.get(&2).unwrap().contains(&1));
+        relax(1, 0.5, 1.0, &mut tentative, &mut buckets);
+        assert_eq!(tentative[1], 0.5);
+        assert!(!buckets.contains_key(&2));
+        assert!(buckets.get(&0).unwrap().contains(&1));
+        relax(1, 0.7, 1.0, &mut tentative, &mut buckets);
+        assert_eq!(tentative[1], 0.5);
+    }
+
+    #[test]
+    fn test_get_mean_weight() {
+        assert_eq!(
+            get_mean_weight(&[vec![(1, 1.0), (2, 2.0)], vec![(0, 3.0)]]),
+            2.0
+        );
+        assert_eq!(get_mean_weight(&[vec![], vec![(0, 0.0)]]), 1.0);
+    }
+}
@@ -1,8 +1,13 @@
-use crate::algorithms::shortest_path::ShortestPathInfo;
-use crate::{Error, ErrorKind, Graph};
-use rayon::prelude::*;
+use crate::algorithms::shortest_path::shortest_path_info::ParallelEdgeKeys;
+use crate::algorithms::shortest_path::{ShortestPathInfo, ShortestPathPredecessors};
+use crate::graph::{IndexedAdjacency, LazyIndexedGraph};
+use crate::{
+    CsrGraph, Direction, Edge, Error, ErrorKind, Graph, ParallelOptions, ProgressHandle,
+};
+use itertools::Either;
+use num_traits::Num;
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::fmt::Display;
 use std::hash::Hash;
 
@@ -11,7 +16,7 @@ As a graph is explored by a shortest-path algorithm the nodes at the
 "fringe" of the explored part are maintained. This struct holds information
 about a fringe node.
 */
-struct FringeNode<T> {
+pub(crate) struct FringeNode<T> {
     pub node_name: T,
     pub count: i32,
     pub distance: f64,
@@ -49,6 +54,44 @@ impl<T: Eq> PartialEq for FringeNode<T> {
 
 impl<T: Eq> Eq for FringeNode<T> {}
 
+/**
+A fringe node whose `distance` can be any weight type. The ordering of the distances is
+reversed, rather than the distances being negated, so that a `BinaryHeap` pops the
+closest node first even when the weights are unsigned.
+*/
+pub(crate) struct WeightedFringeNode<T, W> {
+    pub node_name: T,
+    pub count: i32,
+    pub distance: W,
+}
+
+impl<T: Eq + Ord, W: PartialOrd> Ord for WeightedFringeNode<T, W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match other.distance.partial_cmp(&self.distance) {
+            Some(Ordering::Less) => Ordering::Less,
+            Some(Ordering::Greater) => Ordering::Greater,
+            _ => match self.count.cmp(&other.count) {
+                Ordering::Equal => self.node_name.cmp(&other.node_name),
+                count_ordering => count_ordering,
+            },
+        }
+    }
+}
+
+impl<T: Eq + Ord, W: PartialOrd> PartialOrd for WeightedFringeNode<T, W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Eq + Ord, W: PartialOrd> PartialEq for WeightedFringeNode<T, W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Eq + Ord, W: PartialOrd> Eq for WeightedFringeNode<T, W> {}
+
 static CONTRADICTORY_PATHS_ERROR_MESSAGE: &str =
     "Contradictary paths found, do some edges have negative weights?";
 
@@ -62,6 +105,8 @@ of nodes.
 * `weighted`: determines if shortest paths are determined with edge weight, or not
 * `cutoff`: Length (sum of edge weights) at which the search is stopped.
 If cutoff is provided, only return paths with summed weight <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return paths with at most hop_cutoff edges.
 * `first_only`: If `true` returns the first shortest path found for each source and target,
 if `false` returns all shortest paths found between sources and targets.
 
@@ -71,6 +116,9 @@ A `HashMap` of `HashMaps`. The keys to the first one are the starting nodes
 and the keys to the second are the target nodes. The values of the second one are `Vec`s
 of the shortest paths between the starting and target nodes.
 
+If the search from any starting node fails, for example because some edges have negative
+weights, an `Err` is returned for the first such node in sorted order.
+
 # Examples
 
 ```
@@ -85,7 +133,7 @@ graph.add_edges(vec![
     Edge::with_weight("n2", "n3", 1.1),
 ]);
 
-let all_pairs = dijkstra::all_pairs(&graph, true, None, false);
+let all_pairs = dijkstra::all_pairs(&graph, true, None, None, false);
 assert_eq!(all_pairs.unwrap().get("n1").unwrap().get("n3").unwrap().distance, 2.1);
 ```
 
@@ -94,37 +142,272 @@ assert_eq!(all_pairs.unwrap().get("n1").unwrap().get("n3").unwrap().distance, 2.
 1. E. W. Dijkstra. A note on two problems in connection with graphs. Numer. Math., 1:269–271, 1959.
 
 */
-pub fn all_pairs<T, A>(
-    graph: &Graph<T, A>,
+#[allow(clippy::type_complexity)]
+pub fn all_pairs<T, A, W>(
+    graph: &Graph<T, A, W>,
     weighted: bool,
-    cutoff: Option<f64>,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
     first_only: bool,
-) -> Result<HashMap<T, HashMap<T, ShortestPathInfo<T>>>, Error>
+) -> Result<HashMap<T, HashMap<T, ShortestPathInfo<T, W>>>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
 {
-    let x = graph
-        .get_all_nodes()
-        .into_par_iter()
-        .map(|node| {
-            let ss = single_source(graph, weighted, node.name.clone(), None, cutoff, first_only);
-            (node.name.clone(), ss)
-        })
-        .collect::<Vec<(T, Result<HashMap<T, ShortestPathInfo<T>>, Error>)>>();
-    let y = x
-        .iter()
-        .filter(|t| t.1.is_err())
-        .collect::<Vec<&(T, Result<HashMap<T, ShortestPathInfo<T>>, Error>)>>();
-    if !y.is_empty() {
-        match &y[0].1 {
-            Err(e) => {
-                return Err(e.clone());
+    all_pairs_with_progress(graph, weighted, cutoff, hop_cutoff, first_only, &ProgressHandle::new())
+}
+
+/**
+Uses Dijkstra's algorithm to find shortest weighted paths between all pairs of nodes,
+like [all_pairs](./fn.all_pairs.html), reporting the progress of the search through
+`progress` and stopping if it is cancelled.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only return paths with summed weight <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return paths with at most hop_cutoff edges.
+* `first_only`: If `true` returns the first shortest path found for each source and target,
+  if `false` returns all shortest paths found between sources and targets.
+* `progress`: a [ProgressHandle](../../../struct.ProgressHandle.html); each starting node
+  that has been searched from is a unit of work
+
+# Returns
+
+The same as [all_pairs](./fn.all_pairs.html), or an `Error` with a `kind` of
+`ErrorKind::Cancelled` if `progress` was cancelled.
+
+# Examples
+
+```
+use graphrs::{algorithms::shortest_path::dijkstra, generators, ProgressHandle};
+
+let graph = generators::social::karate_club_graph();
+let progress = ProgressHandle::new().with_callback(|p| {
+    println!("searched from {} of {} nodes", p.completed, p.total.unwrap());
+});
+let all_pairs = dijkstra::all_pairs_with_progress(&graph, false, None, None, true, &progress);
+assert_eq!(all_pairs.unwrap().len(), 34);
+```
+*/
+#[allow(clippy::type_complexity)]
+pub fn all_pairs_with_progress<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+    first_only: bool,
+    progress: &ProgressHandle,
+) -> Result<HashMap<T, HashMap<T, ShortestPathInfo<T, W>>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    let options = ParallelOptions {
+        progress: progress.clone(),
+        ..ParallelOptions::default()
+    };
+    all_pairs_with_options(graph, weighted, cutoff, hop_cutoff, first_only, &options)
+}
+
+/**
+Uses Dijkstra's algorithm to find shortest weighted paths between all pairs of nodes,
+like [all_pairs](./fn.all_pairs.html), with the threads and
+[ProgressHandle](../../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only return paths with summed weight <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return paths with at most hop_cutoff edges.
+* `first_only`: If `true` returns the first shortest path found for each source and target,
+  if `false` returns all shortest paths found between sources and targets.
+* `options`: a [ParallelOptions](../../../struct.ParallelOptions.html); each starting node
+  that has been searched from is a unit of work for its `progress`
+
+# Returns
+
+The same as [all_pairs](./fn.all_pairs.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::shortest_path::dijkstra, generators, Parallelism, ParallelOptions};
+
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let all_pairs = dijkstra::all_pairs_with_options(&graph, false, None, None, true, &options);
+assert_eq!(all_pairs.unwrap().len(), 34);
+```
+*/
+#[allow(clippy::type_complexity)]
+pub fn all_pairs_with_options<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+    first_only: bool,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, HashMap<T, ShortestPathInfo<T, W>>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    let csr = get_csr(graph, weighted)?;
+    let names = csr.get_node_names();
+    let progress = &options.progress;
+    progress.start(Some(names.len()))?;
+    let results: Vec<Result<HashMap<T, ShortestPathInfo<T, W>>, Error>> =
+        options.map_collect(names.len(), |index| {
+            progress.check()?;
+            let result = dijkstra_multisource(
+                &csr,
+                weighted,
+                vec![index as u32],
+                None,
+                cutoff,
+                hop_cutoff,
+                first_only,
+            );
+            progress.advance();
+            result
+        })?;
+    progress.check()?;
+    let mut all_infos = collect_source_results(names, results)?;
+    if let Some(edge_keys) = ParallelEdgeKeys::new(graph, weighted) {
+        all_infos.values_mut().for_each(|infos| edge_keys.set_all_edge_keys(infos));
+    }
+    Ok(all_infos)
+}
+
+/**
+An iterator over the shortest paths from each node of a graph to all the other nodes.
+Created by [all_pairs_iter](./fn.all_pairs_iter.html).
+*/
+pub struct AllPairsIter<T, W> {
+    csr: CsrGraph<T, W>,
+    edge_keys: Option<ParallelEdgeKeys<T>>,
+    weighted: bool,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+    first_only: bool,
+    index: usize,
+}
+
+impl<T, W> Iterator for AllPairsIter<T, W>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    type Item = Result<(T, HashMap<T, ShortestPathInfo<T, W>>), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let source = self.csr.get_node_name(self.index as u32)?.clone();
+        let result = dijkstra_multisource(
+            &self.csr,
+            self.weighted,
+            vec![self.index as u32],
+            None,
+            self.cutoff,
+            self.hop_cutoff,
+            self.first_only,
+        );
+        self.index += 1;
+        Some(match result {
+            Ok(mut infos) => {
+                if let Some(edge_keys) = &self.edge_keys {
+                    edge_keys.set_all_edge_keys(&mut infos);
+                }
+                Ok((source, infos))
             }
-            Ok(_) => {}
-        }
+            Err(e) => Err(get_source_error(e, &source)),
+        })
     }
-    Ok(x.into_iter().map(|t| (t.0, t.1.unwrap())).collect())
+}
+
+/**
+Uses Dijkstra's algorithm to find shortest weighted paths between all pairs
+of nodes, one starting node at a time.
+
+Unlike [all_pairs](./fn.all_pairs.html) the paths aren't all held in memory at once: the
+paths from each starting node are only found when the iterator reaches it, so they can be
+aggregated or written out before the next ones are found. The iterator can be run in
+parallel with rayon's `par_bridge`.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only return paths with summed weight <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return paths with at most hop_cutoff edges.
+* `first_only`: If `true` returns the first shortest path found for each source and target,
+  if `false` returns all shortest paths found between sources and targets.
+
+# Returns
+
+An [AllPairsIter](./struct.AllPairsIter.html) that yields each starting node, in sorted
+order, with a `HashMap` of target nodes to their shortest paths from the starting node.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+]);
+
+let mut reachable = vec![];
+for result in dijkstra::all_pairs_iter(&graph, true, None, None, false).unwrap() {
+    let (source, infos) = result.unwrap();
+    reachable.push((source, infos.len()));
+}
+assert_eq!(reachable, vec![("n1", 3), ("n2", 3), ("n3", 1)]);
+```
+*/
+pub fn all_pairs_iter<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+    first_only: bool,
+) -> Result<AllPairsIter<T, W>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    Ok(AllPairsIter {
+        csr: get_csr(graph, weighted)?,
+        edge_keys: ParallelEdgeKeys::new(graph, weighted),
+        weighted,
+        cutoff,
+        hop_cutoff,
+        first_only,
+        index: 0,
+    })
 }
 
 /**
@@ -141,6 +424,8 @@ than just the first one found.
 all other nodes will be found.
 * `cutoff`: Length (sum of edge weights) at which the search is stopped.
 If cutoff is provided, only return paths with summed weight <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return paths with at most hop_cutoff edges.
 * `first_only`: If `true` returns the first shortest path found for each target, if
 `false` returns all shortest paths found between source and targets.
 
@@ -158,7 +443,7 @@ graph.add_edges(vec![
     Edge::with_weight("n2", "n3", 1.1),
 ]);
 
-let shortest_paths = dijkstra::single_source(&graph, true, "n1", Some("n3"), None, false);
+let shortest_paths = dijkstra::single_source(&graph, true, "n1", Some("n3"), None, None, false);
 assert_eq!(shortest_paths.unwrap().get("n3").unwrap().distance, 2.1);
 ```
 
@@ -166,19 +451,29 @@ assert_eq!(shortest_paths.unwrap().get("n3").unwrap().distance, 2.1);
 
 1. E. W. Dijkstra. A note on two problems in connection with graphs. Numer. Math., 1:269–271, 1959.
 */
-pub fn single_source<T, A>(
-    graph: &Graph<T, A>,
+pub fn single_source<T, A, W>(
+    graph: &Graph<T, A, W>,
     weighted: bool,
     source: T,
     target: Option<T>,
-    cutoff: Option<f64>,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
     first_only: bool,
-) -> Result<HashMap<T, ShortestPathInfo<T>>, Error>
+) -> Result<HashMap<T, ShortestPathInfo<T, W>>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
 {
-    multi_source(graph, weighted, vec![source], target, cutoff, first_only)
+    multi_source(
+        graph,
+        weighted,
+        vec![source],
+        target,
+        cutoff,
+        hop_cutoff,
+        first_only,
+    )
 }
 
 /**
@@ -196,6 +491,8 @@ for any of the `sources` and ends at the `target`.
 all other nodes will be found.
 * `cutoff`: Length (sum of edge weights) at which the search is stopped.
 If cutoff is provided, only return paths with summed weight <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return paths with at most hop_cutoff edges.
 * `first_only`: If `true` returns the first shortest path found for each target, if
 `false` returns all shortest paths found between sources and targets.
 
@@ -213,7 +510,7 @@ graph.add_edges(vec![
     Edge::with_weight("n2", "n3", 1.1),
 ]);
 
-let shortest_paths = dijkstra::multi_source(&graph, true, vec!["n1", "n2"], Some("n3"), None, false);
+let shortest_paths = dijkstra::multi_source(&graph, true, vec!["n1", "n2"], Some("n3"), None, None, false);
 assert_eq!(shortest_paths.unwrap().get("n3").unwrap().distance, 1.1);
 ```
 
@@ -221,208 +518,1823 @@ assert_eq!(shortest_paths.unwrap().get("n3").unwrap().distance, 1.1);
 
 1. E. W. Dijkstra. A note on two problems in connection with graphs. Numer. Math., 1:269–271, 1959.
 */
-pub fn multi_source<T, A>(
-    graph: &Graph<T, A>,
+pub fn multi_source<T, A, W>(
+    graph: &Graph<T, A, W>,
     weighted: bool,
     sources: Vec<T>,
     target: Option<T>,
-    cutoff: Option<f64>,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
     first_only: bool,
-) -> Result<HashMap<T, ShortestPathInfo<T>>, Error>
+) -> Result<HashMap<T, ShortestPathInfo<T, W>>, Error>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
 {
-    let shortest_path_infos =
-        dijkstra_multisource(graph, weighted, sources, target.clone(), cutoff, first_only);
-    match shortest_path_infos {
-        Err(e) => Err(e),
-        Ok(spis) => Ok(spis
-            .into_iter()
-            .filter(|(k, _v)| target.is_none() || k == target.as_ref().unwrap())
-            .collect::<HashMap<T, ShortestPathInfo<T>>>()),
-    }
+    let adjacency = get_lazy_indexed_graph(graph, weighted, Direction::Outgoing)?;
+    let targets = target.as_ref().map(std::slice::from_ref);
+    let infos = dijkstra_multisource_to_targets(
+        adjacency, weighted, &sources, targets, cutoff, hop_cutoff, first_only,
+    )?;
+    Ok(with_edge_keys(infos, ParallelEdgeKeys::new(graph, weighted).as_ref()))
 }
 
 /**
-Uses Dijkstra's algorithm to find shortest weighted paths.
-This is a private function that does all the work of finding the
-shortest paths. All the public functions in this module call this one.
-*/
-fn dijkstra_multisource<T, A>(
-    graph: &Graph<T, A>,
-    weighted: bool,
-    sources: Vec<T>,
-    target: Option<T>,
-    cutoff: Option<f64>,
-    first_only: bool,
-) -> Result<HashMap<T, ShortestPathInfo<T>>, Error>
-where
-    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
-    A: Clone,
-{
-    if weighted {
-        graph.ensure_weighted()?;
-    }
+Uses Dijkstra's algorithm to find shortest weighted paths from a single source node to
+a set of target nodes. The search stops as soon as the shortest paths to all the targets
+have been found, which is much faster than finding the paths to every node and
+discarding most of them.
 
-    let get_cost = |u, v| match weighted {
-        true => match graph.specs.multi_edges {
-            false => get_cost_single(graph, u, v),
-            true => get_cost_multi(graph, u, v),
-        },
-        false => 1.0,
-    };
+# Arguments
 
-    let mut paths: HashMap<T, Vec<Vec<T>>> =
-        sources.iter().map(|s| (s.clone(), vec![vec![s.clone()]])).collect();
-    let mut dist = HashMap::<T, f64>::new();
-    let mut seen = HashMap::<T, f64>::new();
-    let mut fringe = BinaryHeap::new();
-    let mut count = 0;
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `source`: The starting node.
+* `targets`: The ending nodes. Targets that aren't in the graph are ignored.
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only return paths with summed weight <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return paths with at most hop_cutoff edges.
+* `first_only`: If `true` returns the first shortest path found for each target, if
+  `false` returns all shortest paths found between source and targets.
 
-    for source in sources {
-        seen.insert(source.clone(), 0.0);
-        fringe.push(FringeNode {
-            node_name: source,
-            count: 0,
-            distance: -0.0,
-        });
-    }
-
-    while !fringe.is_empty() {
-        let fringe_item = fringe.pop().unwrap();
-        let d = -fringe_item.distance;
-        let v = fringe_item.node_name.clone();
-        if dist.contains_key(&v.clone()) {
-            continue;
-        }
-        dist.insert(v.clone(), d);
-        if target.is_some() && &v.clone() == target.as_ref().unwrap() {
-            break;
-        }
-        for node in graph.get_successors_or_neighbors(v.clone()) {
-            let u = node.name.clone();
-            let cost = get_cost(v.clone(), u.clone());
-            let vu_dist = dist.get(&v).unwrap() + cost;
-            if cutoff.is_some() && vu_dist > cutoff.unwrap() {
-                continue;
-            }
-            if dist.contains_key(&u) {
-                let u_dist = *dist.get(&u).unwrap();
-                if vu_dist < u_dist {
-                    return Err(get_contractory_paths_error());
-                }
-            } else if !seen.contains_key(&u) || vu_dist < *seen.get(&u).unwrap() {
-                seen.insert(u.clone(), vu_dist);
-                push_fringe_node(&mut count, &mut fringe, u.clone(), vu_dist);
-                let mut new_paths_v = paths.entry(v.clone()).or_default().clone();
-                new_paths_v.iter_mut().for_each(|pv| pv.push(u.clone()));
-                paths.insert(u, new_paths_v);
-            } else if !first_only && vu_dist == *seen.get(&u).unwrap() {
-                push_fringe_node(&mut count, &mut fringe, u.clone(), vu_dist);
-                add_u_to_v_paths_and_append_v_paths_to_u_paths(u.clone(), v.clone(), &mut paths);
-            }
-        }
-    }
+# Returns
 
-    Ok(get_shortest_path_infos::<T, A>(dist, paths))
-}
+A `HashMap` with an entry for each target that can be reached from `source`.
 
-/// Returns the `Error` object for a contradictory-paths error.
-#[inline]
-fn get_contractory_paths_error() -> Error {
-    Error {
-        kind: ErrorKind::ContradictoryPaths,
-        message: CONTRADICTORY_PATHS_ERROR_MESSAGE.to_string(),
-    }
-}
+# Examples
 
-/**
-Pushes a `FringeNode` into the `fringe` `BinaryHeap`.
-Increments `count`.
-*/
-#[inline]
-fn push_fringe_node<T>(count: &mut i32, fringe: &mut BinaryHeap<FringeNode<T>>, u: T, vu_dist: f64)
-where
-    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
-{
-    *count += 1;
-    fringe.push(FringeNode {
-        node_name: u,
-        count: *count,
-        distance: -vu_dist,
-    });
-}
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
 
-/**
-Adds `u` to the paths that lead to `v`, then appends all the paths that
-lead to `v` to the paths that lead to `u`.
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+    Edge::with_weight("n3", "n4", 5.0),
+]);
+
+let shortest_paths =
+    dijkstra::single_source_to_targets(&graph, true, "n1", vec!["n2", "n3"], None, None, false)
+        .unwrap();
+assert_eq!(shortest_paths.len(), 2);
+assert_eq!(shortest_paths.get("n3").unwrap().distance, 2.1);
+```
 */
-#[inline]
-fn add_u_to_v_paths_and_append_v_paths_to_u_paths<T>(
-    u: T,
-    v: T,
-    paths: &mut HashMap<T, Vec<Vec<T>>>,
-) where
+pub fn single_source_to_targets<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    source: T,
+    targets: Vec<T>,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+    first_only: bool,
+) -> Result<HashMap<T, ShortestPathInfo<T, W>>, Error>
+where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
 {
-    // add u to all paths[v], then *append* them to paths[u]
-    let v_paths: Vec<Vec<T>> = paths
-        .get(&v)
-        .unwrap()
-        .iter()
-        .map(|p| {
-            let mut x = p.clone();
-            x.push(u.clone());
-            x
-        })
-        .collect();
-    let u_paths = paths.get_mut(&u).unwrap();
-    for v_path in v_paths {
-        u_paths.push(v_path);
-    }
+    multi_source_to_targets(
+        graph,
+        weighted,
+        vec![source],
+        targets,
+        cutoff,
+        hop_cutoff,
+        first_only,
+    )
 }
 
 /**
-Gets all shortest paths that pass through a given node.
-
-Does not return paths where the node is the source or the target;
-the node must be between two other nodes.
+Uses Dijkstra's algorithm to find shortest weighted paths from multiple source nodes to
+a set of target nodes. The search stops as soon as the shortest paths to all the targets
+have been found.
 
 # Arguments
 
-* `graph`: a [Graph](../../../struct.Graph.html) instance
-* `node_name`: the name of the intermediate node
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
 * `weighted`: determines if shortest paths are determined with edge weight, or not
+* `sources`: The starting nodes. A shortest path to a target is one that starts at
+  any of the `sources`.
+* `targets`: The ending nodes. Targets that aren't in the graph are ignored.
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only return paths with summed weight <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return paths with at most hop_cutoff edges.
+* `first_only`: If `true` returns the first shortest path found for each target, if
+  `false` returns all shortest paths found between sources and targets.
+
+# Returns
+
+A `HashMap` with an entry for each target that can be reached from the `sources`.
 
 # Examples
 
 ```
-use graphrs::{Edge, Graph, GraphSpecs, Node};
+use graphrs::{Edge, Graph, GraphSpecs};
 use graphrs::{algorithms::{shortest_path::{dijkstra}}};
 
 let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
 graph.add_edges(vec![
-    Edge::new("n1", "n2"),
-    Edge::new("n2", "n3"),
-    Edge::new("n1", "n4"),
-    Edge::new("n4", "n3"),
-    Edge::new("n2", "n5"),
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+    Edge::with_weight("n3", "n4", 5.0),
 ]);
-let result = dijkstra::get_all_shortest_paths_involving(&graph, "n2", false);
-assert_eq!(result.len(), 2);
+
+let shortest_paths = dijkstra::multi_source_to_targets(
+    &graph,
+    true,
+    vec!["n1", "n3"],
+    vec!["n2", "n4"],
+    None,
+    None,
+    false,
+)
+.unwrap();
+assert_eq!(shortest_paths.get("n2").unwrap().distance, 1.0);
+assert_eq!(shortest_paths.get("n4").unwrap().paths, vec![vec!["n3", "n4"]]);
 ```
 */
-pub fn get_all_shortest_paths_involving<T, A>(
-    graph: &Graph<T, A>,
+pub fn multi_source_to_targets<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    sources: Vec<T>,
+    targets: Vec<T>,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+    first_only: bool,
+) -> Result<HashMap<T, ShortestPathInfo<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    let adjacency = get_lazy_indexed_graph(graph, weighted, Direction::Outgoing)?;
+    let infos = dijkstra_multisource_to_targets(
+        adjacency,
+        weighted,
+        &sources,
+        Some(&targets),
+        cutoff,
+        hop_cutoff,
+        first_only,
+    )?;
+    Ok(with_edge_keys(infos, ParallelEdgeKeys::new(graph, weighted).as_ref()))
+}
+
+/**
+Uses Dijkstra's algorithm to find the shortest weighted paths from `source` to `target`.
+The search stops as soon as `target` is reached.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `source`: The starting node.
+* `target`: The ending node.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return paths with at most hop_cutoff edges.
+
+# Returns
+
+`Ok(None)` if `target` cannot be reached from `source`, otherwise `Ok(Some(...))` with all
+the shortest paths from `source` to `target`. An `Err` is returned if either node isn't
+in the graph.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+]);
+
+let shortest_path = dijkstra::shortest_path(&graph, true, "n1", "n3", None).unwrap().unwrap();
+assert_eq!(shortest_path.distance, 2.1);
+assert_eq!(shortest_path.paths, vec![vec!["n1", "n2", "n3"]]);
+assert!(dijkstra::shortest_path(&graph, true, "n3", "n1", None).unwrap().is_none());
+
+let shortest_path = dijkstra::shortest_path(&graph, true, "n1", "n3", Some(1)).unwrap().unwrap();
+assert_eq!(shortest_path.paths, vec![vec!["n1", "n3"]]);
+```
+*/
+pub fn shortest_path<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    source: T,
+    target: T,
+    hop_cutoff: Option<usize>,
+) -> Result<Option<ShortestPathInfo<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    let mut adjacency = get_lazy_indexed_graph(graph, weighted, Direction::Outgoing)?;
+    adjacency.get_node_indexes(&[source.clone(), target.clone()])?;
+    let targets = Some(std::slice::from_ref(&target));
+    let infos = dijkstra_multisource_to_targets(
+        adjacency,
+        weighted,
+        &[source],
+        targets,
+        None,
+        hop_cutoff,
+        false,
+    )?;
+    let mut infos = with_edge_keys(infos, ParallelEdgeKeys::new(graph, weighted).as_ref());
+    Ok(infos.remove(&target))
+}
+
+/**
+Uses Dijkstra's algorithm to find shortest weighted paths from a single source node
+in a [CsrGraph](../../../struct.CsrGraph.html). This is the same as
+[single_source](./fn.single_source.html) but reads the adjacency from the compact arrays
+of the `CsrGraph` rather than the `HashMap`s of the `Graph`, which is faster when running
+many searches that visit most of a large graph that doesn't change.
+
+# Arguments
+
+* `csr`: a [CsrGraph](../../../struct.CsrGraph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `source`: The starting node.
+* `target`: The ending node. If `None` then the shortest paths between `source` and
+  all other nodes will be found.
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only return paths with summed weight <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return paths with at most hop_cutoff edges.
+* `first_only`: If `true` returns the first shortest path found for each target, if
+  `false` returns all shortest paths found between source and targets.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+]);
+
+let csr = graph.to_csr();
+let shortest_paths = dijkstra::single_source_csr(&csr, true, "n1", Some("n3"), None, None, false);
+assert_eq!(shortest_paths.unwrap().get("n3").unwrap().distance, 2.1);
+```
+*/
+pub fn single_source_csr<T, W>(
+    csr: &CsrGraph<T, W>,
+    weighted: bool,
+    source: T,
+    target: Option<T>,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+    first_only: bool,
+) -> Result<HashMap<T, ShortestPathInfo<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    multi_source_csr(
+        csr,
+        weighted,
+        vec![source],
+        target,
+        cutoff,
+        hop_cutoff,
+        first_only,
+    )
+}
+
+/**
+Uses Dijkstra's algorithm to find shortest weighted paths from multiple source nodes
+in a [CsrGraph](../../../struct.CsrGraph.html). This is the same as
+[multi_source](./fn.multi_source.html) but reads the adjacency from the `CsrGraph`.
+
+# Arguments
+
+* `csr`: a [CsrGraph](../../../struct.CsrGraph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `sources`: The starting nodes.
+* `target`: The ending node. If `None` then the shortest paths between `sources` and
+  all other nodes will be found.
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only return paths with summed weight <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return paths with at most hop_cutoff edges.
+* `first_only`: If `true` returns the first shortest path found for each target, if
+  `false` returns all shortest paths found between sources and targets.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+]);
+
+let csr = graph.to_csr();
+let shortest_paths = dijkstra::multi_source_csr(&csr, true, vec!["n1", "n2"], Some("n3"), None, None, false);
+assert_eq!(shortest_paths.unwrap().get("n3").unwrap().distance, 1.1);
+```
+*/
+pub fn multi_source_csr<T, W>(
+    csr: &CsrGraph<T, W>,
+    weighted: bool,
+    sources: Vec<T>,
+    target: Option<T>,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+    first_only: bool,
+) -> Result<HashMap<T, ShortestPathInfo<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    if weighted {
+        csr.ensure_weighted()?;
+    }
+    let targets = target.as_ref().map(std::slice::from_ref);
+    dijkstra_multisource_to_targets(
+        csr, weighted, &sources, targets, cutoff, hop_cutoff, first_only,
+    )
+}
+
+/**
+Uses Dijkstra's algorithm to find shortest paths from a single source node, where the
+cost of each edge is computed by a `cost` function rather than read from the edge's `weight`.
+This allows costs to be derived from edge attributes (for example travel time from
+length and speed) without building a new graph.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `source`: The starting node.
+* `target`: The ending node. If `None` then the shortest paths between `source` and
+  all other nodes will be found.
+* `cutoff`: Length (sum of edge costs) at which the search is stopped.
+  If cutoff is provided, only return paths with summed cost <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return paths with at most hop_cutoff edges.
+* `first_only`: If `true` returns the first shortest path found for each target, if
+  `false` returns all shortest paths found between source and targets.
+* `cost`: A function that takes an `Edge` and returns its cost, which must not be
+  negative. For undirected graphs the `Edge` may be traversed from `v` to `u`.
+  For multigraphs the lowest cost of the edges between two nodes is used.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+#[derive(Clone)]
+struct Road {
+    length: f64,
+    speed: f64,
+}
+
+let road = |u, v, length, speed| Edge {
+    u,
+    v,
+    attributes: Some(Road { length, speed }),
+    weight: f64::NAN,
+};
+let mut graph = Graph::<&str, Road>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    road("n1", "n2", 10.0, 50.0),
+    road("n2", "n3", 10.0, 50.0),
+    road("n1", "n3", 15.0, 30.0),
+]);
+
+let travel_time = |edge: &Edge<&str, Road>| {
+    let road = edge.attributes.as_ref().unwrap();
+    road.length / road.speed
+};
+let shortest_paths =
+    dijkstra::single_source_with_cost(&graph, "n1", Some("n3"), None, None, false, travel_time);
+let info = shortest_paths.unwrap();
+assert_eq!(info.get("n3").unwrap().paths, vec![vec!["n1", "n2", "n3"]]);
+```
+*/
+pub fn single_source_with_cost<T, A, W, F>(
+    graph: &Graph<T, A, W>,
+    source: T,
+    target: Option<T>,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+    first_only: bool,
+    cost: F,
+) -> Result<HashMap<T, ShortestPathInfo<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+    F: Fn(&Edge<T, A, W>) -> W,
+{
+    multi_source_with_cost(
+        graph,
+        vec![source],
+        target,
+        cutoff,
+        hop_cutoff,
+        first_only,
+        cost,
+    )
+}
+
+/**
+Uses Dijkstra's algorithm to find shortest paths from multiple source nodes, where the
+cost of each edge is computed by a `cost` function rather than read from the edge's `weight`.
+See [single_source_with_cost](./fn.single_source_with_cost.html).
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `sources`: The starting nodes. The shortest path will be found that can start
+  for any of the `sources` and ends at the `target`.
+* `target`: The ending node. If `None` then the shortest paths between `sources` and
+  all other nodes will be found.
+* `cutoff`: Length (sum of edge costs) at which the search is stopped.
+  If cutoff is provided, only return paths with summed cost <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return paths with at most hop_cutoff edges.
+* `first_only`: If `true` returns the first shortest path found for each target, if
+  `false` returns all shortest paths found between sources and targets.
+* `cost`: A function that takes an `Edge` and returns its cost, which must not be
+  negative. For undirected graphs the `Edge` may be traversed from `v` to `u`.
+  For multigraphs the lowest cost of the edges between two nodes is used.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+]);
+
+// count each edge as at least 2.0
+let cost = |edge: &Edge<&str, ()>| edge.weight.max(2.0);
+let shortest_paths =
+    dijkstra::multi_source_with_cost(&graph, vec!["n1", "n2"], Some("n3"), None, None, false, cost);
+assert_eq!(shortest_paths.unwrap().get("n3").unwrap().distance, 2.0);
+```
+*/
+pub fn multi_source_with_cost<T, A, W, F>(
+    graph: &Graph<T, A, W>,
+    sources: Vec<T>,
+    target: Option<T>,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+    first_only: bool,
+    cost: F,
+) -> Result<HashMap<T, ShortestPathInfo<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+    F: Fn(&Edge<T, A, W>) -> W,
+{
+    let get_cost = |u: &T, v: &T| match graph.specs.multi_edges {
+        false => cost(graph.get_edge(u.clone(), v.clone()).unwrap()),
+        true => graph
+            .get_edges(u.clone(), v.clone())
+            .unwrap()
+            .into_iter()
+            .map(&cost)
+            .reduce(min_weight)
+            .unwrap(),
+    };
+    let adjacency = LazyIndexedGraph::new(graph, Direction::Outgoing, get_cost);
+    let targets = target.as_ref().map(std::slice::from_ref);
+    let infos = dijkstra_multisource_to_targets(
+        adjacency, true, &sources, targets, cutoff, hop_cutoff, first_only,
+    )?;
+    Ok(with_edge_keys(infos, ParallelEdgeKeys::with_costs(graph, &cost).as_ref()))
+}
+
+/**
+Uses Dijkstra's algorithm to find the shortest path distances between all pairs of nodes.
+No paths are recorded, which makes this much faster than [all_pairs](./fn.all_pairs.html)
+when only distances are needed.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only return distances that are <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return distances of paths with at most hop_cutoff edges.
+
+# Returns
+
+A `HashMap` of `HashMaps`. The keys to the first one are the starting nodes
+and the keys to the second are the target nodes. The values of the second one are the
+shortest path distances between the starting and target nodes.
+
+If the search from any starting node fails an `Err` is returned for the first such node
+in sorted order.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+]);
+
+let distances = dijkstra::all_pairs_distances(&graph, true, None, None).unwrap();
+assert_eq!(distances.get("n1").unwrap().get("n3").unwrap(), &2.1);
+assert_eq!(distances.get("n3").unwrap().len(), 1);
+```
+*/
+pub fn all_pairs_distances<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+) -> Result<HashMap<T, HashMap<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    let options = ParallelOptions::default();
+    all_pairs_distances_with_options(graph, weighted, cutoff, hop_cutoff, &options)
+}
+
+/**
+Uses Dijkstra's algorithm to find the shortest path distances between all pairs of nodes,
+like [all_pairs_distances](./fn.all_pairs_distances.html), with the threads and
+[ProgressHandle](../../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only return distances that are <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return distances of paths with at most hop_cutoff edges.
+* `options`: a [ParallelOptions](../../../struct.ParallelOptions.html); each starting node
+  that has been searched from is a unit of work for its `progress`
+
+# Returns
+
+The same as [all_pairs_distances](./fn.all_pairs_distances.html). An `Error` with a `kind`
+of `ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::shortest_path::dijkstra, generators, Parallelism, ParallelOptions};
+
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let distances = dijkstra::all_pairs_distances_with_options(&graph, false, None, None, &options);
+assert_eq!(distances.unwrap().len(), 34);
+```
+*/
+pub fn all_pairs_distances_with_options<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+    options: &ParallelOptions,
+) -> Result<HashMap<T, HashMap<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    let csr = get_csr(graph, weighted)?;
+    let names = csr.get_node_names();
+    let progress = &options.progress;
+    progress.start(Some(names.len()))?;
+    let results: Vec<Result<HashMap<T, W>, Error>> = options.map_collect(names.len(), |index| {
+        progress.check()?;
+        let result = dijkstra_distances(&csr, weighted, index as u32, cutoff, hop_cutoff);
+        progress.advance();
+        result
+    })?;
+    progress.check()?;
+    collect_source_results(names, results)
+}
+
+/**
+Uses Dijkstra's algorithm to find the shortest path distances from a single source node
+to all the nodes that can be reached from it. No paths are recorded, which makes this
+much faster than [single_source](./fn.single_source.html) when only distances are needed.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `source`: The starting node.
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only return distances that are <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return distances of paths with at most hop_cutoff edges.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+]);
+
+let distances = dijkstra::single_source_distances(&graph, true, "n1", None, None).unwrap();
+assert_eq!(distances.get("n3").unwrap(), &2.1);
+```
+*/
+pub fn single_source_distances<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    source: T,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+) -> Result<HashMap<T, W>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    single_source_distances_in_direction(
+        graph,
+        weighted,
+        source,
+        Direction::Outgoing,
+        cutoff,
+        hop_cutoff,
+    )
+}
+
+/**
+Uses Dijkstra's algorithm to find the shortest path distances between a single node and
+all the nodes that are connected to it by paths that follow the edges in `direction`.
+With `Direction::Incoming` these are the distances from every node that can reach
+`source` to `source`, which avoids having to create a reversed copy of a directed graph.
+With `Direction::Outgoing` this is the same as
+[single_source_distances](./fn.single_source_distances.html).
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `source`: The node that paths start from, or end at for `Direction::Incoming`.
+* `direction`: the [Direction](../../../enum.Direction.html) of the edges to follow
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only return distances that are <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return distances of paths with at most hop_cutoff edges.
+
+# Examples
+
+```
+use graphrs::{Direction, Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+    Edge::with_weight("n3", "n4", 1.0),
+]);
+
+let distances = dijkstra::single_source_distances_in_direction(
+    &graph, true, "n3", Direction::Incoming, None, None
+).unwrap();
+assert_eq!(distances.get("n1").unwrap(), &2.1);
+assert!(distances.get("n4").is_none());
+```
+*/
+pub fn single_source_distances_in_direction<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    source: T,
+    direction: Direction,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+) -> Result<HashMap<T, W>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    let mut adjacency = get_lazy_indexed_graph(graph, weighted, direction)?;
+    let source = adjacency.get_node_indexes(&[source])?[0];
+    dijkstra_distances(adjacency, weighted, source, cutoff, hop_cutoff)
+}
+
+/**
+Uses Dijkstra's algorithm to find the shortest path distances from a single source node
+in a [CsrGraph](../../../struct.CsrGraph.html). This is the same as
+[single_source_distances](./fn.single_source_distances.html) but reads the adjacency
+from the `CsrGraph`.
+
+# Arguments
+
+* `csr`: a [CsrGraph](../../../struct.CsrGraph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `source`: The starting node.
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only return distances that are <= cutoff.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return distances of paths with at most hop_cutoff edges.
+
+# Examples
+
+```
+use graphrs::generators;
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+let graph = generators::social::karate_club_graph();
+let csr = graph.to_csr();
+let distances = dijkstra::single_source_distances_csr(&csr, false, 0, None, None).unwrap();
+assert_eq!(distances.get(&1).unwrap(), &1.0);
+```
+*/
+pub fn single_source_distances_csr<T, W>(
+    csr: &CsrGraph<T, W>,
+    weighted: bool,
+    source: T,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+) -> Result<HashMap<T, W>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    if weighted {
+        csr.ensure_weighted()?;
+    }
+    let source = csr.get_node_indexes(&[source])?[0];
+    dijkstra_distances(csr, weighted, source, cutoff, hop_cutoff)
+}
+
+/**
+Uses Dijkstra's algorithm to find shortest weighted paths from a single source node,
+returning the distances and shortest-path predecessors of the nodes rather than the paths.
+
+On graphs with many equal-cost shortest paths the number of paths can grow exponentially,
+which makes [single_source](./fn.single_source.html) slow and memory hungry. The
+predecessors are linear in the size of the graph, and paths can be reconstructed
+on demand for just the targets that are needed.
+
+There is no `hop_cutoff` argument. A shortest path to a node with at most `hop_cutoff`
+edges can reach one of its predecessors by a path that isn't that predecessor's own
+shortest path within the limit, so these paths can't be rebuilt from the predecessors
+of each node. Use [single_source](./fn.single_source.html) with a `hop_cutoff` instead.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `source`: The starting node.
+* `target`: The ending node. If `Some` the search stops once `target` is reached.
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only nodes with a distance <= cutoff are reached.
+* `first_only`: If `true` only the first shortest-path predecessor found is kept for each
+  node, if `false` all shortest-path predecessors are kept.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n1", "n3", 1.0),
+    Edge::with_weight("n2", "n4", 1.0),
+    Edge::with_weight("n3", "n4", 1.0),
+]);
+
+let result = dijkstra::single_source_predecessors(&graph, true, "n1", None, None, false).unwrap();
+assert_eq!(result.get_distance(&"n4"), Some(2.0));
+assert_eq!(result.predecessors.get("n4").unwrap().len(), 2);
+assert_eq!(result.iter_paths(&"n4").count(), 2);
+```
+*/
+pub fn single_source_predecessors<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    source: T,
+    target: Option<T>,
+    cutoff: Option<W>,
+    first_only: bool,
+) -> Result<ShortestPathPredecessors<T, W>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    multi_source_predecessors(graph, weighted, vec![source], target, cutoff, first_only)
+}
+
+/**
+Uses Dijkstra's algorithm to find shortest weighted paths from multiple source nodes,
+returning the distances and shortest-path predecessors of the nodes rather than the paths.
+See [single_source_predecessors](./fn.single_source_predecessors.html), which also explains
+why there is no `hop_cutoff` argument.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `sources`: The starting nodes.
+* `target`: The ending node. If `Some` the search stops once `target` is reached.
+* `cutoff`: Length (sum of edge weights) at which the search is stopped.
+  If cutoff is provided, only nodes with a distance <= cutoff are reached.
+* `first_only`: If `true` only the first shortest-path predecessor found is kept for each
+  node, if `false` all shortest-path predecessors are kept.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+]);
+
+let result =
+    dijkstra::multi_source_predecessors(&graph, true, vec!["n1", "n2"], None, None, false)
+        .unwrap();
+assert_eq!(result.get_distance(&"n3"), Some(1.1));
+assert_eq!(result.get_paths(&"n3"), vec![vec!["n2", "n3"]]);
+```
+*/
+pub fn multi_source_predecessors<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    sources: Vec<T>,
+    target: Option<T>,
+    cutoff: Option<W>,
+    first_only: bool,
+) -> Result<ShortestPathPredecessors<T, W>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    let mut adjacency = get_lazy_indexed_graph(graph, weighted, Direction::Outgoing)?;
+    let sources = adjacency.get_node_indexes(&sources)?;
+    let target = target.and_then(|t| adjacency.get_node_index(&t));
+    let num_indexes = adjacency.number_of_indexes();
+
+    let mut preds: Vec<Vec<u32>> = vec![vec![]; num_indexes];
+    let mut dist: Vec<Option<W>> = vec![None; num_indexes];
+    let mut seen: Vec<Option<W>> = vec![None; num_indexes];
+    let mut fringe = BinaryHeap::new();
+    let mut count = 0;
+    let mut row = vec![];
+
+    for source in sort_by_name(&adjacency, sources.clone()) {
+        seen[source as usize] = Some(W::zero());
+        push_weighted_fringe_node(&mut count, &mut fringe, source, W::zero());
+    }
+
+    while let Some(fringe_item) = fringe.pop() {
+        let d = fringe_item.distance;
+        let v = fringe_item.node_name;
+        if dist[v as usize].is_some() {
+            continue;
+        }
+        dist[v as usize] = Some(d);
+        if target == Some(v) {
+            break;
+        }
+        adjacency.load_successors_or_neighbors(v, &mut row);
+        let num_indexes = adjacency.number_of_indexes();
+        grow(&mut preds, num_indexes, vec![]);
+        grow(&mut dist, num_indexes, None);
+        grow(&mut seen, num_indexes, None);
+        for &(u, weight) in row.iter() {
+            let vu_dist = d + get_cost_or_one(weight, weighted);
+            if cutoff.is_some() && vu_dist > cutoff.unwrap() {
+                continue;
+            }
+            if let Some(u_dist) = dist[u as usize] {
+                if vu_dist < u_dist {
+                    return Err(get_contractory_paths_error());
+                }
+            } else if seen[u as usize].is_none() || vu_dist < seen[u as usize].unwrap() {
+                seen[u as usize] = Some(vu_dist);
+                push_weighted_fringe_node(&mut count, &mut fringe, u, vu_dist);
+                preds[u as usize] = vec![v];
+            } else if !first_only && Some(vu_dist) == seen[u as usize] {
+                preds[u as usize].push(v);
+            }
+        }
+    }
+
+    // a source can be reached from another source along zero-weight edges,
+    // but it is still the start of its paths
+    for source in sources {
+        preds[source as usize] = vec![];
+    }
+
+    let name = |i: usize| adjacency.get_node_name(i as u32).clone();
+    let reached = (0..dist.len()).filter(|i| dist[*i].is_some());
+    Ok(ShortestPathPredecessors {
+        distances: reached.clone().map(|i| (name(i), dist[i].unwrap())).collect(),
+        predecessors: reached
+            .map(|i| (name(i), preds[i].iter().map(|p| name(*p as usize)).collect()))
+            .collect(),
+    })
+}
+
+/**
+Uses a bidirectional version of Dijkstra's algorithm to find the shortest weighted path
+between `source` and `target`. The search proceeds from both ends at the same time,
+which typically explores far fewer nodes than a single-source search.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance where all edges have a weight.
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `source`: The starting node.
+* `target`: The ending node.
+* `hop_cutoff`: Number of edges at which the search is stopped.
+  If hop_cutoff is provided, only return a path with at most hop_cutoff edges. The end of
+  a shortest path with at most hop_cutoff edges isn't a shortest path from the target, so
+  the search only proceeds from `source`, like [shortest_path](./fn.shortest_path.html).
+
+# Returns
+
+`Ok(None)` if `target` cannot be reached from `source`, otherwise `Ok(Some(...))` with a
+single shortest path.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+]);
+
+let shortest_path = dijkstra::bidirectional(&graph, true, "n1", "n3", None).unwrap().unwrap();
+assert_eq!(shortest_path.distance, 2.1);
+assert_eq!(shortest_path.paths, vec![vec!["n1", "n2", "n3"]]);
+```
+
+# References
+
+1. Ira Pohl. Bi-directional Search. Machine Intelligence, 6:127–140, 1971.
+*/
+pub fn bidirectional<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    source: T,
+    target: T,
+    hop_cutoff: Option<usize>,
+) -> Result<Option<ShortestPathInfo<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    for node_name in [&source, &target] {
+        if !graph.has_node(node_name) {
+            return Err(Error {
+                kind: ErrorKind::NodeNotFound,
+                message: format!("Requested node '{}' was not found in the graph.", node_name),
+            });
+        }
+    }
+    let edge_keys = ParallelEdgeKeys::new(graph, weighted);
+    if source == target {
+        return Ok(Some(with_path_edge_keys(
+            ShortestPathInfo {
+                distance: W::zero(),
+                paths: vec![vec![source]],
+                edge_keys: None,
+            },
+            edge_keys.as_ref(),
+        )));
+    }
+    if hop_cutoff.is_some() {
+        let adjacency = get_lazy_indexed_graph(graph, weighted, Direction::Outgoing)?;
+        let targets = Some(std::slice::from_ref(&target));
+        let mut infos = dijkstra_multisource_to_targets(
+            adjacency,
+            weighted,
+            &[source],
+            targets,
+            None,
+            hop_cutoff,
+            true,
+        )?;
+        let info = infos.remove(&target);
+        return Ok(info.map(|info| with_path_edge_keys(info, edge_keys.as_ref())));
+    }
+
+    let get_cost = |u, v| match weighted {
+        true => match graph.specs.multi_edges {
+            false => get_cost_single(graph, u, v),
+            true => get_cost_multi(graph, u, v),
+        },
+        false => W::one(),
+    };
+
+    // index 0 holds the forward search from `source`, index 1 the backward search from `target`
+    let mut dists = [HashMap::<T, W>::new(), HashMap::<T, W>::new()];
+    let mut seen = [HashMap::<T, W>::new(), HashMap::<T, W>::new()];
+    let mut paths = [HashMap::<T, Vec<T>>::new(), HashMap::<T, Vec<T>>::new()];
+    let mut fringes = [BinaryHeap::new(), BinaryHeap::new()];
+    let mut count = 0;
+    let mut final_dist = W::zero();
+    let mut final_path: Vec<T> = vec![];
+
+    for (dir, node_name) in vec![source, target].into_iter().enumerate() {
+        seen[dir].insert(node_name.clone(), W::zero());
+        paths[dir].insert(node_name.clone(), vec![node_name.clone()]);
+        push_weighted_fringe_node(&mut count, &mut fringes[dir], node_name, W::zero());
+    }
+
+    let mut dir = 1;
+    while !fringes[0].is_empty() && !fringes[1].is_empty() {
+        dir = 1 - dir;
+        let fringe_item = fringes[dir].pop().unwrap();
+        let d = fringe_item.distance;
+        let v = fringe_item.node_name;
+        if dists[dir].contains_key(&v) {
+            continue;
+        }
+        dists[dir].insert(v.clone(), d);
+        if dists[1 - dir].contains_key(&v) {
+            // the searches have met; the best connection found so far is the shortest path
+            return Ok(Some(with_path_edge_keys(
+                ShortestPathInfo {
+                    distance: final_dist,
+                    paths: vec![final_path],
+                    edge_keys: None,
+                },
+                edge_keys.as_ref(),
+            )));
+        }
+        let nbrs = match (dir, graph.specs.directed) {
+            (1, true) => Either::Left(graph.predecessors_iter(v.clone()).unwrap()),
+            _ => Either::Right(graph.successors_or_neighbors_iter(v.clone()).unwrap()),
+        };
+        for w in nbrs {
+            let w = w.clone();
+            let cost = match dir {
+                0 => get_cost(v.clone(), w.clone()),
+                _ => get_cost(w.clone(), v.clone()),
+            };
+            let vw_dist = d + cost;
+            if let Some(w_dist) = dists[dir].get(&w) {
+                if vw_dist < *w_dist {
+                    return Err(get_contractory_paths_error());
+                }
+            } else if !seen[dir].contains_key(&w) || vw_dist < *seen[dir].get(&w).unwrap() {
+                seen[dir].insert(w.clone(), vw_dist);
+                push_weighted_fringe_node(&mut count, &mut fringes[dir], w.clone(), vw_dist);
+                let mut w_path = paths[dir].get(&v).unwrap().clone();
+                w_path.push(w.clone());
+                paths[dir].insert(w.clone(), w_path);
+                if let (Some(d0), Some(d1)) = (seen[0].get(&w), seen[1].get(&w)) {
+                    let total_dist = *d0 + *d1;
+                    if final_path.is_empty() || total_dist < final_dist {
+                        final_dist = total_dist;
+                        final_path = paths[0].get(&w).unwrap().clone();
+                        final_path.extend(paths[1].get(&w).unwrap().iter().rev().skip(1).cloned());
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/**
+Uses Dijkstra's algorithm to find shortest weighted paths from `sources` and returns the
+results for `targets`, if there are any. Targets that aren't in the graph are ignored.
+*/
+pub(crate) fn dijkstra_multisource_to_targets<T, W, G>(
+    mut graph: G,
+    weighted: bool,
+    sources: &[T],
+    targets: Option<&[T]>,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+    first_only: bool,
+) -> Result<HashMap<T, ShortestPathInfo<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+    G: IndexedAdjacency<T, W>,
+{
+    let sources = graph.get_node_indexes(sources)?;
+    let targets: Option<Vec<u32>> =
+        targets.map(|ts| ts.iter().filter_map(|t| graph.get_node_index(t)).collect());
+    if targets.as_ref().is_some_and(|ts| ts.is_empty()) {
+        return Ok(HashMap::new());
+    }
+    dijkstra_multisource(
+        graph,
+        weighted,
+        sources,
+        targets.as_deref(),
+        cutoff,
+        hop_cutoff,
+        first_only,
+    )
+}
+
+/**
+Uses Dijkstra's algorithm to find shortest weighted paths.
+This is a private function that does all the work of finding the
+shortest paths. All the public functions in this module that return paths call this one.
+*/
+fn dijkstra_multisource<T, W, G>(
+    mut graph: G,
+    weighted: bool,
+    sources: Vec<u32>,
+    targets: Option<&[u32]>,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+    first_only: bool,
+) -> Result<HashMap<T, ShortestPathInfo<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+    G: IndexedAdjacency<T, W>,
+{
+    if let Some(max_hops) = get_binding_hop_cutoff(hop_cutoff, graph.number_of_nodes()) {
+        let search = dijkstra_hop_limited(
+            &mut graph, weighted, &sources, targets, cutoff, max_hops, first_only,
+        )?;
+        let (is_target, _) = get_is_target(search.dist.len(), targets);
+        let paths: Vec<Vec<Vec<u32>>> = (0..search.dist.len())
+            .map(|i| match is_target.is_empty() || is_target[i] {
+                true => search.get_paths(i),
+                false => vec![],
+            })
+            .collect();
+        return Ok(get_shortest_path_infos(
+            &graph,
+            &search.dist,
+            &paths,
+            &is_target,
+        ));
+    }
+
+    let num_indexes = graph.number_of_indexes();
+    let mut paths: Vec<Vec<Vec<u32>>> = vec![vec![]; num_indexes];
+    let mut dist: Vec<Option<W>> = vec![None; num_indexes];
+    let mut seen: Vec<Option<W>> = vec![None; num_indexes];
+    let mut fringe = BinaryHeap::new();
+    let mut count = 0;
+    let (mut is_target, mut remaining_targets) = get_is_target(num_indexes, targets);
+    let mut row = vec![];
+
+    for source in sort_by_name(&graph, sources) {
+        paths[source as usize] = vec![vec![source]];
+        seen[source as usize] = Some(W::zero());
+        push_weighted_fringe_node(&mut count, &mut fringe, source, W::zero());
+    }
+
+    while let Some(fringe_item) = fringe.pop() {
+        let d = fringe_item.distance;
+        let v = fringe_item.node_name;
+        if dist[v as usize].is_some() {
+            continue;
+        }
+        dist[v as usize] = Some(d);
+        if targets.is_some() && is_target[v as usize] {
+            remaining_targets -= 1;
+            if remaining_targets == 0 {
+                break;
+            }
+        }
+        graph.load_successors_or_neighbors(v, &mut row);
+        let num_indexes = graph.number_of_indexes();
+        grow(&mut paths, num_indexes, vec![]);
+        grow(&mut dist, num_indexes, None);
+        grow(&mut seen, num_indexes, None);
+        if targets.is_some() {
+            grow(&mut is_target, num_indexes, false);
+        }
+        for &(u, weight) in row.iter() {
+            let vu_dist = d + get_cost_or_one(weight, weighted);
+            if cutoff.is_some() && vu_dist > cutoff.unwrap() {
+                continue;
+            }
+            if let Some(u_dist) = dist[u as usize] {
+                if vu_dist < u_dist {
+                    return Err(get_contractory_paths_error());
+                }
+            } else if seen[u as usize].is_none() || vu_dist < seen[u as usize].unwrap() {
+                seen[u as usize] = Some(vu_dist);
+                push_weighted_fringe_node(&mut count, &mut fringe, u, vu_dist);
+                let mut new_paths_v = paths[v as usize].clone();
+                new_paths_v.iter_mut().for_each(|pv| pv.push(u));
+                paths[u as usize] = new_paths_v;
+            } else if !first_only && Some(vu_dist) == seen[u as usize] {
+                push_weighted_fringe_node(&mut count, &mut fringe, u, vu_dist);
+                add_u_to_v_paths_and_append_v_paths_to_u_paths(u, v, &mut paths);
+            }
+        }
+    }
+
+    Ok(get_shortest_path_infos(&graph, &dist, &paths, &is_target))
+}
+
+/**
+Converts the distances and paths of the node indexes reached by a search into
+`ShortestPathInfo`s. If `is_target` isn't empty only the targets are converted.
+*/
+fn get_shortest_path_infos<T, W, G>(
+    graph: &G,
+    dist: &[Option<W>],
+    paths: &[Vec<Vec<u32>>],
+    is_target: &[bool],
+) -> HashMap<T, ShortestPathInfo<T, W>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+    G: IndexedAdjacency<T, W>,
+{
+    (0..dist.len())
+        .filter(|i| dist[*i].is_some() && (is_target.is_empty() || is_target[*i]))
+        .map(|i| {
+            let info = ShortestPathInfo {
+                distance: dist[i].unwrap(),
+                paths: paths[i]
+                    .iter()
+                    .map(|path| path.iter().map(|n| graph.get_node_name(*n).clone()).collect())
+                    .collect(),
+                edge_keys: None,
+            };
+            (graph.get_node_name(i as u32).clone(), info)
+        })
+        .collect()
+}
+
+/**
+Uses Dijkstra's algorithm to find the shortest path distances from `source`, without
+recording any paths.
+*/
+fn dijkstra_distances<T, W, G>(
+    mut graph: G,
+    weighted: bool,
+    source: u32,
+    cutoff: Option<W>,
+    hop_cutoff: Option<usize>,
+) -> Result<HashMap<T, W>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+    G: IndexedAdjacency<T, W>,
+{
+    if let Some(max_hops) = get_binding_hop_cutoff(hop_cutoff, graph.number_of_nodes()) {
+        let search =
+            dijkstra_hop_limited(&mut graph, weighted, &[source], None, cutoff, max_hops, true)?;
+        return Ok(get_distances(&graph, &search.dist));
+    }
+
+    let num_indexes = graph.number_of_indexes();
+    let mut dist: Vec<Option<W>> = vec![None; num_indexes];
+    let mut seen: Vec<Option<W>> = vec![None; num_indexes];
+    let mut fringe = BinaryHeap::new();
+    let mut count = 0;
+    let mut row = vec![];
+
+    seen[source as usize] = Some(W::zero());
+    push_weighted_fringe_node(&mut count, &mut fringe, source, W::zero());
+
+    while let Some(fringe_item) = fringe.pop() {
+        let d = fringe_item.distance;
+        let v = fringe_item.node_name;
+        if dist[v as usize].is_some() {
+            continue;
+        }
+        dist[v as usize] = Some(d);
+        graph.load_successors_or_neighbors(v, &mut row);
+        let num_indexes = graph.number_of_indexes();
+        grow(&mut dist, num_indexes, None);
+        grow(&mut seen, num_indexes, None);
+        for &(u, weight) in row.iter() {
+            let vu_dist = d + get_cost_or_one(weight, weighted);
+            if cutoff.is_some() && vu_dist > cutoff.unwrap() {
+                continue;
+            }
+            if let Some(u_dist) = dist[u as usize] {
+                if vu_dist < u_dist {
+                    return Err(get_contractory_paths_error());
+                }
+            } else if seen[u as usize].is_none() || vu_dist < seen[u as usize].unwrap() {
+                seen[u as usize] = Some(vu_dist);
+                push_weighted_fringe_node(&mut count, &mut fringe, u, vu_dist);
+            }
+        }
+    }
+
+    Ok(get_distances(&graph, &dist))
+}
+
+/// Converts the distances of the node indexes reached by a search into a `HashMap`.
+fn get_distances<T, W, G>(graph: &G, dist: &[Option<W>]) -> HashMap<T, W>
+where
+    T: Hash + Eq + Clone,
+    W: Copy,
+    G: IndexedAdjacency<T, W>,
+{
+    (0..dist.len())
+        .filter_map(|i| dist[i].map(|d| (graph.get_node_name(i as u32).clone(), d)))
+        .collect()
+}
+
+/**
+The result of a Dijkstra search that only follows paths with at most `max_hops` edges.
+
+The search runs over (node index, number of edges) states rather than nodes, because the
+shortest path to a node that is within the hop limit can be longer than its shortest path
+overall, and a node reached by a longer path with fewer edges can still lead further.
+*/
+struct HopLimitedSearch<W> {
+    dist: Vec<Option<W>>,
+    /// the states whose paths are shortest paths to each node
+    states: Vec<Vec<(u32, usize)>>,
+    /// the predecessor states of each state that isn't a source
+    preds: HashMap<(u32, usize), Vec<(u32, usize)>>,
+}
+
+impl<W> HopLimitedSearch<W> {
+    /// Returns the shortest paths to the node with index `v`; paths that visit a node
+    /// twice, along edges with zero weight, are left out.
+    fn get_paths(&self, v: usize) -> Vec<Vec<u32>> {
+        let mut paths = vec![];
+        for state in self.states[v].iter() {
+            let mut stack = vec![(*state, vec![state.0])];
+            while let Some((s, path)) = stack.pop() {
+                match self.preds.get(&s) {
+                    None => paths.push(path.into_iter().rev().collect()),
+                    Some(preds) => {
+                        for pred in preds.iter().rev().filter(|p| !path.contains(&p.0)) {
+                            let mut pred_path = path.clone();
+                            pred_path.push(pred.0);
+                            stack.push((*pred, pred_path));
+                        }
+                    }
+                }
+            }
+        }
+        paths
+    }
+}
+
+/**
+Uses Dijkstra's algorithm to find shortest weighted paths from `sources` that have at most
+`max_hops` edges. See [HopLimitedSearch](./struct.HopLimitedSearch.html).
+*/
+fn dijkstra_hop_limited<T, W, G>(
+    mut graph: G,
+    weighted: bool,
+    sources: &[u32],
+    targets: Option<&[u32]>,
+    cutoff: Option<W>,
+    max_hops: usize,
+    first_only: bool,
+) -> Result<HopLimitedSearch<W>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+    G: IndexedAdjacency<T, W>,
+{
+    let num_indexes = graph.number_of_indexes();
+    let mut search = HopLimitedSearch {
+        dist: vec![None; num_indexes],
+        states: vec![vec![]; num_indexes],
+        preds: HashMap::new(),
+    };
+    // the fewest edges of the states of each node that have been expanded
+    let mut min_hops = vec![usize::MAX; num_indexes];
+    let mut seen: HashMap<(u32, usize), W> = HashMap::new();
+    let mut settled: HashSet<(u32, usize)> = HashSet::new();
+    let mut fringe = BinaryHeap::new();
+    let mut count = 0;
+    let (mut is_target, mut remaining_targets) = get_is_target(num_indexes, targets);
+    let mut row = vec![];
+
+    for source in sort_by_name(&graph, sources.to_vec()) {
+        seen.insert((source, 0), W::zero());
+        push_weighted_fringe_node(&mut count, &mut fringe, (source, 0), W::zero());
+    }
+
+    while let Some(fringe_item) = fringe.pop() {
+        let d = fringe_item.distance;
+        let (v, hops) = fringe_item.node_name;
+        if !settled.insert((v, hops)) {
+            continue;
+        }
+        match search.dist[v as usize] {
+            None => {
+                search.dist[v as usize] = Some(d);
+                search.states[v as usize].push((v, hops));
+                if targets.is_some() && is_target[v as usize] {
+                    remaining_targets -= 1;
+                    if remaining_targets == 0 {
+                        break;
+                    }
+                }
+            }
+            Some(v_dist) if !first_only && d == v_dist => {
+                search.states[v as usize].push((v, hops));
+            }
+            // a longer path to `v` only needs to be followed if it has fewer edges
+            Some(_) if hops >= min_hops[v as usize] => continue,
+            Some(_) => {}
+        }
+        min_hops[v as usize] = min_hops[v as usize].min(hops);
+        if hops == max_hops {
+            continue;
+        }
+        graph.load_successors_or_neighbors(v, &mut row);
+        let num_indexes = graph.number_of_indexes();
+        grow(&mut search.dist, num_indexes, None);
+        grow(&mut search.states, num_indexes, vec![]);
+        grow(&mut min_hops, num_indexes, usize::MAX);
+        if targets.is_some() {
+            grow(&mut is_target, num_indexes, false);
+        }
+        for &(u, weight) in row.iter() {
+            let vu_dist = d + get_cost_or_one(weight, weighted);
+            if cutoff.is_some() && vu_dist > cutoff.unwrap() {
+                continue;
+            }
+            let state = (u, hops + 1);
+            match seen.get(&state) {
+                Some(u_dist) if settled.contains(&state) => {
+                    if vu_dist < *u_dist {
+                        return Err(get_contractory_paths_error());
+                    }
+                }
+                Some(u_dist) if *u_dist <= vu_dist => {
+                    if !first_only && vu_dist == *u_dist {
+                        search.preds.get_mut(&state).unwrap().push((v, hops));
+                    }
+                }
+                _ => {
+                    seen.insert(state, vu_dist);
+                    push_weighted_fringe_node(&mut count, &mut fringe, state, vu_dist);
+                    search.preds.insert(state, vec![(v, hops)]);
+                }
+            }
+        }
+    }
+
+    Ok(search)
+}
+
+/**
+Returns the node `indexes` sorted by the names of the nodes. Searches start from their
+sources in this order, so that their results don't depend on the order in which the
+`graph` gave the nodes their indexes.
+*/
+fn sort_by_name<T, W, G>(graph: &G, mut indexes: Vec<u32>) -> Vec<u32>
+where
+    T: Ord,
+    G: IndexedAdjacency<T, W>,
+{
+    indexes.sort_by(|a, b| graph.get_node_name(*a).cmp(graph.get_node_name(*b)));
+    indexes
+}
+
+/**
+Adds `value`s to the end of `values` until there is one for each of the first `len` node
+indexes. A graph can give out more indexes while it is being searched.
+*/
+#[inline]
+fn grow<X: Clone>(values: &mut Vec<X>, len: usize, value: X) {
+    if values.len() < len {
+        values.resize(len, value);
+    }
+}
+
+/**
+Returns `hop_cutoff` if it can rule out any shortest paths. A shortest path never needs
+more than `num_nodes - 1` edges, so larger values are returned as `None`.
+*/
+fn get_binding_hop_cutoff(hop_cutoff: Option<usize>, num_nodes: usize) -> Option<usize> {
+    hop_cutoff.filter(|max_hops| max_hops.saturating_add(1) < num_nodes)
+}
+
+/**
+Pairs the result of the search from each source in `names` with the source. If any of the
+searches failed the error of the first of those sources is returned, so that the error
+doesn't depend on the order in which the parallel searches finish, and the message names
+the source.
+*/
+pub(crate) fn collect_source_results<T, R>(
+    names: &[T],
+    results: Vec<Result<R, Error>>,
+) -> Result<HashMap<T, R>, Error>
+where
+    T: Hash + Eq + Clone + Display,
+{
+    names
+        .iter()
+        .zip(results)
+        .map(|(name, result)| match result {
+            Ok(r) => Ok((name.clone(), r)),
+            Err(e) => Err(get_source_error(e, name)),
+        })
+        .collect()
+}
+
+/// Adds the name of the `source` node whose search failed to the message of `error`.
+fn get_source_error<T: Display>(error: Error, source: &T) -> Error {
+    Error {
+        kind: error.kind,
+        message: format!("Search from node '{}' failed: {}", source, error.message),
+    }
+}
+
+/**
+Returns a `Vec` that is `true` for the indexes of the `targets`, and the number of distinct
+targets. The `Vec` is empty if there are no `targets`.
+*/
+fn get_is_target(num_nodes: usize, targets: Option<&[u32]>) -> (Vec<bool>, usize) {
+    match targets {
+        None => (vec![], 0),
+        Some(targets) => {
+            let mut is_target = vec![false; num_nodes];
+            let mut num_targets = 0;
+            for target in targets {
+                if !is_target[*target as usize] {
+                    is_target[*target as usize] = true;
+                    num_targets += 1;
+                }
+            }
+            (is_target, num_targets)
+        }
+    }
+}
+
+/// Sets the `edge_keys` of the `infos` if `edge_keys` is `Some`, which it is for multigraphs.
+pub(crate) fn with_edge_keys<T, W>(
+    mut infos: HashMap<T, ShortestPathInfo<T, W>>,
+    edge_keys: Option<&ParallelEdgeKeys<T>>,
+) -> HashMap<T, ShortestPathInfo<T, W>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    if let Some(edge_keys) = edge_keys {
+        edge_keys.set_all_edge_keys(&mut infos);
+    }
+    infos
+}
+
+/// Sets the `edge_keys` of `info` if `edge_keys` is `Some`, which it is for multigraphs.
+pub(crate) fn with_path_edge_keys<T, W>(
+    mut info: ShortestPathInfo<T, W>,
+    edge_keys: Option<&ParallelEdgeKeys<T>>,
+) -> ShortestPathInfo<T, W>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    if let Some(edge_keys) = edge_keys {
+        edge_keys.set_edge_keys(&mut info);
+    }
+    info
+}
+
+/// Returns a `CsrGraph` of `graph`, after checking that it has weights if `weighted` is `true`.
+fn get_csr<T, A, W>(graph: &Graph<T, A, W>, weighted: bool) -> Result<CsrGraph<T, W>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    Ok(graph.to_csr())
+}
+
+/**
+Returns the adjacency of `graph` that follows the edges in `direction`, where the nodes are
+only given indexes as a search reaches them, after checking that it has weights if
+`weighted` is `true`. Unlike [get_csr](./fn.get_csr.html) this doesn't copy the whole graph,
+so searches that only visit part of the graph don't pay for the rest of it.
+*/
+pub(crate) fn get_lazy_indexed_graph<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    direction: Direction,
+) -> Result<impl IndexedAdjacency<T, W> + '_, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let get_cost = move |u: &T, v: &T| match (weighted, direction, graph.specs.directed) {
+        (false, _, _) => W::one(),
+        (true, Direction::Incoming, true) => graph.get_min_weight(v, u),
+        (true, _, _) => graph.get_min_weight(u, v),
+    };
+    Ok(LazyIndexedGraph::new(graph, direction, get_cost))
+}
+
+/// Returns the cost of an edge: its `weight` if `weighted` is `true`, or 1 otherwise.
+#[inline]
+fn get_cost_or_one<W: Num>(weight: W, weighted: bool) -> W {
+    match weighted {
+        true => weight,
+        false => W::one(),
+    }
+}
+
+fn get_contractory_paths_error() -> Error {
+    Error {
+        kind: ErrorKind::ContradictoryPaths,
+        message: CONTRADICTORY_PATHS_ERROR_MESSAGE.to_string(),
+    }
+}
+
+/**
+Pushes a `FringeNode` into the `fringe` `BinaryHeap`.
+Increments `count`.
+*/
+#[inline]
+pub(crate) fn push_fringe_node<T>(
+    count: &mut i32,
+    fringe: &mut BinaryHeap<FringeNode<T>>,
+    u: T,
+    vu_dist: f64,
+) where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    *count += 1;
+    fringe.push(FringeNode {
+        node_name: u,
+        count: *count,
+        distance: -vu_dist,
+    });
+}
+
+/**
+Pushes a `WeightedFringeNode` into the `fringe` `BinaryHeap`.
+Increments `count`.
+*/
+#[inline]
+pub(crate) fn push_weighted_fringe_node<T, W>(
+    count: &mut i32,
+    fringe: &mut BinaryHeap<WeightedFringeNode<T, W>>,
+    u: T,
+    vu_dist: W,
+) where
+    T: Eq + Ord,
+    W: PartialOrd,
+{
+    *count += 1;
+    fringe.push(WeightedFringeNode {
+        node_name: u,
+        count: *count,
+        distance: vu_dist,
+    });
+}
+
+/// Returns the smaller of two weights.
+#[inline]
+fn min_weight<W: PartialOrd>(a: W, b: W) -> W {
+    match b < a {
+        true => b,
+        false => a,
+    }
+}
+
+/**
+Adds `u` to the paths that lead to `v`, then appends all the paths that
+lead to `v` to the paths that lead to `u`.
+*/
+#[inline]
+fn add_u_to_v_paths_and_append_v_paths_to_u_paths(u: u32, v: u32, paths: &mut [Vec<Vec<u32>>]) {
+    // add u to all paths[v], then *append* them to paths[u]
+    let v_paths: Vec<Vec<u32>> = paths[v as usize]
+        .iter()
+        .map(|p| {
+            let mut x = p.clone();
+            x.push(u);
+            x
+        })
+        .collect();
+    paths[u as usize].extend(v_paths);
+}
+
+/**
+Gets all shortest paths that pass through a given node.
+
+Does not return paths where the node is the source or the target;
+the node must be between two other nodes.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `node_name`: the name of the intermediate node
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs, Node};
+use graphrs::{algorithms::{shortest_path::{dijkstra}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n1", "n4"),
+    Edge::new("n4", "n3"),
+    Edge::new("n2", "n5"),
+]);
+let result = dijkstra::get_all_shortest_paths_involving(&graph, "n2", false);
+assert_eq!(result.len(), 2);
+```
+*/
+pub fn get_all_shortest_paths_involving<T, A, W>(
+    graph: &Graph<T, A, W>,
     node_name: T,
     weighted: bool,
-) -> Vec<ShortestPathInfo<T>>
+) -> Vec<ShortestPathInfo<T, W>>
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
 {
-    let result = all_pairs(graph, weighted, None, false);
+    let result = all_pairs(graph, weighted, None, None, false);
     match result {
         Err(_) => vec![],
         Ok(pairs) => pairs
@@ -438,51 +2350,26 @@ Returns the "cost" of a (`u`, `v`) edges when the `graph` is a multigraph.
 
 Finds lowest weight of the (u, v) edges.
 */
-fn get_cost_multi<T, A>(graph: &Graph<T, A>, u: T, v: T) -> f64
+pub(crate) fn get_cost_multi<T, A, W>(graph: &Graph<T, A, W>, u: T, v: T) -> W
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
 {
     let edges = graph.get_edges(u, v).unwrap();
     let weights = edges.into_iter().map(|e| e.weight);
-    weights.into_iter().reduce(f64::min).unwrap()
+    weights.into_iter().reduce(min_weight).unwrap()
 }
 
 /**
 Returns the weight of the (u, v) edge in a `graph` that is not a multigraph.
 */
-fn get_cost_single<T, A>(graph: &Graph<T, A>, u: T, v: T) -> f64
+pub(crate) fn get_cost_single<T, A, W>(graph: &Graph<T, A, W>, u: T, v: T) -> W
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
 {
     let edge = graph.get_edge(u, v).unwrap();
     edge.weight
 }
-
-/**
-Zips the `distances` and the `paths` together into a `HashMap` where
-the keys are the names of the target nodes and the values are
-`ShortestPathInfo` objects.
-*/
-fn get_shortest_path_infos<T, A>(
-    distances: HashMap<T, f64>,
-    paths: HashMap<T, Vec<Vec<T>>>,
-) -> HashMap<T, ShortestPathInfo<T>>
-where
-    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
-    A: Clone,
-{
-    distances
-        .into_iter()
-        .map(|(k, v)| {
-            (
-                k.clone(),
-                ShortestPathInfo {
-                    distance: v,
-                    paths: paths.get(&k).unwrap().clone(),
-                },
-            )
-        })
-        .collect::<HashMap<T, ShortestPathInfo<T>>>()
-}
@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/**
+Shortest path distances between every pair of nodes in a graph, stored as a dense matrix.
+
+Row and column `i` of `distances` (and `predecessors`) refer to the node `nodes[i]`;
+`node_indexes` maps each node name back to its index.
+*/
+pub struct DistanceMatrix<T> {
+    /// The names of the nodes, in index order.
+    pub nodes: Vec<T>,
+    /// A mapping of node name to index.
+    pub node_indexes: HashMap<T, usize>,
+    /// `distances[i][j]` is the shortest distance from `nodes[i]` to `nodes[j]`;
+    /// `f64::INFINITY` if `nodes[j]` cannot be reached from `nodes[i]`.
+    pub distances: Vec<Vec<f64>>,
+    /// If requested, `predecessors[i][j]` is the index of the node that precedes
+    /// `nodes[j]` on a shortest path from `nodes[i]`.
+    pub predecessors: Option<Vec<Vec<Option<usize>>>>,
+}
+
+impl<T> DistanceMatrix<T>
+where
+    T: Hash + Eq + Clone,
+{
+    /// Returns the shortest distance from `u` to `v`, or `None` if either node
+    /// is not in the matrix.
+    pub fn get_distance(&self, u: &T, v: &T) -> Option<f64> {
+        let i = self.node_indexes.get(u)?;
+        let j = self.node_indexes.get(v)?;
+        Some(self.distances[*i][*j])
+    }
+
+    /// Returns a shortest path from `u` to `v`, or `None` if there is no path or
+    /// the matrix was computed without predecessors.
+    pub fn get_path(&self, u: &T, v: &T) -> Option<Vec<T>> {
+        let predecessors = self.predecessors.as_ref()?;
+        let i = *self.node_indexes.get(u)?;
+        let mut j = *self.node_indexes.get(v)?;
+        if self.distances[i][j].is_infinite() {
+            return None;
+        }
+        let mut path = vec![self.nodes[j].clone()];
+        while j != i {
+            j = predecessors[i][j]?;
+            path.push(self.nodes[j].clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
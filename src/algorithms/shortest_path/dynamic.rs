@@ -0,0 +1,346 @@
+use crate::algorithms::shortest_path::dijkstra::{push_fringe_node, FringeNode};
+use crate::{Error, ErrorKind, Graph};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+A single-source shortest path tree that is kept up to date as the edges of the graph change.
+
+A `DynamicSssp` holds its own copy of the graph's edges. When an edge is added, removed or
+changes weight only the part of the tree that is affected is repaired: a shorter edge
+starts a Dijkstra search from its target that stops where distances don't improve, and a
+longer or removed tree edge recomputes the distances of just the subtree below it. For
+small changes to a large graph this is much faster than running Dijkstra's algorithm from
+scratch.
+
+Edge weights must not be negative. If there are multiple edges between two nodes only the
+lowest weight is kept.
+
+# Examples
+
+```
+use graphrs::{algorithms::shortest_path::dynamic::DynamicSssp, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n3", 1.0),
+    Edge::with_weight("n1", "n3", 3.0),
+]);
+
+let mut sssp = DynamicSssp::new(&graph, true, "n1").unwrap();
+assert_eq!(sssp.get_path("n3"), Some(vec!["n1", "n2", "n3"]));
+sssp.edge_removed("n2", "n3").unwrap();
+assert_eq!(sssp.get_distance("n3"), Some(3.0));
+sssp.edge_added("n2", "n4", 0.5).unwrap();
+sssp.weight_changed("n1", "n3", 1.0).unwrap();
+assert_eq!(sssp.get_distance("n4"), Some(1.5));
+assert_eq!(sssp.get_path("n3"), Some(vec!["n1", "n3"]));
+```
+*/
+pub struct DynamicSssp<T> {
+    source: T,
+    directed: bool,
+    /// The weight of the arc from each node to each of its successors.
+    outgoing: HashMap<T, HashMap<T, f64>>,
+    /// The weight of the arc to each node from each of its predecessors.
+    incoming: HashMap<T, HashMap<T, f64>>,
+    /// The distance from `source` to each node that can be reached.
+    distances: HashMap<T, f64>,
+    /// The parent of each reachable node, other than `source`, in the shortest path tree.
+    parents: HashMap<T, T>,
+    /// The children of each node in the shortest path tree.
+    children: HashMap<T, HashSet<T>>,
+}
+
+impl<T> DynamicSssp<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    /**
+    Computes the shortest path tree of `graph` from `source`.
+
+    # Arguments
+
+    * `graph`: a [Graph](../../../struct.Graph.html) instance
+    * `weighted`: determines if shortest paths are determined with edge weight, or not
+    * `source`: the starting node
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::NodeNotFound` is returned if `source` isn't in
+    the graph, and one with a `kind` of `ErrorKind::InvalidArgument` if an edge has a
+    negative weight.
+    */
+    pub fn new<A>(graph: &Graph<T, A>, weighted: bool, source: T) -> Result<DynamicSssp<T>, Error>
+    where
+        A: Clone,
+    {
+        graph.ensure_node(&source)?;
+        if weighted {
+            graph.ensure_weighted()?;
+        }
+        let mut sssp = DynamicSssp {
+            source: source.clone(),
+            directed: graph.specs.directed,
+            outgoing: HashMap::new(),
+            incoming: HashMap::new(),
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+            children: HashMap::new(),
+        };
+        for node_name in graph.get_all_node_names() {
+            sssp.outgoing.insert(node_name.clone(), HashMap::new());
+            sssp.incoming.insert(node_name.clone(), HashMap::new());
+        }
+        for edge in graph.get_all_edges() {
+            let weight = match weighted {
+                true => edge.weight,
+                false => 1.0,
+            };
+            ensure_non_negative(&edge.u, &edge.v, weight)?;
+            if weight < *sssp.outgoing[&edge.u].get(&edge.v).unwrap_or(&f64::INFINITY) {
+                sssp.set_arcs(edge.u.clone(), edge.v.clone(), weight);
+            }
+        }
+        sssp.distances.insert(source.clone(), 0.0);
+        let mut fringe = BinaryHeap::new();
+        let mut count = 0;
+        push_fringe_node(&mut count, &mut fringe, source, 0.0);
+        sssp.propagate(fringe, count);
+        Ok(sssp)
+    }
+
+    /// Returns the source node of the shortest path tree.
+    pub fn get_source(&self) -> &T {
+        &self.source
+    }
+
+    /// Returns the distance from the source to `node_name`, or `None` if it can't be reached.
+    pub fn get_distance(&self, node_name: T) -> Option<f64> {
+        self.distances.get(&node_name).copied()
+    }
+
+    /// Returns the distance from the source to every node that can be reached.
+    pub fn get_distances(&self) -> &HashMap<T, f64> {
+        &self.distances
+    }
+
+    /// Returns the parent of `node_name` in the shortest path tree, or `None` if it's the
+    /// source or can't be reached.
+    pub fn get_parent(&self, node_name: T) -> Option<&T> {
+        self.parents.get(&node_name)
+    }
+
+    /// Returns a shortest path from the source to `node_name`, or `None` if it can't be
+    /// reached.
+    pub fn get_path(&self, node_name: T) -> Option<Vec<T>> {
+        if !self.distances.contains_key(&node_name) {
+            return None;
+        }
+        let mut path = vec![node_name];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /**
+    Adds an edge from `u` to `v`, adding the nodes if they don't exist, and updates the
+    shortest paths that the edge shortens.
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::DuplicateEdge` is returned if there's already an
+    edge from `u` to `v`, and one with a `kind` of `ErrorKind::InvalidArgument` if `weight`
+    is negative.
+    */
+    pub fn edge_added(&mut self, u: T, v: T, weight: f64) -> Result<(), Error> {
+        ensure_non_negative(&u, &v, weight)?;
+        if self.get_weight(&u, &v).is_some() {
+            return Err(Error {
+                kind: ErrorKind::DuplicateEdge,
+                message: format!("There is already an edge from '{}' to '{}'.", u, v),
+            });
+        }
+        for node_name in [&u, &v] {
+            self.outgoing.entry(node_name.clone()).or_default();
+            self.incoming.entry(node_name.clone()).or_default();
+        }
+        self.set_arcs(u.clone(), v.clone(), weight);
+        self.decrease(u, v, weight);
+        Ok(())
+    }
+
+    /**
+    Removes the edge from `u` to `v` and repairs the shortest paths that used it.
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::EdgeNotFound` is returned if there's no edge
+    from `u` to `v`.
+    */
+    pub fn edge_removed(&mut self, u: T, v: T) -> Result<(), Error> {
+        self.ensure_edge(&u, &v)?;
+        self.outgoing.get_mut(&u).unwrap().remove(&v);
+        self.incoming.get_mut(&v).unwrap().remove(&u);
+        if !self.directed {
+            self.outgoing.get_mut(&v).unwrap().remove(&u);
+            self.incoming.get_mut(&u).unwrap().remove(&v);
+        }
+        self.increase(u, v);
+        Ok(())
+    }
+
+    /**
+    Changes the weight of the edge from `u` to `v` and updates the shortest paths that are
+    affected.
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::EdgeNotFound` is returned if there's no edge
+    from `u` to `v`, and one with a `kind` of `ErrorKind::InvalidArgument` if `weight` is
+    negative.
+    */
+    pub fn weight_changed(&mut self, u: T, v: T, weight: f64) -> Result<(), Error> {
+        let old_weight = self.ensure_edge(&u, &v)?;
+        ensure_non_negative(&u, &v, weight)?;
+        self.set_arcs(u.clone(), v.clone(), weight);
+        match weight < old_weight {
+            true => self.decrease(u, v, weight),
+            false => self.increase(u, v),
+        }
+        Ok(())
+    }
+
+    /// Returns the weight of the edge from `u` to `v`, or an `EdgeNotFound` error.
+    fn ensure_edge(&self, u: &T, v: &T) -> Result<f64, Error> {
+        self.get_weight(u, v).ok_or_else(|| Error {
+            kind: ErrorKind::EdgeNotFound,
+            message: format!("The requested edge ({}, {}) does not exist.", u, v),
+        })
+    }
+
+    fn get_weight(&self, u: &T, v: &T) -> Option<f64> {
+        self.outgoing.get(u).and_then(|successors| successors.get(v)).copied()
+    }
+
+    /// Sets the weight of the arc from `u` to `v`, and from `v` to `u` if undirected.
+    fn set_arcs(&mut self, u: T, v: T, weight: f64) {
+        self.outgoing.get_mut(&u).unwrap().insert(v.clone(), weight);
+        self.incoming.get_mut(&v).unwrap().insert(u.clone(), weight);
+        if !self.directed {
+            self.outgoing.get_mut(&v).unwrap().insert(u.clone(), weight);
+            self.incoming.get_mut(&u).unwrap().insert(v, weight);
+        }
+    }
+
+    /// Updates the tree after the edge from `u` to `v` became shorter or was added.
+    fn decrease(&mut self, u: T, v: T, weight: f64) {
+        let mut fringe = BinaryHeap::new();
+        let mut count = 0;
+        let mut arcs = vec![(u.clone(), v.clone())];
+        if !self.directed {
+            arcs.push((v, u));
+        }
+        for (x, y) in arcs {
+            if let Some(x_dist) = self.distances.get(&x) {
+                let y_dist = x_dist + weight;
+                if y_dist < *self.distances.get(&y).unwrap_or(&f64::INFINITY) {
+                    self.set_distance(y.clone(), y_dist, x);
+                    push_fringe_node(&mut count, &mut fringe, y, y_dist);
+                }
+            }
+        }
+        self.propagate(fringe, count);
+    }
+
+    /// Updates the tree after the edge from `u` to `v` became longer or was removed.
+    /// Only the subtree below the edge, if it's a tree edge, is affected.
+    fn increase(&mut self, u: T, v: T) {
+        let root = match (self.parents.get(&v), self.parents.get(&u)) {
+            (Some(parent), _) if *parent == u => v,
+            (_, Some(parent)) if !self.directed && *parent == v => u,
+            _ => return,
+        };
+        let mut subtree = vec![root];
+        let mut i = 0;
+        while i < subtree.len() {
+            let children = self.children.remove(&subtree[i]).unwrap_or_default();
+            subtree.extend(children);
+            i += 1;
+        }
+        for node_name in subtree.iter() {
+            self.distances.remove(node_name);
+            if let Some(parent) = self.parents.remove(node_name) {
+                if let Some(siblings) = self.children.get_mut(&parent) {
+                    siblings.remove(node_name);
+                }
+            }
+        }
+        // the distances outside the subtree don't change, so the subtree is reconnected
+        // through its best arc from outside the subtree
+        let mut fringe = BinaryHeap::new();
+        let mut count = 0;
+        for node_name in subtree {
+            let best = self.incoming[&node_name]
+                .iter()
+                .filter_map(|(x, w)| self.distances.get(x).map(|d| (d + w, x)))
+                .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap().then_with(|| a.1.cmp(b.1)))
+                .map(|(d, x)| (d, x.clone()));
+            if let Some((distance, parent)) = best {
+                self.set_distance(node_name.clone(), distance, parent);
+                push_fringe_node(&mut count, &mut fringe, node_name, distance);
+            }
+        }
+        self.propagate(fringe, count);
+    }
+
+    /// Runs Dijkstra's algorithm from the nodes in `fringe`, whose distances have been set,
+    /// updating the nodes whose distances improve.
+    fn propagate(&mut self, mut fringe: BinaryHeap<FringeNode<T>>, mut count: i32) {
+        while let Some(fringe_item) = fringe.pop() {
+            let distance = -fringe_item.distance;
+            let u = fringe_item.node_name;
+            if distance > self.distances[&u] {
+                continue;
+            }
+            let improved: Vec<(T, f64)> = self.outgoing[&u]
+                .iter()
+                .map(|(v, w)| (v, distance + w))
+                .filter(|(v, d)| *d < *self.distances.get(*v).unwrap_or(&f64::INFINITY))
+                .map(|(v, d)| (v.clone(), d))
+                .collect();
+            for (v, v_dist) in improved {
+                self.set_distance(v.clone(), v_dist, u.clone());
+                push_fringe_node(&mut count, &mut fringe, v, v_dist);
+            }
+        }
+    }
+
+    /// Sets the distance of `node_name` and makes `parent` its parent in the tree.
+    fn set_distance(&mut self, node_name: T, distance: f64, parent: T) {
+        if let Some(old_parent) = self.parents.get(&node_name) {
+            self.children.get_mut(old_parent).unwrap().remove(&node_name);
+        }
+        self.children.entry(parent.clone()).or_default().insert(node_name.clone());
+        self.parents.insert(node_name.clone(), parent);
+        self.distances.insert(node_name, distance);
+    }
+}
+
+fn ensure_non_negative<T: Display>(u: &T, v: &T, weight: f64) -> Result<(), Error> {
+    if weight < 0.0 || weight.is_nan() {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!(
+                "The ({}, {}) edge has a negative or NaN weight; dynamic shortest paths \
+                require non-negative edge weights.",
+                u, v
+            ),
+        });
+    }
+    Ok(())
+}
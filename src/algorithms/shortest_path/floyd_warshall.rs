@@ -0,0 +1,207 @@
+use crate::algorithms::shortest_path::DistanceMatrix;
+use crate::{Error, ErrorKind, Graph, ParallelOptions};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Uses the Floyd-Warshall algorithm to find the shortest path distances between
+all pairs of nodes.
+
+The result is a dense [DistanceMatrix](../struct.DistanceMatrix.html) rather than
+nested `HashMap`s, which makes this a good choice for dense graphs. The running time
+is O(V^3) regardless of the number of edges. Negative edge weights are supported.
+
+Only graphs with `f64` weights are supported, because the `DistanceMatrix` records
+unreachable pairs of nodes as `f64::INFINITY`.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `with_predecessors`: if `true` the predecessor matrix is also computed, which
+  allows paths to be found with `DistanceMatrix::get_path`.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{floyd_warshall}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n1", 2.0),
+    Edge::with_weight("n1", "n3", 3.0),
+    Edge::with_weight("n2", "n3", 1.1),
+]);
+
+let matrix = floyd_warshall::all_pairs(&graph, true, true).unwrap();
+assert_eq!(matrix.get_distance(&"n1", &"n3"), Some(2.1));
+assert_eq!(matrix.get_path(&"n1", &"n3"), Some(vec!["n1", "n2", "n3"]));
+assert_eq!(matrix.get_distance(&"n3", &"n1"), Some(f64::INFINITY));
+```
+
+# References
+
+1. Robert W. Floyd. Algorithm 97: Shortest Path. Communications of the ACM, 5(6):345, 1962.
+*/
+pub fn all_pairs<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    with_predecessors: bool,
+) -> Result<DistanceMatrix<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    all_pairs_with_options(
+        graph,
+        weighted,
+        with_predecessors,
+        &ParallelOptions::default(),
+    )
+}
+
+/**
+Uses the Floyd-Warshall algorithm to find the shortest path distances between
+all pairs of nodes, like [all_pairs](./fn.all_pairs.html), with the threads and
+[ProgressHandle](../../../struct.ProgressHandle.html) given in `options`.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `with_predecessors`: if `true` the predecessor matrix is also computed, which
+  allows paths to be found with `DistanceMatrix::get_path`.
+* `options`: a [ParallelOptions](../../../struct.ParallelOptions.html); each node
+  that paths have been relaxed through is a unit of work for its `progress`
+
+# Returns
+
+The same as [all_pairs](./fn.all_pairs.html). An `Error` with a `kind` of
+`ErrorKind::Cancelled` is returned if `options.progress` was cancelled, and one with a
+`kind` of `ErrorKind::InvalidArgument` if a thread pool couldn't be built for
+`options.parallelism`.
+
+# Examples
+
+```
+use graphrs::{algorithms::shortest_path::floyd_warshall, generators, Parallelism, ParallelOptions};
+
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::Sequential,
+    ..ParallelOptions::default()
+};
+let matrix = floyd_warshall::all_pairs_with_options(&graph, false, false, &options).unwrap();
+assert_eq!(matrix.get_distance(&0, &33), Some(2.0));
+```
+*/
+pub fn all_pairs_with_options<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    with_predecessors: bool,
+    options: &ParallelOptions,
+) -> Result<DistanceMatrix<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+
+    let mut nodes = graph.get_all_node_names().into_iter().cloned().collect::<Vec<T>>();
+    nodes.sort();
+    let node_indexes: HashMap<T, usize> =
+        nodes.iter().enumerate().map(|(i, n)| (n.clone(), i)).collect();
+    let n = nodes.len();
+
+    let mut distances = vec![vec![f64::INFINITY; n]; n];
+    let mut predecessors = vec![vec![None; n]; n];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[i] = 0.0;
+    }
+    for edge in graph.get_all_edges() {
+        let u = *node_indexes.get(&edge.u).unwrap();
+        let v = *node_indexes.get(&edge.v).unwrap();
+        let weight = match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+        let arcs = match graph.specs.directed {
+            true => vec![(u, v)],
+            false => vec![(u, v), (v, u)],
+        };
+        for (a, b) in arcs {
+            if weight < distances[a][b] {
+                distances[a][b] = weight;
+                predecessors[a][b] = Some(a);
+            }
+        }
+    }
+
+    let progress = &options.progress;
+    progress.start(Some(n))?;
+    options.install(|parallel| {
+        for k in 0..n {
+            progress.check()?;
+            relax_through(&mut distances, &mut predecessors, k, parallel);
+            progress.advance();
+        }
+        Ok::<(), Error>(())
+    })??;
+
+    let negative_nodes: Vec<String> =
+        (0..n).filter(|i| distances[*i][*i] < 0.0).map(|i| nodes[i].to_string()).collect();
+    if !negative_nodes.is_empty() {
+        return Err(Error {
+            kind: ErrorKind::NegativeCycle,
+            message: format!(
+                "A negative cycle was found involving the nodes: [{}].",
+                negative_nodes.join(", ")
+            ),
+        });
+    }
+
+    Ok(DistanceMatrix {
+        nodes,
+        node_indexes,
+        distances,
+        predecessors: match with_predecessors {
+            true => Some(predecessors),
+            false => None,
+        },
+    })
+}
+
+/// Shortens the paths between each pair of nodes that are shorter when they go through
+/// node `k`, a row at a time, in parallel if `parallel` is `true`.
+fn relax_through(
+    distances: &mut [Vec<f64>],
+    predecessors: &mut [Vec<Option<usize>>],
+    k: usize,
+    parallel: bool,
+) {
+    let k_row = distances[k].clone();
+    let k_preds = predecessors[k].clone();
+    let relax_row = |(i_row, i_preds): (&mut Vec<f64>, &mut Vec<Option<usize>>)| {
+        let ik = i_row[k];
+        if ik.is_infinite() {
+            return;
+        }
+        for j in 0..k_row.len() {
+            let ikj = ik + k_row[j];
+            if ikj < i_row[j] {
+                i_row[j] = ikj;
+                i_preds[j] = k_preds[j];
+            }
+        }
+    };
+    match parallel {
+        true => distances.par_iter_mut().zip(predecessors.par_iter_mut()).for_each(relax_row),
+        false => distances.iter_mut().zip(predecessors.iter_mut()).for_each(relax_row),
+    }
+}
@@ -0,0 +1,208 @@
+use crate::algorithms::shortest_path::bellman_ford::bellman_ford_multisource;
+use crate::algorithms::shortest_path::dijkstra::collect_source_results;
+use crate::algorithms::shortest_path::shortest_path_info::ParallelEdgeKeys;
+use crate::algorithms::shortest_path::{dijkstra, ShortestPathInfo, ShortestPathPredecessors};
+use crate::{Edge, Error, Graph, Node, ParallelOptions};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Uses Johnson's algorithm to find shortest weighted paths between all pairs of nodes
+in a graph that may have negative edge weights.
+
+A single Bellman-Ford pass computes a potential for every node, which is used to
+reweight the edges so that none are negative. Dijkstra's algorithm is then run
+(in parallel) from every node, and the distances are mapped back to the original weights.
+This is typically faster than [bellman_ford::all_pairs](../bellman_ford/fn.all_pairs.html)
+on sparse graphs. If no edge has a negative weight this is the same as
+[dijkstra::all_pairs](../dijkstra/fn.all_pairs.html).
+
+Rounding errors in the reweighted edges can make the reweighted lengths of paths that tie
+in the original weights differ slightly, so when all shortest paths are wanted an edge is
+taken to be on a shortest path if it joins the distances of its nodes to within a small
+relative tolerance.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `first_only`: If `true` returns the first shortest path found for each source and target,
+  if `false` returns all shortest paths found between sources and targets.
+
+# Returns
+
+A `HashMap` of `HashMaps`. The keys to the first one are the starting nodes
+and the keys to the second are the target nodes. If the graph contains a negative
+cycle an `Error` with a `kind` of `ErrorKind::NegativeCycle` is returned.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{johnson}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 4.0),
+    Edge::with_weight("n1", "n3", 1.0),
+    Edge::with_weight("n2", "n3", -5.0),
+]);
+
+let all_pairs = johnson::all_pairs(&graph, true, false).unwrap();
+let info = all_pairs.get("n1").unwrap().get("n3").unwrap();
+assert_eq!(info.distance, -1.0);
+assert_eq!(info.paths, vec![vec!["n1", "n2", "n3"]]);
+```
+
+# References
+
+1. Donald B. Johnson. Efficient algorithms for shortest paths in sparse networks.
+   Journal of the ACM, 24(1):1–13, 1977.
+*/
+pub fn all_pairs<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    first_only: bool,
+) -> Result<HashMap<T, HashMap<T, ShortestPathInfo<T>>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if !weighted || graph.get_all_edges().iter().all(|edge| edge.weight >= 0.0) {
+        return dijkstra::all_pairs(graph, weighted, None, None, first_only);
+    }
+
+    // starting from every node at distance zero is equivalent to adding a new node
+    // with a zero-weight edge to every other node
+    let all_nodes = graph.get_all_node_names().into_iter().cloned().collect();
+    let (potentials, _) = bellman_ford_multisource(graph, true, all_nodes)?;
+
+    let nodes: Vec<Node<T, A>> = graph.get_all_nodes().into_iter().cloned().collect();
+    let edges: Vec<Edge<T, A>> = graph
+        .get_all_edges()
+        .into_iter()
+        .map(|edge| {
+            let weight =
+                edge.weight + potentials.get(&edge.u).unwrap() - potentials.get(&edge.v).unwrap();
+            // guard against tiny negative weights caused by floating point rounding
+            Edge {
+                weight: weight.max(0.0),
+                ..edge.clone()
+            }
+        })
+        .collect();
+    let reweighted = Graph::new_from_nodes_and_edges(nodes, edges, graph.specs.clone())?;
+
+    let mut names: Vec<T> = graph.get_all_node_names().into_iter().cloned().collect();
+    names.sort();
+    let edge_keys = ParallelEdgeKeys::new(graph, true);
+    let results = ParallelOptions::default().map_collect(names.len(), |index| {
+        let source = &names[index];
+        let search = dijkstra::single_source_predecessors(
+            &reweighted,
+            true,
+            source.clone(),
+            None,
+            None,
+            first_only,
+        )?;
+        let search = restore_weights(graph, &potentials, source, search, first_only);
+        let mut infos: HashMap<T, ShortestPathInfo<T>> = search
+            .distances
+            .keys()
+            .map(|v| (v.clone(), search.get_shortest_path_info(v).unwrap()))
+            .collect();
+        if let Some(edge_keys) = &edge_keys {
+            edge_keys.set_all_edge_keys(&mut infos);
+        }
+        Ok(infos)
+    })?;
+    collect_source_results(&names, results)
+}
+
+/// The relative tolerance within which reweighted path lengths are taken to be equal.
+const TOLERANCE: f64 = 1.0e-9;
+
+/**
+Maps the distances of a search of the reweighted graph from `source` back to the original
+weights. Unless `first_only` is `true`, every edge that joins the distances of its nodes,
+to within the `TOLERANCE`, is added to the predecessors, so that paths whose reweighted
+lengths differ only by rounding errors are all kept.
+*/
+fn restore_weights<T, A>(
+    graph: &Graph<T, A>,
+    potentials: &HashMap<T, f64>,
+    source: &T,
+    search: ShortestPathPredecessors<T>,
+    first_only: bool,
+) -> ShortestPathPredecessors<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let source_potential = potentials.get(source).unwrap();
+    let distances: HashMap<T, f64> = search
+        .distances
+        .into_iter()
+        .map(|(v, distance)| {
+            let distance = distance - source_potential + potentials.get(&v).unwrap();
+            (v, distance)
+        })
+        .collect();
+    let mut predecessors = search.predecessors;
+    if !first_only {
+        for edge in graph.get_all_edges() {
+            add_if_tight(
+                &distances,
+                &mut predecessors,
+                source,
+                &edge.u,
+                &edge.v,
+                edge.weight,
+            );
+            if !graph.specs.directed {
+                add_if_tight(
+                    &distances,
+                    &mut predecessors,
+                    source,
+                    &edge.v,
+                    &edge.u,
+                    edge.weight,
+                );
+            }
+        }
+    }
+    ShortestPathPredecessors {
+        distances,
+        predecessors,
+    }
+}
+
+/// Adds `u` to the predecessors of `v` if the edge from `u` to `v` is on a shortest path.
+fn add_if_tight<T>(
+    distances: &HashMap<T, f64>,
+    predecessors: &mut HashMap<T, Vec<T>>,
+    source: &T,
+    u: &T,
+    v: &T,
+    weight: f64,
+) where
+    T: Hash + Eq + Clone,
+{
+    if u == v || v == source {
+        return;
+    }
+    let (u_distance, v_distance) = match (distances.get(u), distances.get(v)) {
+        (Some(u_distance), Some(v_distance)) => (*u_distance, *v_distance),
+        _ => return,
+    };
+    let scale = 1.0 + u_distance.abs().max(v_distance.abs()).max(weight.abs());
+    if (u_distance + weight - v_distance).abs() > TOLERANCE * scale {
+        return;
+    }
+    let v_predecessors = predecessors.get_mut(v).unwrap();
+    if !v_predecessors.contains(u) {
+        v_predecessors.push(u.clone());
+    }
+}
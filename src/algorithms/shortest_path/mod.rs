@@ -1,6 +1,54 @@
+/// Compute the shortest path between two nodes in the graph,
+/// using the A* ("A-star") algorithm.
+pub mod astar;
+
+/// Compute the shortest paths and path lengths between nodes in the graph,
+/// using the Bellman-Ford algorithm, which supports negative edge weights.
+pub mod bellman_ford;
+
+/// Preprocess a graph into a contraction hierarchy, to quickly answer repeated
+/// shortest path queries between two nodes.
+pub mod contraction_hierarchy;
+
+/// Compute the shortest path lengths from a node in the graph, using the
+/// delta-stepping algorithm, which relaxes edges in parallel.
+pub mod delta_stepping;
+
 /// Compute the shortest paths and path lengths between nodes in the graph,
 /// using Dijkstra's algorithm.
 pub mod dijkstra;
 
+/// Maintain the shortest paths from a node as edges are added, removed or change weight,
+/// repairing only the affected part of the shortest path tree.
+pub mod dynamic;
+
+/// Compute the shortest path lengths between all pairs of nodes in the graph,
+/// using the Floyd-Warshall algorithm.
+pub mod floyd_warshall;
+
+/// Compute the shortest paths and path lengths between all pairs of nodes in the graph,
+/// using Johnson's algorithm, which supports negative edge weights.
+pub mod johnson;
+
+/// Compute the earliest arrival times from a node in a temporal graph, following only
+/// time-respecting paths.
+pub mod temporal;
+
+/// Compute the k shortest loopless paths between two nodes in the graph,
+/// using Yen's algorithm.
+pub mod yen;
+
+mod distance_matrix;
+pub use distance_matrix::DistanceMatrix;
+
+mod reachability;
+pub use reachability::{has_path, reachable_from};
+
 mod shortest_path_info;
 pub use shortest_path_info::ShortestPathInfo;
+
+mod shortest_path_predecessors;
+pub use shortest_path_predecessors::{ShortestPathPredecessors, ShortestPathsIter};
+
+mod waypoints;
+pub use waypoints::via_waypoints;
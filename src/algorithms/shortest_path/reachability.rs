@@ -0,0 +1,122 @@
+use crate::{Error, Graph};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns `true` if there is a path from `source` to `target` in the graph. The search
+follows the edges without looking at their weights and stops as soon as `target` is found,
+so it is much cheaper than computing a shortest path.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `source`: the node to start the path from
+* `target`: the node to find a path to
+
+# Returns
+
+An `Error` with a `kind` of `ErrorKind::NodeNotFound` is returned if `source` or `target`
+isn't in the graph. A node always has a path to itself.
+
+# Examples
+
+```
+use graphrs::{algorithms::shortest_path, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n4", "n3"),
+]);
+assert!(shortest_path::has_path(&graph, "n1", "n3").unwrap());
+assert!(!shortest_path::has_path(&graph, "n1", "n4").unwrap());
+assert!(!shortest_path::has_path(&graph, "n3", "n1").unwrap());
+```
+*/
+pub fn has_path<T, A, W>(graph: &Graph<T, A, W>, source: T, target: T) -> Result<bool, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Copy + PartialOrd,
+{
+    graph.ensure_node(&target)?;
+    let (_reached, found) = search(graph, source, Some(&target))?;
+    Ok(found)
+}
+
+/**
+Returns the nodes that can be reached from `source` by following the edges of the graph,
+including `source` itself. For an undirected graph this is the connected component that
+contains `source`.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `source`: the node to start the search from
+
+# Returns
+
+An `Error` with a `kind` of `ErrorKind::NodeNotFound` is returned if `source` isn't in the
+graph.
+
+# Examples
+
+```
+use graphrs::{algorithms::shortest_path, Edge, Graph, GraphSpecs};
+use std::collections::HashSet;
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n4", "n3"),
+]);
+let reachable = shortest_path::reachable_from(&graph, "n2").unwrap();
+assert_eq!(reachable, HashSet::from(["n2", "n3"]));
+```
+*/
+pub fn reachable_from<T, A, W>(graph: &Graph<T, A, W>, source: T) -> Result<HashSet<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Copy + PartialOrd,
+{
+    let (reached, _found) = search(graph, source, None)?;
+    Ok(reached)
+}
+
+/**
+Does a depth-first search from `source`, returning the nodes that were reached and
+whether `target` was one of them. The search stops as soon as `target` is reached.
+*/
+fn search<T, A, W>(
+    graph: &Graph<T, A, W>,
+    source: T,
+    target: Option<&T>,
+) -> Result<(HashSet<T>, bool), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Copy + PartialOrd,
+{
+    graph.ensure_node(&source)?;
+    if target == Some(&source) {
+        return Ok((vec![source].into_iter().collect(), true));
+    }
+    let mut reached: HashSet<T> = vec![source.clone()].into_iter().collect();
+    let mut stack = vec![source];
+    while let Some(node) = stack.pop() {
+        for neighbor in graph.successors_or_neighbors_iter(node)? {
+            if Some(neighbor) == target {
+                reached.insert(neighbor.clone());
+                return Ok((reached, true));
+            }
+            if reached.insert(neighbor.clone()) {
+                stack.push(neighbor.clone());
+            }
+        }
+    }
+    Ok((reached, false))
+}
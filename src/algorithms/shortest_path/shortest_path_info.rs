@@ -1,16 +1,113 @@
+use crate::{Edge, Graph, IntoOrdered};
+use itertools::Itertools;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
 /**
 Information about the weighted shortest path between two nodes.
+
+A `ShortestPathInfo` can be iterated over, by value or by reference, to get its paths.
+It is marked `#[non_exhaustive]`, so outside this crate one is created with
+[ShortestPathInfo::new](#method.new) rather than a struct literal.
+
+# Examples
+
+```
+use graphrs::{algorithms::shortest_path::dijkstra, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n1", "n3", 1.0),
+    Edge::with_weight("n2", "n4", 1.0),
+    Edge::with_weight("n3", "n4", 1.0),
+]);
+let mut infos = dijkstra::single_source(&graph, true, "n1", Some("n4"), None, None, false).unwrap();
+let info = infos.remove("n4").unwrap();
+assert_eq!(info.num_paths(), 2);
+assert_eq!(info.path_length(), Some(2));
+assert!(info.contains_node("n3"));
+for path in &info {
+    assert_eq!(path.first(), Some(&"n1"));
+}
+```
 */
-pub struct ShortestPathInfo<T> {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub struct ShortestPathInfo<T, W = f64> {
     /// The distance (sum-of-weights) between two nodes.
-    pub distance: f64,
+    pub distance: W,
     /// The paths between two nodes. If there are more than one they
     /// will be of equal length. In each path the first item is the starting node
     /// and the last item is the target node.
     pub paths: Vec<Vec<T>>,
+    /// For a multigraph, which of the parallel edges each path uses: `edge_keys[i][j]` is
+    /// the key, as used by [Graph::get_edge_by_key](../../struct.Graph.html#method.get_edge_by_key),
+    /// of the edge between `paths[i][j]` and `paths[i][j + 1]`. Unless edges were added
+    /// with keys or removed by key, this is the edge's index in the `Vec` returned by
+    /// [Graph::get_edges](../../struct.Graph.html#method.get_edges). When the paths are
+    /// weighted this is the first of the edges with the smallest weight, otherwise the first
+    /// edge. `None` if the graph isn't a multigraph or the algorithm doesn't record the edges.
+    pub edge_keys: Option<Vec<Vec<usize>>>,
 }
 
-impl<T> ShortestPathInfo<T> {
+impl<T, W> ShortestPathInfo<T, W> {
+    /**
+    Creates a `ShortestPathInfo` with the given `distance` and `paths`, and no `edge_keys`.
+
+    # Examples
+
+    ```
+    use graphrs::algorithms::shortest_path::ShortestPathInfo;
+    let info: ShortestPathInfo<&str> = ShortestPathInfo::new(2.0, vec![vec!["n1", "n2", "n3"]]);
+    assert_eq!(info.path_length(), Some(2));
+    assert!(info.edge_keys.is_none());
+    ```
+    */
+    pub fn new(distance: W, paths: Vec<Vec<T>>) -> ShortestPathInfo<T, W> {
+        ShortestPathInfo {
+            distance,
+            paths,
+            edge_keys: None,
+        }
+    }
+
+    /// Returns the first of the shortest paths, or `None` if there are no paths.
+    pub fn first_path(&self) -> Option<&Vec<T>> {
+        self.paths.first()
+    }
+
+    /// Returns an iterator over the shortest paths.
+    pub fn paths_iter(&self) -> std::slice::Iter<'_, Vec<T>> {
+        self.paths.iter()
+    }
+
+    /// Returns the number of shortest paths.
+    pub fn num_paths(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Returns the number of edges in the first of the shortest paths, or `None` if there
+    /// are no paths. Paths with the same distance can have different numbers of edges
+    /// when the edges are weighted.
+    pub fn path_length(&self) -> Option<usize> {
+        self.first_path().map(|path| path.len().saturating_sub(1))
+    }
+
+    /// Returns `true` if any of the shortest paths contains `node_name`, including as
+    /// its first or last node.
+    pub fn contains_node(&self, node_name: T) -> bool
+    where
+        T: Eq,
+    {
+        self.paths.iter().any(|path| path.contains(&node_name))
+    }
+
+    /// Returns `true` if any of the shortest paths passes through `node_name`, which
+    /// must not be its first or last node.
     pub fn contains_path_through_node(&self, node_name: T) -> bool
     where
         T: Eq + Clone,
@@ -26,3 +123,136 @@ impl<T> ShortestPathInfo<T> {
         false
     }
 }
+
+/**
+The key of the parallel edge that a shortest path algorithm uses between each pair of
+nodes in a multigraph, for filling in [ShortestPathInfo::edge_keys](./struct.ShortestPathInfo.html#structfield.edge_keys).
+Only the pairs whose key isn't 0 are stored.
+*/
+pub(crate) struct ParallelEdgeKeys<T> {
+    keys: HashMap<(T, T), usize>,
+    directed: bool,
+}
+
+impl<T> ParallelEdgeKeys<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    /// Returns the edge keys of `graph`, or `None` if it isn't a multigraph.
+    pub(crate) fn new<A, W>(graph: &Graph<T, A, W>, weighted: bool) -> Option<ParallelEdgeKeys<T>>
+    where
+        A: Clone,
+        W: Copy + PartialOrd,
+    {
+        match weighted {
+            true => ParallelEdgeKeys::with_costs(graph, |edge| edge.weight),
+            // every edge costs the same, so the first one is used
+            false => ParallelEdgeKeys::with_costs(graph, |_edge| 0),
+        }
+    }
+
+    /// Returns the edge keys of `graph` when the cost of each edge is `cost(edge)`, or
+    /// `None` if it isn't a multigraph.
+    pub(crate) fn with_costs<A, W, C, F>(
+        graph: &Graph<T, A, W>,
+        cost: F,
+    ) -> Option<ParallelEdgeKeys<T>>
+    where
+        A: Clone,
+        W: Copy + PartialOrd,
+        C: PartialOrd,
+        F: Fn(&Edge<T, A, W>) -> C,
+    {
+        if !graph.specs.multi_edges {
+            return None;
+        }
+        let directed = graph.specs.directed;
+        let mut keys = HashMap::new();
+        for (u, successors) in graph.get_successors_map() {
+            for v in successors.iter().filter(|v| directed || u <= *v) {
+                let costs: Vec<C> =
+                    graph.get_edges(u.clone(), v.clone()).unwrap().into_iter().map(&cost).collect();
+                let index = (1..costs.len()).fold(0, |min, i| match costs[i] < costs[min] {
+                    true => i,
+                    false => min,
+                });
+                let key = graph.get_edge_keys(u.clone(), v.clone()).unwrap()[index];
+                if key > 0 {
+                    keys.insert((u.clone(), v.clone()), key);
+                }
+            }
+        }
+        Some(ParallelEdgeKeys { keys, directed })
+    }
+
+    /// Sets the `edge_keys` of `info` for its paths.
+    pub(crate) fn set_edge_keys<W>(&self, info: &mut ShortestPathInfo<T, W>) {
+        let edge_keys = info
+            .paths
+            .iter()
+            .map(|path| path.windows(2).map(|pair| self.get_key(&pair[0], &pair[1])).collect())
+            .collect();
+        info.edge_keys = Some(edge_keys);
+    }
+
+    /// Sets the `edge_keys` of all the `infos`.
+    pub(crate) fn set_all_edge_keys<W>(&self, infos: &mut HashMap<T, ShortestPathInfo<T, W>>) {
+        for info in infos.values_mut() {
+            self.set_edge_keys(info);
+        }
+    }
+
+    fn get_key(&self, u: &T, v: &T) -> usize {
+        let key = match !self.directed && u > v {
+            false => (u.clone(), v.clone()),
+            true => (v.clone(), u.clone()),
+        };
+        self.keys.get(&key).copied().unwrap_or(0)
+    }
+}
+
+impl<T, W> IntoIterator for ShortestPathInfo<T, W> {
+    type Item = Vec<T>;
+    type IntoIter = std::vec::IntoIter<Vec<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.paths.into_iter()
+    }
+}
+
+impl<'a, T, W> IntoIterator for &'a ShortestPathInfo<T, W> {
+    type Item = &'a Vec<T>;
+    type IntoIter = std::slice::Iter<'a, Vec<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.paths.iter()
+    }
+}
+
+impl<T, W> IntoOrdered for ShortestPathInfo<T, W>
+where
+    T: Ord,
+{
+    type Ordered = ShortestPathInfo<T, W>;
+
+    /// Sorts the paths, keeping each path's edge keys with it.
+    fn into_ordered(self) -> Self::Ordered {
+        let ShortestPathInfo {
+            distance,
+            paths,
+            edge_keys,
+        } = self;
+        let (paths, edge_keys) = match edge_keys {
+            None => (paths.into_iter().sorted().collect(), None),
+            Some(edge_keys) => {
+                let (paths, edge_keys) = paths.into_iter().zip(edge_keys).sorted().unzip();
+                (paths, Some(edge_keys))
+            }
+        };
+        ShortestPathInfo {
+            distance,
+            paths,
+            edge_keys,
+        }
+    }
+}
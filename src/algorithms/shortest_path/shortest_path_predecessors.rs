@@ -0,0 +1,107 @@
+use crate::algorithms::shortest_path::ShortestPathInfo;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/**
+The result of a shortest path search that records, for every reached node, its distance
+from the source(s) and the nodes that precede it on a shortest path.
+
+Storing predecessors rather than every path keeps memory use linear in the size of the
+graph, even when there are a very large number of equal-cost shortest paths. Paths are
+reconstructed on demand with `get_paths` or `iter_paths`.
+*/
+pub struct ShortestPathPredecessors<T, W = f64> {
+    /// The distance (sum-of-weights) from the source(s) to each reached node.
+    pub distances: HashMap<T, W>,
+    /// The predecessors of each reached node on its shortest paths.
+    /// The source nodes have no predecessors.
+    pub predecessors: HashMap<T, Vec<T>>,
+}
+
+impl<T, W> ShortestPathPredecessors<T, W>
+where
+    T: Hash + Eq + Clone,
+    W: Copy,
+{
+    /// Returns the distance to `target`, or `None` if it was not reached.
+    pub fn get_distance(&self, target: &T) -> Option<W> {
+        self.distances.get(target).copied()
+    }
+
+    /// Returns all the shortest paths to `target`; empty if `target` was not reached.
+    pub fn get_paths(&self, target: &T) -> Vec<Vec<T>> {
+        self.iter_paths(target).collect()
+    }
+
+    /// Returns a single shortest path to `target`, or `None` if it was not reached.
+    pub fn get_first_path(&self, target: &T) -> Option<Vec<T>> {
+        self.iter_paths(target).next()
+    }
+
+    /// Returns a `ShortestPathInfo` with the distance to, and all shortest paths to,
+    /// `target`, or `None` if it was not reached.
+    pub fn get_shortest_path_info(&self, target: &T) -> Option<ShortestPathInfo<T, W>> {
+        Some(ShortestPathInfo {
+            distance: self.get_distance(target)?,
+            paths: self.get_paths(target),
+            edge_keys: None,
+        })
+    }
+
+    /// Returns an iterator that lazily reconstructs the shortest paths to `target`,
+    /// one at a time.
+    pub fn iter_paths(&self, target: &T) -> ShortestPathsIter<'_, T> {
+        let stack = match self.predecessors.contains_key(target) {
+            true => vec![(target.clone(), 0)],
+            false => vec![],
+        };
+        ShortestPathsIter {
+            predecessors: &self.predecessors,
+            on_stack: stack.iter().map(|(n, _)| n.clone()).collect(),
+            stack,
+        }
+    }
+}
+
+/**
+An iterator over the shortest paths to a node, created by
+`ShortestPathPredecessors::iter_paths`. Paths are found with a depth-first walk
+back along the predecessors, so only one path is held in memory at a time.
+*/
+pub struct ShortestPathsIter<'a, T> {
+    predecessors: &'a HashMap<T, Vec<T>>,
+    stack: Vec<(T, usize)>,
+    on_stack: HashSet<T>,
+}
+
+impl<'a, T> Iterator for ShortestPathsIter<'a, T>
+where
+    T: Hash + Eq + Clone,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, index)) = self.stack.last().cloned() {
+            let preds = self.predecessors.get(&node).unwrap();
+            if preds.is_empty() {
+                // reached a source
+                let path = self.stack.iter().rev().map(|(n, _)| n.clone()).collect();
+                self.on_stack.remove(&node);
+                self.stack.pop();
+                return Some(path);
+            }
+            if index < preds.len() {
+                self.stack.last_mut().unwrap().1 += 1;
+                let pred = preds[index].clone();
+                // zero-weight cycles can make the predecessors cyclic
+                if self.on_stack.insert(pred.clone()) {
+                    self.stack.push((pred, 0));
+                }
+            } else {
+                self.on_stack.remove(&node);
+                self.stack.pop();
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,92 @@
+use crate::algorithms::shortest_path::dijkstra::{push_fringe_node, FringeNode};
+use crate::{Error, ErrorKind, TemporalGraph};
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Finds the earliest time at which each node of a [TemporalGraph](../../../struct.TemporalGraph.html)
+can be reached from `source`, leaving `source` no earlier than `departure_time`.
+
+Only time-respecting paths are followed: an edge can be traversed if its `time` is no
+earlier than the time at which its starting node was reached, so the edges of a path are
+traversed in non-decreasing time order. Waiting at a node is allowed. Edges with the same
+time can be chained when their `duration` is zero.
+
+# Arguments
+
+* `graph`: a [TemporalGraph](../../../struct.TemporalGraph.html) instance
+* `source`: the node to start from
+* `departure_time`: the earliest time at which `source` can be left
+
+# Returns
+
+A `HashMap` of the earliest arrival time at each node that can be reached from `source`;
+the arrival time at `source` is `departure_time`. Nodes that can't be reached aren't in
+the `HashMap`. An `Error` with a `kind` of `ErrorKind::NodeNotFound` is returned if
+`source` isn't in the graph.
+
+# Examples
+
+```
+use graphrs::{algorithms::shortest_path::temporal, TemporalEdge, TemporalGraph};
+
+let mut graph: TemporalGraph<&str> = TemporalGraph::new(true);
+graph.add_edges(vec![
+    TemporalEdge::new("a", "b", 1.0),
+    TemporalEdge::new("b", "c", 2.0),
+    TemporalEdge::new("c", "d", 1.5),
+    TemporalEdge::with_duration("a", "d", 3.0, 1.0),
+]).unwrap();
+let arrivals = temporal::earliest_arrival(&graph, "a", 0.0).unwrap();
+assert_eq!(arrivals.get("c"), Some(&2.0));
+// the "c" to "d" edge leaves before "c" is reached
+assert_eq!(arrivals.get("d"), Some(&4.0));
+let arrivals = temporal::earliest_arrival(&graph, "a", 2.0).unwrap();
+assert_eq!(arrivals.get("b"), None);
+```
+*/
+pub fn earliest_arrival<T>(
+    graph: &TemporalGraph<T>,
+    source: T,
+    departure_time: f64,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    if !graph.has_node(&source) {
+        return Err(Error {
+            kind: ErrorKind::NodeNotFound,
+            message: format!("Requested node '{}' was not found in the graph.", source),
+        });
+    }
+    let mut arrivals: HashMap<T, f64> = HashMap::new();
+    let mut seen: HashMap<T, f64> = HashMap::new();
+    let mut fringe: BinaryHeap<FringeNode<T>> = BinaryHeap::new();
+    let mut count = 0;
+    seen.insert(source.clone(), departure_time);
+    push_fringe_node(&mut count, &mut fringe, source, departure_time);
+    while let Some(fringe_item) = fringe.pop() {
+        let time = -fringe_item.distance;
+        let u = fringe_item.node_name;
+        if arrivals.contains_key(&u) {
+            continue;
+        }
+        for edge in graph.get_edges_from(u.clone()) {
+            if edge.time < time {
+                continue;
+            }
+            let v = match edge.u == u {
+                true => &edge.v,
+                false => &edge.u,
+            };
+            let arrival_time = edge.arrival_time();
+            if arrival_time < *seen.get(v).unwrap_or(&f64::INFINITY) {
+                seen.insert(v.clone(), arrival_time);
+                push_fringe_node(&mut count, &mut fringe, v.clone(), arrival_time);
+            }
+        }
+        arrivals.insert(u, time);
+    }
+    Ok(arrivals)
+}
@@ -0,0 +1,219 @@
+use crate::algorithms::shortest_path::dijkstra::{
+    dijkstra_multisource_to_targets, get_lazy_indexed_graph, with_path_edge_keys,
+};
+use crate::algorithms::shortest_path::shortest_path_info::ParallelEdgeKeys;
+use crate::algorithms::shortest_path::ShortestPathInfo;
+use crate::graph::IndexedAdjacency;
+use crate::{Direction, Error, ErrorKind, Graph};
+use itertools::Itertools;
+use num_traits::Num;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// The most waypoints that `via_waypoints` will try every order of.
+const MAX_ANY_ORDER_WAYPOINTS: usize = 8;
+
+/**
+Uses Dijkstra's algorithm to find the shortest path from `source` to `target` that passes
+through each of the `waypoints`. The path is made by joining the shortest paths between
+consecutive stops, so it may visit a node more than once.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `source`: the node to start the path from
+* `waypoints`: the nodes that the path must pass through
+* `target`: the node to end the path at
+* `any_order`: if `false` the path visits the `waypoints` in the order given. If `true`
+  it visits them in the order that gives the shortest path, trying every order; this is
+  only allowed for up to 8 waypoints.
+
+# Returns
+
+`Ok(None)` if there is no such path, otherwise `Ok(Some(...))` with one shortest path.
+If more than one order of the `waypoints` gives the shortest path the first of them, in
+the order of the `waypoints`, is used. An `Error` with a `kind` of
+`ErrorKind::NodeNotFound` is returned if any of the nodes isn't in the graph, and one
+with a `kind` of `ErrorKind::InvalidArgument` if `any_order` is `true` and there are more
+than 8 `waypoints`.
+
+# Examples
+
+```
+use graphrs::{algorithms::shortest_path, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n3", 1.0),
+    Edge::with_weight("n3", "n4", 1.0),
+    Edge::with_weight("n1", "n4", 5.0),
+]);
+
+let info = shortest_path::via_waypoints(&graph, true, "n1", &["n3", "n2"], "n4", false)
+    .unwrap()
+    .unwrap();
+assert_eq!(info.distance, 5.0);
+assert_eq!(info.paths, vec![vec!["n1", "n2", "n3", "n2", "n3", "n4"]]);
+
+let info = shortest_path::via_waypoints(&graph, true, "n1", &["n3", "n2"], "n4", true)
+    .unwrap()
+    .unwrap();
+assert_eq!(info.distance, 3.0);
+assert_eq!(info.paths, vec![vec!["n1", "n2", "n3", "n4"]]);
+```
+*/
+pub fn via_waypoints<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    source: T,
+    waypoints: &[T],
+    target: T,
+    any_order: bool,
+) -> Result<Option<ShortestPathInfo<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    if any_order && waypoints.len() > MAX_ANY_ORDER_WAYPOINTS {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!(
+                "Every order of the waypoints can only be tried for up to {} waypoints, not {}.",
+                MAX_ANY_ORDER_WAYPOINTS,
+                waypoints.len()
+            ),
+        });
+    }
+    let mut adjacency = get_lazy_indexed_graph(graph, weighted, Direction::Outgoing)?;
+    let mut stops = vec![source];
+    stops.extend(waypoints.iter().cloned());
+    stops.push(target);
+    adjacency.get_node_indexes(&stops)?;
+    let stops = match any_order {
+        false => stops,
+        true => match get_best_order(&mut adjacency, weighted, &stops)? {
+            None => return Ok(None),
+            Some(order) => order,
+        },
+    };
+    let mut legs = vec![];
+    for pair in stops.windows(2) {
+        match get_leg(&mut adjacency, weighted, &pair[0], &pair[1])? {
+            None => return Ok(None),
+            Some(leg) => legs.push(leg),
+        }
+    }
+    let info = join_legs(legs);
+    Ok(Some(with_path_edge_keys(
+        info,
+        ParallelEdgeKeys::new(graph, weighted).as_ref(),
+    )))
+}
+
+/**
+Returns the `stops`, with the waypoints between the first and last stops in the order
+that gives the shortest path, or `None` if there is no order that gives a path.
+*/
+fn get_best_order<T, W, G>(
+    adjacency: &mut G,
+    weighted: bool,
+    stops: &[T],
+) -> Result<Option<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+    G: IndexedAdjacency<T, W>,
+{
+    let num_stops = stops.len();
+    // the distances from each stop to the stops that can come after it
+    let mut distances: Vec<HashMap<T, W>> = vec![];
+    for stop in stops[..num_stops - 1].iter() {
+        let infos = dijkstra_multisource_to_targets(
+            &mut *adjacency,
+            weighted,
+            std::slice::from_ref(stop),
+            Some(&stops[1..]),
+            None,
+            None,
+            true,
+        )?;
+        distances.push(infos.into_iter().map(|(node, info)| (node, info.distance)).collect());
+    }
+    let mut best: Option<(W, Vec<usize>)> = None;
+    for order in (1..num_stops - 1).permutations(num_stops - 2) {
+        let order: Vec<usize> =
+            std::iter::once(0).chain(order).chain(std::iter::once(num_stops - 1)).collect();
+        let mut distance = W::zero();
+        let mut reachable = true;
+        for pair in order.windows(2) {
+            match distances[pair[0]].get(&stops[pair[1]]) {
+                None => {
+                    reachable = false;
+                    break;
+                }
+                Some(leg_distance) => distance = distance + *leg_distance,
+            }
+        }
+        let is_better = match &best {
+            None => true,
+            Some((best_distance, _)) => distance < *best_distance,
+        };
+        if reachable && is_better {
+            best = Some((distance, order));
+        }
+    }
+    Ok(best.map(|(_distance, order)| order.into_iter().map(|i| stops[i].clone()).collect()))
+}
+
+/// Returns a shortest path from `u` to `v`, or `None` if `v` can't be reached from `u`.
+fn get_leg<T, W, G>(
+    adjacency: &mut G,
+    weighted: bool,
+    u: &T,
+    v: &T,
+) -> Result<Option<ShortestPathInfo<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Num + PartialOrd + Copy + Send + Sync,
+    G: IndexedAdjacency<T, W>,
+{
+    let (sources, targets) = (std::slice::from_ref(u), std::slice::from_ref(v));
+    let mut infos = dijkstra_multisource_to_targets(
+        adjacency,
+        weighted,
+        sources,
+        Some(targets),
+        None,
+        None,
+        true,
+    )?;
+    Ok(infos.remove(v))
+}
+
+/// Joins the first paths of the `legs` into one path, with the sum of their distances.
+fn join_legs<T, W>(legs: Vec<ShortestPathInfo<T, W>>) -> ShortestPathInfo<T, W>
+where
+    T: Clone,
+    W: Num + Copy,
+{
+    let mut distance = W::zero();
+    let mut path: Vec<T> = vec![];
+    for leg in legs {
+        distance = distance + leg.distance;
+        let leg_path = leg.paths.into_iter().next().unwrap();
+        let skip = match path.is_empty() {
+            true => 0,
+            false => 1,
+        };
+        path.extend(leg_path.into_iter().skip(skip));
+    }
+    ShortestPathInfo {
+        distance,
+        paths: vec![path],
+        edge_keys: None,
+    }
+}
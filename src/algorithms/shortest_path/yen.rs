@@ -0,0 +1,233 @@
+use crate::algorithms::shortest_path::dijkstra::{
+    get_cost_multi, get_cost_single, push_weighted_fringe_node, with_path_edge_keys,
+};
+use crate::algorithms::shortest_path::shortest_path_info::ParallelEdgeKeys;
+use crate::algorithms::shortest_path::ShortestPathInfo;
+use crate::{Error, ErrorKind, Graph};
+use num_traits::Num;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Uses Yen's algorithm to find the `k` shortest loopless paths between `source` and `target`.
+
+Unlike the functions in the [dijkstra](../dijkstra/index.html) module, which only return
+paths that tie for the shortest distance, this returns the best path, the second-best
+path, the third-best path and so on.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: determines if shortest paths are determined with edge weight, or not
+* `source`: The starting node.
+* `target`: The ending node.
+* `k`: The maximum number of paths to return.
+
+# Returns
+
+A `Vec` of up to `k` `ShortestPathInfo` objects, ordered by increasing distance,
+each of which contains a single path. Fewer than `k` items are returned if there are
+fewer than `k` simple paths between `source` and `target`; an empty `Vec` is returned
+if `target` cannot be reached from `source`.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs};
+use graphrs::{algorithms::{shortest_path::{yen}}};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("n1", "n2", 1.0),
+    Edge::with_weight("n2", "n4", 1.0),
+    Edge::with_weight("n1", "n3", 1.5),
+    Edge::with_weight("n3", "n4", 1.0),
+    Edge::with_weight("n1", "n4", 4.0),
+]);
+
+let result = yen::k_shortest_paths(&graph, true, "n1", "n4", 3).unwrap();
+assert_eq!(result.len(), 3);
+assert_eq!(result[0].paths, vec![vec!["n1", "n2", "n4"]]);
+assert_eq!(result[1].distance, 2.5);
+assert_eq!(result[2].paths, vec![vec!["n1", "n4"]]);
+```
+
+# References
+
+1. Jin Y. Yen. Finding the K Shortest Loopless Paths in a Network.
+   Management Science, 17(11):712–716, 1971.
+*/
+pub fn k_shortest_paths<T, A, W>(
+    graph: &Graph<T, A, W>,
+    weighted: bool,
+    source: T,
+    target: T,
+    k: usize,
+) -> Result<Vec<ShortestPathInfo<T, W>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    for node_name in [&source, &target] {
+        if !graph.has_node(node_name) {
+            return Err(Error {
+                kind: ErrorKind::NodeNotFound,
+                message: format!("Requested node '{}' was not found in the graph.", node_name),
+            });
+        }
+    }
+
+    let get_cost = |u: T, v: T| match weighted {
+        true => match graph.specs.multi_edges {
+            false => get_cost_single(graph, u, v),
+            true => get_cost_multi(graph, u, v),
+        },
+        false => W::one(),
+    };
+
+    if k == 0 {
+        return Ok(vec![]);
+    }
+    let mut found: Vec<(W, Vec<T>)> = vec![];
+    let mut candidates: Vec<(W, Vec<T>)> = vec![];
+    match restricted_shortest_path(
+        graph,
+        &get_cost,
+        source,
+        target.clone(),
+        &HashSet::new(),
+        &HashSet::new(),
+    ) {
+        None => return Ok(vec![]),
+        Some(first) => found.push(first),
+    }
+
+    while found.len() < k {
+        let previous_path = found.last().unwrap().1.clone();
+        for j in 0..(previous_path.len() - 1) {
+            let spur_node = previous_path[j].clone();
+            let root_path = &previous_path[..=j];
+            // remove the edges that would recreate a path already found
+            let mut ignore_edges = HashSet::new();
+            for (_, path) in found.iter() {
+                if path.len() > j + 1 && &path[..=j] == root_path {
+                    ignore_edges.insert((path[j].clone(), path[j + 1].clone()));
+                    if !graph.specs.directed {
+                        ignore_edges.insert((path[j + 1].clone(), path[j].clone()));
+                    }
+                }
+            }
+            // remove the root path's nodes, except the spur node, to keep paths loopless
+            let ignore_nodes: HashSet<T> = root_path[..j].iter().cloned().collect();
+            let spur = restricted_shortest_path(
+                graph,
+                &get_cost,
+                spur_node,
+                target.clone(),
+                &ignore_nodes,
+                &ignore_edges,
+            );
+            if let Some((spur_distance, spur_path)) = spur {
+                let root_distance = root_path
+                    .windows(2)
+                    .map(|w| get_cost(w[0].clone(), w[1].clone()))
+                    .fold(W::zero(), |total, cost| total + cost);
+                let mut total_path = root_path[..j].to_vec();
+                total_path.extend(spur_path);
+                let exists = candidates.iter().chain(found.iter()).any(|c| c.1 == total_path);
+                if !exists {
+                    candidates.push((root_distance + spur_distance, total_path));
+                }
+            }
+        }
+        if candidates.is_empty() {
+            break;
+        }
+        let best_index = candidates
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.0.partial_cmp(&b.0).unwrap().then_with(|| a.1.cmp(&b.1)))
+            .unwrap()
+            .0;
+        found.push(candidates.swap_remove(best_index));
+    }
+
+    let edge_keys = ParallelEdgeKeys::new(graph, weighted);
+    Ok(found
+        .into_iter()
+        .map(|(distance, path)| ShortestPathInfo {
+            distance,
+            paths: vec![path],
+            edge_keys: None,
+        })
+        .map(|info| with_path_edge_keys(info, edge_keys.as_ref()))
+        .collect())
+}
+
+/**
+Uses Dijkstra's algorithm to find a single shortest path from `source` to `target`
+while ignoring the nodes in `ignore_nodes` and the edges in `ignore_edges`.
+Returns `None` if `target` cannot be reached.
+*/
+fn restricted_shortest_path<T, A, W, F>(
+    graph: &Graph<T, A, W>,
+    get_cost: &F,
+    source: T,
+    target: T,
+    ignore_nodes: &HashSet<T>,
+    ignore_edges: &HashSet<(T, T)>,
+) -> Option<(W, Vec<T>)>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    W: Num + PartialOrd + Copy + Send + Sync,
+    F: Fn(T, T) -> W,
+{
+    let mut dist = HashMap::<T, W>::new();
+    let mut seen = HashMap::<T, W>::new();
+    let mut parents = HashMap::<T, T>::new();
+    let mut fringe = BinaryHeap::new();
+    let mut count = 0;
+
+    seen.insert(source.clone(), W::zero());
+    push_weighted_fringe_node(&mut count, &mut fringe, source, W::zero());
+
+    while let Some(fringe_item) = fringe.pop() {
+        let d = fringe_item.distance;
+        let v = fringe_item.node_name;
+        if dist.contains_key(&v) {
+            continue;
+        }
+        dist.insert(v.clone(), d);
+        if v == target {
+            let mut path = vec![target];
+            while let Some(parent) = parents.get(path.last().unwrap()) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some((d, path));
+        }
+        for node in graph.get_successors_or_neighbors(v.clone()) {
+            let u = node.name.clone();
+            if ignore_nodes.contains(&u)
+                || dist.contains_key(&u)
+                || ignore_edges.contains(&(v.clone(), u.clone()))
+            {
+                continue;
+            }
+            let vu_dist = d + get_cost(v.clone(), u.clone());
+            if !seen.contains_key(&u) || vu_dist < *seen.get(&u).unwrap() {
+                seen.insert(u.clone(), vu_dist);
+                parents.insert(u.clone(), v.clone());
+                push_weighted_fringe_node(&mut count, &mut fringe, u, vu_dist);
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,411 @@
+use super::get_sorted_neighbors_directed;
+use crate::{CsrGraph, Direction, Error, Graph};
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Callbacks that are invoked as [breadth_first_search](./fn.breadth_first_search.html)
+explores a graph. All the methods have empty default implementations, so only the
+ones that are needed have to be implemented.
+*/
+pub trait BfsVisitor<T> {
+    /// Called when `node` is first reached, `depth` edges away from the source.
+    fn on_discover(&mut self, _node: &T, _depth: usize) {}
+    /// Called for an edge from `u` to `v` that leads to a newly discovered node `v`.
+    fn on_tree_edge(&mut self, _u: &T, _v: &T) {}
+    /// Called for an edge from `u` to `v` where `v` has already been discovered.
+    /// For undirected graphs each non-tree edge is only reported once.
+    fn on_non_tree_edge(&mut self, _u: &T, _v: &T) {}
+}
+
+/**
+An iterator over the nodes of a graph in breadth-first order.
+Created by [breadth_first_nodes](./fn.breadth_first_nodes.html).
+*/
+pub struct BfsIter<'a, T, A>
+where
+    T: PartialOrd + Send,
+    A: Clone,
+{
+    graph: &'a Graph<T, A>,
+    direction: Direction,
+    queue: VecDeque<T>,
+    discovered: HashSet<T>,
+}
+
+impl<'a, T, A> Iterator for BfsIter<'a, T, A>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        for neighbor in get_sorted_neighbors_directed(self.graph, &node, self.direction) {
+            if self.discovered.insert(neighbor.clone()) {
+                self.queue.push_back(neighbor);
+            }
+        }
+        Some(node)
+    }
+}
+
+/**
+An iterator over the nodes of a [CsrGraph](../../../struct.CsrGraph.html) in breadth-first
+order. Created by [breadth_first_nodes_csr](./fn.breadth_first_nodes_csr.html).
+*/
+pub struct CsrBfsIter<'a, T, W> {
+    csr: &'a CsrGraph<T, W>,
+    queue: VecDeque<u32>,
+    discovered: Vec<bool>,
+}
+
+impl<'a, T, W> Iterator for CsrBfsIter<'a, T, W>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Copy + PartialOrd,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        for &neighbor in self.csr.get_successors_or_neighbors(node) {
+            if !self.discovered[neighbor as usize] {
+                self.discovered[neighbor as usize] = true;
+                self.queue.push_back(neighbor);
+            }
+        }
+        self.csr.get_node_name(node).cloned()
+    }
+}
+
+/**
+Returns an iterator over the nodes that can be reached from `source`, in breadth-first
+order. The neighbors of each node are visited in sorted order, so the order is deterministic.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `source`: the node to start the search from
+
+# Examples
+
+```
+use graphrs::{algorithms::traversal::bfs, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n1", "n3"),
+    Edge::new("n2", "n4"),
+    Edge::new("n3", "n4"),
+    Edge::new("n5", "n1"),
+]);
+
+let nodes: Vec<&str> = bfs::breadth_first_nodes(&graph, "n1").unwrap().collect();
+assert_eq!(nodes, vec!["n1", "n2", "n3", "n4"]);
+```
+*/
+pub fn breadth_first_nodes<T, A>(graph: &Graph<T, A>, source: T) -> Result<BfsIter<'_, T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    breadth_first_nodes_in_direction(graph, source, Direction::Outgoing)
+}
+
+/**
+Returns an iterator over the nodes that are connected to `source` by a path that follows
+the edges in `direction`, in breadth-first order. With `Direction::Incoming` these are the
+nodes that can reach `source` in a directed graph, without having to reverse the graph.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `source`: the node to start the search from
+* `direction`: the [Direction](../../../enum.Direction.html) of the edges to follow
+
+# Examples
+
+```
+use graphrs::{algorithms::traversal::bfs, Direction, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n1", "n3"),
+    Edge::new("n2", "n4"),
+    Edge::new("n3", "n4"),
+    Edge::new("n5", "n1"),
+]);
+
+let nodes: Vec<&str> = bfs::breadth_first_nodes_in_direction(&graph, "n4", Direction::Incoming)
+    .unwrap()
+    .collect();
+assert_eq!(nodes, vec!["n4", "n2", "n3", "n1", "n5"]);
+```
+*/
+pub fn breadth_first_nodes_in_direction<T, A>(
+    graph: &Graph<T, A>,
+    source: T,
+    direction: Direction,
+) -> Result<BfsIter<'_, T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    graph.ensure_node(&source)?;
+    Ok(BfsIter {
+        graph,
+        direction,
+        queue: vec![source.clone()].into_iter().collect(),
+        discovered: vec![source].into_iter().collect(),
+    })
+}
+
+/**
+Performs a breadth-first search from `source`, calling the methods of `visitor` as nodes
+are discovered and edges are examined. This allows custom traversal logic to be written
+without having to manage the queue.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `source`: the node to start the search from
+* `visitor`: an implementation of [BfsVisitor](./trait.BfsVisitor.html)
+
+# Examples
+
+```
+use graphrs::{algorithms::traversal::bfs, Edge, Graph, GraphSpecs};
+use std::collections::HashMap;
+
+struct Depths {
+    depths: HashMap<i32, usize>,
+    non_tree_edges: Vec<(i32, i32)>,
+}
+
+impl bfs::BfsVisitor<i32> for Depths {
+    fn on_discover(&mut self, node: &i32, depth: usize) {
+        self.depths.insert(*node, depth);
+    }
+    fn on_non_tree_edge(&mut self, u: &i32, v: &i32) {
+        self.non_tree_edges.push((*u, *v));
+    }
+}
+
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![Edge::new(1, 2), Edge::new(2, 3), Edge::new(3, 1), Edge::new(3, 4)]);
+
+let mut visitor = Depths { depths: HashMap::new(), non_tree_edges: vec![] };
+bfs::breadth_first_search(&graph, 1, &mut visitor).unwrap();
+assert_eq!(visitor.depths.get(&4), Some(&2));
+assert_eq!(visitor.non_tree_edges, vec![(2, 3)]);
+```
+*/
+pub fn breadth_first_search<T, A, V>(
+    graph: &Graph<T, A>,
+    source: T,
+    visitor: &mut V,
+) -> Result<(), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    V: BfsVisitor<T>,
+{
+    breadth_first_search_in_direction(graph, source, Direction::Outgoing, visitor)
+}
+
+/**
+Performs a breadth-first search from `source` that follows the edges in `direction`,
+calling the methods of `visitor` as nodes are discovered and edges are examined. With
+`Direction::Incoming` the search goes backwards along the edges of a directed graph, and
+the `u` and `v` passed to the visitor are the node the search came from and the
+predecessor it found, so the edge in the graph is from `v` to `u`.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `source`: the node to start the search from
+* `direction`: the [Direction](../../../enum.Direction.html) of the edges to follow
+* `visitor`: an implementation of [BfsVisitor](./trait.BfsVisitor.html)
+
+# Examples
+
+```
+use graphrs::{algorithms::traversal::bfs, Direction, Edge, Graph, GraphSpecs};
+use std::collections::HashMap;
+
+struct Depths {
+    depths: HashMap<i32, usize>,
+}
+
+impl bfs::BfsVisitor<i32> for Depths {
+    fn on_discover(&mut self, node: &i32, depth: usize) {
+        self.depths.insert(*node, depth);
+    }
+}
+
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![Edge::new(1, 2), Edge::new(2, 3), Edge::new(3, 4), Edge::new(4, 5)]);
+
+let mut visitor = Depths { depths: HashMap::new() };
+bfs::breadth_first_search_in_direction(&graph, 4, Direction::Incoming, &mut visitor).unwrap();
+assert_eq!(visitor.depths.get(&1), Some(&3));
+assert_eq!(visitor.depths.get(&5), None);
+```
+*/
+pub fn breadth_first_search_in_direction<T, A, V>(
+    graph: &Graph<T, A>,
+    source: T,
+    direction: Direction,
+    visitor: &mut V,
+) -> Result<(), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    V: BfsVisitor<T>,
+{
+    graph.ensure_node(&source)?;
+    let mut discovered = HashSet::new();
+    let mut finished = HashSet::new();
+    let mut queue = VecDeque::new();
+    visitor.on_discover(&source, 0);
+    discovered.insert(source.clone());
+    queue.push_back((source, 0));
+    while let Some((u, depth)) = queue.pop_front() {
+        for v in get_sorted_neighbors_directed(graph, &u, direction) {
+            if discovered.insert(v.clone()) {
+                visitor.on_tree_edge(&u, &v);
+                visitor.on_discover(&v, depth + 1);
+                queue.push_back((v, depth + 1));
+            } else if graph.specs.directed || !finished.contains(&v) {
+                // an undirected edge to a finished node was already examined from that node
+                visitor.on_non_tree_edge(&u, &v);
+            }
+        }
+        finished.insert(u);
+    }
+    Ok(())
+}
+
+/**
+Returns an iterator over the nodes that can be reached from `source` in a
+[CsrGraph](../../../struct.CsrGraph.html), in breadth-first order. The nodes are returned
+in the same order as [breadth_first_nodes](./fn.breadth_first_nodes.html) would return
+them for the graph that the `CsrGraph` was created from.
+
+# Arguments
+
+* `csr`: a [CsrGraph](../../../struct.CsrGraph.html) instance
+* `source`: the node to start the search from
+
+# Examples
+
+```
+use graphrs::{algorithms::traversal::bfs, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n1", "n3"),
+    Edge::new("n2", "n4"),
+    Edge::new("n3", "n4"),
+    Edge::new("n5", "n1"),
+]);
+
+let csr = graph.to_csr();
+let nodes: Vec<&str> = bfs::breadth_first_nodes_csr(&csr, "n1").unwrap().collect();
+assert_eq!(nodes, vec!["n1", "n2", "n3", "n4"]);
+```
+*/
+pub fn breadth_first_nodes_csr<T, W>(
+    csr: &CsrGraph<T, W>,
+    source: T,
+) -> Result<CsrBfsIter<'_, T, W>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Copy + PartialOrd,
+{
+    let source = csr.get_node_indexes(&[source])?[0];
+    let mut discovered = vec![false; csr.number_of_nodes()];
+    discovered[source as usize] = true;
+    Ok(CsrBfsIter {
+        csr,
+        queue: vec![source].into_iter().collect(),
+        discovered,
+    })
+}
+
+/**
+Performs a breadth-first search from `source` in a [CsrGraph](../../../struct.CsrGraph.html),
+calling the methods of `visitor` as nodes are discovered and edges are examined. The
+methods are called in the same order as [breadth_first_search](./fn.breadth_first_search.html)
+would call them for the graph that the `CsrGraph` was created from.
+
+# Arguments
+
+* `csr`: a [CsrGraph](../../../struct.CsrGraph.html) instance
+* `source`: the node to start the search from
+* `visitor`: an implementation of [BfsVisitor](./trait.BfsVisitor.html)
+
+# Examples
+
+```
+use graphrs::{algorithms::traversal::bfs, generators};
+use std::collections::HashMap;
+
+struct Depths {
+    depths: HashMap<i32, usize>,
+}
+
+impl bfs::BfsVisitor<i32> for Depths {
+    fn on_discover(&mut self, node: &i32, depth: usize) {
+        self.depths.insert(*node, depth);
+    }
+}
+
+let graph = generators::social::karate_club_graph();
+let csr = graph.to_csr();
+let mut visitor = Depths { depths: HashMap::new() };
+bfs::breadth_first_search_csr(&csr, 0, &mut visitor).unwrap();
+assert_eq!(visitor.depths.get(&33), Some(&2));
+```
+*/
+pub fn breadth_first_search_csr<T, W, V>(
+    csr: &CsrGraph<T, W>,
+    source: T,
+    visitor: &mut V,
+) -> Result<(), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    W: Copy + PartialOrd,
+    V: BfsVisitor<T>,
+{
+    let source = csr.get_node_indexes(&[source])?[0];
+    let names = csr.get_node_names();
+    let mut discovered = vec![false; csr.number_of_nodes()];
+    let mut finished = vec![false; csr.number_of_nodes()];
+    let mut queue = VecDeque::new();
+    visitor.on_discover(&names[source as usize], 0);
+    discovered[source as usize] = true;
+    queue.push_back((source, 0));
+    while let Some((u, depth)) = queue.pop_front() {
+        for &v in csr.get_successors_or_neighbors(u) {
+            let (u_name, v_name) = (&names[u as usize], &names[v as usize]);
+            if !discovered[v as usize] {
+                discovered[v as usize] = true;
+                visitor.on_tree_edge(u_name, v_name);
+                visitor.on_discover(v_name, depth + 1);
+                queue.push_back((v, depth + 1));
+            } else if csr.is_directed() || !finished[v as usize] {
+                // an undirected edge to a finished node was already examined from that node
+                visitor.on_non_tree_edge(u_name, v_name);
+            }
+        }
+        finished[u as usize] = true;
+    }
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use super::get_sorted_successors_or_neighbors;
+use crate::{Error, Graph};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+The classification of an edge examined during a depth-first search.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DfsEdgeType {
+    /// The edge led to a newly discovered node.
+    Tree,
+    /// The edge leads to an ancestor in the depth-first forest (including self-loops).
+    Back,
+    /// The edge leads to a descendant that was already discovered.
+    /// Only occurs in directed graphs.
+    Forward,
+    /// The edge leads to a node in another branch, or another tree, of the
+    /// depth-first forest. Only occurs in directed graphs.
+    Cross,
+}
+
+/**
+The result of a depth-first search.
+
+Discovery and finish times share a single clock that is incremented each time a node
+is discovered or finished, so `discovery[u] < discovery[v] < finish[v] < finish[u]`
+when `v` is a descendant of `u`.
+*/
+pub struct DfsResult<T> {
+    /// The time at which each node was discovered.
+    pub discovery: HashMap<T, usize>,
+    /// The time at which each node was finished, after all its descendants were finished.
+    pub finish: HashMap<T, usize>,
+    /// The parent of each node in the depth-first forest; the roots have no parent.
+    pub parents: HashMap<T, T>,
+    /// The nodes in the order they were discovered.
+    pub preorder: Vec<T>,
+    /// The nodes in the order they were finished.
+    pub postorder: Vec<T>,
+    /// The edges, as (`u`, `v`, type) tuples, in the order they were examined.
+    /// For undirected graphs each edge is only included once.
+    pub edges: Vec<(T, T, DfsEdgeType)>,
+}
+
+/**
+Performs a depth-first search, recording the discovery and finish times of the nodes
+and classifying each edge as a tree, back, forward or cross edge.
+
+The neighbors of each node are visited in sorted order, so the result is deterministic.
+The search is iterative, so it will not overflow the stack on deep graphs.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `source`: the node to start the search from. If `None` every node is searched from,
+  in sorted order, until all nodes have been discovered.
+
+# Examples
+
+```
+use graphrs::{algorithms::traversal::dfs::{self, DfsEdgeType}, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n1"),
+    Edge::new("n1", "n3"),
+]);
+
+let result = dfs::depth_first_search(&graph, None).unwrap();
+assert_eq!(result.preorder, vec!["n1", "n2", "n3"]);
+assert_eq!(result.postorder, vec!["n3", "n2", "n1"]);
+assert_eq!(result.discovery.get("n3"), Some(&2));
+assert_eq!(result.finish.get("n1"), Some(&5));
+assert_eq!(
+    result.edges,
+    vec![
+        ("n1", "n2", DfsEdgeType::Tree),
+        ("n2", "n3", DfsEdgeType::Tree),
+        ("n3", "n1", DfsEdgeType::Back),
+        ("n1", "n3", DfsEdgeType::Forward),
+    ]
+);
+```
+
+# References
+
+1. Thomas H. Cormen, Charles E. Leiserson, Ronald L. Rivest and Clifford Stein.
+   Introduction to Algorithms, 3rd edition, section 22.3. MIT Press, 2009.
+*/
+pub fn depth_first_search<T, A>(
+    graph: &Graph<T, A>,
+    source: Option<T>,
+) -> Result<DfsResult<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let roots = match source {
+        Some(s) => {
+            graph.ensure_node(&s)?;
+            vec![s]
+        }
+        None => {
+            let mut names: Vec<T> = graph.get_all_node_names().into_iter().cloned().collect();
+            names.sort();
+            names
+        }
+    };
+
+    let mut result = DfsResult {
+        discovery: HashMap::new(),
+        finish: HashMap::new(),
+        parents: HashMap::new(),
+        preorder: vec![],
+        postorder: vec![],
+        edges: vec![],
+    };
+    let mut time = 0;
+
+    for root in roots {
+        if result.discovery.contains_key(&root) {
+            continue;
+        }
+        result.discovery.insert(root.clone(), time);
+        time += 1;
+        result.preorder.push(root.clone());
+        let root_neighbors = get_sorted_successors_or_neighbors(graph, &root);
+        let mut stack = vec![(root, root_neighbors, 0)];
+        while let Some((u, neighbors, index)) = stack.last_mut() {
+            if *index == neighbors.len() {
+                let u = u.clone();
+                stack.pop();
+                result.finish.insert(u.clone(), time);
+                time += 1;
+                result.postorder.push(u);
+                continue;
+            }
+            let v = neighbors[*index].clone();
+            *index += 1;
+            let u = u.clone();
+            match classify_edge(graph, &result, &u, &v) {
+                None => {}
+                Some(DfsEdgeType::Tree) => {
+                    result.edges.push((u.clone(), v.clone(), DfsEdgeType::Tree));
+                    result.parents.insert(v.clone(), u);
+                    result.discovery.insert(v.clone(), time);
+                    time += 1;
+                    result.preorder.push(v.clone());
+                    let v_neighbors = get_sorted_successors_or_neighbors(graph, &v);
+                    stack.push((v, v_neighbors, 0));
+                }
+                Some(edge_type) => result.edges.push((u, v, edge_type)),
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/**
+Classifies the (`u`, `v`) edge given the state of the search so far.
+Returns `None` for undirected edges that have already been classified.
+*/
+fn classify_edge<T, A>(
+    graph: &Graph<T, A>,
+    result: &DfsResult<T>,
+    u: &T,
+    v: &T,
+) -> Option<DfsEdgeType>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let v_discovery = match result.discovery.get(v) {
+        None => return Some(DfsEdgeType::Tree),
+        Some(d) => d,
+    };
+    let v_finished = result.finish.contains_key(v);
+    match graph.specs.directed {
+        true => match (v_finished, result.discovery.get(u).unwrap() < v_discovery) {
+            (false, _) => Some(DfsEdgeType::Back),
+            (true, true) => Some(DfsEdgeType::Forward),
+            (true, false) => Some(DfsEdgeType::Cross),
+        },
+        false => {
+            // the reverse of a tree edge, or a back edge already seen from the descendant
+            if v_finished || result.parents.get(u) == Some(v) {
+                None
+            } else {
+                Some(DfsEdgeType::Back)
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{Direction, Graph};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// Traverse the graph in breadth-first order.
+pub mod bfs;
+
+/// Traverse the graph in depth-first order, classifying its edges.
+pub mod dfs;
+
+/**
+Returns the names of the successors (for a directed graph) or neighbors (for an
+undirected graph) of `node_name`, sorted so that traversals are deterministic.
+*/
+pub(crate) fn get_sorted_successors_or_neighbors<T, A>(graph: &Graph<T, A>, node_name: &T) -> Vec<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    get_sorted_neighbors_directed(graph, node_name, Direction::Outgoing)
+}
+
+/**
+Returns the names of the nodes that are one edge away from `node_name` in `direction`,
+sorted so that traversals are deterministic.
+*/
+pub(crate) fn get_sorted_neighbors_directed<T, A>(
+    graph: &Graph<T, A>,
+    node_name: &T,
+    direction: Direction,
+) -> Vec<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let mut names: Vec<T> =
+        graph.neighbors_directed_iter(node_name.clone(), direction).unwrap().cloned().collect();
+    names.sort();
+    names
+}
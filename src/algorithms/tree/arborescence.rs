@@ -0,0 +1,197 @@
+use crate::{Edge, Error, ErrorKind, Graph};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Finds a minimum spanning arborescence of a directed graph, using Edmonds' algorithm:
+a set of edges with the smallest total weight such that there is exactly one path from
+`root` to every other node.
+
+Ties between edges of equal weight are broken by the names of their nodes.
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance where all edges have a weight
+* `root`: the node that the arborescence grows from
+
+# Returns
+
+A new `Graph` with all the nodes of `graph` and the edges of the arborescence. An error
+with `ErrorKind::NoArborescence` is returned if some node can't be reached from `root`.
+
+# Examples
+
+```
+use graphrs::{algorithms::tree, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("r", "a", 5.0),
+    Edge::with_weight("r", "b", 1.0),
+    Edge::with_weight("a", "b", 1.0),
+    Edge::with_weight("b", "a", 2.0),
+]);
+
+let arborescence = tree::minimum_spanning_arborescence(&graph, "r").unwrap();
+assert_eq!(arborescence.size(true), 3.0);
+assert!(arborescence.get_edge("b", "a").is_ok());
+```
+
+# References
+
+1. J. Edmonds. Optimum branchings. Journal of Research of the National Bureau of
+   Standards 71B(4):233–240, 1967.
+*/
+pub fn minimum_spanning_arborescence<T, A>(
+    graph: &Graph<T, A>,
+    root: T,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    graph.ensure_weighted()?;
+    graph.ensure_node(&root)?;
+    let mut node_names = graph.get_all_node_names();
+    node_names.sort();
+    let node_indexes: HashMap<&T, usize> =
+        node_names.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let mut edges = graph.get_all_edges();
+    edges.sort_by(|e1, e2| (&e1.u, &e1.v).cmp(&(&e2.u, &e2.v)));
+    let arcs: Vec<(usize, usize, f64)> = edges
+        .iter()
+        .map(|e| {
+            let u = *node_indexes.get(&e.u).unwrap();
+            let v = *node_indexes.get(&e.v).unwrap();
+            (u, v, e.weight)
+        })
+        .collect();
+    let chosen = match edmonds(node_names.len(), *node_indexes.get(&root).unwrap(), &arcs) {
+        None => {
+            return Err(Error {
+                kind: ErrorKind::NoArborescence,
+                message: format!(
+                    "Not every node can be reached from {}, so there is no spanning arborescence.",
+                    root
+                ),
+            })
+        }
+        Some(chosen) => chosen,
+    };
+    let nodes = graph.get_all_nodes().into_iter().cloned().collect();
+    let new_edges: Vec<Edge<T, A>> = chosen.into_iter().map(|i| edges[i].clone()).collect();
+    Graph::new_from_nodes_and_edges(nodes, new_edges, graph.specs.clone())
+}
+
+/**
+Finds a minimum spanning arborescence of the nodes `0..num_nodes`, where arc `i` goes
+from `arcs[i].0` to `arcs[i].1` with weight `arcs[i].2`. Returns the arcs of the
+arborescence, or `None` if there isn't one.
+
+The lightest arc into each node is chosen. If the chosen arcs form cycles, each cycle is
+contracted into a single node and the problem is solved again for the smaller graph, with
+the weight of each arc into a cycle reduced by the weight of the chosen arc it would replace.
+*/
+fn edmonds(num_nodes: usize, root: usize, arcs: &[(usize, usize, f64)]) -> Option<Vec<usize>> {
+    let mut lightest_in: Vec<Option<usize>> = vec![None; num_nodes];
+    for (i, (u, v, weight)) in arcs.iter().enumerate() {
+        let is_lighter = match lightest_in[*v] {
+            None => true,
+            Some(j) => *weight < arcs[j].2,
+        };
+        if u != v && *v != root && is_lighter {
+            lightest_in[*v] = Some(i);
+        }
+    }
+    if (0..num_nodes).any(|v| v != root && lightest_in[v].is_none()) {
+        return None;
+    }
+    let get_parent = |v: usize| arcs[lightest_in[v].unwrap()].0;
+
+    // find the cycles formed by the lightest arcs
+    let mut components = vec![usize::MAX; num_nodes];
+    let mut visited_from = vec![usize::MAX; num_nodes];
+    let mut num_components = 0;
+    for start in 0..num_nodes {
+        let mut v = start;
+        while v != root && visited_from[v] == usize::MAX {
+            visited_from[v] = start;
+            v = get_parent(v);
+        }
+        if v != root && visited_from[v] == start && components[v] == usize::MAX {
+            let mut u = v;
+            loop {
+                components[u] = num_components;
+                u = get_parent(u);
+                if u == v {
+                    break;
+                }
+            }
+            num_components += 1;
+        }
+    }
+    if num_components == 0 {
+        return Some((0..num_nodes).filter_map(|v| lightest_in[v]).collect());
+    }
+    let in_cycle: Vec<bool> = components.iter().map(|c| *c != usize::MAX).collect();
+    for component in components.iter_mut().filter(|c| **c == usize::MAX) {
+        *component = num_components;
+        num_components += 1;
+    }
+
+    // contract the cycles and solve the smaller problem
+    let mut contracted_arcs = vec![];
+    let mut original_arcs = vec![];
+    for (i, (u, v, weight)) in arcs.iter().enumerate() {
+        if components[*u] == components[*v] || *v == root {
+            continue;
+        }
+        let weight = match in_cycle[*v] {
+            true => weight - arcs[lightest_in[*v].unwrap()].2,
+            false => *weight,
+        };
+        contracted_arcs.push((components[*u], components[*v], weight));
+        original_arcs.push(i);
+    }
+    let contracted_chosen = edmonds(num_components, components[root], &contracted_arcs)?;
+
+    // expand the cycles, keeping all of each cycle's arcs except the one that was replaced
+    let mut entered = vec![false; num_nodes];
+    let mut chosen = vec![];
+    for j in contracted_chosen {
+        let i = original_arcs[j];
+        entered[arcs[i].1] = true;
+        chosen.push(i);
+    }
+    for v in (0..num_nodes).filter(|v| in_cycle[*v] && !entered[*v]) {
+        chosen.push(lightest_in[v].unwrap());
+    }
+    Some(chosen)
+}
+
+// tests for private methods only; other tests are in:
+// tests/test_algorithms_tree.rs
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_edmonds() {
+        // the lightest arcs into 1, 2 and 3 form a cycle
+        let arcs = vec![
+            (0, 1, 10.0),
+            (0, 2, 4.0),
+            (1, 2, 1.0),
+            (2, 3, 1.0),
+            (3, 1, 1.0),
+            (0, 3, 8.0),
+        ];
+        let mut chosen = edmonds(4, 0, &arcs).unwrap();
+        chosen.sort_unstable();
+        assert_eq!(chosen, vec![1, 3, 4]);
+        assert_eq!(edmonds(5, 0, &arcs), None);
+    }
+}
@@ -0,0 +1,6 @@
+mod arborescence;
+mod spanning_tree;
+pub use arborescence::minimum_spanning_arborescence;
+pub use spanning_tree::{
+    minimum_spanning_edges, minimum_spanning_tree, SpanningEdges, SpanningTreeAlgorithm,
+};
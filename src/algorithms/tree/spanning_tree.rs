@@ -0,0 +1,284 @@
+use crate::algorithms::components::union_find::UnionFind;
+use crate::{Edge, Error, Graph};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// The algorithm used to find a minimum spanning tree.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpanningTreeAlgorithm {
+    /// Borůvka's algorithm: repeatedly adds the lightest edge leaving each tree of the forest.
+    Boruvka,
+    /// Kruskal's algorithm: adds edges in order of weight, skipping those that form a cycle.
+    Kruskal,
+    /// Prim's algorithm: grows a tree from a node by adding the lightest edge leaving it.
+    Prim,
+}
+
+/**
+An iterator over the edges of a minimum spanning tree, in the order they were added
+to the tree. Created by [minimum_spanning_edges](fn.minimum_spanning_edges.html).
+*/
+pub struct SpanningEdges<'a, T, A>
+where
+    T: PartialOrd + Send,
+{
+    edges: std::vec::IntoIter<&'a Edge<T, A>>,
+}
+
+impl<'a, T, A> Iterator for SpanningEdges<'a, T, A>
+where
+    T: PartialOrd + Send,
+{
+    type Item = &'a Edge<T, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.edges.next()
+    }
+}
+
+/**
+Finds the edges of a minimum spanning tree of an undirected graph: a set of edges that
+connects all the nodes with the smallest total weight. If the graph isn't connected
+the edges of a minimum spanning forest, with a tree for each connected component, are
+found.
+
+Ties between edges of equal weight are broken by the names of their nodes, so every
+algorithm returns the same edges.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `algorithm`: the [SpanningTreeAlgorithm](enum.SpanningTreeAlgorithm.html) to use
+* `weighted`: set to `true` to use edge weights; otherwise every edge has a weight of `1.0`
+* `maximum`: set to `true` to find a maximum spanning tree instead
+
+# Examples
+
+```
+use graphrs::{algorithms::tree::{self, SpanningTreeAlgorithm}, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("a", "b", 1.0),
+    Edge::with_weight("b", "c", 2.0),
+    Edge::with_weight("a", "c", 3.0),
+]);
+
+let edges: Vec<(&str, &str)> =
+    tree::minimum_spanning_edges(&graph, SpanningTreeAlgorithm::Kruskal, true, false)
+        .unwrap()
+        .map(|e| (e.u, e.v))
+        .collect();
+assert_eq!(edges, vec![("a", "b"), ("b", "c")]);
+```
+*/
+pub fn minimum_spanning_edges<T, A>(
+    graph: &Graph<T, A>,
+    algorithm: SpanningTreeAlgorithm,
+    weighted: bool,
+    maximum: bool,
+) -> Result<SpanningEdges<'_, T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let mut node_names = graph.get_all_node_names();
+    node_names.sort();
+    let node_indexes: HashMap<&T, usize> =
+        node_names.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let get_weight = |edge: &Edge<T, A>| match (weighted, maximum) {
+        (true, false) => edge.weight,
+        (true, true) => -edge.weight,
+        (false, _) => 1.0,
+    };
+    let mut edges: Vec<&Edge<T, A>> =
+        graph.get_all_edges().into_iter().filter(|e| e.u != e.v).collect();
+    edges.sort_by(|e1, e2| {
+        get_weight(e1)
+            .partial_cmp(&get_weight(e2))
+            .unwrap()
+            .then_with(|| (&e1.u, &e1.v).cmp(&(&e2.u, &e2.v)))
+    });
+    // edges are identified by their position in `edges`, which orders them by weight
+    let endpoints: Vec<(usize, usize)> = edges
+        .iter()
+        .map(|e| {
+            (
+                *node_indexes.get(&e.u).unwrap(),
+                *node_indexes.get(&e.v).unwrap(),
+            )
+        })
+        .collect();
+    let ranks = match algorithm {
+        SpanningTreeAlgorithm::Boruvka => boruvka(node_names.len(), &endpoints),
+        SpanningTreeAlgorithm::Kruskal => kruskal(node_names.len(), &endpoints),
+        SpanningTreeAlgorithm::Prim => prim(node_names.len(), &endpoints),
+    };
+    Ok(SpanningEdges {
+        edges: ranks.into_iter().map(|r| edges[r]).collect::<Vec<_>>().into_iter(),
+    })
+}
+
+/**
+Finds a minimum spanning tree of an undirected graph, or a minimum spanning forest if the
+graph isn't connected. See [minimum_spanning_edges](fn.minimum_spanning_edges.html).
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `algorithm`: the [SpanningTreeAlgorithm](enum.SpanningTreeAlgorithm.html) to use
+* `weighted`: set to `true` to use edge weights; otherwise every edge has a weight of `1.0`
+* `maximum`: set to `true` to find a maximum spanning tree instead
+
+# Returns
+
+A new `Graph` with all the nodes of `graph` and the edges of the spanning tree.
+
+# Examples
+
+```
+use graphrs::{algorithms::tree::{self, SpanningTreeAlgorithm}, generators};
+
+let graph = generators::social::karate_club_graph();
+let mst = tree::minimum_spanning_tree(&graph, SpanningTreeAlgorithm::Prim, false, false).unwrap();
+assert_eq!(mst.get_all_nodes().len(), 34);
+assert_eq!(mst.get_all_edges().len(), 33);
+```
+*/
+pub fn minimum_spanning_tree<T, A>(
+    graph: &Graph<T, A>,
+    algorithm: SpanningTreeAlgorithm,
+    weighted: bool,
+    maximum: bool,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let edges = minimum_spanning_edges(graph, algorithm, weighted, maximum)?.cloned().collect();
+    let nodes = graph.get_all_nodes().into_iter().cloned().collect();
+    Graph::new_from_nodes_and_edges(nodes, edges, graph.specs.clone())
+}
+
+/**
+Uses Borůvka's algorithm to find a minimum spanning forest of the nodes `0..num_nodes`.
+Edge `i` joins `endpoints[i]`, and lower numbered edges are lighter.
+Returns the edges of the forest.
+*/
+fn boruvka(num_nodes: usize, endpoints: &[(usize, usize)]) -> Vec<usize> {
+    let mut union_find = UnionFind::new(num_nodes);
+    let mut forest = vec![];
+    loop {
+        let mut lightest: HashMap<usize, usize> = HashMap::new();
+        for (i, (u, v)) in endpoints.iter().enumerate() {
+            let u_root = union_find.find(*u);
+            let v_root = union_find.find(*v);
+            if u_root != v_root {
+                for root in [u_root, v_root] {
+                    lightest.entry(root).or_insert(i);
+                }
+            }
+        }
+        if lightest.is_empty() {
+            return forest;
+        }
+        let mut candidates: Vec<usize> = lightest.into_values().collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+        for i in candidates {
+            let (u, v) = endpoints[i];
+            if union_find.find(u) != union_find.find(v) {
+                union_find.union(u, v);
+                forest.push(i);
+            }
+        }
+    }
+}
+
+/**
+Uses Kruskal's algorithm to find a minimum spanning forest of the nodes `0..num_nodes`.
+Edge `i` joins `endpoints[i]`, and lower numbered edges are lighter.
+Returns the edges of the forest.
+*/
+fn kruskal(num_nodes: usize, endpoints: &[(usize, usize)]) -> Vec<usize> {
+    let mut union_find = UnionFind::new(num_nodes);
+    let mut forest = vec![];
+    for (i, (u, v)) in endpoints.iter().enumerate() {
+        if union_find.find(*u) != union_find.find(*v) {
+            union_find.union(*u, *v);
+            forest.push(i);
+        }
+    }
+    forest
+}
+
+/**
+Uses Prim's algorithm to find a minimum spanning forest of the nodes `0..num_nodes`,
+growing a tree from each node that isn't yet in the forest.
+Edge `i` joins `endpoints[i]`, and lower numbered edges are lighter.
+Returns the edges of the forest.
+*/
+fn prim(num_nodes: usize, endpoints: &[(usize, usize)]) -> Vec<usize> {
+    let mut node_edges: Vec<Vec<usize>> = vec![vec![]; num_nodes];
+    for (i, (u, v)) in endpoints.iter().enumerate() {
+        node_edges[*u].push(i);
+        node_edges[*v].push(i);
+    }
+    let mut in_forest = vec![false; num_nodes];
+    let mut forest = vec![];
+    for start in 0..num_nodes {
+        if in_forest[start] {
+            continue;
+        }
+        in_forest[start] = true;
+        let mut heap: BinaryHeap<Reverse<usize>> =
+            node_edges[start].iter().map(|i| Reverse(*i)).collect();
+        while let Some(Reverse(i)) = heap.pop() {
+            let (u, v) = endpoints[i];
+            let new_node = match (in_forest[u], in_forest[v]) {
+                (true, false) => v,
+                (false, true) => u,
+                _ => continue,
+            };
+            in_forest[new_node] = true;
+            forest.push(i);
+            heap.extend(node_edges[new_node].iter().map(|i| Reverse(*i)));
+        }
+    }
+    forest
+}
+
+// tests for private methods only; other tests are in:
+// tests/test_algorithms_tree.rs
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn get_endpoints() -> Vec<(usize, usize)> {
+        vec![(0, 1), (1, 2), (0, 2), (2, 3), (4, 5), (3, 4), (1, 3)]
+    }
+
+    #[test]
+    fn test_boruvka() {
+        let mut forest = boruvka(7, &get_endpoints());
+        forest.sort_unstable();
+        assert_eq!(forest, vec![0, 1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_kruskal() {
+        assert_eq!(kruskal(7, &get_endpoints()), vec![0, 1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_prim() {
+        assert_eq!(prim(7, &get_endpoints()), vec![0, 1, 3, 5, 4]);
+    }
+}
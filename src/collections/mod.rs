@@ -0,0 +1,2 @@
+mod union_find;
+pub use union_find::UnionFind;
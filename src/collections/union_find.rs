@@ -0,0 +1,172 @@
+use crate::algorithms::components::union_find::UnionFind as IndexUnionFind;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+/**
+A disjoint-set (union-find) structure: a collection of elements split into sets that can be
+merged, with path compression and union by rank so that each operation takes nearly
+constant time.
+
+This is the structure behind Kruskal's minimum spanning tree algorithm and connected
+components, and it can be driven directly with a stream of edges, for example to track the
+components of a graph as its edges arrive or to find when a percolating cluster forms.
+Elements are added with `insert`, or by `union` when they aren't already present.
+
+# Examples
+
+```
+use graphrs::collections::UnionFind;
+
+let mut uf: UnionFind<&str> = UnionFind::new();
+uf.union("a", "b");
+uf.union("c", "d");
+uf.insert("e");
+assert!(uf.connected(&"a", &"b"));
+assert!(!uf.connected(&"b", &"c"));
+uf.union("b", "c");
+assert!(uf.connected(&"a", &"d"));
+assert_eq!(uf.num_sets(), 2);
+assert_eq!(uf.set_size(&"a"), Some(4));
+assert_eq!(uf.sets(), vec![vec!["a", "b", "c", "d"], vec!["e"]]);
+```
+*/
+pub struct UnionFind<T> {
+    elements: Vec<T>,
+    indexes: HashMap<T, usize>,
+    inner: IndexUnionFind,
+    num_sets: usize,
+}
+
+impl<T> UnionFind<T>
+where
+    T: Hash + Eq + Clone + Ord,
+{
+    /// Creates an empty `UnionFind`.
+    pub fn new() -> UnionFind<T> {
+        UnionFind {
+            elements: vec![],
+            indexes: HashMap::new(),
+            inner: IndexUnionFind::new(0),
+            num_sets: 0,
+        }
+    }
+
+    /// Adds `x` in a set of its own. Returns `false` if `x` was already present, in which
+    /// case its set isn't changed.
+    pub fn insert(&mut self, x: T) -> bool {
+        if self.indexes.contains_key(&x) {
+            return false;
+        }
+        let index = self.inner.push();
+        self.indexes.insert(x.clone(), index);
+        self.elements.push(x);
+        self.num_sets += 1;
+        true
+    }
+
+    /// Returns `true` if `x` has been added.
+    pub fn contains(&self, x: &T) -> bool {
+        self.indexes.contains_key(x)
+    }
+
+    /// Returns the representative element of the set that contains `x`, or `None` if `x`
+    /// hasn't been added. Two elements are in the same set if they have the same
+    /// representative; the representative of a set can change when it's merged.
+    pub fn find(&mut self, x: &T) -> Option<&T> {
+        let index = *self.indexes.get(x)?;
+        Some(&self.elements[self.inner.find(index)])
+    }
+
+    /**
+    Merges the sets that contain `x` and `y`, adding `x` and `y` if they aren't present.
+
+    # Returns
+
+    `true` if the sets were merged, or `false` if `x` and `y` were already in the same set.
+    */
+    pub fn union(&mut self, x: T, y: T) -> bool {
+        self.insert(x.clone());
+        self.insert(y.clone());
+        let merged = self.inner.union(self.indexes[&x], self.indexes[&y]);
+        if merged {
+            self.num_sets -= 1;
+        }
+        merged
+    }
+
+    /// Returns `true` if `x` and `y` are in the same set, and `false` if they aren't or
+    /// either hasn't been added.
+    pub fn connected(&mut self, x: &T, y: &T) -> bool {
+        match (self.indexes.get(x), self.indexes.get(y)) {
+            (Some(x), Some(y)) => {
+                let (x, y) = (*x, *y);
+                self.inner.find(x) == self.inner.find(y)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the number of elements in the set that contains `x`, or `None` if `x`
+    /// hasn't been added.
+    pub fn set_size(&mut self, x: &T) -> Option<usize> {
+        let index = *self.indexes.get(x)?;
+        Some(self.inner.size(index))
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns `true` if no elements have been added.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn num_sets(&self) -> usize {
+        self.num_sets
+    }
+
+    /// Returns the sets, each sorted, and sorted by their smallest element.
+    pub fn sets(&mut self) -> Vec<Vec<T>> {
+        let mut order: Vec<usize> = (0..self.elements.len()).collect();
+        order.sort_unstable_by(|a, b| self.elements[*a].cmp(&self.elements[*b]));
+        let mut sets: Vec<Vec<T>> = vec![];
+        let mut set_indexes: HashMap<usize, usize> = HashMap::new();
+        for index in order {
+            let root = self.inner.find(index);
+            let next_index = sets.len();
+            let set_index = *set_indexes.entry(root).or_insert(next_index);
+            if set_index == next_index {
+                sets.push(vec![]);
+            }
+            sets[set_index].push(self.elements[index].clone());
+        }
+        sets
+    }
+}
+
+impl<T> Default for UnionFind<T>
+where
+    T: Hash + Eq + Clone + Ord,
+{
+    fn default() -> Self {
+        UnionFind::new()
+    }
+}
+
+impl<T> FromIterator<T> for UnionFind<T>
+where
+    T: Hash + Eq + Clone + Ord,
+{
+    /// Creates a `UnionFind` where each element is in a set of its own.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut union_find = UnionFind::new();
+        for x in iter {
+            union_find.insert(x);
+        }
+        union_find
+    }
+}
@@ -3,14 +3,20 @@ use std::fmt;
 use std::fmt::{Debug, Display};
 use std::hash::{Hash, Hasher};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /**
 Represents a graph edge as (`u`, `v`).
 
 Also allows `attributes`, as a `HashMap`, to be stored on an edge.
+
+The type of the `weight` is `W`, which is `f64` by default. Other numeric types, such
+as `u64` for hop counts, can be used to compute shortest paths with exact arithmetic.
 */
 #[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Edge<T, A>
+pub struct Edge<T, A, W = f64>
 where
     T: PartialOrd + Send,
 {
@@ -21,8 +27,8 @@ where
     /// Any attributes of the edge.
     pub attributes: Option<A>,
     /// The edge weight. For weighted `Graph` this should be a real number.
-    /// For an unweighted `Graph` this should be `f64:NAN`.
-    pub weight: f64,
+    /// For an unweighted `Graph` with `f64` weights this should be `f64:NAN`.
+    pub weight: W,
 }
 
 impl<T, A> Edge<T, A>
@@ -56,7 +62,13 @@ where
             weight: f64::NAN,
         }
     }
+}
 
+impl<T, A, W> Edge<T, A, W>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+{
     /**
     Returns (v, u) if u > v.
 
@@ -68,7 +80,7 @@ where
     assert_eq!(edge2.v, "n2");
     ```
     */
-    pub fn ordered(self: Edge<T, A>) -> Edge<T, A> {
+    pub fn ordered(self: Edge<T, A, W>) -> Edge<T, A, W> {
         match self.u > self.v {
             true => self.reversed(),
             false => self,
@@ -85,7 +97,7 @@ where
     assert_eq!(edge2.v, "n2");
     ```
     */
-    pub fn reversed(self: Edge<T, A>) -> Edge<T, A> {
+    pub fn reversed(self: Edge<T, A, W>) -> Edge<T, A, W> {
         Edge {
             u: self.v,
             v: self.u,
@@ -112,7 +124,7 @@ where
     ];
     ```
     */
-    pub fn with_weight(u: T, v: T, weight: f64) -> Edge<T, A> {
+    pub fn with_weight(u: T, v: T, weight: W) -> Edge<T, A, W> {
         Edge {
             u,
             v,
@@ -122,15 +134,15 @@ where
     }
 }
 
-impl<T: PartialEq + PartialOrd + Send + Sync, A> PartialEq for Edge<T, A> {
+impl<T: PartialEq + PartialOrd + Send + Sync, A, W> PartialEq for Edge<T, A, W> {
     fn eq(&self, other: &Self) -> bool {
         self.u == other.u && self.v == other.v
     }
 }
 
-impl<T: Eq + PartialOrd + Send + Sync, A> Eq for Edge<T, A> {}
+impl<T: Eq + PartialOrd + Send + Sync, A, W> Eq for Edge<T, A, W> {}
 
-impl<T: Debug + PartialOrd + Send + Sync, A> fmt::Debug for Edge<T, A> {
+impl<T: Debug + PartialOrd + Send + Sync, A, W> fmt::Debug for Edge<T, A, W> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Node")
             .field("u", &self.u)
@@ -139,26 +151,26 @@ impl<T: Debug + PartialOrd + Send + Sync, A> fmt::Debug for Edge<T, A> {
     }
 }
 
-impl<T: Display + PartialOrd + Send + Sync, A> fmt::Display for Edge<T, A> {
+impl<T: Display + PartialOrd + Send + Sync, A, W> fmt::Display for Edge<T, A, W> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "({}, {})", self.u, self.v)
     }
 }
 
-impl<T: Hash + PartialOrd + Send + Sync, A> Hash for Edge<T, A> {
+impl<T: Hash + PartialOrd + Send + Sync, A, W> Hash for Edge<T, A, W> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.u.hash(state);
         self.v.hash(state);
     }
 }
 
-impl<T: Eq + PartialEq + PartialOrd + Send + Sync, A> PartialOrd for Edge<T, A> {
+impl<T: Eq + PartialEq + PartialOrd + Send + Sync, A, W> PartialOrd for Edge<T, A, W> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<T: Eq + PartialOrd + Send + Sync, A> Ord for Edge<T, A> {
+impl<T: Eq + PartialOrd + Send + Sync, A, W> Ord for Edge<T, A, W> {
     fn cmp(&self, other: &Self) -> Ordering {
         let u_cmp = self.u.partial_cmp(&other.u).unwrap();
         match u_cmp {
@@ -21,13 +21,29 @@ analyzing [Graph](./struct.Graph.html) objects.
 */
 #[derive(Clone, Debug)]
 pub enum ErrorKind {
+    /// An algorithm was stopped through its [ProgressHandle](./struct.ProgressHandle.html)
+    /// before it finished.
+    Cancelled,
     /// Contradictory paths were found when computing shortest paths.
     ContradictoryPaths,
     /// A duplicate `Edge` was added to a [Graph](./struct.Graph.html) that doesn't
     /// support multi `Edge`s.
     DuplicateEdge,
+    /// A cycle was found in a [Graph](./struct.Graph.html) when an acyclic graph was required.
+    GraphContainsCycle,
+    /// An algorithm that requires a bipartite [Graph](./struct.Graph.html) was given one
+    /// that isn't.
+    GraphNotBipartite,
+    /// An algorithm that requires a connected (or, if directed, strongly connected)
+    /// [Graph](./struct.Graph.html) was given one that isn't.
+    GraphNotConnected,
     /// An argument to a function was not a valid value.
     InvalidArgument,
+    /// A cycle whose edge weights sum to a negative value was found while computing
+    /// shortest paths.
+    NegativeCycle,
+    /// No spanning arborescence rooted at the specified [Node](./struct.Node.html) exists.
+    NoArborescence,
     /// A [Node](./struct.Node.html) was requested from a [Graph](./struct.Graph.html) but the
     /// [Node](./struct.Node.html) doesn't exist.
     NodeNotFound,
@@ -58,11 +74,17 @@ pub enum ErrorKind {
 impl Display for ErrorKind {
     fn fmt(&self, f: &mut Formatter) -> Result {
         match self {
+            ErrorKind::Cancelled => write!(f, "algorithm was cancelled"),
             ErrorKind::ContradictoryPaths => write!(f, "contradictory paths"),
             ErrorKind::DuplicateEdge => write!(f, "duplicate edge detected"),
             ErrorKind::EdgeNotFound => write!(f, "edge not found"),
             ErrorKind::EdgeWeightNotSpecified => write!(f, "edge weight not found"),
+            ErrorKind::GraphContainsCycle => write!(f, "graph contains a cycle"),
+            ErrorKind::GraphNotBipartite => write!(f, "graph is not bipartite"),
+            ErrorKind::GraphNotConnected => write!(f, "graph is not connected"),
             ErrorKind::InvalidArgument => write!(f, "invalid argument"),
+            ErrorKind::NegativeCycle => write!(f, "negative cycle detected"),
+            ErrorKind::NoArborescence => write!(f, "no spanning arborescence was found"),
             ErrorKind::NodeNotFound => write!(f, "node not found"),
             ErrorKind::NoPartitions => write!(f, "no partitions were found"),
             ErrorKind::NotAPartition => write!(f, "communities were not a partition"),
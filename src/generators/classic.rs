@@ -1,4 +1,4 @@
-use crate::{Edge, Graph, GraphSpecs, MissingNodeStrategy};
+use crate::{Edge, Graph, GraphSpecs, MissingNodeStrategy, Node};
 use itertools::Itertools;
 
 /**
@@ -22,10 +22,7 @@ pub fn complete_graph(num_nodes: i32, directed: bool) -> Graph<i32, ()> {
         true => (0..num_nodes).permutations(2).collect::<Vec<Vec<i32>>>(),
     };
     let nodes = vec![];
-    let edges = x
-        .into_iter()
-        .map(|x| Edge::new(x[0], x[1]))
-        .collect::<Vec<Edge<i32, ()>>>();
+    let edges = x.into_iter().map(|x| Edge::new(x[0], x[1])).collect::<Vec<Edge<i32, ()>>>();
     let specs = match directed {
         false => GraphSpecs {
             missing_node_strategy: MissingNodeStrategy::Create,
@@ -38,3 +35,151 @@ pub fn complete_graph(num_nodes: i32, directed: bool) -> Graph<i32, ()> {
     };
     Graph::new_from_nodes_and_edges(nodes, edges, specs).unwrap()
 }
+
+/**
+Generates a cycle graph: the nodes `0..num_nodes` connected in a ring. If there are fewer
+than three nodes the nodes are connected in a path instead.
+
+# Arguments
+
+* `num_nodes`: The number of nodes to generate.
+* `directed`: `true` for a directed graph, `false` for an undirected graph
+
+# Examples
+
+```
+use graphrs::{generators};
+let graph = generators::classic::cycle_graph(5, false);
+assert_eq!(graph.get_all_edges().len(), 5);
+```
+*/
+pub fn cycle_graph(num_nodes: i32, directed: bool) -> Graph<i32, ()> {
+    if num_nodes < 3 {
+        return path_graph(num_nodes, directed);
+    }
+    let edges = (0..num_nodes).map(|i| (i, (i + 1) % num_nodes)).collect();
+    get_graph(num_nodes, edges, directed)
+}
+
+/**
+Generates a path graph: the nodes `0..num_nodes` connected in a line.
+
+# Arguments
+
+* `num_nodes`: The number of nodes to generate.
+* `directed`: `true` for a directed graph, `false` for an undirected graph
+
+# Examples
+
+```
+use graphrs::{generators};
+let graph = generators::classic::path_graph(5, true);
+assert_eq!(graph.get_all_edges().len(), 4);
+```
+*/
+pub fn path_graph(num_nodes: i32, directed: bool) -> Graph<i32, ()> {
+    let edges = (1..num_nodes).map(|i| (i - 1, i)).collect();
+    get_graph(num_nodes, edges, directed)
+}
+
+/**
+Generates a star graph: a center node, `0`, connected to `num_outer_nodes` other nodes.
+
+# Arguments
+
+* `num_outer_nodes`: The number of nodes to connect to the center node.
+* `directed`: `true` for a directed graph, with edges from the center node, `false` for an
+  undirected graph
+
+# Examples
+
+```
+use graphrs::{generators};
+let graph = generators::classic::star_graph(5, false);
+assert_eq!(graph.get_all_nodes().len(), 6);
+assert_eq!(graph.get_node_degree(0), Some(5));
+```
+*/
+pub fn star_graph(num_outer_nodes: i32, directed: bool) -> Graph<i32, ()> {
+    let edges = (1..=num_outer_nodes).map(|i| (0, i)).collect();
+    get_graph(num_outer_nodes.max(0) + 1, edges, directed)
+}
+
+/**
+Generates a two-dimensional grid graph, where each node is connected to the nodes above,
+below, left and right of it. The node in row `r` and column `c` is named `r * num_cols + c`.
+
+# Arguments
+
+* `num_rows`: The number of rows in the grid.
+* `num_cols`: The number of columns in the grid.
+* `directed`: `true` for a directed graph, with edges that go down and to the right, `false`
+  for an undirected graph
+
+# Examples
+
+```
+use graphrs::{generators};
+let graph = generators::classic::grid_2d_graph(3, 4, false);
+assert_eq!(graph.get_all_nodes().len(), 12);
+assert_eq!(graph.get_all_edges().len(), 17);
+```
+*/
+pub fn grid_2d_graph(num_rows: i32, num_cols: i32, directed: bool) -> Graph<i32, ()> {
+    let mut edges = vec![];
+    for r in 0..num_rows {
+        for c in 0..num_cols {
+            let node = r * num_cols + c;
+            if c + 1 < num_cols {
+                edges.push((node, node + 1));
+            }
+            if r + 1 < num_rows {
+                edges.push((node, node + num_cols));
+            }
+        }
+    }
+    get_graph(num_rows.max(0) * num_cols.max(0), edges, directed)
+}
+
+/**
+Generates a perfectly balanced tree, where every node other than the leaves has
+`branching_factor` children. The root is node `0` and the children of node `i` are
+`i * branching_factor + 1` to `i * branching_factor + branching_factor`.
+
+# Arguments
+
+* `branching_factor`: The number of children of each node.
+* `height`: The number of edges on the path from the root to each leaf.
+* `directed`: `true` for a directed graph, with edges from parents to children, `false` for
+  an undirected graph
+
+# Examples
+
+```
+use graphrs::{generators};
+let graph = generators::classic::balanced_tree(2, 3, false);
+assert_eq!(graph.get_all_nodes().len(), 15);
+assert_eq!(graph.get_all_edges().len(), 14);
+```
+*/
+pub fn balanced_tree(branching_factor: i32, height: i32, directed: bool) -> Graph<i32, ()> {
+    let mut num_nodes = 1;
+    let mut level_size = 1;
+    for _ in 0..height {
+        level_size *= branching_factor.max(0);
+        num_nodes += level_size;
+    }
+    let edges = (1..num_nodes).map(|i| ((i - 1) / branching_factor, i)).collect();
+    get_graph(num_nodes, edges, directed)
+}
+
+/// Creates a graph with the nodes `0..num_nodes` and the specified edges.
+fn get_graph(num_nodes: i32, edges: Vec<(i32, i32)>, directed: bool) -> Graph<i32, ()> {
+    let specs = match directed {
+        false => GraphSpecs::undirected(),
+        true => GraphSpecs::directed(),
+    };
+    let nodes = (0..num_nodes).map(Node::from_name).collect();
+    let edges = edges.into_iter().map(|(u, v)| Edge::new(u, v)).collect::<Vec<Edge<i32, ()>>>();
+    Graph::new_from_nodes_and_edges(nodes, edges, specs).unwrap()
+}
@@ -1,6 +1,121 @@
+use crate::algorithms::components;
 use crate::{Error, ErrorKind, Graph, GraphSpecs, Node};
 use rand::{Rng, RngCore, SeedableRng};
 use rand_chacha::ChaCha20Rng;
+use std::collections::HashSet;
+
+/**
+Returns an Erdos-Renyi or binomial random graph, where each possible edge is created
+with probability `edge_probability`.
+
+Rather than considering every pair of nodes, the number of pairs to skip before the next
+edge is sampled from a geometric distribution, so the graph is generated in O(n + m) time.
+
+# Arguments
+
+* `num_nodes`: The number of nodes to generate, named `0..num_nodes`.
+* `edge_probability`: The probability for edge creation, between 0.0 and 1.0.
+* `seed`: The seed for the random number generator; if `None` the graph is different each time.
+* `directed`: If `true` generates a directed graph, otherwise it generates an undirected graph.
+
+# Examples
+
+```
+use graphrs::{generators};
+let graph = generators::random::gnp_random_graph(100, 0.1, Some(1), false).unwrap();
+assert_eq!(graph.get_all_nodes().len(), 100);
+```
+
+# References
+
+1. V. Batagelj and U. Brandes. Efficient generation of large random networks.
+   Physical Review E 71:036113, 2005.
+*/
+pub fn gnp_random_graph(
+    num_nodes: usize,
+    edge_probability: f64,
+    seed: Option<u64>,
+    directed: bool,
+) -> Result<Graph<usize, ()>, Error> {
+    if !(0.0..=1.0).contains(&edge_probability) {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!(
+                "`edge_probability` was {} but it must be between 0.0 and 1.0.",
+                edge_probability
+            ),
+        });
+    }
+    let mut rng = get_random_number_generator(seed);
+    let num_pairs = get_num_pairs(num_nodes, directed);
+    let mut edges = vec![];
+    if edge_probability > 0.0 {
+        let lp = (1.0 - edge_probability).ln();
+        let mut index: usize = 0;
+        loop {
+            let lr: f64 = (1.0_f64 - rng.gen::<f64>()).ln();
+            index = index.saturating_add((lr / lp) as usize);
+            if index >= num_pairs {
+                break;
+            }
+            edges.push(get_pair(index, num_nodes, directed));
+            index += 1;
+        }
+    }
+    get_graph_with_edges(num_nodes, edges, directed)
+}
+
+/**
+Returns a random graph chosen uniformly from all the graphs with `num_nodes` nodes and
+`num_edges` edges. If `num_edges` is more than the number of possible edges then a
+complete graph is returned.
+
+# Arguments
+
+* `num_nodes`: The number of nodes to generate, named `0..num_nodes`.
+* `num_edges`: The number of edges to generate.
+* `seed`: The seed for the random number generator; if `None` the graph is different each time.
+* `directed`: If `true` generates a directed graph, otherwise it generates an undirected graph.
+
+# Examples
+
+```
+use graphrs::{generators};
+let graph = generators::random::gnm_random_graph(100, 250, Some(1), true).unwrap();
+assert_eq!(graph.get_all_nodes().len(), 100);
+assert_eq!(graph.get_all_edges().len(), 250);
+```
+*/
+pub fn gnm_random_graph(
+    num_nodes: usize,
+    num_edges: usize,
+    seed: Option<u64>,
+    directed: bool,
+) -> Result<Graph<usize, ()>, Error> {
+    let mut rng = get_random_number_generator(seed);
+    let num_pairs = get_num_pairs(num_nodes, directed);
+    let num_edges = num_edges.min(num_pairs);
+    // when more than half of the pairs are edges, choose the pairs that aren't edges instead
+    let sparse = num_edges * 2 <= num_pairs;
+    let num_to_choose = match sparse {
+        true => num_edges,
+        false => num_pairs - num_edges,
+    };
+    let mut chosen = HashSet::new();
+    while chosen.len() < num_to_choose {
+        chosen.insert(rng.gen_range(0..num_pairs));
+    }
+    let indexes: Vec<usize> = match sparse {
+        true => {
+            let mut indexes: Vec<usize> = chosen.into_iter().collect();
+            indexes.sort_unstable();
+            indexes
+        }
+        false => (0..num_pairs).filter(|i| !chosen.contains(i)).collect(),
+    };
+    let edges = indexes.into_iter().map(|i| get_pair(i, num_nodes, directed)).collect();
+    get_graph_with_edges(num_nodes, edges, directed)
+}
 
 /**
 Returns an Erdos-Renyi or binomial random graph.
@@ -107,6 +222,221 @@ fn fast_gnp_random_graph_undirected(
     }
 }
 
+/**
+Returns a Watts–Strogatz small-world graph.
+
+The nodes are first arranged in a ring, with each node connected to its `k / 2` nearest
+neighbors on each side. Then each edge `(u, v)` is rewired, with probability
+`rewire_probability`, by replacing `v` with a node chosen at random, avoiding self-loops
+and duplicate edges.
+
+# Arguments
+
+* `num_nodes`: The number of nodes to generate, named `0..num_nodes`.
+* `k`: Each node is connected to its `k` nearest neighbors in the ring; if `k` is odd then
+  `k - 1` is used.
+* `rewire_probability`: The probability of rewiring each edge, between 0.0 and 1.0.
+* `seed`: The seed for the random number generator; if `None` the graph is different each time.
+
+# Examples
+
+```
+use graphrs::{generators};
+let graph = generators::random::watts_strogatz_graph(100, 4, 0.1, Some(1)).unwrap();
+assert_eq!(graph.get_all_nodes().len(), 100);
+assert_eq!(graph.get_all_edges().len(), 200);
+```
+
+# References
+
+1. D. J. Watts and S. H. Strogatz. Collective dynamics of 'small-world' networks.
+   Nature 393:440–442, 1998.
+*/
+pub fn watts_strogatz_graph(
+    num_nodes: usize,
+    k: usize,
+    rewire_probability: f64,
+    seed: Option<u64>,
+) -> Result<Graph<usize, ()>, Error> {
+    check_watts_strogatz_args(num_nodes, k, rewire_probability)?;
+    let mut rng = get_random_number_generator(seed);
+    get_watts_strogatz_graph(num_nodes, k, rewire_probability, &mut rng)
+}
+
+/**
+Returns a connected Watts–Strogatz small-world graph. Graphs are generated with
+[watts_strogatz_graph](fn.watts_strogatz_graph.html) until one is connected.
+
+# Arguments
+
+* `num_nodes`: The number of nodes to generate, named `0..num_nodes`.
+* `k`: Each node is connected to its `k` nearest neighbors in the ring; if `k` is odd then
+  `k - 1` is used.
+* `rewire_probability`: The probability of rewiring each edge, between 0.0 and 1.0.
+* `max_tries`: The number of graphs to generate before giving up; defaults to 100.
+* `seed`: The seed for the random number generator; if `None` the graph is different each time.
+
+# Returns
+
+An error is returned if none of the generated graphs is connected.
+
+# Examples
+
+```
+use graphrs::{algorithms::components, generators};
+let graph =
+    generators::random::connected_watts_strogatz_graph(100, 4, 0.5, None, Some(1)).unwrap();
+assert_eq!(components::number_connected_components(&graph).unwrap(), 1);
+```
+*/
+pub fn connected_watts_strogatz_graph(
+    num_nodes: usize,
+    k: usize,
+    rewire_probability: f64,
+    max_tries: Option<usize>,
+    seed: Option<u64>,
+) -> Result<Graph<usize, ()>, Error> {
+    check_watts_strogatz_args(num_nodes, k, rewire_probability)?;
+    let _max_tries = max_tries.unwrap_or(100);
+    let mut rng = get_random_number_generator(seed);
+    for _ in 0.._max_tries {
+        let graph = get_watts_strogatz_graph(num_nodes, k, rewire_probability, &mut rng)?;
+        if components::number_connected_components(&graph)? <= 1 {
+            return Ok(graph);
+        }
+    }
+    Err(Error {
+        kind: ErrorKind::InvalidArgument,
+        message: format!(
+            "A connected graph wasn't generated in {} tries.",
+            _max_tries
+        ),
+    })
+}
+
+fn check_watts_strogatz_args(
+    num_nodes: usize,
+    k: usize,
+    rewire_probability: f64,
+) -> Result<(), Error> {
+    if k > num_nodes {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!(
+                "`k` was {} but it can't be more than `num_nodes`, {}.",
+                k, num_nodes
+            ),
+        });
+    }
+    if !(0.0..=1.0).contains(&rewire_probability) {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!(
+                "`rewire_probability` was {} but it must be between 0.0 and 1.0.",
+                rewire_probability
+            ),
+        });
+    }
+    Ok(())
+}
+
+fn get_watts_strogatz_graph(
+    num_nodes: usize,
+    k: usize,
+    rewire_probability: f64,
+    rng: &mut Box<dyn RngCore>,
+) -> Result<Graph<usize, ()>, Error> {
+    if k == num_nodes {
+        return get_graph_with_edges(
+            num_nodes,
+            (0..get_num_pairs(num_nodes, false)).map(|i| get_pair(i, num_nodes, false)).collect(),
+            false,
+        );
+    }
+    let mut neighbors: Vec<HashSet<usize>> = vec![HashSet::new(); num_nodes];
+    for j in 1..=k / 2 {
+        for u in 0..num_nodes {
+            let v = (u + j) % num_nodes;
+            neighbors[u].insert(v);
+            neighbors[v].insert(u);
+        }
+    }
+    for j in 1..=k / 2 {
+        for u in 0..num_nodes {
+            let v = (u + j) % num_nodes;
+            if !neighbors[u].contains(&v) || rng.gen::<f64>() >= rewire_probability {
+                continue;
+            }
+            if neighbors[u].len() >= num_nodes - 1 {
+                continue;
+            }
+            let mut w = rng.gen_range(0..num_nodes);
+            while w == u || neighbors[u].contains(&w) {
+                w = rng.gen_range(0..num_nodes);
+            }
+            neighbors[u].remove(&v);
+            neighbors[v].remove(&u);
+            neighbors[u].insert(w);
+            neighbors[w].insert(u);
+        }
+    }
+    let mut edges: Vec<(usize, usize)> = neighbors
+        .iter()
+        .enumerate()
+        .flat_map(|(u, vs)| vs.iter().filter(move |v| u < **v).map(move |v| (u, *v)))
+        .collect();
+    edges.sort_unstable();
+    get_graph_with_edges(num_nodes, edges, false)
+}
+
+/// Returns the number of possible edges, excluding self-loops, between `num_nodes` nodes.
+fn get_num_pairs(num_nodes: usize, directed: bool) -> usize {
+    match directed {
+        true => num_nodes * num_nodes.saturating_sub(1),
+        false => num_nodes * num_nodes.saturating_sub(1) / 2,
+    }
+}
+
+/**
+Returns the pair of nodes at position `index` in an ordering of all the possible edges
+between `num_nodes` nodes. For undirected graphs the pairs are `(u, v)` where `u < v`.
+*/
+fn get_pair(index: usize, num_nodes: usize, directed: bool) -> (usize, usize) {
+    if directed {
+        let u = index / (num_nodes - 1);
+        let r = index % (num_nodes - 1);
+        return match r < u {
+            true => (u, r),
+            false => (u, r + 1),
+        };
+    }
+    // undirected pairs are ordered by `v` then `u`; the pairs with `v` start at v(v - 1) / 2
+    let mut v = ((1.0 + (1.0 + 8.0 * index as f64).sqrt()) / 2.0) as usize;
+    while v * (v - 1) / 2 > index {
+        v -= 1;
+    }
+    while (v + 1) * v / 2 <= index {
+        v += 1;
+    }
+    (index - v * (v - 1) / 2, v)
+}
+
+/// Creates a graph with the nodes `0..num_nodes` and the specified edges.
+fn get_graph_with_edges(
+    num_nodes: usize,
+    edges: Vec<(usize, usize)>,
+    directed: bool,
+) -> Result<Graph<usize, ()>, Error> {
+    let specs = match directed {
+        true => GraphSpecs::directed_create_missing(),
+        false => GraphSpecs::undirected_create_missing(),
+    };
+    let mut graph = Graph::new(specs);
+    graph.add_nodes((0..num_nodes).map(Node::from_name).collect());
+    graph.add_edge_tuples(edges)?;
+    Ok(graph)
+}
+
 fn get_random_number_generator(seed: Option<u64>) -> Box<dyn RngCore> {
     match seed {
         None => Box::new(rand::thread_rng()),
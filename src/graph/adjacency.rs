@@ -0,0 +1,279 @@
+use super::Graph;
+use crate::{Edge, Error, ErrorKind};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Specifies which edges of a directed graph are followed from a node.
+
+`Outgoing`: follow the edges from the node to its successors.
+
+`Incoming`: follow the edges to the node from its predecessors, so that a search finds the
+nodes that can reach the node rather than the nodes it can reach.
+
+Both directions follow every edge of an undirected graph.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    Outgoing,
+    Incoming,
+}
+
+impl<T, A, W> Graph<T, A, W>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+    W: Copy + PartialOrd,
+{
+    /**
+    Returns an iterator over the names of the nodes that connect to `node_name`, without
+    allocating a `Vec`. This is the iterator equivalent of
+    [get_neighbor_nodes](#method.get_neighbor_nodes), but the names aren't sorted.
+
+    # Arguments
+
+    * `node_name`: the name of the node to find neighbors for
+
+    # Returns
+
+    For an undirected graph the adjacent nodes; for a directed graph the predecessors and
+    successors, each once. An `Error` with a `kind` of `ErrorKind::NodeNotFound` is returned
+    if `node_name` isn't in the graph.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_edges(vec![
+        Edge::new("n1", "n2"),
+        Edge::new("n2", "n1"),
+        Edge::new("n2", "n3"),
+    ]);
+    let mut neighbors: Vec<&&str> = graph.neighbors_iter("n2").unwrap().collect();
+    neighbors.sort();
+    assert_eq!(neighbors, vec![&"n1", &"n3"]);
+    ```
+    */
+    pub fn neighbors_iter(&self, node_name: T) -> Result<impl Iterator<Item = &T> + '_, Error> {
+        let successors = self.get_adjacent_names(&self.successors, &node_name)?;
+        let predecessors = match self.specs.directed {
+            true => self.predecessors.get(&node_name),
+            false => None,
+        };
+        let predecessors = predecessors
+            .into_iter()
+            .flatten()
+            .filter(move |p| !successors.is_some_and(|s| s.contains(*p)));
+        Ok(successors.into_iter().flatten().chain(predecessors))
+    }
+
+    /**
+    Returns an iterator over the names of the successors of `node_name`: all v for (u, v)
+    edges where `node_name` is u. This is the iterator equivalent of
+    [get_successor_nodes](#method.get_successor_nodes).
+
+    # Arguments
+
+    * `node_name`: the name of the node to find successors for
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned for an undirected graph;
+    use `neighbors_iter` instead. One with a `kind` of `ErrorKind::NodeNotFound` is returned
+    if `node_name` isn't in the graph.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n3", "n1")]);
+    let successors: Vec<&&str> = graph.successors_iter("n1").unwrap().collect();
+    assert_eq!(successors, vec![&"n2"]);
+    ```
+    */
+    pub fn successors_iter(&self, node_name: T) -> Result<impl Iterator<Item = &T> + '_, Error> {
+        self.ensure_directed_for("successors_iter")?;
+        let successors = self.get_adjacent_names(&self.successors, &node_name)?;
+        Ok(successors.into_iter().flatten())
+    }
+
+    /**
+    Returns an iterator over the names of the predecessors of `node_name`: all u for (u, v)
+    edges where `node_name` is v. This is the iterator equivalent of
+    [get_predecessor_nodes](#method.get_predecessor_nodes).
+
+    # Arguments
+
+    * `node_name`: the name of the node to find predecessors for
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned for an undirected graph;
+    use `neighbors_iter` instead. One with a `kind` of `ErrorKind::NodeNotFound` is returned
+    if `node_name` isn't in the graph.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n3", "n1")]);
+    let predecessors: Vec<&&str> = graph.predecessors_iter("n1").unwrap().collect();
+    assert_eq!(predecessors, vec![&"n3"]);
+    ```
+    */
+    pub fn predecessors_iter(&self, node_name: T) -> Result<impl Iterator<Item = &T> + '_, Error> {
+        self.ensure_directed_for("predecessors_iter")?;
+        let predecessors = self.get_adjacent_names(&self.predecessors, &node_name)?;
+        Ok(predecessors.into_iter().flatten())
+    }
+
+    /**
+    Returns an iterator over the names of the successors of a node if the `graph` is
+    directed, or of its neighbors if the `graph` is undirected.
+
+    Returns an `Error` with a `kind` of `ErrorKind::NodeNotFound` if `node_name` isn't in the
+    graph.
+    */
+    pub fn successors_or_neighbors_iter(
+        &self,
+        node_name: T,
+    ) -> Result<impl Iterator<Item = &T> + '_, Error> {
+        let successors = self.get_adjacent_names(&self.successors, &node_name)?;
+        Ok(successors.into_iter().flatten())
+    }
+
+    /**
+    Returns an iterator over the names of the nodes that are one edge away from `node_name`
+    in `direction`: the successors of the node for `Direction::Outgoing` and its
+    predecessors for `Direction::Incoming`. For an undirected graph the neighbors of the
+    node are returned for both directions.
+
+    # Arguments
+
+    * `node_name`: the name of the node to find the adjacent nodes of
+    * `direction`: the [Direction](./enum.Direction.html) of the edges to follow
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::NodeNotFound` is returned if `node_name` isn't
+    in the graph.
+
+    # Examples
+
+    ```
+    use graphrs::{Direction, Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n3", "n1")]);
+    let incoming: Vec<&&str> = graph
+        .neighbors_directed_iter("n1", Direction::Incoming)
+        .unwrap()
+        .collect();
+    assert_eq!(incoming, vec![&"n3"]);
+    ```
+    */
+    pub fn neighbors_directed_iter(
+        &self,
+        node_name: T,
+        direction: Direction,
+    ) -> Result<impl Iterator<Item = &T> + '_, Error> {
+        let adjacency = match (direction, self.specs.directed) {
+            (Direction::Incoming, true) => &self.predecessors,
+            _ => &self.successors,
+        };
+        let names = self.get_adjacent_names(adjacency, &node_name)?;
+        Ok(names.into_iter().flatten())
+    }
+
+    /**
+    Returns an iterator over the edges that connect to `node_name`, with their weights and
+    attributes, without allocating a `Vec`. Each edge is returned once, including a
+    self-loop.
+
+    # Arguments
+
+    * `node_name`: the name of the node to find edges for
+
+    # Returns
+
+    For a directed graph the edges from `node_name` come first, followed by the edges to
+    it. An `Error` with a `kind` of `ErrorKind::NodeNotFound` is returned if `node_name`
+    isn't in the graph.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected_create_missing());
+    graph.add_edges(vec![
+        Edge::with_weight("n1", "n2", 1.0),
+        Edge::with_weight("n3", "n2", 2.5),
+        Edge::with_weight("n3", "n4", 4.0),
+    ]);
+    let total: f64 = graph.edges_of("n2").unwrap().map(|e| e.weight).sum();
+    assert_eq!(total, 3.5);
+    ```
+    */
+    pub fn edges_of(
+        &self,
+        node_name: T,
+    ) -> Result<impl Iterator<Item = &Edge<T, A, W>> + '_, Error> {
+        let successors = self.get_adjacent_names(&self.successors, &node_name)?;
+        let predecessors = match self.specs.directed {
+            true => self.predecessors.get(&node_name),
+            false => None,
+        };
+        let directed = self.specs.directed;
+        let in_name = node_name.clone();
+        let out_edges = successors.into_iter().flatten().flat_map(move |v| {
+            let key = match !directed && node_name > *v {
+                false => (node_name.clone(), v.clone()),
+                true => (v.clone(), node_name.clone()),
+            };
+            self.edges[&key].iter()
+        });
+        let in_edges = predecessors.into_iter().flatten().flat_map(move |u| match *u == in_name {
+            // self-loops were already returned with the out-edges
+            true => [].iter(),
+            false => self.edges[&(u.clone(), in_name.clone())].iter(),
+        });
+        Ok(out_edges.chain(in_edges))
+    }
+
+    /// Returns the adjacent names of `node_name` in `adjacency`, which is `None` if the node
+    /// has none, or a `NodeNotFound` error.
+    fn get_adjacent_names<'a>(
+        &self,
+        adjacency: &'a HashMap<T, HashSet<T>>,
+        node_name: &T,
+    ) -> Result<Option<&'a HashSet<T>>, Error> {
+        if !self.nodes.contains_key(node_name) {
+            return Err(Error {
+                kind: ErrorKind::NodeNotFound,
+                message: format!("Requested node '{}' was not found in the graph.", node_name),
+            });
+        }
+        Ok(adjacency.get(node_name))
+    }
+
+    fn ensure_directed_for(&self, method: &str) -> Result<(), Error> {
+        if !self.specs.directed {
+            return Err(Error {
+                kind: ErrorKind::WrongMethod,
+                message: format!(
+                    "For undirected graphs use the `neighbors_iter` method instead of `{}`",
+                    method
+                ),
+            });
+        }
+        Ok(())
+    }
+}
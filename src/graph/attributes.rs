@@ -0,0 +1,100 @@
+use super::Graph;
+use crate::{Error, ErrorKind};
+use std::fmt::Display;
+use std::hash::Hash;
+
+impl<T, A, W> Graph<T, A, W>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+    W: Copy + PartialOrd,
+{
+    /**
+    Gets the attributes of a node.
+
+    # Arguments
+
+    * `name`: the name of the node
+
+    # Returns
+
+    `None` if the node doesn't exist or has no attributes.
+
+    # Examples
+
+    ```
+    use graphrs::{Graph, GraphSpecs, Node};
+
+    let mut graph: Graph<&str, i32> = Graph::new(GraphSpecs::directed());
+    graph.add_node(Node::from_name_and_attributes("n1", 5));
+    assert_eq!(graph.get_node_attributes("n1"), Some(&5));
+    ```
+    */
+    pub fn get_node_attributes(&self, name: T) -> Option<&A> {
+        self.nodes.get(&name).and_then(|n| n.attributes.as_ref())
+    }
+
+    /**
+    Gets a mutable reference to the attributes of a node, so they can be updated in place.
+
+    # Arguments
+
+    * `name`: the name of the node
+
+    # Returns
+
+    `None` if the node doesn't exist or has no attributes; use `set_node_attribute` to
+    give a node attributes.
+
+    # Examples
+
+    ```
+    use graphrs::{Graph, GraphSpecs, Node};
+
+    let mut graph: Graph<&str, Vec<&str>> = Graph::new(GraphSpecs::directed());
+    graph.add_node(Node::from_name_and_attributes("n1", vec!["red"]));
+    graph.get_node_attributes_mut("n1").unwrap().push("large");
+    assert_eq!(graph.get_node_attributes("n1").unwrap(), &vec!["red", "large"]);
+    ```
+    */
+    pub fn get_node_attributes_mut(&mut self, name: T) -> Option<&mut A> {
+        self.nodes.get_mut(&name).and_then(|n| n.attributes.as_mut())
+    }
+
+    /**
+    Sets the attributes of a node, replacing any it already has.
+
+    # Arguments
+
+    * `name`: the name of the node
+    * `attributes`: the node's new attributes
+
+    # Returns
+
+    If the node doesn't exist, `Err` is returned.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, &str> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_edge(Edge::new("n1", "n2")).unwrap();
+    graph.set_node_attribute("n1", "blue").unwrap();
+    assert_eq!(graph.get_node("n1").unwrap().attributes, Some("blue"));
+    assert!(graph.set_node_attribute("n3", "red").is_err());
+    ```
+    */
+    pub fn set_node_attribute(&mut self, name: T, attributes: A) -> Result<(), Error> {
+        match self.nodes.get_mut(&name) {
+            None => Err(Error {
+                kind: ErrorKind::NodeNotFound,
+                message: format!("Requested node '{}' was not found in the graph.", name),
+            }),
+            Some(node) => {
+                node.attributes = Some(attributes);
+                Ok(())
+            }
+        }
+    }
+}
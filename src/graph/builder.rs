@@ -0,0 +1,154 @@
+use super::convert::merge_edges;
+use super::{EdgeMergeStrategy, Graph};
+use crate::{Edge, Error, GraphSpecs, Node};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Accumulates nodes and edges and then creates a [Graph](./struct.Graph.html) from them in one
+pass, after cleaning up the edges as configured:
+
+* `symmetrize`: for a directed graph, add the reverse of every edge that doesn't start and
+  end on the same node
+* `drop_self_loops`: discard edges that start and end on the same node
+* `merge_parallel_edges`: combine the edges between the same two nodes into a single edge,
+  using an [EdgeMergeStrategy](./enum.EdgeMergeStrategy.html), instead of letting the
+  `specs.edge_dedupe_strategy` decide what happens to them
+
+These steps are applied in that order, so symmetrizing a graph that has an edge in both
+directions creates parallel edges that can then be merged. Anything not handled by the
+builder, such as missing nodes, is handled by the `specs` of the graph as usual, and any
+`Error` that adding the nodes and edges to a `Graph` would return is returned by `build`.
+
+Nodes that are added more than once keep the attributes of the last one added.
+
+# Examples
+
+```
+use graphrs::{Edge, EdgeMergeStrategy, GraphBuilder, GraphSpecs};
+
+let mut builder = GraphBuilder::<&str, ()>::new(GraphSpecs::undirected_create_missing())
+    .drop_self_loops()
+    .merge_parallel_edges(EdgeMergeStrategy::Min);
+builder.add_edges(vec![
+    Edge::with_weight("n1", "n2", 3.0),
+    Edge::with_weight("n2", "n1", 1.0),
+    Edge::with_weight("n2", "n2", 1.0),
+]);
+let graph = builder.build().unwrap();
+assert_eq!(graph.get_all_edges().len(), 1);
+assert_eq!(graph.get_edge("n1", "n2").unwrap().weight, 1.0);
+```
+*/
+pub struct GraphBuilder<T: PartialOrd + Send, A> {
+    specs: GraphSpecs,
+    nodes: Vec<Node<T, A>>,
+    edges: Vec<Edge<T, A>>,
+    symmetrize: bool,
+    drop_self_loops: bool,
+    merge_strategy: Option<EdgeMergeStrategy>,
+}
+
+impl<T, A> GraphBuilder<T, A>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+{
+    /// Creates a `GraphBuilder` for a graph with `specs` that doesn't clean up the edges.
+    pub fn new(specs: GraphSpecs) -> GraphBuilder<T, A> {
+        GraphBuilder {
+            specs,
+            nodes: vec![],
+            edges: vec![],
+            symmetrize: false,
+            drop_self_loops: false,
+            merge_strategy: None,
+        }
+    }
+
+    /// Adds the reverse of every edge, if the graph is directed.
+    pub fn symmetrize(mut self) -> GraphBuilder<T, A> {
+        self.symmetrize = true;
+        self
+    }
+
+    /// Discards the edges that start and end on the same node.
+    pub fn drop_self_loops(mut self) -> GraphBuilder<T, A> {
+        self.drop_self_loops = true;
+        self
+    }
+
+    /// Combines the edges between the same two nodes into one edge whose weight is given by
+    /// `merge_strategy` and which has the attributes of the first of them that was added.
+    pub fn merge_parallel_edges(mut self, merge_strategy: EdgeMergeStrategy) -> GraphBuilder<T, A> {
+        self.merge_strategy = Some(merge_strategy);
+        self
+    }
+
+    /// Adds a node.
+    pub fn add_node(&mut self, node: Node<T, A>) {
+        self.nodes.push(node);
+    }
+
+    /// Adds nodes.
+    pub fn add_nodes(&mut self, nodes: Vec<Node<T, A>>) {
+        self.nodes.extend(nodes);
+    }
+
+    /// Adds an edge.
+    pub fn add_edge(&mut self, edge: Edge<T, A>) {
+        self.edges.push(edge);
+    }
+
+    /// Adds edges.
+    pub fn add_edges(&mut self, edges: Vec<Edge<T, A>>) {
+        self.edges.extend(edges);
+    }
+
+    /// Adds edges, as (u, v) tuples.
+    pub fn add_edge_tuples(&mut self, edges: Vec<(T, T)>) {
+        self.edges.extend(edges.into_iter().map(|(u, v)| Edge::new(u, v)));
+    }
+
+    /**
+    Creates the [Graph](./struct.Graph.html).
+
+    # Returns
+
+    The `Error` that adding the nodes and edges to a `Graph` with the builder's `specs`
+    returns, if any. For example, an `Error` with a `kind` of `ErrorKind::SelfLoopsFound`
+    is returned for a self-loop that isn't dropped if the `specs` don't allow self-loops and
+    have a `self_loops_false_strategy` of `Error`.
+    */
+    pub fn build(self) -> Result<Graph<T, A>, Error> {
+        let mut edges = self.edges;
+        if self.symmetrize && self.specs.directed {
+            let reversed: Vec<Edge<T, A>> =
+                edges.iter().filter(|e| e.u != e.v).map(|e| e.clone().reversed()).collect();
+            edges.extend(reversed);
+        }
+        if self.drop_self_loops {
+            edges.retain(|e| e.u != e.v);
+        }
+        if let Some(merge_strategy) = self.merge_strategy {
+            let directed = self.specs.directed;
+            let mut parallel: Vec<Vec<&Edge<T, A>>> = vec![];
+            let mut indexes: HashMap<(&T, &T), usize> = HashMap::new();
+            for edge in edges.iter() {
+                let key = match !directed && edge.u > edge.v {
+                    false => (&edge.u, &edge.v),
+                    true => (&edge.v, &edge.u),
+                };
+                let next_index = parallel.len();
+                let index = *indexes.entry(key).or_insert(next_index);
+                if index == next_index {
+                    parallel.push(vec![]);
+                }
+                parallel[index].push(edge);
+            }
+            edges = parallel.iter().map(|p| merge_edges(p, merge_strategy)).collect();
+        }
+        Graph::new_from_nodes_and_edges(self.nodes, edges, self.specs)
+    }
+}
@@ -0,0 +1,239 @@
+use super::Graph;
+use crate::{Edge, Error, ErrorKind, Node, SelfLoopsFalseStrategy};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Specifies what happens to the edges between two nodes that are merged into one node, which
+become self-loops.
+
+`Keep`: keep the self-loops. If the graph doesn't support self-loops its
+`specs.self_loops_false_strategy` determines what happens, as when adding a self-loop.
+
+`Remove`: remove the self-loops.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SelfLoopPolicy {
+    Keep,
+    Remove,
+}
+
+impl<T, A> Graph<T, A>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+{
+    /**
+    Contracts the edge between `u` and `v`: `v` is merged into `u`, so that every edge
+    that connected to `v` connects to `u` instead. `u` keeps its attributes.
+
+    If `specs.multi_edges` is `false`, edges that end up between the same two nodes are
+    merged into one edge whose weight is the sum of their weights and which has the
+    attributes of one of them, preferring an edge that connected to `u`.
+
+    # Arguments
+
+    * `u`: the node that remains
+    * `v`: the node that's merged into `u`
+    * `self_loop_policy`: the [SelfLoopPolicy](./enum.SelfLoopPolicy.html) for the edges
+      between `u` and `v`, which become self-loops on `u`
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::EdgeNotFound` is returned if there's no edge
+    from `u` to `v`, and one with a `kind` of `ErrorKind::SelfLoopsFound` if a self-loop
+    would be kept in a graph whose `specs.self_loops_false_strategy` is `Error`. The graph
+    isn't changed if an error is returned.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs, SelfLoopPolicy};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected_create_missing());
+    graph.add_edges(vec![
+        Edge::with_weight("a", "b", 1.0),
+        Edge::with_weight("a", "c", 2.0),
+        Edge::with_weight("b", "c", 3.0),
+    ]).unwrap();
+    graph.contract_edge("a", "b", SelfLoopPolicy::Remove).unwrap();
+    assert_eq!(graph.get_all_nodes().len(), 2);
+    assert_eq!(graph.get_all_edges().len(), 1);
+    assert_eq!(graph.get_edge("a", "c").unwrap().weight, 5.0);
+    ```
+    */
+    pub fn contract_edge(
+        &mut self,
+        u: T,
+        v: T,
+        self_loop_policy: SelfLoopPolicy,
+    ) -> Result<(), Error> {
+        self.get_edge(u.clone(), v.clone())?;
+        let attributes = self.get_node(u.clone()).unwrap().attributes.clone();
+        self.merge_nodes_with_policy(&[u.clone(), v], u, attributes, self_loop_policy)
+    }
+
+    /**
+    Merges `nodes` into a single node named `new_name`, so that every edge that connected to
+    one of `nodes` connects to `new_name` instead. This is the operation behind graph
+    coarsening: merging each group of a partition gives a smaller graph with the same
+    overall structure.
+
+    Edges between the merged nodes become self-loops on the new node. If the graph doesn't
+    support self-loops its `specs.self_loops_false_strategy` determines what happens to
+    them, as when adding a self-loop. If `specs.multi_edges` is `false`, edges that end up
+    between the same two nodes are merged into one edge whose weight is the sum of their
+    weights and which has the attributes of one of them.
+
+    # Arguments
+
+    * `nodes`: the nodes to merge
+    * `new_name`: the name of the merged node; this can be one of `nodes`, or a name that
+      isn't in the graph
+    * `attr_merge_fn`: a function that's called with the nodes being merged, sorted by name,
+      and returns the attributes of the merged node
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::NodeNotFound` is returned if one of `nodes`
+    doesn't exist, and one with a `kind` of `ErrorKind::InvalidArgument` if `nodes` is empty
+    or `new_name` is a node of the graph that isn't in `nodes`. One with a `kind` of
+    `ErrorKind::SelfLoopsFound` is returned if a self-loop would be created in a graph whose
+    `specs.self_loops_false_strategy` is `Error`. The graph isn't changed if an error is
+    returned.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs, Node};
+
+    let mut graph: Graph<&str, i32> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_nodes(vec![
+        Node::from_name_and_attributes("a", 1),
+        Node::from_name_and_attributes("b", 2),
+    ]);
+    graph.add_edges(vec![
+        Edge::with_weight("a", "c", 1.0),
+        Edge::with_weight("b", "c", 2.0),
+        Edge::with_weight("c", "b", 4.0),
+    ]).unwrap();
+    graph.merge_nodes(&["a", "b"], "ab", |nodes| {
+        Some(nodes.iter().filter_map(|n| n.attributes).sum())
+    }).unwrap();
+    assert_eq!(graph.get_node("ab").unwrap().attributes, Some(3));
+    assert_eq!(graph.get_edge("ab", "c").unwrap().weight, 3.0);
+    assert_eq!(graph.get_edge("c", "ab").unwrap().weight, 4.0);
+    ```
+    */
+    pub fn merge_nodes<F>(
+        &mut self,
+        nodes: &[T],
+        new_name: T,
+        attr_merge_fn: F,
+    ) -> Result<(), Error>
+    where
+        F: FnOnce(Vec<&Node<T, A>>) -> Option<A>,
+    {
+        if nodes.is_empty() {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: "At least one node must be given to merge.".to_string(),
+            });
+        }
+        for node in nodes {
+            self.ensure_node(node)?;
+        }
+        if self.has_node(&new_name) && !nodes.contains(&new_name) {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!(
+                    "The new name '{}' is a node of the graph that isn't being merged.",
+                    new_name
+                ),
+            });
+        }
+        let mut merged: Vec<&Node<T, A>> =
+            nodes.iter().map(|n| self.nodes.get(n).unwrap()).collect();
+        merged.sort_unstable();
+        merged.dedup();
+        let attributes = attr_merge_fn(merged);
+        self.merge_nodes_with_policy(nodes, new_name, attributes, SelfLoopPolicy::Keep)
+    }
+
+    /// Merges `nodes`, which exist, into a node named `new_name` with `attributes`.
+    fn merge_nodes_with_policy(
+        &mut self,
+        nodes: &[T],
+        new_name: T,
+        attributes: Option<A>,
+        self_loop_policy: SelfLoopPolicy,
+    ) -> Result<(), Error> {
+        let merged: HashSet<&T> = nodes.iter().collect();
+        let rename = |name: &T| match merged.contains(name) {
+            true => new_name.clone(),
+            false => name.clone(),
+        };
+        // edges that connected to `new_name`, if it's one of `nodes`, come first so that
+        // they keep their attributes when edges are combined
+        let mut edges: Vec<&Edge<T, A>> = self
+            .get_all_edges()
+            .into_iter()
+            .filter(|e| merged.contains(&e.u) || merged.contains(&e.v))
+            .collect();
+        edges.sort_by_key(|e| (e.u != new_name && e.v != new_name, &e.u, &e.v));
+        let mut new_edges: Vec<Edge<T, A>> = vec![];
+        let mut indexes: HashMap<(T, T), usize> = HashMap::new();
+        for edge in edges {
+            let mut new_edge = Edge {
+                u: rename(&edge.u),
+                v: rename(&edge.v),
+                ..edge.clone()
+            };
+            if new_edge.u == new_edge.v {
+                if self_loop_policy == SelfLoopPolicy::Remove {
+                    continue;
+                }
+                if !self.specs.self_loops {
+                    match self.specs.self_loops_false_strategy {
+                        SelfLoopsFalseStrategy::Error => {
+                            return Err(Error {
+                                kind: ErrorKind::SelfLoopsFound,
+                                message: format!(
+                                    "Merging the nodes would create a self-loop on '{}' but \
+                                    the graph doesn't support self-loops.",
+                                    new_name
+                                ),
+                            });
+                        }
+                        SelfLoopsFalseStrategy::Drop => continue,
+                    }
+                }
+            }
+            if !self.specs.directed {
+                new_edge = new_edge.ordered();
+            }
+            let key = (new_edge.u.clone(), new_edge.v.clone());
+            match (self.specs.multi_edges, indexes.get(&key)) {
+                (false, Some(i)) => new_edges[*i].weight += new_edge.weight,
+                _ => {
+                    indexes.insert(key, new_edges.len());
+                    new_edges.push(new_edge);
+                }
+            }
+        }
+        let mut unique: Vec<&T> = merged.into_iter().collect();
+        unique.sort_unstable();
+        for node in unique {
+            self.remove_node(node.clone())?;
+        }
+        self.add_node(Node {
+            name: new_name,
+            attributes,
+        });
+        for edge in new_edges {
+            self.add_edge(edge)?;
+        }
+        Ok(())
+    }
+}
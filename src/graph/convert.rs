@@ -1,15 +1,36 @@
 use super::Graph;
-use crate::{Edge, Error, ErrorKind, GraphSpecs};
+use crate::{Edge, Error, ErrorKind, GraphSpecs, Node};
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::hash::Hash;
 
+/**
+Specifies how the weights of edges that are merged into one edge are combined, for example
+when the reciprocal edges (u, v) and (v, u) of a directed graph become a single undirected edge.
+
+`Max`: use the largest weight.
+
+`Mean`: use the mean of the weights.
+
+`Min`: use the smallest weight.
+
+`Sum`: use the sum of the weights.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EdgeMergeStrategy {
+    Max,
+    Mean,
+    Min,
+    Sum,
+}
+
 impl<T, A> Graph<T, A>
 where
     T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
     A: Clone,
 {
     /**
-    Reverses the edges in a directed graph.
+    Reverses the edges in a directed graph. In a multigraph the edges keep their keys.
 
     # Examples
 
@@ -33,14 +54,221 @@ where
             });
         }
         let new_nodes = self.get_all_nodes().into_iter().cloned().collect();
+        let new_edges = self
+            .get_all_edges_with_keys()
+            .into_iter()
+            .map(|(edge, key)| (edge.clone().reversed(), key))
+            .collect();
+        let mut graph = Graph::new(self.specs.clone());
+        graph.add_nodes(new_nodes);
+        graph.add_edges_with_keys(new_edges)?;
+        Ok(graph)
+    }
+
+    /**
+    Converts a directed graph to an undirected graph.
+
+    If `specs.multi_edges` is `false` the reciprocal edges (u, v) and (v, u) become one
+    edge, with a weight determined by `merge_strategy` and the attributes of the edge
+    whose first node is smaller. If `specs.multi_edges` is `true` every edge is kept.
+
+    # Arguments
+
+    * `merge_strategy`: the [EdgeMergeStrategy](./enum.EdgeMergeStrategy.html) that
+      combines the weights of reciprocal edges
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, EdgeMergeStrategy, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_edges(vec![
+        Edge::with_weight("n1", "n2", 1.0),
+        Edge::with_weight("n2", "n1", 3.0),
+        Edge::with_weight("n2", "n3", 2.0),
+    ]);
+    let new_graph = graph.to_undirected(EdgeMergeStrategy::Sum).unwrap();
+    assert!(!new_graph.specs.directed);
+    assert_eq!(new_graph.get_edge("n2", "n1").unwrap().weight, 4.0);
+    assert_eq!(new_graph.get_all_edges().len(), 2);
+    ```
+    */
+    pub fn to_undirected(&self, merge_strategy: EdgeMergeStrategy) -> Result<Graph<T, A>, Error> {
+        if !self.specs.directed {
+            return Err(Error {
+                kind: ErrorKind::WrongMethod,
+                message: "The `to_undirected` method is not applicable to undirected graphs."
+                    .to_string(),
+            });
+        }
+        let new_nodes = self.get_all_nodes().into_iter().cloned().collect();
+        let mut edges = self.get_all_edges();
+        edges.sort_by(|e1, e2| (&e1.u, &e1.v).cmp(&(&e2.u, &e2.v)));
+        let new_edges = match self.specs.multi_edges {
+            true => edges.into_iter().cloned().collect(),
+            false => {
+                let mut merged: HashMap<(T, T), Vec<&Edge<T, A>>> = HashMap::new();
+                for edge in edges.iter() {
+                    let key = match edge.u > edge.v {
+                        false => (edge.u.clone(), edge.v.clone()),
+                        true => (edge.v.clone(), edge.u.clone()),
+                    };
+                    merged.entry(key).or_default().push(edge);
+                }
+                merged.into_values().map(|edges| merge_edges(&edges, merge_strategy)).collect()
+            }
+        };
+        Graph::new_from_nodes_and_edges(
+            new_nodes,
+            new_edges,
+            GraphSpecs {
+                directed: false,
+                ..self.specs.clone()
+            },
+        )
+    }
+
+    /**
+    Converts an undirected graph to a directed graph, replacing each edge (u, v) with the
+    edges (u, v) and (v, u). Both edges have the weight and attributes of the original edge.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected_create_missing());
+    graph.add_edges(vec![Edge::with_weight("n1", "n2", 1.5)]);
+    let new_graph = graph.to_directed().unwrap();
+    assert!(new_graph.specs.directed);
+    assert_eq!(new_graph.get_edge("n1", "n2").unwrap().weight, 1.5);
+    assert_eq!(new_graph.get_edge("n2", "n1").unwrap().weight, 1.5);
+    ```
+    */
+    pub fn to_directed(&self) -> Result<Graph<T, A>, Error> {
+        if self.specs.directed {
+            return Err(Error {
+                kind: ErrorKind::WrongMethod,
+                message: "The `to_directed` method is not applicable to directed graphs."
+                    .to_string(),
+            });
+        }
+        let new_nodes = self.get_all_nodes().into_iter().cloned().collect();
         let new_edges = self
             .get_all_edges()
             .into_iter()
-            .map(|edge| edge.clone().reversed())
+            .flat_map(|edge| match edge.u == edge.v {
+                true => vec![edge.clone()],
+                false => vec![edge.clone(), edge.clone().reversed()],
+            })
             .collect();
+        Graph::new_from_nodes_and_edges(
+            new_nodes,
+            new_edges,
+            GraphSpecs {
+                directed: true,
+                ..self.specs.clone()
+            },
+        )
+    }
+
+    /**
+    Returns a copy of the graph with the node names replaced according to `mapping`.
+    Node and edge attributes and edge weights are kept.
+
+    If two nodes are mapped to the same new name they become one node, and the graph's
+    `specs` determine what happens to any duplicate edges or self-loops that results in.
+
+    # Arguments
+
+    * `mapping`: the new name of every node in the graph
+
+    # Returns
+
+    If `mapping` doesn't have a new name for every node, `Err` is returned.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+    use std::collections::HashMap;
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_edges(vec![Edge::with_weight("a", "b", 2.0)]);
+    let mapping: HashMap<&str, String> =
+        vec![("a", "A".to_string()), ("b", "B".to_string())].into_iter().collect();
+    let new_graph = graph.relabel_nodes(&mapping).unwrap();
+    assert_eq!(new_graph.get_edge("A".to_string(), "B".to_string()).unwrap().weight, 2.0);
+    ```
+    */
+    pub fn relabel_nodes<U>(&self, mapping: &HashMap<T, U>) -> Result<Graph<U, A>, Error>
+    where
+        U: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    {
+        let get_new_name = |name: &T| match mapping.get(name) {
+            None => Err(Error {
+                kind: ErrorKind::NodeNotFound,
+                message: format!("The mapping has no new name for node {}.", name),
+            }),
+            Some(new_name) => Ok(new_name.clone()),
+        };
+        let new_nodes = self
+            .get_all_nodes()
+            .into_iter()
+            .map(|node| {
+                Ok(Node {
+                    name: get_new_name(&node.name)?,
+                    attributes: node.attributes.clone(),
+                })
+            })
+            .collect::<Result<Vec<Node<U, A>>, Error>>()?;
+        let new_edges = self
+            .get_all_edges()
+            .into_iter()
+            .map(|edge| {
+                Ok(Edge {
+                    u: get_new_name(&edge.u)?,
+                    v: get_new_name(&edge.v)?,
+                    attributes: edge.attributes.clone(),
+                    weight: edge.weight,
+                })
+            })
+            .collect::<Result<Vec<Edge<U, A>>, Error>>()?;
         Graph::new_from_nodes_and_edges(new_nodes, new_edges, self.specs.clone())
     }
 
+    /**
+    Returns a copy of the graph with the nodes renamed to the integers `0..n`, in the order
+    of their names, along with the original names. Many algorithms and file formats work
+    best with dense integer node names.
+
+    # Returns
+
+    A tuple with the new graph and a `Vec` where item `i` is the original name of node `i`.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected_create_missing());
+    graph.add_edges(vec![Edge::new("c", "a"), Edge::new("b", "c")]);
+    let (new_graph, names) = graph.convert_node_names_to_indexes();
+    assert_eq!(names, vec!["a", "b", "c"]);
+    assert!(new_graph.get_edge(0, 2).is_ok());
+    assert!(new_graph.get_edge(1, 2).is_ok());
+    ```
+    */
+    pub fn convert_node_names_to_indexes(&self) -> (Graph<usize, A>, Vec<T>) {
+        let mut names: Vec<T> = self.get_all_node_names().into_iter().cloned().collect();
+        names.sort();
+        let mapping: HashMap<T, usize> =
+            names.iter().enumerate().map(|(i, name)| (name.clone(), i)).collect();
+        let new_graph = self.relabel_nodes(&mapping).unwrap();
+        (new_graph, names)
+    }
+
     /**
     Return a new graph with all the edge weights set to the specified value.
 
@@ -134,3 +362,25 @@ where
     let sum_weight = v.iter().map(|e| e.weight).sum();
     Edge::with_weight(k.0.clone(), k.1.clone(), sum_weight)
 }
+
+/**
+Merges edges between the same two nodes into one edge, combining their weights according
+to `merge_strategy` and keeping the attributes of the first edge.
+*/
+pub(super) fn merge_edges<T, A>(edges: &[&Edge<T, A>], merge_strategy: EdgeMergeStrategy) -> Edge<T, A>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+{
+    let weights = edges.iter().map(|e| e.weight);
+    let weight = match merge_strategy {
+        EdgeMergeStrategy::Max => weights.fold(f64::NAN, f64::max),
+        EdgeMergeStrategy::Mean => weights.sum::<f64>() / edges.len() as f64,
+        EdgeMergeStrategy::Min => weights.fold(f64::NAN, f64::min),
+        EdgeMergeStrategy::Sum => weights.sum(),
+    };
+    Edge {
+        weight,
+        ..edges[0].clone()
+    }
+}
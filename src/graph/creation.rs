@@ -1,16 +1,18 @@
 use super::Graph;
 use crate::{
     Edge, EdgeDedupeStrategy, Error, ErrorKind, GraphSpecs, MissingNodeStrategy, Node,
-    SelfLoopsFalseStrategy,
+    ParallelOptions, SelfLoopsFalseStrategy,
 };
+use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::hash::Hash;
 
-impl<T, A> Graph<T, A>
+impl<T, A, W> Graph<T, A, W>
 where
     T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
     A: Clone,
+    W: Copy + PartialOrd,
 {
     /**
     Adds an `edge` to the `Graph`.
@@ -26,7 +28,7 @@ where
     assert!(result.is_ok());
     ```
     */
-    pub fn add_edge(&mut self, edge: Edge<T, A>) -> Result<(), Error>
+    pub fn add_edge(&mut self, edge: Edge<T, A, W>) -> Result<(), Error>
     where
         T: Hash + Eq + Clone + Ord + Display,
         A: Clone,
@@ -35,13 +37,7 @@ where
         if !self.specs.self_loops && edge.u == edge.v {
             match self.specs.self_loops_false_strategy {
                 SelfLoopsFalseStrategy::Error => {
-                    return Err(Error {
-                        kind: ErrorKind::SelfLoopsFound,
-                        message: format!(
-                            "Edge ({}, {}) is a self-loop and `specs.self_loops` is false.",
-                            edge.u, edge.v
-                        ),
-                    });
+                    return Err(get_self_loop_error(&edge));
                 }
                 SelfLoopsFalseStrategy::Drop => {
                     return Ok(());
@@ -53,15 +49,7 @@ where
         if self.specs.missing_node_strategy == MissingNodeStrategy::Error
             && (!self.nodes.contains_key(&edge.u) || !self.nodes.contains_key(&edge.v))
         {
-            return Err(Error {
-                kind: ErrorKind::NodeNotFound,
-                message: format!(
-                    "While adding edge ({}, {}) one or both of the nodes was not \
-                    found in the graph. Either add the nodes or set \
-                    GraphSpecs.missing_node_strategy to `Create`.",
-                    edge.u, edge.v
-                ),
-            });
+            return Err(get_missing_node_error(&edge));
         }
         self.nodes
             .entry(edge.u.clone())
@@ -98,10 +86,9 @@ where
         // add edge
         match self.specs.multi_edges {
             true => {
-                self.edges
-                    .entry((ordered.u.clone(), ordered.v.clone()))
-                    .or_default()
-                    .push(ordered);
+                let key = (ordered.u.clone(), ordered.v.clone());
+                self.edges.entry(key.clone()).or_default().push(ordered);
+                self.push_edge_key(&key);
             }
             false => match self.get_edge(ordered.u.clone(), ordered.v.clone()).is_ok() {
                 false => {
@@ -110,15 +97,7 @@ where
                 }
                 true => match self.specs.edge_dedupe_strategy {
                     EdgeDedupeStrategy::Error => {
-                        return Err(Error {
-                            kind: ErrorKind::DuplicateEdge,
-                            message: format!(
-                                "A duplicate edge was found: {}. \
-                                Set the `GraphSpecs.edge_dedupe_strategy` if a different
-                                behavior is desired.",
-                                ordered
-                            ),
-                        });
+                        return Err(get_duplicate_edge_error(&ordered));
                     }
                     EdgeDedupeStrategy::KeepLast => {
                         self.edges
@@ -133,28 +112,46 @@ where
     }
 
     /**
-    Adds an edge, as a (u, v) tuple, to the `Graph`.
+    Adds new edges to a `Graph`, or updates existing edges, or both.
 
-    If the new edge references nodes that don't exist the graph's `specs.missing_node_strategy`
+    If the new edges reference nodes that don't exist the graph's `specs.missing_node_strategy`
     determines what happens.
 
+    # Arguments
+
+    * `edges`: the new edges to add to the graph
+
     ```
     use graphrs::{Edge, Graph, GraphSpecs};
 
     let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
-    let result = graph.add_edge_tuple("n1", "n2");
+    let result = graph.add_edges(vec![
+        Edge::new("n1", "n2"),
+        Edge::new("n2", "n3"),
+    ]);
     assert!(result.is_ok());
     ```
     */
-    pub fn add_edge_tuple(&mut self, u: T, v: T) -> Result<(), Error>
+    pub fn add_edges(&mut self, edges: Vec<Edge<T, A, W>>) -> Result<(), Error>
     where
         T: Hash + Eq + Clone + Ord + Display,
+        A: Clone,
     {
-        self.add_edge(Edge::new(u, v))
+        for edge in edges {
+            self.add_edge(edge)?;
+        }
+        Ok(())
     }
 
     /**
-    Adds new edges to a `Graph`, or updates existing edges, or both.
+    Adds new edges to a `Graph`, or updates existing edges, or both, using multiple threads.
+    This gives the same graph as [add_edges](#method.add_edges) but is much faster when
+    adding millions of edges.
+
+    The edges are sorted so that the edges of each node are next to each other, then the
+    successors and predecessors of the nodes are built in parallel and merged into the graph.
+    Unlike `add_edges`, all the edges are checked before any of them are added, so if an
+    `Err` is returned the graph is unchanged.
 
     If the new edges reference nodes that don't exist the graph's `specs.missing_node_strategy`
     determines what happens.
@@ -166,53 +163,239 @@ where
     ```
     use graphrs::{Edge, Graph, GraphSpecs};
 
-    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
-    let result = graph.add_edges(vec![
-        Edge::new("n1", "n2"),
-        Edge::new("n2", "n3"),
-    ]);
+    let mut graph: Graph<i32, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    let edges = (0..1000).map(|i| Edge::new(i, (i + 1) % 1000)).collect();
+    let result = graph.add_edges_par(edges);
     assert!(result.is_ok());
+    assert_eq!(graph.get_all_edges().len(), 1000);
+    assert_eq!(graph.get_successor_node_names(999).unwrap(), vec![&0]);
     ```
     */
-    pub fn add_edges(&mut self, edges: Vec<Edge<T, A>>) -> Result<(), Error>
+    pub fn add_edges_par(&mut self, edges: Vec<Edge<T, A, W>>) -> Result<(), Error>
     where
-        T: Hash + Eq + Clone + Ord + Display,
-        A: Clone,
+        A: Send + Sync,
+        W: Send + Sync,
+    {
+        self.add_edges_par_with_options(edges, &ParallelOptions::default())
+    }
+
+    /**
+    Adds new edges to a `Graph`, or updates existing edges, or both, like
+    [add_edges_par](#method.add_edges_par), with the threads given in `options`.
+    The `progress` of `options` isn't used.
+
+    # Arguments
+
+    * `edges`: the new edges to add to the graph
+    * `options`: a [ParallelOptions](./struct.ParallelOptions.html)
+
+    # Returns
+
+    The same as [add_edges_par](#method.add_edges_par). An `Error` with a `kind` of
+    `ErrorKind::InvalidArgument` is returned if a thread pool couldn't be built for
+    `options.parallelism`.
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs, Parallelism, ParallelOptions};
+
+    let mut graph: Graph<i32, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    let edges = (0..1000).map(|i| Edge::new(i, (i + 1) % 1000)).collect();
+    let options = ParallelOptions {
+        parallelism: Parallelism::MaxThreads(2),
+        ..ParallelOptions::default()
+    };
+    let result = graph.add_edges_par_with_options(edges, &options);
+    assert!(result.is_ok());
+    assert_eq!(graph.get_all_edges().len(), 1000);
+    ```
+    */
+    pub fn add_edges_par_with_options(
+        &mut self,
+        edges: Vec<Edge<T, A, W>>,
+        options: &ParallelOptions,
+    ) -> Result<(), Error>
+    where
+        A: Send + Sync,
+        W: Send + Sync,
+    {
+        options.install(|parallel| self.add_sorted_edges(edges, parallel))?
+    }
+
+    /// Checks, sorts and adds `edges` for `add_edges_par`, in parallel if `parallel` is `true`.
+    fn add_sorted_edges(&mut self, edges: Vec<Edge<T, A, W>>, parallel: bool) -> Result<(), Error>
+    where
+        A: Send + Sync,
+        W: Send + Sync,
     {
+        // check for self loops
+        let mut edges = match self.specs.self_loops {
+            true => edges,
+            false => match self.specs.self_loops_false_strategy {
+                SelfLoopsFalseStrategy::Error => {
+                    if let Some(i) = find_first(edges.len(), parallel, |i| edges[i].u == edges[i].v)
+                    {
+                        return Err(get_self_loop_error(&edges[i]));
+                    }
+                    edges
+                }
+                SelfLoopsFalseStrategy::Drop => match parallel {
+                    true => edges.into_par_iter().filter(|e| e.u != e.v).collect(),
+                    false => edges.into_iter().filter(|e| e.u != e.v).collect(),
+                },
+            },
+        };
+
+        // check for missing nodes
+        if self.specs.missing_node_strategy == MissingNodeStrategy::Error {
+            let nodes = &self.nodes;
+            let missing = find_first(edges.len(), parallel, |i| {
+                !nodes.contains_key(&edges[i].u) || !nodes.contains_key(&edges[i].v)
+            });
+            if let Some(i) = missing {
+                return Err(get_missing_node_error(&edges[i]));
+            }
+        }
+
+        // a stable sort keeps the edges between the same two nodes in the order they were given
+        let compare = |e1: &Edge<T, A, W>, e2: &Edge<T, A, W>| (&e1.u, &e1.v).cmp(&(&e2.u, &e2.v));
+        match parallel {
+            true => {
+                if !self.specs.directed {
+                    edges = edges.into_par_iter().map(|e| e.ordered()).collect();
+                }
+                edges.par_sort_by(compare);
+            }
+            false => {
+                if !self.specs.directed {
+                    edges = edges.into_iter().map(|e| e.ordered()).collect();
+                }
+                edges.sort_by(compare);
+            }
+        }
+
+        // check for duplicate edges
+        if !self.specs.multi_edges && self.specs.edge_dedupe_strategy == EdgeDedupeStrategy::Error {
+            let existing = &self.edges;
+            let duplicate = find_first(edges.len(), parallel, |i| {
+                let edge = &edges[i];
+                (i > 0 && edge.u == edges[i - 1].u && edge.v == edges[i - 1].v)
+                    || existing.contains_key(&(edge.u.clone(), edge.v.clone()))
+            });
+            if let Some(i) = duplicate {
+                return Err(get_duplicate_edge_error(&edges[i]));
+            }
+        }
+
+        // build the successors and predecessors of each node, in parallel if `parallel`
+        let get_arc = |e: &Edge<T, A, W>| (e.u.clone(), e.v.clone());
+        let reverse = |(u, v): &(T, T)| (v.clone(), u.clone());
+        let (arcs, reversed_arcs) = match parallel {
+            true => {
+                let arcs: Vec<(T, T)> = edges.par_iter().map(get_arc).collect();
+                let reversed_arcs: Vec<(T, T)> = arcs.par_iter().map(reverse).collect();
+                (arcs, reversed_arcs)
+            }
+            false => {
+                let arcs: Vec<(T, T)> = edges.iter().map(get_arc).collect();
+                let reversed_arcs: Vec<(T, T)> = arcs.iter().map(reverse).collect();
+                (arcs, reversed_arcs)
+            }
+        };
+        let (successors, predecessors) = match (self.specs.directed, parallel) {
+            (true, true) => rayon::join(
+                || get_adjacency_sets(arcs, true),
+                || get_adjacency_sets(reversed_arcs, true),
+            ),
+            (true, false) => (
+                get_adjacency_sets(arcs, false),
+                get_adjacency_sets(reversed_arcs, false),
+            ),
+            (false, _) => {
+                let mut arcs = arcs;
+                arcs.extend(reversed_arcs);
+                (get_adjacency_sets(arcs, parallel), vec![])
+            }
+        };
+
+        // merge them into the graph
+        for (adjacency, sets) in [
+            (&mut self.successors, successors),
+            (&mut self.predecessors, predecessors),
+        ] {
+            for (name, names) in sets {
+                self.nodes.entry(name.clone()).or_insert_with(|| Node::from_name(name.clone()));
+                match adjacency.get_mut(&name) {
+                    Some(existing) => existing.extend(names),
+                    None => {
+                        adjacency.insert(name, names);
+                    }
+                }
+            }
+        }
+        self.edges.reserve(edges.len());
         for edge in edges {
-            self.add_edge(edge)?;
+            let key = (edge.u.clone(), edge.v.clone());
+            match (self.specs.multi_edges, &self.specs.edge_dedupe_strategy) {
+                (true, _) => {
+                    self.edges.entry(key.clone()).or_default().push(edge);
+                    self.push_edge_key(&key);
+                }
+                (false, EdgeDedupeStrategy::KeepFirst) => {
+                    self.edges.entry(key).or_insert_with(|| vec![edge]);
+                }
+                (false, _) => {
+                    self.edges.insert(key, vec![edge]);
+                }
+            }
         }
+
         Ok(())
     }
 
     /**
-    Adds new edges to a `Graph`, or updates existing edges, or both.
-
-    If the new edges reference nodes that don't exist the graph's `specs.missing_node_strategy`
-    determines what happens.
+    Adds new edges to a `Graph`, or updates existing edges, or both. Nodes that are
+    created because they are referenced by an edge, when `specs.missing_node_strategy` is
+    `Create`, get the attributes returned by `node_attributes`.
 
     # Arguments
 
     * `edges`: the new edges to add to the graph
+    * `node_attributes`: a function that is called with the name of each new node and
+      returns its attributes
 
     ```
     use graphrs::{Edge, Graph, GraphSpecs};
 
-    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
-    let result = graph.add_edge_tuples(vec![
-        ("n1", "n2"),
-        ("n2", "n3"),
-    ]);
+    let mut graph: Graph<&str, String> = Graph::new(GraphSpecs::directed_create_missing());
+    let result = graph.add_edges_with_node_attributes(
+        vec![Edge::new("n1", "n2"), Edge::new("n2", "n3")],
+        |name| name.to_uppercase(),
+    );
     assert!(result.is_ok());
+    assert_eq!(graph.get_node("n3").unwrap().attributes, Some("N3".to_string()));
     ```
     */
-    pub fn add_edge_tuples(&mut self, edges: Vec<(T, T)>) -> Result<(), Error>
+    pub fn add_edges_with_node_attributes<F>(
+        &mut self,
+        edges: Vec<Edge<T, A, W>>,
+        node_attributes: F,
+    ) -> Result<(), Error>
     where
-        T: Hash + Eq + Clone + Ord + Display,
-        A: Clone,
+        F: Fn(&T) -> A,
     {
         for edge in edges {
-            self.add_edge(Edge::new(edge.0, edge.1))?;
+            let new_nodes: Vec<T> = vec![edge.u.clone(), edge.v.clone()]
+                .into_iter()
+                .filter(|name| !self.nodes.contains_key(name))
+                .collect();
+            self.add_edge(edge)?;
+            for name in new_nodes {
+                if let Some(node) = self.nodes.get_mut(&name) {
+                    if node.attributes.is_none() {
+                        node.attributes = Some(node_attributes(&name));
+                    }
+                }
+            }
         }
         Ok(())
     }
@@ -267,30 +450,60 @@ where
     }
 
     /**
-    Creates an empty graph, according to the `specs`.
+    Creates an empty graph, according to the `specs`, whose edge weights are of type `W`.
+    Use [new](./struct.Graph.html#method.new) for graphs with `f64` weights.
 
     # Arguments
 
     * `specs`: An instance of [GraphSpecs](./struct.GraphSpecs.html) that determines the
-    characteristics and constraints of the graph.
+      characteristics and constraints of the graph.
 
     # Examples
 
     ```
-    use graphrs::{Graph, GraphSpecs};
-    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    use graphrs::{Edge, Graph, GraphSpecs};
+    let mut graph: Graph<&str, (), u64> =
+        Graph::new_with_weight_type(GraphSpecs::directed_create_missing());
+    graph.add_edge(Edge::with_weight("n1", "n2", 3)).unwrap();
     ```
     */
-    pub fn new(specs: GraphSpecs) -> Graph<T, A> {
+    pub fn new_with_weight_type(specs: GraphSpecs) -> Graph<T, A, W> {
         Graph {
             nodes: HashMap::<T, Node<T, A>>::new(),
-            edges: HashMap::<(T, T), Vec<Edge<T, A>>>::new(),
+            edges: HashMap::<(T, T), Vec<Edge<T, A, W>>>::new(),
+            edge_keys: HashMap::<(T, T), Vec<usize>>::new(),
             specs,
             successors: HashMap::<T, HashSet<T>>::new(),
             predecessors: HashMap::<T, HashSet<T>>::new(),
         }
     }
 
+}
+
+impl<T, A> Graph<T, A>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+{
+    /**
+    Creates an empty graph, according to the `specs`.
+
+    # Arguments
+
+    * `specs`: An instance of [GraphSpecs](./struct.GraphSpecs.html) that determines the
+    characteristics and constraints of the graph.
+
+    # Examples
+
+    ```
+    use graphrs::{Graph, GraphSpecs};
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    ```
+    */
+    pub fn new(specs: GraphSpecs) -> Graph<T, A> {
+        Graph::new_with_weight_type(specs)
+    }
+
     /**
     Create a new `Graph` from the specified `nodes` and `edges`.
 
@@ -345,4 +558,141 @@ where
             Ok(_) => Ok(graph),
         }
     }
+
+    /**
+    Adds an edge, as a (u, v) tuple, to the `Graph`.
+
+    If the new edge references nodes that don't exist the graph's `specs.missing_node_strategy`
+    determines what happens.
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    let result = graph.add_edge_tuple("n1", "n2");
+    assert!(result.is_ok());
+    ```
+    */
+    pub fn add_edge_tuple(&mut self, u: T, v: T) -> Result<(), Error>
+    where
+        T: Hash + Eq + Clone + Ord + Display,
+    {
+        self.add_edge(Edge::new(u, v))
+    }
+
+    /**
+    Adds new edges to a `Graph`, or updates existing edges, or both.
+
+    If the new edges reference nodes that don't exist the graph's `specs.missing_node_strategy`
+    determines what happens.
+
+    # Arguments
+
+    * `edges`: the new edges to add to the graph
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    let result = graph.add_edge_tuples(vec![
+        ("n1", "n2"),
+        ("n2", "n3"),
+    ]);
+    assert!(result.is_ok());
+    ```
+    */
+    pub fn add_edge_tuples(&mut self, edges: Vec<(T, T)>) -> Result<(), Error>
+    where
+        T: Hash + Eq + Clone + Ord + Display,
+        A: Clone,
+    {
+        for edge in edges {
+            self.add_edge(Edge::new(edge.0, edge.1))?;
+        }
+        Ok(())
+    }
+}
+
+/**
+Groups `arcs` by their first node, in parallel if `parallel` is `true`, returning each node
+with the set of second nodes of its arcs.
+*/
+fn get_adjacency_sets<T>(mut arcs: Vec<(T, T)>, parallel: bool) -> Vec<(T, HashSet<T>)>
+where
+    T: Eq + Clone + Ord + Hash + Send + Sync,
+{
+    match parallel {
+        true => arcs.par_sort_unstable_by(|a1, a2| a1.0.cmp(&a2.0)),
+        false => arcs.sort_unstable_by(|a1, a2| a1.0.cmp(&a2.0)),
+    }
+    let is_start = |i: &usize| *i == 0 || arcs[*i].0 != arcs[i - 1].0;
+    let get_run = |starts: &[usize], s: usize| {
+        let end = starts.get(s + 1).copied().unwrap_or(arcs.len());
+        let run = &arcs[starts[s]..end];
+        (
+            run[0].0.clone(),
+            run.iter().map(|(_u, v)| v.clone()).collect(),
+        )
+    };
+    if !parallel {
+        let starts: Vec<usize> = (0..arcs.len()).filter(is_start).collect();
+        return (0..starts.len()).map(|s| get_run(&starts, s)).collect();
+    }
+    let starts: Vec<usize> = (0..arcs.len()).into_par_iter().filter(is_start).collect();
+    (0..starts.len()).into_par_iter().map(|s| get_run(&starts, s)).collect()
+}
+
+/// Returns the first of `0..len` for which `f` is `true`, searching in parallel if
+/// `parallel` is `true`.
+fn find_first<F>(len: usize, parallel: bool, f: F) -> Option<usize>
+where
+    F: Fn(usize) -> bool + Send + Sync,
+{
+    match parallel {
+        true => (0..len).into_par_iter().find_first(|i| f(*i)),
+        false => (0..len).find(|i| f(*i)),
+    }
+}
+
+fn get_self_loop_error<T, A, W>(edge: &Edge<T, A, W>) -> Error
+where
+    T: PartialOrd + Send + Sync + Display,
+{
+    Error {
+        kind: ErrorKind::SelfLoopsFound,
+        message: format!(
+            "Edge ({}, {}) is a self-loop and `specs.self_loops` is false.",
+            edge.u, edge.v
+        ),
+    }
+}
+
+fn get_missing_node_error<T, A, W>(edge: &Edge<T, A, W>) -> Error
+where
+    T: PartialOrd + Send + Sync + Display,
+{
+    Error {
+        kind: ErrorKind::NodeNotFound,
+        message: format!(
+            "While adding edge ({}, {}) one or both of the nodes was not \
+            found in the graph. Either add the nodes or set \
+            GraphSpecs.missing_node_strategy to `Create`.",
+            edge.u, edge.v
+        ),
+    }
+}
+
+fn get_duplicate_edge_error<T, A, W>(edge: &Edge<T, A, W>) -> Error
+where
+    T: PartialOrd + Send + Sync + Display,
+{
+    Error {
+        kind: ErrorKind::DuplicateEdge,
+        message: format!(
+            "A duplicate edge was found: {}. \
+            Set the `GraphSpecs.edge_dedupe_strategy` if a different \
+            behavior is desired.",
+            edge
+        ),
+    }
 }
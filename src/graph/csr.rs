@@ -0,0 +1,283 @@
+use super::{Direction, Graph};
+use crate::{Error, ErrorKind};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+A frozen, compressed sparse row (CSR) representation of a [Graph](./struct.Graph.html).
+
+The node names are interned into dense `u32` indexes, assigned in the order of the sorted
+node names, and the successors (for a directed graph) or neighbors (for an undirected
+graph) of every node are stored in one contiguous array, sorted by index. Algorithms
+that visit a lot of nodes can iterate over these arrays without hashing node names or
+allocating a `Vec` for every node they visit.
+
+Multiple edges between the same two nodes are stored once, with the smallest of their
+weights. A `CsrGraph` doesn't hold any node or edge attributes and can't be modified;
+create a new one with [Graph::to_csr](./struct.Graph.html#method.to_csr) if the graph
+changes.
+
+# Examples
+
+```
+use graphrs::{algorithms::shortest_path::dijkstra, generators};
+
+let graph = generators::social::karate_club_graph();
+let csr = graph.to_csr();
+assert_eq!(csr.number_of_nodes(), 34);
+let index = csr.get_node_index(&0).unwrap();
+assert_eq!(csr.get_successors_or_neighbors(index).len(), 16);
+let distances = dijkstra::single_source_distances_csr(&csr, false, 0, None, None).unwrap();
+assert_eq!(distances.get(&33), Some(&2.0));
+```
+*/
+pub struct CsrGraph<T, W = f64> {
+    /// The node names; `names[i]` is the name of the node with index `i`.
+    names: Vec<T>,
+    /// The index of each node name.
+    indexes: HashMap<T, u32>,
+    /// The successors or neighbors of node `i` are `targets[offsets[i]..offsets[i + 1]]`.
+    offsets: Vec<usize>,
+    targets: Vec<u32>,
+    /// The weight of the edge to each of the `targets`.
+    weights: Vec<W>,
+    directed: bool,
+}
+
+impl<T, A, W> Graph<T, A, W>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+    W: Copy + PartialOrd,
+{
+    /**
+    Creates a [CsrGraph](./struct.CsrGraph.html) from the graph: a frozen copy of its
+    adjacency that algorithms can traverse much faster than the `Graph` itself.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_edges(vec![
+        Edge::with_weight("n1", "n2", 1.0),
+        Edge::with_weight("n1", "n3", 2.0),
+        Edge::with_weight("n3", "n2", 3.0),
+    ]);
+    let csr = graph.to_csr();
+    let n1 = csr.get_node_index(&"n1").unwrap();
+    let successors: Vec<&str> = csr
+        .get_successors_or_neighbors(n1)
+        .iter()
+        .map(|i| *csr.get_node_name(*i).unwrap())
+        .collect();
+    assert_eq!(successors, vec!["n2", "n3"]);
+    assert_eq!(csr.get_weights(n1), &[1.0, 2.0]);
+    ```
+    */
+    pub fn to_csr(&self) -> CsrGraph<T, W> {
+        self.to_csr_with_costs(|u, v| self.get_min_weight(u, v))
+    }
+
+    /**
+    Creates a [CsrGraph](./struct.CsrGraph.html) from the graph that follows the edges in
+    `direction`. With `Direction::Incoming` the successors of each node in the `CsrGraph`
+    of a directed graph are its predecessors in the graph, so algorithms that run on the
+    `CsrGraph` search the graph backwards. For an undirected graph this is the same as
+    [to_csr](#method.to_csr).
+
+    # Examples
+
+    ```
+    use graphrs::{Direction, Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_edges(vec![
+        Edge::with_weight("n1", "n2", 1.0),
+        Edge::with_weight("n1", "n3", 2.0),
+        Edge::with_weight("n3", "n2", 3.0),
+    ]);
+    let csr = graph.to_csr_in_direction(Direction::Incoming);
+    let n2 = csr.get_node_index(&"n2").unwrap();
+    let predecessors: Vec<&str> = csr
+        .get_successors_or_neighbors(n2)
+        .iter()
+        .map(|i| *csr.get_node_name(*i).unwrap())
+        .collect();
+    assert_eq!(predecessors, vec!["n1", "n3"]);
+    assert_eq!(csr.get_weights(n2), &[1.0, 3.0]);
+    ```
+    */
+    pub fn to_csr_in_direction(&self, direction: Direction) -> CsrGraph<T, W> {
+        match (direction, self.specs.directed) {
+            (Direction::Incoming, true) => {
+                self.to_csr_from_adjacency(&self.predecessors, |u, v| self.get_min_weight(v, u))
+            }
+            _ => self.to_csr(),
+        }
+    }
+
+    /**
+    Creates a [CsrGraph](./struct.CsrGraph.html) from the graph where the weight of the
+    edge from `u` to `v` is `get_cost(u, v)`.
+    */
+    pub(crate) fn to_csr_with_costs<CW, F>(&self, get_cost: F) -> CsrGraph<T, CW>
+    where
+        F: Fn(&T, &T) -> CW,
+    {
+        self.to_csr_from_adjacency(&self.successors, get_cost)
+    }
+
+    /**
+    Creates a [CsrGraph](./struct.CsrGraph.html) where the successors of each node are
+    given by `adjacency` and the weight of the arc from `u` to `v` is `get_cost(u, v)`.
+    */
+    fn to_csr_from_adjacency<CW, F>(
+        &self,
+        adjacency: &HashMap<T, HashSet<T>>,
+        get_cost: F,
+    ) -> CsrGraph<T, CW>
+    where
+        F: Fn(&T, &T) -> CW,
+    {
+        let mut names: Vec<T> = self.nodes.keys().cloned().collect();
+        names.sort_unstable();
+        let indexes: HashMap<T, u32> =
+            names.iter().enumerate().map(|(i, name)| (name.clone(), i as u32)).collect();
+        let mut offsets = Vec::with_capacity(names.len() + 1);
+        let mut targets = vec![];
+        let mut weights = vec![];
+        offsets.push(0);
+        for u in names.iter() {
+            if let Some(successors) = adjacency.get(u) {
+                let mut row: Vec<(u32, &T)> = successors.iter().map(|v| (indexes[v], v)).collect();
+                row.sort_unstable_by_key(|(index, _v)| *index);
+                for (index, v) in row {
+                    targets.push(index);
+                    weights.push(get_cost(u, v));
+                }
+            }
+            offsets.push(targets.len());
+        }
+        CsrGraph {
+            names,
+            indexes,
+            offsets,
+            targets,
+            weights,
+            directed: self.specs.directed,
+        }
+    }
+
+    /// Returns the smallest weight of the edges from `u` to `v`, or NaN if any of them is NaN.
+    pub(crate) fn get_min_weight(&self, u: &T, v: &T) -> W {
+        let key = match !self.specs.directed && u > v {
+            false => (u.clone(), v.clone()),
+            true => (v.clone(), u.clone()),
+        };
+        self.edges[&key]
+            .iter()
+            .map(|e| e.weight)
+            .reduce(|a, b| match b < a || b.partial_cmp(&b).is_none() {
+                true => b,
+                false => a,
+            })
+            .unwrap()
+    }
+}
+
+impl<T, W> CsrGraph<T, W>
+where
+    T: Eq + Clone + Hash + Display,
+    W: Copy + PartialOrd,
+{
+    /// Returns `true` if the graph that the `CsrGraph` was created from is directed.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Returns the number of nodes.
+    pub fn number_of_nodes(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns the names of all the nodes, in index order.
+    pub fn get_node_names(&self) -> &[T] {
+        &self.names
+    }
+
+    /// Returns the name of the node with `index`, or `None` if there is no such node.
+    pub fn get_node_name(&self, index: u32) -> Option<&T> {
+        self.names.get(index as usize)
+    }
+
+    /// Returns the index of the node called `name`, or `None` if there is no such node.
+    pub fn get_node_index(&self, name: &T) -> Option<u32> {
+        self.indexes.get(name).copied()
+    }
+
+    /**
+    Returns the indexes of the successors (for a directed graph) or neighbors (for an
+    undirected graph) of the node with `index`, in ascending order.
+
+    # Panics
+
+    If there isn't a node with `index`.
+    */
+    pub fn get_successors_or_neighbors(&self, index: u32) -> &[u32] {
+        let i = index as usize;
+        &self.targets[self.offsets[i]..self.offsets[i + 1]]
+    }
+
+    /**
+    Returns the weights of the edges to the nodes returned by
+    [get_successors_or_neighbors](#method.get_successors_or_neighbors), in the same order.
+
+    # Panics
+
+    If there isn't a node with `index`.
+    */
+    pub fn get_weights(&self, index: u32) -> &[W] {
+        let i = index as usize;
+        &self.weights[self.offsets[i]..self.offsets[i + 1]]
+    }
+
+    /**
+    Determines if all edges have a weight value.
+
+    # Returns
+
+    `true` if all edges have a `weight` value and the value isn't NAN, false otherwise.
+    */
+    pub fn edges_have_weight(&self) -> bool {
+        // only NAN can't be compared with itself
+        self.weights.iter().all(|w| w.partial_cmp(w).is_some())
+    }
+
+    /// Returns an `Err` if any edge doesn't have a weight.
+    pub(crate) fn ensure_weighted(&self) -> Result<(), Error> {
+        if !self.edges_have_weight() {
+            return Err(Error {
+                kind: ErrorKind::EdgeWeightNotSpecified,
+                message: "Not all edges in the graph have a weight.".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the indexes of `names`, or an `Err` if any of them don't exist.
+    pub(crate) fn get_node_indexes(&self, names: &[T]) -> Result<Vec<u32>, Error> {
+        names
+            .iter()
+            .map(|name| match self.indexes.get(name) {
+                Some(index) => Ok(*index),
+                None => Err(Error {
+                    kind: ErrorKind::NodeNotFound,
+                    message: format!("Requested node '{}' was not found in the graph.", name),
+                }),
+            })
+            .collect()
+    }
+}
@@ -41,6 +41,63 @@ where
             .collect()
     }
 
+    /**
+    Returns an iterator over `(node name, degree)` pairs for all nodes in the graph,
+    in no particular order. Unlike [get_degree_for_all_nodes](#method.get_degree_for_all_nodes)
+    this doesn't build a `HashMap`: each degree is computed with
+    [get_node_degree](#method.get_node_degree) as the iterator is advanced.
+
+    # Examples
+
+    ```
+    use graphrs::{generators};
+    let graph = generators::social::karate_club_graph();
+    let max_degree = graph.get_degree_iter().map(|(_, d)| d).max().unwrap();
+    assert_eq!(max_degree, 17);
+    ```
+    */
+    pub fn get_degree_iter(&self) -> impl Iterator<Item = (&T, usize)> + '_ {
+        self.nodes
+            .keys()
+            .map(move |name| (name, self.get_node_degree(name.clone()).unwrap()))
+    }
+
+    /**
+    Computes a histogram of the degrees of the nodes in the graph.
+
+    # Returns
+
+    A `Vec` where the value at index `i` is the number of nodes with degree `i`.
+    The length of the `Vec` is one more than the maximum degree, and it is empty
+    if the graph has no nodes.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+    let edges = vec![
+        Edge::new("n1", "n2"),
+        Edge::new("n1", "n3"),
+        Edge::new("n1", "n4"),
+        Edge::new("n4", "n5"),
+    ];
+    let graph: Graph<&str, ()> =
+        Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+            .unwrap();
+    assert_eq!(graph.get_degree_histogram(), vec![0, 3, 1, 1]);
+    ```
+    */
+    pub fn get_degree_histogram(&self) -> Vec<usize> {
+        let mut histogram = vec![];
+        for (_, degree) in self.get_degree_iter() {
+            if degree >= histogram.len() {
+                histogram.resize(degree + 1, 0);
+            }
+            histogram[degree] += 1;
+        }
+        histogram
+    }
+
     /**
     Compute the in-degree for all nodes in the graph.
 
@@ -0,0 +1,364 @@
+use super::Graph;
+use crate::{Edge, Error, ErrorKind};
+use std::fmt::Display;
+use std::hash::Hash;
+
+impl<T, A, W> Graph<T, A, W>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+    W: Copy + PartialOrd,
+{
+    /**
+    Adds an `edge` to a multigraph with a `key` that identifies it among the edges between
+    the same two nodes, so that it can be found, updated and removed on its own.
+
+    Edges that are added without a key are given the number of edges that are already
+    between the two nodes, or the next number up if that is in use. So the edges that are
+    added between two nodes with `add_edge` have the keys 0, 1, 2, and so on. Subgraphs,
+    reversed graphs, and graphs that are deserialized or read with `readwrite::binary`
+    keep the keys of the graph they were made from. Graphs that are converted in other
+    ways, for example with `to_undirected`, give their edges these default keys.
+
+    # Arguments
+
+    * `edge`: the edge to add
+    * `key`: the key of the edge
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned if `specs.multi_edges`
+    is `false`, and with a `kind` of `ErrorKind::DuplicateEdge` if there is already an edge
+    with `key` between the nodes. Otherwise any `Error` that `add_edge` returns.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs, MissingNodeStrategy};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+        missing_node_strategy: MissingNodeStrategy::Create,
+        ..GraphSpecs::multi_directed()
+    });
+    graph.add_edge_with_key(Edge::with_weight("n1", "n2", 1.0), 7).unwrap();
+    graph.add_edge(Edge::with_weight("n1", "n2", 2.0)).unwrap();
+    assert_eq!(graph.get_edge_keys("n1", "n2").unwrap(), vec![7, 1]);
+    assert_eq!(graph.get_edge_by_key("n1", "n2", 7).unwrap().weight, 1.0);
+    ```
+    */
+    pub fn add_edge_with_key(&mut self, edge: Edge<T, A, W>, key: usize) -> Result<(), Error> {
+        self.ensure_multi_edges("add_edge_with_key")?;
+        let pair = self.get_ordered_pair(&edge.u, &edge.v);
+        if self.get_edge_key_index(&pair, key).is_some() {
+            return Err(Error {
+                kind: ErrorKind::DuplicateEdge,
+                message: format!(
+                    "An edge with key {} already exists between {} and {}.",
+                    key, edge.u, edge.v
+                ),
+            });
+        }
+        let num_edges = self.edges.get(&pair).map_or(0, |edges| edges.len());
+        self.add_edge(edge)?;
+        if self.edges.get(&pair).map_or(0, |edges| edges.len()) == num_edges {
+            // the edge was dropped, as allowed by `specs.self_loops_false_strategy`
+            return Ok(());
+        }
+        let keys = self.edge_keys.entry(pair.clone()).or_insert_with(|| (0..=num_edges).collect());
+        *keys.last_mut().unwrap() = key;
+        self.normalize_edge_keys(&pair);
+        Ok(())
+    }
+
+    /**
+    Returns the keys of the edges between `u` and `v` in a multigraph, in the same order as
+    the edges returned by [get_edges](#method.get_edges).
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned if `specs.multi_edges`
+    is `false`, and with a `kind` of `ErrorKind::EdgeNotFound` if there are no edges
+    between `u` and `v`.
+    */
+    pub fn get_edge_keys(&self, u: T, v: T) -> Result<Vec<usize>, Error> {
+        self.ensure_multi_edges("get_edge_keys")?;
+        let pair = self.get_ordered_pair(&u, &v);
+        match (self.edges.get(&pair), self.edge_keys.get(&pair)) {
+            (None, _) => Err(get_edges_not_found_error(&u, &v)),
+            (Some(_), Some(keys)) => Ok(keys.clone()),
+            (Some(edges), None) => Ok((0..edges.len()).collect()),
+        }
+    }
+
+    /**
+    Returns the edge between `u` and `v` with `key` in a multigraph.
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned if `specs.multi_edges`
+    is `false`, and with a `kind` of `ErrorKind::EdgeNotFound` if there is no such edge.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs, MissingNodeStrategy};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+        missing_node_strategy: MissingNodeStrategy::Create,
+        ..GraphSpecs::multi_undirected()
+    });
+    graph.add_edges(vec![
+        Edge::with_weight("n1", "n2", 1.0),
+        Edge::with_weight("n2", "n1", 2.0),
+    ]);
+    assert_eq!(graph.get_edge_by_key("n2", "n1", 1).unwrap().weight, 2.0);
+    assert!(graph.get_edge_by_key("n1", "n2", 2).is_err());
+    ```
+    */
+    pub fn get_edge_by_key(&self, u: T, v: T, key: usize) -> Result<&Edge<T, A, W>, Error> {
+        self.ensure_multi_edges("get_edge_by_key")?;
+        let pair = self.get_ordered_pair(&u, &v);
+        match self.get_edge_key_index(&pair, key) {
+            None => Err(get_edge_key_not_found_error(&u, &v, key)),
+            Some(index) => Ok(&self.edges[&pair][index]),
+        }
+    }
+
+    /**
+    Sets the weight of the edge between `u` and `v` with `key` in a multigraph.
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned if `specs.multi_edges`
+    is `false`, and with a `kind` of `ErrorKind::EdgeNotFound` if there is no such edge.
+    */
+    pub fn set_edge_weight_by_key(
+        &mut self,
+        u: T,
+        v: T,
+        key: usize,
+        weight: W,
+    ) -> Result<(), Error> {
+        self.get_edge_by_key_mut(u, v, key, "set_edge_weight_by_key")?.weight = weight;
+        Ok(())
+    }
+
+    /**
+    Sets the attributes of the edge between `u` and `v` with `key` in a multigraph.
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned if `specs.multi_edges`
+    is `false`, and with a `kind` of `ErrorKind::EdgeNotFound` if there is no such edge.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs, MissingNodeStrategy};
+
+    let mut graph: Graph<&str, &str> = Graph::new(GraphSpecs {
+        missing_node_strategy: MissingNodeStrategy::Create,
+        ..GraphSpecs::multi_directed()
+    });
+    graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n1", "n2")]);
+    graph.set_edge_attributes_by_key("n1", "n2", 1, Some("second")).unwrap();
+    graph.set_edge_weight_by_key("n1", "n2", 1, 4.0).unwrap();
+    let edge = graph.get_edge_by_key("n1", "n2", 1).unwrap();
+    assert_eq!((edge.attributes, edge.weight), (Some("second"), 4.0));
+    assert_eq!(graph.get_edge_by_key("n1", "n2", 0).unwrap().attributes, None);
+    ```
+    */
+    pub fn set_edge_attributes_by_key(
+        &mut self,
+        u: T,
+        v: T,
+        key: usize,
+        attributes: Option<A>,
+    ) -> Result<(), Error> {
+        self.get_edge_by_key_mut(u, v, key, "set_edge_attributes_by_key")?.attributes = attributes;
+        Ok(())
+    }
+
+    /**
+    Removes the edge between `u` and `v` with `key` from a multigraph, leaving the other
+    edges between `u` and `v` and their keys as they are.
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::WrongMethod` is returned if `specs.multi_edges`
+    is `false`, and with a `kind` of `ErrorKind::EdgeNotFound` if there is no such edge.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs, MissingNodeStrategy};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+        missing_node_strategy: MissingNodeStrategy::Create,
+        ..GraphSpecs::multi_directed()
+    });
+    graph.add_edges(vec![
+        Edge::with_weight("n1", "n2", 1.0),
+        Edge::with_weight("n1", "n2", 2.0),
+        Edge::with_weight("n1", "n2", 3.0),
+    ]);
+    graph.remove_edge_by_key("n1", "n2", 0).unwrap();
+    assert_eq!(graph.get_edge_keys("n1", "n2").unwrap(), vec![1, 2]);
+    graph.add_edge(Edge::with_weight("n1", "n2", 4.0)).unwrap();
+    assert_eq!(graph.get_edge_keys("n1", "n2").unwrap(), vec![1, 2, 3]);
+    ```
+    */
+    pub fn remove_edge_by_key(&mut self, u: T, v: T, key: usize) -> Result<(), Error> {
+        self.ensure_multi_edges("remove_edge_by_key")?;
+        let pair = self.get_ordered_pair(&u, &v);
+        let index = match self.get_edge_key_index(&pair, key) {
+            None => return Err(get_edge_key_not_found_error(&u, &v, key)),
+            Some(index) => index,
+        };
+        let edges = self.edges.get_mut(&pair).unwrap();
+        if edges.len() == 1 {
+            self.edges.remove(&pair);
+            self.edge_keys.remove(&pair);
+            self.remove_adjacency(&pair.0, &pair.1);
+            return Ok(());
+        }
+        let num_edges = edges.len();
+        edges.remove(index);
+        self.edge_keys
+            .entry(pair.clone())
+            .or_insert_with(|| (0..num_edges).collect())
+            .remove(index);
+        self.normalize_edge_keys(&pair);
+        Ok(())
+    }
+
+    /**
+    Returns every edge and its key. For a graph that isn't a multigraph every key is 0.
+    The edges between each pair of nodes are in the same order as [get_edges](#method.get_edges).
+    */
+    pub(crate) fn get_all_edges_with_keys(&self) -> Vec<(&Edge<T, A, W>, usize)> {
+        let mut edges_with_keys = vec![];
+        for (pair, edges) in self.edges.iter() {
+            match self.edge_keys.get(pair) {
+                None => edges_with_keys.extend(edges.iter().zip(0..)),
+                Some(keys) => edges_with_keys.extend(edges.iter().zip(keys.iter().copied())),
+            }
+        }
+        edges_with_keys
+    }
+
+    /**
+    Adds `edges` with their keys if the graph is a multigraph, otherwise adds them with
+    [add_edges](#method.add_edges) and ignores the keys.
+    */
+    pub(crate) fn add_edges_with_keys(
+        &mut self,
+        edges: Vec<(Edge<T, A, W>, usize)>,
+    ) -> Result<(), Error> {
+        if !self.specs.multi_edges {
+            return self.add_edges(edges.into_iter().map(|(edge, _key)| edge).collect());
+        }
+        for (edge, key) in edges {
+            self.add_edge_with_key(edge, key)?;
+        }
+        Ok(())
+    }
+
+    /// Gives the edge that was just added to the end of the multi-edges between the `pair`
+    /// of nodes a key, if the keys of those edges aren't just their indexes.
+    pub(super) fn push_edge_key(&mut self, pair: &(T, T)) {
+        if let Some(keys) = self.edge_keys.get_mut(pair) {
+            let mut key = keys.len();
+            while keys.contains(&key) {
+                key += 1;
+            }
+            keys.push(key);
+        }
+    }
+
+    /// Keeps the keys of the multi-edges between the `pair` of nodes in step with the edges
+    /// after the edges for which `keep` is `false` have been removed.
+    pub(super) fn retain_edge_keys(&mut self, pair: &(T, T), keep: &[bool]) {
+        if let Some(keys) = self.edge_keys.get_mut(pair) {
+            let mut index = 0;
+            keys.retain(|_key| {
+                index += 1;
+                keep[index - 1]
+            });
+            self.normalize_edge_keys(pair);
+        }
+    }
+
+    /// Stops storing the keys of the multi-edges between the `pair` of nodes if each one is
+    /// the same as the edge's index.
+    fn normalize_edge_keys(&mut self, pair: &(T, T)) {
+        let is_default = match self.edge_keys.get(pair) {
+            None => return,
+            Some(keys) => keys.iter().enumerate().all(|(index, key)| index == *key),
+        };
+        if is_default {
+            self.edge_keys.remove(pair);
+        }
+    }
+
+    /// Returns the index, among the edges between the `pair` of nodes, of the edge with `key`.
+    fn get_edge_key_index(&self, pair: &(T, T), key: usize) -> Option<usize> {
+        let num_edges = self.edges.get(pair).map_or(0, |edges| edges.len());
+        match self.edge_keys.get(pair) {
+            Some(keys) => keys.iter().position(|k| *k == key),
+            None => (key < num_edges).then_some(key),
+        }
+    }
+
+    fn get_edge_by_key_mut(
+        &mut self,
+        u: T,
+        v: T,
+        key: usize,
+        method: &str,
+    ) -> Result<&mut Edge<T, A, W>, Error> {
+        self.ensure_multi_edges(method)?;
+        let pair = self.get_ordered_pair(&u, &v);
+        match self.get_edge_key_index(&pair, key) {
+            None => Err(get_edge_key_not_found_error(&u, &v, key)),
+            Some(index) => Ok(&mut self.edges.get_mut(&pair).unwrap()[index]),
+        }
+    }
+
+    fn get_ordered_pair(&self, u: &T, v: &T) -> (T, T) {
+        match !self.specs.directed && u > v {
+            false => (u.clone(), v.clone()),
+            true => (v.clone(), u.clone()),
+        }
+    }
+
+    fn ensure_multi_edges(&self, method: &str) -> Result<(), Error> {
+        if !self.specs.multi_edges {
+            return Err(Error {
+                kind: ErrorKind::WrongMethod,
+                message: format!(
+                    "The `{}` method requires `multi_edges` to be `true`.",
+                    method
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+fn get_edges_not_found_error<T: Display>(u: &T, v: &T) -> Error {
+    Error {
+        kind: ErrorKind::EdgeNotFound,
+        message: format!("No edges found for the requested ({}, {})", u, v),
+    }
+}
+
+fn get_edge_key_not_found_error<T: Display>(u: &T, v: &T, key: usize) -> Error {
+    Error {
+        kind: ErrorKind::EdgeNotFound,
+        message: format!(
+            "The requested edge ({}, {}) with key {} does not exist.",
+            u, v, key
+        ),
+    }
+}
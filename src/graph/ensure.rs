@@ -3,10 +3,11 @@ use crate::{Error, ErrorKind};
 use std::fmt::Display;
 use std::hash::Hash;
 
-impl<T, A> Graph<T, A>
+impl<T, A, W> Graph<T, A, W>
 where
     T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
     A: Clone,
+    W: Copy + PartialOrd,
 {
     /// Returns an `Err` if the `graph` is not a directed graph.
     pub fn ensure_directed(&self) -> Result<(), Error>
@@ -38,6 +39,21 @@ where
         Ok(())
     }
 
+    /// Returns an `Err` if the `graph` does not contain the node `node_name`.
+    pub fn ensure_node(&self, node_name: &T) -> Result<(), Error>
+    where
+        T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+        A: Clone,
+    {
+        if !self.has_node(node_name) {
+            return Err(Error {
+                kind: ErrorKind::NodeNotFound,
+                message: format!("Requested node '{}' was not found in the graph.", node_name),
+            });
+        }
+        Ok(())
+    }
+
     /// Returns an `Err` if the `graph` is not a directed graph.
     pub fn ensure_not_multi_edges(&self) -> Result<(), Error>
     where
@@ -0,0 +1,204 @@
+use super::{CsrGraph, Direction, Graph};
+use crate::{Error, ErrorKind};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+An indexed view of the adjacency of a graph, where the nodes are identified by dense `u32`
+indexes, which is what the Dijkstra searches run on. It doesn't change how a `Graph`
+stores its nodes, edges and adjacency, which are keyed by node name; the other algorithms
+and the graph methods still work with the names.
+
+A [CsrGraph](./struct.CsrGraph.html) gives every node an index up front, which pays off when
+a search visits most of the graph. A [LazyIndexedGraph](./struct.LazyIndexedGraph.html)
+only gives indexes to the nodes a search reaches, so a search that stops early doesn't
+have to touch the rest of the graph.
+*/
+pub(crate) trait IndexedAdjacency<T, W> {
+    /// Returns the number of nodes in the graph.
+    fn number_of_nodes(&self) -> usize;
+
+    /// Returns the number of indexes given out so far; every index is smaller than this.
+    fn number_of_indexes(&self) -> usize;
+
+    /// Returns the name of the node with `index`.
+    fn get_node_name(&self, index: u32) -> &T;
+
+    /// Returns the index of `name`, or `None` if it isn't in the graph.
+    fn get_node_index(&mut self, name: &T) -> Option<u32>;
+
+    /// Returns the indexes of `names`, or an `Err` if any of them don't exist.
+    fn get_node_indexes(&mut self, names: &[T]) -> Result<Vec<u32>, Error>;
+
+    /**
+    Replaces the contents of `row` with the successors (for a directed graph) or neighbors
+    (for an undirected graph) of the node with `index` and the weights of the edges to them,
+    in the order of their names.
+    */
+    fn load_successors_or_neighbors(&mut self, index: u32, row: &mut Vec<(u32, W)>);
+}
+
+impl<T, W> IndexedAdjacency<T, W> for &CsrGraph<T, W>
+where
+    T: Hash + Eq + Clone + Display,
+    W: Copy + PartialOrd,
+{
+    fn number_of_nodes(&self) -> usize {
+        CsrGraph::number_of_nodes(self)
+    }
+
+    fn number_of_indexes(&self) -> usize {
+        CsrGraph::number_of_nodes(self)
+    }
+
+    fn get_node_name(&self, index: u32) -> &T {
+        &self.get_node_names()[index as usize]
+    }
+
+    fn get_node_index(&mut self, name: &T) -> Option<u32> {
+        CsrGraph::get_node_index(self, name)
+    }
+
+    fn get_node_indexes(&mut self, names: &[T]) -> Result<Vec<u32>, Error> {
+        CsrGraph::get_node_indexes(self, names)
+    }
+
+    fn load_successors_or_neighbors(&mut self, index: u32, row: &mut Vec<(u32, W)>) {
+        row.clear();
+        let neighbors = self.get_successors_or_neighbors(index).iter().copied();
+        row.extend(neighbors.zip(self.get_weights(index).iter().copied()));
+    }
+}
+
+impl<T, W, G> IndexedAdjacency<T, W> for &mut G
+where
+    G: IndexedAdjacency<T, W> + ?Sized,
+{
+    fn number_of_nodes(&self) -> usize {
+        (**self).number_of_nodes()
+    }
+
+    fn number_of_indexes(&self) -> usize {
+        (**self).number_of_indexes()
+    }
+
+    fn get_node_name(&self, index: u32) -> &T {
+        (**self).get_node_name(index)
+    }
+
+    fn get_node_index(&mut self, name: &T) -> Option<u32> {
+        (**self).get_node_index(name)
+    }
+
+    fn get_node_indexes(&mut self, names: &[T]) -> Result<Vec<u32>, Error> {
+        (**self).get_node_indexes(names)
+    }
+
+    fn load_successors_or_neighbors(&mut self, index: u32, row: &mut Vec<(u32, W)>) {
+        (**self).load_successors_or_neighbors(index, row)
+    }
+}
+
+/**
+The adjacency of a [Graph](./struct.Graph.html) that follows the edges in `direction`,
+where the nodes are given indexes as they are reached and the weight of the edge from
+`u` to `v` is `get_cost(u, v)`. For `Direction::Incoming` `u` is the node the search
+has reached and `v` is a predecessor of it.
+*/
+pub(crate) struct LazyIndexedGraph<'a, T, A, W, F>
+where
+    T: PartialOrd + Send,
+    A: Clone,
+{
+    graph: &'a Graph<T, A, W>,
+    direction: Direction,
+    get_cost: F,
+    names: Vec<T>,
+    indexes: HashMap<T, u32>,
+}
+
+impl<'a, T, A, W, F> LazyIndexedGraph<'a, T, A, W, F>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    pub(crate) fn new(graph: &'a Graph<T, A, W>, direction: Direction, get_cost: F) -> Self {
+        LazyIndexedGraph {
+            graph,
+            direction,
+            get_cost,
+            names: vec![],
+            indexes: HashMap::new(),
+        }
+    }
+
+    /// Returns the index of `name`, giving it the next index if it doesn't have one yet.
+    fn get_or_add_index(&mut self, name: &T) -> u32 {
+        match self.indexes.get(name) {
+            Some(index) => *index,
+            None => {
+                let index = self.names.len() as u32;
+                self.names.push(name.clone());
+                self.indexes.insert(name.clone(), index);
+                index
+            }
+        }
+    }
+}
+
+impl<'a, T, A, W, CW, F> IndexedAdjacency<T, CW> for LazyIndexedGraph<'a, T, A, W, F>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    F: Fn(&T, &T) -> CW,
+{
+    fn number_of_nodes(&self) -> usize {
+        self.graph.nodes.len()
+    }
+
+    fn number_of_indexes(&self) -> usize {
+        self.names.len()
+    }
+
+    fn get_node_name(&self, index: u32) -> &T {
+        &self.names[index as usize]
+    }
+
+    fn get_node_index(&mut self, name: &T) -> Option<u32> {
+        match self.graph.nodes.contains_key(name) {
+            true => Some(self.get_or_add_index(name)),
+            false => None,
+        }
+    }
+
+    fn get_node_indexes(&mut self, names: &[T]) -> Result<Vec<u32>, Error> {
+        names
+            .iter()
+            .map(|name| match self.get_node_index(name) {
+                Some(index) => Ok(index),
+                None => Err(Error {
+                    kind: ErrorKind::NodeNotFound,
+                    message: format!("Requested node '{}' was not found in the graph.", name),
+                }),
+            })
+            .collect()
+    }
+
+    fn load_successors_or_neighbors(&mut self, index: u32, row: &mut Vec<(u32, CW)>) {
+        let graph = self.graph;
+        let adjacency = match (self.direction, graph.specs.directed) {
+            (Direction::Incoming, true) => &graph.predecessors,
+            _ => &graph.successors,
+        };
+        row.clear();
+        let name = &graph.nodes[self.get_node_name(index)].name;
+        if let Some(adjacent) = adjacency.get(name) {
+            let mut adjacent: Vec<&T> = adjacent.iter().collect();
+            adjacent.sort_unstable();
+            for v in adjacent {
+                row.push((self.get_or_add_index(v), (self.get_cost)(name, v)));
+            }
+        }
+    }
+}
@@ -8,12 +8,22 @@ It allows graphs to be created with support for:
 * multiple edges between two nodes
 * self-loops
 
-A `Graph` has two generic arguments:
-* `T`: Specifies the type to use for node names.
+A `Graph` has three generic arguments:
+* `T`: Specifies the type to use for node names. Node names are cloned rather than
+  copied, so types such as `String` can be used as well as integers and `&str`.
 * `A`: Specifies the type to use for node and edge attributes. Attributes are *optional*
 extra data that are associated with a node or an edge. For example, if nodes represent
 people and `T` is an `i32` of their employee ID then the node attributes might store
 their first and last names.
+* `W`: Specifies the type to use for edge weights, which defaults to `f64`. Graphs with
+  other weight types, such as `u64`, are created with
+  [new_with_weight_type](./struct.Graph.html#method.new_with_weight_type).
+
+The graph methods, the `dijkstra`, `astar` and `yen` shortest path functions and
+`via_waypoints` work with any `W: Num + PartialOrd + Copy`. The rest of the algorithms, including Bellman-Ford,
+Johnson, delta-stepping and Floyd-Warshall, as well as serialization and the readers and
+writers in `readwrite`, only work with `f64` weights, because they rely on `f64` values
+such as infinity for unreachable nodes or on converting weights to and from text or bytes.
 
 # Example
 
@@ -42,12 +52,15 @@ let graph = Graph::<&str, ()>::new_from_nodes_and_edges(
 );
 ```
 */
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Graph<T: PartialOrd + Send, A: Clone> {
+pub struct Graph<T: PartialOrd + Send, A: Clone, W = f64> {
     /// The graph's nodes, stored as a `HashMap` keyed by the node names.
     nodes: HashMap<T, Node<T, A>>,
     /// The graph's edges, stored as a `HashMap` keyed by a tuple of node names.
-    edges: HashMap<(T, T), Vec<Edge<T, A>>>,
+    #[allow(clippy::type_complexity)]
+    edges: HashMap<(T, T), Vec<Edge<T, A, W>>>,
+    /// The keys of the edges in a multigraph, in the same order as the `edges`. Only the
+    /// keys of the edges between two nodes that aren't 0, 1, 2, ... are stored.
+    edge_keys: HashMap<(T, T), Vec<usize>>,
     /// The [GraphSpecs](./struct.GraphSpecs.html) for the graph.
     pub specs: GraphSpecs,
     /// Stores the successors of nodes. A successor of u is a node v such that there
@@ -60,10 +73,32 @@ pub struct Graph<T: PartialOrd + Send, A: Clone> {
     predecessors: HashMap<T, HashSet<T>>,
 }
 
+mod adjacency;
+mod attributes;
+mod builder;
+mod contraction;
 mod convert;
 mod creation;
+mod csr;
 mod degree;
 mod density;
+mod edge_keys;
 mod ensure;
+mod indexed;
 mod query;
+mod removal;
+#[cfg(feature = "serde")]
+mod serialization;
+mod set_operations;
 mod subgraph;
+mod temporal;
+mod view;
+
+pub use adjacency::Direction;
+pub use builder::GraphBuilder;
+pub use contraction::SelfLoopPolicy;
+pub use convert::EdgeMergeStrategy;
+pub use csr::CsrGraph;
+pub(crate) use indexed::{IndexedAdjacency, LazyIndexedGraph};
+pub use temporal::{TemporalEdge, TemporalGraph};
+pub use view::GraphView;
@@ -5,10 +5,11 @@ use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::hash::Hash;
 
-impl<T, A> Graph<T, A>
+impl<T, A, W> Graph<T, A, W>
 where
     T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
     A: Clone,
+    W: Copy + PartialOrd,
 {
     /**
     Determines if all edges have a weight value.
@@ -23,7 +24,8 @@ where
         A: Clone,
     {
         for edge in self.get_all_edges() {
-            if edge.weight.is_nan() {
+            // only NAN can't be compared with itself
+            if edge.weight.partial_cmp(&edge.weight).is_none() {
                 return false;
             }
         }
@@ -47,12 +49,12 @@ where
     assert_eq!(all_edges.len(), 2);
     ```
     **/
-    pub fn get_all_edges(&self) -> Vec<&Edge<T, A>>
+    pub fn get_all_edges(&self) -> Vec<&Edge<T, A, W>>
     where
         T: Hash + Eq + Clone + Ord,
         A: Clone,
     {
-        self.edges.values().into_iter().flatten().collect::<Vec<&Edge<T, A>>>()
+        self.edges.values().flatten().collect::<Vec<&Edge<T, A, W>>>()
     }
 
     /**
@@ -128,7 +130,7 @@ where
     assert!(edge.is_ok());
     ```
     */
-    pub fn get_edge(&self, u: T, v: T) -> Result<&Edge<T, A>, Error>
+    pub fn get_edge(&self, u: T, v: T) -> Result<&Edge<T, A, W>, Error>
     where
         T: Hash + Eq + Clone + Ord,
         A: Clone,
@@ -187,7 +189,7 @@ where
     assert_eq!(edges.unwrap().len(), 2);
     ```
     */
-    pub fn get_edges(&self, u: T, v: T) -> Result<Vec<&Edge<T, A>>, Error>
+    pub fn get_edges(&self, u: T, v: T) -> Result<Vec<&Edge<T, A, W>>, Error>
     where
         T: Hash + Eq + Clone + Ord,
         A: Clone,
@@ -210,7 +212,7 @@ where
                 kind: ErrorKind::EdgeNotFound,
                 message: format!("No edges found for the requested ({}, {})", u, v),
             }),
-            Some(e) => Ok(e.iter().collect::<Vec<&Edge<T, A>>>()),
+            Some(e) => Ok(e.iter().collect::<Vec<&Edge<T, A, W>>>()),
         }
     }
 
@@ -235,7 +237,7 @@ where
     assert_eq!(n2_edges.len(), 2);
     ```
     */
-    pub fn get_edges_for_node(&self, name: T) -> Result<Vec<&Edge<T, A>>, Error>
+    pub fn get_edges_for_node(&self, name: T) -> Result<Vec<&Edge<T, A, W>>, Error>
     where
         T: Hash + Eq + Clone + Ord,
         A: Clone,
@@ -252,7 +254,7 @@ where
         let pred_edges = pred_node_names
             .iter()
             .flat_map(|pnn| self.edges.get(&(pnn.clone(), name.clone())).unwrap());
-        let succ_edges: Vec<&Edge<T, A>> = succ_node_names
+        let succ_edges: Vec<&Edge<T, A, W>> = succ_node_names
             .iter()
             .flat_map(|snn| {
                 let ordered = match !self.specs.directed && name > snn.clone() {
@@ -287,7 +289,7 @@ where
     assert_eq!(n2_edges.len(), 2);
     ```
     */
-    pub fn get_edges_for_nodes(&self, names: &[T]) -> Result<Vec<&Edge<T, A>>, Error>
+    pub fn get_edges_for_nodes(&self, names: &[T]) -> Result<Vec<&Edge<T, A, W>>, Error>
     where
         T: Hash + Eq + Clone + Ord,
         A: Clone,
@@ -330,7 +332,7 @@ where
     assert_eq!(n2_in_edges.len(), 2);
     ```
     */
-    pub fn get_in_edges_for_node(&self, name: T) -> Result<Vec<&Edge<T, A>>, Error>
+    pub fn get_in_edges_for_node(&self, name: T) -> Result<Vec<&Edge<T, A, W>>, Error>
     where
         T: Hash + Eq + Clone + Ord,
         A: Clone,
@@ -378,7 +380,7 @@ where
     assert_eq!(n2_in_edges.len(), 2);
     ```
     */
-    pub fn get_in_edges_for_nodes(&self, names: &[T]) -> Result<Vec<&Edge<T, A>>, Error>
+    pub fn get_in_edges_for_nodes(&self, names: &[T]) -> Result<Vec<&Edge<T, A, W>>, Error>
     where
         T: Hash + Eq + Clone + Ord,
         A: Clone,
@@ -424,7 +426,7 @@ where
     assert_eq!(n2_out_edges.len(), 2);
     ```
     */
-    pub fn get_out_edges_for_node(&self, name: T) -> Result<Vec<&Edge<T, A>>, Error>
+    pub fn get_out_edges_for_node(&self, name: T) -> Result<Vec<&Edge<T, A, W>>, Error>
     where
         T: Hash + Eq + Clone + Ord,
         A: Clone,
@@ -472,7 +474,7 @@ where
     assert_eq!(n1_out_edges.len(), 2);
     ```
     */
-    pub fn get_out_edges_for_nodes(&self, names: &[T]) -> Result<Vec<&Edge<T, A>>, Error>
+    pub fn get_out_edges_for_nodes(&self, names: &[T]) -> Result<Vec<&Edge<T, A, W>>, Error>
     where
         T: Hash + Eq + Clone + Ord,
         A: Clone,
@@ -848,6 +850,22 @@ where
         true
     }
 
+    // PRIVATE METHODS
+
+    fn get_nodes_for_names(&self, names: &HashSet<T>) -> Vec<&Node<T, A>>
+    where
+        T: Hash + Eq + Clone + Ord,
+        A: Clone,
+    {
+        names.iter().map(|n| self.nodes.get(n).unwrap()).collect::<Vec<&Node<T, A>>>()
+    }
+}
+
+impl<T, A> Graph<T, A>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+{
     /**
     Returns the number of edges or sum of all edge weights.
 
@@ -869,14 +887,4 @@ where
             true => self.get_all_edges().iter().map(|e| e.weight).sum(),
         }
     }
-
-    // PRIVATE METHODS
-
-    fn get_nodes_for_names(&self, names: &HashSet<T>) -> Vec<&Node<T, A>>
-    where
-        T: Hash + Eq + Clone + Ord,
-        A: Clone,
-    {
-        names.iter().map(|n| self.nodes.get(n).unwrap()).collect::<Vec<&Node<T, A>>>()
-    }
 }
@@ -0,0 +1,222 @@
+use super::Graph;
+use crate::{Edge, Error, ErrorKind};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::hash::Hash;
+
+impl<T, A, W> Graph<T, A, W>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+    W: Copy + PartialOrd,
+{
+    /**
+    Removes the edge between `u` and `v`. If `specs.multi_edges` is `true` all the edges
+    between `u` and `v` are removed; use `remove_edges_where` to remove only some of them.
+
+    # Arguments
+
+    * `u`: The name of the first node of the edge.
+    * `v`: The name of the second node of the edge.
+
+    # Returns
+
+    If no edge exists between `u` and `v`, `Err` is returned.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n2", "n3")]);
+    graph.remove_edge("n1", "n2").unwrap();
+    assert!(graph.get_edge("n1", "n2").is_err());
+    assert_eq!(graph.get_all_nodes().len(), 3);
+    ```
+    */
+    pub fn remove_edge(&mut self, u: T, v: T) -> Result<(), Error> {
+        let ordered = match !self.specs.directed && u > v {
+            false => (u.clone(), v.clone()),
+            true => (v.clone(), u.clone()),
+        };
+        match self.edges.remove(&ordered) {
+            None => Err(Error {
+                kind: ErrorKind::EdgeNotFound,
+                message: format!("The requested edge ({}, {}) does not exist.", u, v),
+            }),
+            Some(_) => {
+                self.edge_keys.remove(&ordered);
+                self.remove_adjacency(&ordered.0, &ordered.1);
+                Ok(())
+            }
+        }
+    }
+
+    /**
+    Removes all the edges for which `predicate` returns `true`.
+
+    # Arguments
+
+    * `predicate`: a function that is called with each edge and returns `true` if the
+      edge should be removed
+
+    # Returns
+
+    The number of edges that were removed.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected_create_missing());
+    graph.add_edges(vec![
+        Edge::with_weight("n1", "n2", 1.0),
+        Edge::with_weight("n2", "n3", 5.0),
+        Edge::with_weight("n3", "n1", 7.0),
+    ]);
+    let removed = graph.remove_edges_where(|e| e.weight > 2.0);
+    assert_eq!(removed, 2);
+    assert_eq!(graph.get_all_edges().len(), 1);
+    ```
+    */
+    pub fn remove_edges_where<F>(&mut self, predicate: F) -> usize
+    where
+        F: Fn(&Edge<T, A, W>) -> bool,
+    {
+        let mut num_removed = 0;
+        let mut emptied = vec![];
+        let mut retained = vec![];
+        for (key, edges) in self.edges.iter_mut() {
+            let keep: Vec<bool> = edges.iter().map(|e| !predicate(e)).collect();
+            let num_kept = keep.iter().filter(|k| **k).count();
+            if num_kept == edges.len() {
+                continue;
+            }
+            num_removed += edges.len() - num_kept;
+            match num_kept {
+                0 => emptied.push(key.clone()),
+                _ => {
+                    let mut index = 0;
+                    edges.retain(|_e| {
+                        index += 1;
+                        keep[index - 1]
+                    });
+                    retained.push((key.clone(), keep));
+                }
+            }
+        }
+        for (key, keep) in retained {
+            self.retain_edge_keys(&key, &keep);
+        }
+        for (u, v) in emptied {
+            let key = (u.clone(), v.clone());
+            self.edges.remove(&key);
+            self.edge_keys.remove(&key);
+            self.remove_adjacency(&u, &v);
+        }
+        num_removed
+    }
+
+    /**
+    Removes a node and all the edges that connect to it.
+
+    # Arguments
+
+    * `name`: The name of the node to remove.
+
+    # Returns
+
+    If the node doesn't exist, `Err` is returned.
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n2", "n3"), Edge::new("n1", "n3")]);
+    graph.remove_node("n2").unwrap();
+    assert_eq!(graph.get_all_nodes().len(), 2);
+    assert_eq!(graph.get_all_edges().len(), 1);
+    ```
+    */
+    pub fn remove_node(&mut self, name: T) -> Result<(), Error> {
+        self.ensure_node(&name)?;
+        self.nodes.remove(&name);
+        let successors = self.successors.remove(&name).unwrap_or_default();
+        let predecessors = self.predecessors.remove(&name).unwrap_or_default();
+        for successor in successors.iter() {
+            let key = match !self.specs.directed && *successor < name {
+                false => (name.clone(), successor.clone()),
+                true => (successor.clone(), name.clone()),
+            };
+            self.edges.remove(&key);
+            self.edge_keys.remove(&key);
+            self.remove_adjacency(&key.0, &key.1);
+        }
+        for predecessor in predecessors.iter() {
+            let key = (predecessor.clone(), name.clone());
+            self.edges.remove(&key);
+            self.edge_keys.remove(&key);
+            self.remove_adjacency(&key.0, &key.1);
+        }
+        Ok(())
+    }
+
+    /**
+    Removes nodes and all the edges that connect to them.
+
+    # Arguments
+
+    * `names`: The names of the nodes to remove.
+
+    # Returns
+
+    If any of the nodes don't exist, `Err` is returned and no nodes are removed.
+
+    # Examples
+
+    ```
+    use graphrs::generators;
+
+    let mut graph = generators::social::karate_club_graph();
+    graph.remove_nodes_from(&[0, 33]).unwrap();
+    assert_eq!(graph.get_all_nodes().len(), 32);
+    ```
+    */
+    pub fn remove_nodes_from(&mut self, names: &[T]) -> Result<(), Error> {
+        for name in names {
+            self.ensure_node(name)?;
+        }
+        let unique: HashSet<&T> = names.iter().collect();
+        for name in unique {
+            self.remove_node(name.clone())?;
+        }
+        Ok(())
+    }
+
+    /**
+    Updates `successors` and `predecessors` after the edges with the key `(u, v)` have
+    been removed, removing any sets that become empty.
+    */
+    pub(super) fn remove_adjacency(&mut self, u: &T, v: &T) {
+        let pairs = match self.specs.directed {
+            true => vec![(u, v, true), (v, u, false)],
+            false => vec![(u, v, true), (v, u, true)],
+        };
+        for (node, other, is_successor) in pairs {
+            let map = match is_successor {
+                true => &mut self.successors,
+                false => &mut self.predecessors,
+            };
+            if let Some(set) = map.get_mut(node) {
+                set.remove(other);
+                if set.is_empty() {
+                    map.remove(node);
+                }
+            }
+        }
+    }
+}
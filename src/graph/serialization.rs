@@ -0,0 +1,95 @@
+use super::Graph;
+use crate::{Edge, GraphSpecs, Node};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+The form a `Graph` is serialized in: its specs, its nodes and its edges, and for a
+multigraph the key of each edge.
+*/
+#[derive(Serialize)]
+struct SerializedGraph<'a, T, A>
+where
+    T: PartialOrd + Send,
+{
+    specs: &'a GraphSpecs,
+    nodes: Vec<&'a Node<T, A>>,
+    edges: Vec<&'a Edge<T, A>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    edge_keys: Option<Vec<usize>>,
+}
+
+#[derive(Deserialize)]
+struct DeserializedGraph<T, A>
+where
+    T: PartialOrd + Send,
+{
+    specs: GraphSpecs,
+    nodes: Vec<Node<T, A>>,
+    edges: Vec<Edge<T, A>>,
+    #[serde(default)]
+    edge_keys: Option<Vec<usize>>,
+}
+
+/**
+A `Graph` is serialized as its `specs`, a list of its `nodes` and a list of its `edges`,
+both sorted by node name, rather than as its internal maps, which would require formats to
+support non-string keys. A multigraph also has a list of `edge_keys`, the key of each edge.
+*/
+impl<T, A> Serialize for Graph<T, A>
+where
+    T: Serialize + Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Serialize + Clone,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut nodes = self.get_all_nodes();
+        nodes.sort_by(|n1, n2| n1.name.cmp(&n2.name));
+        // a stable sort, so parallel edges keep their order
+        let mut edges = self.get_all_edges_with_keys();
+        edges.sort_by(|(e1, _), (e2, _)| (&e1.u, &e1.v).cmp(&(&e2.u, &e2.v)));
+        let (edges, keys): (Vec<&Edge<T, A>>, Vec<usize>) = edges.into_iter().unzip();
+        SerializedGraph {
+            specs: &self.specs,
+            nodes,
+            edges,
+            edge_keys: self.specs.multi_edges.then_some(keys),
+        }
+        .serialize(serializer)
+    }
+}
+
+/**
+A `Graph` is deserialized by adding its nodes and edges to a new `Graph` with its `specs`.
+The edges of a multigraph are given their `edge_keys`, if there are any.
+*/
+impl<'de, T, A> Deserialize<'de> for Graph<T, A>
+where
+    T: Deserialize<'de> + Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Deserialize<'de> + Clone,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let graph = DeserializedGraph::deserialize(deserializer)?;
+        let keys = match graph.edge_keys {
+            None => None,
+            Some(keys) if keys.len() == graph.edges.len() => Some(keys),
+            Some(_) => return Err(de::Error::custom("there isn't a key for every edge")),
+        };
+        let mut new_graph = Graph::new(graph.specs);
+        new_graph.add_nodes(graph.nodes);
+        let result = match keys {
+            None => new_graph.add_edges(graph.edges),
+            Some(keys) => {
+                new_graph.add_edges_with_keys(graph.edges.into_iter().zip(keys).collect())
+            }
+        };
+        result.map_err(|e| de::Error::custom(e.message))?;
+        Ok(new_graph)
+    }
+}
@@ -0,0 +1,192 @@
+use super::Graph;
+use crate::{EdgeDedupeStrategy, Error, ErrorKind, GraphSpecs, Node};
+use std::fmt::Display;
+use std::hash::Hash;
+
+impl<T, A> Graph<T, A>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+{
+    /**
+    Returns a graph with all the nodes and edges of this graph and `other`.
+
+    Nodes that are in both graphs keep the attributes they have in this graph, unless they
+    have none. If both graphs have an edge between the same two nodes, and
+    `specs.multi_edges` is `false`, this graph's `specs.edge_dedupe_strategy` determines
+    what happens: `Error` returns an error, `KeepFirst` keeps this graph's edge and
+    `KeepLast` keeps the edge from `other`.
+
+    # Arguments
+
+    * `other`: the graph to combine with this one; it must also be directed, or also undirected
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, EdgeDedupeStrategy, Graph, GraphSpecs};
+
+    let specs = GraphSpecs {
+        edge_dedupe_strategy: EdgeDedupeStrategy::KeepFirst,
+        ..GraphSpecs::directed_create_missing()
+    };
+    let mut graph1: Graph<&str, ()> = Graph::new(specs.clone());
+    graph1.add_edges(vec![Edge::with_weight("n1", "n2", 1.0)]);
+    let mut graph2: Graph<&str, ()> = Graph::new(specs);
+    graph2.add_edges(vec![Edge::with_weight("n1", "n2", 2.0), Edge::new("n2", "n3")]);
+
+    let union = graph1.union(&graph2).unwrap();
+    assert_eq!(union.get_all_edges().len(), 2);
+    assert_eq!(union.get_edge("n1", "n2").unwrap().weight, 1.0);
+    ```
+    */
+    pub fn union(&self, other: &Graph<T, A>) -> Result<Graph<T, A>, Error> {
+        self.ensure_same_directedness(other)?;
+        let mut graph = Graph::new(self.specs.clone());
+        graph.add_nodes(self.get_all_nodes().into_iter().cloned().collect());
+        for node in other.get_all_nodes() {
+            match graph.nodes.get(&node.name) {
+                Some(existing) if existing.attributes.is_some() => {}
+                _ => graph.add_node(node.clone()),
+            }
+        }
+        graph.add_edges(self.get_all_edges().into_iter().cloned().collect())?;
+        graph.add_edges(other.get_all_edges().into_iter().cloned().collect())?;
+        Ok(graph)
+    }
+
+    /**
+    Returns a graph with all the nodes and edges of this graph and `other`, where `other`
+    takes precedence: nodes that are in both graphs have the attributes they have in
+    `other`, unless they have none, and if both graphs have an edge between the same two
+    nodes the edge from `other` is kept. If `specs.multi_edges` is `true` all the edges
+    of both graphs are kept.
+
+    # Arguments
+
+    * `other`: the graph to compose with this one; it must also be directed, or also undirected
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs, Node};
+
+    let mut graph1: Graph<&str, &str> = Graph::new(GraphSpecs::undirected_create_missing());
+    graph1.add_node(Node::from_name_and_attributes("n1", "old"));
+    graph1.add_edges(vec![Edge::with_weight("n1", "n2", 1.0)]);
+    let mut graph2: Graph<&str, &str> = Graph::new(GraphSpecs::undirected_create_missing());
+    graph2.add_node(Node::from_name_and_attributes("n1", "new"));
+    graph2.add_edges(vec![Edge::with_weight("n2", "n1", 2.0)]);
+
+    let composed = graph1.compose(&graph2).unwrap();
+    assert_eq!(composed.get_edge("n1", "n2").unwrap().weight, 2.0);
+    assert_eq!(composed.get_node("n1").unwrap().attributes, Some("new"));
+    ```
+    */
+    pub fn compose(&self, other: &Graph<T, A>) -> Result<Graph<T, A>, Error> {
+        self.ensure_same_directedness(other)?;
+        let mut graph = Graph::new(GraphSpecs {
+            edge_dedupe_strategy: EdgeDedupeStrategy::KeepLast,
+            ..self.specs.clone()
+        });
+        graph.add_nodes(self.get_all_nodes().into_iter().cloned().collect());
+        for node in other.get_all_nodes() {
+            match (graph.nodes.get(&node.name), &node.attributes) {
+                (Some(_), None) => {}
+                _ => graph.add_node(node.clone()),
+            }
+        }
+        graph.add_edges(self.get_all_edges().into_iter().cloned().collect())?;
+        graph.add_edges(other.get_all_edges().into_iter().cloned().collect())?;
+        graph.specs = self.specs.clone();
+        Ok(graph)
+    }
+
+    /**
+    Returns a graph with the nodes that are in both this graph and `other`, and the edges
+    of this graph whose nodes are joined by an edge in `other` too. Nodes and edges keep
+    their attributes and weights from this graph.
+
+    # Arguments
+
+    * `other`: the graph to intersect with this one; it must also be directed, or also undirected
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph1: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph1.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n2", "n3")]);
+    let mut graph2: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph2.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n3", "n2")]);
+
+    let intersection = graph1.intersection(&graph2).unwrap();
+    assert_eq!(intersection.get_all_nodes().len(), 3);
+    assert_eq!(intersection.get_all_edges().len(), 1);
+    ```
+    */
+    pub fn intersection(&self, other: &Graph<T, A>) -> Result<Graph<T, A>, Error> {
+        self.ensure_same_directedness(other)?;
+        let nodes = self
+            .get_all_nodes()
+            .into_iter()
+            .filter(|n| other.nodes.contains_key(&n.name))
+            .cloned()
+            .collect::<Vec<Node<T, A>>>();
+        let edges = self
+            .edges
+            .iter()
+            .filter(|(key, _)| other.edges.contains_key(key))
+            .flat_map(|(_, edges)| edges.iter().cloned())
+            .collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, self.specs.clone())
+    }
+
+    /**
+    Returns a graph with all the nodes of this graph, and the edges of this graph whose
+    nodes aren't joined by an edge in `other`.
+
+    # Arguments
+
+    * `other`: the graph whose edges are removed; it must also be directed, or also undirected
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph1: Graph<&str, ()> = Graph::new(GraphSpecs::undirected_create_missing());
+    graph1.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n2", "n3")]);
+    let mut graph2: Graph<&str, ()> = Graph::new(GraphSpecs::undirected_create_missing());
+    graph2.add_edges(vec![Edge::new("n2", "n1")]);
+
+    let difference = graph1.difference(&graph2).unwrap();
+    assert_eq!(difference.get_all_nodes().len(), 3);
+    assert_eq!(difference.get_all_edges().len(), 1);
+    assert!(difference.get_edge("n2", "n3").is_ok());
+    ```
+    */
+    pub fn difference(&self, other: &Graph<T, A>) -> Result<Graph<T, A>, Error> {
+        self.ensure_same_directedness(other)?;
+        let nodes = self.get_all_nodes().into_iter().cloned().collect();
+        let edges = self
+            .edges
+            .iter()
+            .filter(|(key, _)| !other.edges.contains_key(key))
+            .flat_map(|(_, edges)| edges.iter().cloned())
+            .collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, self.specs.clone())
+    }
+
+    /// Returns an error if `other` isn't directed when this graph is, or vice versa.
+    fn ensure_same_directedness(&self, other: &Graph<T, A>) -> Result<(), Error> {
+        match self.specs.directed == other.specs.directed {
+            true => Ok(()),
+            false => Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: "Both graphs must be directed, or both must be undirected.".to_string(),
+            }),
+        }
+    }
+}
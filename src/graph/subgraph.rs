@@ -1,5 +1,6 @@
 use super::Graph;
-use crate::{Edge, Node};
+use crate::algorithms::shortest_path::dijkstra;
+use crate::{Edge, Error, Node};
 use std::collections::HashSet;
 use std::fmt::Display;
 use std::hash::Hash;
@@ -11,7 +12,7 @@ where
 {
     /**
     Returns an induced subgraph that contains only the specified nodes
-    and the edges between those nodes.
+    and the edges between those nodes. In a multigraph the edges keep their keys.
 
     # Arguments
 
@@ -35,11 +36,115 @@ where
             .cloned()
             .collect::<Vec<Node<T, A>>>();
         let new_edges = self
-            .get_all_edges()
+            .get_all_edges_with_keys()
             .into_iter()
-            .filter(|e| nodes_set.contains(&e.u) && nodes_set.contains(&e.v))
+            .filter(|(e, _key)| nodes_set.contains(&e.u) && nodes_set.contains(&e.v))
+            .map(|(e, key)| (e.clone(), key))
+            .collect::<Vec<(Edge<T, A>, usize)>>();
+        let mut graph = Graph::new(self.specs.clone());
+        graph.add_nodes(new_nodes);
+        graph.add_edges_with_keys(new_edges).unwrap();
+        graph
+    }
+
+    /**
+    Returns the subgraph that contains only the specified edges and the nodes they connect.
+    The weights and attributes of the edges and nodes are kept.
+
+    If `specs.multi_edges` is `true` all the edges between each pair of nodes are included,
+    with their keys.
+    Pairs of nodes that don't have an edge between them are ignored.
+
+    # Arguments
+
+    * `edges`: The edges the subgraph must contain, as `(u, v)` tuples.
+
+    # Examples
+
+    ```
+    use graphrs::generators;
+    let graph = generators::social::karate_club_graph();
+    let subgraph = graph.get_edge_subgraph(&[(0, 1), (1, 2), (5, 6)]);
+    assert_eq!(subgraph.get_all_nodes().len(), 5);
+    assert_eq!(subgraph.get_all_edges().len(), 3);
+    ```
+    */
+    pub fn get_edge_subgraph(&self, edges: &[(T, T)]) -> Graph<T, A> {
+        let mut pairs: Vec<(T, T)> = edges
+            .iter()
+            .map(|(u, v)| match !self.specs.directed && u > v {
+                false => (u.clone(), v.clone()),
+                true => (v.clone(), u.clone()),
+            })
+            .collect();
+        pairs.sort();
+        pairs.dedup();
+        let mut new_edges: Vec<(Edge<T, A>, usize)> = vec![];
+        for (u, v) in pairs {
+            match self.specs.multi_edges {
+                false => {
+                    if let Ok(edge) = self.get_edge(u, v) {
+                        new_edges.push((edge.clone(), 0));
+                    }
+                }
+                true => {
+                    if let Ok(edges) = self.get_edges(u.clone(), v.clone()) {
+                        let keys = self.get_edge_keys(u, v).unwrap();
+                        new_edges.extend(edges.into_iter().cloned().zip(keys));
+                    }
+                }
+            }
+        }
+        let node_names: HashSet<&T> = new_edges.iter().flat_map(|(e, _key)| [&e.u, &e.v]).collect();
+        let new_nodes = self
+            .get_all_nodes()
+            .into_iter()
+            .filter(|n| node_names.contains(&n.name))
             .cloned()
-            .collect::<Vec<Edge<T, A>>>();
-        Graph::new_from_nodes_and_edges(new_nodes, new_edges, self.specs.clone()).unwrap()
+            .collect::<Vec<Node<T, A>>>();
+        let mut graph = Graph::new(self.specs.clone());
+        graph.add_nodes(new_nodes);
+        graph.add_edges_with_keys(new_edges).unwrap();
+        graph
+    }
+
+    /**
+    Returns the induced subgraph of the nodes that are within `radius` of `center`.
+
+    Distances follow the direction of the edges in a directed graph.
+
+    # Arguments
+
+    * `center`: The node at the center of the subgraph.
+    * `radius`: The largest distance from `center` of the nodes to include; a number of
+      edges if `weighted` is `false`, otherwise a sum of edge weights.
+    * `weighted`: Set to `true` to measure distances with edge weights.
+
+    # Returns
+
+    If `center` doesn't exist, or `weighted` is `true` and some edges don't have a weight,
+    `Err` is returned.
+
+    # Examples
+
+    ```
+    use graphrs::generators;
+    let graph = generators::social::karate_club_graph();
+    let ego_graph = graph.get_ego_graph(11, 1.0, false).unwrap();
+    assert_eq!(ego_graph.get_all_nodes().len(), 2);
+    assert_eq!(ego_graph.get_all_edges().len(), 1);
+    ```
+    */
+    pub fn get_ego_graph(
+        &self,
+        center: T,
+        radius: f64,
+        weighted: bool,
+    ) -> Result<Graph<T, A>, Error> {
+        self.ensure_node(&center)?;
+        let distances =
+            dijkstra::single_source_distances(self, weighted, center, Some(radius), None)?;
+        let nodes: Vec<T> = distances.into_keys().collect();
+        Ok(self.get_subgraph(&nodes))
     }
 }
@@ -0,0 +1,177 @@
+use crate::{Error, ErrorKind};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+An edge of a [TemporalGraph](./struct.TemporalGraph.html): a contact from `u` to `v` that
+starts at `time` and takes `duration` to traverse, arriving at `time + duration`. Between
+two nodes there can be any number of contacts, at different or equal times.
+
+# Examples
+
+```
+use graphrs::TemporalEdge;
+
+let edge = TemporalEdge::with_duration("a", "b", 9.0, 0.5);
+assert_eq!(edge.arrival_time(), 9.5);
+```
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemporalEdge<T> {
+    pub u: T,
+    pub v: T,
+    /// The time at which the edge can be traversed.
+    pub time: f64,
+    /// The time it takes to traverse the edge; zero for an instantaneous contact.
+    pub duration: f64,
+}
+
+impl<T> TemporalEdge<T> {
+    /// Creates an instantaneous edge from `u` to `v` at `time`.
+    pub fn new(u: T, v: T, time: f64) -> TemporalEdge<T> {
+        TemporalEdge {
+            u,
+            v,
+            time,
+            duration: 0.0,
+        }
+    }
+
+    /// Creates an edge from `u` to `v` that departs at `time` and takes `duration`.
+    pub fn with_duration(u: T, v: T, time: f64, duration: f64) -> TemporalEdge<T> {
+        TemporalEdge {
+            u,
+            v,
+            time,
+            duration,
+        }
+    }
+
+    /// Returns the time at which the edge arrives at `v`: `time + duration`.
+    pub fn arrival_time(&self) -> f64 {
+        self.time + self.duration
+    }
+}
+
+/**
+A graph whose edges are only available at a point in time, such as the contacts in a
+contact network or the connections of a transit timetable. A path through a
+`TemporalGraph` is only valid if it's time-respecting: each edge departs no earlier than
+the previous edge arrives. See
+[shortest_path::temporal](./algorithms/shortest_path/temporal/index.html) for the
+algorithms that find such paths.
+
+Nodes are added when an edge that uses them is added, or with `add_node`. An undirected
+`TemporalGraph` allows each edge to be traversed in either direction.
+
+# Examples
+
+```
+use graphrs::{TemporalEdge, TemporalGraph};
+
+let mut graph: TemporalGraph<&str> = TemporalGraph::new(true);
+graph.add_edges(vec![
+    TemporalEdge::new("a", "b", 1.0),
+    TemporalEdge::new("b", "c", 2.0),
+    TemporalEdge::new("c", "d", 1.5),
+]).unwrap();
+assert_eq!(graph.get_all_node_names().len(), 4);
+assert_eq!(graph.get_edges_from("b").len(), 1);
+```
+*/
+pub struct TemporalGraph<T> {
+    nodes: HashSet<T>,
+    edges: Vec<TemporalEdge<T>>,
+    /// The indexes into `edges` of the edges that can be traversed from each node.
+    out_edges: HashMap<T, Vec<usize>>,
+    directed: bool,
+}
+
+impl<T> TemporalGraph<T>
+where
+    T: Eq + Clone + Ord + Hash + Display,
+{
+    /// Creates an empty `TemporalGraph`, which is directed if `directed` is `true`.
+    pub fn new(directed: bool) -> TemporalGraph<T> {
+        TemporalGraph {
+            nodes: HashSet::new(),
+            edges: vec![],
+            out_edges: HashMap::new(),
+            directed,
+        }
+    }
+
+    /// Returns `true` if the graph is directed.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Adds a node, if it isn't already in the graph.
+    pub fn add_node(&mut self, node_name: T) {
+        self.nodes.insert(node_name);
+    }
+
+    /**
+    Adds an edge, and its nodes if they aren't already in the graph.
+
+    # Returns
+
+    An `Error` with a `kind` of `ErrorKind::InvalidArgument` is returned if the edge's
+    `time` is NaN or its `duration` is negative or NaN.
+    */
+    pub fn add_edge(&mut self, edge: TemporalEdge<T>) -> Result<(), Error> {
+        if edge.time.is_nan() || edge.duration.is_nan() || edge.duration < 0.0 {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!(
+                    "The edge from '{}' to '{}' must have a time and a non-negative duration.",
+                    edge.u, edge.v
+                ),
+            });
+        }
+        let index = self.edges.len();
+        self.out_edges.entry(edge.u.clone()).or_default().push(index);
+        if !self.directed && edge.u != edge.v {
+            self.out_edges.entry(edge.v.clone()).or_default().push(index);
+        }
+        self.nodes.insert(edge.u.clone());
+        self.nodes.insert(edge.v.clone());
+        self.edges.push(edge);
+        Ok(())
+    }
+
+    /// Adds edges, stopping at the first one that can't be added.
+    pub fn add_edges(&mut self, edges: Vec<TemporalEdge<T>>) -> Result<(), Error> {
+        for edge in edges {
+            self.add_edge(edge)?;
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if `node_name` is a node of the graph.
+    pub fn has_node(&self, node_name: &T) -> bool {
+        self.nodes.contains(node_name)
+    }
+
+    /// Returns the names of all the nodes, sorted.
+    pub fn get_all_node_names(&self) -> Vec<&T> {
+        let mut names: Vec<&T> = self.nodes.iter().collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Returns all the edges, in the order they were added.
+    pub fn get_all_edges(&self) -> &[TemporalEdge<T>] {
+        &self.edges
+    }
+
+    /**
+    Returns the edges that can be traversed from `node_name`, in the order they were
+    added. For an undirected graph these are all the edges that have `node_name` as their
+    `u` or `v`.
+    */
+    pub fn get_edges_from(&self, node_name: T) -> Vec<&TemporalEdge<T>> {
+        self.out_edges.get(&node_name).into_iter().flatten().map(|i| &self.edges[*i]).collect()
+    }
+}
@@ -0,0 +1,337 @@
+use super::Graph;
+use crate::{Edge, Error, ErrorKind, GraphSpecs, Node};
+use itertools::Itertools;
+use std::fmt::Display;
+use std::hash::Hash;
+
+type NodePredicate<'a, T, A> = Box<dyn Fn(&Node<T, A>) -> bool + 'a>;
+type EdgePredicate<'a, T, A> = Box<dyn Fn(&Edge<T, A>) -> bool + 'a>;
+
+/**
+A read-only view of a [Graph](./struct.Graph.html) that hides the nodes and edges that
+don't satisfy its predicates. Nothing is copied: the predicates are applied each time the
+view is queried. An edge is visible if it satisfies the edge predicates and both of its
+nodes are visible.
+
+A `GraphView` is created with [Graph::filter_nodes](./struct.Graph.html#method.filter_nodes)
+or [Graph::filter_edges](./struct.Graph.html#method.filter_edges), and can be filtered
+further with its own `filter_nodes` and `filter_edges` methods. Use `to_graph` to create a
+`Graph` with just the visible nodes and edges, for example to run an algorithm on it.
+
+# Examples
+
+```
+use graphrs::generators;
+
+let graph = generators::social::karate_club_graph();
+let view = graph
+    .filter_nodes(|n| n.name < 10)
+    .filter_edges(|e| e.u != 0);
+assert_eq!(view.get_all_nodes().len(), 10);
+assert!(view.get_edge(0, 1).is_err());
+assert!(view.get_edge(1, 2).is_ok());
+```
+*/
+pub struct GraphView<'a, T, A>
+where
+    T: PartialOrd + Send,
+    A: Clone,
+{
+    graph: &'a Graph<T, A>,
+    /// The [GraphSpecs](./struct.GraphSpecs.html) of the underlying graph.
+    pub specs: &'a GraphSpecs,
+    node_predicates: Vec<NodePredicate<'a, T, A>>,
+    edge_predicates: Vec<EdgePredicate<'a, T, A>>,
+}
+
+impl<T, A> Graph<T, A>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+{
+    /**
+    Returns a [GraphView](./struct.GraphView.html) of the graph that only has the nodes
+    for which `predicate` returns `true`, and the edges between them.
+
+    # Arguments
+
+    * `predicate`: a function that is called with a node and returns `true` if it is visible
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n2", "n3")]);
+    let view = graph.filter_nodes(|n| n.name != "n3");
+    assert_eq!(view.get_all_nodes().len(), 2);
+    assert_eq!(view.get_all_edges().len(), 1);
+    ```
+    */
+    pub fn filter_nodes<'a, F>(&'a self, predicate: F) -> GraphView<'a, T, A>
+    where
+        F: Fn(&Node<T, A>) -> bool + 'a,
+    {
+        GraphView::new(self).filter_nodes(predicate)
+    }
+
+    /**
+    Returns a [GraphView](./struct.GraphView.html) of the graph that has all the nodes
+    but only the edges for which `predicate` returns `true`.
+
+    # Arguments
+
+    * `predicate`: a function that is called with an edge and returns `true` if it is visible
+
+    # Examples
+
+    ```
+    use graphrs::{Edge, Graph, GraphSpecs};
+
+    let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+    graph.add_edges(vec![Edge::with_weight("n1", "n2", 1.0), Edge::with_weight("n2", "n3", 5.0)]);
+    let view = graph.filter_edges(|e| e.weight < 2.0);
+    assert_eq!(view.get_all_nodes().len(), 3);
+    assert_eq!(view.get_all_edges().len(), 1);
+    ```
+    */
+    pub fn filter_edges<'a, F>(&'a self, predicate: F) -> GraphView<'a, T, A>
+    where
+        F: Fn(&Edge<T, A>) -> bool + 'a,
+    {
+        GraphView::new(self).filter_edges(predicate)
+    }
+}
+
+impl<'a, T, A> GraphView<'a, T, A>
+where
+    T: Eq + Clone + PartialOrd + Ord + Hash + Send + Sync + Display,
+    A: Clone,
+{
+    fn new(graph: &'a Graph<T, A>) -> GraphView<'a, T, A> {
+        GraphView {
+            graph,
+            specs: &graph.specs,
+            node_predicates: vec![],
+            edge_predicates: vec![],
+        }
+    }
+
+    /// Returns a view that also hides the nodes for which `predicate` returns `false`.
+    pub fn filter_nodes<F>(mut self, predicate: F) -> GraphView<'a, T, A>
+    where
+        F: Fn(&Node<T, A>) -> bool + 'a,
+    {
+        self.node_predicates.push(Box::new(predicate));
+        self
+    }
+
+    /// Returns a view that also hides the edges for which `predicate` returns `false`.
+    pub fn filter_edges<F>(mut self, predicate: F) -> GraphView<'a, T, A>
+    where
+        F: Fn(&Edge<T, A>) -> bool + 'a,
+    {
+        self.edge_predicates.push(Box::new(predicate));
+        self
+    }
+
+    /// Returns all the visible edges.
+    pub fn get_all_edges(&self) -> Vec<&'a Edge<T, A>> {
+        self.graph.get_all_edges().into_iter().filter(|e| self.is_edge_visible(e)).collect()
+    }
+
+    /// Returns all the visible nodes.
+    pub fn get_all_nodes(&self) -> Vec<&'a Node<T, A>> {
+        self.graph.get_all_nodes().into_iter().filter(|n| self.is_node_visible(n)).collect()
+    }
+
+    /// Returns the names of all the visible nodes.
+    pub fn get_all_node_names(&self) -> Vec<&'a T> {
+        self.get_all_nodes().into_iter().map(|n| &n.name).collect()
+    }
+
+    /// Gets the visible edge between `u` and `v`. See [Graph::get_edge](./struct.Graph.html#method.get_edge).
+    pub fn get_edge(&self, u: T, v: T) -> Result<&'a Edge<T, A>, Error> {
+        let edge = self.graph.get_edge(u.clone(), v.clone())?;
+        match self.is_edge_visible(edge) {
+            true => Ok(edge),
+            false => Err(get_edge_not_found_error(&u, &v)),
+        }
+    }
+
+    /// Gets the visible edges between `u` and `v`. See [Graph::get_edges](./struct.Graph.html#method.get_edges).
+    pub fn get_edges(&self, u: T, v: T) -> Result<Vec<&'a Edge<T, A>>, Error> {
+        let edges: Vec<&Edge<T, A>> = self
+            .graph
+            .get_edges(u.clone(), v.clone())?
+            .into_iter()
+            .filter(|e| self.is_edge_visible(e))
+            .collect();
+        match edges.is_empty() {
+            true => Err(get_edge_not_found_error(&u, &v)),
+            false => Ok(edges),
+        }
+    }
+
+    /// Returns all the visible edges that connect to a visible node.
+    pub fn get_edges_for_node(&self, name: T) -> Result<Vec<&'a Edge<T, A>>, Error> {
+        self.ensure_node(&name)?;
+        Ok(self
+            .graph
+            .get_edges_for_node(name)?
+            .into_iter()
+            .filter(|e| self.is_edge_visible(e))
+            .collect())
+    }
+
+    /**
+    Gets the visible nodes that are joined to a node by a visible edge, in either direction.
+    See [Graph::get_neighbor_nodes](./struct.Graph.html#method.get_neighbor_nodes).
+    */
+    pub fn get_neighbor_nodes(&self, node_name: T) -> Result<Vec<&'a Node<T, A>>, Error> {
+        let edges = self.get_edges_for_node(node_name.clone())?;
+        Ok(self.get_nodes_for_edges(edges, |e| match e.u == node_name {
+            true => &e.v,
+            false => &e.u,
+        }))
+    }
+
+    /// Gets the visible successors of a node. See [Graph::get_successor_nodes](./struct.Graph.html#method.get_successor_nodes).
+    pub fn get_successor_nodes(&self, node_name: T) -> Result<Vec<&'a Node<T, A>>, Error> {
+        self.ensure_directed("get_successor_nodes")?;
+        let edges = self.get_edges_for_node(node_name.clone())?;
+        let out_edges = edges.into_iter().filter(|e| e.u == node_name).collect();
+        Ok(self.get_nodes_for_edges(out_edges, |e| &e.v))
+    }
+
+    /// Gets the visible predecessors of a node. See [Graph::get_predecessor_nodes](./struct.Graph.html#method.get_predecessor_nodes).
+    pub fn get_predecessor_nodes(&self, node_name: T) -> Result<Vec<&'a Node<T, A>>, Error> {
+        self.ensure_directed("get_predecessor_nodes")?;
+        let edges = self.get_edges_for_node(node_name.clone())?;
+        let in_edges = edges.into_iter().filter(|e| e.v == node_name).collect();
+        Ok(self.get_nodes_for_edges(in_edges, |e| &e.u))
+    }
+
+    /**
+    Returns the visible successors of a node if the graph is directed, or its visible
+    neighbors if the graph is undirected.
+    */
+    pub fn get_successors_or_neighbors(&self, node_name: T) -> Vec<&'a Node<T, A>> {
+        match self.specs.directed {
+            true => self.get_successor_nodes(node_name).unwrap(),
+            false => self.get_neighbor_nodes(node_name).unwrap(),
+        }
+    }
+
+    /// Gets a visible node.
+    pub fn get_node(&self, name: T) -> Option<&'a Node<T, A>> {
+        self.graph.get_node(name).filter(|n| self.is_node_visible(n))
+    }
+
+    /// Returns `true` if the view has a given node.
+    pub fn has_node(&self, node_name: &T) -> bool {
+        self.get_node(node_name.clone()).is_some()
+    }
+
+    /// Computes the degree of a visible node, counting only visible edges.
+    pub fn get_node_degree(&self, node_name: T) -> Option<usize> {
+        match self.get_edges_for_node(node_name.clone()) {
+            Err(_) => None,
+            Ok(edges) => {
+                // self-loops are double-counted, as in `Graph::get_node_degree`
+                let self_loops_count =
+                    edges.iter().filter(|e| e.u == node_name && e.v == node_name).count();
+                Some(edges.len() + self_loops_count)
+            }
+        }
+    }
+
+    /// Returns the number of visible edges or the sum of their weights.
+    pub fn size(&self, weighted: bool) -> f64 {
+        match weighted {
+            false => self.get_all_edges().len() as f64,
+            true => self.get_all_edges().iter().map(|e| e.weight).sum(),
+        }
+    }
+
+    /**
+    Creates a `Graph` with the visible nodes and edges, and the same specs as the
+    underlying graph.
+
+    # Examples
+
+    ```
+    use graphrs::{algorithms::components, generators};
+
+    let graph = generators::social::karate_club_graph();
+    let subgraph = graph.filter_edges(|e| e.u != 0 && e.v != 0).to_graph();
+    assert_eq!(components::number_connected_components(&subgraph).unwrap(), 4);
+    ```
+    */
+    pub fn to_graph(&self) -> Graph<T, A> {
+        let nodes = self.get_all_nodes().into_iter().cloned().collect();
+        let edges = self.get_all_edges().into_iter().cloned().collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, self.specs.clone()).unwrap()
+    }
+
+    // PRIVATE METHODS
+
+    fn ensure_directed(&self, method: &str) -> Result<(), Error> {
+        match self.specs.directed {
+            true => Ok(()),
+            false => Err(Error {
+                kind: ErrorKind::WrongMethod,
+                message: format!(
+                    "For undirected graphs use the `get_neighbor_nodes` method instead of `{}`",
+                    method
+                ),
+            }),
+        }
+    }
+
+    fn ensure_node(&self, node_name: &T) -> Result<(), Error> {
+        match self.has_node(node_name) {
+            true => Ok(()),
+            false => Err(Error {
+                kind: ErrorKind::NodeNotFound,
+                message: format!(
+                    "Requested node '{}' was not found in the graph view.",
+                    node_name
+                ),
+            }),
+        }
+    }
+
+    fn is_node_visible(&self, node: &Node<T, A>) -> bool {
+        self.node_predicates.iter().all(|p| p(node))
+    }
+
+    fn is_edge_visible(&self, edge: &Edge<T, A>) -> bool {
+        self.edge_predicates.iter().all(|p| p(edge))
+            && (self.node_predicates.is_empty() || self.has_node(&edge.u) && self.has_node(&edge.v))
+    }
+
+    /// Returns the sorted, distinct nodes that `get_name` selects from each of `edges`.
+    fn get_nodes_for_edges<F>(&self, edges: Vec<&'a Edge<T, A>>, get_name: F) -> Vec<&'a Node<T, A>>
+    where
+        F: Fn(&'a Edge<T, A>) -> &'a T,
+    {
+        edges
+            .into_iter()
+            .map(|e| self.graph.get_node(get_name(e).clone()).unwrap())
+            .sorted_by(|a, b| Ord::cmp(&a.name, &b.name))
+            .dedup_by(|a, b| a.name == b.name)
+            .collect()
+    }
+}
+
+fn get_edge_not_found_error<T: Display>(u: &T, v: &T) -> Error {
+    Error {
+        kind: ErrorKind::EdgeNotFound,
+        message: format!(
+            "The requested edge ({}, {}) is not in the graph view.",
+            u, v
+        ),
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /**
 Specifications for the type of [Graph](./struct.Graph.html) being created
 and how various situations involving the addition of nodes and edges are handled.
@@ -20,6 +23,7 @@ let specs = GraphSpecs {
 ```
 */
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GraphSpecs {
     /// Determines if a [Graph](./struct.Graph.html) is directed or undirected.
     pub directed: bool,
@@ -47,6 +51,7 @@ Specifies options for a situation where a duplicate edge is being added to a [Gr
 `KeepLast`: discard the first (original) [Edge](./struct.Edge.html) and keep the one that is being added.
 */
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EdgeDedupeStrategy {
     Error,
     KeepFirst,
@@ -63,6 +68,7 @@ Specifies options for a situation where an [Edge](./struct.Edge.html) is being a
 `Error`: return an `Error`.
 */
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MissingNodeStrategy {
     Create,
     Error,
@@ -77,6 +83,7 @@ being added to a [Graph](./struct.Graph.html) but the [Graph](./struct.Graph.htm
 `Drop`: drops any self-loop edges
 */
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SelfLoopsFalseStrategy {
     Error,
     Drop,
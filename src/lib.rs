@@ -88,10 +88,16 @@ pub use error::{Error, ErrorKind};
 mod ext;
 
 mod graph;
-pub use graph::Graph;
+pub use graph::{
+    CsrGraph, Direction, EdgeMergeStrategy, Graph, GraphBuilder, GraphView, SelfLoopPolicy,
+    TemporalEdge, TemporalGraph,
+};
 
 pub mod algorithms;
+pub mod collections;
 pub mod generators;
+pub mod linalg;
+pub mod operators;
 pub mod readwrite;
 
 mod graph_specs;
@@ -99,5 +105,16 @@ pub use graph_specs::{
     EdgeDedupeStrategy, GraphSpecs, MissingNodeStrategy, SelfLoopsFalseStrategy,
 };
 
+mod macros;
+
 mod node;
 pub use node::Node;
+
+mod ordered;
+pub use ordered::IntoOrdered;
+
+mod parallel;
+pub use parallel::{ParallelOptions, Parallelism};
+
+mod progress;
+pub use progress::{Progress, ProgressHandle};
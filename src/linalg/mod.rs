@@ -0,0 +1,289 @@
+mod sparse_matrix;
+pub use sparse_matrix::SparseMatrix;
+
+mod spectral;
+pub(crate) use spectral::lanczos_smallest;
+pub use spectral::{algebraic_connectivity, fiedler_vector, spectral_bisection};
+
+use crate::{Error, Graph};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the adjacency matrix of the graph: the value at row `i` and column `j` is the
+weight of the edge from node `i` to node `j`, or `1.0` if `weighted` is `false`. Multiple
+edges between two nodes are summed. The matrix of an undirected graph is symmetric, with
+a self-loop appearing once on the diagonal.
+
+# Arguments
+
+* `graph`: a [Graph](../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights
+
+# Returns
+
+The node names, sorted, which give the order of the rows and columns, and the
+[SparseMatrix](struct.SparseMatrix.html). An `Error` with a `kind` of
+`ErrorKind::EdgeWeightNotSpecified` is returned if `weighted` is `true` and an edge
+doesn't have a weight.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs, linalg};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![
+    Edge::with_weight("a", "b", 2.0),
+    Edge::with_weight("b", "c", 3.0),
+]);
+let (nodes, matrix) = linalg::adjacency_matrix(&graph, true).unwrap();
+assert_eq!(nodes, vec!["a", "b", "c"]);
+assert_eq!(matrix.get(0, 1), 2.0);
+assert_eq!(matrix.get(1, 0), 0.0);
+```
+*/
+pub fn adjacency_matrix<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+) -> Result<(Vec<T>, SparseMatrix), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let (nodes, triplets) = get_adjacency_triplets(graph, weighted)?;
+    let n = nodes.len();
+    Ok((nodes, SparseMatrix::from_triplets(n, n, triplets)))
+}
+
+/**
+Returns the Laplacian matrix of the graph, `D - A`, where `A` is the
+[adjacency_matrix](fn.adjacency_matrix.html) and `D` is the diagonal matrix of the row sums
+of `A`: the (weighted) degrees, or out-degrees for a directed graph. Self-loops appear in
+both `D` and `A` and so cancel out.
+
+# Arguments
+
+* `graph`: a [Graph](../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights
+
+# Returns
+
+The node names, sorted, which give the order of the rows and columns, and the
+[SparseMatrix](struct.SparseMatrix.html). An `Error` with a `kind` of
+`ErrorKind::EdgeWeightNotSpecified` is returned if `weighted` is `true` and an edge
+doesn't have a weight.
+
+# Examples
+
+```
+use graphrs::{generators, linalg};
+
+let graph = generators::classic::path_graph(3, false);
+let (_nodes, matrix) = linalg::laplacian_matrix(&graph, false).unwrap();
+assert_eq!(matrix.get(1, 1), 2.0);
+assert_eq!(matrix.get(0, 1), -1.0);
+assert_eq!(matrix.mul_vec(&[1.0, 1.0, 1.0]), vec![0.0, 0.0, 0.0]);
+```
+*/
+pub fn laplacian_matrix<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+) -> Result<(Vec<T>, SparseMatrix), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let (nodes, triplets) = get_adjacency_triplets(graph, weighted)?;
+    let n = nodes.len();
+    let degrees = get_row_sums(n, &triplets);
+    let laplacian = triplets
+        .into_iter()
+        .map(|(i, j, w)| (i, j, -w))
+        .chain(degrees.into_iter().enumerate().map(|(i, d)| (i, i, d)))
+        .collect();
+    Ok((nodes, SparseMatrix::from_triplets(n, n, laplacian)))
+}
+
+/**
+Returns the normalized Laplacian matrix of the graph, `D^(-1/2) (D - A) D^(-1/2)`, where
+`A` is the [adjacency_matrix](fn.adjacency_matrix.html) and `D` is the diagonal matrix of
+the row sums of `A`. For a graph without self-loops the diagonal values are `1.0` and the
+value at row `i` and column `j` is `-w / sqrt(d(i) * d(j))`. The rows and columns of nodes
+whose degree is zero are all zero.
+
+# Arguments
+
+* `graph`: a [Graph](../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights
+
+# Returns
+
+The node names, sorted, which give the order of the rows and columns, and the
+[SparseMatrix](struct.SparseMatrix.html). An `Error` with a `kind` of
+`ErrorKind::EdgeWeightNotSpecified` is returned if `weighted` is `true` and an edge
+doesn't have a weight.
+
+# Examples
+
+```
+use graphrs::{generators, linalg};
+
+let graph = generators::classic::star_graph(4, false);
+let (_nodes, matrix) = linalg::normalized_laplacian(&graph, false).unwrap();
+assert_eq!(matrix.get(0, 0), 1.0);
+assert_eq!(matrix.get(0, 1), -0.5);
+```
+*/
+pub fn normalized_laplacian<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+) -> Result<(Vec<T>, SparseMatrix), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let (nodes, triplets) = get_adjacency_triplets(graph, weighted)?;
+    let n = nodes.len();
+    let degrees = get_row_sums(n, &triplets);
+    let scales: Vec<f64> = degrees
+        .iter()
+        .map(|d| match *d > 0.0 {
+            true => 1.0 / d.sqrt(),
+            false => 0.0,
+        })
+        .collect();
+    let normalized = triplets
+        .into_iter()
+        .map(|(i, j, w)| (i, j, -w * scales[i] * scales[j]))
+        .chain(degrees.iter().enumerate().map(|(i, d)| (i, i, d * scales[i] * scales[i])))
+        .collect();
+    Ok((nodes, SparseMatrix::from_triplets(n, n, normalized)))
+}
+
+/**
+Returns the incidence matrix of the graph, which has a row for each node and a column for
+each edge. The value at row `i` and column `k` is non-zero if node `i` is an endpoint of
+edge `k`: `-1.0` for the `u` node and `1.0` for the `v` node if `oriented` is `true`, and
+`1.0` for both otherwise. The values are multiplied by the edge weight if `weighted` is
+`true`. The column of a self-loop is all zero.
+
+# Arguments
+
+* `graph`: a [Graph](../struct.Graph.html) instance
+* `oriented`: set to `true` to give the `u` node of each edge a negative value
+* `weighted`: set to `true` to use edge weights
+
+# Returns
+
+The node names, sorted, which give the order of the rows; the `(u, v)` node names of each
+edge, sorted, which give the order of the columns; and the
+[SparseMatrix](struct.SparseMatrix.html). Multiple edges between two nodes get a column
+each. An `Error` with a `kind` of `ErrorKind::EdgeWeightNotSpecified` is returned if
+`weighted` is `true` and an edge doesn't have a weight.
+
+# Examples
+
+```
+use graphrs::{generators, linalg};
+
+let graph = generators::classic::path_graph(3, true);
+let (nodes, edges, matrix) = linalg::incidence_matrix(&graph, true, false).unwrap();
+assert_eq!(edges, vec![(0, 1), (1, 2)]);
+assert_eq!(matrix.nrows(), 3);
+assert_eq!(matrix.ncols(), 2);
+assert_eq!(matrix.get(0, 0), -1.0);
+assert_eq!(matrix.get(1, 0), 1.0);
+assert_eq!(matrix.get(1, 1), -1.0);
+```
+*/
+#[allow(clippy::type_complexity)]
+pub fn incidence_matrix<T, A>(
+    graph: &Graph<T, A>,
+    oriented: bool,
+    weighted: bool,
+) -> Result<(Vec<T>, Vec<(T, T)>, SparseMatrix), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let nodes = get_sorted_node_names(graph);
+    let indexes: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    let mut edges = graph.get_all_edges();
+    // a stable sort keeps multiple edges between two nodes in the order they were added
+    edges.sort_by(|a, b| (&a.u, &a.v).cmp(&(&b.u, &b.v)));
+    let mut triplets = vec![];
+    for (k, edge) in edges.iter().enumerate() {
+        if edge.u == edge.v {
+            continue;
+        }
+        let value = match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+        let u_value = match oriented {
+            true => -value,
+            false => value,
+        };
+        triplets.push((indexes[&edge.u], k, u_value));
+        triplets.push((indexes[&edge.v], k, value));
+    }
+    let matrix = SparseMatrix::from_triplets(nodes.len(), edges.len(), triplets);
+    let edges = edges.into_iter().map(|e| (e.u.clone(), e.v.clone())).collect();
+    Ok((nodes, edges, matrix))
+}
+
+/// Returns the sorted node names and the `(row, column, weight)` triplets of the adjacency
+/// matrix, with an entry for each edge and, for an undirected graph, its reverse.
+#[allow(clippy::type_complexity)]
+fn get_adjacency_triplets<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+) -> Result<(Vec<T>, Vec<(usize, usize, f64)>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let nodes = get_sorted_node_names(graph);
+    let indexes: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    let mut triplets = vec![];
+    for edge in graph.get_all_edges() {
+        let weight = match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+        let u = indexes[&edge.u];
+        let v = indexes[&edge.v];
+        triplets.push((u, v, weight));
+        if !graph.specs.directed && u != v {
+            triplets.push((v, u, weight));
+        }
+    }
+    Ok((nodes, triplets))
+}
+
+/// Returns the sum of the values in each of the `n` rows of a matrix given as triplets.
+fn get_row_sums(n: usize, triplets: &[(usize, usize, f64)]) -> Vec<f64> {
+    let mut sums = vec![0.0; n];
+    for (i, _j, w) in triplets {
+        sums[*i] += w;
+    }
+    sums
+}
+
+fn get_sorted_node_names<T, A>(graph: &Graph<T, A>) -> Vec<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let mut nodes: Vec<T> = graph.get_all_node_names().into_iter().cloned().collect();
+    nodes.sort_unstable();
+    nodes
+}
@@ -0,0 +1,126 @@
+#[cfg(feature = "ndarray")]
+use ndarray::Array2;
+
+/**
+A sparse matrix of `f64` values in compressed sparse row (CSR) format: the non-zero
+values of each row are stored in one contiguous array, sorted by column.
+
+With the `ndarray` feature enabled a `SparseMatrix` can be converted to a dense
+`ndarray::Array2<f64>` with [to_dense](#method.to_dense).
+
+# Examples
+
+```
+use graphrs::{generators, linalg};
+
+let graph = generators::classic::path_graph(3, false);
+let (nodes, matrix) = linalg::adjacency_matrix(&graph, false).unwrap();
+assert_eq!(nodes, vec![0, 1, 2]);
+assert_eq!(matrix.get(0, 1), 1.0);
+assert_eq!(matrix.get(0, 2), 0.0);
+assert_eq!(matrix.mul_vec(&[1.0, 2.0, 3.0]), vec![2.0, 4.0, 2.0]);
+```
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseMatrix {
+    nrows: usize,
+    ncols: usize,
+    /// The values of row `i` are `data[indptr[i]..indptr[i + 1]]`.
+    indptr: Vec<usize>,
+    /// The column of each of the `data` values.
+    indices: Vec<usize>,
+    data: Vec<f64>,
+}
+
+impl SparseMatrix {
+    /**
+    Creates a `SparseMatrix` from `(row, column, value)` triplets. Values at the same
+    position are summed, and positions whose values sum to zero are dropped.
+    */
+    pub(crate) fn from_triplets(
+        nrows: usize,
+        ncols: usize,
+        mut triplets: Vec<(usize, usize, f64)>,
+    ) -> SparseMatrix {
+        triplets.sort_by_key(|(row, column, _value)| (*row, *column));
+        let mut indptr = Vec::with_capacity(nrows + 1);
+        let mut indices: Vec<usize> = vec![];
+        let mut data: Vec<f64> = vec![];
+        indptr.push(0);
+        let mut triplets = triplets.into_iter().peekable();
+        for row in 0..nrows {
+            while let Some((_r, column, mut value)) = triplets.next_if(|(r, _c, _v)| *r == row) {
+                while let Some((_r, _c, v)) =
+                    triplets.next_if(|(r, c, _v)| *r == row && *c == column)
+                {
+                    value += v;
+                }
+                if value != 0.0 {
+                    indices.push(column);
+                    data.push(value);
+                }
+            }
+            indptr.push(data.len());
+        }
+        SparseMatrix {
+            nrows,
+            ncols,
+            indptr,
+            indices,
+            data,
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// Returns the number of columns.
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Returns the number of values that are stored, which are the non-zero values.
+    pub fn nnz(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the value at `row` and `column`, which is `0.0` if no value is stored there.
+    pub fn get(&self, row: usize, column: usize) -> f64 {
+        let (columns, values) = self.get_row(row);
+        match columns.binary_search(&column) {
+            Ok(i) => values[i],
+            Err(_) => 0.0,
+        }
+    }
+
+    /// Returns the columns, in ascending order, and the values of the non-zero values in `row`.
+    pub fn get_row(&self, row: usize) -> (&[usize], &[f64]) {
+        let range = self.indptr[row]..self.indptr[row + 1];
+        (&self.indices[range.clone()], &self.data[range])
+    }
+
+    /// Multiplies the matrix by the vector `x`, which must have `ncols` values.
+    pub fn mul_vec(&self, x: &[f64]) -> Vec<f64> {
+        (0..self.nrows)
+            .map(|row| {
+                let (columns, values) = self.get_row(row);
+                columns.iter().zip(values).map(|(c, v)| v * x[*c]).sum()
+            })
+            .collect()
+    }
+
+    /// Converts the matrix to a dense `ndarray::Array2<f64>`.
+    #[cfg(feature = "ndarray")]
+    pub fn to_dense(&self) -> Array2<f64> {
+        let mut dense = Array2::zeros((self.nrows, self.ncols));
+        for row in 0..self.nrows {
+            let (columns, values) = self.get_row(row);
+            for (column, value) in columns.iter().zip(values) {
+                dense[[row, *column]] = *value;
+            }
+        }
+        dense
+    }
+}
@@ -0,0 +1,413 @@
+use super::{laplacian_matrix, normalized_laplacian, SparseMatrix};
+use crate::algorithms::community::louvain::get_rng;
+use crate::algorithms::components;
+use crate::{Error, ErrorKind, Graph};
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// The largest number of Lanczos vectors built before restarting.
+const MAX_KRYLOV_DIMENSION: usize = 100;
+
+/// The number of times the Lanczos iteration is restarted before giving up.
+const MAX_RESTARTS: usize = 500;
+
+/**
+Computes the algebraic connectivity of an undirected graph: the second smallest eigenvalue
+of its Laplacian matrix. It is greater than zero if and only if the graph is connected,
+and the larger it is the harder the graph is to cut into pieces.
+
+The eigenvalue is found with the Lanczos method, with the known eigenvector of the smallest
+eigenvalue, `0`, projected out.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights
+* `normalized`: set to `true` to use the
+  [normalized_laplacian](fn.normalized_laplacian.html) rather than the
+  [laplacian_matrix](fn.laplacian_matrix.html)
+* `tolerance`: the tolerance for the residual of the eigenvector; use `None` to use the
+  default value of `1.0e-8`
+* `seed`: a seed for the random number generator that chooses the starting vector
+
+# Returns
+
+The algebraic connectivity, which is `0.0` if the graph isn't connected. An `Error` with a
+`kind` of `ErrorKind::WrongMethod` is returned if the graph is directed, one with a `kind`
+of `ErrorKind::InvalidArgument` if it has fewer than two nodes, and one with a `kind` of
+`ErrorKind::PowerIterationFailedConvergence` if the eigenvalue doesn't converge.
+
+# Examples
+
+```
+use graphrs::{generators, linalg};
+
+let graph = generators::classic::complete_graph(5, false);
+let result = linalg::algebraic_connectivity(&graph, false, false, None, Some(1)).unwrap();
+assert!((result - 5.0).abs() < 1.0e-8);
+```
+
+# References
+
+1. M. Fiedler. Algebraic connectivity of graphs. Czechoslovak Mathematical Journal,
+   23(2), 298–305, 1973.
+*/
+pub fn algebraic_connectivity<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    tolerance: Option<f64>,
+    seed: Option<u64>,
+) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    check_graph(graph)?;
+    if components::number_connected_components(graph)? > 1 {
+        return Ok(0.0);
+    }
+    let (_nodes, eigenvalue, _eigenvector) =
+        get_fiedler_pair(graph, weighted, normalized, tolerance, seed)?;
+    Ok(eigenvalue)
+}
+
+/**
+Computes the Fiedler vector of a connected undirected graph: the eigenvector of the
+algebraic connectivity. Nodes that are close together in the graph have similar values,
+so the vector orders the nodes for partitioning and for drawing.
+
+The vector has unit length. Its sign is arbitrary, so it's chosen so that the first node,
+in sorted order, whose value isn't zero has a negative value.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights
+* `normalized`: set to `true` to use the
+  [normalized_laplacian](fn.normalized_laplacian.html) rather than the
+  [laplacian_matrix](fn.laplacian_matrix.html)
+* `tolerance`: the tolerance for the residual of the eigenvector; use `None` to use the
+  default value of `1.0e-8`
+* `seed`: a seed for the random number generator that chooses the starting vector
+
+# Returns
+
+A `HashMap` of each node's value in the Fiedler vector. An `Error` with a `kind` of
+`ErrorKind::WrongMethod` is returned if the graph is directed, one with a `kind` of
+`ErrorKind::InvalidArgument` if it has fewer than two nodes, one with a `kind` of
+`ErrorKind::GraphNotConnected` if it isn't connected, and one with a `kind` of
+`ErrorKind::PowerIterationFailedConvergence` if the eigenvector doesn't converge.
+
+# Examples
+
+```
+use graphrs::{generators, linalg};
+
+let graph = generators::classic::path_graph(5, false);
+let result = linalg::fiedler_vector(&graph, false, false, None, Some(1)).unwrap();
+assert!(result[&0] < result[&1] && result[&1] < result[&2]);
+assert!(result[&2].abs() < 1.0e-8);
+```
+*/
+pub fn fiedler_vector<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    tolerance: Option<f64>,
+    seed: Option<u64>,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    check_graph(graph)?;
+    if components::number_connected_components(graph)? > 1 {
+        return Err(Error {
+            kind: ErrorKind::GraphNotConnected,
+            message: "The Fiedler vector is only defined for connected graphs.".to_string(),
+        });
+    }
+    let (nodes, _eigenvalue, eigenvector) =
+        get_fiedler_pair(graph, weighted, normalized, tolerance, seed)?;
+    Ok(nodes.into_iter().zip(eigenvector).collect())
+}
+
+/**
+Splits a connected undirected graph in two by the signs of the values of its
+[fiedler_vector](fn.fiedler_vector.html): nodes with negative values in one part and the
+rest in the other. This tends to cut few edges while keeping the parts of similar sizes.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights
+* `normalized`: set to `true` to use the
+  [normalized_laplacian](fn.normalized_laplacian.html), which favors parts with similar
+  total degrees rather than similar numbers of nodes
+* `tolerance`: the tolerance for the residual of the eigenvector; use `None` to use the
+  default value of `1.0e-8`
+* `seed`: a seed for the random number generator that chooses the starting vector
+
+# Returns
+
+The two parts, each sorted; the first part contains the first node, in sorted order, whose
+value isn't zero. The errors are the same as those of [fiedler_vector](fn.fiedler_vector.html).
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs, linalg};
+
+// two triangles joined by a single edge
+let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+graph.add_edges(vec![
+    Edge::new(0, 1), Edge::new(1, 2), Edge::new(2, 0),
+    Edge::new(3, 4), Edge::new(4, 5), Edge::new(5, 3),
+    Edge::new(2, 3),
+]);
+let (part1, part2) = linalg::spectral_bisection(&graph, false, false, None, Some(1)).unwrap();
+assert_eq!(part1, vec![0, 1, 2]);
+assert_eq!(part2, vec![3, 4, 5]);
+```
+*/
+pub fn spectral_bisection<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    tolerance: Option<f64>,
+    seed: Option<u64>,
+) -> Result<(Vec<T>, Vec<T>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let fiedler = fiedler_vector(graph, weighted, normalized, tolerance, seed)?;
+    let (mut part1, mut part2): (Vec<T>, Vec<T>) =
+        fiedler.keys().cloned().partition(|n| fiedler[n] < 0.0);
+    part1.sort_unstable();
+    part2.sort_unstable();
+    Ok((part1, part2))
+}
+
+/// Returns an `Err` if the graph is directed or has fewer than two nodes.
+fn check_graph<T, A>(graph: &Graph<T, A>) -> Result<(), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    if graph.get_all_nodes().len() < 2 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "Spectral methods require a graph with at least two nodes.".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/**
+Returns the sorted node names and the second smallest eigenvalue, with its eigenvector, of
+the Laplacian of a connected graph.
+*/
+fn get_fiedler_pair<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    tolerance: Option<f64>,
+    seed: Option<u64>,
+) -> Result<(Vec<T>, f64, Vec<f64>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let (nodes, laplacian) = match normalized {
+        false => laplacian_matrix(graph, weighted)?,
+        true => normalized_laplacian(graph, weighted)?,
+    };
+    // the eigenvector of the eigenvalue 0 is a vector of ones, or of the square roots of
+    // the degrees for the normalized Laplacian
+    let null: Vec<f64> = match normalized {
+        false => vec![1.0; nodes.len()],
+        true => {
+            let (_nodes, adjacency) = super::adjacency_matrix(graph, weighted)?;
+            adjacency.mul_vec(&vec![1.0; nodes.len()]).into_iter().map(f64::sqrt).collect()
+        }
+    };
+    let mut rng = get_rng(seed);
+    let (eigenvalue, mut eigenvector) = lanczos_smallest(
+        &laplacian,
+        &normalize(null),
+        tolerance.unwrap_or(1.0e-8),
+        &mut rng,
+    )?;
+    let scale = eigenvector.iter().map(|x| x.abs()).fold(0.0, f64::max);
+    if let Some(first) = eigenvector.iter().find(|x| x.abs() > scale * 1.0e-6) {
+        if *first > 0.0 {
+            eigenvector.iter_mut().for_each(|x| *x = -*x);
+        }
+    }
+    Ok((nodes, eigenvalue.max(0.0), eigenvector))
+}
+
+/**
+Finds the smallest eigenvalue, and its eigenvector, of the symmetric `matrix` restricted
+to the vectors orthogonal to the unit vector `null`, using the Lanczos method with full
+reorthogonalization and explicit restarts.
+*/
+pub(crate) fn lanczos_smallest(
+    matrix: &SparseMatrix,
+    null: &[f64],
+    tolerance: f64,
+    rng: &mut StdRng,
+) -> Result<(f64, Vec<f64>), Error> {
+    let n = matrix.nrows();
+    let krylov_dimension = MAX_KRYLOV_DIMENSION.min(n - 1);
+    let start: Vec<f64> = (0..n).map(|_| rng.gen::<f64>() - 0.5).collect();
+    let mut v = normalize(project_out(start, &[null]));
+    for _restart in 0..MAX_RESTARTS {
+        let mut basis: Vec<Vec<f64>> = vec![v];
+        let mut alphas: Vec<f64> = vec![];
+        let mut betas: Vec<f64> = vec![];
+        loop {
+            let j = basis.len() - 1;
+            let w = project_out(matrix.mul_vec(&basis[j]), &[null]);
+            alphas.push(dot(&w, &basis[j]));
+            // projecting out every basis vector, twice, keeps the basis orthogonal
+            let mut w = project_out(w, &basis.iter().map(|b| &b[..]).collect::<Vec<_>>());
+            w = project_out(w, &basis.iter().map(|b| &b[..]).collect::<Vec<_>>());
+            w = project_out(w, &[null]);
+            let beta = norm(&w);
+            if basis.len() == krylov_dimension || beta < 1.0e-12 {
+                break;
+            }
+            betas.push(beta);
+            basis.push(w.into_iter().map(|x| x / beta).collect());
+        }
+        let (eigenvalues, eigenvectors) = tridiagonal_eigen(&alphas, &betas)?;
+        let smallest = (0..eigenvalues.len())
+            .min_by(|a, b| eigenvalues[*a].partial_cmp(&eigenvalues[*b]).unwrap())
+            .unwrap();
+        let theta = eigenvalues[smallest];
+        let mut ritz = vec![0.0; n];
+        for (b, s) in basis.iter().zip(eigenvectors.iter().map(|row| row[smallest])) {
+            for (r, x) in ritz.iter_mut().zip(b) {
+                *r += s * x;
+            }
+        }
+        let ritz = normalize(project_out(ritz, &[null]));
+        let product = project_out(matrix.mul_vec(&ritz), &[null]);
+        let residual: Vec<f64> = product.iter().zip(&ritz).map(|(p, r)| p - theta * r).collect();
+        if norm(&residual) <= tolerance * theta.abs().max(1.0) {
+            return Ok((theta, ritz));
+        }
+        v = ritz;
+    }
+    Err(Error {
+        kind: ErrorKind::PowerIterationFailedConvergence,
+        message: "The Lanczos iteration failed to converge to the specified tolerance.".to_string(),
+    })
+}
+
+/**
+Computes the eigenvalues and eigenvectors of the symmetric tridiagonal matrix with
+`diagonal` on its diagonal and `off_diagonal` beside it, using the QL algorithm with
+implicit shifts. Column `k` of the returned matrix is the eigenvector of eigenvalue `k`.
+*/
+fn tridiagonal_eigen(
+    diagonal: &[f64],
+    off_diagonal: &[f64],
+) -> Result<(Vec<f64>, Vec<Vec<f64>>), Error> {
+    let n = diagonal.len();
+    let mut d = diagonal.to_vec();
+    let mut e: Vec<f64> = off_diagonal.iter().cloned().chain(std::iter::once(0.0)).collect();
+    e.truncate(n);
+    let mut z: Vec<Vec<f64>> =
+        (0..n).map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect();
+    for l in 0..n {
+        let mut iterations = 0;
+        loop {
+            let mut m = l;
+            while m + 1 < n {
+                let dd = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= f64::EPSILON * dd {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+            iterations += 1;
+            if iterations > 60 {
+                return Err(Error {
+                    kind: ErrorKind::PowerIterationFailedConvergence,
+                    message: "The tridiagonal QL iteration failed to converge.".to_string(),
+                });
+            }
+            let mut g = (d[l + 1] - d[l]) / (2.0 * e[l]);
+            let mut r = g.hypot(1.0);
+            g = d[m] - d[l] + e[l] / (g + r.copysign(g));
+            let (mut s, mut c, mut p) = (1.0, 1.0, 0.0);
+            let mut underflow = false;
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r == 0.0 {
+                    d[i + 1] -= p;
+                    e[m] = 0.0;
+                    underflow = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + 2.0 * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+                for row in z.iter_mut() {
+                    let f = row[i + 1];
+                    row[i + 1] = s * row[i] + c * f;
+                    row[i] = c * row[i] - s * f;
+                }
+            }
+            if underflow {
+                continue;
+            }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = 0.0;
+        }
+    }
+    Ok((d, z))
+}
+
+/// Subtracts the projection of `x` onto each of the orthonormal `vectors`.
+fn project_out(mut x: Vec<f64>, vectors: &[&[f64]]) -> Vec<f64> {
+    for v in vectors {
+        let projection = dot(&x, v);
+        for (a, b) in x.iter_mut().zip(v.iter()) {
+            *a -= projection * b;
+        }
+    }
+    x
+}
+
+fn normalize(x: Vec<f64>) -> Vec<f64> {
+    let length = norm(&x);
+    x.into_iter().map(|a| a / length).collect()
+}
+
+fn norm(x: &[f64]) -> f64 {
+    dot(x, x).sqrt()
+}
+
+fn dot(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y).map(|(a, b)| a * b).sum()
+}
@@ -0,0 +1,87 @@
+/**
+Creates a [Graph](./struct.Graph.html) from a list of edges and nodes.
+
+The list starts with `directed;` or `undirected;`. Each item is either an edge, written
+`u -> v` in a directed graph and `u -- v` in an undirected graph and optionally followed by
+`: weight`, or a node on its own. Node names and weights must be literals, variables or
+expressions in parentheses. Nodes are created for the edges automatically, and edges that
+start and end on the same node are allowed. The graph has no node or edge attributes.
+
+# Panics
+
+Panics if the same edge is given more than once.
+
+# Examples
+
+```
+use graphrs::graph;
+
+let graph = graph! { directed; "a" -> "b": 1.0, "b" -> "c": 2.5, "c" -> "a", "d" };
+assert!(graph.specs.directed);
+assert_eq!(graph.get_all_nodes().len(), 4);
+assert_eq!(graph.get_edge("b", "c").unwrap().weight, 2.5);
+assert!(graph.get_edge("c", "a").unwrap().weight.is_nan());
+
+let weight = 0.5;
+let graph = graph! { undirected; 1 -- 2: weight, 2 -- 3: (weight * 2.0) };
+assert_eq!(graph.get_edge(3, 2).unwrap().weight, 1.0);
+```
+*/
+#[macro_export]
+macro_rules! graph {
+    (directed; $($items:tt)*) => {{
+        #[allow(unused_mut)]
+        let mut nodes = ::std::vec::Vec::new();
+        #[allow(unused_mut)]
+        let mut edges = ::std::vec::Vec::new();
+        $crate::graph!(@directed nodes edges; $($items)*);
+        $crate::graph!(@build nodes edges; $crate::GraphSpecs::directed_create_missing())
+    }};
+    (undirected; $($items:tt)*) => {{
+        #[allow(unused_mut)]
+        let mut nodes = ::std::vec::Vec::new();
+        #[allow(unused_mut)]
+        let mut edges = ::std::vec::Vec::new();
+        $crate::graph!(@undirected nodes edges; $($items)*);
+        $crate::graph!(@build nodes edges; $crate::GraphSpecs::undirected_create_missing())
+    }};
+    (@build $nodes:ident $edges:ident; $specs:expr) => {
+        match $crate::Graph::<_, ()>::new_from_nodes_and_edges(
+            $nodes,
+            $edges,
+            $crate::GraphSpecs {
+                self_loops: true,
+                ..$specs
+            },
+        ) {
+            Ok(graph) => graph,
+            Err(error) => panic!("graph!: {}", error),
+        }
+    };
+    (@directed $nodes:ident $edges:ident; ) => {};
+    (@directed $nodes:ident $edges:ident; $u:tt -> $v:tt : $w:expr $(, $($rest:tt)*)?) => {
+        $edges.push($crate::Edge::with_weight($u, $v, $w));
+        $crate::graph!(@directed $nodes $edges; $($($rest)*)?);
+    };
+    (@directed $nodes:ident $edges:ident; $u:tt -> $v:tt $(, $($rest:tt)*)?) => {
+        $edges.push($crate::Edge::new($u, $v));
+        $crate::graph!(@directed $nodes $edges; $($($rest)*)?);
+    };
+    (@directed $nodes:ident $edges:ident; $n:tt $(, $($rest:tt)*)?) => {
+        $nodes.push($crate::Node::from_name($n));
+        $crate::graph!(@directed $nodes $edges; $($($rest)*)?);
+    };
+    (@undirected $nodes:ident $edges:ident; ) => {};
+    (@undirected $nodes:ident $edges:ident; $u:tt -- $v:tt : $w:expr $(, $($rest:tt)*)?) => {
+        $edges.push($crate::Edge::with_weight($u, $v, $w));
+        $crate::graph!(@undirected $nodes $edges; $($($rest)*)?);
+    };
+    (@undirected $nodes:ident $edges:ident; $u:tt -- $v:tt $(, $($rest:tt)*)?) => {
+        $edges.push($crate::Edge::new($u, $v));
+        $crate::graph!(@undirected $nodes $edges; $($($rest)*)?);
+    };
+    (@undirected $nodes:ident $edges:ident; $n:tt $(, $($rest:tt)*)?) => {
+        $nodes.push($crate::Node::from_name($n));
+        $crate::graph!(@undirected $nodes $edges; $($($rest)*)?);
+    };
+}
@@ -3,6 +3,9 @@ use std::fmt;
 use std::fmt::{Debug, Display};
 use std::hash::{Hash, Hasher};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /**
 Represents a graph node, with `name` and `attributes`.
 */
@@ -0,0 +1,265 @@
+/// Products of two graphs, whose nodes are pairs of nodes of the two graphs.
+pub mod product;
+
+use crate::algorithms::community::partitions::is_partition;
+use crate::{Edge, Error, ErrorKind, Graph, GraphSpecs, Node};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fmt::Display;
+use std::hash::Hash;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/**
+The name of a node in a [line_graph](fn.line_graph.html): the `u` and `v` nodes of an
+edge of the original graph. For an undirected graph `u` is never greater than `v`. It's
+displayed as `(u, v)`.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LineGraphNode<T> {
+    pub u: T,
+    pub v: T,
+}
+
+impl<T: Display> fmt::Display for LineGraphNode<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.u, self.v)
+    }
+}
+
+/**
+Returns the line graph of a graph: a graph with a node for each edge of the original graph.
+For an undirected graph two nodes are joined by an edge if their edges share a node. For a
+directed graph there's an edge from `(u, v)` to `(v, w)`: from each edge to the edges that
+follow it; so a directed self-loop `(u, u)` gets a self-loop in the line graph.
+
+Running a community detection algorithm on the line graph groups the edges, rather than
+the nodes, of the original graph, which lets nodes belong to more than one community.
+
+# Arguments
+
+* `graph`: a [Graph](../struct.Graph.html) instance that doesn't support multiple edges
+  between nodes
+
+# Returns
+
+A new [Graph](../struct.Graph.html), directed if `graph` is directed, whose nodes are
+named with a [LineGraphNode](struct.LineGraphNode.html) and have the attributes of their
+edges. The edges of the line graph don't have weights. An `Error` with a `kind` of
+`ErrorKind::WrongMethod` is returned if `graph` supports multiple edges between nodes.
+
+# Examples
+
+```
+use graphrs::{generators, operators::{self, LineGraphNode}};
+
+let graph = generators::classic::star_graph(3, false);
+let line_graph = operators::line_graph(&graph).unwrap();
+assert_eq!(line_graph.get_all_nodes().len(), 3);
+assert_eq!(line_graph.get_all_edges().len(), 3);
+assert!(line_graph.has_node(&LineGraphNode { u: 0, v: 1 }));
+```
+*/
+pub fn line_graph<T, A>(graph: &Graph<T, A>) -> Result<Graph<LineGraphNode<T>, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_not_multi_edges()?;
+    let edges = graph.get_all_edges();
+    let get_name = |e: &Edge<T, A>| LineGraphNode {
+        u: e.u.clone(),
+        v: e.v.clone(),
+    };
+    let nodes = edges
+        .iter()
+        .map(|e| Node {
+            name: get_name(e),
+            attributes: e.attributes.clone(),
+        })
+        .collect();
+    let mut new_edges = vec![];
+    match graph.specs.directed {
+        true => {
+            let mut out_edges: HashMap<&T, Vec<&Edge<T, A>>> = HashMap::new();
+            for edge in edges.iter() {
+                out_edges.entry(&edge.u).or_default().push(edge);
+            }
+            for edge in edges.iter() {
+                for next in out_edges.get(&edge.v).into_iter().flatten() {
+                    new_edges.push(Edge::new(get_name(edge), get_name(next)));
+                }
+            }
+        }
+        false => {
+            let mut incident_edges: HashMap<&T, Vec<&Edge<T, A>>> = HashMap::new();
+            for edge in edges.iter() {
+                incident_edges.entry(&edge.u).or_default().push(edge);
+                if edge.u != edge.v {
+                    incident_edges.entry(&edge.v).or_default().push(edge);
+                }
+            }
+            // two different edges share at most one node, so each pair is only joined once
+            for incident in incident_edges.values() {
+                for (i, edge1) in incident.iter().enumerate() {
+                    for edge2 in incident[i + 1..].iter() {
+                        new_edges.push(Edge::new(get_name(edge1), get_name(edge2)));
+                    }
+                }
+            }
+        }
+    }
+    let specs = GraphSpecs {
+        self_loops: graph.specs.directed && graph.specs.self_loops,
+        ..graph.specs.clone()
+    };
+    Graph::new_from_nodes_and_edges(nodes, new_edges, specs)
+}
+
+/**
+Returns the complement of a graph: a graph with the same nodes, where two different nodes
+are joined by an edge if and only if they aren't joined in the original graph. For a
+directed graph there's an edge from `u` to `v` if there's no edge from `u` to `v` in the
+original graph.
+
+# Arguments
+
+* `graph`: a [Graph](../struct.Graph.html) instance
+
+# Returns
+
+A new [Graph](../struct.Graph.html) with the `specs` of `graph` and its nodes and their
+attributes. The edges don't have weights or attributes, and there are no self-loops or
+multiple edges between nodes.
+
+# Examples
+
+```
+use graphrs::{generators, operators};
+
+let graph = generators::classic::path_graph(4, false);
+let complement = operators::complement(&graph).unwrap();
+assert_eq!(complement.get_all_edges().len(), 3);
+assert!(complement.get_edge(0, 2).is_ok());
+assert!(complement.get_edge(0, 1).is_err());
+```
+*/
+pub fn complement<T, A>(graph: &Graph<T, A>) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let mut nodes = graph.get_all_nodes();
+    nodes.sort_unstable();
+    let mut adjacent: HashSet<(&T, &T)> = HashSet::new();
+    for edge in graph.get_all_edges() {
+        adjacent.insert((&edge.u, &edge.v));
+        if !graph.specs.directed {
+            adjacent.insert((&edge.v, &edge.u));
+        }
+    }
+    let mut new_edges = vec![];
+    for (i, u) in nodes.iter().enumerate() {
+        for (j, v) in nodes.iter().enumerate() {
+            let is_pair = match graph.specs.directed {
+                true => i != j,
+                false => i < j,
+            };
+            if is_pair && !adjacent.contains(&(&u.name, &v.name)) {
+                new_edges.push(Edge::new(u.name.clone(), v.name.clone()));
+            }
+        }
+    }
+    let nodes = nodes.into_iter().cloned().collect();
+    Graph::new_from_nodes_and_edges(nodes, new_edges, graph.specs.clone())
+}
+
+/**
+Returns the quotient graph of a graph for a partition of its nodes: each block of the
+partition, such as a community found by
+[louvain_communities](../algorithms/community/louvain/fn.louvain_communities.html),
+becomes a single node, and the edges between two blocks become a single edge whose weight
+is the total weight of those edges. The edges within a block become a self-loop on its
+node, so the total weight of the edges is the same as in the original graph.
+
+Applying this to the communities of a graph, then to the communities of the quotient
+graph, and so on, gives a hierarchy of summaries of the graph.
+
+# Arguments
+
+* `graph`: a [Graph](../struct.Graph.html) instance
+* `partition`: the blocks of nodes; every node must be in exactly one block
+* `weighted`: set to `true` to sum the edge weights, or `false` to count the edges
+
+# Returns
+
+A new [Graph](../struct.Graph.html), directed if `graph` is directed, whose nodes are
+named by the index of their block in `partition`. Every block gets a node, even if it has
+no edges. An `Error` with a `kind` of `ErrorKind::NotAPartition` is returned if `partition`
+isn't a partition of the nodes of `graph`, and one with a `kind` of
+`ErrorKind::EdgeWeightNotSpecified` if `weighted` is `true` and an edge doesn't have a
+weight.
+
+# Examples
+
+```
+use graphrs::{algorithms::community::louvain, generators, operators};
+
+let graph = generators::social::karate_club_graph();
+let communities = louvain::louvain_communities(&graph, false, None, None, Some(1)).unwrap();
+let quotient = operators::quotient_graph(&graph, &communities, false).unwrap();
+assert_eq!(quotient.get_all_nodes().len(), communities.len());
+assert_eq!(quotient.size(true), 78.0);
+```
+*/
+pub fn quotient_graph<T, A>(
+    graph: &Graph<T, A>,
+    partition: &[HashSet<T>],
+    weighted: bool,
+) -> Result<Graph<usize, ()>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if !is_partition(graph, partition) {
+        return Err(Error {
+            kind: ErrorKind::NotAPartition,
+            message: "The specified blocks did not form a partition of the graph.".to_string(),
+        });
+    }
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let blocks: HashMap<&T, usize> = partition
+        .iter()
+        .enumerate()
+        .flat_map(|(i, block)| block.iter().map(move |n| (n, i)))
+        .collect();
+    let mut weights: HashMap<(usize, usize), f64> = HashMap::new();
+    for edge in graph.get_all_edges() {
+        let (u, v) = (blocks[&edge.u], blocks[&edge.v]);
+        let key = match !graph.specs.directed && u > v {
+            false => (u, v),
+            true => (v, u),
+        };
+        *weights.entry(key).or_insert(0.0) += match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+    }
+    let nodes = (0..partition.len()).map(Node::from_name).collect();
+    let mut edges: Vec<Edge<usize, ()>> =
+        weights.into_iter().map(|((u, v), w)| Edge::with_weight(u, v, w)).collect();
+    edges.sort_by_key(|e| (e.u, e.v));
+    let specs = GraphSpecs {
+        multi_edges: false,
+        self_loops: true,
+        ..match graph.specs.directed {
+            true => GraphSpecs::directed(),
+            false => GraphSpecs::undirected(),
+        }
+    };
+    Graph::new_from_nodes_and_edges(nodes, edges, specs)
+}
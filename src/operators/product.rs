@@ -0,0 +1,427 @@
+use crate::{Edge, Error, ErrorKind, Graph, GraphSpecs, Node};
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::Display;
+use std::hash::Hash;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/**
+The name of a node in a graph product: a node of the first graph paired with a node of
+the second graph. It's displayed as `(u, x)`.
+
+# Examples
+
+```
+use graphrs::operators::product::ProductNode;
+
+let node = ProductNode(1, "a");
+assert_eq!(node.to_string(), "(1, a)");
+assert_eq!(ProductNode::from((1, "a")), node);
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProductNode<T, U>(pub T, pub U);
+
+impl<T, U> From<(T, U)> for ProductNode<T, U> {
+    fn from(pair: (T, U)) -> ProductNode<T, U> {
+        ProductNode(pair.0, pair.1)
+    }
+}
+
+impl<T: Display, U: Display> fmt::Display for ProductNode<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+/**
+The type of the graphs returned by the graph products. The attributes of node `(u, x)`
+pair the attributes of `u` and of `x`; the attributes of an edge pair the attributes of the
+edges it was created from, with `None` for a graph that didn't contribute an edge.
+*/
+pub type ProductGraph<T, U, A, B> = Graph<ProductNode<T, U>, (Option<A>, Option<B>)>;
+
+/**
+Returns the Cartesian product of two graphs. Its nodes are the pairs `(u, x)` of a node
+`u` of `g` and a node `x` of `h`. There's an edge between `(u, x)` and `(v, y)` if there's
+an edge between `u` and `v` in `g` and `x == y`, or if `u == v` and there's an edge
+between `x` and `y` in `h`. Each edge has the weight of the edge it was created from.
+
+The Cartesian product of two paths is a grid, and the product of a graph with a path of
+`n` nodes is `n` copies of the graph with corresponding nodes joined in a path.
+
+# Arguments
+
+* `g`: a [Graph](../../struct.Graph.html) instance
+* `h`: a [Graph](../../struct.Graph.html) instance; it must be directed if `g` is directed,
+  and undirected otherwise
+
+# Returns
+
+A [ProductGraph](type.ProductGraph.html) with the `specs` of `g`, which allows multiple
+edges or self-loops if either graph does. An `Error` with a `kind` of
+`ErrorKind::InvalidArgument` is returned if one graph is directed and the other isn't.
+
+# Examples
+
+```
+use graphrs::{generators, operators::product::{self, ProductNode}};
+
+let g = generators::classic::path_graph(3, false);
+let h = generators::classic::path_graph(4, false);
+let grid = product::cartesian_product(&g, &h).unwrap();
+assert_eq!(grid.get_all_nodes().len(), 12);
+assert_eq!(grid.get_all_edges().len(), 17);
+assert!(grid.get_edge(ProductNode(1, 2), ProductNode(1, 3)).is_ok());
+```
+*/
+pub fn cartesian_product<T, U, A, B>(
+    g: &Graph<T, A>,
+    h: &Graph<U, B>,
+) -> Result<ProductGraph<T, U, A, B>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    U: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    B: Clone,
+{
+    ensure_same_directedness(g, h)?;
+    let mut edges = get_edges_cross_nodes(g, h);
+    edges.extend(get_nodes_cross_edges(g, h));
+    build_product(g, h, edges)
+}
+
+/**
+Returns the tensor product of two graphs, also known as the categorical, direct or
+Kronecker product. Its nodes are the pairs `(u, x)` of a node `u` of `g` and a node `x` of
+`h`. There's an edge between `(u, x)` and `(v, y)` if there's an edge between `u` and `v`
+in `g` and an edge between `x` and `y` in `h`. The weight of each edge is the product of the
+weights of the two edges it was created from, so the adjacency matrix of the result is the
+Kronecker product of the adjacency matrices of `g` and `h`.
+
+# Arguments
+
+* `g`: a [Graph](../../struct.Graph.html) instance
+* `h`: a [Graph](../../struct.Graph.html) instance; it must be directed if `g` is directed,
+  and undirected otherwise
+
+# Returns
+
+A [ProductGraph](type.ProductGraph.html) with the `specs` of `g`, which allows multiple
+edges or self-loops if either graph does. An `Error` with a `kind` of
+`ErrorKind::InvalidArgument` is returned if one graph is directed and the other isn't.
+
+# Examples
+
+```
+use graphrs::{Edge, Graph, GraphSpecs, operators::product::{self, ProductNode}};
+
+let mut g = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+g.add_edge(Edge::with_weight("a", "b", 2.0)).unwrap();
+let mut h = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+h.add_edge(Edge::with_weight(1, 2, 3.0)).unwrap();
+let tensor = product::tensor_product(&g, &h).unwrap();
+assert_eq!(tensor.get_all_edges().len(), 2);
+assert_eq!(tensor.get_edge(ProductNode("a", 1), ProductNode("b", 2)).unwrap().weight, 6.0);
+assert_eq!(tensor.get_edge(ProductNode("a", 2), ProductNode("b", 1)).unwrap().weight, 6.0);
+```
+*/
+pub fn tensor_product<T, U, A, B>(
+    g: &Graph<T, A>,
+    h: &Graph<U, B>,
+) -> Result<ProductGraph<T, U, A, B>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    U: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    B: Clone,
+{
+    ensure_same_directedness(g, h)?;
+    let edges = get_edges_cross_edges(g, h);
+    build_product(g, h, edges)
+}
+
+/**
+Returns the strong product of two graphs: the union of their
+[cartesian_product](fn.cartesian_product.html) and their
+[tensor_product](fn.tensor_product.html). The strong product of two paths is a grid with
+the diagonals of each square also joined, like the moves of a king on a chessboard.
+
+# Arguments
+
+* `g`: a [Graph](../../struct.Graph.html) instance
+* `h`: a [Graph](../../struct.Graph.html) instance; it must be directed if `g` is directed,
+  and undirected otherwise
+
+# Returns
+
+A [ProductGraph](type.ProductGraph.html) with the `specs` of `g`, which allows multiple
+edges or self-loops if either graph does. An `Error` with a `kind` of
+`ErrorKind::InvalidArgument` is returned if one graph is directed and the other isn't.
+
+# Examples
+
+```
+use graphrs::{generators, operators::product::{self, ProductNode}};
+
+let g = generators::classic::path_graph(3, false);
+let king = product::strong_product(&g, &g).unwrap();
+assert_eq!(king.get_all_edges().len(), 20);
+assert_eq!(king.get_neighbor_nodes(ProductNode(1, 1)).unwrap().len(), 8);
+```
+*/
+pub fn strong_product<T, U, A, B>(
+    g: &Graph<T, A>,
+    h: &Graph<U, B>,
+) -> Result<ProductGraph<T, U, A, B>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    U: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    B: Clone,
+{
+    ensure_same_directedness(g, h)?;
+    let mut edges = get_edges_cross_nodes(g, h);
+    edges.extend(get_nodes_cross_edges(g, h));
+    edges.extend(get_edges_cross_edges(g, h));
+    build_product(g, h, edges)
+}
+
+/**
+Returns the lexicographic product of two graphs, also known as the composition of `g`
+with `h`: `g` with each of its nodes replaced by a copy of `h`. Its nodes are the pairs
+`(u, x)` of a node `u` of `g` and a node `x` of `h`. There's an edge between `(u, x)` and
+`(v, y)` if there's an edge between `u` and `v` in `g`, whatever `x` and `y` are, or if
+`u == v` and there's an edge between `x` and `y` in `h`. Each edge has the weight of the
+edge it was created from.
+
+Unlike the other products, the lexicographic product isn't commutative.
+
+# Arguments
+
+* `g`: a [Graph](../../struct.Graph.html) instance
+* `h`: a [Graph](../../struct.Graph.html) instance; it must be directed if `g` is directed,
+  and undirected otherwise
+
+# Returns
+
+A [ProductGraph](type.ProductGraph.html) with the `specs` of `g`, which allows multiple
+edges or self-loops if either graph does. An `Error` with a `kind` of
+`ErrorKind::InvalidArgument` is returned if one graph is directed and the other isn't.
+
+# Examples
+
+```
+use graphrs::{generators, operators::product::{self, ProductNode}};
+
+let g = generators::classic::path_graph(2, false);
+let h = generators::classic::path_graph(3, false);
+let lexicographic = product::lexicographic_product(&g, &h).unwrap();
+assert_eq!(lexicographic.get_all_edges().len(), 13);
+assert!(lexicographic.get_edge(ProductNode(0, 0), ProductNode(1, 2)).is_ok());
+assert!(lexicographic.get_edge(ProductNode(0, 0), ProductNode(0, 2)).is_err());
+```
+*/
+pub fn lexicographic_product<T, U, A, B>(
+    g: &Graph<T, A>,
+    h: &Graph<U, B>,
+) -> Result<ProductGraph<T, U, A, B>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    U: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    B: Clone,
+{
+    ensure_same_directedness(g, h)?;
+    let mut edges = get_edges_cross_node_pairs(g, h);
+    edges.extend(get_nodes_cross_edges(g, h));
+    build_product(g, h, edges)
+}
+
+type ProductEdge<T, U, A, B> = Edge<ProductNode<T, U>, (Option<A>, Option<B>)>;
+
+/// Returns the edges `(u, x) - (v, x)` for every edge `(u, v)` of `g` and node `x` of `h`.
+fn get_edges_cross_nodes<T, U, A, B>(
+    g: &Graph<T, A>,
+    h: &Graph<U, B>,
+) -> Vec<ProductEdge<T, U, A, B>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    U: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    B: Clone,
+{
+    let h_nodes = h.get_all_node_names();
+    g.get_all_edges()
+        .into_iter()
+        .flat_map(|e| {
+            h_nodes.iter().map(move |x| Edge {
+                u: ProductNode(e.u.clone(), (*x).clone()),
+                v: ProductNode(e.v.clone(), (*x).clone()),
+                attributes: Some((e.attributes.clone(), None)),
+                weight: e.weight,
+            })
+        })
+        .collect()
+}
+
+/// Returns the edges `(u, x) - (u, y)` for every node `u` of `g` and edge `(x, y)` of `h`.
+fn get_nodes_cross_edges<T, U, A, B>(
+    g: &Graph<T, A>,
+    h: &Graph<U, B>,
+) -> Vec<ProductEdge<T, U, A, B>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    U: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    B: Clone,
+{
+    let h_edges = h.get_all_edges();
+    g.get_all_node_names()
+        .into_iter()
+        .flat_map(|u| {
+            h_edges.iter().map(move |f| Edge {
+                u: ProductNode(u.clone(), f.u.clone()),
+                v: ProductNode(u.clone(), f.v.clone()),
+                attributes: Some((None, f.attributes.clone())),
+                weight: f.weight,
+            })
+        })
+        .collect()
+}
+
+/**
+Returns the edges `(u, x) - (v, y)` for every edge `(u, v)` of `g` and edge `(x, y)` of `h`.
+For undirected graphs the edges `(u, y) - (v, x)` are also returned, unless they're the
+same as the `(u, x) - (v, y)` edges because one of the two edges is a self-loop.
+*/
+fn get_edges_cross_edges<T, U, A, B>(
+    g: &Graph<T, A>,
+    h: &Graph<U, B>,
+) -> Vec<ProductEdge<T, U, A, B>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    U: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    B: Clone,
+{
+    let h_edges = h.get_all_edges();
+    let mut edges = vec![];
+    for e in g.get_all_edges() {
+        for f in h_edges.iter() {
+            let attributes = Some((e.attributes.clone(), f.attributes.clone()));
+            edges.push(Edge {
+                u: ProductNode(e.u.clone(), f.u.clone()),
+                v: ProductNode(e.v.clone(), f.v.clone()),
+                attributes: attributes.clone(),
+                weight: e.weight * f.weight,
+            });
+            if !g.specs.directed && e.u != e.v && f.u != f.v {
+                edges.push(Edge {
+                    u: ProductNode(e.u.clone(), f.v.clone()),
+                    v: ProductNode(e.v.clone(), f.u.clone()),
+                    attributes,
+                    weight: e.weight * f.weight,
+                });
+            }
+        }
+    }
+    edges
+}
+
+/**
+Returns the edges `(u, x) - (v, y)` for every edge `(u, v)` of `g` and every pair of nodes
+`x` and `y` of `h`, including `x == y`. For an undirected self-loop `(u, u)` only the pairs
+with `x <= y` are used, as the others give the same edges.
+*/
+fn get_edges_cross_node_pairs<T, U, A, B>(
+    g: &Graph<T, A>,
+    h: &Graph<U, B>,
+) -> Vec<ProductEdge<T, U, A, B>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    U: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    B: Clone,
+{
+    let h_nodes = h.get_all_node_names();
+    let mut edges = vec![];
+    for e in g.get_all_edges() {
+        for x in h_nodes.iter() {
+            for y in h_nodes.iter() {
+                if !g.specs.directed && e.u == e.v && x > y {
+                    continue;
+                }
+                edges.push(Edge {
+                    u: ProductNode(e.u.clone(), (*x).clone()),
+                    v: ProductNode(e.v.clone(), (*y).clone()),
+                    attributes: Some((e.attributes.clone(), None)),
+                    weight: e.weight,
+                });
+            }
+        }
+    }
+    edges
+}
+
+/**
+Creates the product graph with every pair of nodes of `g` and `h`, and `edges`. Duplicate
+edges are dropped unless the product allows multiple edges.
+*/
+fn build_product<T, U, A, B>(
+    g: &Graph<T, A>,
+    h: &Graph<U, B>,
+    mut edges: Vec<ProductEdge<T, U, A, B>>,
+) -> Result<ProductGraph<T, U, A, B>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    U: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    B: Clone,
+{
+    let h_nodes = h.get_all_nodes();
+    let nodes = g
+        .get_all_nodes()
+        .into_iter()
+        .flat_map(|u| {
+            h_nodes.iter().map(move |x| Node {
+                name: ProductNode(u.name.clone(), x.name.clone()),
+                attributes: Some((u.attributes.clone(), x.attributes.clone())),
+            })
+        })
+        .collect();
+    let specs = GraphSpecs {
+        multi_edges: g.specs.multi_edges || h.specs.multi_edges,
+        self_loops: g.specs.self_loops || h.specs.self_loops,
+        ..g.specs.clone()
+    };
+    if !specs.multi_edges {
+        // a self-loop in `g` can create the same edge in two ways; keep the first
+        let mut seen = HashSet::new();
+        edges.retain(|e| match !specs.directed && e.u > e.v {
+            true => seen.insert((e.v.clone(), e.u.clone())),
+            false => seen.insert((e.u.clone(), e.v.clone())),
+        });
+    }
+    Graph::new_from_nodes_and_edges(nodes, edges, specs)
+}
+
+/// Returns an error if `h` isn't directed when `g` is, or vice versa.
+fn ensure_same_directedness<T, U, A, B>(g: &Graph<T, A>, h: &Graph<U, B>) -> Result<(), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    U: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+    B: Clone,
+{
+    match g.specs.directed == h.specs.directed {
+        true => Ok(()),
+        false => Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "Both graphs must be directed, or both must be undirected.".to_string(),
+        }),
+    }
+}
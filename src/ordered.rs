@@ -0,0 +1,147 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/**
+Converts the result of an algorithm into one with a fixed order.
+
+Most algorithms return `HashMap`s and `HashSet`s, whose iteration order changes between
+runs. `into_ordered` turns each `HashMap` into a `BTreeMap` and each `HashSet` into a
+`BTreeSet`, at every level of the result, so that it iterates, prints and serializes in
+the order of its keys. The order of a `Vec` is kept, but its items are converted; for
+example the communities returned by the community algorithms become a `Vec` of
+`BTreeSet`s, which can then be sorted.
+
+The paths in a [ShortestPathInfo](./algorithms/shortest_path/struct.ShortestPathInfo.html)
+are sorted, along with their edge keys.
+
+# Examples
+
+```
+use graphrs::{algorithms::centrality::betweenness, generators, IntoOrdered};
+use std::collections::BTreeMap;
+
+let graph = generators::social::karate_club_graph();
+let centralities: BTreeMap<i32, f64> = betweenness::betweenness_centrality(&graph, false, true)
+    .unwrap()
+    .into_ordered();
+assert_eq!(centralities.keys().next(), Some(&0));
+```
+
+```
+use graphrs::{algorithms::shortest_path::dijkstra, generators, IntoOrdered};
+
+let graph = generators::social::karate_club_graph();
+let all_pairs = dijkstra::all_pairs(&graph, false, None, None, false).unwrap().into_ordered();
+let sources: Vec<i32> = all_pairs.keys().cloned().collect();
+assert_eq!(sources, (0..34).collect::<Vec<i32>>());
+assert!(all_pairs[&0].keys().zip(all_pairs[&0].keys().skip(1)).all(|(a, b)| a < b));
+```
+*/
+pub trait IntoOrdered {
+    /// The ordered version of the type.
+    type Ordered;
+
+    /// Returns the value with every `HashMap` and `HashSet` in it replaced by a `BTreeMap`
+    /// or `BTreeSet`.
+    fn into_ordered(self) -> Self::Ordered;
+}
+
+impl<K, V> IntoOrdered for HashMap<K, V>
+where
+    K: Ord,
+    V: IntoOrdered,
+{
+    type Ordered = BTreeMap<K, V::Ordered>;
+
+    fn into_ordered(self) -> Self::Ordered {
+        self.into_iter().map(|(k, v)| (k, v.into_ordered())).collect()
+    }
+}
+
+impl<T> IntoOrdered for HashSet<T>
+where
+    T: Ord,
+{
+    type Ordered = BTreeSet<T>;
+
+    fn into_ordered(self) -> Self::Ordered {
+        self.into_iter().collect()
+    }
+}
+
+impl<T> IntoOrdered for Vec<T>
+where
+    T: IntoOrdered,
+{
+    type Ordered = Vec<T::Ordered>;
+
+    fn into_ordered(self) -> Self::Ordered {
+        self.into_iter().map(|v| v.into_ordered()).collect()
+    }
+}
+
+impl<T> IntoOrdered for Option<T>
+where
+    T: IntoOrdered,
+{
+    type Ordered = Option<T::Ordered>;
+
+    fn into_ordered(self) -> Self::Ordered {
+        self.map(|v| v.into_ordered())
+    }
+}
+
+impl<T1, T2> IntoOrdered for (T1, T2)
+where
+    T1: IntoOrdered,
+    T2: IntoOrdered,
+{
+    type Ordered = (T1::Ordered, T2::Ordered);
+
+    fn into_ordered(self) -> Self::Ordered {
+        (self.0.into_ordered(), self.1.into_ordered())
+    }
+}
+
+/// Implements `IntoOrdered` for types that have no order to fix, returning them unchanged.
+macro_rules! impl_into_ordered_unchanged {
+    ($($t:ty),*) => {
+        $(
+            impl IntoOrdered for $t {
+                type Ordered = $t;
+
+                fn into_ordered(self) -> Self::Ordered {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_into_ordered_unchanged!(
+    bool,
+    char,
+    f32,
+    f64,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    String,
+    ()
+);
+
+impl<'a> IntoOrdered for &'a str {
+    type Ordered = &'a str;
+
+    fn into_ordered(self) -> Self::Ordered {
+        self
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{Error, ErrorKind, ProgressHandle};
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::sync::Arc;
+
+/**
+Specifies which threads an algorithm that runs in parallel uses.
+
+`GlobalPool`: use rayon's global thread pool, which by default has a thread for each core.
+
+`ThreadPool`: use a thread pool that the caller has built, for example to share a fixed
+number of threads between several algorithms.
+
+`MaxThreads`: use a new thread pool with at most this many threads.
+
+`Sequential`: don't use any other threads; the algorithm runs on the calling thread.
+*/
+#[derive(Clone)]
+pub enum Parallelism {
+    GlobalPool,
+    ThreadPool(Arc<ThreadPool>),
+    MaxThreads(usize),
+    Sequential,
+}
+
+/**
+Options for algorithms that run in parallel, such as
+[all_pairs_with_options](./algorithms/shortest_path/dijkstra/fn.all_pairs_with_options.html)
+and [betweenness_centrality_with_options](./algorithms/centrality/betweenness/fn.betweenness_centrality_with_options.html).
+
+Each algorithm that uses several threads has a `_with_options` variant that takes a
+`ParallelOptions`; the variant without it uses `ParallelOptions::default()`. This includes
+the all-pairs shortest path algorithms, delta-stepping, clustering, distance measures, link
+prediction, random walks, Monte Carlo personalized PageRank and
+[Graph::add_edges_par_with_options](./struct.Graph.html#method.add_edges_par_with_options).
+
+# Examples
+
+```
+use graphrs::{algorithms::centrality::betweenness, generators};
+use graphrs::{Parallelism, ParallelOptions};
+
+let graph = generators::social::karate_club_graph();
+let options = ParallelOptions {
+    parallelism: Parallelism::MaxThreads(2),
+    ..ParallelOptions::default()
+};
+let centralities = betweenness::betweenness_centrality_with_options(&graph, false, true, &options);
+assert_eq!(centralities.unwrap().len(), 34);
+```
+*/
+#[derive(Clone)]
+pub struct ParallelOptions {
+    /// The threads that the algorithm uses.
+    pub parallelism: Parallelism,
+    /// A [ProgressHandle](./struct.ProgressHandle.html) for reporting the progress of the
+    /// algorithm and cancelling it.
+    pub progress: ProgressHandle,
+}
+
+impl Default for ParallelOptions {
+    /// Returns `ParallelOptions` that use rayon's global thread pool, with a
+    /// `ProgressHandle` that has no callback.
+    fn default() -> Self {
+        ParallelOptions {
+            parallelism: Parallelism::GlobalPool,
+            progress: ProgressHandle::new(),
+        }
+    }
+}
+
+impl ParallelOptions {
+    /// Calls `f` for each of `0..len` with the threads of `parallelism`, returning the
+    /// results in order.
+    pub(crate) fn map_collect<R, F>(&self, len: usize, f: F) -> Result<Vec<R>, Error>
+    where
+        R: Send,
+        F: Fn(usize) -> R + Send + Sync,
+    {
+        self.run(
+            || (0..len).map(&f).collect(),
+            || (0..len).into_par_iter().map(&f).collect(),
+        )
+    }
+
+    /// Calls `f` for each of `0..len` like `map_collect`, with each call being a unit of
+    /// work for `progress`. Returns an `Error` if `progress` was cancelled.
+    pub(crate) fn map_collect_with_progress<R, F>(&self, len: usize, f: F) -> Result<Vec<R>, Error>
+    where
+        R: Send,
+        F: Fn(usize) -> R + Send + Sync,
+    {
+        let progress = &self.progress;
+        progress.start(Some(len))?;
+        let results = self.map_collect(len, |index| {
+            progress.check()?;
+            let result = f(index);
+            progress.advance();
+            Ok(result)
+        })?;
+        progress.check()?;
+        results.into_iter().collect()
+    }
+
+    /// Calls `f` for each of `0..len` with the threads of `parallelism`, combining the
+    /// results with `reduce`, starting from `identity()`.
+    pub(crate) fn map_reduce<R, F, I, OP>(
+        &self,
+        len: usize,
+        f: F,
+        identity: I,
+        reduce: OP,
+    ) -> Result<R, Error>
+    where
+        R: Send,
+        F: Fn(usize) -> R + Send + Sync,
+        I: Fn() -> R + Send + Sync,
+        OP: Fn(R, R) -> R + Send + Sync,
+    {
+        self.run(
+            || (0..len).map(&f).fold(identity(), &reduce),
+            || (0..len).into_par_iter().map(&f).reduce(&identity, &reduce),
+        )
+    }
+
+    /// Runs `f` in the thread pool of `parallelism`, passing `true`, or on the calling
+    /// thread, passing `false`, if `parallelism` is `Sequential`. `f` uses rayon's
+    /// parallel iterators only when it is passed `true`.
+    pub(crate) fn install<R, F>(&self, f: F) -> Result<R, Error>
+    where
+        R: Send,
+        F: FnOnce(bool) -> R + Send,
+    {
+        match &self.parallelism {
+            Parallelism::GlobalPool => Ok(f(true)),
+            Parallelism::ThreadPool(pool) => Ok(pool.install(|| f(true))),
+            Parallelism::MaxThreads(num_threads) => {
+                Ok(build_pool(*num_threads)?.install(|| f(true)))
+            }
+            Parallelism::Sequential => Ok(f(false)),
+        }
+    }
+
+    /// Runs `sequential` if `parallelism` is `Sequential`, otherwise runs `parallel` in
+    /// the thread pool of `parallelism`.
+    fn run<R, S, P>(&self, sequential: S, parallel: P) -> Result<R, Error>
+    where
+        R: Send,
+        S: FnOnce() -> R,
+        P: FnOnce() -> R + Send,
+    {
+        match &self.parallelism {
+            Parallelism::GlobalPool => Ok(parallel()),
+            Parallelism::ThreadPool(pool) => Ok(pool.install(parallel)),
+            Parallelism::MaxThreads(num_threads) => Ok(build_pool(*num_threads)?.install(parallel)),
+            Parallelism::Sequential => Ok(sequential()),
+        }
+    }
+}
+
+fn build_pool(num_threads: usize) -> Result<ThreadPool, Error> {
+    if num_threads == 0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "`Parallelism::MaxThreads` must be greater than 0.".to_string(),
+        });
+    }
+    ThreadPoolBuilder::new().num_threads(num_threads).build().map_err(|e| Error {
+        kind: ErrorKind::InvalidArgument,
+        message: format!("Failed to build a thread pool: {}", e),
+    })
+}
@@ -0,0 +1,132 @@
+use crate::{Error, ErrorKind};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/**
+How far a long-running algorithm has got, as passed to the callback of a
+[ProgressHandle](./struct.ProgressHandle.html).
+
+`completed` counts units of work, such as the source nodes that have been searched from.
+`total` is the number of units that the algorithm will do, or `None` if it isn't known in
+advance, as for the passes of the Louvain algorithm.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Progress {
+    pub completed: usize,
+    pub total: Option<usize>,
+}
+
+/**
+Reports the progress of a long-running algorithm, and lets it be cancelled.
+
+Algorithms that accept a `ProgressHandle` call its callback, if it has one, each time they
+complete a unit of work, and stop with an `Error` with a `kind` of `ErrorKind::Cancelled`
+soon after [cancel](#method.cancel) is called. Clones of a `ProgressHandle` share their
+state, so a clone can be kept by another thread, for example a GUI or server thread, to
+cancel the algorithm while it runs. The callback may be called from several threads at
+once by algorithms that run in parallel.
+
+# Examples
+
+```
+use graphrs::{algorithms::centrality::betweenness, generators, ErrorKind, ProgressHandle};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+let graph = generators::social::karate_club_graph();
+let calls = Arc::new(AtomicUsize::new(0));
+let counter = calls.clone();
+let progress = ProgressHandle::new().with_callback(move |p| {
+    assert_eq!(p.total, Some(34));
+    counter.fetch_add(1, Ordering::SeqCst);
+});
+betweenness::betweenness_centrality_with_progress(&graph, false, true, &progress).unwrap();
+assert_eq!(calls.load(Ordering::SeqCst), 34);
+assert_eq!(progress.get_progress().completed, 34);
+
+progress.cancel();
+let result = betweenness::betweenness_centrality_with_progress(&graph, false, true, &progress);
+assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+```
+*/
+#[derive(Clone, Default)]
+pub struct ProgressHandle {
+    state: Arc<ProgressState>,
+    #[allow(clippy::type_complexity)]
+    callback: Option<Arc<dyn Fn(Progress) + Send + Sync>>,
+}
+
+#[derive(Default)]
+struct ProgressState {
+    cancelled: AtomicBool,
+    completed: AtomicUsize,
+    /// the total number of units of work plus one, or 0 if the total isn't known
+    total: AtomicUsize,
+}
+
+impl ProgressHandle {
+    /// Returns a `ProgressHandle` that can be used to cancel an algorithm, but that
+    /// doesn't report its progress.
+    pub fn new() -> ProgressHandle {
+        ProgressHandle::default()
+    }
+
+    /// Sets the `callback` that is called with the [Progress](./struct.Progress.html) of
+    /// the algorithm each time it completes a unit of work.
+    pub fn with_callback<F>(mut self, callback: F) -> ProgressHandle
+    where
+        F: Fn(Progress) + Send + Sync + 'static,
+    {
+        self.callback = Some(Arc::new(callback));
+        self
+    }
+
+    /// Asks the algorithm that is using this handle, or any of its clones, to stop.
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` if [cancel](#method.cancel) has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns the progress of the algorithm that is using, or last used, this handle.
+    pub fn get_progress(&self) -> Progress {
+        Progress {
+            completed: self.state.completed.load(Ordering::SeqCst),
+            total: match self.state.total.load(Ordering::SeqCst) {
+                0 => None,
+                total => Some(total - 1),
+            },
+        }
+    }
+
+    /// Starts reporting the progress of an algorithm that will do `total` units of work.
+    pub(crate) fn start(&self, total: Option<usize>) -> Result<(), Error> {
+        self.state.completed.store(0, Ordering::SeqCst);
+        self.state.total.store(total.map_or(0, |t| t + 1), Ordering::SeqCst);
+        self.check()
+    }
+
+    /// Records that a unit of work has been completed and calls the callback.
+    pub(crate) fn advance(&self) {
+        let completed = self.state.completed.fetch_add(1, Ordering::SeqCst) + 1;
+        if let Some(callback) = &self.callback {
+            let total = self.get_progress().total;
+            callback(Progress { completed, total });
+        }
+    }
+
+    /// Returns an `Error` with a `kind` of `ErrorKind::Cancelled` if the algorithm has
+    /// been cancelled.
+    pub(crate) fn check(&self) -> Result<(), Error> {
+        match self.is_cancelled() {
+            false => Ok(()),
+            true => Err(Error {
+                kind: ErrorKind::Cancelled,
+                message: "The algorithm was cancelled through its `ProgressHandle`.".to_string(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,579 @@
+use crate::{
+    Edge, EdgeDedupeStrategy, Error, ErrorKind, Graph, GraphSpecs, MissingNodeStrategy, Node,
+    SelfLoopsFalseStrategy,
+};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::fs;
+use std::hash::Hash;
+
+/// The bytes that every file written by [save](fn.save.html) starts with.
+const MAGIC: &[u8; 4] = b"GRRS";
+/// The version of the format written by [save](fn.save.html).
+const VERSION: u32 = 3;
+/// The compression byte that follows the version when the rest of the data isn't compressed.
+const NO_COMPRESSION: u8 = 0;
+/// The compression byte that follows the version when the rest of the data is zstd-compressed.
+const ZSTD_COMPRESSION: u8 = 1;
+
+/**
+A type that can be written to, and read from, the binary graph format used by
+[save](fn.save.html) and [load](fn.load.html). It is implemented for the primitive
+number types, `bool`, `char`, `String` and `()`.
+
+Implement it for other node name or attribute types to save graphs that use them.
+
+# Examples
+
+```
+use graphrs::{readwrite::binary::Encode, Error};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl Encode for Point {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        self.x.encode(bytes);
+        self.y.encode(bytes);
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Result<Self, Error> {
+        Ok(Point {
+            x: f64::decode(bytes)?,
+            y: f64::decode(bytes)?,
+        })
+    }
+}
+
+let mut bytes = vec![];
+Point { x: 1.0, y: 2.0 }.encode(&mut bytes);
+assert_eq!(Point::decode(&mut bytes.as_slice()).unwrap(), Point { x: 1.0, y: 2.0 });
+```
+*/
+pub trait Encode: Sized {
+    /// Appends the encoded value to `bytes`.
+    fn encode(&self, bytes: &mut Vec<u8>);
+    /// Reads a value from the start of `bytes` and advances `bytes` past it.
+    fn decode(bytes: &mut &[u8]) -> Result<Self, Error>;
+}
+
+macro_rules! impl_encode_for_number {
+    ($($t:ty),*) => {
+        $(
+            impl Encode for $t {
+                fn encode(&self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn decode(bytes: &mut &[u8]) -> Result<Self, Error> {
+                    let mut array = [0; std::mem::size_of::<$t>()];
+                    array.copy_from_slice(take(bytes, std::mem::size_of::<$t>())?);
+                    Ok(<$t>::from_le_bytes(array))
+                }
+            }
+        )*
+    };
+}
+
+impl_encode_for_number!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl Encode for usize {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        (*self as u64).encode(bytes);
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Result<Self, Error> {
+        let value = u64::decode(bytes)?;
+        usize::try_from(value).map_err(|_| get_read_error("a usize value is too large"))
+    }
+}
+
+impl Encode for isize {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        (*self as i64).encode(bytes);
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Result<Self, Error> {
+        let value = i64::decode(bytes)?;
+        isize::try_from(value).map_err(|_| get_read_error("an isize value is too large"))
+    }
+}
+
+impl Encode for bool {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        bytes.push(*self as u8);
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Result<Self, Error> {
+        match u8::decode(bytes)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(get_read_error("a bool value is not 0 or 1")),
+        }
+    }
+}
+
+impl Encode for char {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        (*self as u32).encode(bytes);
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Result<Self, Error> {
+        char::from_u32(u32::decode(bytes)?).ok_or_else(|| get_read_error("a char is not valid"))
+    }
+}
+
+/// A `String` is encoded as its length in bytes followed by its UTF-8 bytes.
+impl Encode for String {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        self.len().encode(bytes);
+        bytes.extend_from_slice(self.as_bytes());
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Result<Self, Error> {
+        let len = usize::decode(bytes)?;
+        String::from_utf8(take(bytes, len)?.to_vec())
+            .map_err(|_| get_read_error("a string is not valid UTF-8"))
+    }
+}
+
+impl Encode for () {
+    fn encode(&self, _bytes: &mut Vec<u8>) {}
+
+    fn decode(_bytes: &mut &[u8]) -> Result<Self, Error> {
+        Ok(())
+    }
+}
+
+/// An `Option` is encoded as a `0` byte for `None`, or a `1` byte followed by the value.
+impl<E: Encode> Encode for Option<E> {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        match self {
+            None => bytes.push(0),
+            Some(value) => {
+                bytes.push(1);
+                value.encode(bytes);
+            }
+        }
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Result<Self, Error> {
+        match bool::decode(bytes)? {
+            false => Ok(None),
+            true => Ok(Some(E::decode(bytes)?)),
+        }
+    }
+}
+
+/**
+Returns a `Graph` encoded in a compact binary format, which can be decoded with
+[from_bytes](fn.from_bytes.html).
+
+The format is versioned and length-prefixed: a header with the format version and how
+the rest of the data is compressed is followed by the graph's specs, the number of nodes,
+the nodes, the number of edges and the edges. Edges refer to their nodes by position, so
+node names are only stored once. The edges of a multigraph are stored with their keys,
+which [from_bytes](fn.from_bytes.html) gives them back. The nodes are sorted by name and the edges by the names
+of their nodes, so the same graph always has the same bytes.
+
+The data isn't compressed; use [to_bytes_compressed](fn.to_bytes_compressed.html),
+which needs the `zstd` feature, to compress it.
+
+# Arguments
+
+* `graph`: the [Graph](../../struct.Graph.html) to encode; its node names and attributes
+  must implement [Encode](trait.Encode.html)
+
+# Examples
+
+```
+use graphrs::{generators, readwrite::binary, GraphSpecs};
+
+let graph = generators::social::karate_club_graph();
+let bytes = binary::to_bytes(&graph);
+let new_graph = binary::from_bytes::<i32, ()>(&bytes).unwrap();
+assert_eq!(new_graph.get_all_edges().len(), 78);
+```
+*/
+pub fn to_bytes<T, A>(graph: &Graph<T, A>) -> Vec<u8>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync + Encode,
+    A: Clone + Encode,
+{
+    let mut bytes = get_header(NO_COMPRESSION);
+    bytes.extend(encode_graph(graph));
+    bytes
+}
+
+/**
+Returns a `Graph` encoded in the binary format of [to_bytes](fn.to_bytes.html), with
+everything after the header compressed with zstd. [from_bytes](fn.from_bytes.html)
+decompresses it when the `zstd` feature is enabled.
+
+Requires the `zstd` feature.
+
+# Arguments
+
+* `graph`: the [Graph](../../struct.Graph.html) to encode
+* `level`: the zstd compression level, from 1 (fastest) to 22 (smallest); 0 uses
+  zstd's default level
+
+# Examples
+
+```
+use graphrs::{generators, readwrite::binary};
+
+let graph = generators::social::karate_club_graph();
+let bytes = binary::to_bytes_compressed(&graph, 3).unwrap();
+assert!(bytes.len() < binary::to_bytes(&graph).len());
+let new_graph = binary::from_bytes::<i32, ()>(&bytes).unwrap();
+assert_eq!(new_graph.get_all_edges().len(), 78);
+```
+*/
+#[cfg(feature = "zstd")]
+pub fn to_bytes_compressed<T, A>(graph: &Graph<T, A>, level: i32) -> Result<Vec<u8>, std::io::Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync + Encode,
+    A: Clone + Encode,
+{
+    let mut bytes = get_header(ZSTD_COMPRESSION);
+    bytes.extend(zstd::stream::encode_all(
+        encode_graph(graph).as_slice(),
+        level,
+    )?);
+    Ok(bytes)
+}
+
+/// Returns the magic bytes, the format version and the `compression` byte.
+fn get_header(compression: u8) -> Vec<u8> {
+    let mut bytes = MAGIC.to_vec();
+    VERSION.encode(&mut bytes);
+    bytes.push(compression);
+    bytes
+}
+
+/// Encodes the specs, nodes and edges of `graph`.
+fn encode_graph<T, A>(graph: &Graph<T, A>) -> Vec<u8>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync + Encode,
+    A: Clone + Encode,
+{
+    let mut nodes = graph.get_all_nodes();
+    nodes.sort_by(|n1, n2| n1.name.cmp(&n2.name));
+    let node_indexes: HashMap<&T, usize> =
+        nodes.iter().enumerate().map(|(i, n)| (&n.name, i)).collect();
+    // a stable sort, so parallel edges keep their order
+    let mut edges = graph.get_all_edges_with_keys();
+    edges.sort_by(|(e1, _), (e2, _)| (&e1.u, &e1.v).cmp(&(&e2.u, &e2.v)));
+
+    let mut bytes = vec![];
+    encode_specs(&graph.specs, &mut bytes);
+    nodes.len().encode(&mut bytes);
+    for node in nodes.iter() {
+        node.name.encode(&mut bytes);
+        node.attributes.encode(&mut bytes);
+    }
+    edges.len().encode(&mut bytes);
+    for (edge, key) in edges {
+        node_indexes.get(&edge.u).unwrap().encode(&mut bytes);
+        node_indexes.get(&edge.v).unwrap().encode(&mut bytes);
+        edge.weight.encode(&mut bytes);
+        edge.attributes.encode(&mut bytes);
+        if graph.specs.multi_edges {
+            key.encode(&mut bytes);
+        }
+    }
+    bytes
+}
+
+/**
+Creates a `Graph` from bytes that were created by [to_bytes](fn.to_bytes.html).
+
+# Arguments
+
+* `bytes`: the encoded graph
+
+# Returns
+
+An error with `ErrorKind::ReadError` if `bytes` isn't a graph encoded by a supported
+version of the format, or if it is compressed and the `zstd` feature isn't enabled.
+
+# Examples
+
+```
+use graphrs::{readwrite::binary, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<String, f64>::new(GraphSpecs::directed_create_missing());
+graph.add_edge(Edge::with_weight("a".to_string(), "b".to_string(), 2.0));
+let bytes = binary::to_bytes(&graph);
+
+let new_graph = binary::from_bytes::<String, f64>(&bytes).unwrap();
+assert_eq!(new_graph.get_edge("a".to_string(), "b".to_string()).unwrap().weight, 2.0);
+assert!(binary::from_bytes::<String, f64>(&bytes[..10]).is_err());
+```
+*/
+pub fn from_bytes<T, A>(bytes: &[u8]) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync + Encode,
+    A: Clone + Encode,
+{
+    let mut bytes = bytes;
+    if take(&mut bytes, MAGIC.len())? != MAGIC {
+        return Err(get_read_error("the data is not a graphrs binary graph"));
+    }
+    let version = u32::decode(&mut bytes)?;
+    if version != VERSION {
+        return Err(get_read_error(&format!(
+            "version {} of the binary graph format is not supported",
+            version
+        )));
+    }
+    match u8::decode(&mut bytes)? {
+        NO_COMPRESSION => decode_graph(bytes),
+        ZSTD_COMPRESSION => decode_graph(&decompress(bytes)?),
+        _ => Err(get_read_error("the compression of the data is not valid")),
+    }
+}
+
+#[cfg(feature = "zstd")]
+fn decompress(bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    zstd::stream::decode_all(bytes)
+        .map_err(|e| get_read_error(&format!("the data could not be decompressed: {}", e)))
+}
+
+#[cfg(not(feature = "zstd"))]
+fn decompress(_bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    Err(get_read_error(
+        "the data is zstd-compressed; enable the zstd feature to read it",
+    ))
+}
+
+/// Decodes the specs, nodes and edges that were encoded by `encode_graph`.
+fn decode_graph<T, A>(mut bytes: &[u8]) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync + Encode,
+    A: Clone + Encode,
+{
+    let specs = decode_specs(&mut bytes)?;
+    let num_nodes = usize::decode(&mut bytes)?;
+    // each node takes at least one byte, so a corrupt length can't cause a huge allocation
+    let mut nodes = Vec::with_capacity(std::cmp::min(num_nodes, bytes.len()));
+    for _ in 0..num_nodes {
+        nodes.push(Node {
+            name: T::decode(&mut bytes)?,
+            attributes: Option::<A>::decode(&mut bytes)?,
+        });
+    }
+    let num_edges = usize::decode(&mut bytes)?;
+    let mut edges = Vec::with_capacity(std::cmp::min(num_edges, bytes.len()));
+    let get_name = |index: usize| match nodes.get(index) {
+        None => Err(get_read_error(
+            "an edge refers to a node that doesn't exist",
+        )),
+        Some(node) => Ok(node.name.clone()),
+    };
+    for _ in 0..num_edges {
+        let edge = Edge {
+            u: get_name(usize::decode(&mut bytes)?)?,
+            v: get_name(usize::decode(&mut bytes)?)?,
+            weight: f64::decode(&mut bytes)?,
+            attributes: Option::<A>::decode(&mut bytes)?,
+        };
+        let key = match specs.multi_edges {
+            true => usize::decode(&mut bytes)?,
+            false => 0,
+        };
+        edges.push((edge, key));
+    }
+    if !bytes.is_empty() {
+        return Err(get_read_error("there is data after the end of the graph"));
+    }
+    let mut graph = Graph::new(specs);
+    graph.add_nodes(nodes);
+    graph.add_edges_with_keys(edges)?;
+    Ok(graph)
+}
+
+/**
+Saves a `Graph` to a file in a compact binary format. See [to_bytes](fn.to_bytes.html).
+
+# Arguments
+
+* `graph`: the [Graph](../../struct.Graph.html) to save
+* `file`: the name of the file to write
+
+# Examples
+
+```ignore
+use graphrs::{generators, readwrite::binary};
+let graph = generators::social::karate_club_graph();
+binary::save(&graph, "/some/file.grrs");
+```
+*/
+pub fn save<T, A>(graph: &Graph<T, A>, file: &str) -> Result<(), std::io::Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync + Encode,
+    A: Clone + Encode,
+{
+    fs::write(file, to_bytes(graph))
+}
+
+/**
+Saves a `Graph` to a file in the compressed binary format of
+[to_bytes_compressed](fn.to_bytes_compressed.html). Read it with [load](fn.load.html).
+
+Requires the `zstd` feature.
+
+# Arguments
+
+* `graph`: the [Graph](../../struct.Graph.html) to save
+* `file`: the name of the file to write
+* `level`: the zstd compression level, from 1 (fastest) to 22 (smallest); 0 uses
+  zstd's default level
+
+# Examples
+
+```ignore
+use graphrs::{generators, readwrite::binary};
+let graph = generators::social::karate_club_graph();
+binary::save_compressed(&graph, "/some/file.grrs", 3);
+```
+*/
+#[cfg(feature = "zstd")]
+pub fn save_compressed<T, A>(
+    graph: &Graph<T, A>,
+    file: &str,
+    level: i32,
+) -> Result<(), std::io::Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync + Encode,
+    A: Clone + Encode,
+{
+    fs::write(file, to_bytes_compressed(graph, level)?)
+}
+
+/**
+Loads a `Graph` from a file that was written by [save](fn.save.html) or
+[save_compressed](fn.save_compressed.html).
+
+# Arguments
+
+* `file`: the name of the file to read
+
+# Examples
+
+```ignore
+use graphrs::readwrite::binary;
+let graph = binary::load::<i32, ()>("/some/file.grrs");
+```
+*/
+pub fn load<T, A>(file: &str) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync + Encode,
+    A: Clone + Encode,
+{
+    match fs::read(file) {
+        Err(e) => Err(get_read_error(&format!("could not read {}: {}", file, e))),
+        Ok(bytes) => from_bytes(&bytes),
+    }
+}
+
+fn encode_specs(specs: &GraphSpecs, bytes: &mut Vec<u8>) {
+    specs.directed.encode(bytes);
+    specs.multi_edges.encode(bytes);
+    specs.self_loops.encode(bytes);
+    bytes.push(match specs.edge_dedupe_strategy {
+        EdgeDedupeStrategy::Error => 0,
+        EdgeDedupeStrategy::KeepFirst => 1,
+        EdgeDedupeStrategy::KeepLast => 2,
+    });
+    bytes.push(match specs.missing_node_strategy {
+        MissingNodeStrategy::Create => 0,
+        MissingNodeStrategy::Error => 1,
+    });
+    bytes.push(match specs.self_loops_false_strategy {
+        SelfLoopsFalseStrategy::Error => 0,
+        SelfLoopsFalseStrategy::Drop => 1,
+    });
+}
+
+fn decode_specs(bytes: &mut &[u8]) -> Result<GraphSpecs, Error> {
+    let invalid = || get_read_error("the graph specs are not valid");
+    Ok(GraphSpecs {
+        directed: bool::decode(bytes)?,
+        multi_edges: bool::decode(bytes)?,
+        self_loops: bool::decode(bytes)?,
+        edge_dedupe_strategy: match u8::decode(bytes)? {
+            0 => EdgeDedupeStrategy::Error,
+            1 => EdgeDedupeStrategy::KeepFirst,
+            2 => EdgeDedupeStrategy::KeepLast,
+            _ => return Err(invalid()),
+        },
+        missing_node_strategy: match u8::decode(bytes)? {
+            0 => MissingNodeStrategy::Create,
+            1 => MissingNodeStrategy::Error,
+            _ => return Err(invalid()),
+        },
+        self_loops_false_strategy: match u8::decode(bytes)? {
+            0 => SelfLoopsFalseStrategy::Error,
+            1 => SelfLoopsFalseStrategy::Drop,
+            _ => return Err(invalid()),
+        },
+    })
+}
+
+/// Returns the first `len` bytes and advances `bytes` past them.
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Result<&'a [u8], Error> {
+    if bytes.len() < len {
+        return Err(get_read_error("the data ended unexpectedly"));
+    }
+    let (taken, rest) = bytes.split_at(len);
+    *bytes = rest;
+    Ok(taken)
+}
+
+fn get_read_error(message: &str) -> Error {
+    Error {
+        kind: ErrorKind::ReadError,
+        message: message.to_string(),
+    }
+}
+
+// tests for private methods only; other tests are in:
+// tests/test_readwrite_binary.rs
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_specs() {
+        let specs = GraphSpecs {
+            multi_edges: true,
+            self_loops_false_strategy: SelfLoopsFalseStrategy::Drop,
+            ..GraphSpecs::undirected_create_missing()
+        };
+        let mut bytes = vec![];
+        encode_specs(&specs, &mut bytes);
+        assert_eq!(bytes, vec![0, 1, 0, 0, 0, 1]);
+        let decoded = decode_specs(&mut bytes.as_slice()).unwrap();
+        assert!(!decoded.directed);
+        assert!(decoded.multi_edges);
+        assert!(decoded.self_loops_false_strategy == SelfLoopsFalseStrategy::Drop);
+        assert!(decoded.missing_node_strategy == MissingNodeStrategy::Create);
+        bytes[3] = 3;
+        assert!(decode_specs(&mut bytes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_take() {
+        let mut bytes: &[u8] = &[1, 2, 3];
+        assert_eq!(take(&mut bytes, 2).unwrap(), &[1, 2]);
+        assert_eq!(bytes, &[3]);
+        assert!(take(&mut bytes, 2).is_err());
+    }
+}
@@ -0,0 +1,604 @@
+use crate::{Edge, Error, ErrorKind, Graph, GraphSpecs, Node};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs;
+use std::hash::Hash;
+
+/// A function that returns the label of a node.
+pub type NodeLabeler<T, A> = Box<dyn Fn(&Node<T, A>) -> String>;
+/// A function that returns the label of an edge.
+pub type EdgeLabeler<T, A> = Box<dyn Fn(&Edge<T, A>) -> String>;
+/// A function that returns the DOT attributes of a node, as `(name, value)` pairs.
+pub type NodeAttributeMapper<T, A> = Box<dyn Fn(&Node<T, A>) -> Vec<(String, String)>>;
+/// A function that returns the DOT attributes of an edge, as `(name, value)` pairs.
+pub type EdgeAttributeMapper<T, A> = Box<dyn Fn(&Edge<T, A>) -> Vec<(String, String)>>;
+
+/**
+Options for writing a [Graph](../../struct.Graph.html) in the DOT language.
+
+# Examples
+
+```
+use graphrs::{readwrite::dot::DotOptions, Edge};
+
+let options = DotOptions {
+    graph_name: Some("roads".to_string()),
+    edge_label: Some(Box::new(|e: &Edge<&str, ()>| format!("{} km", e.weight))),
+    ..DotOptions::default()
+};
+```
+*/
+pub struct DotOptions<T: PartialOrd + Send, A> {
+    /// The name of the graph.
+    pub graph_name: Option<String>,
+    /// If `true` the weight of each edge that has one is written as a `weight` attribute.
+    pub include_weights: bool,
+    /// Returns the `label` attribute of each node.
+    pub node_label: Option<NodeLabeler<T, A>>,
+    /// Returns the `label` attribute of each edge.
+    pub edge_label: Option<EdgeLabeler<T, A>>,
+    /// Returns more attributes for each node, for example mapped from the node's `attributes`.
+    pub node_attributes: Option<NodeAttributeMapper<T, A>>,
+    /// Returns more attributes for each edge, for example mapped from the edge's `attributes`.
+    pub edge_attributes: Option<EdgeAttributeMapper<T, A>>,
+}
+
+impl<T: PartialOrd + Send, A> Default for DotOptions<T, A> {
+    /// Returns `DotOptions` that write the node names, edges and edge weights, with no labels.
+    fn default() -> Self {
+        DotOptions {
+            graph_name: None,
+            include_weights: true,
+            node_label: None,
+            edge_label: None,
+            node_attributes: None,
+            edge_attributes: None,
+        }
+    }
+}
+
+/**
+Returns a `Graph` as a string in the DOT language, which can be visualized with Graphviz.
+
+# Arguments
+
+* `graph`: the [Graph](../../struct.Graph.html) to convert
+* `options`: the [DotOptions](struct.DotOptions.html) that determine what is written
+
+# Examples
+
+```
+use graphrs::{readwrite::dot::{self, DotOptions}, Edge, Graph, GraphSpecs};
+
+let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+graph.add_edges(vec![Edge::with_weight("a", "b", 2.5)]);
+
+let dot_string = dot::to_dot_string(&graph, &DotOptions::default());
+assert_eq!(dot_string, "digraph {\n  \"a\";\n  \"b\";\n  \"a\" -> \"b\" [weight=\"2.5\"];\n}\n");
+```
+*/
+pub fn to_dot_string<T, A>(graph: &Graph<T, A>, options: &DotOptions<T, A>) -> String
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let (graph_type, edge_op) = match graph.specs.directed {
+        true => ("digraph", "->"),
+        false => ("graph", "--"),
+    };
+    let mut dot = match &options.graph_name {
+        None => format!("{} {{\n", graph_type),
+        Some(name) => format!("{} {} {{\n", graph_type, quote(name)),
+    };
+    let mut nodes = graph.get_all_nodes();
+    nodes.sort_by(|n1, n2| n1.name.cmp(&n2.name));
+    for node in nodes {
+        let mut attributes = vec![];
+        if let Some(node_label) = &options.node_label {
+            attributes.push(("label".to_string(), node_label(node)));
+        }
+        if let Some(node_attributes) = &options.node_attributes {
+            attributes.extend(node_attributes(node));
+        }
+        let id = quote(&node.name.to_string());
+        dot.push_str(&format!("  {}{};\n", id, get_attribute_list(&attributes)));
+    }
+    let mut edges = graph.get_all_edges();
+    edges.sort_by(|e1, e2| (&e1.u, &e1.v).cmp(&(&e2.u, &e2.v)));
+    for edge in edges {
+        let mut attributes = vec![];
+        if options.include_weights && !edge.weight.is_nan() {
+            attributes.push(("weight".to_string(), edge.weight.to_string()));
+        }
+        if let Some(edge_label) = &options.edge_label {
+            attributes.push(("label".to_string(), edge_label(edge)));
+        }
+        if let Some(edge_attributes) = &options.edge_attributes {
+            attributes.extend(edge_attributes(edge));
+        }
+        dot.push_str(&format!(
+            "  {} {} {}{};\n",
+            quote(&edge.u.to_string()),
+            edge_op,
+            quote(&edge.v.to_string()),
+            get_attribute_list(&attributes)
+        ));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/**
+Writes a `Graph` to a file in the DOT language.
+
+# Arguments
+
+* `graph`: the [Graph](../../struct.Graph.html) to write to file
+* `file`: the name of the file to write
+* `options`: the [DotOptions](struct.DotOptions.html) that determine what is written
+
+# Examples
+
+```ignore
+use graphrs::{generators, readwrite::dot::{self, DotOptions}};
+let graph = generators::social::karate_club_graph();
+dot::write_dot(&graph, "/some/file.dot", &DotOptions::default());
+```
+*/
+pub fn write_dot<T, A>(
+    graph: &Graph<T, A>,
+    file: &str,
+    options: &DotOptions<T, A>,
+) -> Result<(), std::io::Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    fs::write(file, to_dot_string(graph, options))
+}
+
+/**
+Creates a graph from a string in the DOT language.
+
+Node and edge statements, with their attribute lists, are read; graph attributes and
+default attribute statements are ignored. Subgraphs aren't supported. A `weight` attribute
+on an edge becomes the edge's weight, and the other attributes of each node and edge are
+stored in its `attributes`.
+
+# Arguments
+
+* `dot`: the DOT string
+* `specs`: the [GraphSpecs](../../struct.GraphSpecs.html) to use for the created
+  [Graph](../../struct.Graph.html); `directed` is set according to whether the DOT string
+  describes a `graph` or a `digraph`
+
+# Examples
+
+```
+use graphrs::{readwrite::dot, GraphSpecs};
+
+let graph = dot::from_dot_string(
+    "digraph { a [color=red]; a -> b -> c [weight=2]; }",
+    GraphSpecs::directed(),
+)
+.unwrap();
+assert_eq!(graph.get_all_nodes().len(), 3);
+assert_eq!(graph.get_edge("b".to_string(), "c".to_string()).unwrap().weight, 2.0);
+let a = graph.get_node("a".to_string()).unwrap();
+assert_eq!(a.attributes.as_ref().unwrap().get("color").unwrap(), "red");
+```
+*/
+pub fn from_dot_string(
+    dot: &str,
+    specs: GraphSpecs,
+) -> Result<Graph<String, HashMap<String, String>>, Error> {
+    let tokens = tokenize(dot)?;
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+        nodes: vec![],
+        node_indexes: HashMap::new(),
+        edges: vec![],
+    };
+    let directed = parser.parse_graph()?;
+    let nodes = parser
+        .nodes
+        .into_iter()
+        .map(|(name, attributes)| Node {
+            name,
+            attributes: Some(attributes),
+        })
+        .collect();
+    Graph::new_from_nodes_and_edges(nodes, parser.edges, GraphSpecs { directed, ..specs })
+}
+
+/**
+Creates a graph from a file in the DOT language. See
+[from_dot_string](fn.from_dot_string.html).
+
+# Arguments
+
+* `file`: the path to a file in the DOT language
+* `specs`: the [GraphSpecs](../../struct.GraphSpecs.html) to use for the created
+  [Graph](../../struct.Graph.html)
+
+# Examples
+
+```ignore
+use graphrs::{readwrite::dot, GraphSpecs};
+let graph = dot::read_dot("/some/file.dot", GraphSpecs::directed());
+```
+*/
+pub fn read_dot(
+    file: &str,
+    specs: GraphSpecs,
+) -> Result<Graph<String, HashMap<String, String>>, Error> {
+    match fs::read_to_string(file) {
+        Err(e) => Err(get_read_error(&format!("could not read {}: {}", file, e))),
+        Ok(dot) => from_dot_string(&dot, specs),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Id(String),
+    /// A quoted ID, which is never a keyword.
+    QuotedId(String),
+    EdgeOp,
+    LeftBrace,
+    RightBrace,
+    LeftBracket,
+    RightBracket,
+    Equals,
+    Comma,
+    Semicolon,
+    Colon,
+}
+
+/// Splits a DOT string into tokens, skipping whitespace and comments.
+fn tokenize(dot: &str) -> Result<Vec<Token>, Error> {
+    let chars: Vec<char> = dot.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    let mut line_start = true;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\n' {
+            line_start = true;
+            i += 1;
+            continue;
+        }
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        if c == '#' && line_start {
+            // preprocessor output lines are ignored
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+        line_start = false;
+        let next = chars.get(i + 1).copied();
+        match c {
+            '/' if next == Some('/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if next == Some('*') => {
+                i += 2;
+                while i + 1 < chars.len() && !(chars[i] == '*' && chars[i + 1] == '/') {
+                    i += 1;
+                }
+                i += 2;
+            }
+            '-' if next == Some('>') || next == Some('-') => {
+                tokens.push(Token::EdgeOp);
+                i += 2;
+            }
+            '{' | '}' | '[' | ']' | '=' | ',' | ';' | ':' => {
+                tokens.push(match c {
+                    '{' => Token::LeftBrace,
+                    '}' => Token::RightBrace,
+                    '[' => Token::LeftBracket,
+                    ']' => Token::RightBracket,
+                    '=' => Token::Equals,
+                    ',' => Token::Comma,
+                    ';' => Token::Semicolon,
+                    _ => Token::Colon,
+                });
+                i += 1;
+            }
+            '"' => {
+                let mut id = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err(get_read_error("a quoted string was not closed")),
+                        Some('"') => break,
+                        Some('\\') if matches!(chars.get(i + 1), Some('"') | Some('\\')) => {
+                            id.push(chars[i + 1]);
+                            i += 2;
+                        }
+                        Some('\\') if chars.get(i + 1) == Some(&'\n') => i += 2,
+                        Some(c) => {
+                            id.push(*c);
+                            i += 1;
+                        }
+                    }
+                }
+                i += 1;
+                tokens.push(Token::QuotedId(id));
+            }
+            '<' => {
+                // an HTML string, which may contain nested angle brackets
+                let mut depth = 0;
+                let start = i;
+                loop {
+                    match chars.get(i) {
+                        None => return Err(get_read_error("an HTML string was not closed")),
+                        Some('<') => depth += 1,
+                        Some('>') => depth -= 1,
+                        _ => (),
+                    }
+                    i += 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                tokens.push(Token::Id(chars[start..i].iter().collect()));
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' => {
+                let start = i;
+                while i < chars.len()
+                    && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+                {
+                    i += 1;
+                }
+                if i == start {
+                    // a '-' that starts a negative number
+                    i += 1;
+                    while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                        i += 1;
+                    }
+                }
+                tokens.push(Token::Id(chars[start..i].iter().collect()));
+            }
+            _ => {
+                return Err(get_read_error(&format!(
+                    "the character '{}' was not expected",
+                    c
+                )))
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Builds the nodes and edges of a graph from DOT tokens.
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    /// The nodes, in the order they were first seen, with their attributes.
+    nodes: Vec<(String, HashMap<String, String>)>,
+    node_indexes: HashMap<String, usize>,
+    edges: Vec<Edge<String, HashMap<String, String>>>,
+}
+
+impl Parser {
+    /// Parses a whole graph, returning `true` if it is a `digraph`.
+    fn parse_graph(&mut self) -> Result<bool, Error> {
+        if self.peek_keyword("strict") {
+            self.position += 1;
+        }
+        let directed = match self.next_token() {
+            Some(Token::Id(id)) if id.eq_ignore_ascii_case("digraph") => true,
+            Some(Token::Id(id)) if id.eq_ignore_ascii_case("graph") => false,
+            _ => return Err(get_read_error("expected \"graph\" or \"digraph\"")),
+        };
+        if let Some(Token::Id(_)) | Some(Token::QuotedId(_)) = self.peek() {
+            self.position += 1;
+        }
+        self.expect(Token::LeftBrace)?;
+        loop {
+            match self.peek() {
+                None => return Err(get_read_error("expected \"}\" at the end of the graph")),
+                Some(Token::RightBrace) => {
+                    self.position += 1;
+                    break;
+                }
+                Some(Token::Semicolon) => self.position += 1,
+                Some(Token::LeftBrace) => {
+                    return Err(get_read_error("subgraphs are not supported"))
+                }
+                Some(Token::Id(_)) | Some(Token::QuotedId(_)) => self.parse_statement()?,
+                Some(token) => {
+                    return Err(get_read_error(&format!(
+                        "the token {:?} was not expected",
+                        token
+                    )))
+                }
+            }
+        }
+        Ok(directed)
+    }
+
+    /// Parses a node, edge, attribute or graph attribute statement.
+    fn parse_statement(&mut self) -> Result<(), Error> {
+        if self.peek_keyword("subgraph") {
+            return Err(get_read_error("subgraphs are not supported"));
+        }
+        let is_keyword = ["graph", "node", "edge"].iter().any(|k| self.peek_keyword(k));
+        let id = self.next_id()?;
+        if is_keyword && self.peek() == Some(&Token::LeftBracket) {
+            self.parse_attribute_lists()?;
+            return Ok(());
+        }
+        if self.peek() == Some(&Token::Equals) {
+            self.position += 1;
+            self.next_id()?;
+            return Ok(());
+        }
+        self.skip_port()?;
+        let mut node_ids = vec![id];
+        while self.peek() == Some(&Token::EdgeOp) {
+            self.position += 1;
+            node_ids.push(self.next_id()?);
+            self.skip_port()?;
+        }
+        let mut attributes = self.parse_attribute_lists()?;
+        if node_ids.len() == 1 {
+            let index = self.ensure_node(&node_ids[0]);
+            self.nodes[index].1.extend(attributes);
+            return Ok(());
+        }
+        let weight = match attributes.remove("weight") {
+            None => f64::NAN,
+            Some(weight) => weight.parse::<f64>().map_err(|_| {
+                get_read_error(&format!("the edge weight \"{}\" is not a number", weight))
+            })?,
+        };
+        for id in node_ids.iter() {
+            self.ensure_node(id);
+        }
+        for pair in node_ids.windows(2) {
+            self.edges.push(Edge {
+                u: pair[0].clone(),
+                v: pair[1].clone(),
+                attributes: Some(attributes.clone()),
+                weight,
+            });
+        }
+        Ok(())
+    }
+
+    /// Parses zero or more `[name=value, ...]` lists.
+    fn parse_attribute_lists(&mut self) -> Result<HashMap<String, String>, Error> {
+        let mut attributes = HashMap::new();
+        while self.peek() == Some(&Token::LeftBracket) {
+            self.position += 1;
+            loop {
+                match self.next_token() {
+                    Some(Token::RightBracket) => break,
+                    Some(Token::Comma) | Some(Token::Semicolon) => (),
+                    Some(Token::Id(name)) | Some(Token::QuotedId(name)) => {
+                        self.expect(Token::Equals)?;
+                        let value = self.next_id()?;
+                        attributes.insert(name, value);
+                    }
+                    _ => return Err(get_read_error("an attribute list was not valid")),
+                }
+            }
+        }
+        Ok(attributes)
+    }
+
+    /// Skips the `:port` or `:port:compass_point` that may follow a node ID.
+    fn skip_port(&mut self) -> Result<(), Error> {
+        while self.peek() == Some(&Token::Colon) {
+            self.position += 1;
+            self.next_id()?;
+        }
+        Ok(())
+    }
+
+    fn ensure_node(&mut self, id: &str) -> usize {
+        match self.node_indexes.get(id) {
+            Some(index) => *index,
+            None => {
+                self.nodes.push((id.to_string(), HashMap::new()));
+                self.node_indexes.insert(id.to_string(), self.nodes.len() - 1);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    /// Returns `true` if the next token is `keyword`, which can't be quoted.
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Id(id)) if id.eq_ignore_ascii_case(keyword))
+    }
+
+    fn next_token(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn next_id(&mut self) -> Result<String, Error> {
+        match self.next_token() {
+            Some(Token::Id(id)) | Some(Token::QuotedId(id)) => Ok(id),
+            _ => Err(get_read_error("expected an ID")),
+        }
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), Error> {
+        match self.next_token() {
+            Some(token) if token == expected => Ok(()),
+            _ => Err(get_read_error(&format!("expected {:?}", expected))),
+        }
+    }
+}
+
+/// Returns `s` as a quoted DOT ID, with `\` and `"` escaped.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Returns ` [name="value" ...]`, or an empty string if there are no attributes.
+fn get_attribute_list(attributes: &[(String, String)]) -> String {
+    match attributes.is_empty() {
+        true => "".to_string(),
+        false => format!(
+            " [{}]",
+            attributes
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, quote(value)))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn get_read_error(message: &str) -> Error {
+    Error {
+        kind: ErrorKind::ReadError,
+        message: message.to_string(),
+    }
+}
+
+// tests for private methods only; other tests are in:
+// tests/test_readwrite_dot.rs
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let tokens =
+            tokenize("a:n -> \"b \\\"c\\\"\" [w=-1.5] // comment\n/* x */ <<b>y</b>>;").unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Id("a".to_string()),
+                Token::Colon,
+                Token::Id("n".to_string()),
+                Token::EdgeOp,
+                Token::QuotedId("b \"c\"".to_string()),
+                Token::LeftBracket,
+                Token::Id("w".to_string()),
+                Token::Equals,
+                Token::Id("-1.5".to_string()),
+                Token::RightBracket,
+                Token::Id("<<b>y</b>>".to_string()),
+                Token::Semicolon,
+            ]
+        );
+    }
+}
@@ -0,0 +1,379 @@
+use crate::{Edge, Error, ErrorKind, Graph, GraphSpecs, Node};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::hash::Hash;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+
+/**
+Options for reading and writing delimited edge lists, where each line is an edge with the
+source node in the first column and the target node in the second column.
+
+# Examples
+
+```
+use graphrs::readwrite::edgelist::EdgeListOptions;
+
+// tab separated, no header, weights in the third column
+let options = EdgeListOptions {
+    delimiter: '\t',
+    has_header: false,
+    weight_column: Some(2),
+    ..EdgeListOptions::default()
+};
+```
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct EdgeListOptions {
+    /// The character that separates columns, for example `','` for CSV or `'\t'` for TSV.
+    pub delimiter: char,
+    /// If `true` the first line names the columns.
+    pub has_header: bool,
+    /// The zero-based column that has the edge weights, if any.
+    pub weight_column: Option<usize>,
+    /**
+    The zero-based columns to read into each edge's attributes. The attributes are named
+    by the header, or by the column number if there is no header.
+    */
+    pub attribute_columns: Vec<usize>,
+}
+
+impl Default for EdgeListOptions {
+    /// Returns `EdgeListOptions` for a comma-separated file with a header and no weights.
+    fn default() -> Self {
+        EdgeListOptions {
+            delimiter: ',',
+            has_header: true,
+            weight_column: None,
+            attribute_columns: vec![],
+        }
+    }
+}
+
+/**
+An iterator that reads edges from a delimited edge list one edge at a time, so the whole
+list is never held in memory. Created by [EdgeListReader::new](struct.EdgeListReader.html#method.new).
+
+Blank lines are skipped. Fields may be enclosed in double quotes, and a double quote inside
+a quoted field is written as two double quotes. A quoted field may contain line breaks, so
+one edge can span several lines.
+*/
+pub struct EdgeListReader<R: BufRead> {
+    reader: R,
+    options: EdgeListOptions,
+    /// The name of each attribute column.
+    attribute_names: Vec<String>,
+    line_number: usize,
+}
+
+impl<R: BufRead> EdgeListReader<R> {
+    /**
+    Creates an `EdgeListReader`, reading the header if there is one.
+
+    # Arguments
+
+    * `reader`: the source of the edge list, for example a `BufReader<File>`
+    * `options`: the [EdgeListOptions](struct.EdgeListOptions.html) that describe the columns
+
+    # Examples
+
+    ```
+    use graphrs::readwrite::edgelist::{EdgeListOptions, EdgeListReader};
+
+    let data = "from,to,weight\na,b,1.5\nb,c,2\n";
+    let options = EdgeListOptions {
+        weight_column: Some(2),
+        ..EdgeListOptions::default()
+    };
+    let reader = EdgeListReader::new(data.as_bytes(), options).unwrap();
+    let weights: Vec<f64> = reader.map(|e| e.unwrap().weight).collect();
+    assert_eq!(weights, vec![1.5, 2.0]);
+    ```
+    */
+    pub fn new(reader: R, options: EdgeListOptions) -> Result<EdgeListReader<R>, Error> {
+        let mut edge_list_reader = EdgeListReader {
+            reader,
+            options,
+            attribute_names: vec![],
+            line_number: 0,
+        };
+        let header = match edge_list_reader.options.has_header {
+            true => edge_list_reader.next_fields()?,
+            false => None,
+        };
+        edge_list_reader.attribute_names = edge_list_reader
+            .options
+            .attribute_columns
+            .iter()
+            .map(|c| match &header {
+                None => Ok(c.to_string()),
+                Some(header) => header
+                    .get(*c)
+                    .cloned()
+                    .ok_or_else(|| get_read_error(&format!("the header has no column {}", c))),
+            })
+            .collect::<Result<Vec<String>, Error>>()?;
+        Ok(edge_list_reader)
+    }
+
+    /**
+    Returns the fields of the next record that isn't blank, or `None` at the end of the input.
+    A record is read up to the first line break that isn't inside a quoted field.
+    */
+    fn next_fields(&mut self) -> Result<Option<Vec<String>>, Error> {
+        let mut record = String::new();
+        let mut first_line_number = self.line_number + 1;
+        loop {
+            let bytes_read =
+                self.reader.read_line(&mut record).map_err(|e| get_read_error(&e.to_string()))?;
+            if bytes_read == 0 && record.is_empty() {
+                return Ok(None);
+            }
+            self.line_number += 1;
+            if record.trim().is_empty() {
+                record.clear();
+                first_line_number = self.line_number + 1;
+                continue;
+            }
+            let line = record.strip_suffix('\n').unwrap_or(&record);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            match split_line(line, self.options.delimiter) {
+                // the line break is inside a quoted field, so the record continues
+                Err(_) if bytes_read > 0 => continue,
+                result => {
+                    return result
+                        .map(Some)
+                        .map_err(|m| get_read_error(&format!("line {}: {}", first_line_number, m)))
+                }
+            }
+        }
+    }
+
+    /// Creates an edge from the fields of a line.
+    fn get_edge(
+        &self,
+        fields: Vec<String>,
+    ) -> Result<Edge<String, HashMap<String, String>>, Error> {
+        let get_field = |column: usize| {
+            fields.get(column).ok_or_else(|| {
+                get_read_error(&format!(
+                    "line {} has no column {}",
+                    self.line_number, column
+                ))
+            })
+        };
+        let weight = match self.options.weight_column {
+            None => f64::NAN,
+            Some(column) => {
+                let weight = get_field(column)?;
+                weight.trim().parse::<f64>().map_err(|_| {
+                    get_read_error(&format!(
+                        "line {}: the weight \"{}\" is not a number",
+                        self.line_number, weight
+                    ))
+                })?
+            }
+        };
+        let mut attributes = HashMap::new();
+        for (name, column) in self.attribute_names.iter().zip(self.options.attribute_columns.iter())
+        {
+            attributes.insert(name.clone(), get_field(*column)?.clone());
+        }
+        Ok(Edge {
+            u: get_field(0)?.clone(),
+            v: get_field(1)?.clone(),
+            attributes: Some(attributes),
+            weight,
+        })
+    }
+}
+
+impl<R: BufRead> Iterator for EdgeListReader<R> {
+    type Item = Result<Edge<String, HashMap<String, String>>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_fields() {
+            Err(e) => Some(Err(e)),
+            Ok(None) => None,
+            Ok(Some(fields)) => Some(self.get_edge(fields)),
+        }
+    }
+}
+
+/**
+Reads a graph from a delimited edge list file, such as a CSV or TSV file. The file is read
+one line at a time. See [EdgeListReader](struct.EdgeListReader.html).
+
+# Arguments
+
+* `file`: the path to the edge list file
+* `specs`: the [GraphSpecs](../../struct.GraphSpecs.html) to use for the created
+  [Graph](../../struct.Graph.html); nodes are added as they are found, so
+  `missing_node_strategy` doesn't apply
+* `options`: the [EdgeListOptions](struct.EdgeListOptions.html) that describe the columns
+
+# Examples
+
+```ignore
+use graphrs::{readwrite::edgelist::{self, EdgeListOptions}, GraphSpecs};
+let graph = edgelist::read_edgelist(
+    "/some/file.csv",
+    GraphSpecs::directed(),
+    EdgeListOptions::default(),
+);
+```
+*/
+pub fn read_edgelist(
+    file: &str,
+    specs: GraphSpecs,
+    options: EdgeListOptions,
+) -> Result<Graph<String, HashMap<String, String>>, Error> {
+    let reader = File::open(file)
+        .map(BufReader::new)
+        .map_err(|e| get_read_error(&format!("could not read {}: {}", file, e)))?;
+    let mut graph = Graph::new(specs);
+    for edge in EdgeListReader::new(reader, options)? {
+        let edge = edge?;
+        for name in [&edge.u, &edge.v] {
+            if !graph.has_node(name) {
+                graph.add_node(Node::from_name(name.clone()));
+            }
+        }
+        graph.add_edge(edge)?;
+    }
+    Ok(graph)
+}
+
+/**
+Writes the edges of a graph to a delimited edge list file, such as a CSV or TSV file.
+
+The source node is written to the first column and the target node to the second. If
+`options.weight_column` is set the edge weights are written to that column. Edge
+attributes aren't written; `options.attribute_columns` is only used when reading.
+
+# Arguments
+
+* `graph`: the [Graph](../../struct.Graph.html) to write to file
+* `file`: the name of the file to write
+* `options`: the [EdgeListOptions](struct.EdgeListOptions.html) that describe the columns
+
+# Examples
+
+```ignore
+use graphrs::{generators, readwrite::edgelist::{self, EdgeListOptions}};
+let graph = generators::social::karate_club_graph();
+edgelist::write_edgelist(&graph, "/some/file.csv", &EdgeListOptions::default());
+```
+*/
+pub fn write_edgelist<T, A>(
+    graph: &Graph<T, A>,
+    file: &str,
+    options: &EdgeListOptions,
+) -> Result<(), std::io::Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let num_columns = match options.weight_column {
+        None => 2,
+        Some(column) => std::cmp::max(2, column + 1),
+    };
+    let mut writer = BufWriter::new(fs::File::create(file)?);
+    let mut write_row = |values: HashMap<usize, String>| {
+        let row: Vec<String> = (0..num_columns)
+            .map(|c| match values.get(&c) {
+                None => "".to_string(),
+                Some(value) => quote_field(value, options.delimiter),
+            })
+            .collect();
+        writeln!(writer, "{}", row.join(&options.delimiter.to_string()))
+    };
+    if options.has_header {
+        let mut header = HashMap::new();
+        header.insert(0, "source".to_string());
+        header.insert(1, "target".to_string());
+        if let Some(column) = options.weight_column {
+            header.insert(column, "weight".to_string());
+        }
+        write_row(header)?;
+    }
+    let mut edges = graph.get_all_edges();
+    edges.sort_by(|e1, e2| (&e1.u, &e1.v).cmp(&(&e2.u, &e2.v)));
+    for edge in edges {
+        let mut values = HashMap::new();
+        values.insert(0, edge.u.to_string());
+        values.insert(1, edge.v.to_string());
+        if let Some(column) = options.weight_column {
+            values.insert(column, edge.weight.to_string());
+        }
+        write_row(values)?;
+    }
+    writer.flush()
+}
+
+/// Splits a line into fields, removing the double quotes around quoted fields.
+fn split_line(line: &str, delimiter: char) -> Result<Vec<String>, String> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut chars = line.chars().peekable();
+    let mut in_quotes = false;
+    while let Some(c) = chars.next() {
+        match (in_quotes, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            (true, '"') => in_quotes = false,
+            (true, c) => field.push(c),
+            (false, '"') if field.is_empty() => in_quotes = true,
+            (false, c) if c == delimiter => fields.push(std::mem::take(&mut field)),
+            (false, c) => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err("a quoted field was not closed".to_string());
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+/// Encloses `field` in double quotes if it contains the delimiter, a double quote or a line break.
+fn quote_field(field: &str, delimiter: char) -> String {
+    match field.contains([delimiter, '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+fn get_read_error(message: &str) -> Error {
+    Error {
+        kind: ErrorKind::ReadError,
+        message: message.to_string(),
+    }
+}
+
+// tests for private methods only; other tests are in:
+// tests/test_readwrite_edgelist.rs
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_split_line() {
+        assert_eq!(
+            split_line("a,\"b,\"\"c\"\"\",,d", ',').unwrap(),
+            vec!["a", "b,\"c\"", "", "d"]
+        );
+        assert_eq!(split_line("a\tb c", '\t').unwrap(), vec!["a", "b c"]);
+        assert!(split_line("a,\"b", ',').is_err());
+    }
+
+    #[test]
+    fn test_quote_field() {
+        assert_eq!(quote_field("a b", ','), "a b");
+        assert_eq!(quote_field("a,b", ','), "\"a,b\"");
+        assert_eq!(quote_field("a\"b", '\t'), "\"a\"\"b\"");
+        assert_eq!(quote_field("a\r\nb", ','), "\"a\r\nb\"");
+    }
+}
@@ -1,2 +1,11 @@
+/// Save and load graphs in a compact binary format.
+pub mod binary;
+
 /// Read and write GraphML files.
 pub mod graphml;
+
+/// Read and write DOT (Graphviz) files.
+pub mod dot;
+
+/// Read and write delimited edge lists, such as CSV and TSV files.
+pub mod edgelist;
@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::approximation::tsp::{self, Tour},
+        generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+    use itertools::Itertools;
+
+    /// A complete graph of points in the plane, weighted by the distances between them.
+    fn get_metric_graph(num_nodes: usize, seed: usize) -> Graph<usize, ()> {
+        let points: Vec<(f64, f64)> = (0..num_nodes)
+            .map(|i| {
+                (
+                    ((i * 37 + seed * 11) % 20) as f64,
+                    ((i * 53 + seed * 7) % 17) as f64,
+                )
+            })
+            .collect();
+        let mut graph = Graph::new(GraphSpecs::undirected_create_missing());
+        for (u, v) in (0..num_nodes).tuple_combinations() {
+            let weight =
+                ((points[u].0 - points[v].0).powi(2) + (points[u].1 - points[v].1).powi(2)).sqrt();
+            graph.add_edge(Edge::with_weight(u, v, weight)).unwrap();
+        }
+        graph
+    }
+
+    /// A complete directed graph whose edge weights depend on their direction.
+    fn get_asymmetric_graph(num_nodes: usize) -> Graph<usize, ()> {
+        let mut graph = Graph::new(GraphSpecs::directed_create_missing());
+        for (u, v) in (0..num_nodes).tuple_combinations() {
+            graph.add_edge(Edge::with_weight(u, v, ((u * 7 + v * 3) % 10 + 1) as f64)).unwrap();
+            graph.add_edge(Edge::with_weight(v, u, ((u * 5 + v * 11) % 10 + 1) as f64)).unwrap();
+        }
+        graph
+    }
+
+    fn get_tour_weight(graph: &Graph<usize, ()>, nodes: &[usize]) -> f64 {
+        (0..nodes.len())
+            .map(|i| graph.get_edge(nodes[i], nodes[(i + 1) % nodes.len()]).unwrap().weight)
+            .sum()
+    }
+
+    fn get_optimal_weight(graph: &Graph<usize, ()>, num_nodes: usize) -> f64 {
+        (1..num_nodes)
+            .permutations(num_nodes - 1)
+            .map(|p| get_tour_weight(graph, &[vec![0], p].concat()))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    fn assert_valid_tour(graph: &Graph<usize, ()>, tour: &Tour<usize>) {
+        let mut nodes = tour.nodes.clone();
+        nodes.sort_unstable();
+        assert_eq!(
+            nodes,
+            (0..graph.get_all_nodes().len()).collect::<Vec<usize>>()
+        );
+        assert!((tour.weight - get_tour_weight(graph, &tour.nodes)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_christofides_1() {
+        for seed in 0..10 {
+            let graph = get_metric_graph(7, seed);
+            let optimal = get_optimal_weight(&graph, 7);
+            let tour = tsp::christofides(&graph, true).unwrap();
+            assert_valid_tour(&graph, &tour);
+            assert!(tour.weight >= optimal - 1e-9);
+            assert!(tour.weight <= optimal * 1.5 + 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_christofides_2() {
+        let graph = generators::classic::complete_graph(5, false);
+        let tour = tsp::christofides(&graph, false).unwrap();
+        assert_eq!(tour.nodes.len(), 5);
+        assert_eq!(tour.weight, 5.0);
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected());
+        graph.add_node(Node::from_name(0));
+        let tour = tsp::christofides(&graph, false).unwrap();
+        assert_eq!(
+            tour,
+            Tour {
+                nodes: vec![0],
+                weight: 0.0
+            }
+        );
+        let graph = generators::classic::complete_graph(2, false);
+        let tour = tsp::christofides(&graph, false).unwrap();
+        assert_eq!(tour.nodes, vec![0, 1]);
+        assert_eq!(tour.weight, 2.0);
+    }
+
+    #[test]
+    fn test_greedy_1() {
+        let graph = get_asymmetric_graph(5);
+        let tour = tsp::greedy(&graph, true, Some(2)).unwrap();
+        assert_valid_tour(&graph, &tour);
+        assert_eq!(tour.nodes[0], 2);
+        for seed in 0..10 {
+            let graph = get_metric_graph(7, seed);
+            let tour = tsp::greedy(&graph, true, None).unwrap();
+            assert_valid_tour(&graph, &tour);
+            assert_eq!(tour.nodes[0], 0);
+            assert!(tour.weight >= get_optimal_weight(&graph, 7) - 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_greedy_2() {
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("a", "b", 1.0),
+                Edge::with_weight("b", "a", 5.0),
+                Edge::with_weight("a", "c", 2.0),
+                Edge::with_weight("c", "a", 1.0),
+                Edge::with_weight("b", "c", 3.0),
+                Edge::with_weight("c", "b", 4.0),
+            ])
+            .unwrap();
+        let tour = tsp::greedy(&graph, true, None).unwrap();
+        assert_eq!(tour.nodes, vec!["a", "b", "c"]);
+        assert_eq!(tour.weight, 5.0);
+        let tour = tsp::greedy(&graph, true, Some("c")).unwrap();
+        assert_eq!(tour.nodes, vec!["c", "a", "b"]);
+        assert_eq!(tour.weight, 5.0);
+        let tour = tsp::greedy(&graph, false, Some("b")).unwrap();
+        assert_eq!(tour.nodes, vec!["b", "a", "c"]);
+        assert_eq!(tour.weight, 3.0);
+    }
+
+    #[test]
+    fn test_simulated_annealing_1() {
+        for seed in 0..5 {
+            let graph = get_metric_graph(7, seed);
+            let greedy = tsp::greedy(&graph, true, None).unwrap();
+            let tour =
+                tsp::simulated_annealing(&graph, true, None, 5000, Some(seed as u64)).unwrap();
+            assert_valid_tour(&graph, &tour);
+            assert!(tour.weight <= greedy.weight + 1e-9);
+            assert!((tour.weight - get_optimal_weight(&graph, 7)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_simulated_annealing_2() {
+        let graph = get_asymmetric_graph(6);
+        let initial_tour = Some(vec![5, 4, 3, 2, 1, 0]);
+        let tour = tsp::simulated_annealing(&graph, true, initial_tour, 5000, Some(1)).unwrap();
+        assert_valid_tour(&graph, &tour);
+        assert_eq!(tour.nodes[0], 5);
+        assert_eq!(tour.weight, get_optimal_weight(&graph, 6));
+        let again =
+            tsp::simulated_annealing(&graph, true, Some(vec![5, 4, 3, 2, 1, 0]), 5000, Some(1))
+                .unwrap();
+        assert_eq!(tour, again);
+    }
+
+    #[test]
+    fn test_tsp_errors() {
+        let graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected());
+        let result = tsp::greedy(&graph, false, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let graph = generators::classic::path_graph(4, false);
+        let result = tsp::christofides(&graph, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let graph = generators::classic::complete_graph(4, true);
+        let result = tsp::christofides(&graph, false);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let result = tsp::greedy(&graph, true, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+        let result = tsp::greedy(&graph, false, Some(4));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = tsp::simulated_annealing(&graph, false, Some(vec![0, 1, 2, 2]), 10, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = tsp::simulated_annealing(&graph, false, Some(vec![0, 1, 2]), 10, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let mut graph = generators::classic::complete_graph(3, false);
+        graph.add_node(Node::from_name(3));
+        let result = tsp::greedy(&graph, false, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+}
@@ -0,0 +1,97 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::round;
+    use graphrs::{
+        algorithms::assortativity, generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+
+    fn get_graph(specs: GraphSpecs) -> Graph<&'static str, (&'static str, f64)> {
+        let mut graph = Graph::new(specs);
+        graph.add_nodes(vec![
+            Node::from_name_and_attributes("n1", ("a", 1.0)),
+            Node::from_name_and_attributes("n2", ("b", 4.0)),
+            Node::from_name_and_attributes("n3", ("a", 2.0)),
+            Node::from_name_and_attributes("n4", ("b", 3.0)),
+            Node::from_name_and_attributes("n5", ("a", 5.0)),
+        ]);
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n1", "n3", 2.0),
+                Edge::with_weight("n2", "n3", 1.5),
+                Edge::with_weight("n3", "n4", 3.0),
+                Edge::with_weight("n4", "n1", 0.5),
+                Edge::with_weight("n4", "n2", 2.5),
+                Edge::with_weight("n5", "n1", 1.0),
+            ])
+            .unwrap();
+        graph
+    }
+
+    fn category(node: &Node<&str, (&'static str, f64)>) -> &'static str {
+        node.attributes.unwrap().0
+    }
+
+    fn number(node: &Node<&str, (&'static str, f64)>) -> f64 {
+        node.attributes.unwrap().1
+    }
+
+    #[test]
+    fn test_degree_assortativity_coefficient() {
+        let graph = generators::social::karate_club_graph();
+        let result = assortativity::degree_assortativity_coefficient(&graph, false).unwrap();
+        assert_eq!(round(&result, 8), -0.4756131);
+
+        let graph = get_graph(GraphSpecs::directed());
+        let result = assortativity::degree_assortativity_coefficient(&graph, false).unwrap();
+        assert_eq!(round(&result, 8), 0.47140452);
+        let result = assortativity::degree_assortativity_coefficient(&graph, true).unwrap();
+        assert_eq!(round(&result, 8), 0.35642255);
+
+        let graph = get_graph(GraphSpecs::undirected());
+        let result = assortativity::degree_assortativity_coefficient(&graph, false).unwrap();
+        assert_eq!(round(&result, 8), -0.55555556);
+        let result = assortativity::degree_assortativity_coefficient(&graph, true).unwrap();
+        assert_eq!(round(&result, 8), 0.16319678);
+    }
+
+    #[test]
+    fn test_attribute_assortativity_coefficient() {
+        let graph = get_graph(GraphSpecs::directed());
+        let result = assortativity::attribute_assortativity_coefficient(&graph, category).unwrap();
+        assert_eq!(round(&result, 8), -0.16666667);
+        let graph = get_graph(GraphSpecs::undirected());
+        let result = assortativity::attribute_assortativity_coefficient(&graph, category).unwrap();
+        assert_eq!(round(&result, 8), -0.16666667);
+    }
+
+    #[test]
+    fn test_numeric_assortativity_coefficient() {
+        let graph = get_graph(GraphSpecs::directed());
+        let result = assortativity::numeric_assortativity_coefficient(&graph, number).unwrap();
+        assert_eq!(round(&result, 8), -0.53783625);
+        let graph = get_graph(GraphSpecs::undirected());
+        let result = assortativity::numeric_assortativity_coefficient(&graph, number).unwrap();
+        assert_eq!(round(&result, 8), -0.53658537);
+    }
+
+    #[test]
+    fn test_assortativity_errors() {
+        let mut graph: Graph<&str, (&str, f64)> = Graph::new(GraphSpecs::undirected());
+        graph.add_node(Node::from_name_and_attributes("n1", ("a", 1.0)));
+        let result = assortativity::numeric_assortativity_coefficient(&graph, number);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let graph = generators::classic::path_graph(4, false);
+        let result = assortativity::degree_assortativity_coefficient(&graph, true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+    }
+}
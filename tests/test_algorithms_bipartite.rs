@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::bipartite, generators, Edge, ErrorKind, Graph, GraphSpecs, MissingNodeStrategy,
+        Node,
+    };
+
+    /// Returns a graph of people and the languages they use.
+    fn get_people_languages_graph() -> Graph<&'static str, ()> {
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+        graph
+            .add_edge_tuples(vec![
+                ("ann", "rust"),
+                ("ann", "go"),
+                ("ann", "c"),
+                ("bob", "rust"),
+                ("bob", "go"),
+                ("cat", "go"),
+                ("dan", "zig"),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_is_bipartite() {
+        use generators::classic::{balanced_tree, cycle_graph, grid_2d_graph};
+        assert!(bipartite::is_bipartite(&cycle_graph(6, false)));
+        assert!(!bipartite::is_bipartite(&cycle_graph(5, false)));
+        assert!(bipartite::is_bipartite(&grid_2d_graph(3, 4, false)));
+        assert!(bipartite::is_bipartite(&balanced_tree(2, 3, true)));
+        assert!(!bipartite::is_bipartite(&cycle_graph(3, true)));
+        let graph = generators::social::karate_club_graph();
+        assert!(!bipartite::is_bipartite(&graph));
+        assert!(bipartite::is_bipartite(&get_people_languages_graph()));
+        let graph = Graph::<i32, ()>::new(GraphSpecs::undirected());
+        assert!(bipartite::is_bipartite(&graph));
+    }
+
+    #[test]
+    fn test_is_bipartite_self_loop() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            ..GraphSpecs::multi_undirected()
+        });
+        graph.add_edges(vec![Edge::new(1, 2), Edge::new(2, 3), Edge::new(2, 3)]).unwrap();
+        assert!(bipartite::is_bipartite(&graph));
+        graph.add_edge(Edge::new(3, 3)).unwrap();
+        assert!(!bipartite::is_bipartite(&graph));
+    }
+
+    #[test]
+    fn test_color() {
+        let mut graph = generators::classic::grid_2d_graph(3, 3, false);
+        graph.add_node(Node::from_name(100));
+        let colors = bipartite::color(&graph).unwrap();
+        assert_eq!(colors.len(), 10);
+        for edge in graph.get_all_edges() {
+            assert_ne!(colors[&edge.u], colors[&edge.v]);
+        }
+        // the smallest node of each component gets the color 0
+        assert_eq!(colors[&0], 0);
+        assert_eq!(colors[&100], 0);
+        let colors = bipartite::color(&get_people_languages_graph()).unwrap();
+        assert_eq!(colors[&"ann"], 0);
+        assert_eq!(colors[&"go"], 1);
+        assert_eq!(colors[&"cat"], 0);
+        assert_eq!(colors[&"dan"], 0);
+        assert_eq!(colors[&"zig"], 1);
+    }
+
+    #[test]
+    fn test_color_not_bipartite() {
+        let result = bipartite::color(&generators::classic::cycle_graph(7, false));
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::GraphNotBipartite
+        ));
+    }
+
+    #[test]
+    fn test_sets() {
+        let graph = get_people_languages_graph();
+        let (top, bottom) = bipartite::sets(&graph, None).unwrap();
+        assert_eq!(top, vec!["ann", "bob", "cat", "dan"]);
+        assert_eq!(bottom, vec!["c", "go", "rust", "zig"]);
+        let (top, bottom) = bipartite::sets(&graph, Some(&["c", "go", "rust", "zig"])).unwrap();
+        assert_eq!(top, vec!["c", "go", "rust", "zig"]);
+        assert_eq!(bottom, vec!["ann", "bob", "cat", "dan"]);
+        // the disconnected ("dan", "zig") component can be split either way
+        let (top, bottom) = bipartite::sets(&graph, Some(&["c", "dan", "go", "rust"])).unwrap();
+        assert_eq!(top, vec!["c", "dan", "go", "rust"]);
+        assert_eq!(bottom, vec!["ann", "bob", "cat", "zig"]);
+    }
+
+    #[test]
+    fn test_sets_errors() {
+        let graph = get_people_languages_graph();
+        let result = bipartite::sets(&graph, Some(&["go", "rust", "ann"]));
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::GraphNotBipartite
+        ));
+        let result = bipartite::sets(&graph, Some(&["go", "java"]));
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::NodeNotFound));
+        let result = bipartite::sets(&generators::classic::cycle_graph(5, false), None);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::GraphNotBipartite
+        ));
+    }
+
+    #[test]
+    fn test_projected_graph() {
+        let graph = get_people_languages_graph();
+        let people = bipartite::projected_graph(&graph, &["ann", "bob", "cat", "dan"]).unwrap();
+        assert!(!people.specs.directed);
+        assert_eq!(people.get_all_nodes().len(), 4);
+        let mut edges: Vec<(&str, &str)> =
+            people.get_all_edges().into_iter().map(|e| (e.u, e.v)).collect();
+        edges.sort();
+        assert_eq!(edges, vec![("ann", "bob"), ("ann", "cat"), ("bob", "cat")]);
+        assert!(people.get_edge("ann", "bob").unwrap().weight.is_nan());
+        let languages = bipartite::projected_graph(&graph, &["c", "go", "rust"]).unwrap();
+        assert_eq!(languages.get_all_nodes().len(), 3);
+        assert_eq!(languages.get_all_edges().len(), 3);
+    }
+
+    #[test]
+    fn test_projected_graph_directed() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edge_tuples(vec![(1, 10), (2, 10), (10, 3), (3, 11), (11, 1)]).unwrap();
+        let projected = bipartite::projected_graph(&graph, &[1, 2, 3]).unwrap();
+        assert!(projected.specs.directed);
+        let mut edges: Vec<(i32, i32)> =
+            projected.get_all_edges().into_iter().map(|e| (e.u, e.v)).collect();
+        edges.sort();
+        assert_eq!(edges, vec![(1, 3), (2, 3), (3, 1)]);
+    }
+
+    #[test]
+    fn test_projected_graph_keeps_node_attributes() {
+        let mut graph = Graph::<i32, &str>::new(GraphSpecs::undirected_create_missing());
+        graph.add_node(Node::from_name_and_attributes(1, "one"));
+        graph.add_edge_tuples(vec![(1, 10), (2, 10)]).unwrap();
+        let projected = bipartite::projected_graph(&graph, &[1, 2]).unwrap();
+        assert_eq!(projected.get_node(1).unwrap().attributes, Some("one"));
+        assert_eq!(projected.get_node(2).unwrap().attributes, None);
+    }
+
+    #[test]
+    fn test_weighted_projected_graph() {
+        let graph = get_people_languages_graph();
+        let nodes = ["ann", "bob", "cat", "dan"];
+        let people = bipartite::weighted_projected_graph(&graph, &nodes, false).unwrap();
+        assert_eq!(people.get_all_edges().len(), 3);
+        assert_eq!(people.get_edge("ann", "bob").unwrap().weight, 2.0);
+        assert_eq!(people.get_edge("ann", "cat").unwrap().weight, 1.0);
+        assert_eq!(people.get_edge("cat", "bob").unwrap().weight, 1.0);
+        let people = bipartite::weighted_projected_graph(&graph, &nodes, true).unwrap();
+        assert_eq!(people.get_edge("ann", "bob").unwrap().weight, 0.5);
+        assert_eq!(people.get_edge("ann", "cat").unwrap().weight, 0.25);
+    }
+
+    #[test]
+    fn test_projected_graph_node_not_found() {
+        let graph = get_people_languages_graph();
+        let result = bipartite::projected_graph(&graph, &["ann", "eve"]);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = bipartite::weighted_projected_graph(&graph, &["eve"], true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+}
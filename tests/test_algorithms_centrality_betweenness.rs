@@ -4,7 +4,9 @@ mod utility;
 mod tests {
 
     use super::utility::round;
-    use graphrs::{algorithms::centrality::betweenness, generators, Edge, Graph, GraphSpecs};
+    use graphrs::{
+        algorithms::centrality::betweenness, generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
 
     #[test]
     fn test_betweenness_centrality_1() {
@@ -143,6 +145,105 @@ mod tests {
         assert_eq!(round(result.get(&33).unwrap(), 2), 160.55);
     }
 
+    #[test]
+    fn test_betweenness_centrality_10() {
+        // a path graph plus an isolated node; weights are required when `weighted` is true
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        graph.add_edges(vec![Edge::new(1, 2), Edge::new(2, 3), Edge::new(3, 4)]).unwrap();
+        graph.add_node(Node::from_name(5));
+        let result = betweenness::betweenness_centrality(&graph, false, false).unwrap();
+        assert_eq!(result.get(&1).unwrap(), &0.0);
+        assert_eq!(result.get(&2).unwrap(), &2.0);
+        assert_eq!(result.get(&3).unwrap(), &2.0);
+        assert_eq!(result.get(&5).unwrap(), &0.0);
+        assert!(betweenness::betweenness_centrality(&graph, true, false).is_err());
+    }
+
+    #[test]
+    fn test_betweenness_sampled_1() {
+        // sampling every node gives the exact result
+        let graph = generators::social::karate_club_graph();
+        let exact = betweenness::betweenness_centrality(&graph, false, true).unwrap();
+        let result = betweenness::betweenness_sampled(&graph, false, true, 34, None).unwrap();
+        for (node, value) in exact {
+            assert_eq!(round(result.get(&node).unwrap(), 6), round(&value, 6));
+        }
+    }
+
+    #[test]
+    fn test_betweenness_sampled_2() {
+        // seeded results are reproducible
+        let graph = generators::social::karate_club_graph();
+        let result1 = betweenness::betweenness_sampled(&graph, false, true, 10, Some(1)).unwrap();
+        let result2 = betweenness::betweenness_sampled(&graph, false, true, 10, Some(1)).unwrap();
+        assert_eq!(result1.len(), 34);
+        assert_eq!(result1, result2);
+        // node 11 is a leaf so it is never between other nodes
+        assert!(*result1.get(&0).unwrap() > 0.0);
+        assert_eq!(result1.get(&11).unwrap(), &0.0);
+    }
+
+    #[test]
+    fn test_betweenness_sampled_3() {
+        // a sample from a path graph, not normalized
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        graph.add_edges(vec![Edge::new(1, 2), Edge::new(2, 3)]).unwrap();
+        let result = betweenness::betweenness_sampled(&graph, false, false, 2, Some(3)).unwrap();
+        assert_eq!(result.get(&1).unwrap(), &0.0);
+        // the exact value is 1.0; the estimate depends on whether node 2 was sampled
+        let value = *result.get(&2).unwrap();
+        assert!(value == 0.75 || value == 1.5);
+        assert_eq!(result.get(&3).unwrap(), &0.0);
+        let result = betweenness::betweenness_sampled(&graph, false, false, 0, None);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_1() {
+        // directed, weighted, not normalized
+        let graph = get_graph_1(true);
+        let result = betweenness::edge_betweenness_centrality(&graph, true, false).unwrap();
+        assert_eq!(result.len(), 6);
+        assert_eq!(result.get(&("n1", "n2")).unwrap(), &1.0);
+        assert_eq!(result.get(&("n1", "n4")).unwrap(), &3.0);
+        assert_eq!(result.get(&("n1", "n5")).unwrap(), &0.0);
+        assert_eq!(result.get(&("n2", "n3")).unwrap(), &2.0);
+        assert_eq!(result.get(&("n3", "n5")).unwrap(), &4.0);
+        assert_eq!(result.get(&("n4", "n3")).unwrap(), &4.0);
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_2() {
+        // undirected, weighted, not normalized
+        let graph = get_graph_1(false);
+        let result = betweenness::edge_betweenness_centrality(&graph, true, false).unwrap();
+        assert_eq!(result.get(&("n1", "n2")).unwrap(), &2.0);
+        assert_eq!(result.get(&("n1", "n4")).unwrap(), &4.0);
+        assert_eq!(result.get(&("n1", "n5")).unwrap(), &0.0);
+        assert_eq!(result.get(&("n2", "n3")).unwrap(), &2.0);
+        assert_eq!(result.get(&("n3", "n4")).unwrap(), &4.0);
+        assert_eq!(result.get(&("n3", "n5")).unwrap(), &4.0);
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_3() {
+        // undirected, unweighted, normalized
+        let graph = get_graph_1(false);
+        let result = betweenness::edge_betweenness_centrality(&graph, false, true).unwrap();
+        for value in result.values() {
+            assert_eq!(round(value, 4), 0.2333);
+        }
+        let graph = generators::social::karate_club_graph();
+        let result = betweenness::edge_betweenness_centrality(&graph, false, true).unwrap();
+        assert_eq!(round(result.get(&(0, 31)).unwrap(), 4), 0.1273);
+        assert_eq!(round(result.get(&(0, 1)).unwrap(), 4), 0.0253);
+        assert_eq!(round(result.get(&(32, 33)).unwrap(), 4), 0.0082);
+        assert_eq!(round(result.get(&(0, 2)).unwrap(), 4), 0.0778);
+    }
+
     fn get_graph_1<'a>(directed: bool) -> Graph<&'a str, ()> {
         let edges = vec![
             Edge::with_weight("n1", "n2", 1.0),
@@ -0,0 +1,119 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::round;
+    use graphrs::{algorithms::centrality::hits, generators, Edge, ErrorKind, Graph, GraphSpecs};
+
+    #[test]
+    fn test_hits_1() {
+        // directed, weighted, normalized
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        // "h1" links to every authority and "a1" is linked to by every hub
+        graph
+            .add_edges(vec![
+                Edge::with_weight("h1", "a1", 2.0),
+                Edge::with_weight("h1", "a2", 1.0),
+                Edge::with_weight("h1", "a3", 1.0),
+                Edge::with_weight("h2", "a1", 1.0),
+                Edge::with_weight("h2", "a2", 1.0),
+                Edge::with_weight("h3", "a1", 1.0),
+            ])
+            .unwrap();
+        let (hubs, authorities) = hits::hits(&graph, true, None, None, true).unwrap();
+        assert_eq!(round(hubs.get("h1").unwrap(), 4), 0.5321);
+        assert_eq!(round(hubs.get("h2").unwrap(), 4), 0.2831);
+        assert_eq!(round(hubs.get("h3").unwrap(), 4), 0.1848);
+        assert_eq!(round(authorities.get("a1").unwrap(), 4), 0.5321);
+        assert_eq!(round(authorities.get("a2").unwrap(), 4), 0.2831);
+        assert_eq!(round(authorities.get("a3").unwrap(), 4), 0.1848);
+        for name in ["a1", "a2", "a3"] {
+            assert_eq!(round(hubs.get(name).unwrap(), 4), 0.0);
+        }
+        for name in ["h1", "h2", "h3"] {
+            assert_eq!(round(authorities.get(name).unwrap(), 4), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_hits_2() {
+        // directed, weighted, not normalized
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("h1", "a1", 2.0),
+                Edge::with_weight("h1", "a2", 1.0),
+                Edge::with_weight("h1", "a3", 1.0),
+                Edge::with_weight("h2", "a1", 1.0),
+                Edge::with_weight("h2", "a2", 1.0),
+                Edge::with_weight("h3", "a1", 1.0),
+            ])
+            .unwrap();
+        let (hubs, authorities) = hits::hits(&graph, true, None, None, false).unwrap();
+        assert_eq!(round(hubs.get("h1").unwrap(), 4), 1.0);
+        assert_eq!(round(hubs.get("h2").unwrap(), 4), 0.5321);
+        assert_eq!(round(hubs.get("h3").unwrap(), 4), 0.3473);
+        assert_eq!(round(authorities.get("a1").unwrap(), 4), 1.0);
+        assert_eq!(round(authorities.get("a2").unwrap(), 4), 0.5321);
+        assert_eq!(round(authorities.get("a3").unwrap(), 4), 0.3473);
+    }
+
+    #[test]
+    fn test_hits_3() {
+        // directed, unweighted: the heavier "h1" -> "a1" edge no longer counts
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("h1", "a1", 2.0),
+                Edge::with_weight("h1", "a2", 1.0),
+                Edge::with_weight("h1", "a3", 1.0),
+                Edge::with_weight("h2", "a1", 1.0),
+                Edge::with_weight("h2", "a2", 1.0),
+                Edge::with_weight("h3", "a1", 1.0),
+            ])
+            .unwrap();
+        let (hubs, authorities) = hits::hits(&graph, false, None, None, true).unwrap();
+        assert_eq!(round(hubs.get("h1").unwrap(), 4), 0.4450);
+        assert_eq!(round(hubs.get("h2").unwrap(), 4), 0.3569);
+        assert_eq!(round(hubs.get("h3").unwrap(), 4), 0.1981);
+        assert_eq!(round(authorities.get("a1").unwrap(), 4), 0.4450);
+        assert_eq!(round(authorities.get("a2").unwrap(), 4), 0.3569);
+        assert_eq!(round(authorities.get("a3").unwrap(), 4), 0.1981);
+    }
+
+    #[test]
+    fn test_hits_4() {
+        // undirected: hubs and authorities are the same
+        let graph = generators::social::karate_club_graph();
+        let (hubs, authorities) = hits::hits(&graph, false, None, None, true).unwrap();
+        assert_eq!(round(hubs.get(&0).unwrap(), 3), 0.071);
+        assert_eq!(round(hubs.get(&1).unwrap(), 3), 0.053);
+        assert_eq!(round(hubs.get(&2).unwrap(), 3), 0.064);
+        assert_eq!(round(hubs.get(&33).unwrap(), 3), 0.075);
+        for (node, hub) in hubs {
+            assert_eq!(round(&hub, 6), round(authorities.get(&node).unwrap(), 6));
+        }
+    }
+
+    #[test]
+    fn test_hits_5() {
+        // fails to converge
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("h1", "a1", 2.0),
+                Edge::with_weight("h1", "a2", 1.0),
+                Edge::with_weight("h1", "a3", 1.0),
+                Edge::with_weight("h2", "a1", 1.0),
+                Edge::with_weight("h2", "a2", 1.0),
+                Edge::with_weight("h3", "a1", 1.0),
+            ])
+            .unwrap();
+        let result = hits::hits(&graph, true, Some(2), None, true);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::PowerIterationFailedConvergence
+        ));
+    }
+}
@@ -0,0 +1,106 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::round;
+    use graphrs::{algorithms::centrality::katz, generators, Edge, ErrorKind, Graph, GraphSpecs};
+
+    #[test]
+    fn test_katz_centrality_1() {
+        // karate club, unweighted
+        let graph = generators::social::karate_club_graph();
+        let result = katz::katz_centrality(&graph, false, None, None, None, None, true).unwrap();
+        assert_eq!(round(result.get(&0).unwrap(), 2), 0.32);
+        assert_eq!(round(result.get(&1).unwrap(), 2), 0.24);
+        assert_eq!(round(result.get(&2).unwrap(), 2), 0.27);
+        assert_eq!(round(result.get(&3).unwrap(), 2), 0.19);
+        assert_eq!(round(result.get(&4).unwrap(), 2), 0.12);
+        assert_eq!(round(result.get(&11).unwrap(), 2), 0.10);
+        assert_eq!(round(result.get(&16).unwrap(), 2), 0.09);
+        assert_eq!(round(result.get(&32).unwrap(), 2), 0.28);
+        assert_eq!(round(result.get(&33).unwrap(), 2), 0.33);
+    }
+
+    #[test]
+    fn test_katz_centrality_2() {
+        // "x" has two in-neighbors without any of their own, "y" has one in-neighbor, "z",
+        // with four: x = beta * (1 + 2 * alpha) and y = beta * (1 + alpha + 4 * alpha^2),
+        // so "x" ranks above "y" only when alpha is less than 0.25
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::new("s1", "x"),
+                Edge::new("s2", "x"),
+                Edge::new("t1", "z"),
+                Edge::new("t2", "z"),
+                Edge::new("t3", "z"),
+                Edge::new("t4", "z"),
+                Edge::new("z", "y"),
+            ])
+            .unwrap();
+        let result =
+            katz::katz_centrality(&graph, false, Some(0.1), None, None, None, false).unwrap();
+        assert_eq!(round(result.get("s1").unwrap(), 4), 1.0);
+        assert_eq!(round(result.get("x").unwrap(), 4), 1.2);
+        assert_eq!(round(result.get("z").unwrap(), 4), 1.4);
+        assert_eq!(round(result.get("y").unwrap(), 4), 1.14);
+        let result =
+            katz::katz_centrality(&graph, false, Some(0.5), None, None, None, false).unwrap();
+        assert_eq!(round(result.get("x").unwrap(), 4), 2.0);
+        assert_eq!(round(result.get("z").unwrap(), 4), 3.0);
+        assert_eq!(round(result.get("y").unwrap(), 4), 2.5);
+        // beta scales the values without changing the normalized result
+        let result =
+            katz::katz_centrality(&graph, false, Some(0.5), Some(2.0), None, None, false).unwrap();
+        assert_eq!(round(result.get("s1").unwrap(), 4), 2.0);
+        assert_eq!(round(result.get("x").unwrap(), 4), 4.0);
+        assert_eq!(round(result.get("y").unwrap(), 4), 5.0);
+        let normalized =
+            katz::katz_centrality(&graph, false, Some(0.5), None, None, None, true).unwrap();
+        let scaled =
+            katz::katz_centrality(&graph, false, Some(0.5), Some(2.0), None, None, true).unwrap();
+        for (name, value) in normalized.iter() {
+            assert_eq!(round(value, 6), round(scaled.get(name).unwrap(), 6));
+        }
+    }
+
+    #[test]
+    fn test_katz_centrality_3() {
+        // directed, weighted; a light "z" -> "y" edge puts "y" back below "x"
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("s1", "x", 1.0),
+                Edge::with_weight("s2", "x", 1.0),
+                Edge::with_weight("t1", "z", 1.0),
+                Edge::with_weight("t2", "z", 1.0),
+                Edge::with_weight("t3", "z", 1.0),
+                Edge::with_weight("t4", "z", 1.0),
+                Edge::with_weight("z", "y", 0.1),
+            ])
+            .unwrap();
+        let result =
+            katz::katz_centrality(&graph, true, Some(0.5), None, None, None, false).unwrap();
+        assert_eq!(round(result.get("x").unwrap(), 4), 2.0);
+        assert_eq!(round(result.get("z").unwrap(), 4), 3.0);
+        assert_eq!(round(result.get("y").unwrap(), 4), 1.15);
+        let result =
+            katz::katz_centrality(&graph, false, Some(0.5), None, None, None, false).unwrap();
+        assert_eq!(round(result.get("y").unwrap(), 4), 2.5);
+    }
+
+    #[test]
+    fn test_katz_centrality_4() {
+        // fails to converge; the values only settle after four iterations
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::new("a", "b"), Edge::new("b", "c")]).unwrap();
+        let result = katz::katz_centrality(&graph, false, Some(0.5), None, Some(2), None, true);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::PowerIterationFailedConvergence
+        ));
+        let result = katz::katz_centrality(&graph, false, Some(0.5), None, Some(4), None, false);
+        assert_eq!(round(result.unwrap().get("c").unwrap(), 4), 1.75);
+    }
+}
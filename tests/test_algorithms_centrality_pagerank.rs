@@ -0,0 +1,130 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::round;
+    use graphrs::{
+        algorithms::centrality::pagerank, generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_pagerank_1() {
+        // karate club, unweighted
+        let graph = generators::social::karate_club_graph();
+        let result = pagerank::pagerank(&graph, false, 0.85, None, None, None).unwrap();
+        assert_eq!(result.len(), 34);
+        assert_eq!(round(result.get(&0).unwrap(), 3), 0.097);
+        assert_eq!(round(result.get(&1).unwrap(), 3), 0.053);
+        assert_eq!(round(result.get(&2).unwrap(), 3), 0.057);
+        assert_eq!(round(result.get(&3).unwrap(), 3), 0.036);
+        assert_eq!(round(result.get(&11).unwrap(), 3), 0.010);
+        assert_eq!(round(result.get(&23).unwrap(), 3), 0.032);
+        assert_eq!(round(result.get(&32).unwrap(), 3), 0.072);
+        assert_eq!(round(result.get(&33).unwrap(), 3), 0.101);
+    }
+
+    #[test]
+    fn test_pagerank_weighted() {
+        // "a" sends three times as much of its rank to "b" as to "c";
+        // p(a) = ((1 - d) / 3 + d) / (1 + d) and the rest of d * p(a) is split 3:1
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("a", "b", 3.0),
+                Edge::with_weight("a", "c", 1.0),
+                Edge::with_weight("b", "a", 1.0),
+                Edge::with_weight("c", "a", 1.0),
+            ])
+            .unwrap();
+        let result = pagerank::pagerank(&graph, true, 0.85, None, None, None).unwrap();
+        assert_eq!(round(result.get("a").unwrap(), 4), 0.4865);
+        assert_eq!(round(result.get("b").unwrap(), 4), 0.3601);
+        assert_eq!(round(result.get("c").unwrap(), 4), 0.1534);
+        let result = pagerank::pagerank(&graph, false, 0.85, None, None, None).unwrap();
+        assert_eq!(round(result.get("a").unwrap(), 4), 0.4865);
+        assert_eq!(round(result.get("b").unwrap(), 4), 0.2568);
+        assert_eq!(round(result.get("c").unwrap(), 4), 0.2568);
+    }
+
+    #[test]
+    fn test_pagerank_damping() {
+        // "b" is a dangling node, so its rank is spread over both nodes and
+        // p(a) = 1 / (2 + d) for a damping factor of d
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::new("a", "b")]).unwrap();
+        for (damping, expected) in [(0.85, 1.0 / 2.85), (0.5, 0.4), (0.1, 1.0 / 2.1)] {
+            let result = pagerank::pagerank(&graph, false, damping, None, None, None).unwrap();
+            assert_eq!(round(result.get("a").unwrap(), 5), round(&expected, 5));
+            assert_eq!(
+                round(result.get("b").unwrap(), 5),
+                round(&(1.0 - expected), 5)
+            );
+        }
+    }
+
+    #[test]
+    fn test_pagerank_personalization() {
+        // every node of a directed cycle has the same rank, until jumps only go to "a":
+        // then p(a) = (1 - d) / (1 - d^3), p(b) = d * p(a) and p(c) = d * p(b)
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::new("a", "b"),
+                Edge::new("b", "c"),
+                Edge::new("c", "a"),
+            ])
+            .unwrap();
+        let result = pagerank::pagerank(&graph, false, 0.85, None, None, None).unwrap();
+        for name in ["a", "b", "c"] {
+            assert_eq!(round(result.get(name).unwrap(), 4), 0.3333);
+        }
+        let personalization: HashMap<&str, f64> = vec![("a", 1.0)].into_iter().collect();
+        let result =
+            pagerank::pagerank(&graph, false, 0.85, Some(personalization), None, None).unwrap();
+        assert_eq!(round(result.get("a").unwrap(), 4), 0.3887);
+        assert_eq!(round(result.get("b").unwrap(), 4), 0.3304);
+        assert_eq!(round(result.get("c").unwrap(), 4), 0.2809);
+        // only the relative values of the personalization matter
+        let personalization: HashMap<&str, f64> = vec![("a", 5.0)].into_iter().collect();
+        let scaled =
+            pagerank::pagerank(&graph, false, 0.85, Some(personalization), None, None).unwrap();
+        assert_eq!(
+            round(scaled.get("a").unwrap(), 6),
+            round(result.get("a").unwrap(), 6)
+        );
+    }
+
+    #[test]
+    fn test_pagerank_errors() {
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n2", "n3", 5.0),
+                Edge::with_weight("n3", "n1", 2.0),
+            ])
+            .unwrap();
+        let result = pagerank::pagerank(&graph, true, 1.5, None, None, None);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let personalization: HashMap<&str, f64> = vec![("n7", 1.0)].into_iter().collect();
+        let result = pagerank::pagerank(&graph, true, 0.85, Some(personalization), None, None);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::NodeNotFound));
+        let personalization: HashMap<&str, f64> = vec![("n1", 0.0)].into_iter().collect();
+        let result = pagerank::pagerank(&graph, true, 0.85, Some(personalization), None, None);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let personalization: HashMap<&str, f64> = vec![("n1", 1.0)].into_iter().collect();
+        let result = pagerank::pagerank(&graph, true, 0.85, Some(personalization), Some(2), None);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::PowerIterationFailedConvergence
+        ));
+    }
+}
@@ -0,0 +1,134 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::round;
+    use graphrs::{
+        algorithms::centrality::personalized_pagerank::{
+            personalized_pagerank, PersonalizedPageRankMethod,
+        },
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+
+    const POWER_ITERATION: PersonalizedPageRankMethod =
+        PersonalizedPageRankMethod::PowerIteration {
+            max_iter: None,
+            tolerance: None,
+        };
+
+    fn get_graph() -> Graph<&'static str, ()> {
+        // "n5" is a dangling node
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n3", 5.0),
+            Edge::with_weight("n1", "n4", 2.0),
+            Edge::with_weight("n4", "n3", 3.0),
+            Edge::with_weight("n1", "n5", 9.0),
+            Edge::with_weight("n3", "n5", 1.0),
+            Edge::with_weight("n6", "n1", 1.0),
+        ];
+        Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_personalized_pagerank_1() {
+        let graph = generators::social::karate_club_graph();
+        let result = personalized_pagerank(&graph, false, 0.15, &[33], POWER_ITERATION).unwrap();
+        assert_eq!(result.len(), 34);
+        assert_eq!(round(result.get(&0).unwrap(), 4), 0.0482);
+        assert_eq!(round(result.get(&8).unwrap(), 4), 0.0323);
+        assert_eq!(round(result.get(&11).unwrap(), 4), 0.0026);
+        assert_eq!(round(result.get(&32).unwrap(), 4), 0.0902);
+        assert_eq!(round(result.get(&33).unwrap(), 4), 0.2676);
+    }
+
+    #[test]
+    fn test_personalized_pagerank_2() {
+        // directed, with a dangling node and a node that can't be reached from the seeds
+        let graph = get_graph();
+        let result =
+            personalized_pagerank(&graph, false, 0.2, &["n1", "n2"], POWER_ITERATION).unwrap();
+        assert_eq!(round(result.get("n1").unwrap(), 4), 0.1997);
+        assert_eq!(round(result.get("n2").unwrap(), 4), 0.2529);
+        assert_eq!(round(result.get("n3").unwrap(), 4), 0.2449);
+        assert_eq!(round(result.get("n4").unwrap(), 4), 0.0532);
+        assert_eq!(round(result.get("n5").unwrap(), 4), 0.2492);
+        assert_eq!(round(result.get("n6").unwrap(), 4), 0.0);
+        let method = PersonalizedPageRankMethod::MonteCarlo {
+            num_walks: 200_000,
+            seed: Some(1),
+        };
+        let estimate = personalized_pagerank(&graph, false, 0.2, &["n1", "n2"], method).unwrap();
+        for (node, probability) in result.iter() {
+            assert!((estimate[node] - probability).abs() < 0.005);
+        }
+        assert_eq!(estimate["n6"], 0.0);
+    }
+
+    #[test]
+    fn test_personalized_pagerank_3() {
+        // weighted, Monte Carlo is reproducible and close to the power method
+        let graph = get_graph();
+        let exact = personalized_pagerank(&graph, true, 0.3, &["n6"], POWER_ITERATION).unwrap();
+        let method = PersonalizedPageRankMethod::MonteCarlo {
+            num_walks: 100_000,
+            seed: Some(2),
+        };
+        let estimate = personalized_pagerank(&graph, true, 0.3, &["n6"], method).unwrap();
+        let total: f64 = estimate.values().sum();
+        assert_eq!(round(&total, 6), 1.0);
+        for (node, probability) in exact.iter() {
+            assert!((estimate[node] - probability).abs() < 0.005);
+        }
+        let again = personalized_pagerank(&graph, true, 0.3, &["n6"], method).unwrap();
+        assert_eq!(estimate, again);
+    }
+
+    #[test]
+    fn test_personalized_pagerank_4() {
+        // a restart probability of 1 never leaves the seeds
+        let graph = generators::social::karate_club_graph();
+        let result =
+            personalized_pagerank(&graph, false, 1.0, &[0, 0, 5], POWER_ITERATION).unwrap();
+        assert_eq!(round(result.get(&0).unwrap(), 6), 0.666667);
+        assert_eq!(round(result.get(&5).unwrap(), 6), 0.333333);
+        assert_eq!(round(result.get(&1).unwrap(), 6), 0.0);
+    }
+
+    #[test]
+    fn test_personalized_pagerank_errors() {
+        let graph = get_graph();
+        let result = personalized_pagerank(&graph, false, 0.0, &["n1"], POWER_ITERATION);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = personalized_pagerank(&graph, false, 0.15, &[], POWER_ITERATION);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = personalized_pagerank(&graph, false, 0.15, &["n9"], POWER_ITERATION);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let method = PersonalizedPageRankMethod::MonteCarlo {
+            num_walks: 0,
+            seed: None,
+        };
+        let result = personalized_pagerank(&graph, false, 0.15, &["n1"], method);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let graph = generators::classic::path_graph(3, false);
+        let result = personalized_pagerank(&graph, true, 0.15, &[0], POWER_ITERATION);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::clique, generators, Edge, ErrorKind, Graph, GraphSpecs, Node};
+    use std::collections::HashSet;
+
+    /// Finds the maximal cliques by checking every subset of the nodes.
+    fn get_cliques_brute_force(graph: &Graph<usize, ()>) -> HashSet<Vec<usize>> {
+        let mut names: Vec<usize> = graph.get_all_node_names().into_iter().copied().collect();
+        names.sort();
+        let is_clique = |nodes: &[usize]| {
+            nodes.iter().all(|u| nodes.iter().all(|v| u == v || graph.get_edge(*u, *v).is_ok()))
+        };
+        let cliques: Vec<Vec<usize>> = (1..(1 << names.len()))
+            .map(|mask: usize| {
+                names
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| mask & (1 << i) != 0)
+                    .map(|(_, n)| *n)
+                    .collect()
+            })
+            .filter(|nodes: &Vec<usize>| is_clique(nodes))
+            .collect();
+        cliques
+            .iter()
+            .filter(|c| {
+                names.iter().all(|n| {
+                    c.contains(n)
+                        || !is_clique(&c.iter().chain([*n].iter()).copied().collect::<Vec<usize>>())
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn test_find_cliques_1() {
+        let graph = generators::social::karate_club_graph();
+        let cliques: Vec<Vec<i32>> = clique::find_cliques(&graph).unwrap().collect();
+        assert_eq!(cliques.len(), 36);
+        let mut largest: Vec<Vec<i32>> = cliques
+            .into_iter()
+            .filter(|c| c.len() == 5)
+            .map(|mut c| {
+                c.sort();
+                c
+            })
+            .collect();
+        largest.sort();
+        assert_eq!(largest, vec![vec![0, 1, 2, 3, 7], vec![0, 1, 2, 3, 13]]);
+    }
+
+    #[test]
+    fn test_find_cliques_2() {
+        for seed in 0..10 {
+            let graph = generators::random::gnp_random_graph(12, 0.4, Some(seed), false).unwrap();
+            let expected = get_cliques_brute_force(&graph);
+            let cliques: Vec<Vec<usize>> = clique::find_cliques(&graph)
+                .unwrap()
+                .map(|mut c| {
+                    c.sort();
+                    c
+                })
+                .collect();
+            assert_eq!(cliques.len(), expected.len());
+            assert_eq!(
+                cliques.into_iter().collect::<HashSet<Vec<usize>>>(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_cliques_3() {
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected_create_missing()
+        };
+        let mut graph: Graph<&str, ()> = Graph::new(specs);
+        assert_eq!(clique::find_cliques(&graph).unwrap().count(), 0);
+        assert_eq!(clique::max_clique(&graph).unwrap(), Vec::<&str>::new());
+        assert_eq!(clique::clique_number(&graph).unwrap(), 0);
+        graph.add_node(Node::from_name("n0"));
+        graph
+            .add_edges(vec![
+                Edge::new("n1", "n2"),
+                Edge::new("n2", "n3"),
+                Edge::new("n3", "n1"),
+                Edge::new("n3", "n4"),
+                Edge::new("n4", "n4"),
+            ])
+            .unwrap();
+        let mut cliques: Vec<Vec<&str>> = clique::find_cliques(&graph)
+            .unwrap()
+            .map(|mut c| {
+                c.sort();
+                c
+            })
+            .collect();
+        cliques.sort();
+        assert_eq!(
+            cliques,
+            vec![vec!["n0"], vec!["n1", "n2", "n3"], vec!["n3", "n4"]]
+        );
+        let mut largest = clique::max_clique(&graph).unwrap();
+        largest.sort();
+        assert_eq!(largest, vec!["n1", "n2", "n3"]);
+        assert_eq!(clique::clique_number(&graph).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_find_cliques_directed() {
+        let graph = generators::classic::path_graph(3, true);
+        let result = clique::find_cliques(&graph);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+}
@@ -290,4 +290,21 @@ mod tests {
         let result = cluster::triangles(&graph, None);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_triangles_match_generalized_degree() {
+        for seed in 0..5 {
+            let graph = generators::random::gnp_random_graph(80, 0.15, Some(seed), false).unwrap();
+            let result = cluster::triangles(&graph, None).unwrap();
+            let expected: HashMap<usize, usize> = cluster::generalized_degree(&graph, None)
+                .unwrap()
+                .into_iter()
+                .map(|(n, gd)| (n, gd.iter().map(|(k, v)| k * v).sum::<usize>() / 2))
+                .collect();
+            assert_eq!(result, expected);
+            let subset = cluster::triangles(&graph, Some(&[3, 7])).unwrap();
+            assert_eq!(subset.len(), 2);
+            assert_eq!(subset.get(&7), expected.get(&7));
+        }
+    }
 }
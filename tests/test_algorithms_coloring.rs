@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::coloring::{self, ColoringStrategy},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+    use std::collections::HashMap;
+
+    const STRATEGIES: [ColoringStrategy; 4] = [
+        ColoringStrategy::LargestFirst,
+        ColoringStrategy::SmallestLast,
+        ColoringStrategy::RandomSequential(Some(1)),
+        ColoringStrategy::Dsatur,
+    ];
+
+    fn assert_valid_coloring<T>(graph: &Graph<T, ()>, colors: &HashMap<T, usize>)
+    where
+        T: std::hash::Hash + Eq + Clone + Ord + std::fmt::Display + Send + Sync + std::fmt::Debug,
+    {
+        assert_eq!(colors.len(), graph.get_all_nodes().len());
+        for edge in graph.get_all_edges() {
+            if edge.u != edge.v {
+                assert_ne!(colors.get(&edge.u), colors.get(&edge.v));
+            }
+        }
+    }
+
+    fn number_of_colors<T>(colors: &HashMap<T, usize>) -> usize {
+        colors.values().max().map_or(0, |c| c + 1)
+    }
+
+    #[test]
+    fn test_greedy_color_valid() {
+        for seed in 0..5 {
+            let graph = generators::random::gnp_random_graph(50, 0.2, Some(seed), false).unwrap();
+            for strategy in STRATEGIES.iter() {
+                let colors = coloring::greedy_color(&graph, *strategy).unwrap();
+                assert_valid_coloring(&graph, &colors);
+            }
+        }
+        let graph = generators::social::karate_club_graph();
+        for strategy in STRATEGIES.iter() {
+            let colors = coloring::greedy_color(&graph, *strategy).unwrap();
+            assert_valid_coloring(&graph, &colors);
+            // the graph contains a 5-clique
+            assert!(number_of_colors(&colors) >= 5);
+        }
+    }
+
+    #[test]
+    fn test_greedy_color_number_of_colors() {
+        let graph = generators::classic::complete_graph(6, false);
+        for strategy in STRATEGIES.iter() {
+            let colors = coloring::greedy_color(&graph, *strategy).unwrap();
+            assert_eq!(number_of_colors(&colors), 6);
+        }
+        // DSATUR and smallest-last are optimal for bipartite graphs and trees respectively
+        let graph = generators::classic::grid_2d_graph(5, 6, false);
+        let colors = coloring::greedy_color(&graph, ColoringStrategy::Dsatur).unwrap();
+        assert_eq!(number_of_colors(&colors), 2);
+        let graph = generators::classic::balanced_tree(3, 3, false);
+        let colors = coloring::greedy_color(&graph, ColoringStrategy::SmallestLast).unwrap();
+        assert_eq!(number_of_colors(&colors), 2);
+        let graph = generators::classic::cycle_graph(7, false);
+        let colors = coloring::greedy_color(&graph, ColoringStrategy::Dsatur).unwrap();
+        assert_eq!(number_of_colors(&colors), 3);
+    }
+
+    #[test]
+    fn test_greedy_color_random_sequential() {
+        let graph = generators::random::gnp_random_graph(40, 0.3, Some(3), false).unwrap();
+        let strategy = ColoringStrategy::RandomSequential(Some(7));
+        let colors1 = coloring::greedy_color(&graph, strategy).unwrap();
+        let colors2 = coloring::greedy_color(&graph, strategy).unwrap();
+        assert_eq!(colors1, colors2);
+        let colors = coloring::greedy_color(&graph, ColoringStrategy::RandomSequential(None));
+        assert_valid_coloring(&graph, &colors.unwrap());
+    }
+
+    #[test]
+    fn test_greedy_color_edge_cases() {
+        let graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected());
+        let colors = coloring::greedy_color(&graph, ColoringStrategy::Dsatur).unwrap();
+        assert!(colors.is_empty());
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected_create_missing()
+        };
+        let mut graph: Graph<&str, ()> = Graph::new(specs);
+        graph.add_edges(vec![Edge::new("n1", "n1"), Edge::new("n1", "n2")]).unwrap();
+        for strategy in STRATEGIES.iter() {
+            let colors = coloring::greedy_color(&graph, *strategy).unwrap();
+            assert_eq!(number_of_colors(&colors), 2);
+        }
+        let graph = generators::classic::path_graph(3, true);
+        let result = coloring::greedy_color(&graph, ColoringStrategy::LargestFirst);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+}
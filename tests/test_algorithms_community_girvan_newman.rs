@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::community::girvan_newman, generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+    use std::collections::HashSet;
+
+    fn sorted(partition: Vec<HashSet<i32>>) -> Vec<Vec<i32>> {
+        let mut communities: Vec<Vec<i32>> = partition
+            .into_iter()
+            .map(|c| {
+                let mut v: Vec<i32> = c.into_iter().collect();
+                v.sort();
+                v
+            })
+            .collect();
+        communities.sort();
+        communities
+    }
+
+    #[test]
+    fn test_girvan_newman_partitions_1() {
+        // karate club
+        let graph = generators::social::karate_club_graph();
+        let mut partitions = girvan_newman::girvan_newman_partitions(&graph, false).unwrap();
+        assert_eq!(
+            sorted(partitions.next().unwrap()),
+            vec![
+                vec![0, 1, 3, 4, 5, 6, 7, 10, 11, 12, 13, 16, 17, 19, 21],
+                vec![2, 8, 9, 14, 15, 18, 20, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33],
+            ]
+        );
+        assert_eq!(
+            sorted(partitions.next().unwrap()),
+            vec![
+                vec![0, 1, 3, 4, 5, 6, 7, 10, 11, 12, 13, 16, 17, 19, 21],
+                vec![2, 8, 14, 15, 18, 20, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33],
+                vec![9],
+            ]
+        );
+        // the final partition has every node in its own community
+        assert_eq!(partitions.last().unwrap().len(), 34);
+    }
+
+    #[test]
+    fn test_girvan_newman_partitions_2() {
+        // a path, directed
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::new(1, 2), Edge::new(2, 3), Edge::new(3, 4)]).unwrap();
+        let partitions: Vec<Vec<Vec<i32>>> =
+            girvan_newman::girvan_newman_partitions(&graph, false).unwrap().map(sorted).collect();
+        assert_eq!(
+            partitions,
+            vec![
+                vec![vec![1, 2], vec![3, 4]],
+                vec![vec![1], vec![2], vec![3, 4]],
+                vec![vec![1], vec![2], vec![3], vec![4]],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_girvan_newman_communities_1() {
+        // karate club: stops at the partition with the highest modularity
+        let graph = generators::social::karate_club_graph();
+        let communities = girvan_newman::girvan_newman_communities(&graph, false).unwrap();
+        assert_eq!(
+            sorted(communities),
+            vec![
+                vec![0, 1, 3, 7, 11, 12, 13, 17, 19, 21],
+                vec![2, 24, 25, 27, 28, 31],
+                vec![4, 5, 6, 10, 16],
+                vec![8, 14, 15, 18, 20, 22, 23, 26, 29, 30, 32, 33],
+                vec![9],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_girvan_newman_communities_2() {
+        // weights are required when `weighted` is true
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        graph.add_edges(vec![Edge::new(1, 2)]).unwrap();
+        let result = girvan_newman::girvan_newman_communities(&graph, true);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::community::{label_propagation, partitions},
+        generators, Edge, Graph, GraphSpecs, Node,
+    };
+    use std::collections::HashSet;
+
+    fn get_two_cliques_graph(directed: bool) -> Graph<i32, ()> {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs {
+            directed,
+            ..GraphSpecs::directed_create_missing()
+        });
+        for offset in [0, 10] {
+            for u in 0..5 {
+                for v in 0..5 {
+                    if u != v && (directed || u < v) {
+                        graph.add_edge(Edge::with_weight(u + offset, v + offset, 1.0)).unwrap();
+                    }
+                }
+            }
+        }
+        graph.add_edge(Edge::with_weight(4, 10, 1.0)).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_label_propagation_communities_1() {
+        // two cliques joined by a single edge
+        let graph = get_two_cliques_graph(false);
+        let communities =
+            label_propagation::label_propagation_communities(&graph, false, Some(1), None).unwrap();
+        let expected: Vec<HashSet<i32>> = vec![
+            (0..5).collect::<HashSet<i32>>(),
+            (10..15).collect::<HashSet<i32>>(),
+        ];
+        assert_eq!(communities, expected);
+    }
+
+    #[test]
+    fn test_label_propagation_communities_2() {
+        // directed
+        let graph = get_two_cliques_graph(true);
+        let communities =
+            label_propagation::label_propagation_communities(&graph, false, Some(1), None).unwrap();
+        assert_eq!(communities.len(), 2);
+    }
+
+    #[test]
+    fn test_label_propagation_communities_3() {
+        // the same seed gives the same communities
+        let graph = generators::social::karate_club_graph();
+        let communities1 =
+            label_propagation::label_propagation_communities(&graph, false, Some(3), None).unwrap();
+        let communities2 =
+            label_propagation::label_propagation_communities(&graph, false, Some(3), None).unwrap();
+        assert!(partitions::is_partition(&graph, &communities1));
+        assert_eq!(communities1, communities2);
+    }
+
+    #[test]
+    fn test_label_propagation_communities_4() {
+        // weighted; node 2 is pulled into the community it has the heavier edge to
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight(0, 1, 5.0),
+                Edge::with_weight(1, 2, 1.0),
+                Edge::with_weight(2, 3, 1.0),
+                Edge::with_weight(3, 4, 5.0),
+                Edge::with_weight(2, 4, 3.0),
+            ])
+            .unwrap();
+        let communities =
+            label_propagation::label_propagation_communities(&graph, true, Some(1), None).unwrap();
+        assert_eq!(
+            communities,
+            vec![
+                vec![0, 1].into_iter().collect::<HashSet<i32>>(),
+                vec![2, 3, 4].into_iter().collect::<HashSet<i32>>(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_label_propagation_communities_5() {
+        // isolated nodes keep their own labels
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+        graph.add_nodes(vec![Node::from_name("n1"), Node::from_name("n2")]);
+        let communities =
+            label_propagation::label_propagation_communities(&graph, false, None, Some(1)).unwrap();
+        assert_eq!(communities.len(), 2);
+    }
+}
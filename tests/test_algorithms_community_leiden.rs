@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::{
+            community::{leiden, partitions},
+            components,
+        },
+        generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+    use std::collections::HashSet;
+
+    fn get_two_cliques_graph(weighted_bridge: f64) -> Graph<i32, ()> {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        for offset in [0, 10] {
+            for u in 0..5 {
+                for v in (u + 1)..5 {
+                    graph.add_edge(Edge::with_weight(u + offset, v + offset, 1.0)).unwrap();
+                }
+            }
+        }
+        graph.add_edge(Edge::with_weight(4, 10, weighted_bridge)).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_leiden_communities_1() {
+        // karate club
+        let graph = generators::social::karate_club_graph();
+        let communities = leiden::leiden_communities(&graph, false, None, None, Some(1)).unwrap();
+        assert!(partitions::is_partition(&graph, &communities));
+        assert_eq!(communities.len(), 4);
+        let modularity = partitions::modularity(&graph, &communities, false, None).unwrap();
+        assert!(modularity > 0.41);
+    }
+
+    #[test]
+    fn test_leiden_communities_2() {
+        // two cliques joined by a single edge
+        let graph = get_two_cliques_graph(1.0);
+        let communities = leiden::leiden_communities(&graph, false, None, None, None).unwrap();
+        let expected: Vec<HashSet<i32>> = vec![
+            (0..5).collect::<HashSet<i32>>(),
+            (10..15).collect::<HashSet<i32>>(),
+        ];
+        assert_eq!(communities, expected);
+    }
+
+    #[test]
+    fn test_leiden_communities_3() {
+        // a heavy bridge merges the cliques when weights are used
+        let graph = get_two_cliques_graph(100.0);
+        let communities = leiden::leiden_communities(&graph, true, None, None, Some(1)).unwrap();
+        assert!(communities.iter().any(|c| c.contains(&4) && c.contains(&10)));
+        let communities = leiden::leiden_communities(&graph, false, None, None, Some(1)).unwrap();
+        assert!(!communities.iter().any(|c| c.contains(&4) && c.contains(&10)));
+    }
+
+    #[test]
+    fn test_leiden_communities_4() {
+        // every community is connected
+        for seed in 0..5 {
+            let graph =
+                generators::random::fast_gnp_random_graph(100, 0.05, false, Some(seed)).unwrap();
+            let communities =
+                leiden::leiden_communities(&graph, false, None, None, Some(seed)).unwrap();
+            assert!(partitions::is_partition(&graph, &communities));
+            for community in communities {
+                let nodes: Vec<i32> = community.into_iter().collect();
+                let subgraph = graph.get_subgraph(&nodes);
+                assert_eq!(
+                    components::number_connected_components(&subgraph).unwrap(),
+                    1
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_leiden_communities_5() {
+        // a higher resolution gives smaller communities
+        let graph = generators::social::karate_club_graph();
+        let low = leiden::leiden_communities(&graph, false, Some(0.5), None, Some(1)).unwrap();
+        let high = leiden::leiden_communities(&graph, false, Some(2.0), None, Some(1)).unwrap();
+        assert!(low.len() < high.len());
+    }
+
+    #[test]
+    fn test_leiden_communities_6() {
+        // nodes without edges are in their own communities
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+        graph.add_nodes(vec![Node::from_name("n1"), Node::from_name("n2")]);
+        let communities = leiden::leiden_communities(&graph, false, None, None, None).unwrap();
+        assert_eq!(communities.len(), 2);
+    }
+
+    #[test]
+    fn test_leiden_communities_7() {
+        // directed graphs are not supported
+        let graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        let result = leiden::leiden_communities(&graph, false, None, None, None);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::WrongMethod));
+    }
+}
@@ -0,0 +1,102 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::round;
+    use graphrs::{algorithms::community::quality, generators, Edge, ErrorKind, Graph, GraphSpecs};
+    use std::collections::HashSet;
+
+    fn get_karate_communities() -> Vec<HashSet<i32>> {
+        vec![
+            (0..6).collect(),
+            (6..12).collect(),
+            (12..18).collect(),
+            (18..24).collect(),
+            (24..30).collect(),
+            (30..34).collect(),
+        ]
+    }
+
+    fn get_directed_graph() -> Graph<&'static str, ()> {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n3", 5.0),
+            Edge::with_weight("n1", "n4", 2.0),
+            Edge::with_weight("n4", "n3", 3.0),
+            Edge::with_weight("n1", "n5", 9.0),
+            Edge::with_weight("n3", "n5", 1.0),
+        ];
+        Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+            .unwrap()
+    }
+
+    fn get_directed_communities() -> Vec<HashSet<&'static str>> {
+        vec![
+            vec!["n1", "n2", "n4"].into_iter().collect(),
+            vec!["n3", "n5"].into_iter().collect(),
+        ]
+    }
+
+    #[test]
+    fn test_coverage_1() {
+        let graph = generators::social::karate_club_graph();
+        let result = quality::coverage(&graph, &get_karate_communities(), false).unwrap();
+        assert_eq!(round(&result, 6), 0.205128);
+    }
+
+    #[test]
+    fn test_coverage_2() {
+        // directed, unweighted and weighted
+        let graph = get_directed_graph();
+        let communities = get_directed_communities();
+        assert_eq!(quality::coverage(&graph, &communities, false).unwrap(), 0.5);
+        assert_eq!(
+            quality::coverage(&graph, &communities, true).unwrap(),
+            4.0 / 21.0
+        );
+    }
+
+    #[test]
+    fn test_coverage_3() {
+        // not a partition
+        let graph = get_directed_graph();
+        let communities: Vec<HashSet<&str>> = vec![vec!["n1", "n2"].into_iter().collect()];
+        let result = quality::coverage(&graph, &communities, false);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::NotAPartition));
+    }
+
+    #[test]
+    fn test_performance_1() {
+        let graph = generators::social::karate_club_graph();
+        let result = quality::performance(&graph, &get_karate_communities()).unwrap();
+        assert_eq!(round(&result, 6), 0.773619);
+    }
+
+    #[test]
+    fn test_performance_2() {
+        let graph = get_directed_graph();
+        let result = quality::performance(&graph, &get_directed_communities()).unwrap();
+        assert_eq!(result, 0.6);
+    }
+
+    #[test]
+    fn test_performance_3() {
+        // not defined for multigraphs
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n1", "n2")]).unwrap();
+        let communities: Vec<HashSet<&str>> = vec![vec!["n1", "n2"].into_iter().collect()];
+        let result = quality::performance(&graph, &communities);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_modularity_1() {
+        let graph = generators::social::karate_club_graph();
+        let result = quality::modularity(&graph, &get_karate_communities(), false, None).unwrap();
+        assert_eq!(round(&result, 6), -0.001479);
+    }
+}
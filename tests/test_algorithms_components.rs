@@ -0,0 +1,313 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::components, generators, Edge, ErrorKind, Graph, GraphSpecs, Node};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_strongly_connected_components_1() {
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![
+            Edge::new("a", "b"),
+            Edge::new("b", "c"),
+            Edge::new("b", "e"),
+            Edge::new("b", "f"),
+            Edge::new("c", "d"),
+            Edge::new("c", "g"),
+            Edge::new("d", "c"),
+            Edge::new("d", "h"),
+            Edge::new("e", "a"),
+            Edge::new("e", "f"),
+            Edge::new("f", "g"),
+            Edge::new("g", "f"),
+            Edge::new("h", "d"),
+            Edge::new("h", "g"),
+        ]).unwrap();
+        let result = components::strongly_connected_components(&graph).unwrap();
+        assert_eq!(
+            result,
+            vec![vec!["f", "g"], vec!["c", "d", "h"], vec!["a", "b", "e"]]
+        );
+    }
+
+    #[test]
+    fn test_strongly_connected_components_2() {
+        // every node is in exactly one component
+        let graph = generators::random::fast_gnp_random_graph(200, 0.01, true, Some(2)).unwrap();
+        let result = components::strongly_connected_components(&graph).unwrap();
+        let total: usize = result.iter().map(|c| c.len()).sum();
+        assert_eq!(total, 200);
+        let all: HashSet<i32> = result.iter().flatten().cloned().collect();
+        assert_eq!(all.len(), 200);
+    }
+
+    #[test]
+    fn test_strongly_connected_components_3() {
+        // a long cycle doesn't overflow the stack
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        let mut edges: Vec<Edge<i32, ()>> = (0..100_000).map(|i| Edge::new(i, i + 1)).collect();
+        edges.push(Edge::new(100_000, 0));
+        graph.add_edges(edges).unwrap();
+        let result = components::strongly_connected_components(&graph).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].len(), 100_001);
+    }
+
+    #[test]
+    fn test_strongly_connected_components_4() {
+        let graph = generators::social::karate_club_graph();
+        let result = components::strongly_connected_components(&graph);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_connected_components_1() {
+        let graph = generators::social::karate_club_graph();
+        let result = components::connected_components(&graph).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0], (0..34).collect::<Vec<i32>>());
+        assert_eq!(components::number_connected_components(&graph).unwrap(), 1);
+        let result = components::weakly_connected_components(&graph);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_connected_components_2() {
+        let nodes = vec![Node::from_name(7), Node::from_name(0)];
+        let edges = vec![
+            Edge::new(5, 6),
+            Edge::new(1, 2),
+            Edge::new(2, 3),
+            Edge::new(4, 6),
+            Edge::new(3, 1),
+        ];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let result = components::connected_components(&graph).unwrap();
+        assert_eq!(result, vec![vec![0], vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+        assert_eq!(components::number_connected_components(&graph).unwrap(), 4);
+        assert_eq!(
+            components::node_connected_component(&graph, 5).unwrap(),
+            vec![4, 5, 6]
+        );
+        assert_eq!(
+            components::node_connected_component(&graph, 7).unwrap(),
+            vec![7]
+        );
+        let result = components::node_connected_component(&graph, 8);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_weakly_connected_components_1() {
+        let graph = generators::random::fast_gnp_random_graph(200, 0.01, true, Some(2)).unwrap();
+        let weak = components::weakly_connected_components(&graph).unwrap();
+        let strong = components::strongly_connected_components(&graph).unwrap();
+        assert!(weak.len() <= strong.len());
+        // every strongly connected component is inside a weakly connected component
+        for component in strong {
+            assert!(weak.iter().any(|w| component.iter().all(|n| w.contains(n))));
+        }
+        let total: usize = weak.iter().map(|c| c.len()).sum();
+        assert_eq!(total, 200);
+    }
+
+    fn copy_graph(graph: &Graph<usize, ()>) -> Graph<usize, ()> {
+        Graph::new_from_nodes_and_edges(
+            graph.get_all_nodes().into_iter().cloned().collect(),
+            graph.get_all_edges().into_iter().cloned().collect(),
+            graph.specs.clone(),
+        )
+        .unwrap()
+    }
+
+    fn get_graph_with_blocks() -> Graph<i32, ()> {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::new(1, 2),
+                Edge::new(2, 3),
+                Edge::new(3, 1),
+                Edge::new(3, 4),
+                Edge::new(4, 5),
+                Edge::new(5, 6),
+                Edge::new(6, 4),
+                Edge::new(6, 7),
+                Edge::new(8, 9),
+                Edge::new(9, 10),
+                Edge::new(10, 8),
+                Edge::new(10, 11),
+                Edge::new(11, 12),
+                Edge::new(12, 10),
+                Edge::new(13, 14),
+                Edge::new(16, 16),
+            ])
+            .unwrap();
+        graph.add_node(Node::from_name(15));
+        graph
+    }
+
+    #[test]
+    fn test_articulation_points_1() {
+        let graph = get_graph_with_blocks();
+        let result = components::articulation_points(&graph).unwrap();
+        assert_eq!(result, vec![3, 4, 6, 10]);
+        let graph = generators::social::karate_club_graph();
+        assert_eq!(components::articulation_points(&graph).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_articulation_points_2() {
+        for seed in 0..20 {
+            let graph = generators::random::gnp_random_graph(15, 0.15, Some(seed), false).unwrap();
+            let expected_count = components::number_connected_components(&graph).unwrap();
+            let expected: Vec<usize> = graph
+                .get_all_node_names()
+                .into_iter()
+                .filter(|n| {
+                    let mut g = copy_graph(&graph);
+                    g.remove_node(**n).unwrap();
+                    components::number_connected_components(&g).unwrap() > expected_count
+                })
+                .cloned()
+                .collect::<std::collections::BTreeSet<usize>>()
+                .into_iter()
+                .collect();
+            assert_eq!(components::articulation_points(&graph).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_bridges_1() {
+        let graph = get_graph_with_blocks();
+        let result = components::bridges(&graph).unwrap();
+        assert_eq!(result, vec![(3, 4), (6, 7), (13, 14)]);
+        let graph = generators::social::karate_club_graph();
+        assert_eq!(components::bridges(&graph).unwrap(), vec![(0, 11)]);
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::multi_undirected());
+        graph.add_nodes(vec![
+            Node::from_name(1),
+            Node::from_name(2),
+            Node::from_name(3),
+        ]);
+        graph.add_edges(vec![Edge::new(1, 2), Edge::new(2, 1), Edge::new(2, 3)]).unwrap();
+        assert_eq!(components::bridges(&graph).unwrap(), vec![(2, 3)]);
+    }
+
+    #[test]
+    fn test_bridges_2() {
+        for seed in 0..20 {
+            let graph = generators::random::gnp_random_graph(15, 0.15, Some(seed), false).unwrap();
+            let expected_count = components::number_connected_components(&graph).unwrap();
+            let mut expected: Vec<(usize, usize)> = graph
+                .get_all_edges()
+                .into_iter()
+                .filter(|e| {
+                    let mut g = copy_graph(&graph);
+                    g.remove_edge(e.u, e.v).unwrap();
+                    components::number_connected_components(&g).unwrap() > expected_count
+                })
+                .map(|e| (e.u.min(e.v), e.u.max(e.v)))
+                .collect();
+            expected.sort();
+            assert_eq!(components::bridges(&graph).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_biconnected_components_1() {
+        let graph = get_graph_with_blocks();
+        let result = components::biconnected_components(&graph).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                vec![1, 2, 3],
+                vec![3, 4],
+                vec![4, 5, 6],
+                vec![6, 7],
+                vec![8, 9, 10],
+                vec![10, 11, 12],
+                vec![13, 14]
+            ]
+        );
+        let graph = generators::social::karate_club_graph();
+        let result = components::biconnected_components(&graph).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[1], vec![0, 4, 5, 6, 10, 16]);
+        assert_eq!(result[2], vec![0, 11]);
+        assert_eq!(result[0].len(), 28);
+    }
+
+    #[test]
+    fn test_biconnected_errors() {
+        let graph = generators::classic::path_graph(4, true);
+        let result = components::articulation_points(&graph);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let result = components::bridges(&graph);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let result = components::biconnected_components(&graph);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_condensation_1() {
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::new("a", "b"),
+                Edge::new("b", "c"),
+                Edge::new("b", "e"),
+                Edge::new("b", "f"),
+                Edge::new("c", "d"),
+                Edge::new("c", "g"),
+                Edge::new("d", "c"),
+                Edge::new("d", "h"),
+                Edge::new("e", "a"),
+                Edge::new("e", "f"),
+                Edge::new("f", "g"),
+                Edge::new("g", "f"),
+                Edge::new("h", "d"),
+                Edge::new("h", "g"),
+            ])
+            .unwrap();
+        let (condensation, mapping) = components::condensation(&graph).unwrap();
+        let members: Vec<Vec<&str>> =
+            (0..3).map(|i| condensation.get_node(i).unwrap().attributes.clone().unwrap()).collect();
+        assert_eq!(
+            members,
+            vec![vec!["a", "b", "e"], vec!["c", "d", "h"], vec!["f", "g"]]
+        );
+        let mut edges: Vec<(usize, usize)> =
+            condensation.get_all_edges().into_iter().map(|e| (e.u, e.v)).collect();
+        edges.sort();
+        assert_eq!(edges, vec![(0, 1), (0, 2), (1, 2)]);
+        assert_eq!(mapping.len(), 8);
+        assert_eq!(mapping.get("h"), Some(&1));
+        assert!(condensation.specs.directed);
+    }
+
+    #[test]
+    fn test_condensation_2() {
+        let graph = generators::random::fast_gnp_random_graph(200, 0.01, true, Some(2)).unwrap();
+        let (condensation, mapping) = components::condensation(&graph).unwrap();
+        let strong = components::strongly_connected_components(&graph).unwrap();
+        assert_eq!(condensation.get_all_nodes().len(), strong.len());
+        // every edge goes forward in topological order
+        for edge in graph.get_all_edges() {
+            assert!(mapping[&edge.u] <= mapping[&edge.v]);
+        }
+        for edge in condensation.get_all_edges() {
+            assert!(edge.u < edge.v);
+        }
+        let graph = generators::social::karate_club_graph();
+        let result = components::condensation(&graph);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+}
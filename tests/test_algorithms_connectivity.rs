@@ -0,0 +1,228 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::{components, connectivity, flow},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+    use itertools::Itertools;
+
+    fn get_directed_graph() -> Graph<i32, ()> {
+        let mut graph = Graph::new(GraphSpecs::directed_create_missing());
+        let edges = vec![
+            (0, 1),
+            (0, 5),
+            (0, 6),
+            (1, 2),
+            (1, 4),
+            (1, 6),
+            (1, 7),
+            (2, 1),
+            (2, 3),
+            (2, 6),
+            (3, 1),
+            (3, 4),
+            (3, 5),
+            (3, 6),
+            (3, 7),
+            (4, 1),
+            (4, 3),
+            (5, 0),
+            (5, 3),
+            (5, 4),
+            (6, 0),
+            (6, 2),
+            (6, 5),
+            (7, 0),
+            (7, 1),
+            (7, 2),
+            (7, 4),
+            (7, 6),
+        ];
+        graph.add_edges(edges.into_iter().map(|(u, v)| Edge::new(u, v)).collect()).unwrap();
+        graph
+    }
+
+    /// Returns `true` if removing `removed` from the graph disconnects it.
+    fn is_disconnected_without(graph: &Graph<usize, ()>, removed: &[usize]) -> bool {
+        let remaining: Vec<usize> = graph
+            .get_all_node_names()
+            .into_iter()
+            .filter(|n| !removed.contains(n))
+            .cloned()
+            .collect();
+        let subgraph = graph.get_subgraph(&remaining);
+        components::number_connected_components(&subgraph).unwrap() > 1
+    }
+
+    /// Finds the node connectivity by trying every set of nodes, smallest first.
+    fn get_node_connectivity_brute_force(graph: &Graph<usize, ()>) -> usize {
+        let nodes: Vec<usize> = graph.get_all_node_names().into_iter().cloned().collect();
+        (0..nodes.len() - 1)
+            .find(|k| {
+                nodes.iter().cloned().combinations(*k).any(|c| is_disconnected_without(graph, &c))
+            })
+            .unwrap_or(nodes.len() - 1)
+    }
+
+    #[test]
+    fn test_node_connectivity_1() {
+        for seed in 0..20 {
+            let graph = generators::random::gnp_random_graph(9, 0.5, Some(seed), false).unwrap();
+            let expected = get_node_connectivity_brute_force(&graph);
+            assert_eq!(
+                connectivity::node_connectivity(&graph, None).unwrap(),
+                expected
+            );
+            let cut = connectivity::minimum_node_cut(&graph, None).unwrap();
+            assert_eq!(cut.len(), expected);
+            if expected < 8 && components::number_connected_components(&graph).unwrap() == 1 {
+                assert!(is_disconnected_without(&graph, &cut));
+            }
+        }
+    }
+
+    #[test]
+    fn test_node_connectivity_2() {
+        let graph = generators::social::karate_club_graph();
+        assert_eq!(connectivity::node_connectivity(&graph, None).unwrap(), 1);
+        assert_eq!(
+            connectivity::minimum_node_cut(&graph, None).unwrap(),
+            vec![0]
+        );
+        assert_eq!(
+            connectivity::node_connectivity(&graph, Some((0, 33))).unwrap(),
+            6
+        );
+        let graph = generators::classic::complete_graph(5, false);
+        assert_eq!(connectivity::node_connectivity(&graph, None).unwrap(), 4);
+        assert_eq!(
+            connectivity::minimum_node_cut(&graph, None).unwrap().len(),
+            4
+        );
+        let graph = generators::classic::cycle_graph(6, false);
+        assert_eq!(connectivity::node_connectivity(&graph, None).unwrap(), 2);
+        let cut = connectivity::minimum_node_cut(&graph, Some((0, 3))).unwrap();
+        assert_eq!(cut.len(), 2);
+    }
+
+    #[test]
+    fn test_node_connectivity_3() {
+        let graph = get_directed_graph();
+        assert_eq!(connectivity::node_connectivity(&graph, None).unwrap(), 2);
+        assert_eq!(
+            connectivity::minimum_node_cut(&graph, None).unwrap().len(),
+            2
+        );
+        assert_eq!(
+            connectivity::node_connectivity(&graph, Some((0, 5))).unwrap(),
+            3
+        );
+        assert_eq!(
+            connectivity::node_connectivity(&graph, Some((0, 2))).unwrap(),
+            3
+        );
+        let cut = connectivity::minimum_node_cut(&graph, Some((0, 2))).unwrap();
+        assert_eq!(cut, vec![1, 5, 6]);
+    }
+
+    #[test]
+    fn test_edge_connectivity_1() {
+        for seed in 0..20 {
+            let graph = generators::random::gnp_random_graph(10, 0.4, Some(seed), false).unwrap();
+            let expected = flow::stoer_wagner_min_cut(&graph, false).unwrap().cut_value as usize;
+            assert_eq!(
+                connectivity::edge_connectivity(&graph, None).unwrap(),
+                expected
+            );
+            let cut = connectivity::minimum_edge_cut(&graph, None).unwrap();
+            assert_eq!(cut.len(), expected);
+        }
+    }
+
+    #[test]
+    fn test_edge_connectivity_2() {
+        let graph = generators::social::karate_club_graph();
+        assert_eq!(connectivity::edge_connectivity(&graph, None).unwrap(), 1);
+        assert_eq!(
+            connectivity::minimum_edge_cut(&graph, None).unwrap(),
+            vec![(0, 11)]
+        );
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, Some((0, 33))).unwrap(),
+            10
+        );
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::undirected_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::new("a", "b"),
+                Edge::new("a", "b"),
+                Edge::new("b", "c"),
+                Edge::new("c", "a"),
+            ])
+            .unwrap();
+        assert_eq!(connectivity::edge_connectivity(&graph, None).unwrap(), 2);
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, Some(("a", "b"))).unwrap(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_edge_connectivity_3() {
+        let graph = get_directed_graph();
+        assert_eq!(connectivity::edge_connectivity(&graph, None).unwrap(), 2);
+        assert_eq!(
+            connectivity::minimum_edge_cut(&graph, None).unwrap().len(),
+            2
+        );
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, Some((0, 5))).unwrap(),
+            3
+        );
+        let cut = connectivity::minimum_edge_cut(&graph, Some((2, 0))).unwrap();
+        assert_eq!(cut, vec![(2, 1), (2, 3), (2, 6)]);
+    }
+
+    #[test]
+    fn test_connectivity_disconnected() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        graph.add_edges(vec![Edge::new(1, 2), Edge::new(2, 3), Edge::new(4, 5)]).unwrap();
+        assert_eq!(connectivity::node_connectivity(&graph, None).unwrap(), 0);
+        assert_eq!(connectivity::edge_connectivity(&graph, None).unwrap(), 0);
+        assert!(connectivity::minimum_node_cut(&graph, None).unwrap().is_empty());
+        assert!(connectivity::minimum_edge_cut(&graph, None).unwrap().is_empty());
+        assert_eq!(
+            connectivity::node_connectivity(&graph, Some((1, 4))).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_connectivity_errors() {
+        let graph = generators::classic::cycle_graph(5, false);
+        let result = connectivity::node_connectivity(&graph, Some((0, 0)));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = connectivity::edge_connectivity(&graph, Some((0, 7)));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = connectivity::minimum_node_cut(&graph, Some((0, 1)));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = connectivity::minimum_edge_cut(&graph, Some((7, 0)));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+}
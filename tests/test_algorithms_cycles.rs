@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::cycles, generators, Edge, ErrorKind, Graph, GraphSpecs};
+    use std::collections::HashSet;
+
+    /// Counts the simple cycles by extending every path from each start node through higher nodes.
+    fn count_cycles_brute_force(graph: &Graph<usize, ()>) -> usize {
+        fn extend(graph: &Graph<usize, ()>, path: &mut Vec<usize>) -> usize {
+            let (start, last) = (path[0], *path.last().unwrap());
+            let mut count = 0;
+            for v in graph.get_successor_node_names(last).unwrap() {
+                if *v == start {
+                    count += 1;
+                } else if *v > start && !path.contains(v) {
+                    path.push(*v);
+                    count += extend(graph, path);
+                    path.pop();
+                }
+            }
+            count
+        }
+        graph.get_all_node_names().into_iter().map(|n| extend(graph, &mut vec![*n])).sum()
+    }
+
+    fn assert_is_cycle<T>(graph: &Graph<T, ()>, cycle: &[T])
+    where
+        T: std::hash::Hash + Eq + Clone + Ord + std::fmt::Display + Send + Sync,
+    {
+        assert_eq!(cycle.iter().collect::<HashSet<&T>>().len(), cycle.len());
+        for (i, u) in cycle.iter().enumerate() {
+            let v = &cycle[(i + 1) % cycle.len()];
+            assert!(graph.get_edge(u.clone(), v.clone()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_simple_cycles_1() {
+        let graph = generators::classic::complete_graph(5, true);
+        let cycles: Vec<Vec<i32>> = cycles::simple_cycles(&graph).unwrap().collect();
+        assert_eq!(cycles.len(), 84);
+        let unique: HashSet<Vec<i32>> = cycles.iter().cloned().collect();
+        assert_eq!(unique.len(), 84);
+        for cycle in cycles.iter() {
+            assert_is_cycle(&graph, cycle);
+            assert_eq!(cycle.iter().min(), cycle.first());
+        }
+    }
+
+    #[test]
+    fn test_simple_cycles_2() {
+        for seed in 0..10 {
+            let graph = generators::random::gnp_random_graph(9, 0.3, Some(seed), true).unwrap();
+            let cycles: Vec<Vec<usize>> = cycles::simple_cycles(&graph).unwrap().collect();
+            assert_eq!(cycles.len(), count_cycles_brute_force(&graph));
+            for cycle in cycles.iter() {
+                assert_is_cycle(&graph, cycle);
+            }
+        }
+    }
+
+    #[test]
+    fn test_simple_cycles_3() {
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        };
+        let mut graph: Graph<&str, ()> = Graph::new(specs);
+        assert_eq!(cycles::simple_cycles(&graph).unwrap().count(), 0);
+        graph
+            .add_edges(vec![
+                Edge::new("n1", "n1"),
+                Edge::new("n1", "n2"),
+                Edge::new("n2", "n3"),
+                Edge::new("n3", "n4"),
+            ])
+            .unwrap();
+        let cycles: Vec<Vec<&str>> = cycles::simple_cycles(&graph).unwrap().collect();
+        assert_eq!(cycles, vec![vec!["n1"]]);
+        let graph = generators::classic::cycle_graph(4, false);
+        let result = cycles::simple_cycles(&graph);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let graph = generators::classic::path_graph(5, true);
+        assert_eq!(cycles::find_cycle(&graph, None).unwrap(), None);
+        let graph = generators::classic::path_graph(5, false);
+        assert_eq!(cycles::find_cycle(&graph, None).unwrap(), None);
+        let graph = generators::classic::cycle_graph(5, false);
+        let cycle = cycles::find_cycle(&graph, Some(2)).unwrap().unwrap();
+        assert_eq!(cycle.len(), 5);
+        assert_is_cycle(&graph, &cycle);
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::new("n1", "n2"),
+                Edge::new("n2", "n3"),
+                Edge::new("n3", "n2"),
+                Edge::new("n4", "n1"),
+            ])
+            .unwrap();
+        assert_eq!(
+            cycles::find_cycle(&graph, Some("n4")).unwrap(),
+            Some(vec!["n2", "n3"])
+        );
+        let result = cycles::find_cycle(&graph, Some("n5"));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_cycle_basis() {
+        let graph = generators::social::karate_club_graph();
+        let basis = cycles::cycle_basis(&graph, None).unwrap();
+        assert_eq!(basis.len(), 45);
+        for cycle in basis.iter() {
+            assert_is_cycle(&graph, cycle);
+        }
+        let basis = cycles::cycle_basis(&graph, Some(33)).unwrap();
+        assert_eq!(basis.len(), 45);
+
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected_create_missing()
+        };
+        let mut graph: Graph<&str, ()> = Graph::new(specs);
+        graph
+            .add_edges(vec![
+                Edge::new("n1", "n2"),
+                Edge::new("n2", "n3"),
+                Edge::new("n3", "n1"),
+                Edge::new("n4", "n5"),
+                Edge::new("n5", "n5"),
+            ])
+            .unwrap();
+        let mut basis: Vec<Vec<&str>> = cycles::cycle_basis(&graph, None)
+            .unwrap()
+            .into_iter()
+            .map(|mut c| {
+                c.sort();
+                c
+            })
+            .collect();
+        basis.sort();
+        assert_eq!(basis, vec![vec!["n1", "n2", "n3"], vec!["n5"]]);
+
+        let result = cycles::cycle_basis(&graph, Some("n6"));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let graph = generators::classic::cycle_graph(4, true);
+        let result = cycles::cycle_basis(&graph, None);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+}
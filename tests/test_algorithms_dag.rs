@@ -0,0 +1,366 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::dag, generators, Edge, ErrorKind, Graph, GraphSpecs, Node};
+
+    #[test]
+    fn test_topological_sort_1() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![
+            Edge::new(5, 11),
+            Edge::new(7, 11),
+            Edge::new(7, 8),
+            Edge::new(3, 8),
+            Edge::new(3, 10),
+            Edge::new(11, 2),
+            Edge::new(11, 9),
+            Edge::new(11, 10),
+            Edge::new(8, 9),
+        ]).unwrap();
+        let order = dag::topological_sort(&graph).unwrap();
+        assert_eq!(order, vec![3, 5, 7, 8, 11, 2, 9, 10]);
+        let position = |n| order.iter().position(|x| *x == n).unwrap();
+        for edge in graph.get_all_edges() {
+            assert!(position(edge.u) < position(edge.v));
+        }
+        assert!(dag::is_directed_acyclic_graph(&graph));
+    }
+
+    #[test]
+    fn test_topological_sort_2() {
+        // a random DAG: edges only go from lower to higher numbered nodes
+        let random = generators::random::fast_gnp_random_graph(100, 0.1, true, Some(1)).unwrap();
+        let edges: Vec<Edge<i32, ()>> = random
+            .get_all_edges()
+            .into_iter()
+            .filter(|e| e.u < e.v)
+            .map(|e| Edge::new(e.v, e.u))
+            .collect();
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let order = dag::topological_sort(&graph).unwrap();
+        assert_eq!(order.len(), graph.get_all_nodes().len());
+        let position = |n| order.iter().position(|x| *x == n).unwrap();
+        for edge in graph.get_all_edges() {
+            assert!(position(edge.u) < position(edge.v));
+        }
+    }
+
+    #[test]
+    fn test_topological_sort_3() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![
+            Edge::new(1, 2),
+            Edge::new(2, 3),
+            Edge::new(3, 4),
+            Edge::new(4, 2),
+            Edge::new(4, 5),
+        ]).unwrap();
+        let error = dag::topological_sort(&graph).unwrap_err();
+        assert!(matches!(error.kind, ErrorKind::GraphContainsCycle));
+        assert_eq!(error.message, "The graph contains a cycle: [2, 3, 4].");
+        assert!(!dag::is_directed_acyclic_graph(&graph));
+    }
+
+    #[test]
+    fn test_topological_sort_4() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph.add_edges(vec![Edge::new(1, 2), Edge::new(2, 2)]).unwrap();
+        let error = dag::topological_sort(&graph).unwrap_err();
+        assert_eq!(error.message, "The graph contains a cycle: [2].");
+        let graph = generators::social::karate_club_graph();
+        let error = dag::topological_sort(&graph).unwrap_err();
+        assert!(matches!(error.kind, ErrorKind::WrongMethod));
+        assert!(!dag::is_directed_acyclic_graph(&graph));
+        let graph = Graph::<i32, ()>::new(GraphSpecs::directed());
+        assert_eq!(dag::topological_sort(&graph).unwrap().len(), 0);
+    }
+
+    fn get_dag() -> Graph<i32, ()> {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::new(5, 11),
+                Edge::new(7, 11),
+                Edge::new(7, 8),
+                Edge::new(3, 8),
+                Edge::new(3, 10),
+                Edge::new(11, 2),
+                Edge::new(11, 9),
+                Edge::new(11, 10),
+                Edge::new(8, 9),
+                Edge::new(3, 9),
+                Edge::new(7, 9),
+                Edge::new(5, 2),
+            ])
+            .unwrap();
+        graph
+    }
+
+    fn get_sorted_edges(graph: &Graph<i32, ()>) -> Vec<(i32, i32)> {
+        let mut edges: Vec<(i32, i32)> =
+            graph.get_all_edges().into_iter().map(|e| (e.u, e.v)).collect();
+        edges.sort();
+        edges
+    }
+
+    fn get_random_dag(seed: u64) -> Graph<i32, ()> {
+        let random = generators::random::fast_gnp_random_graph(60, 0.1, true, Some(seed)).unwrap();
+        let edges: Vec<Edge<i32, ()>> = random
+            .get_all_edges()
+            .into_iter()
+            .filter(|e| e.u < e.v)
+            .map(|e| Edge::new(e.u, e.v))
+            .collect();
+        Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_transitive_closure_1() {
+        let closure = dag::transitive_closure(&get_dag()).unwrap();
+        assert_eq!(
+            get_sorted_edges(&closure),
+            vec![
+                (3, 8),
+                (3, 9),
+                (3, 10),
+                (5, 2),
+                (5, 9),
+                (5, 10),
+                (5, 11),
+                (7, 2),
+                (7, 8),
+                (7, 9),
+                (7, 10),
+                (7, 11),
+                (8, 9),
+                (11, 2),
+                (11, 9),
+                (11, 10)
+            ]
+        );
+        assert_eq!(closure.get_all_nodes().len(), 8);
+    }
+
+    #[test]
+    fn test_transitive_closure_2() {
+        // a cycle: every node reaches every other node
+        let graph = generators::classic::cycle_graph(5, true);
+        let closure = dag::transitive_closure(&graph).unwrap();
+        assert_eq!(closure.get_all_edges().len(), 20);
+        assert!(closure.get_edge(0, 0).is_err());
+        let graph = generators::classic::cycle_graph(5, false);
+        let result = dag::transitive_closure(&graph);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_transitive_closure_3() {
+        // edges that are kept have their weights
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("a", "b", 2.0),
+                Edge::with_weight("b", "c", 3.0),
+            ])
+            .unwrap();
+        let closure = dag::transitive_closure(&graph).unwrap();
+        assert_eq!(closure.get_edge("a", "b").unwrap().weight, 2.0);
+        assert!(closure.get_edge("a", "c").unwrap().weight.is_nan());
+        let reduction = dag::transitive_reduction(&closure).unwrap();
+        assert_eq!(reduction.get_edge("b", "c").unwrap().weight, 3.0);
+        assert_eq!(reduction.get_all_edges().len(), 2);
+    }
+
+    #[test]
+    fn test_transitive_reduction_1() {
+        let reduction = dag::transitive_reduction(&get_dag()).unwrap();
+        assert_eq!(
+            get_sorted_edges(&reduction),
+            vec![
+                (3, 8),
+                (3, 10),
+                (5, 11),
+                (7, 8),
+                (7, 11),
+                (8, 9),
+                (11, 2),
+                (11, 9),
+                (11, 10)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transitive_reduction_2() {
+        for seed in 0..5 {
+            let graph = get_random_dag(seed);
+            let reduction = dag::transitive_reduction(&graph).unwrap();
+            let closure = dag::transitive_closure(&graph).unwrap();
+            // the reduction has the same reachability, and no edge can be removed
+            let reduction_closure = dag::transitive_closure(&reduction).unwrap();
+            assert_eq!(
+                get_sorted_edges(&reduction_closure),
+                get_sorted_edges(&closure)
+            );
+            for (u, v) in get_sorted_edges(&reduction) {
+                let mut fewer = dag::transitive_reduction(&graph).unwrap();
+                fewer.remove_edge(u, v).unwrap();
+                let fewer_closure = dag::transitive_closure(&fewer).unwrap();
+                assert!(fewer_closure.get_edge(u, v).is_err());
+            }
+            // reducing again changes nothing
+            let again = dag::transitive_reduction(&closure).unwrap();
+            assert_eq!(get_sorted_edges(&again), get_sorted_edges(&reduction));
+        }
+    }
+
+    #[test]
+    fn test_transitive_reduction_3() {
+        let graph = generators::classic::cycle_graph(5, true);
+        let result = dag::transitive_reduction(&graph);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::GraphContainsCycle
+        ));
+        let graph = generators::classic::path_graph(5, false);
+        let result = dag::transitive_reduction(&graph);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_topological_generations_1() {
+        let generations = dag::topological_generations(&get_dag()).unwrap();
+        assert_eq!(
+            generations,
+            vec![vec![3, 5, 7], vec![8, 11], vec![2, 9, 10]]
+        );
+        for seed in 0..5 {
+            let graph = get_random_dag(seed);
+            let generations = dag::topological_generations(&graph).unwrap();
+            let total: usize = generations.iter().map(|g| g.len()).sum();
+            assert_eq!(total, graph.get_all_nodes().len());
+            let position = |n: i32| generations.iter().position(|g| g.contains(&n)).unwrap();
+            for edge in graph.get_all_edges() {
+                assert!(position(edge.u) < position(edge.v));
+            }
+        }
+    }
+
+    #[test]
+    fn test_topological_generations_2() {
+        let graph = generators::classic::cycle_graph(5, true);
+        let result = dag::topological_generations(&graph);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::GraphContainsCycle
+        ));
+        let graph = generators::classic::path_graph(5, false);
+        let result = dag::topological_generations(&graph);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let graph = Graph::<i32, ()>::new(GraphSpecs::directed());
+        assert!(dag::topological_generations(&graph).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_ancestors_and_descendants_1() {
+        let graph = get_dag();
+        assert_eq!(dag::ancestors(&graph, 9).unwrap(), vec![3, 5, 7, 8, 11]);
+        assert_eq!(dag::descendants(&graph, 7).unwrap(), vec![2, 8, 9, 10, 11]);
+        assert!(dag::ancestors(&graph, 3).unwrap().is_empty());
+        assert!(dag::descendants(&graph, 10).unwrap().is_empty());
+        // on a cycle a node is not its own ancestor or descendant
+        let graph = generators::classic::cycle_graph(4, true);
+        assert_eq!(dag::ancestors(&graph, 0).unwrap(), vec![1, 2, 3]);
+        assert_eq!(dag::descendants(&graph, 0).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_ancestors_and_descendants_2() {
+        let graph = get_dag();
+        let result = dag::ancestors(&graph, 4);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = dag::descendants(&graph, 4);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let graph = generators::classic::path_graph(5, false);
+        let result = dag::descendants(&graph, 0);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_longest_path_1() {
+        let (path, length) = dag::longest_path(&get_dag(), false).unwrap();
+        assert_eq!(path, vec![5, 11, 2]);
+        assert_eq!(length, 2.0);
+        let (path, length) = dag::longest_path(&get_random_dag(1), false).unwrap();
+        assert_eq!(length, (path.len() - 1) as f64);
+        let graph = get_random_dag(1);
+        for pair in path.windows(2) {
+            assert!(graph.get_edge(pair[0], pair[1]).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_longest_path_2() {
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::multi_directed());
+        graph.add_nodes(vec![
+            Node::from_name("a"),
+            Node::from_name("b"),
+            Node::from_name("c"),
+            Node::from_name("d"),
+            Node::from_name("e"),
+        ]);
+        graph
+            .add_edges(vec![
+                Edge::with_weight("a", "b", 2.0),
+                Edge::with_weight("a", "b", 4.0),
+                Edge::with_weight("b", "c", -1.0),
+                Edge::with_weight("a", "c", 1.0),
+                Edge::with_weight("c", "d", 3.0),
+                Edge::with_weight("b", "d", 1.0),
+                Edge::with_weight("e", "d", -2.0),
+            ])
+            .unwrap();
+        let (path, length) = dag::longest_path(&graph, true).unwrap();
+        assert_eq!(path, vec!["a", "b", "c", "d"]);
+        assert_eq!(length, 6.0);
+        let (path, length) = dag::longest_path(&graph, false).unwrap();
+        assert_eq!(path, vec!["a", "b", "c", "d"]);
+        assert_eq!(length, 3.0);
+    }
+
+    #[test]
+    fn test_longest_path_3() {
+        let graph = Graph::<i32, ()>::new(GraphSpecs::directed());
+        assert_eq!(dag::longest_path(&graph, false).unwrap(), (vec![], 0.0));
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed());
+        graph.add_nodes(vec![Node::from_name(2), Node::from_name(1)]);
+        assert_eq!(dag::longest_path(&graph, false).unwrap(), (vec![1], 0.0));
+        let graph = generators::classic::cycle_graph(4, true);
+        let result = dag::longest_path(&graph, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::GraphContainsCycle
+        ));
+        let graph = get_dag();
+        let result = dag::longest_path(&graph, true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+        let graph = generators::classic::path_graph(4, false);
+        let result = dag::longest_path(&graph, false);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::distance_measures, generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+
+    fn get_weighted_graph() -> Graph<&'static str, ()> {
+        let mut graph = Graph::new(GraphSpecs::undirected_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n2", "n3", 2.0),
+                Edge::with_weight("n3", "n4", 1.0),
+                Edge::with_weight("n1", "n4", 5.0),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_weighted_distance_measures() {
+        let graph = get_weighted_graph();
+        let eccentricities = distance_measures::eccentricity(&graph, true).unwrap();
+        assert_eq!(eccentricities.get("n1"), Some(&4.0));
+        assert_eq!(eccentricities.get("n2"), Some(&3.0));
+        assert_eq!(eccentricities.get("n3"), Some(&3.0));
+        assert_eq!(eccentricities.get("n4"), Some(&4.0));
+        assert_eq!(distance_measures::diameter(&graph, true).unwrap(), 4.0);
+        assert_eq!(distance_measures::radius(&graph, true).unwrap(), 3.0);
+        assert_eq!(
+            distance_measures::center(&graph, true).unwrap(),
+            vec!["n2", "n3"]
+        );
+        assert_eq!(
+            distance_measures::periphery(&graph, true).unwrap(),
+            vec!["n1", "n4"]
+        );
+        // 1 + 3 + 4 + 2 + 3 + 1 for the six pairs, counted in both directions
+        let average = distance_measures::average_shortest_path_length(&graph, true).unwrap();
+        assert_eq!(average, 28.0 / 12.0);
+    }
+
+    #[test]
+    fn test_unweighted_distance_measures() {
+        let graph = get_weighted_graph();
+        assert_eq!(distance_measures::diameter(&graph, false).unwrap(), 2.0);
+        assert_eq!(distance_measures::radius(&graph, false).unwrap(), 2.0);
+        let average = distance_measures::average_shortest_path_length(&graph, false).unwrap();
+        assert_eq!(average, 16.0 / 12.0);
+
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::new("n1", "n2"),
+                Edge::new("n2", "n3"),
+                Edge::new("n3", "n1"),
+            ])
+            .unwrap();
+        let result = distance_measures::eccentricity(&graph, true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+        let eccentricities = distance_measures::eccentricity(&graph, false).unwrap();
+        assert!(eccentricities.values().all(|e| *e == 2.0));
+        let average = distance_measures::average_shortest_path_length(&graph, false).unwrap();
+        assert_eq!(average, 1.5);
+    }
+
+    #[test]
+    fn test_distance_measures_not_connected() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n2", "n3")]).unwrap();
+        let result = distance_measures::diameter(&graph, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::GraphNotConnected
+        ));
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected_create_missing());
+        graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n3", "n4")]).unwrap();
+        let result = distance_measures::average_shortest_path_length(&graph, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::GraphNotConnected
+        ));
+    }
+
+    #[test]
+    fn test_distance_measures_small_graphs() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected());
+        assert!(distance_measures::eccentricity(&graph, false).unwrap().is_empty());
+        let result = distance_measures::radius(&graph, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = distance_measures::average_shortest_path_length(&graph, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        graph.add_node(Node::from_name("n1"));
+        assert_eq!(distance_measures::diameter(&graph, false).unwrap(), 0.0);
+        assert_eq!(
+            distance_measures::center(&graph, false).unwrap(),
+            vec!["n1"]
+        );
+        let average = distance_measures::average_shortest_path_length(&graph, false).unwrap();
+        assert_eq!(average, 0.0);
+    }
+
+    #[test]
+    fn test_diameter_approx_matches_diameter() {
+        for seed in 0..10 {
+            let graph =
+                generators::random::connected_watts_strogatz_graph(60, 4, 0.2, None, Some(seed))
+                    .unwrap();
+            let expected = distance_measures::diameter(&graph, false).unwrap();
+            let result = distance_measures::diameter_approx(&graph, None).unwrap();
+            assert_eq!(result, (expected, expected));
+            let (lower, upper) = distance_measures::diameter_approx(&graph, Some(4)).unwrap();
+            assert!(lower <= expected && expected <= upper);
+        }
+        for graph in [
+            generators::classic::path_graph(9, false),
+            generators::classic::cycle_graph(9, false),
+            generators::classic::grid_2d_graph(4, 7, false),
+            generators::classic::balanced_tree(3, 3, false),
+        ] {
+            let expected = distance_measures::diameter(&graph, false).unwrap();
+            let result = distance_measures::diameter_approx(&graph, None).unwrap();
+            assert_eq!(result, (expected, expected));
+        }
+        let graph = generators::classic::path_graph(1, false);
+        let result = distance_measures::diameter_approx(&graph, None).unwrap();
+        assert_eq!(result, (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_diameter_approx_errors() {
+        let graph = generators::classic::path_graph(5, true);
+        let result = distance_measures::diameter_approx(&graph, None);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected_create_missing());
+        let result = distance_measures::diameter_approx(&graph, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n3", "n4")]).unwrap();
+        let result = distance_measures::diameter_approx(&graph, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::GraphNotConnected
+        ));
+    }
+}
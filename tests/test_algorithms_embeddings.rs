@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::embeddings::{self, EmbeddingOptions},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+
+    fn get_cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+        let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+        dot / (norm_a * norm_b)
+    }
+
+    #[test]
+    fn test_node2vec_1() {
+        let graph = generators::social::karate_club_graph();
+        let options = EmbeddingOptions {
+            dimensions: 12,
+            walk_length: 15,
+            walks_per_node: 4,
+            p: 0.5,
+            q: 2.0,
+            seed: Some(1),
+            ..EmbeddingOptions::default()
+        };
+        let result = embeddings::node2vec(&graph, &options).unwrap();
+        assert_eq!(result.len(), 34);
+        assert!(result.values().all(|e| e.len() == 12));
+        assert!(result.values().flatten().all(|x| x.is_finite()));
+        let again = embeddings::node2vec(&graph, &options).unwrap();
+        assert_eq!(result, again);
+        let options = EmbeddingOptions {
+            seed: Some(2),
+            ..options
+        };
+        let other = embeddings::node2vec(&graph, &options).unwrap();
+        assert_ne!(result, other);
+    }
+
+    #[test]
+    fn test_node2vec_2() {
+        // two disconnected cliques: nodes should be closer to nodes in their own clique
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        for offset in [0, 10] {
+            for u in 0..6 {
+                for v in (u + 1)..6 {
+                    graph.add_edge(Edge::new(u + offset, v + offset)).unwrap();
+                }
+            }
+        }
+        let options = EmbeddingOptions {
+            dimensions: 16,
+            walk_length: 20,
+            epochs: 3,
+            seed: Some(3),
+            ..EmbeddingOptions::default()
+        };
+        let result = embeddings::node2vec(&graph, &options).unwrap();
+        let mut within = vec![];
+        let mut between = vec![];
+        for u in (0..6).chain(10..16) {
+            for v in (0..6).chain(10..16) {
+                if u < v {
+                    let similarity = get_cosine_similarity(&result[&u], &result[&v]);
+                    match (u < 10) == (v < 10) {
+                        true => within.push(similarity),
+                        false => between.push(similarity),
+                    }
+                }
+            }
+        }
+        let mean_within = within.iter().sum::<f32>() / within.len() as f32;
+        let mean_between = between.iter().sum::<f32>() / between.len() as f32;
+        assert!(mean_within > mean_between + 0.5);
+    }
+
+    #[test]
+    fn test_deepwalk_1() {
+        let graph = generators::classic::path_graph(5, false);
+        let options = EmbeddingOptions {
+            dimensions: 4,
+            walk_length: 10,
+            p: -1.0,
+            seed: Some(1),
+            ..EmbeddingOptions::default()
+        };
+        let result = embeddings::deepwalk(&graph, &options).unwrap();
+        assert_eq!(result.len(), 5);
+        assert!(result.values().all(|e| e.len() == 4));
+        let options = EmbeddingOptions { p: 1.0, ..options };
+        let node2vec = embeddings::node2vec(&graph, &options).unwrap();
+        assert_eq!(result, node2vec);
+    }
+
+    #[test]
+    fn test_embeddings_errors() {
+        let graph = generators::classic::cycle_graph(5, false);
+        let options = EmbeddingOptions {
+            dimensions: 0,
+            ..EmbeddingOptions::default()
+        };
+        let result = embeddings::deepwalk(&graph, &options);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let options = EmbeddingOptions {
+            window: 0,
+            ..EmbeddingOptions::default()
+        };
+        let result = embeddings::node2vec(&graph, &options);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let options = EmbeddingOptions {
+            q: 0.0,
+            ..EmbeddingOptions::default()
+        };
+        let result = embeddings::node2vec(&graph, &options);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let options = EmbeddingOptions {
+            weighted: true,
+            ..EmbeddingOptions::default()
+        };
+        let result = embeddings::node2vec(&graph, &options);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+    }
+}
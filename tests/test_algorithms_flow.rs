@@ -0,0 +1,325 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::flow, generators, Edge, ErrorKind, Graph, GraphSpecs, Node};
+
+    fn get_graph(directed: bool) -> Graph<&'static str, ()> {
+        let edges = vec![
+            Edge::with_weight("x", "a", 3.0),
+            Edge::with_weight("x", "b", 1.0),
+            Edge::with_weight("a", "c", 3.0),
+            Edge::with_weight("b", "c", 5.0),
+            Edge::with_weight("b", "d", 4.0),
+            Edge::with_weight("d", "e", 2.0),
+            Edge::with_weight("c", "y", 2.0),
+            Edge::with_weight("e", "y", 3.0),
+        ];
+        let specs = match directed {
+            true => GraphSpecs::directed_create_missing(),
+            false => GraphSpecs::undirected_create_missing(),
+        };
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    #[test]
+    fn test_maximum_flow_1() {
+        // directed
+        let graph = get_graph(true);
+        let result = flow::maximum_flow(&graph, "x", "y").unwrap();
+        assert_eq!(result.flow_value, 3.0);
+        assert_eq!(result.edge_flows.len(), 8);
+        assert_eq!(result.edge_flows.get(&("x", "a")).unwrap(), &2.0);
+        assert_eq!(result.edge_flows.get(&("x", "b")).unwrap(), &1.0);
+        assert_eq!(result.edge_flows.get(&("a", "c")).unwrap(), &2.0);
+        assert_eq!(result.edge_flows.get(&("b", "c")).unwrap(), &0.0);
+        assert_eq!(result.edge_flows.get(&("b", "d")).unwrap(), &1.0);
+        assert_eq!(result.edge_flows.get(&("d", "e")).unwrap(), &1.0);
+        assert_eq!(result.edge_flows.get(&("c", "y")).unwrap(), &2.0);
+        assert_eq!(result.edge_flows.get(&("e", "y")).unwrap(), &1.0);
+    }
+
+    #[test]
+    fn test_maximum_flow_2() {
+        // undirected: flow can go from "c" to "b"
+        let graph = get_graph(false);
+        let result = flow::maximum_flow(&graph, "x", "y").unwrap();
+        assert_eq!(result.flow_value, 4.0);
+        assert_eq!(result.edge_flows.get(&("c", "b")).unwrap(), &1.0);
+        assert!(!result.edge_flows.contains_key(&("b", "c")));
+        // flow is conserved at every node other than the source and sink
+        for node in ["a", "b", "c", "d", "e"] {
+            let inflow: f64 =
+                result.edge_flows.iter().filter(|(k, _)| k.1 == node).map(|(_, f)| f).sum();
+            let outflow: f64 =
+                result.edge_flows.iter().filter(|(k, _)| k.0 == node).map(|(_, f)| f).sum();
+            assert_eq!(inflow, outflow);
+        }
+    }
+
+    #[test]
+    fn test_maximum_flow_3() {
+        // the sink can't be reached
+        let graph = get_graph(true);
+        let result = flow::maximum_flow(&graph, "y", "x").unwrap();
+        assert_eq!(result.flow_value, 0.0);
+        assert!(result.edge_flows.values().all(|f| *f == 0.0));
+    }
+
+    #[test]
+    fn test_maximum_flow_4() {
+        // infinite capacities
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("s", "a", f64::INFINITY),
+                Edge::with_weight("a", "t", 2.0),
+                Edge::with_weight("s", "t", 1.0),
+            ])
+            .unwrap();
+        let result = flow::maximum_flow(&graph, "s", "t").unwrap();
+        assert_eq!(result.flow_value, 3.0);
+        graph.add_edge(Edge::with_weight("a", "b", f64::INFINITY)).unwrap();
+        graph.add_edge(Edge::with_weight("b", "t", f64::INFINITY)).unwrap();
+        let result = flow::maximum_flow(&graph, "s", "t");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_maximum_flow_5() {
+        // errors
+        let graph = get_graph(true);
+        let result = flow::maximum_flow(&graph, "x", "x");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = flow::maximum_flow(&graph, "x", "z");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::new("s", "t")]).unwrap();
+        let result = flow::maximum_flow(&graph, "s", "t");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::with_weight("s", "t", -1.0)]).unwrap();
+        let result = flow::maximum_flow(&graph, "s", "t");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_minimum_cut_1() {
+        // directed
+        let graph = get_graph(true);
+        let result = flow::minimum_cut(&graph, "x", "y").unwrap();
+        assert_eq!(result.cut_value, 3.0);
+        assert_eq!(
+            result.partition,
+            (vec!["a", "c", "x"], vec!["b", "d", "e", "y"])
+        );
+    }
+
+    #[test]
+    fn test_minimum_cut_2() {
+        // undirected
+        let graph = get_graph(false);
+        let result = flow::minimum_cut(&graph, "x", "y").unwrap();
+        assert_eq!(result.cut_value, 4.0);
+        assert_eq!(
+            result.partition,
+            (vec!["x"], vec!["a", "b", "c", "d", "e", "y"])
+        );
+    }
+
+    #[test]
+    fn test_minimum_cut_3() {
+        // errors
+        let graph = get_graph(true);
+        let result = flow::minimum_cut(&graph, "x", "x");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = flow::minimum_cut(&graph, "z", "y");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_stoer_wagner_min_cut_1() {
+        let graph = get_graph(false);
+        let result = flow::stoer_wagner_min_cut(&graph, true).unwrap();
+        assert_eq!(result.cut_value, 4.0);
+        let result = flow::stoer_wagner_min_cut(&graph, false).unwrap();
+        assert_eq!(result.cut_value, 2.0);
+    }
+
+    #[test]
+    fn test_stoer_wagner_min_cut_2() {
+        // karate club, unweighted
+        let graph = generators::social::karate_club_graph();
+        let result = flow::stoer_wagner_min_cut(&graph, false).unwrap();
+        assert_eq!(result.cut_value, 1.0);
+        assert_eq!(result.partition.1, vec![11]);
+    }
+
+    #[test]
+    fn test_stoer_wagner_min_cut_3() {
+        // disconnected
+        let mut graph = get_graph(false);
+        graph.add_edge(Edge::with_weight("p", "q", 5.0)).unwrap();
+        let result = flow::stoer_wagner_min_cut(&graph, true).unwrap();
+        assert_eq!(result.cut_value, 0.0);
+        assert_eq!(
+            result.partition,
+            (vec!["a", "b", "c", "d", "e", "x", "y"], vec!["p", "q"])
+        );
+    }
+
+    #[test]
+    fn test_stoer_wagner_min_cut_4() {
+        // errors
+        let graph = get_graph(true);
+        let result = flow::stoer_wagner_min_cut(&graph, true);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+        graph.add_node(Node::from_name("a"));
+        let result = flow::stoer_wagner_min_cut(&graph, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+        graph.add_edges(vec![Edge::with_weight("s", "t", -1.0)]).unwrap();
+        let result = flow::stoer_wagner_min_cut(&graph, true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_min_cost_flow_1() {
+        // the weight of each edge is used as its cost, and its capacity is 2.0
+        let graph = get_graph(true);
+        let edges = graph
+            .get_all_edges()
+            .into_iter()
+            .map(|e| Edge {
+                u: e.u,
+                v: e.v,
+                attributes: Some(e.weight),
+                weight: 2.0,
+            })
+            .collect();
+        let graph: Graph<&str, f64> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let cost = |e: &Edge<&str, f64>| e.attributes.unwrap();
+        let result = flow::min_cost_flow(&graph, "x", "y", cost).unwrap();
+        assert_eq!(result.flow_value, 4.0);
+        assert_eq!(result.flow_cost, 36.0);
+        assert_eq!(result.edge_flows.get(&("x", "a")).unwrap(), &2.0);
+        assert_eq!(result.edge_flows.get(&("x", "b")).unwrap(), &2.0);
+        assert_eq!(result.edge_flows.get(&("b", "c")).unwrap(), &0.0);
+        assert_eq!(result.edge_flows.get(&("b", "d")).unwrap(), &2.0);
+    }
+
+    #[test]
+    fn test_min_cost_flow_2() {
+        // an assignment problem
+        let costs = vec![
+            ("w1", "j1", 4.0),
+            ("w1", "j2", 1.0),
+            ("w1", "j3", 3.0),
+            ("w2", "j1", 2.0),
+            ("w2", "j2", 0.0),
+            ("w2", "j3", 5.0),
+            ("w3", "j1", 3.0),
+            ("w3", "j2", 2.0),
+            ("w3", "j3", 2.0),
+        ];
+        let mut edges: Vec<Edge<&str, f64>> = costs
+            .into_iter()
+            .map(|(u, v, c)| Edge {
+                u,
+                v,
+                attributes: Some(c),
+                weight: 1.0,
+            })
+            .collect();
+        for worker in ["w1", "w2", "w3"] {
+            edges.push(Edge::with_weight("s", worker, 1.0));
+        }
+        for job in ["j1", "j2", "j3"] {
+            edges.push(Edge::with_weight(job, "t", 1.0));
+        }
+        let graph: Graph<&str, f64> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let cost = |e: &Edge<&str, f64>| e.attributes.unwrap_or(0.0);
+        let result = flow::min_cost_flow(&graph, "s", "t", cost).unwrap();
+        assert_eq!(result.flow_value, 3.0);
+        assert_eq!(result.flow_cost, 5.0);
+        assert_eq!(result.edge_flows.get(&("w1", "j2")).unwrap(), &1.0);
+        assert_eq!(result.edge_flows.get(&("w2", "j1")).unwrap(), &1.0);
+        assert_eq!(result.edge_flows.get(&("w3", "j3")).unwrap(), &1.0);
+    }
+
+    #[test]
+    fn test_min_cost_flow_3() {
+        // negative costs
+        let graph = get_graph(true);
+        let cost = |e: &Edge<&str, ()>| match (e.u, e.v) {
+            ("b", "c") => -2.0,
+            _ => 1.0,
+        };
+        let result = flow::min_cost_flow(&graph, "x", "y", cost).unwrap();
+        assert_eq!(result.flow_value, 3.0);
+        assert_eq!(result.flow_cost, 10.0);
+        assert_eq!(result.edge_flows.get(&("b", "c")).unwrap(), &0.0);
+    }
+
+    #[test]
+    fn test_min_cost_flow_4() {
+        // errors
+        let graph = get_graph(false);
+        let result = flow::min_cost_flow(&graph, "x", "y", |_| 1.0);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let mut graph = get_graph(true);
+        graph.add_edge(Edge::with_weight("c", "b", 1.0)).unwrap();
+        let cost = |e: &Edge<&str, ()>| match (e.u, e.v) {
+            ("b", "c") => -2.0,
+            _ => 1.0,
+        };
+        let result = flow::min_cost_flow(&graph, "x", "y", cost);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NegativeCycle
+        ));
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("s", "a", f64::INFINITY),
+                Edge::with_weight("a", "t", f64::INFINITY),
+            ])
+            .unwrap();
+        let result = flow::min_cost_flow(&graph, "s", "t", |_| 1.0);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::lca::{self, TreeLca},
+        generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+
+    fn get_dag() -> Graph<i32, ()> {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::new(0, 1),
+                Edge::new(0, 2),
+                Edge::new(1, 3),
+                Edge::new(2, 3),
+                Edge::new(1, 4),
+                Edge::new(2, 4),
+                Edge::new(5, 4),
+                Edge::new(3, 6),
+                Edge::new(4, 6),
+            ])
+            .unwrap();
+        graph
+    }
+
+    /// The parent of each node `i > 0` of a pseudo-random tree rooted at `0`.
+    fn get_random_parents(num_nodes: usize, seed: usize) -> Vec<usize> {
+        (0..num_nodes).map(|i| ((i * 2654435761 + seed * 40503) >> 7) % i.max(1)).collect()
+    }
+
+    fn get_lca_naively(parents: &[usize], u: usize, v: usize) -> usize {
+        let mut ancestors = vec![u];
+        while *ancestors.last().unwrap() != 0 {
+            ancestors.push(parents[*ancestors.last().unwrap()]);
+        }
+        let mut v = v;
+        while !ancestors.contains(&v) {
+            v = parents[v];
+        }
+        v
+    }
+
+    #[test]
+    fn test_lowest_common_ancestor_1() {
+        let graph = get_dag();
+        assert_eq!(lca::lowest_common_ancestor(&graph, 6, 5).unwrap(), Some(5));
+        assert_eq!(lca::lowest_common_ancestor(&graph, 3, 5).unwrap(), None);
+        assert_eq!(lca::lowest_common_ancestor(&graph, 1, 2).unwrap(), Some(0));
+        assert_eq!(lca::lowest_common_ancestor(&graph, 6, 1).unwrap(), Some(1));
+        assert_eq!(lca::lowest_common_ancestor(&graph, 0, 0).unwrap(), Some(0));
+        // 1 and 2 are both lowest common ancestors; 2 comes last in topological order
+        assert_eq!(lca::lowest_common_ancestor(&graph, 3, 4).unwrap(), Some(2));
+    }
+
+    #[test]
+    fn test_lowest_common_ancestor_2() {
+        let graph = get_dag();
+        let result = lca::lowest_common_ancestor(&graph, 0, 7);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let graph = generators::classic::cycle_graph(4, true);
+        let result = lca::lowest_common_ancestor(&graph, 0, 1);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::GraphContainsCycle
+        ));
+        let graph = generators::classic::path_graph(4, false);
+        let result = lca::lowest_common_ancestor(&graph, 0, 1);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_tree_lca_1() {
+        for directed in [false, true] {
+            let graph = generators::classic::balanced_tree(2, 4, directed);
+            let tree = TreeLca::build(&graph, 0).unwrap();
+            assert_eq!(tree.query(15, 18).unwrap(), 3);
+            assert_eq!(tree.query(15, 30).unwrap(), 0);
+            assert_eq!(tree.query(22, 10).unwrap(), 10);
+            assert_eq!(tree.query(7, 7).unwrap(), 7);
+            assert_eq!(tree.get_depth(0).unwrap(), 0);
+            assert_eq!(tree.get_depth(30).unwrap(), 4);
+        }
+        // an undirected tree can be rooted at any node
+        let graph = generators::classic::balanced_tree(2, 4, false);
+        let tree = TreeLca::build(&graph, 15).unwrap();
+        assert_eq!(tree.query(18, 30).unwrap(), 3);
+        assert_eq!(tree.query(0, 16).unwrap(), 7);
+        assert_eq!(tree.get_depth(0).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_tree_lca_2() {
+        for seed in 0..5 {
+            let parents = get_random_parents(200, seed);
+            let edges = (1..200).map(|i| Edge::new(parents[i], i)).collect();
+            let graph: Graph<usize, ()> = Graph::new_from_nodes_and_edges(
+                vec![],
+                edges,
+                GraphSpecs::directed_create_missing(),
+            )
+            .unwrap();
+            let tree = TreeLca::build(&graph, 0).unwrap();
+            for u in (0..200).step_by(7) {
+                for v in (0..200).step_by(3) {
+                    assert_eq!(tree.query(u, v).unwrap(), get_lca_naively(&parents, u, v));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_tree_lca_3() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected());
+        graph.add_node(Node::from_name(0));
+        let tree = TreeLca::build(&graph, 0).unwrap();
+        assert_eq!(tree.query(0, 0).unwrap(), 0);
+        let result = tree.query(0, 1);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = TreeLca::build(&graph, 1);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_tree_lca_errors() {
+        let graph = generators::classic::cycle_graph(5, false);
+        let result = TreeLca::build(&graph, 0);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        // the edges must point away from the root
+        let graph = generators::classic::balanced_tree(2, 3, true);
+        let result = TreeLca::build(&graph, 1);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let mut graph = generators::classic::path_graph(4, false);
+        graph.add_node(Node::from_name(4));
+        let result = TreeLca::build(&graph, 0);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+}
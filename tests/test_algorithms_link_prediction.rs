@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::link_prediction, generators, Edge, ErrorKind, Graph, GraphSpecs};
+
+    fn get_graph() -> Graph<i32, ()> {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::new(0, 1),
+                Edge::new(0, 2),
+                Edge::new(0, 3),
+                Edge::new(1, 2),
+                Edge::new(1, 4),
+                Edge::new(3, 4),
+                Edge::new(4, 5),
+            ])
+            .unwrap();
+        graph
+    }
+
+    fn assert_scores(actual: Vec<(i32, i32, f64)>, expected: Vec<(i32, i32, f64)>) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert_eq!((a.0, a.1), (e.0, e.1));
+            assert!((a.2 - e.2).abs() < 1e-10, "{:?} != {:?}", a, e);
+        }
+    }
+
+    #[test]
+    fn test_jaccard_coefficient_1() {
+        let graph = get_graph();
+        let result = link_prediction::jaccard_coefficient(
+            &graph,
+            Some(&[(0, 4), (2, 3), (2, 5), (5, 5)]),
+            None,
+        )
+        .unwrap()
+        .collect();
+        assert_scores(
+            result,
+            vec![
+                (0, 4, 2.0 / 4.0),
+                (2, 3, 1.0 / 3.0),
+                (2, 5, 0.0),
+                (5, 5, 1.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_jaccard_coefficient_2() {
+        let graph = get_graph();
+        let result: Vec<(i32, i32, f64)> =
+            link_prediction::jaccard_coefficient(&graph, None, None).unwrap().collect();
+        let pairs: Vec<(i32, i32)> = result.iter().map(|(u, v, _s)| (*u, *v)).collect();
+        assert_eq!(
+            pairs,
+            vec![
+                (0, 4),
+                (0, 5),
+                (1, 3),
+                (1, 5),
+                (2, 3),
+                (2, 4),
+                (2, 5),
+                (3, 5)
+            ]
+        );
+        let result: Vec<(i32, i32, f64)> =
+            link_prediction::jaccard_coefficient(&graph, None, Some(2)).unwrap().collect();
+        let pairs: Vec<(i32, i32)> = result.iter().map(|(u, v, _s)| (*u, *v)).collect();
+        assert_eq!(pairs, vec![(2, 3), (2, 5), (3, 5)]);
+    }
+
+    #[test]
+    fn test_adamic_adar_index_1() {
+        let graph = get_graph();
+        let result =
+            link_prediction::adamic_adar_index(&graph, Some(&[(0, 4), (2, 3), (3, 5)]), None)
+                .unwrap()
+                .collect();
+        assert_scores(
+            result,
+            vec![
+                (0, 4, 1.0 / 3.0_f64.ln() + 1.0 / 2.0_f64.ln()),
+                (2, 3, 1.0 / 3.0_f64.ln()),
+                (3, 5, 1.0 / 3.0_f64.ln()),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_preferential_attachment_1() {
+        let graph = get_graph();
+        let result =
+            link_prediction::preferential_attachment(&graph, Some(&[(0, 4), (1, 5), (4, 5)]), None)
+                .unwrap()
+                .collect();
+        assert_scores(result, vec![(0, 4, 9.0), (1, 5, 3.0), (4, 5, 3.0)]);
+    }
+
+    #[test]
+    fn test_resource_allocation_index_1() {
+        let graph = get_graph();
+        let result =
+            link_prediction::resource_allocation_index(&graph, Some(&[(0, 4), (2, 5)]), None)
+                .unwrap()
+                .collect();
+        assert_scores(result, vec![(0, 4, 1.0 / 3.0 + 1.0 / 2.0), (2, 5, 0.0)]);
+    }
+
+    #[test]
+    fn test_resource_allocation_index_2() {
+        let graph = generators::social::karate_club_graph();
+        let result: Vec<(i32, i32, f64)> =
+            link_prediction::resource_allocation_index(&graph, None, None).unwrap().collect();
+        assert_eq!(result.len(), 34 * 33 / 2 - 78);
+        let best = result.iter().max_by(|a, b| a.2.partial_cmp(&b.2).unwrap()).unwrap();
+        assert_eq!((best.0, best.1), (2, 33));
+        assert!((best.2 - 1.5666666666666667).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_link_prediction_errors() {
+        let graph = generators::classic::path_graph(3, true);
+        let result = link_prediction::jaccard_coefficient(&graph, None, None);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let graph = get_graph();
+        let result = link_prediction::adamic_adar_index(&graph, Some(&[(0, 9)]), None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::matching, generators, Edge, ErrorKind, Graph, GraphSpecs, Node};
+    use std::collections::HashMap;
+
+    fn get_graph() -> Graph<&'static str, ()> {
+        let edges = vec![
+            ("l0", "r0"),
+            ("l0", "r5"),
+            ("l0", "r6"),
+            ("l1", "r1"),
+            ("l1", "r2"),
+            ("l2", "r1"),
+            ("l3", "r0"),
+            ("l3", "r4"),
+            ("l5", "r2"),
+            ("l5", "r3"),
+            ("l5", "r4"),
+            ("l7", "r6"),
+        ];
+        let mut graph = Graph::new(GraphSpecs::undirected_create_missing());
+        graph.add_edge_tuples(edges).unwrap();
+        graph.add_nodes(vec![Node::from_name("l4"), Node::from_name("l6")]);
+        graph
+    }
+
+    #[test]
+    fn test_hopcroft_karp_1() {
+        let graph = get_graph();
+        let left_nodes = vec!["l0", "l1", "l2", "l3", "l4", "l5", "l6", "l7"];
+        let result = matching::hopcroft_karp(&graph, &left_nodes).unwrap();
+        assert_eq!(result.len(), 12);
+        for (u, v) in result.iter() {
+            assert_eq!(result.get(v).unwrap(), u);
+            assert!(graph.get_edge(u, v).is_ok());
+        }
+        assert_eq!(result.get("l2"), Some(&"r1"));
+        assert_eq!(result.get("l1"), Some(&"r2"));
+        assert_eq!(result.get("l7"), Some(&"r6"));
+        assert!(matching::is_maximal_matching(&graph, &result).unwrap());
+        assert!(!matching::is_perfect_matching(&graph, &result).unwrap());
+    }
+
+    #[test]
+    fn test_hopcroft_karp_2() {
+        // the right nodes can be given as the left nodes
+        let graph = get_graph();
+        let left_nodes = vec!["r0", "r1", "r2", "r3", "r4", "r5", "r6"];
+        let result = matching::hopcroft_karp(&graph, &left_nodes).unwrap();
+        assert_eq!(result.len(), 12);
+    }
+
+    #[test]
+    fn test_hopcroft_karp_3() {
+        // complete bipartite graph
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        for u in 0..4 {
+            for v in 4..8 {
+                graph.add_edge(Edge::new(u, v)).unwrap();
+            }
+        }
+        let result = matching::hopcroft_karp(&graph, &[0, 1, 2, 3]).unwrap();
+        assert_eq!(result.len(), 8);
+        assert!(matching::is_perfect_matching(&graph, &result).unwrap());
+        assert!(matching::is_maximal_matching(&graph, &result).unwrap());
+    }
+
+    #[test]
+    fn test_hopcroft_karp_4() {
+        // errors
+        let graph = get_graph();
+        let result = matching::hopcroft_karp(&graph, &["l0", "r0"]);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = matching::hopcroft_karp(&graph, &["l8"]);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edge(Edge::new("l0", "r0")).unwrap();
+        let result = matching::hopcroft_karp(&graph, &["l0"]);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_is_maximal_matching() {
+        let graph = get_graph();
+        let matching: HashMap<&str, &str> = vec![("l0", "r0"), ("r1", "l1")].into_iter().collect();
+        assert!(!matching::is_maximal_matching(&graph, &matching).unwrap());
+        let matching: HashMap<&str, &str> = vec![
+            ("l0", "r0"),
+            ("l1", "r1"),
+            ("l3", "r4"),
+            ("l5", "r2"),
+            ("l7", "r6"),
+        ]
+        .into_iter()
+        .collect();
+        assert!(matching::is_maximal_matching(&graph, &matching).unwrap());
+        // not an edge
+        let matching: HashMap<&str, &str> = vec![("l0", "r1")].into_iter().collect();
+        assert!(!matching::is_maximal_matching(&graph, &matching).unwrap());
+        // not a matching
+        let matching: HashMap<&str, &str> = vec![("l0", "r0"), ("r0", "l3")].into_iter().collect();
+        assert!(!matching::is_maximal_matching(&graph, &matching).unwrap());
+        // node not in graph
+        let matching: HashMap<&str, &str> = vec![("l0", "r9")].into_iter().collect();
+        let result = matching::is_maximal_matching(&graph, &matching);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_is_perfect_matching() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        graph.add_edge_tuples(vec![(1, 2), (2, 3), (3, 4), (4, 1)]).unwrap();
+        let matching: HashMap<i32, i32> = vec![(1, 2), (2, 1), (4, 3)].into_iter().collect();
+        assert!(matching::is_perfect_matching(&graph, &matching).unwrap());
+        let matching: HashMap<i32, i32> = vec![(1, 2), (2, 3)].into_iter().collect();
+        assert!(!matching::is_perfect_matching(&graph, &matching).unwrap());
+        let matching: HashMap<i32, i32> = vec![(1, 3), (2, 4)].into_iter().collect();
+        assert!(!matching::is_perfect_matching(&graph, &matching).unwrap());
+    }
+
+    /// Returns the (number of edges, weight) of the best matching, by trying them all.
+    fn get_best_matching_brute_force(
+        num_nodes: usize,
+        edges: &[(usize, usize, f64)],
+        max_cardinality: bool,
+    ) -> (usize, f64) {
+        fn search(
+            edges: &[(usize, usize, f64)],
+            used: &mut Vec<bool>,
+            index: usize,
+            max_cardinality: bool,
+        ) -> (usize, f64) {
+            if index == edges.len() {
+                return (0, 0.0);
+            }
+            let mut best = search(edges, used, index + 1, max_cardinality);
+            let (u, v, w) = edges[index];
+            if !used[u] && !used[v] {
+                used[u] = true;
+                used[v] = true;
+                let (count, weight) = search(edges, used, index + 1, max_cardinality);
+                let candidate = (count + 1, weight + w);
+                let better = match max_cardinality {
+                    true => (candidate.0, candidate.1) > (best.0, best.1),
+                    false => candidate.1 > best.1,
+                };
+                if better {
+                    best = candidate;
+                }
+                used[u] = false;
+                used[v] = false;
+            }
+            best
+        }
+        search(edges, &mut vec![false; num_nodes], 0, max_cardinality)
+    }
+
+    #[test]
+    fn test_max_weight_matching_1() {
+        for seed in 0..40 {
+            let graph = generators::random::gnp_random_graph(10, 0.4, Some(seed), false).unwrap();
+            let mut weighted: Graph<usize, ()> = Graph::new(GraphSpecs::undirected());
+            weighted.add_nodes(graph.get_all_nodes().into_iter().cloned().collect());
+            let edges: Vec<(usize, usize, f64)> = graph
+                .get_all_edges()
+                .into_iter()
+                .map(|e| (e.u, e.v, ((e.u * 7 + e.v * 13 + seed as usize) % 11) as f64))
+                .collect();
+            weighted
+                .add_edges(edges.iter().map(|(u, v, w)| Edge::with_weight(*u, *v, *w)).collect())
+                .unwrap();
+            for max_cardinality in [false, true].iter() {
+                let matching =
+                    matching::max_weight_matching(&weighted, true, *max_cardinality).unwrap();
+                let pairs: Vec<(usize, usize)> =
+                    matching.iter().filter(|(u, v)| u < v).map(|(u, v)| (*u, *v)).collect();
+                assert_eq!(pairs.len() * 2, matching.len());
+                let weight: f64 =
+                    pairs.iter().map(|(u, v)| weighted.get_edge(*u, *v).unwrap().weight).sum();
+                let expected = get_best_matching_brute_force(10, &edges, *max_cardinality);
+                assert_eq!(weight, expected.1);
+                if *max_cardinality {
+                    assert_eq!(pairs.len(), expected.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_weight_matching_2() {
+        // an odd cycle, which the algorithm shrinks into a blossom
+        let graph = generators::classic::cycle_graph(5, false);
+        let matching = matching::max_weight_matching(&graph, false, false).unwrap();
+        assert_eq!(matching.len(), 4);
+        assert!(matching::is_maximal_matching(&graph, &matching).unwrap());
+        let graph = generators::classic::complete_graph(6, false);
+        let matching = matching::max_weight_matching(&graph, false, false).unwrap();
+        assert!(matching::is_perfect_matching(&graph, &matching).unwrap());
+        let graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected());
+        assert!(matching::max_weight_matching(&graph, false, false).unwrap().is_empty());
+        let graph = generators::classic::path_graph(3, true);
+        let result = matching::max_weight_matching(&graph, false, false);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::partition, generators, Edge, ErrorKind, Graph, GraphSpecs};
+    use std::collections::HashMap;
+
+    fn get_part_sizes(parts: &HashMap<i32, usize>, num_parts: usize) -> Vec<usize> {
+        (0..num_parts).map(|p| parts.values().filter(|v| **v == p).count()).collect()
+    }
+
+    fn get_cut_size(graph: &Graph<i32, ()>, parts: &HashMap<i32, usize>) -> f64 {
+        graph.get_all_edges().iter().filter(|e| parts[&e.u] != parts[&e.v]).count() as f64
+    }
+
+    /// Returns `num_cliques` complete graphs of `size` nodes, joined in a ring by single
+    /// edges of weight `1.0`; the edges within the cliques have weight `2.0`.
+    fn get_ring_of_cliques(num_cliques: i32, size: i32) -> Graph<i32, ()> {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        for c in 0..num_cliques {
+            for u in 0..size {
+                for v in (u + 1)..size {
+                    graph.add_edge(Edge::with_weight(c * size + u, c * size + v, 2.0)).unwrap();
+                }
+            }
+            let next = ((c + 1) % num_cliques) * size;
+            graph.add_edge(Edge::with_weight(c * size, next + 1, 1.0)).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn test_kernighan_lin_bisection_1() {
+        let graph = generators::social::karate_club_graph();
+        let initial = partition::kernighan_lin_bisection(&graph, false, Some(0), Some(1)).unwrap();
+        let result = partition::kernighan_lin_bisection(&graph, false, None, Some(1)).unwrap();
+        assert_eq!(get_part_sizes(&result.parts, 2), vec![17, 17]);
+        assert!(result.cut_value < initial.cut_value);
+        let cut_value = get_cut_size(&graph, &result.parts);
+        assert_eq!(result.cut_value, cut_value);
+    }
+
+    #[test]
+    fn test_kernighan_lin_bisection_2() {
+        // odd number of nodes, weighted
+        let mut graph = get_ring_of_cliques(2, 5);
+        graph.add_edge(Edge::with_weight(10, 0, 1.0)).unwrap();
+        let result = partition::kernighan_lin_bisection(&graph, true, None, Some(2)).unwrap();
+        let mut sizes = get_part_sizes(&result.parts, 2);
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![5, 6]);
+        assert_eq!(result.cut_value, 2.0);
+        assert_eq!(result.parts[&0], result.parts[&4]);
+        assert_eq!(result.parts[&5], result.parts[&9]);
+    }
+
+    #[test]
+    fn test_spectral_partition_1() {
+        let graph = get_ring_of_cliques(6, 5);
+        let result = partition::spectral_partition(&graph, 3, true, Some(1)).unwrap();
+        assert_eq!(get_part_sizes(&result.parts, 3), vec![10, 10, 10]);
+        assert_eq!(result.cut_value, 3.0);
+        for c in 0..6 {
+            for v in 1..5 {
+                assert_eq!(result.parts[&(c * 5)], result.parts[&(c * 5 + v)]);
+            }
+        }
+        let result = partition::spectral_partition(&graph, 6, false, Some(1)).unwrap();
+        assert_eq!(get_part_sizes(&result.parts, 6), vec![5; 6]);
+        assert_eq!(result.cut_value, 6.0);
+    }
+
+    #[test]
+    fn test_spectral_partition_2() {
+        // parts whose sizes differ by one, and a single part
+        let graph = generators::social::karate_club_graph();
+        let result = partition::spectral_partition(&graph, 5, false, Some(1)).unwrap();
+        let mut sizes = get_part_sizes(&result.parts, 5);
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![6, 7, 7, 7, 7]);
+        assert_eq!(result.cut_value, get_cut_size(&graph, &result.parts));
+        let result = partition::spectral_partition(&graph, 1, false, Some(1)).unwrap();
+        assert!(result.parts.values().all(|p| *p == 0));
+        assert_eq!(result.cut_value, 0.0);
+        let result = partition::spectral_partition(&graph, 34, false, Some(1)).unwrap();
+        assert_eq!(get_part_sizes(&result.parts, 34), vec![1; 34]);
+        assert_eq!(result.cut_value, 78.0);
+    }
+
+    #[test]
+    fn test_spectral_partition_3() {
+        // disconnected: the components become the parts
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        for c in 0..4 {
+            graph
+                .add_edges(vec![
+                    Edge::new(c * 3, c * 3 + 1),
+                    Edge::new(c * 3 + 1, c * 3 + 2),
+                    Edge::new(c * 3 + 2, c * 3),
+                ])
+                .unwrap();
+        }
+        let result = partition::spectral_partition(&graph, 4, false, Some(1)).unwrap();
+        assert_eq!(result.cut_value, 0.0);
+    }
+
+    #[test]
+    fn test_partition_errors() {
+        let graph = generators::classic::path_graph(4, true);
+        let result = partition::kernighan_lin_bisection(&graph, false, None, None);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let graph = generators::classic::path_graph(4, false);
+        let result = partition::spectral_partition(&graph, 0, false, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = partition::spectral_partition(&graph, 5, false, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = partition::spectral_partition(&graph, 2, true, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+    }
+}
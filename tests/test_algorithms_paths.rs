@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::{paths, shortest_path::dijkstra},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+
+    #[test]
+    fn test_all_simple_paths_directed() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::new(0, 1),
+                Edge::new(0, 2),
+                Edge::new(1, 2),
+                Edge::new(2, 1),
+                Edge::new(1, 3),
+                Edge::new(2, 3),
+                Edge::new(3, 0),
+            ])
+            .unwrap();
+        let all: Vec<Vec<i32>> = paths::all_simple_paths(&graph, 0, 3, None).unwrap().collect();
+        assert_eq!(
+            all,
+            vec![
+                vec![0, 1, 2, 3],
+                vec![0, 1, 3],
+                vec![0, 2, 1, 3],
+                vec![0, 2, 3]
+            ]
+        );
+        let all: Vec<Vec<i32>> = paths::all_simple_paths(&graph, 3, 1, None).unwrap().collect();
+        assert_eq!(all, vec![vec![3, 0, 1], vec![3, 0, 2, 1]]);
+    }
+
+    #[test]
+    fn test_all_simple_paths_cutoff() {
+        // in a complete graph there are (n - 2)! / (n - 2 - k)! paths with k middle nodes
+        let graph = generators::classic::complete_graph(6, false);
+        let count = |cutoff| paths::all_simple_paths(&graph, 0, 5, cutoff).unwrap().count();
+        assert_eq!(count(Some(0)), 0);
+        assert_eq!(count(Some(1)), 1);
+        assert_eq!(count(Some(2)), 1 + 4);
+        assert_eq!(count(Some(3)), 1 + 4 + 12);
+        assert_eq!(count(None), 1 + 4 + 12 + 24 + 24);
+        assert!(paths::all_simple_paths(&graph, 0, 5, Some(3))
+            .unwrap()
+            .all(|p| p.len() <= 4 && p[0] == 0 && p[p.len() - 1] == 5));
+    }
+
+    #[test]
+    fn test_all_simple_paths_include_shortest_path() {
+        let graph = generators::social::karate_club_graph();
+        let shortest = dijkstra::single_source(&graph, false, 0, Some(29), None, None, false)
+            .unwrap()
+            .remove(&29)
+            .unwrap();
+        let cutoff = shortest.distance as usize;
+        let all: Vec<Vec<i32>> =
+            paths::all_simple_paths(&graph, 0, 29, Some(cutoff)).unwrap().collect();
+        assert_eq!(all.len(), shortest.paths.len());
+        for path in shortest.paths {
+            assert!(all.contains(&path));
+        }
+    }
+
+    #[test]
+    fn test_all_simple_paths_no_paths() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::new(0, 1), Edge::new(2, 1)]).unwrap();
+        assert_eq!(
+            paths::all_simple_paths(&graph, 1, 0, None).unwrap().count(),
+            0
+        );
+        assert_eq!(
+            paths::all_simple_paths(&graph, 0, 2, None).unwrap().count(),
+            0
+        );
+        assert_eq!(
+            paths::all_simple_paths(&graph, 0, 0, None).unwrap().count(),
+            0
+        );
+        let result = paths::all_simple_paths(&graph, 0, 9, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = paths::all_simple_edge_paths(&graph, 9, 0, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_all_simple_paths_multigraph() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::new(0, 1),
+                Edge::new(0, 1),
+                Edge::new(1, 2),
+                Edge::new(1, 2),
+                Edge::new(0, 2),
+            ])
+            .unwrap();
+        let all: Vec<Vec<i32>> = paths::all_simple_paths(&graph, 0, 2, None).unwrap().collect();
+        assert_eq!(all, vec![vec![0, 1, 2], vec![0, 2]]);
+        let all: Vec<Vec<(i32, i32, usize)>> =
+            paths::all_simple_edge_paths(&graph, 0, 2, None).unwrap().collect();
+        assert_eq!(
+            all,
+            vec![
+                vec![(0, 1, 0), (1, 2, 0)],
+                vec![(0, 1, 0), (1, 2, 1)],
+                vec![(0, 1, 1), (1, 2, 0)],
+                vec![(0, 1, 1), (1, 2, 1)],
+                vec![(0, 2, 0)],
+            ]
+        );
+        let all: Vec<Vec<(i32, i32, usize)>> =
+            paths::all_simple_edge_paths(&graph, 0, 2, Some(1)).unwrap().collect();
+        assert_eq!(all, vec![vec![(0, 2, 0)]]);
+    }
+
+    #[test]
+    fn test_all_simple_edge_paths_keys() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::undirected_create_missing()
+        });
+        graph.add_edges(vec![Edge::new(1, 0), Edge::new(2, 1)]).unwrap();
+        graph.add_edge_with_key(Edge::new(0, 1), 5).unwrap();
+        graph.remove_edge_by_key(1, 0, 0).unwrap();
+        let all: Vec<Vec<(i32, i32, usize)>> =
+            paths::all_simple_edge_paths(&graph, 2, 0, None).unwrap().collect();
+        assert_eq!(all, vec![vec![(2, 1, 0), (1, 0, 5)]]);
+    }
+
+    #[test]
+    fn test_all_simple_edge_paths_not_multi() {
+        let graph = generators::classic::path_graph(4, false);
+        let all: Vec<Vec<(i32, i32, usize)>> =
+            paths::all_simple_edge_paths(&graph, 3, 0, None).unwrap().collect();
+        assert_eq!(all, vec![vec![(3, 2, 0), (2, 1, 0), (1, 0, 0)]]);
+    }
+}
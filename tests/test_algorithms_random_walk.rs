@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::random_walk, generators, Edge, ErrorKind, Graph, GraphSpecs};
+
+    fn assert_valid_walks(graph: &Graph<i32, ()>, walks: &[Vec<i32>]) {
+        for walk in walks {
+            for pair in walk.windows(2) {
+                assert!(graph.get_edge(pair[0], pair[1]).is_ok());
+            }
+        }
+    }
+
+    /// Returns the fraction of steps that go straight back to the previous node.
+    fn get_return_fraction(walks: &[Vec<i32>]) -> f64 {
+        let triples: Vec<&[i32]> = walks.iter().flat_map(|w| w.windows(3)).collect();
+        triples.iter().filter(|t| t[0] == t[2]).count() as f64 / triples.len() as f64
+    }
+
+    #[test]
+    fn test_uniform_walks_1() {
+        let graph = generators::social::karate_club_graph();
+        let starts: Vec<i32> = (0..34).chain(0..34).collect();
+        let walks = random_walk::uniform_walks(&graph, &starts, 15, false, Some(1)).unwrap();
+        assert_eq!(walks.len(), 68);
+        for (walk, start) in walks.iter().zip(starts.iter()) {
+            assert_eq!(walk.len(), 15);
+            assert_eq!(walk[0], *start);
+        }
+        assert_valid_walks(&graph, &walks);
+        let again = random_walk::uniform_walks(&graph, &starts, 15, false, Some(1)).unwrap();
+        assert_eq!(walks, again);
+        let other = random_walk::uniform_walks(&graph, &starts, 15, false, Some(2)).unwrap();
+        assert_ne!(walks, other);
+    }
+
+    #[test]
+    fn test_uniform_walks_2() {
+        let graph = generators::classic::path_graph(4, true);
+        let walks = random_walk::uniform_walks(&graph, &[0, 2, 3], 10, false, None).unwrap();
+        assert_eq!(walks, vec![vec![0, 1, 2, 3], vec![2, 3], vec![3]]);
+        let walks = random_walk::uniform_walks(&graph, &[0], 0, false, None).unwrap();
+        assert_eq!(walks, vec![Vec::<i32>::new()]);
+        let walks = random_walk::uniform_walks(&graph, &[], 10, false, None).unwrap();
+        assert!(walks.is_empty());
+    }
+
+    #[test]
+    fn test_uniform_walks_3() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight(0, 1, 9.0),
+                Edge::with_weight(0, 2, 1.0),
+                Edge::with_weight(0, 3, 0.0),
+                Edge::with_weight(1, 0, 1.0),
+                Edge::with_weight(2, 0, 1.0),
+                Edge::with_weight(3, 0, 1.0),
+            ])
+            .unwrap();
+        let walks = random_walk::uniform_walks(&graph, &[0; 100], 21, true, Some(3)).unwrap();
+        let steps: Vec<i32> =
+            walks.iter().flat_map(|w| w.iter().skip(1).step_by(2)).cloned().collect();
+        assert_eq!(steps.len(), 1000);
+        assert!(!steps.contains(&3));
+        let ones = steps.iter().filter(|n| **n == 1).count();
+        assert!(ones > 850 && ones < 950);
+        let walks = random_walk::uniform_walks(&graph, &[0; 100], 21, false, Some(3)).unwrap();
+        assert!(walks.iter().flatten().any(|n| *n == 3));
+    }
+
+    #[test]
+    fn test_biased_walks_1() {
+        let graph = generators::social::karate_club_graph();
+        let starts: Vec<i32> = (0..34).collect();
+        let walks =
+            random_walk::biased_walks(&graph, &starts, 15, 1.0, 1.0, false, Some(5)).unwrap();
+        let uniform = random_walk::uniform_walks(&graph, &starts, 15, false, Some(5)).unwrap();
+        assert_eq!(walks, uniform);
+        let walks =
+            random_walk::biased_walks(&graph, &starts, 15, 0.5, 2.0, false, Some(5)).unwrap();
+        assert_valid_walks(&graph, &walks);
+        let again =
+            random_walk::biased_walks(&graph, &starts, 15, 0.5, 2.0, false, Some(5)).unwrap();
+        assert_eq!(walks, again);
+    }
+
+    #[test]
+    fn test_biased_walks_2() {
+        let graph = generators::classic::cycle_graph(20, false);
+        let starts: Vec<i32> = (0..20).collect();
+        let walks =
+            random_walk::biased_walks(&graph, &starts, 20, 0.01, 1.0, false, Some(1)).unwrap();
+        assert!(get_return_fraction(&walks) > 0.95);
+        let walks =
+            random_walk::biased_walks(&graph, &starts, 20, 100.0, 1.0, false, Some(1)).unwrap();
+        assert!(get_return_fraction(&walks) < 0.05);
+        let walks = random_walk::uniform_walks(&graph, &starts, 20, false, Some(1)).unwrap();
+        let fraction = get_return_fraction(&walks);
+        assert!(fraction > 0.4 && fraction < 0.6);
+    }
+
+    #[test]
+    fn test_random_walk_errors() {
+        let graph = generators::classic::cycle_graph(5, false);
+        let result = random_walk::uniform_walks(&graph, &[0, 5], 5, false, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = random_walk::uniform_walks(&graph, &[0], 5, true, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+        let result = random_walk::biased_walks(&graph, &[0], 5, 0.0, 1.0, false, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = random_walk::biased_walks(&graph, &[0], 5, 1.0, f64::NAN, false, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight(0, 1, 1.0),
+                Edge::with_weight(1, 2, -1.0),
+            ])
+            .unwrap();
+        let result = random_walk::uniform_walks(&graph, &[0], 5, true, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+}
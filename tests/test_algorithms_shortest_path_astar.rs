@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::shortest_path::{astar, dijkstra},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+
+    // nodes are named `x * size + y`
+    fn get_grid_graph(size: i32) -> Graph<i32, ()> {
+        let mut graph = Graph::new(GraphSpecs::undirected_create_missing());
+        for x in 0..size {
+            for y in 0..size {
+                let n = x * size + y;
+                if x + 1 < size {
+                    graph.add_edge(Edge::with_weight(n, n + size, 1.0)).unwrap();
+                }
+                if y + 1 < size {
+                    graph.add_edge(Edge::with_weight(n, n + 1, 1.0)).unwrap();
+                }
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn test_single_source_target_1() {
+        let graph = get_grid_graph(10);
+        let heuristic = |n: i32| ((9 - n / 10).abs() + (9 - n % 10).abs()) as f64;
+        let result = astar::single_source_target(&graph, 0, 99, heuristic).unwrap().unwrap();
+        assert_eq!(result.distance, 18.0);
+        assert_eq!(result.paths.len(), 1);
+        assert_eq!(result.paths[0].len(), 19);
+        assert_eq!(result.paths[0][0], 0);
+        assert_eq!(result.paths[0][18], 99);
+    }
+
+    #[test]
+    fn test_single_source_target_2() {
+        // a zero heuristic gives the same distances as Dijkstra
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n3", 5.0),
+            Edge::with_weight("n1", "n4", 2.0),
+            Edge::with_weight("n4", "n3", 3.0),
+            Edge::with_weight("n1", "n5", 9.0),
+            Edge::with_weight("n3", "n5", 1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = astar::single_source_target(&graph, "n1", "n5", |_| 0.0).unwrap().unwrap();
+        let expected =
+            dijkstra::single_source(&graph, true, "n1", Some("n5"), None, None, false).unwrap();
+        assert_eq!(result.distance, expected.get("n5").unwrap().distance);
+        assert_eq!(result.paths, vec![vec!["n1", "n4", "n3", "n5"]]);
+    }
+
+    #[test]
+    fn test_single_source_target_unreachable() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n3", "n2", 1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = astar::single_source_target(&graph, "n1", "n3", |_| 0.0).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_single_source_target_multi_edges() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 5.0),
+                Edge::with_weight("n1", "n2", 2.0),
+                Edge::with_weight("n2", "n3", 1.0),
+            ])
+            .unwrap();
+        let result = astar::single_source_target(&graph, "n1", "n3", |_| 0.0).unwrap().unwrap();
+        assert_eq!(result.distance, 3.0);
+        assert_eq!(result.edge_keys, Some(vec![vec![1, 0]]));
+    }
+
+    #[test]
+    fn test_single_source_target_integer_weights() {
+        // nodes are named `x * 10 + y`
+        let mut graph: Graph<i32, (), u32> =
+            Graph::new_with_weight_type(GraphSpecs::undirected_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight(0, 1, 1),
+                Edge::with_weight(1, 11, 3),
+                Edge::with_weight(0, 10, 2),
+                Edge::with_weight(10, 11, 1),
+            ])
+            .unwrap();
+        let heuristic = |n: i32| ((1 - n / 10).abs() + (1 - n % 10).abs()) as u32;
+        let result = astar::single_source_target(&graph, 0, 11, heuristic).unwrap().unwrap();
+        assert_eq!(result.distance, 3);
+        assert_eq!(result.paths, vec![vec![0, 10, 11]]);
+    }
+
+    #[test]
+    fn test_single_source_target_errors() {
+        let graph = generators::social::karate_club_graph();
+        let result = astar::single_source_target(&graph, 0, 33, |_| 0.0);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+        let graph = graph.set_all_edge_weights(1.0);
+        let result = astar::single_source_target(&graph, 0, 99, |_| 0.0);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+}
@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::shortest_path::{bellman_ford, dijkstra},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+
+    #[test]
+    fn test_single_source_1() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 4.0),
+            Edge::with_weight("n1", "n3", 2.0),
+            Edge::with_weight("n2", "n4", -3.0),
+            Edge::with_weight("n3", "n4", 1.0),
+            Edge::with_weight("n4", "n5", 2.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = bellman_ford::single_source(&graph, true, "n1", None, false).unwrap();
+        assert_eq!(result.len(), 5);
+        assert_eq!(result.get("n1").unwrap().distance, 0.0);
+        assert_eq!(result.get("n1").unwrap().paths, vec![vec!["n1"]]);
+        assert_eq!(result.get("n4").unwrap().distance, 1.0);
+        assert_eq!(
+            result.get("n4").unwrap().paths,
+            vec![vec!["n1", "n2", "n4"]]
+        );
+        assert_eq!(result.get("n5").unwrap().distance, 3.0);
+        assert_eq!(
+            result.get("n5").unwrap().paths,
+            vec![vec!["n1", "n2", "n4", "n5"]]
+        );
+    }
+
+    #[test]
+    fn test_single_source_2() {
+        // equal-cost paths are all returned
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n1", "n3", 3.0),
+            Edge::with_weight("n2", "n4", 1.0),
+            Edge::with_weight("n3", "n4", -1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = bellman_ford::single_source(&graph, true, "n1", Some("n4"), false).unwrap();
+        assert_eq!(result.len(), 1);
+        let info = result.get("n4").unwrap();
+        assert_eq!(info.distance, 2.0);
+        let mut paths = info.paths.clone();
+        paths.sort();
+        assert_eq!(paths, vec![vec!["n1", "n2", "n4"], vec!["n1", "n3", "n4"]]);
+        let result = bellman_ford::single_source(&graph, true, "n1", Some("n4"), true).unwrap();
+        assert_eq!(result.get("n4").unwrap().paths.len(), 1);
+    }
+
+    #[test]
+    fn test_single_source_negative_cycle() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n3", 1.0),
+            Edge::with_weight("n3", "n4", -3.0),
+            Edge::with_weight("n4", "n2", 1.0),
+            Edge::with_weight("n5", "n1", 1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = bellman_ford::single_source(&graph, true, "n1", None, false);
+        let err = result.err().unwrap();
+        assert!(matches!(err.kind, ErrorKind::NegativeCycle));
+        assert!(err.message.contains("n2"));
+        assert!(err.message.contains("n3"));
+        assert!(err.message.contains("n4"));
+        assert!(!err.message.contains("n1"));
+    }
+
+    #[test]
+    fn test_single_source_unreachable_negative_cycle() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n3", "n4", -3.0),
+            Edge::with_weight("n4", "n3", 1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = bellman_ford::single_source(&graph, true, "n1", None, false).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result.get("n2").unwrap().distance, 1.0);
+    }
+
+    #[test]
+    fn test_single_source_undirected_negative_edge() {
+        let edges = vec![Edge::with_weight("n1", "n2", -1.0)];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let result = bellman_ford::single_source(&graph, true, "n1", None, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NegativeCycle
+        ));
+    }
+
+    #[test]
+    fn test_single_source_zero_weight_cycle_at_source() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 0.0),
+            Edge::with_weight("n2", "n3", 1.0),
+            Edge::with_weight("n1", "n4", 2.0),
+        ];
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(
+            vec![],
+            edges,
+            GraphSpecs::undirected_create_missing(),
+        )
+        .unwrap();
+        let result = bellman_ford::single_source(&graph, true, "n1", None, false).unwrap();
+        assert_eq!(result.get("n1").unwrap().paths, vec![vec!["n1"]]);
+        assert_eq!(result.get("n2").unwrap().paths, vec![vec!["n1", "n2"]]);
+        assert_eq!(result.get("n3").unwrap().paths, vec![vec!["n1", "n2", "n3"]]);
+        assert_eq!(result.get("n4").unwrap().paths, vec![vec!["n1", "n4"]]);
+        let edges = vec![
+            Edge::with_weight("s", "a", 0.0),
+            Edge::with_weight("a", "s", 0.0),
+            Edge::with_weight("a", "b", 1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = bellman_ford::single_source(&graph, true, "s", None, false).unwrap();
+        assert_eq!(result.get("s").unwrap().paths, vec![vec!["s"]]);
+        assert_eq!(result.get("a").unwrap().paths, vec![vec!["s", "a"]]);
+        assert_eq!(result.get("b").unwrap().paths, vec![vec!["s", "a", "b"]]);
+    }
+
+    #[test]
+    fn test_single_source_node_not_found() {
+        let graph = generators::social::karate_club_graph();
+        let result = bellman_ford::single_source(&graph, false, 99, None, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_all_pairs_matches_dijkstra() {
+        let graph = generators::social::karate_club_graph();
+        let bf = bellman_ford::all_pairs(&graph, false, false).unwrap();
+        let dj = dijkstra::all_pairs(&graph, false, None, None, false).unwrap();
+        assert_eq!(bf.len(), dj.len());
+        for (source, targets) in dj {
+            for (target, info) in targets {
+                let bf_info = bf.get(&source).unwrap().get(&target).unwrap();
+                assert_eq!(bf_info.distance, info.distance);
+                assert_eq!(bf_info.paths.len(), info.paths.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_pairs_negative_cycle() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n1", -2.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = bellman_ford::all_pairs(&graph, true, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NegativeCycle
+        ));
+    }
+
+    #[test]
+    fn test_all_pairs_negative_cycle_first_source() {
+        // two separate negative cycles; the error is always for the first source in sorted order
+        let edges = vec![
+            Edge::with_weight("n3", "n4", 1.0),
+            Edge::with_weight("n4", "n3", -2.0),
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n1", -2.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        for _ in 0..20 {
+            let err = bellman_ford::all_pairs(&graph, true, false).err().unwrap();
+            assert!(matches!(err.kind, ErrorKind::NegativeCycle));
+            assert!(err.message.starts_with("Search from node 'n1' failed"));
+        }
+    }
+}
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::shortest_path::{contraction_hierarchy::ContractionHierarchy, dijkstra},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+
+    fn get_grid_graph(size: i32, directed: bool) -> Graph<i32, ()> {
+        // a grid with "roads" of varying weights, like a simple road network
+        let specs = match directed {
+            true => GraphSpecs::directed_create_missing(),
+            false => GraphSpecs::undirected_create_missing(),
+        };
+        let mut graph = Graph::new(specs);
+        for x in 0..size {
+            for y in 0..size {
+                let node = x * size + y;
+                let weight = 1.0 + ((x * 7 + y * 3) % 5) as f64;
+                if x + 1 < size {
+                    graph.add_edge(Edge::with_weight(node, node + size, weight)).unwrap();
+                }
+                if y + 1 < size {
+                    graph.add_edge(Edge::with_weight(node, node + 1, weight / 2.0)).unwrap();
+                }
+                if directed && x > 0 {
+                    graph.add_edge(Edge::with_weight(node, node - size, weight * 1.5)).unwrap();
+                }
+            }
+        }
+        graph
+    }
+
+    fn assert_queries_match_dijkstra(graph: &Graph<i32, ()>, weighted: bool) {
+        let ch = ContractionHierarchy::build(graph, weighted).unwrap();
+        for source in graph.get_all_node_names() {
+            let expected =
+                dijkstra::single_source(graph, weighted, *source, None, None, None, false).unwrap();
+            for target in graph.get_all_node_names() {
+                let result = ch.query(*source, *target).unwrap();
+                match expected.get(target) {
+                    None => assert!(result.is_none()),
+                    Some(info) => {
+                        let result = result.unwrap();
+                        assert!((result.distance - info.distance).abs() < 1e-9);
+                        assert!(info.paths.contains(&result.paths[0]));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_query_1() {
+        assert_queries_match_dijkstra(&get_grid_graph(7, false), true);
+    }
+
+    #[test]
+    fn test_query_2() {
+        assert_queries_match_dijkstra(&get_grid_graph(7, true), true);
+    }
+
+    #[test]
+    fn test_query_3() {
+        assert_queries_match_dijkstra(&generators::social::karate_club_graph(), false);
+    }
+
+    #[test]
+    fn test_query_4() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n3", "n4", 1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let ch = ContractionHierarchy::build(&graph, true).unwrap();
+        assert!(ch.query("n1", "n4").unwrap().is_none());
+        let info = ch.query("n1", "n1").unwrap().unwrap();
+        assert_eq!(info.distance, 0.0);
+        assert_eq!(info.paths, vec![vec!["n1"]]);
+        let result = ch.query("n1", "n9");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_query_zero_weight_edges() {
+        let edges = vec![
+            Edge::with_weight(0, 1, 3.0),
+            Edge::with_weight(0, 2, 0.0),
+            Edge::with_weight(0, 3, 0.0),
+            Edge::with_weight(1, 3, 1.0),
+            Edge::with_weight(2, 4, 3.0),
+        ];
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(
+            vec![],
+            edges,
+            GraphSpecs::undirected_create_missing(),
+        )
+        .unwrap();
+        let ch = ContractionHierarchy::build(&graph, true).unwrap();
+        let info = ch.query(0, 3).unwrap().unwrap();
+        assert_eq!(info.distance, 0.0);
+        assert_eq!(info.paths, vec![vec![0, 3]]);
+        let info = ch.query(0, 1).unwrap().unwrap();
+        assert_eq!(info.distance, 1.0);
+        assert_eq!(info.paths, vec![vec![0, 3, 1]]);
+        assert_queries_match_dijkstra(&graph, true);
+    }
+
+    #[test]
+    fn test_build_1() {
+        let edges = vec![Edge::with_weight("n1", "n2", -1.0)];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = ContractionHierarchy::build(&graph, true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let edges = vec![Edge::new("n1", "n2")];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        assert!(ContractionHierarchy::build(&graph, true).is_err());
+        assert!(ContractionHierarchy::build(&graph, false).is_ok());
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::shortest_path::{delta_stepping, dijkstra},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+
+    #[test]
+    fn test_single_source_distances_1() {
+        // unweighted results match dijkstra
+        let graph = generators::social::karate_club_graph();
+        for source in 0..34 {
+            let expected =
+                dijkstra::single_source_distances(&graph, false, source, None, None).unwrap();
+            let result =
+                delta_stepping::single_source_distances(&graph, false, source, None).unwrap();
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_single_source_distances_2() {
+        // weighted results match dijkstra for a range of deltas
+        let mut graph =
+            generators::random::fast_gnp_random_graph(200, 0.05, true, Some(1)).unwrap();
+        let edges = graph
+            .get_all_edges()
+            .into_iter()
+            .map(|e| Edge::with_weight(e.u, e.v, ((e.u * 7 + e.v * 13) % 10) as f64 / 4.0))
+            .collect::<Vec<Edge<i32, ()>>>();
+        graph = Graph::new_from_nodes_and_edges(
+            graph.get_all_nodes().into_iter().cloned().collect(),
+            edges,
+            GraphSpecs::directed(),
+        )
+        .unwrap();
+        let expected = dijkstra::single_source_distances(&graph, true, 0, None, None).unwrap();
+        for delta in [None, Some(0.1), Some(1.0), Some(100.0)] {
+            let result = delta_stepping::single_source_distances(&graph, true, 0, delta).unwrap();
+            assert_eq!(result.len(), expected.len());
+            for (node, distance) in expected.iter() {
+                assert!((result.get(node).unwrap() - distance).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_source_distances_3() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n3", -1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = delta_stepping::single_source_distances(&graph, true, "n1", None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = delta_stepping::single_source_distances(&graph, false, "n1", Some(0.0));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = delta_stepping::single_source_distances(&graph, false, "n9", None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = delta_stepping::single_source_distances(&graph, false, "n3", None).unwrap();
+        assert_eq!(result.len(), 1);
+    }
+}
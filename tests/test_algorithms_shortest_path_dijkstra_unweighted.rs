@@ -2,7 +2,8 @@
 mod tests {
 
     use graphrs::{
-        algorithms::shortest_path::dijkstra, Edge, Graph, GraphSpecs, MissingNodeStrategy, Node,
+        algorithms::shortest_path::dijkstra, generators, Edge, Graph, GraphSpecs,
+        MissingNodeStrategy, Node,
     };
     use std::collections::HashSet;
 
@@ -27,7 +28,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = dijkstra::all_pairs(&graph, false, None, false);
+        let result = dijkstra::all_pairs(&graph, false, None, None, false);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         assert_eq!(
@@ -143,7 +144,7 @@ mod tests {
     // fn test_all_pairs_2() {
     //     let graph = generators::social::karate_club_graph();
 
-    //     let result = dijkstra::all_pairs(&graph, false, None);
+    //     let result = dijkstra::all_pairs(&graph, false, None, None);
     //     assert!(result.is_ok());
     //     let unwrapped = result.unwrap();
 
@@ -204,7 +205,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = dijkstra::single_source(&graph, false, "n1", Some("n3"), None, false);
+        let result = dijkstra::single_source(&graph, false, "n1", Some("n3"), None, None, false);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         assert!(unwrapped.get("n1").is_none());
@@ -241,7 +242,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = dijkstra::single_source(&graph, false, "n12", Some("n9"), None, false);
+        let result = dijkstra::single_source(&graph, false, "n12", Some("n9"), None, None, false);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         assert_eq!(unwrapped.get("n9").unwrap().distance, 3.0);
@@ -276,7 +277,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = dijkstra::single_source(&graph, false, "n12", Some("n9"), None, true);
+        let result = dijkstra::single_source(&graph, false, "n12", Some("n9"), None, None, true);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         let n9 = unwrapped.get("n9").unwrap();
@@ -307,7 +308,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = dijkstra::single_source(&graph, false, "n1", Some("n5"), None, true);
+        let result = dijkstra::single_source(&graph, false, "n1", Some("n5"), None, None, true);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         assert_eq!(unwrapped.keys().len(), 0);
@@ -333,7 +334,8 @@ mod tests {
 
         let graph = Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::directed()).unwrap();
 
-        let result = dijkstra::multi_source(&graph, false, vec!["n1"], Some("n3"), None, false);
+        let result =
+            dijkstra::multi_source(&graph, false, vec!["n1"], Some("n3"), None, None, false);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         let n3_info = unwrapped.get("n3").unwrap();
@@ -365,7 +367,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = dijkstra::multi_source(&graph, false, vec!["n1"], None, None, false);
+        let result = dijkstra::multi_source(&graph, false, vec!["n1"], None, None, None, false);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         assert_eq!(unwrapped.get("n1").unwrap().distance, 0.0);
@@ -411,4 +413,131 @@ mod tests {
         let b: HashSet<&Vec<&str>> = v2.iter().collect();
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn test_bidirectional_1() {
+        let graph = generators::social::karate_club_graph();
+        let all_pairs = dijkstra::all_pairs(&graph, false, None, None, false).unwrap();
+        for (source, targets) in all_pairs {
+            for (target, info) in targets {
+                let result =
+                    dijkstra::bidirectional(&graph, false, source, target, None).unwrap().unwrap();
+                assert_eq!(result.distance, info.distance);
+                assert!(info.paths.contains(&result.paths[0]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_source_predecessors_1() {
+        // the lazily built paths match the paths built by `single_source`
+        let graph = generators::social::karate_club_graph();
+        for source in 0..34 {
+            let expected =
+                dijkstra::single_source(&graph, false, source, None, None, None, false).unwrap();
+            let result =
+                dijkstra::single_source_predecessors(&graph, false, source, None, None, false)
+                    .unwrap();
+            assert_eq!(result.distances.len(), expected.len());
+            for (target, info) in expected {
+                assert_eq!(result.get_distance(&target), Some(info.distance));
+                let paths = result.get_paths(&target);
+                assert_eq!(paths.len(), info.paths.len());
+                for path in paths {
+                    assert!(info.paths.contains(&path));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_source_predecessors_2() {
+        // a chain of diamonds has 2^n shortest paths but only ~3n predecessors
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        for i in 0..20 {
+            let (a, b, c, d) = (i * 3, i * 3 + 1, i * 3 + 2, i * 3 + 3);
+            graph.add_edges(vec![
+                Edge::new(a, b),
+                Edge::new(a, c),
+                Edge::new(b, d),
+                Edge::new(c, d),
+            ]).unwrap();
+        }
+        let result =
+            dijkstra::single_source_predecessors(&graph, false, 0, None, None, false).unwrap();
+        assert_eq!(result.get_distance(&60), Some(40.0));
+        let mut iter = result.iter_paths(&60);
+        assert_eq!(iter.next().unwrap().len(), 41);
+        assert_eq!(iter.take(1000).count(), 1000);
+        assert_eq!(result.get_first_path(&3).unwrap().len(), 3);
+        assert_eq!(result.get_paths(&3).len(), 2);
+        assert_eq!(result.get_paths(&100).len(), 0);
+        assert!(result.get_shortest_path_info(&100).is_none());
+    }
+
+    #[test]
+    fn test_single_source_predecessors_3() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![
+            Edge::new(0, 1),
+            Edge::new(1, 2),
+            Edge::new(0, 3),
+            Edge::new(3, 2),
+        ]).unwrap();
+        let result =
+            dijkstra::single_source_predecessors(&graph, false, 0, None, None, true).unwrap();
+        assert_eq!(result.get_paths(&2).len(), 1);
+        let result =
+            dijkstra::single_source_predecessors(&graph, false, 0, Some(1), None, false).unwrap();
+        assert_eq!(result.get_paths(&1), vec![vec![0, 1]]);
+        assert!(result.get_distance(&2).is_none());
+        let result =
+            dijkstra::single_source_predecessors(&graph, false, 0, None, Some(1.0), false).unwrap();
+        assert_eq!(result.distances.len(), 3);
+        assert!(result.get_distance(&2).is_none());
+    }
+
+    #[test]
+    fn test_multi_source_predecessors_1() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        graph.add_edges(vec![
+            Edge::new(0, 1),
+            Edge::new(1, 2),
+            Edge::new(2, 3),
+            Edge::new(3, 4),
+        ]).unwrap();
+        let result =
+            dijkstra::multi_source_predecessors(&graph, false, vec![0, 4], None, None, false)
+                .unwrap();
+        assert_eq!(result.get_distance(&2), Some(2.0));
+        let paths = result.get_paths(&2);
+        assert_eq!(paths.len(), 2);
+        assert!(paths.contains(&vec![0, 1, 2]));
+        assert!(paths.contains(&vec![4, 3, 2]));
+        assert_eq!(result.get_paths(&4), vec![vec![4]]);
+    }
+
+    #[test]
+    fn test_all_pairs_distances_1() {
+        let graph = generators::social::karate_club_graph();
+        let expected = dijkstra::all_pairs(&graph, false, None, None, true).unwrap();
+        let result = dijkstra::all_pairs_distances(&graph, false, None, None).unwrap();
+        assert_eq!(result.len(), 34);
+        for (source, targets) in expected {
+            let distances = result.get(&source).unwrap();
+            assert_eq!(distances.len(), targets.len());
+            for (target, info) in targets {
+                assert_eq!(distances.get(&target).unwrap(), &info.distance);
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_source_distances_1() {
+        let graph = generators::social::karate_club_graph();
+        let result = dijkstra::single_source_distances(&graph, false, 0, Some(1.0), None).unwrap();
+        assert_eq!(result.len(), 17);
+        assert_eq!(result.get(&0).unwrap(), &0.0);
+        assert!(result.values().all(|d| *d <= 1.0));
+    }
 }
@@ -2,10 +2,10 @@
 mod tests {
 
     use graphrs::{
-        algorithms::shortest_path::dijkstra, generators, Edge, Graph, GraphSpecs,
-        MissingNodeStrategy, Node,
+        algorithms::shortest_path::dijkstra, generators, Direction, Edge, ErrorKind, Graph,
+        GraphSpecs, MissingNodeStrategy, Node,
     };
-    use std::collections::HashSet;
+    use std::collections::{HashMap, HashSet};
 
     #[test]
     fn test_all_pairs_1() {
@@ -28,7 +28,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = dijkstra::all_pairs(&graph, true, None, false);
+        let result = dijkstra::all_pairs(&graph, true, None, None, false);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         assert_eq!(
@@ -161,7 +161,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = dijkstra::all_pairs(&graph, true, Some(2.9), false);
+        let result = dijkstra::all_pairs(&graph, true, Some(2.9), None, false);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         assert_eq!(
@@ -252,7 +252,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = dijkstra::single_source(&graph, true, "n1", Some("n3"), None, false);
+        let result = dijkstra::single_source(&graph, true, "n1", Some("n3"), None, None, false);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         assert!(unwrapped.get("n1").is_none());
@@ -283,7 +283,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = dijkstra::single_source(&graph, true, "n1", Some("n4"), None, false);
+        let result = dijkstra::single_source(&graph, true, "n1", Some("n4"), None, None, false);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         assert_eq!(unwrapped.get("n4").unwrap().distance, 2.0);
@@ -313,7 +313,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = dijkstra::single_source(&graph, true, "n1", Some("n5"), None, false);
+        let result = dijkstra::single_source(&graph, true, "n1", Some("n5"), None, None, false);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         assert_eq!(unwrapped.get("n5").unwrap().distance, 3.0);
@@ -343,7 +343,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = dijkstra::single_source(&graph, true, "n1", Some("n5"), None, true);
+        let result = dijkstra::single_source(&graph, true, "n1", Some("n5"), None, None, true);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         let n5 = unwrapped.get("n5").unwrap();
@@ -372,7 +372,8 @@ mod tests {
 
         let graph = Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::directed()).unwrap();
 
-        let result = dijkstra::multi_source(&graph, true, vec!["n1"], Some("n3"), None, false);
+        let result =
+            dijkstra::multi_source(&graph, true, vec!["n1"], Some("n3"), None, None, false);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         let n3_info = unwrapped.get("n3").unwrap();
@@ -383,7 +384,8 @@ mod tests {
     #[test]
     fn test_multi_source_2() {
         let graph = generators::social::karate_club_graph();
-        let result = dijkstra::multi_source(&graph, true, vec![0, 1, 2], Some(24), None, false);
+        let result =
+            dijkstra::multi_source(&graph, true, vec![0, 1, 2], Some(24), None, None, false);
         assert!(result.is_err());
     }
 
@@ -408,7 +410,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = dijkstra::multi_source(&graph, true, vec!["n1"], None, None, false);
+        let result = dijkstra::multi_source(&graph, true, vec!["n1"], None, None, None, false);
         assert!(result.is_ok());
         let unwrapped = result.unwrap();
         assert_eq!(unwrapped.get("n1").unwrap().distance, 0.0);
@@ -428,6 +430,740 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bidirectional_1() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n3", 5.0),
+            Edge::with_weight("n1", "n4", 2.0),
+            Edge::with_weight("n4", "n3", 3.0),
+            Edge::with_weight("n1", "n5", 9.0),
+            Edge::with_weight("n3", "n5", 1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = dijkstra::bidirectional(&graph, true, "n1", "n5", None).unwrap().unwrap();
+        assert_eq!(result.distance, 6.0);
+        assert_eq!(result.paths, vec![vec!["n1", "n4", "n3", "n5"]]);
+        let result = dijkstra::bidirectional(&graph, true, "n1", "n1", None).unwrap().unwrap();
+        assert_eq!(result.distance, 0.0);
+        assert_eq!(result.paths, vec![vec!["n1"]]);
+        let result = dijkstra::bidirectional(&graph, true, "n5", "n1", None).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_bidirectional_2() {
+        // an undirected graph; compare against `single_source` for every pair of nodes
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.5),
+            Edge::with_weight("n2", "n3", 0.5),
+            Edge::with_weight("n1", "n4", 2.0),
+            Edge::with_weight("n4", "n3", 3.0),
+            Edge::with_weight("n3", "n5", 1.0),
+            Edge::with_weight("n5", "n6", 4.0),
+            Edge::with_weight("n4", "n6", 2.5),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let nodes = vec!["n1", "n2", "n3", "n4", "n5", "n6"];
+        for source in &nodes {
+            let expected =
+                dijkstra::single_source(&graph, true, *source, None, None, None, false).unwrap();
+            for target in &nodes {
+                let result =
+                    dijkstra::bidirectional(&graph, true, *source, *target, None).unwrap().unwrap();
+                let info = expected.get(target).unwrap();
+                assert_eq!(result.distance, info.distance);
+                assert!(info.paths.contains(&result.paths[0]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_source_distances_1() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n3", 5.0),
+            Edge::with_weight("n1", "n4", 2.0),
+            Edge::with_weight("n4", "n3", 3.0),
+            Edge::with_weight("n6", "n1", 1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = dijkstra::single_source_distances(&graph, true, "n1", None, None).unwrap();
+        assert_eq!(result.len(), 4);
+        assert_eq!(result.get("n3").unwrap(), &5.0);
+        assert!(!result.contains_key("n6"));
+        let result =
+            dijkstra::single_source_distances(&graph, true, "n1", Some(2.0), None).unwrap();
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn test_single_source_distances_in_direction() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n3", 5.0),
+            Edge::with_weight("n1", "n4", 2.0),
+            Edge::with_weight("n4", "n3", 3.0),
+            Edge::with_weight("n6", "n1", 1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = dijkstra::single_source_distances_in_direction(
+            &graph,
+            true,
+            "n3",
+            Direction::Incoming,
+            None,
+            None,
+        )
+        .unwrap();
+        let expected: HashMap<&str, f64> = vec![
+            ("n3", 0.0),
+            ("n2", 5.0),
+            ("n4", 3.0),
+            ("n1", 5.0),
+            ("n6", 6.0),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(result, expected);
+        // the incoming distances to a node match the outgoing distances from every node
+        let reversed = graph.reverse().unwrap();
+        let outgoing = dijkstra::single_source_distances(&reversed, false, "n3", None, None);
+        let incoming = dijkstra::single_source_distances_in_direction(
+            &graph,
+            false,
+            "n3",
+            Direction::Incoming,
+            None,
+            Some(1),
+        );
+        assert_eq!(incoming.unwrap().len(), 3);
+        assert_eq!(outgoing.unwrap().get("n6"), Some(&3.0));
+        let result = dijkstra::single_source_distances_in_direction(
+            &graph,
+            true,
+            "n1",
+            Direction::Outgoing,
+            None,
+            None,
+        );
+        assert_eq!(result.unwrap().get("n3").unwrap(), &5.0);
+        let result = dijkstra::single_source_distances_in_direction(
+            &graph,
+            true,
+            "n9",
+            Direction::Incoming,
+            None,
+            None,
+        );
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_all_pairs_distances_1() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n3", -5.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = dijkstra::all_pairs_distances(&graph, false, None, None).unwrap();
+        assert_eq!(result.get("n1").unwrap().get("n3").unwrap(), &2.0);
+        let edges = vec![Edge::new("n1", "n2")];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        assert!(dijkstra::all_pairs_distances(&graph, true, None, None).is_err());
+    }
+
+    #[test]
+    fn test_single_source_with_cost_1() {
+        // a cost function that returns the weight matches `single_source`
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n3", 2.0),
+            Edge::with_weight("n1", "n4", 2.0),
+            Edge::with_weight("n4", "n3", 1.0),
+            Edge::with_weight("n3", "n5", 1.5),
+            Edge::with_weight("n1", "n5", 4.5),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        for node in graph.get_all_node_names() {
+            let expected =
+                dijkstra::single_source(&graph, true, *node, None, None, None, false).unwrap();
+            let result =
+                dijkstra::single_source_with_cost(&graph, *node, None, None, None, false, |e| {
+                    e.weight
+                })
+                .unwrap();
+            assert_eq!(result.len(), expected.len());
+            for (target, info) in expected {
+                let actual = result.get(target).unwrap();
+                assert_eq!(actual.distance, info.distance);
+                assert_paths_contain_same_items(&actual.paths, &info.paths);
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_source_with_cost_2() {
+        // costs come from the edge attributes; the graph has no weights
+        let edge = |u, v, minutes| Edge {
+            u,
+            v,
+            attributes: Some(minutes),
+            weight: f64::NAN,
+        };
+        let edges = vec![
+            edge("n1", "n2", 5),
+            edge("n2", "n3", 5),
+            edge("n1", "n3", 12),
+            edge("n3", "n4", 1),
+        ];
+        let graph: Graph<&str, i32> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let cost = |e: &Edge<&str, i32>| e.attributes.unwrap() as f64;
+        let result = dijkstra::single_source_with_cost(&graph, "n1", None, None, None, false, cost);
+        let result = result.unwrap();
+        assert_eq!(result.get("n4").unwrap().distance, 11.0);
+        assert_eq!(
+            result.get("n4").unwrap().paths,
+            vec![vec!["n1", "n2", "n3", "n4"]]
+        );
+        let result = dijkstra::single_source_with_cost(
+            &graph,
+            "n1",
+            Some("n3"),
+            Some(9.0),
+            None,
+            false,
+            cost,
+        );
+        assert_eq!(result.unwrap().len(), 0);
+        assert!(dijkstra::single_source(&graph, true, "n1", None, None, None, false).is_err());
+    }
+
+    #[test]
+    fn test_multi_source_with_cost_1() {
+        // multigraphs use the lowest cost edge
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n1", "n2", 3.0),
+            Edge::with_weight("n3", "n2", 1.5),
+        ];
+        let specs = GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::directed_create_missing()
+        };
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let cost = |e: &Edge<&str, ()>| 10.0 - e.weight;
+        let result = dijkstra::multi_source_with_cost(
+            &graph,
+            vec!["n1", "n3"],
+            None,
+            None,
+            None,
+            false,
+            cost,
+        );
+        let result = result.unwrap();
+        assert_eq!(result.get("n2").unwrap().distance, 7.0);
+        assert_eq!(result.get("n2").unwrap().paths, vec![vec!["n1", "n2"]]);
+    }
+
+    fn get_u64_graph(multi_edges: bool) -> Graph<&'static str, (), u64> {
+        let mut graph = Graph::new_with_weight_type(GraphSpecs {
+            multi_edges,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1),
+                Edge::with_weight("n2", "n3", 5),
+                Edge::with_weight("n1", "n4", 2),
+                Edge::with_weight("n4", "n3", 3),
+                Edge::with_weight("n1", "n5", 9),
+                Edge::with_weight("n3", "n5", 1),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_single_source_u64_weights() {
+        let graph = get_u64_graph(false);
+        let result = dijkstra::single_source(&graph, true, "n1", None, None, None, false).unwrap();
+        assert_eq!(result.get("n1").unwrap().distance, 0);
+        assert_eq!(result.get("n3").unwrap().distance, 5);
+        assert_paths_contain_same_items(
+            &result.get("n3").unwrap().paths,
+            &[vec!["n1", "n4", "n3"]],
+        );
+        assert_eq!(result.get("n5").unwrap().distance, 6);
+        let unweighted =
+            dijkstra::single_source(&graph, false, "n1", None, None, None, false).unwrap();
+        assert_eq!(unweighted.get("n3").unwrap().distance, 2);
+        assert_eq!(unweighted.get("n3").unwrap().paths.len(), 2);
+    }
+
+    #[test]
+    fn test_u64_weights_cutoff_and_distances() {
+        let graph = get_u64_graph(false);
+        let result =
+            dijkstra::single_source(&graph, true, "n1", None, Some(2), None, false).unwrap();
+        assert_eq!(result.len(), 3);
+        let distances = dijkstra::all_pairs_distances(&graph, true, None, None).unwrap();
+        assert_eq!(distances.get("n4").unwrap().get("n5").unwrap(), &4);
+        let predecessors =
+            dijkstra::single_source_predecessors(&graph, true, "n1", None, None, false).unwrap();
+        assert_eq!(predecessors.get_distance(&"n5"), Some(6));
+        let path = dijkstra::bidirectional(&graph, true, "n1", "n5", None).unwrap().unwrap();
+        assert_eq!(path.distance, 6);
+        assert_eq!(path.paths, vec![vec!["n1", "n4", "n3", "n5"]]);
+    }
+
+    #[test]
+    fn test_u64_weights_multi_edges() {
+        let mut graph = get_u64_graph(true);
+        graph.add_edge(Edge::with_weight("n1", "n5", 4)).unwrap();
+        let result =
+            dijkstra::single_source(&graph, true, "n1", Some("n5"), None, None, false).unwrap();
+        assert_eq!(result.get("n5").unwrap().distance, 4);
+        assert_eq!(result.get("n5").unwrap().paths, vec![vec!["n1", "n5"]]);
+    }
+
+    #[test]
+    fn test_multi_edges_edge_keys() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 5.0),
+                Edge::with_weight("n1", "n2", 2.0),
+                Edge::with_weight("n1", "n2", 3.0),
+                Edge::with_weight("n2", "n3", 1.0),
+                Edge::with_weight("n1", "n3", 10.0),
+            ])
+            .unwrap();
+        let result =
+            dijkstra::single_source(&graph, true, "n1", Some("n3"), None, None, false).unwrap();
+        let info = result.get("n3").unwrap();
+        assert_eq!(info.distance, 3.0);
+        assert_eq!(info.edge_keys, Some(vec![vec![1, 0]]));
+        let edges = graph.get_edges("n1", "n2").unwrap();
+        assert_eq!(edges[info.edge_keys.as_ref().unwrap()[0][0]].weight, 2.0);
+        let result =
+            dijkstra::single_source(&graph, false, "n1", Some("n3"), None, None, false).unwrap();
+        assert_eq!(result.get("n3").unwrap().edge_keys, Some(vec![vec![0]]));
+        let info = dijkstra::shortest_path(&graph, true, "n1", "n3", None).unwrap().unwrap();
+        assert_eq!(info.edge_keys, Some(vec![vec![1, 0]]));
+        let info = dijkstra::bidirectional(&graph, true, "n1", "n3", None).unwrap().unwrap();
+        assert_eq!(info.edge_keys, Some(vec![vec![1, 0]]));
+        let info = dijkstra::bidirectional(&graph, true, "n1", "n1", None).unwrap().unwrap();
+        assert_eq!(info.edge_keys, Some(vec![vec![]]));
+        let all = dijkstra::all_pairs(&graph, true, None, None, false).unwrap();
+        assert_eq!(all["n1"]["n2"].edge_keys, Some(vec![vec![1]]));
+        for result in dijkstra::all_pairs_iter(&graph, true, None, None, false).unwrap() {
+            let (source, infos) = result.unwrap();
+            assert_eq!(infos[source].edge_keys, Some(vec![vec![]]));
+        }
+        // the edge that costs the least is used, whatever its weight
+        let cost = |edge: &Edge<&str, ()>| match edge.weight == 2.0 {
+            true => 100.0,
+            false => edge.weight,
+        };
+        let result =
+            dijkstra::single_source_with_cost(&graph, "n1", Some("n3"), None, None, false, cost)
+                .unwrap();
+        assert_eq!(result.get("n3").unwrap().edge_keys, Some(vec![vec![2, 0]]));
+    }
+
+    #[test]
+    fn test_multi_edges_edge_keys_undirected() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::undirected_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n2", "n1", 5.0),
+                Edge::with_weight("n1", "n2", 2.0),
+                Edge::with_weight("n3", "n2", 1.0),
+            ])
+            .unwrap();
+        let result =
+            dijkstra::single_source(&graph, true, "n3", Some("n1"), None, None, false).unwrap();
+        let info = result.get("n1").unwrap();
+        assert_eq!(info.paths, vec![vec!["n3", "n2", "n1"]]);
+        assert_eq!(info.edge_keys, Some(vec![vec![0, 1]]));
+        // graphs without parallel edges don't record them
+        let graph = get_u64_graph(false);
+        let info = dijkstra::shortest_path(&graph, true, "n1", "n5", None).unwrap().unwrap();
+        assert!(info.edge_keys.is_none());
+    }
+
+    #[test]
+    fn test_missing_source_is_an_error() {
+        let graph = get_u64_graph(true);
+        let result = dijkstra::single_source(&graph, true, "n9", None, None, None, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result =
+            dijkstra::multi_source(&graph, true, vec!["n1", "n9"], None, None, None, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = dijkstra::single_source_distances(&graph, true, "n9", None, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_single_source_to_targets_matches_single_source() {
+        let random = generators::random::gnm_random_graph(200, 600, Some(2), true).unwrap();
+        let mut graph: Graph<usize, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(
+                random
+                    .get_all_edges()
+                    .into_iter()
+                    .map(|e| Edge::with_weight(e.u, e.v, ((e.u * 3 + e.v) % 4 + 1) as f64))
+                    .collect(),
+            )
+            .unwrap();
+        let all = dijkstra::single_source(&graph, true, 0, None, None, None, false).unwrap();
+        let targets = vec![5, 17, 17, 42, 150, 1000];
+        let result =
+            dijkstra::single_source_to_targets(&graph, true, 0, targets.clone(), None, None, false)
+                .unwrap();
+        let expected: Vec<&usize> = targets.iter().filter(|t| all.contains_key(t)).collect();
+        assert_eq!(result.len(), expected.iter().collect::<HashSet<_>>().len());
+        for target in expected {
+            let info = result.get(target).unwrap();
+            let expected_info = all.get(target).unwrap();
+            assert_eq!(info.distance, expected_info.distance);
+            let a: HashSet<&Vec<usize>> = info.paths.iter().collect();
+            let b: HashSet<&Vec<usize>> = expected_info.paths.iter().collect();
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn test_multi_source_to_targets() {
+        let graph = get_u64_graph(true);
+        let result = dijkstra::multi_source_to_targets(
+            &graph,
+            true,
+            vec!["n1", "n3"],
+            vec!["n5", "n2"],
+            None,
+            None,
+            false,
+        )
+        .unwrap();
+        assert_eq!(result.len(), 2);
+        assert!(result.contains_key("n5"));
+        assert!(result.contains_key("n2"));
+        let result = dijkstra::multi_source_to_targets(
+            &graph,
+            true,
+            vec!["n1"],
+            vec!["n9"],
+            None,
+            None,
+            false,
+        )
+        .unwrap();
+        assert!(result.is_empty());
+        let result = dijkstra::multi_source_to_targets(
+            &graph,
+            true,
+            vec!["n9"],
+            vec!["n1"],
+            None,
+            None,
+            false,
+        );
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = dijkstra::multi_source_to_targets(
+            &graph,
+            true,
+            vec!["n1"],
+            vec!["n5"],
+            Some(1),
+            None,
+            false,
+        )
+        .unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_all_pairs_returns_first_error() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n4", "n2", 1.0),
+                Edge::with_weight("n4", "n3", 2.0),
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n1", "n3", 2.0),
+                Edge::with_weight("n3", "n2", -5.0),
+            ])
+            .unwrap();
+        for _ in 0..10 {
+            let error = dijkstra::all_pairs(&graph, true, None, None, false).err().unwrap();
+            assert!(matches!(error.kind, ErrorKind::ContradictoryPaths));
+            assert!(error.message.contains("'n1'"));
+            let error = dijkstra::all_pairs_distances(&graph, true, None, None).err().unwrap();
+            assert!(matches!(error.kind, ErrorKind::ContradictoryPaths));
+            assert!(error.message.contains("'n1'"));
+        }
+        let result = dijkstra::single_source(&graph, true, "n3", None, None, None, false);
+        assert_eq!(result.unwrap().get("n2").unwrap().distance, -5.0);
+    }
+
+    #[test]
+    fn test_all_pairs_iter_matches_all_pairs() {
+        let graph = generators::random::gnm_random_graph(40, 150, Some(2), true).unwrap();
+        let expected = dijkstra::all_pairs(&graph, false, None, None, false).unwrap();
+        let mut sources = vec![];
+        for result in dijkstra::all_pairs_iter(&graph, false, None, None, false).unwrap() {
+            let (source, infos) = result.unwrap();
+            let expected_infos = expected.get(&source).unwrap();
+            assert_eq!(infos.len(), expected_infos.len());
+            for (target, info) in infos {
+                assert_eq!(info.distance, expected_infos.get(&target).unwrap().distance);
+                assert_eq!(info.paths, expected_infos.get(&target).unwrap().paths);
+            }
+            sources.push(source);
+        }
+        assert_eq!(sources, (0..40).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn test_all_pairs_iter_errors() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n1", "n3", 2.0),
+                Edge::with_weight("n3", "n2", -5.0),
+            ])
+            .unwrap();
+        let results: Vec<_> =
+            dijkstra::all_pairs_iter(&graph, true, None, None, false).unwrap().collect();
+        assert_eq!(results.len(), 3);
+        let error = results[0].as_ref().err().unwrap();
+        assert!(matches!(error.kind, ErrorKind::ContradictoryPaths));
+        assert!(results[1].is_ok());
+        assert!(results[2].is_ok());
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        graph.add_edge(Edge::new("n1", "n2")).unwrap();
+        let result = dijkstra::all_pairs_iter(&graph, true, None, None, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let graph = get_hops_graph();
+        let info = dijkstra::shortest_path(&graph, true, "n1", "n5", None).unwrap().unwrap();
+        assert_eq!(info.distance, 4.0);
+        assert_eq!(info.paths, vec![vec!["n1", "n2", "n3", "n4", "n5"]]);
+        let info = dijkstra::shortest_path(&graph, false, "n1", "n4", None).unwrap().unwrap();
+        assert_eq!(info.distance, 1.0);
+        let info = dijkstra::shortest_path(&graph, true, "n3", "n3", None).unwrap().unwrap();
+        assert_eq!(info.paths, vec![vec!["n3"]]);
+        assert!(dijkstra::shortest_path(&graph, true, "n5", "n1", None).unwrap().is_none());
+        for (source, target) in [("n9", "n1"), ("n1", "n9")] {
+            let result = dijkstra::shortest_path(&graph, true, source, target, None);
+            assert!(matches!(
+                result.err().unwrap().kind,
+                ErrorKind::NodeNotFound
+            ));
+        }
+    }
+
+    fn get_hops_graph() -> Graph<&'static str, ()> {
+        let mut graph = Graph::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n2", "n3", 1.0),
+                Edge::with_weight("n3", "n4", 1.0),
+                Edge::with_weight("n1", "n3", 5.0),
+                Edge::with_weight("n1", "n4", 10.0),
+                Edge::with_weight("n4", "n5", 1.0),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_hop_cutoff() {
+        let graph = get_hops_graph();
+        let result = dijkstra::single_source(&graph, true, "n1", None, None, Some(2), false);
+        let result = result.unwrap();
+        assert_eq!(result.len(), 5);
+        assert_eq!(result.get("n3").unwrap().distance, 2.0);
+        assert_eq!(
+            result.get("n3").unwrap().paths,
+            vec![vec!["n1", "n2", "n3"]]
+        );
+        // the shortest path to n4 has three edges, so a longer one with two is used
+        assert_eq!(result.get("n4").unwrap().distance, 6.0);
+        assert_eq!(
+            result.get("n4").unwrap().paths,
+            vec![vec!["n1", "n3", "n4"]]
+        );
+        assert_eq!(result.get("n5").unwrap().distance, 11.0);
+
+        let result = dijkstra::single_source(&graph, true, "n1", None, None, Some(1), false);
+        let distances: Vec<(&str, f64)> = ["n1", "n2", "n3", "n4"]
+            .iter()
+            .map(|n| (*n, result.as_ref().unwrap().get(n).unwrap().distance))
+            .collect();
+        assert_eq!(
+            distances,
+            vec![("n1", 0.0), ("n2", 1.0), ("n3", 5.0), ("n4", 10.0)]
+        );
+
+        let result = dijkstra::single_source(&graph, true, "n1", None, None, Some(0), false);
+        assert_eq!(result.unwrap().len(), 1);
+
+        let result = dijkstra::single_source(&graph, true, "n1", None, Some(5.0), Some(2), false);
+        let result = result.unwrap();
+        assert_eq!(result.get("n3").unwrap().distance, 2.0);
+        assert!(!result.contains_key("n4"));
+
+        let result = dijkstra::single_source(&graph, true, "n1", None, None, Some(4), false);
+        assert_eq!(result.unwrap().get("n5").unwrap().distance, 4.0);
+    }
+
+    #[test]
+    fn test_hop_cutoff_all_shortest_paths() {
+        let mut graph = get_hops_graph();
+        graph.add_edge(Edge::with_weight("n1", "n6", 1.0)).unwrap();
+        graph.add_edge(Edge::with_weight("n6", "n4", 5.0)).unwrap();
+        let result = dijkstra::single_source(&graph, true, "n1", None, None, Some(2), false);
+        let info = result.unwrap().remove("n4").unwrap();
+        assert_eq!(info.distance, 6.0);
+        assert_paths_contain_same_items(
+            &info.paths,
+            &[vec!["n1", "n3", "n4"], vec!["n1", "n6", "n4"]],
+        );
+        let result = dijkstra::single_source(&graph, true, "n1", None, None, Some(2), true);
+        assert_eq!(result.unwrap().get("n4").unwrap().paths.len(), 1);
+
+        let result = dijkstra::single_source_to_targets(
+            &graph,
+            true,
+            "n1",
+            vec!["n4", "n5"],
+            None,
+            Some(3),
+            false,
+        );
+        let result = result.unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result.get("n4").unwrap().distance, 3.0);
+        assert_eq!(result.get("n5").unwrap().distance, 7.0);
+
+        // paths don't go around cycles of zero-weight edges
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n2", "n3", 0.0),
+                Edge::with_weight("n3", "n4", 1.0),
+                Edge::with_weight("n4", "n5", 1.0),
+                Edge::with_weight("n5", "n6", 1.0),
+            ])
+            .unwrap();
+        let result = dijkstra::single_source(&graph, true, "n1", None, None, Some(3), false);
+        let result = result.unwrap();
+        assert_eq!(result.get("n2").unwrap().paths, vec![vec!["n1", "n2"]]);
+        assert_eq!(
+            result.get("n4").unwrap().paths,
+            vec![vec!["n1", "n2", "n3", "n4"]]
+        );
+    }
+
+    #[test]
+    fn test_hop_cutoff_distances() {
+        let graph = get_hops_graph();
+        for hop_cutoff in 0..6 {
+            let expected: HashMap<&str, f64> =
+                dijkstra::single_source(&graph, true, "n1", None, None, Some(hop_cutoff), true)
+                    .unwrap()
+                    .into_iter()
+                    .map(|(node, info)| (node, info.distance))
+                    .collect();
+            let result =
+                dijkstra::single_source_distances(&graph, true, "n1", None, Some(hop_cutoff));
+            assert_eq!(result.unwrap(), expected);
+        }
+        let result = dijkstra::all_pairs_distances(&graph, false, None, Some(1)).unwrap();
+        assert_eq!(result.get("n1").unwrap().len(), 4);
+        assert_eq!(result.get("n5").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_hop_cutoff_source_to_target() {
+        let graph = get_hops_graph();
+        let info = dijkstra::shortest_path(&graph, true, "n1", "n4", Some(2)).unwrap().unwrap();
+        assert_eq!(info.distance, 6.0);
+        assert_eq!(info.paths, vec![vec!["n1", "n3", "n4"]]);
+        let info = dijkstra::bidirectional(&graph, true, "n1", "n4", Some(2)).unwrap().unwrap();
+        assert_eq!(info.distance, 6.0);
+        assert_eq!(info.paths, vec![vec!["n1", "n3", "n4"]]);
+        for hop_cutoff in 0..6 {
+            let expected =
+                dijkstra::single_source(&graph, true, "n1", None, None, Some(hop_cutoff), true)
+                    .unwrap()
+                    .remove("n5");
+            let result = dijkstra::shortest_path(&graph, true, "n1", "n5", Some(hop_cutoff));
+            let result = result.unwrap();
+            assert_eq!(
+                result.as_ref().map(|i| i.distance),
+                expected.as_ref().map(|i| i.distance)
+            );
+            let result = dijkstra::bidirectional(&graph, true, "n1", "n5", Some(hop_cutoff));
+            assert_eq!(result.unwrap().map(|i| i.paths), expected.map(|i| i.paths));
+        }
+        assert!(dijkstra::shortest_path(&graph, true, "n1", "n5", Some(1)).unwrap().is_none());
+        assert!(dijkstra::bidirectional(&graph, true, "n1", "n5", Some(1)).unwrap().is_none());
+        let info = dijkstra::bidirectional(&graph, true, "n1", "n1", Some(0)).unwrap().unwrap();
+        assert_eq!(info.paths, vec![vec!["n1"]]);
+    }
+
     fn assert_paths_contain_same_items(v1: &[Vec<&str>], v2: &[Vec<&str>]) {
         let a: HashSet<&Vec<&str>> = v1.iter().collect();
         let b: HashSet<&Vec<&str>> = v2.iter().collect();
@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::shortest_path::{dijkstra, dynamic::DynamicSssp},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::HashMap;
+
+    fn get_graph(specs: GraphSpecs) -> Graph<&'static str, ()> {
+        let edges = vec![
+            Edge::with_weight("a", "b", 1.0),
+            Edge::with_weight("a", "c", 4.0),
+            Edge::with_weight("b", "c", 2.0),
+            Edge::with_weight("b", "d", 5.0),
+            Edge::with_weight("c", "d", 1.0),
+            Edge::with_weight("d", "e", 3.0),
+        ];
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    fn assert_distances_match(sssp: &DynamicSssp<usize>, graph: &Graph<usize, ()>) {
+        let expected: HashMap<usize, f64> =
+            dijkstra::single_source_distances(graph, true, *sssp.get_source(), None, None).unwrap();
+        assert_eq!(sssp.get_distances().len(), expected.len());
+        for (node, distance) in expected {
+            assert!((sssp.get_distance(node).unwrap() - distance).abs() < 1e-9);
+            // the path in the tree must have the same length as the distance
+            let path = sssp.get_path(node).unwrap();
+            let length: f64 =
+                path.windows(2).map(|p| graph.get_edge(p[0], p[1]).unwrap().weight).sum();
+            assert!((length - distance).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_dynamic_sssp_directed() {
+        let graph = get_graph(GraphSpecs::directed_create_missing());
+        let mut sssp = DynamicSssp::new(&graph, true, "a").unwrap();
+        assert_eq!(sssp.get_distance("d"), Some(4.0));
+        assert_eq!(sssp.get_path("e"), Some(vec!["a", "b", "c", "d", "e"]));
+        sssp.edge_removed("c", "d").unwrap();
+        assert_eq!(sssp.get_distance("d"), Some(6.0));
+        assert_eq!(sssp.get_distance("e"), Some(9.0));
+        assert_eq!(sssp.get_parent("d"), Some(&"b"));
+        sssp.edge_added("a", "e", 2.0).unwrap();
+        assert_eq!(sssp.get_distance("e"), Some(2.0));
+        sssp.weight_changed("b", "c", 0.5).unwrap();
+        assert_eq!(sssp.get_distance("c"), Some(1.5));
+        sssp.weight_changed("a", "b", 10.0).unwrap();
+        assert_eq!(sssp.get_distance("b"), Some(10.0));
+        assert_eq!(sssp.get_distance("c"), Some(4.0));
+        assert_eq!(sssp.get_path("c"), Some(vec!["a", "c"]));
+        assert_eq!(sssp.get_distance("d"), Some(15.0));
+    }
+
+    #[test]
+    fn test_dynamic_sssp_unreachable() {
+        let graph = get_graph(GraphSpecs::directed_create_missing());
+        let mut sssp = DynamicSssp::new(&graph, false, "c").unwrap();
+        assert_eq!(sssp.get_distances().len(), 3);
+        assert_eq!(sssp.get_distance("e"), Some(2.0));
+        sssp.edge_removed("c", "d").unwrap();
+        assert_eq!(sssp.get_distances().len(), 1);
+        assert_eq!(sssp.get_path("e"), None);
+        sssp.edge_added("c", "f", 1.0).unwrap();
+        sssp.edge_added("f", "e", 1.0).unwrap();
+        assert_eq!(sssp.get_path("e"), Some(vec!["c", "f", "e"]));
+        assert_eq!(sssp.get_parent("c"), None);
+    }
+
+    #[test]
+    fn test_dynamic_sssp_undirected() {
+        let graph = get_graph(GraphSpecs::undirected_create_missing());
+        let mut sssp = DynamicSssp::new(&graph, true, "e").unwrap();
+        assert_eq!(sssp.get_distance("a"), Some(7.0));
+        sssp.edge_removed("d", "c").unwrap();
+        assert_eq!(sssp.get_distance("a"), Some(9.0));
+        assert_eq!(sssp.get_path("c"), Some(vec!["e", "d", "b", "c"]));
+        sssp.weight_changed("c", "a", 1.0).unwrap();
+        assert_eq!(sssp.get_distance("c"), Some(10.0));
+        sssp.edge_added("e", "a", 1.0).unwrap();
+        assert_eq!(sssp.get_distance("c"), Some(2.0));
+        assert_eq!(sssp.get_distance("b"), Some(2.0));
+    }
+
+    #[test]
+    fn test_dynamic_sssp_random_updates() {
+        for directed in [true, false] {
+            let mut rng = ChaCha8Rng::seed_from_u64(1);
+            let random = generators::random::gnm_random_graph(60, 180, Some(1), directed).unwrap();
+            let specs = match directed {
+                true => GraphSpecs::directed_create_missing(),
+                false => GraphSpecs::undirected_create_missing(),
+            };
+            let mut graph = Graph::<usize, ()>::new(specs);
+            graph.add_nodes(random.get_all_nodes().into_iter().cloned().collect());
+            for edge in random.get_all_edges() {
+                let weight = rng.gen_range(1..10) as f64;
+                graph.add_edge(Edge::with_weight(edge.u, edge.v, weight)).unwrap();
+            }
+            let mut sssp = DynamicSssp::new(&graph, true, 0).unwrap();
+            assert_distances_match(&sssp, &graph);
+            for _i in 0..200 {
+                let u = rng.gen_range(0..60);
+                let v = rng.gen_range(0..60);
+                let weight = rng.gen_range(0..10) as f64;
+                if u == v {
+                    continue;
+                }
+                match (graph.get_edge(u, v).is_ok(), rng.gen_bool(0.5)) {
+                    (false, _) => {
+                        graph.add_edge(Edge::with_weight(u, v, weight)).unwrap();
+                        sssp.edge_added(u, v, weight).unwrap();
+                    }
+                    (true, true) => {
+                        graph.remove_edge(u, v).unwrap();
+                        sssp.edge_removed(u, v).unwrap();
+                    }
+                    (true, false) => {
+                        graph.remove_edge(u, v).unwrap();
+                        graph.add_edge(Edge::with_weight(u, v, weight)).unwrap();
+                        sssp.weight_changed(u, v, weight).unwrap();
+                    }
+                }
+                assert_distances_match(&sssp, &graph);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dynamic_sssp_errors() {
+        let graph = get_graph(GraphSpecs::directed_create_missing());
+        let result = DynamicSssp::new(&graph, true, "z");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let mut sssp = DynamicSssp::new(&graph, true, "a").unwrap();
+        let result = sssp.edge_added("a", "b", 1.0);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::DuplicateEdge));
+        let result = sssp.edge_added("a", "z", -1.0);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = sssp.edge_removed("b", "a");
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::EdgeNotFound));
+        let result = sssp.weight_changed("a", "b", f64::NAN);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        assert_eq!(sssp.get_distance("b"), Some(1.0));
+        let graph = Graph::<&str, ()>::new_from_nodes_and_edges(
+            vec![],
+            vec![Edge::with_weight("a", "b", -1.0)],
+            GraphSpecs::directed_create_missing(),
+        )
+        .unwrap();
+        let result = DynamicSssp::new(&graph, true, "a");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+}
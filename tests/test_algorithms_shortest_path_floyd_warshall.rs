@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::shortest_path::{bellman_ford, dijkstra, floyd_warshall},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+
+    #[test]
+    fn test_all_pairs_1() {
+        // compare against dijkstra on an undirected unweighted graph
+        let graph = generators::social::karate_club_graph();
+        let matrix = floyd_warshall::all_pairs(&graph, false, true).unwrap();
+        let expected = dijkstra::all_pairs(&graph, false, None, None, false).unwrap();
+        assert_eq!(matrix.nodes.len(), 34);
+        for (u, targets) in expected {
+            for (v, info) in targets {
+                assert_eq!(matrix.get_distance(&u, &v).unwrap(), info.distance);
+                let path = matrix.get_path(&u, &v).unwrap();
+                assert!(info.paths.contains(&path));
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_pairs_2() {
+        // negative weights; compare against bellman-ford
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 4.0),
+            Edge::with_weight("n1", "n3", 2.0),
+            Edge::with_weight("n2", "n4", -3.0),
+            Edge::with_weight("n3", "n4", 1.0),
+            Edge::with_weight("n4", "n5", 2.0),
+            Edge::with_weight("n5", "n1", 1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let matrix = floyd_warshall::all_pairs(&graph, true, true).unwrap();
+        let expected = bellman_ford::all_pairs(&graph, true, false).unwrap();
+        for (u, targets) in expected {
+            for (v, info) in targets {
+                assert_eq!(matrix.get_distance(&u, &v).unwrap(), info.distance);
+                let path = matrix.get_path(&u, &v).unwrap();
+                assert!(info.paths.contains(&path));
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_pairs_3() {
+        // unreachable nodes and no predecessors
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.5),
+            Edge::with_weight("n3", "n4", 1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let matrix = floyd_warshall::all_pairs(&graph, true, false).unwrap();
+        assert_eq!(matrix.nodes, vec!["n1", "n2", "n3", "n4"]);
+        assert_eq!(
+            matrix.distances[0],
+            vec![0.0, 1.5, f64::INFINITY, f64::INFINITY]
+        );
+        assert_eq!(matrix.get_distance(&"n2", &"n1"), Some(f64::INFINITY));
+        assert_eq!(matrix.get_distance(&"n1", &"n9"), None);
+        assert_eq!(matrix.get_path(&"n1", &"n2"), None);
+    }
+
+    #[test]
+    fn test_all_pairs_4() {
+        // a multigraph uses the lowest weight edge
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 5.0),
+            Edge::with_weight("n1", "n2", 2.0),
+            Edge::with_weight("n2", "n3", 1.0),
+        ];
+        let specs = GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::directed_create_missing()
+        };
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let matrix = floyd_warshall::all_pairs(&graph, true, true).unwrap();
+        assert_eq!(matrix.get_distance(&"n1", &"n3"), Some(3.0));
+        assert_eq!(matrix.get_path(&"n1", &"n3"), Some(vec!["n1", "n2", "n3"]));
+    }
+
+    #[test]
+    fn test_all_pairs_5() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n3", -2.0),
+            Edge::with_weight("n3", "n1", 0.5),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = floyd_warshall::all_pairs(&graph, true, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NegativeCycle
+        ));
+    }
+}
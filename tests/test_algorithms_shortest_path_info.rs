@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::algorithms::shortest_path::ShortestPathInfo;
+
+    fn get_info() -> ShortestPathInfo<&'static str> {
+        ShortestPathInfo::new(
+            3.0,
+            vec![vec!["n1", "n2", "n4"], vec!["n1", "n3", "n5", "n4"]],
+        )
+    }
+
+    #[test]
+    fn test_first_path_and_path_length() {
+        let info = get_info();
+        assert_eq!(info.first_path(), Some(&vec!["n1", "n2", "n4"]));
+        assert_eq!(info.path_length(), Some(2));
+        assert_eq!(info.num_paths(), 2);
+        let info: ShortestPathInfo<&str> = ShortestPathInfo::new(0.0, vec![vec!["n1"]]);
+        assert_eq!(info.path_length(), Some(0));
+        let info: ShortestPathInfo<&str> = ShortestPathInfo::new(0.0, vec![]);
+        assert_eq!(info.first_path(), None);
+        assert_eq!(info.path_length(), None);
+        assert_eq!(info.num_paths(), 0);
+    }
+
+    #[test]
+    fn test_contains_node() {
+        let info = get_info();
+        assert!(info.contains_node("n1"));
+        assert!(info.contains_node("n5"));
+        assert!(!info.contains_node("n6"));
+        assert!(!info.contains_path_through_node("n1"));
+        assert!(info.contains_path_through_node("n5"));
+    }
+
+    #[test]
+    fn test_iterate_paths() {
+        let info = get_info();
+        let lengths: Vec<usize> = info.paths_iter().map(|p| p.len()).collect();
+        assert_eq!(lengths, vec![3, 4]);
+        let mut count = 0;
+        for path in &info {
+            assert_eq!(path.last(), Some(&"n4"));
+            count += 1;
+        }
+        assert_eq!(count, 2);
+        let paths: Vec<Vec<&str>> = info.into_iter().collect();
+        assert_eq!(paths[1], vec!["n1", "n3", "n5", "n4"]);
+    }
+}
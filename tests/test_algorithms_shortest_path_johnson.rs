@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::shortest_path::{bellman_ford, dijkstra, johnson},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+
+    #[test]
+    fn test_all_pairs_1() {
+        // negative weights; compare against bellman-ford
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 4.0),
+            Edge::with_weight("n1", "n3", 2.0),
+            Edge::with_weight("n2", "n4", -3.0),
+            Edge::with_weight("n3", "n4", 1.0),
+            Edge::with_weight("n4", "n5", 2.0),
+            Edge::with_weight("n5", "n1", 1.0),
+            Edge::with_weight("n3", "n5", -1.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = johnson::all_pairs(&graph, true, false).unwrap();
+        let expected = bellman_ford::all_pairs(&graph, true, false).unwrap();
+        assert_eq!(result.len(), expected.len());
+        for (u, targets) in expected {
+            assert_eq!(result.get(u).unwrap().len(), targets.len());
+            for (v, info) in targets {
+                let actual = result.get(u).unwrap().get(v).unwrap();
+                assert_eq!(actual.distance, info.distance);
+                assert_eq!(actual.paths.len(), info.paths.len());
+                for path in actual.paths.iter() {
+                    assert!(info.paths.contains(path));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_pairs_2() {
+        // unweighted results match dijkstra
+        let graph = generators::social::karate_club_graph();
+        let result = johnson::all_pairs(&graph, false, true).unwrap();
+        let expected = dijkstra::all_pairs(&graph, false, None, None, true).unwrap();
+        for (u, targets) in expected {
+            for (v, info) in targets {
+                let actual = result.get(&u).unwrap().get(&v).unwrap();
+                assert_eq!(actual.distance, info.distance);
+                assert_eq!(actual.paths[0].len(), info.paths[0].len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_pairs_3() {
+        // unreachable nodes are left out
+        let edges = vec![
+            Edge::with_weight("n1", "n2", -1.0),
+            Edge::with_weight("n3", "n2", 2.0),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = johnson::all_pairs(&graph, true, false).unwrap();
+        assert_eq!(result.get("n1").unwrap().len(), 2);
+        assert_eq!(result.get("n1").unwrap().get("n2").unwrap().distance, -1.0);
+        assert_eq!(result.get("n2").unwrap().len(), 1);
+        assert!(result.get("n3").unwrap().get("n1").is_none());
+    }
+
+    #[test]
+    fn test_all_pairs_4() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n3", -2.0),
+            Edge::with_weight("n3", "n1", 0.5),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = johnson::all_pairs(&graph, true, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NegativeCycle
+        ));
+    }
+
+    #[test]
+    fn test_all_pairs_tied_paths() {
+        // reweighting makes the two n1 -> n4 paths, which tie in the original weights,
+        // differ by a rounding error
+        let edges = vec![
+            Edge::with_weight("n1", "n5", 1.1),
+            Edge::with_weight("n5", "n4", 1.1),
+            Edge::with_weight("n5", "n0", 0.4),
+            Edge::with_weight("n0", "n4", 0.7),
+            Edge::with_weight("n4", "n3", -0.3),
+            Edge::with_weight("n3", "n1", 0.2),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = johnson::all_pairs(&graph, true, false).unwrap();
+        let mut paths = result["n1"]["n4"].paths.clone();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![vec!["n1", "n5", "n0", "n4"], vec!["n1", "n5", "n4"]]
+        );
+        let expected = bellman_ford::all_pairs(&graph, true, false).unwrap();
+        for (u, targets) in expected {
+            for (v, info) in targets {
+                let actual = &result[u][v];
+                assert_eq!(actual.num_paths(), info.num_paths());
+                for path in info.paths.iter() {
+                    assert!(actual.paths.contains(path));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_pairs_tied_paths_without_negative_weights() {
+        // all shortest paths match dijkstra
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 0.1),
+                Edge::with_weight("n1", "n3", 0.2),
+                Edge::with_weight("n2", "n4", 0.2),
+                Edge::with_weight("n3", "n4", 0.1),
+                Edge::with_weight("n4", "n5", 0.3),
+                Edge::with_weight("n2", "n5", 0.5),
+            ])
+            .unwrap();
+        let result = johnson::all_pairs(&graph, true, false).unwrap();
+        let expected = dijkstra::all_pairs(&graph, true, None, None, false).unwrap();
+        assert_eq!(result["n1"]["n4"].num_paths(), 2);
+        for (u, targets) in expected {
+            for (v, info) in targets {
+                let mut actual_paths = result[u][v].paths.clone();
+                let mut expected_paths = info.paths.clone();
+                actual_paths.sort();
+                expected_paths.sort();
+                assert_eq!(result[u][v].distance, info.distance);
+                assert_eq!(actual_paths, expected_paths);
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::{components, shortest_path},
+        generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+    use std::collections::HashSet;
+
+    fn get_directed_graph() -> Graph<i32, ()> {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph.add_node(Node::from_name(7));
+        graph
+            .add_edges(vec![
+                Edge::new(1, 2),
+                Edge::new(2, 3),
+                Edge::new(3, 1),
+                Edge::new(3, 4),
+                Edge::new(5, 4),
+                Edge::new(6, 6),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_has_path_directed() {
+        let graph = get_directed_graph();
+        assert!(shortest_path::has_path(&graph, 1, 4).unwrap());
+        assert!(shortest_path::has_path(&graph, 3, 2).unwrap());
+        assert!(!shortest_path::has_path(&graph, 4, 1).unwrap());
+        assert!(!shortest_path::has_path(&graph, 1, 5).unwrap());
+        assert!(shortest_path::has_path(&graph, 7, 7).unwrap());
+        assert!(shortest_path::has_path(&graph, 6, 6).unwrap());
+        assert!(!shortest_path::has_path(&graph, 6, 7).unwrap());
+    }
+
+    #[test]
+    fn test_has_path_undirected() {
+        let graph = generators::social::karate_club_graph();
+        for node in graph.get_all_node_names() {
+            assert!(shortest_path::has_path(&graph, 0, *node).unwrap());
+        }
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        graph.add_edges(vec![Edge::new(1, 2), Edge::new(3, 2), Edge::new(4, 5)]).unwrap();
+        assert!(shortest_path::has_path(&graph, 1, 3).unwrap());
+        assert!(shortest_path::has_path(&graph, 3, 1).unwrap());
+        assert!(!shortest_path::has_path(&graph, 1, 5).unwrap());
+    }
+
+    #[test]
+    fn test_has_path_node_not_found() {
+        let graph = get_directed_graph();
+        let result = shortest_path::has_path(&graph, 9, 1);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = shortest_path::has_path(&graph, 1, 9);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_reachable_from() {
+        let graph = get_directed_graph();
+        let reachable = shortest_path::reachable_from(&graph, 2).unwrap();
+        assert_eq!(reachable, HashSet::from([1, 2, 3, 4]));
+        let reachable = shortest_path::reachable_from(&graph, 5).unwrap();
+        assert_eq!(reachable, HashSet::from([4, 5]));
+        let reachable = shortest_path::reachable_from(&graph, 7).unwrap();
+        assert_eq!(reachable, HashSet::from([7]));
+        let result = shortest_path::reachable_from(&graph, 9);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_reachable_from_matches_components() {
+        let graph = generators::random::fast_gnp_random_graph(200, 0.01, false, Some(1)).unwrap();
+        for component in components::connected_components(&graph).unwrap() {
+            let reachable = shortest_path::reachable_from(&graph, component[0]).unwrap();
+            assert_eq!(reachable, component.into_iter().collect());
+        }
+    }
+}
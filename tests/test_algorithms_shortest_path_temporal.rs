@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::shortest_path::temporal, ErrorKind, TemporalEdge, TemporalGraph};
+
+    fn get_graph(directed: bool) -> TemporalGraph<&'static str> {
+        let mut graph = TemporalGraph::new(directed);
+        graph
+            .add_edges(vec![
+                TemporalEdge::new("a", "b", 1.0),
+                TemporalEdge::new("a", "c", 4.0),
+                TemporalEdge::new("b", "c", 2.0),
+                TemporalEdge::new("c", "d", 3.0),
+                TemporalEdge::new("c", "d", 5.0),
+                TemporalEdge::new("d", "e", 4.0),
+                TemporalEdge::new("e", "b", 6.0),
+            ])
+            .unwrap();
+        graph.add_node("f");
+        graph
+    }
+
+    #[test]
+    fn test_earliest_arrival_directed() {
+        let graph = get_graph(true);
+        let arrivals = temporal::earliest_arrival(&graph, "a", 0.0).unwrap();
+        assert_eq!(arrivals.len(), 5);
+        assert_eq!(arrivals.get("a"), Some(&0.0));
+        assert_eq!(arrivals.get("b"), Some(&1.0));
+        assert_eq!(arrivals.get("c"), Some(&2.0));
+        assert_eq!(arrivals.get("d"), Some(&3.0));
+        assert_eq!(arrivals.get("e"), Some(&4.0));
+        assert_eq!(arrivals.get("f"), None);
+    }
+
+    #[test]
+    fn test_earliest_arrival_later_departure() {
+        let graph = get_graph(true);
+        let arrivals = temporal::earliest_arrival(&graph, "a", 3.0).unwrap();
+        assert_eq!(arrivals.len(), 3);
+        assert_eq!(arrivals.get("c"), Some(&4.0));
+        assert_eq!(arrivals.get("d"), Some(&5.0));
+        // the "d" to "e" edge leaves before "d" is reached
+        assert_eq!(arrivals.get("e"), None);
+        assert_eq!(arrivals.get("b"), None);
+        let arrivals = temporal::earliest_arrival(&graph, "a", 4.5).unwrap();
+        assert_eq!(arrivals.len(), 1);
+    }
+
+    #[test]
+    fn test_earliest_arrival_undirected() {
+        let graph = get_graph(false);
+        assert!(!graph.is_directed());
+        let arrivals = temporal::earliest_arrival(&graph, "e", 0.0).unwrap();
+        assert_eq!(arrivals.get("d"), Some(&4.0));
+        assert_eq!(arrivals.get("c"), Some(&5.0));
+        assert_eq!(arrivals.get("b"), Some(&6.0));
+        assert_eq!(arrivals.get("a"), None);
+    }
+
+    #[test]
+    fn test_earliest_arrival_equal_times_and_durations() {
+        let mut graph = TemporalGraph::new(true);
+        graph
+            .add_edges(vec![
+                TemporalEdge::new(2, 3, 1.0),
+                TemporalEdge::new(1, 2, 1.0),
+                TemporalEdge::with_duration(3, 4, 1.0, 0.5),
+                TemporalEdge::new(4, 5, 1.0),
+                TemporalEdge::with_duration(1, 5, 0.0, 3.0),
+            ])
+            .unwrap();
+        let arrivals = temporal::earliest_arrival(&graph, 1, 0.5).unwrap();
+        assert_eq!(arrivals.get(&3), Some(&1.0));
+        assert_eq!(arrivals.get(&4), Some(&1.5));
+        assert_eq!(arrivals.get(&5), None);
+        let arrivals = temporal::earliest_arrival(&graph, 1, 0.0).unwrap();
+        assert_eq!(arrivals.get(&5), Some(&3.0));
+    }
+
+    #[test]
+    fn test_earliest_arrival_node_not_found() {
+        let graph = get_graph(true);
+        let result = temporal::earliest_arrival(&graph, "z", 0.0);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::NodeNotFound));
+    }
+
+    #[test]
+    fn test_temporal_graph() {
+        let mut graph = get_graph(false);
+        assert_eq!(
+            graph.get_all_node_names(),
+            vec![&"a", &"b", &"c", &"d", &"e", &"f"]
+        );
+        assert_eq!(graph.get_all_edges().len(), 7);
+        assert_eq!(graph.get_edges_from("c").len(), 4);
+        assert!(graph.get_edges_from("f").is_empty());
+        let result = graph.add_edge(TemporalEdge::with_duration("a", "f", 1.0, -1.0));
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = graph.add_edge(TemporalEdge::new("a", "f", f64::NAN));
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        assert_eq!(graph.get_all_edges().len(), 7);
+        assert_eq!(get_graph(true).get_edges_from("c").len(), 2);
+    }
+}
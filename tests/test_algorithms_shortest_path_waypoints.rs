@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::shortest_path::{self, dijkstra},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+
+    fn get_directed_graph() -> Graph<&'static str, ()> {
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("a", "b", 1.0),
+                Edge::with_weight("b", "c", 2.0),
+                Edge::with_weight("c", "d", 1.0),
+                Edge::with_weight("a", "d", 1.0),
+                Edge::with_weight("d", "b", 4.0),
+                Edge::with_weight("c", "a", 1.0),
+                Edge::with_weight("e", "a", 1.0),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_via_waypoints_in_order() {
+        let graph = get_directed_graph();
+        let info =
+            shortest_path::via_waypoints(&graph, true, "a", &["c"], "d", false).unwrap().unwrap();
+        assert_eq!(info.distance, 4.0);
+        assert_eq!(info.paths, vec![vec!["a", "b", "c", "d"]]);
+        let info = shortest_path::via_waypoints(&graph, true, "a", &["d", "c"], "a", false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.distance, 8.0);
+        assert_eq!(info.paths, vec![vec!["a", "d", "b", "c", "a"]]);
+        let info = shortest_path::via_waypoints(&graph, false, "a", &["d", "c"], "a", false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.distance, 4.0);
+        assert_eq!(info.edge_keys, None);
+    }
+
+    #[test]
+    fn test_via_waypoints_no_waypoints() {
+        let graph = generators::social::karate_club_graph();
+        let info = shortest_path::via_waypoints(&graph, false, 0, &[], 29, false).unwrap().unwrap();
+        let shortest = dijkstra::shortest_path(&graph, false, 0, 29, None).unwrap().unwrap();
+        assert_eq!(info.distance, shortest.distance);
+        assert!(shortest.paths.contains(&info.paths[0]));
+    }
+
+    #[test]
+    fn test_via_waypoints_any_order() {
+        let graph = get_directed_graph();
+        let info = shortest_path::via_waypoints(&graph, true, "a", &["c", "d"], "a", false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.distance, 11.0);
+        let info = shortest_path::via_waypoints(&graph, true, "a", &["c", "d"], "a", true)
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.distance, 8.0);
+        assert_eq!(info.paths, vec![vec!["a", "d", "b", "c", "a"]]);
+        // ties go to the first order of the waypoints
+        let graph = generators::classic::cycle_graph(6, false);
+        let info =
+            shortest_path::via_waypoints(&graph, false, 0, &[4, 2], 0, true).unwrap().unwrap();
+        assert_eq!(info.distance, 6.0);
+        assert_eq!(info.paths, vec![vec![0, 5, 4, 3, 2, 1, 0]]);
+    }
+
+    #[test]
+    fn test_via_waypoints_repeated_nodes() {
+        let graph = get_directed_graph();
+        for any_order in [false, true] {
+            let info =
+                shortest_path::via_waypoints(&graph, true, "a", &["a", "c", "c"], "c", any_order)
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(info.distance, 3.0);
+            assert_eq!(info.paths, vec![vec!["a", "b", "c"]]);
+        }
+    }
+
+    #[test]
+    fn test_via_waypoints_unreachable() {
+        let graph = get_directed_graph();
+        let result = shortest_path::via_waypoints(&graph, true, "a", &["e"], "d", false).unwrap();
+        assert!(result.is_none());
+        let result = shortest_path::via_waypoints(&graph, true, "a", &["b", "e"], "d", true);
+        assert!(result.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_via_waypoints_multi_edges() {
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::undirected_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight("a", "b", 3.0),
+                Edge::with_weight("a", "b", 1.0),
+                Edge::with_weight("b", "c", 1.0),
+            ])
+            .unwrap();
+        let info =
+            shortest_path::via_waypoints(&graph, true, "c", &["a"], "b", false).unwrap().unwrap();
+        assert_eq!(info.distance, 3.0);
+        assert_eq!(info.paths, vec![vec!["c", "b", "a", "b"]]);
+        assert_eq!(info.edge_keys, Some(vec![vec![0, 1, 1]]));
+    }
+
+    #[test]
+    fn test_via_waypoints_errors() {
+        let graph = get_directed_graph();
+        let result = shortest_path::via_waypoints(&graph, true, "a", &["z"], "d", false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = shortest_path::via_waypoints(&graph, true, "a", &["b"], "z", true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let waypoints = ["b"; 9];
+        let result = shortest_path::via_waypoints(&graph, true, "a", &waypoints, "d", true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let info = shortest_path::via_waypoints(&graph, true, "a", &waypoints, "d", false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.distance, 4.0);
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::shortest_path::yen, Edge, ErrorKind, Graph, GraphSpecs};
+
+    fn get_graph(specs: GraphSpecs) -> Graph<&'static str, ()> {
+        let edges = vec![
+            Edge::with_weight("C", "D", 3.0),
+            Edge::with_weight("C", "E", 2.0),
+            Edge::with_weight("D", "F", 4.0),
+            Edge::with_weight("E", "D", 1.0),
+            Edge::with_weight("E", "F", 2.0),
+            Edge::with_weight("E", "G", 3.0),
+            Edge::with_weight("F", "G", 2.0),
+            Edge::with_weight("F", "H", 1.0),
+            Edge::with_weight("G", "H", 2.0),
+        ];
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    #[test]
+    fn test_k_shortest_paths_1() {
+        // the example from the Wikipedia article on Yen's algorithm
+        let graph = get_graph(GraphSpecs::directed_create_missing());
+        let result = yen::k_shortest_paths(&graph, true, "C", "H", 3).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].distance, 5.0);
+        assert_eq!(result[0].paths, vec![vec!["C", "E", "F", "H"]]);
+        assert_eq!(result[1].distance, 7.0);
+        assert_eq!(result[1].paths, vec![vec!["C", "E", "G", "H"]]);
+        assert_eq!(result[2].distance, 8.0);
+        assert_eq!(result[2].paths, vec![vec!["C", "D", "F", "H"]]);
+    }
+
+    #[test]
+    fn test_k_shortest_paths_2() {
+        // asking for more paths than exist returns all simple paths
+        let graph = get_graph(GraphSpecs::directed_create_missing());
+        let result = yen::k_shortest_paths(&graph, true, "C", "H", 100).unwrap();
+        assert_eq!(result.len(), 7);
+        for pair in result.windows(2) {
+            assert!(pair[0].distance <= pair[1].distance);
+        }
+        let result = yen::k_shortest_paths(&graph, true, "C", "H", 0).unwrap();
+        assert_eq!(result.len(), 0);
+    }
+
+    #[test]
+    fn test_k_shortest_paths_3() {
+        // unweighted and undirected
+        let graph = get_graph(GraphSpecs::undirected_create_missing());
+        let result = yen::k_shortest_paths(&graph, false, "C", "H", 4).unwrap();
+        assert_eq!(result.len(), 4);
+        assert_eq!(result[0].distance, 3.0);
+        assert_eq!(result[3].distance, 4.0);
+        for info in result {
+            let path = &info.paths[0];
+            assert_eq!(path.len() as f64, info.distance + 1.0);
+            let mut nodes = path.clone();
+            nodes.sort();
+            nodes.dedup();
+            assert_eq!(nodes.len(), path.len());
+        }
+    }
+
+    #[test]
+    fn test_k_shortest_paths_integer_weights() {
+        let mut graph: Graph<&str, (), u64> =
+            Graph::new_with_weight_type(GraphSpecs::directed_create_missing());
+        let f64_graph = get_graph(GraphSpecs::directed_create_missing());
+        for edge in f64_graph.get_all_edges() {
+            let weight = edge.weight as u64;
+            graph.add_edge(Edge::with_weight(edge.u, edge.v, weight)).unwrap();
+        }
+        let result = yen::k_shortest_paths(&graph, true, "C", "H", 3).unwrap();
+        let distances: Vec<u64> = result.iter().map(|info| info.distance).collect();
+        assert_eq!(distances, vec![5, 7, 8]);
+        assert_eq!(result[2].paths, vec![vec!["C", "D", "F", "H"]]);
+        let result = yen::k_shortest_paths(&graph, false, "C", "H", 1).unwrap();
+        assert_eq!(result[0].distance, 3);
+    }
+
+    #[test]
+    fn test_k_shortest_paths_4() {
+        let graph = get_graph(GraphSpecs::directed_create_missing());
+        let result = yen::k_shortest_paths(&graph, true, "H", "C", 3).unwrap();
+        assert_eq!(result.len(), 0);
+        let result = yen::k_shortest_paths(&graph, true, "C", "Z", 3);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+}
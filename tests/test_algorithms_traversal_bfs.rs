@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::traversal::bfs, generators, Direction, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct Recorder {
+        discovered: Vec<(i32, usize)>,
+        tree_edges: Vec<(i32, i32)>,
+        non_tree_edges: Vec<(i32, i32)>,
+    }
+
+    impl bfs::BfsVisitor<i32> for Recorder {
+        fn on_discover(&mut self, node: &i32, depth: usize) {
+            self.discovered.push((*node, depth));
+        }
+        fn on_tree_edge(&mut self, u: &i32, v: &i32) {
+            self.tree_edges.push((*u, *v));
+        }
+        fn on_non_tree_edge(&mut self, u: &i32, v: &i32) {
+            self.non_tree_edges.push((*u, *v));
+        }
+    }
+
+    #[test]
+    fn test_breadth_first_nodes_1() {
+        let graph = generators::social::karate_club_graph();
+        let nodes: Vec<i32> = bfs::breadth_first_nodes(&graph, 0).unwrap().collect();
+        assert_eq!(nodes.len(), 34);
+        assert_eq!(nodes[0], 0);
+        assert_eq!(nodes[1..4], [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_breadth_first_nodes_2() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::new(1, 2), Edge::new(2, 3), Edge::new(4, 1)]).unwrap();
+        let nodes: Vec<i32> = bfs::breadth_first_nodes(&graph, 2).unwrap().collect();
+        assert_eq!(nodes, vec![2, 3]);
+        let result = bfs::breadth_first_nodes(&graph, 9);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_breadth_first_search_1() {
+        // depths match unweighted shortest path lengths
+        let graph = generators::social::karate_club_graph();
+        let mut recorder = Recorder::default();
+        bfs::breadth_first_search(&graph, 0, &mut recorder).unwrap();
+        let depths: HashMap<i32, usize> = recorder.discovered.iter().cloned().collect();
+        assert_eq!(depths.len(), 34);
+        assert_eq!(depths.get(&0), Some(&0));
+        assert_eq!(depths.get(&1), Some(&1));
+        assert_eq!(depths.get(&33), Some(&2));
+        assert_eq!(depths.get(&29), Some(&3));
+        // every edge is either a tree edge or a non-tree edge
+        assert_eq!(recorder.tree_edges.len(), 33);
+        assert_eq!(
+            recorder.tree_edges.len() + recorder.non_tree_edges.len(),
+            graph.get_all_edges().len()
+        );
+    }
+
+    #[test]
+    fn test_breadth_first_search_2() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![
+            Edge::new(1, 2),
+            Edge::new(1, 3),
+            Edge::new(2, 4),
+            Edge::new(3, 4),
+            Edge::new(4, 1),
+        ]).unwrap();
+        let mut recorder = Recorder::default();
+        bfs::breadth_first_search(&graph, 1, &mut recorder).unwrap();
+        assert_eq!(recorder.discovered, vec![(1, 0), (2, 1), (3, 1), (4, 2)]);
+        assert_eq!(recorder.tree_edges, vec![(1, 2), (1, 3), (2, 4)]);
+        assert_eq!(recorder.non_tree_edges, vec![(3, 4), (4, 1)]);
+    }
+
+    #[test]
+    fn test_breadth_first_nodes_incoming() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::new(1, 2), Edge::new(2, 3), Edge::new(4, 1)]).unwrap();
+        let nodes: Vec<i32> = bfs::breadth_first_nodes_in_direction(&graph, 2, Direction::Incoming)
+            .unwrap()
+            .collect();
+        assert_eq!(nodes, vec![2, 1, 4]);
+        let nodes: Vec<i32> = bfs::breadth_first_nodes_in_direction(&graph, 2, Direction::Outgoing)
+            .unwrap()
+            .collect();
+        assert_eq!(nodes, vec![2, 3]);
+        let graph = generators::social::karate_club_graph();
+        let outgoing: Vec<i32> = bfs::breadth_first_nodes(&graph, 0).unwrap().collect();
+        let incoming: Vec<i32> =
+            bfs::breadth_first_nodes_in_direction(&graph, 0, Direction::Incoming)
+                .unwrap()
+                .collect();
+        assert_eq!(incoming, outgoing);
+    }
+
+    #[test]
+    fn test_breadth_first_search_incoming() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::new(1, 2),
+                Edge::new(1, 3),
+                Edge::new(2, 4),
+                Edge::new(3, 4),
+                Edge::new(4, 5),
+            ])
+            .unwrap();
+        let mut recorder = Recorder::default();
+        bfs::breadth_first_search_in_direction(&graph, 4, Direction::Incoming, &mut recorder)
+            .unwrap();
+        assert_eq!(recorder.discovered, vec![(4, 0), (2, 1), (3, 1), (1, 2)]);
+        assert_eq!(recorder.tree_edges, vec![(4, 2), (4, 3), (2, 1)]);
+        assert_eq!(recorder.non_tree_edges, vec![(3, 1)]);
+        let result =
+            bfs::breadth_first_search_in_direction(&graph, 9, Direction::Incoming, &mut recorder);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+}
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::traversal::dfs::{self, DfsEdgeType},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+
+    #[test]
+    fn test_depth_first_search_1() {
+        // the directed example from Cormen et al.
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::new("u", "v"),
+                Edge::new("u", "x"),
+                Edge::new("v", "y"),
+                Edge::new("w", "y"),
+                Edge::new("w", "z"),
+                Edge::new("x", "v"),
+                Edge::new("y", "x"),
+                Edge::new("z", "z"),
+            ])
+            .unwrap();
+        let result = dfs::depth_first_search(&graph, None).unwrap();
+        assert_eq!(result.preorder, vec!["u", "v", "y", "x", "w", "z"]);
+        assert_eq!(result.postorder, vec!["x", "y", "v", "u", "z", "w"]);
+        assert_eq!(result.discovery.get("u"), Some(&0));
+        assert_eq!(result.finish.get("u"), Some(&7));
+        assert_eq!(result.discovery.get("w"), Some(&8));
+        assert_eq!(result.finish.get("w"), Some(&11));
+        assert_eq!(result.parents.get("x"), Some(&"y"));
+        assert!(!result.parents.contains_key("w"));
+        let edge_type = |u, v| result.edges.iter().find(|e| e.0 == u && e.1 == v).unwrap().2;
+        assert_eq!(edge_type("u", "v"), DfsEdgeType::Tree);
+        assert_eq!(edge_type("x", "v"), DfsEdgeType::Back);
+        assert_eq!(edge_type("u", "x"), DfsEdgeType::Forward);
+        assert_eq!(edge_type("w", "y"), DfsEdgeType::Cross);
+        assert_eq!(edge_type("z", "z"), DfsEdgeType::Back);
+        assert_eq!(result.edges.len(), 8);
+    }
+
+    #[test]
+    fn test_depth_first_search_2() {
+        // undirected graphs only have tree and back edges, each reported once
+        let graph = generators::social::karate_club_graph();
+        let result = dfs::depth_first_search(&graph, Some(0)).unwrap();
+        assert_eq!(result.preorder.len(), 34);
+        assert_eq!(result.edges.len(), graph.get_all_edges().len());
+        let tree_edges = result.edges.iter().filter(|e| e.2 == DfsEdgeType::Tree).count();
+        assert_eq!(tree_edges, 33);
+        for (u, v, edge_type) in result.edges.iter() {
+            if *edge_type == DfsEdgeType::Back {
+                // v is an ancestor of u
+                assert!(result.discovery.get(v) < result.discovery.get(u));
+                assert!(result.finish.get(v) > result.finish.get(u));
+            }
+        }
+    }
+
+    #[test]
+    fn test_depth_first_search_3() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::new(1, 2), Edge::new(3, 1)]).unwrap();
+        let result = dfs::depth_first_search(&graph, Some(1)).unwrap();
+        assert_eq!(result.preorder, vec![1, 2]);
+        let result = dfs::depth_first_search(&graph, Some(4));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_depth_first_search_4() {
+        // a long path doesn't overflow the stack
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edges((0..100_000).map(|i| Edge::new(i, i + 1)).collect()).unwrap();
+        let result = dfs::depth_first_search(&graph, Some(0)).unwrap();
+        assert_eq!(result.postorder[0], 100_000);
+    }
+}
@@ -0,0 +1,246 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::tree::{self, SpanningTreeAlgorithm},
+        generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+
+    const ALGORITHMS: [SpanningTreeAlgorithm; 3] = [
+        SpanningTreeAlgorithm::Boruvka,
+        SpanningTreeAlgorithm::Kruskal,
+        SpanningTreeAlgorithm::Prim,
+    ];
+
+    fn get_graph() -> Graph<i32, ()> {
+        let edges = vec![
+            (0, 7, 11.0),
+            (0, 2, 5.0),
+            (0, 3, 13.0),
+            (0, 5, 2.0),
+            (0, 9, 3.0),
+            (0, 4, 18.0),
+            (0, 11, 4.0),
+            (1, 5, 12.0),
+            (1, 9, 19.0),
+            (1, 2, 2.0),
+            (1, 4, 17.0),
+            (2, 6, 7.0),
+            (2, 9, 2.0),
+            (2, 3, 3.0),
+            (2, 4, 14.0),
+            (2, 5, 14.0),
+            (2, 11, 3.0),
+            (3, 10, 8.0),
+            (3, 7, 3.0),
+            (3, 11, 18.0),
+            (3, 8, 14.0),
+            (4, 9, 2.0),
+            (4, 6, 19.0),
+            (4, 5, 4.0),
+            (5, 11, 8.0),
+            (6, 8, 19.0),
+            (7, 9, 2.0),
+            (7, 11, 19.0),
+            (8, 10, 19.0),
+            (9, 10, 13.0),
+        ];
+        let edges = edges.into_iter().map(|(u, v, w)| Edge::with_weight(u, v, w)).collect();
+        Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+            .unwrap()
+    }
+
+    fn get_sorted_edges(graph: &Graph<i32, ()>) -> Vec<(i32, i32)> {
+        let mut edges: Vec<(i32, i32)> =
+            graph.get_all_edges().into_iter().map(|e| (e.u.min(e.v), e.u.max(e.v))).collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree_1() {
+        let graph = get_graph();
+        for algorithm in ALGORITHMS {
+            let mst = tree::minimum_spanning_tree(&graph, algorithm, true, false).unwrap();
+            assert_eq!(mst.get_all_nodes().len(), 12);
+            assert_eq!(mst.size(true), 48.0);
+            assert_eq!(
+                get_sorted_edges(&mst),
+                vec![
+                    (0, 5),
+                    (0, 9),
+                    (1, 2),
+                    (2, 3),
+                    (2, 6),
+                    (2, 9),
+                    (2, 11),
+                    (3, 8),
+                    (3, 10),
+                    (4, 9),
+                    (7, 9)
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree_2() {
+        // maximum
+        let graph = get_graph();
+        for algorithm in ALGORITHMS {
+            let mst = tree::minimum_spanning_tree(&graph, algorithm, true, true).unwrap();
+            assert_eq!(mst.get_all_edges().len(), 11);
+            assert_eq!(mst.size(true), 190.0);
+        }
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree_3() {
+        // disconnected and unweighted
+        let mut graph = generators::social::karate_club_graph();
+        graph.add_nodes(vec![
+            Node::from_name(40),
+            Node::from_name(41),
+            Node::from_name(50),
+        ]);
+        graph.add_edge(Edge::new(40, 41)).unwrap();
+        for algorithm in ALGORITHMS {
+            let mst = tree::minimum_spanning_tree(&graph, algorithm, false, false).unwrap();
+            assert_eq!(mst.get_all_nodes().len(), 37);
+            assert_eq!(mst.get_all_edges().len(), 34);
+        }
+    }
+
+    #[test]
+    fn test_minimum_spanning_edges_1() {
+        let graph = get_graph();
+        let edges: Vec<(i32, i32)> =
+            tree::minimum_spanning_edges(&graph, SpanningTreeAlgorithm::Kruskal, true, false)
+                .unwrap()
+                .map(|e| (e.u, e.v))
+                .take(4)
+                .collect();
+        assert_eq!(edges, vec![(0, 5), (1, 2), (2, 9), (4, 9)]);
+        let edges: Vec<(i32, i32)> =
+            tree::minimum_spanning_edges(&graph, SpanningTreeAlgorithm::Prim, true, false)
+                .unwrap()
+                .map(|e| (e.u, e.v))
+                .take(4)
+                .collect();
+        assert_eq!(edges, vec![(0, 5), (0, 9), (2, 9), (1, 2)]);
+    }
+
+    #[test]
+    fn test_minimum_spanning_edges_2() {
+        // errors
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edge(Edge::with_weight(1, 2, 1.0)).unwrap();
+        let result = tree::minimum_spanning_edges(&graph, SpanningTreeAlgorithm::Prim, true, false);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let graph = generators::social::karate_club_graph();
+        let result = tree::minimum_spanning_edges(&graph, SpanningTreeAlgorithm::Prim, true, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+    }
+
+    fn get_directed_graph() -> Graph<i32, ()> {
+        let edges = vec![
+            (0, 6, 8.0),
+            (0, 3, 14.0),
+            (0, 4, 9.0),
+            (0, 5, 14.0),
+            (1, 6, 15.0),
+            (1, 9, 13.0),
+            (1, 8, 8.0),
+            (1, 0, 8.0),
+            (1, 5, 9.0),
+            (1, 4, 14.0),
+            (2, 1, 10.0),
+            (2, 4, 4.0),
+            (2, 3, 3.0),
+            (3, 4, 13.0),
+            (3, 6, 9.0),
+            (3, 7, 8.0),
+            (4, 3, 11.0),
+            (4, 5, 10.0),
+            (4, 0, 13.0),
+            (4, 9, 3.0),
+            (4, 8, 2.0),
+            (4, 6, 8.0),
+            (4, 2, 5.0),
+            (5, 4, 3.0),
+            (5, 6, 2.0),
+            (5, 1, 9.0),
+            (6, 9, 13.0),
+            (7, 2, 15.0),
+            (7, 4, 12.0),
+            (7, 5, 11.0),
+            (7, 0, 1.0),
+            (8, 4, 10.0),
+            (8, 3, 7.0),
+            (9, 5, 8.0),
+            (9, 3, 11.0),
+        ];
+        let edges = edges.into_iter().map(|(u, v, w)| Edge::with_weight(u, v, w)).collect();
+        Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_minimum_spanning_arborescence_1() {
+        let graph = get_directed_graph();
+        let arborescence = tree::minimum_spanning_arborescence(&graph, 0).unwrap();
+        assert_eq!(arborescence.get_all_nodes().len(), 10);
+        assert_eq!(arborescence.size(true), 49.0);
+        let mut edges: Vec<(i32, i32)> =
+            arborescence.get_all_edges().into_iter().map(|e| (e.u, e.v)).collect();
+        edges.sort_unstable();
+        assert_eq!(
+            edges,
+            vec![
+                (0, 4),
+                (2, 3),
+                (3, 7),
+                (4, 2),
+                (4, 8),
+                (4, 9),
+                (5, 1),
+                (5, 6),
+                (9, 5)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_minimum_spanning_arborescence_2() {
+        let graph = get_directed_graph();
+        let arborescence = tree::minimum_spanning_arborescence(&graph, 3).unwrap();
+        assert_eq!(arborescence.size(true), 44.0);
+        for node in 0..10 {
+            let in_degree = arborescence.get_node_in_degree(node).unwrap();
+            assert_eq!(in_degree, if node == 3 { 0 } else { 1 });
+        }
+    }
+
+    #[test]
+    fn test_minimum_spanning_arborescence_3() {
+        // errors
+        let mut graph = get_directed_graph();
+        graph.add_node(Node::from_name(10));
+        let result = tree::minimum_spanning_arborescence(&graph, 0);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NoArborescence
+        ));
+        let result = tree::minimum_spanning_arborescence(&graph, 11);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let graph = get_graph();
+        let result = tree::minimum_spanning_arborescence(&graph, 0);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+}
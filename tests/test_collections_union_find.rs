@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::components, collections::UnionFind, generators};
+
+    #[test]
+    fn test_union_find() {
+        let mut uf: UnionFind<i32> = UnionFind::new();
+        assert!(uf.is_empty());
+        assert!(uf.insert(1));
+        assert!(!uf.insert(1));
+        assert!(uf.union(2, 3));
+        assert!(uf.union(3, 4));
+        assert!(!uf.union(4, 2));
+        assert_eq!(uf.len(), 4);
+        assert_eq!(uf.num_sets(), 2);
+        assert!(uf.contains(&4));
+        assert!(!uf.contains(&5));
+        let root = uf.find(&4).cloned();
+        assert_eq!(uf.find(&2).cloned(), root);
+        assert_eq!(uf.find(&1), Some(&1));
+        assert_eq!(uf.find(&5), None);
+        assert_eq!(uf.set_size(&3), Some(3));
+        assert_eq!(uf.set_size(&1), Some(1));
+        assert_eq!(uf.set_size(&5), None);
+        assert!(!uf.connected(&1, &5));
+        assert!(!uf.connected(&5, &5));
+        assert_eq!(uf.sets(), vec![vec![1], vec![2, 3, 4]]);
+    }
+
+    #[test]
+    fn test_union_find_from_iter() {
+        let mut uf: UnionFind<&str> = vec!["c", "b", "a"].into_iter().collect();
+        assert_eq!(uf.num_sets(), 3);
+        uf.union("c", "a");
+        assert_eq!(uf.sets(), vec![vec!["a", "c"], vec!["b"]]);
+        let uf: UnionFind<&str> = UnionFind::default();
+        assert_eq!(uf.num_sets(), 0);
+    }
+
+    #[test]
+    fn test_union_find_matches_connected_components() {
+        let graph = generators::random::gnm_random_graph(200, 150, Some(1), false).unwrap();
+        let mut uf: UnionFind<usize> = graph.get_all_node_names().into_iter().cloned().collect();
+        for edge in graph.get_all_edges() {
+            uf.union(edge.u, edge.v);
+        }
+        let mut expected: Vec<Vec<usize>> = components::connected_components(&graph)
+            .unwrap()
+            .into_iter()
+            .map(|mut c| {
+                c.sort_unstable();
+                c
+            })
+            .collect();
+        expected.sort();
+        assert_eq!(uf.num_sets(), expected.len());
+        assert_eq!(uf.sets(), expected);
+    }
+}
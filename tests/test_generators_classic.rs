@@ -28,4 +28,72 @@ mod tests {
         assert!(hashset.contains(&Edge::new(1, 2)));
         assert!(hashset.contains(&Edge::new(0, 2)));
     }
+
+    #[test]
+    fn test_cycle_graph() {
+        let graph = generators::classic::cycle_graph(4, true);
+        assert_eq!(graph.get_all_nodes().len(), 4);
+        assert_eq!(graph.get_all_edges().len(), 4);
+        assert!(graph.get_edge(3, 0).is_ok());
+        let graph = generators::classic::cycle_graph(6, false);
+        for node in 0..6 {
+            assert_eq!(graph.get_node_degree(node), Some(2));
+        }
+        let graph = generators::classic::cycle_graph(2, false);
+        assert_eq!(graph.get_all_edges().len(), 1);
+        let graph = generators::classic::cycle_graph(1, false);
+        assert_eq!(graph.get_all_nodes().len(), 1);
+        assert_eq!(graph.get_all_edges().len(), 0);
+    }
+
+    #[test]
+    fn test_path_graph() {
+        let graph = generators::classic::path_graph(5, true);
+        assert_eq!(graph.get_all_nodes().len(), 5);
+        let hashset = graph.get_all_edges().into_iter().collect::<HashSet<&Edge<i32, ()>>>();
+        assert_eq!(hashset.len(), 4);
+        assert!(hashset.contains(&Edge::new(0, 1)));
+        assert!(hashset.contains(&Edge::new(3, 4)));
+        let graph = generators::classic::path_graph(0, false);
+        assert_eq!(graph.get_all_nodes().len(), 0);
+    }
+
+    #[test]
+    fn test_star_graph() {
+        let graph = generators::classic::star_graph(4, true);
+        assert_eq!(graph.get_all_nodes().len(), 5);
+        assert_eq!(graph.get_node_out_degree(0), Some(4));
+        assert_eq!(graph.get_node_in_degree(3), Some(1));
+        let graph = generators::classic::star_graph(0, false);
+        assert_eq!(graph.get_all_nodes().len(), 1);
+    }
+
+    #[test]
+    fn test_grid_2d_graph() {
+        let graph = generators::classic::grid_2d_graph(3, 4, false);
+        assert_eq!(graph.get_all_nodes().len(), 12);
+        assert_eq!(graph.get_all_edges().len(), 17);
+        assert_eq!(graph.get_node_degree(0), Some(2));
+        assert_eq!(graph.get_node_degree(5), Some(4));
+        assert_eq!(graph.get_node_degree(7), Some(3));
+        assert_eq!(graph.get_node_degree(11), Some(2));
+        assert!(graph.get_edge(1, 5).is_ok());
+        assert!(graph.get_edge(3, 4).is_err());
+        let graph = generators::classic::grid_2d_graph(2, 2, true);
+        assert_eq!(graph.get_node_out_degree(0), Some(2));
+        assert_eq!(graph.get_node_in_degree(3), Some(2));
+    }
+
+    #[test]
+    fn test_balanced_tree() {
+        let graph = generators::classic::balanced_tree(3, 2, true);
+        assert_eq!(graph.get_all_nodes().len(), 13);
+        assert_eq!(graph.get_all_edges().len(), 12);
+        assert_eq!(graph.get_node_out_degree(0), Some(3));
+        assert!(graph.get_edge(1, 4).is_ok());
+        assert!(graph.get_edge(3, 12).is_ok());
+        assert_eq!(graph.get_node_out_degree(12), Some(0));
+        let graph = generators::classic::balanced_tree(2, 0, false);
+        assert_eq!(graph.get_all_nodes().len(), 1);
+    }
 }
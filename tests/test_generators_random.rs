@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
 
-    use graphrs::generators;
+    use graphrs::{algorithms::components, generators, ErrorKind};
+    use std::collections::HashSet;
 
     #[test]
     fn test_fast_gnp_random_graph() {
@@ -9,4 +10,117 @@ mod tests {
         let all_nodes = graph.get_all_nodes();
         assert_eq!(all_nodes.len(), 10);
     }
+
+    #[test]
+    fn test_gnp_random_graph_1() {
+        let graph = generators::random::gnp_random_graph(1000, 0.01, Some(1), false).unwrap();
+        assert_eq!(graph.get_all_nodes().len(), 1000);
+        let num_edges = graph.get_all_edges().len();
+        // the expected number of edges is 4995
+        assert!(num_edges > 4500 && num_edges < 5500);
+        assert!(graph.get_all_edges().into_iter().all(|e| e.u < e.v));
+        let graph2 = generators::random::gnp_random_graph(1000, 0.01, Some(1), false).unwrap();
+        assert_eq!(graph2.get_all_edges().len(), num_edges);
+    }
+
+    #[test]
+    fn test_gnp_random_graph_2() {
+        let graph = generators::random::gnp_random_graph(200, 0.1, Some(2), true).unwrap();
+        let num_edges = graph.get_all_edges().len();
+        // the expected number of edges is 3980
+        assert!(num_edges > 3600 && num_edges < 4400);
+        assert!(graph.get_all_edges().into_iter().all(|e| e.u != e.v));
+    }
+
+    #[test]
+    fn test_gnp_random_graph_3() {
+        let graph = generators::random::gnp_random_graph(10, 1.0, None, true).unwrap();
+        assert_eq!(graph.get_all_edges().len(), 90);
+        let graph = generators::random::gnp_random_graph(10, 1.0, None, false).unwrap();
+        assert_eq!(graph.get_all_edges().len(), 45);
+        let graph = generators::random::gnp_random_graph(10, 0.0, None, false).unwrap();
+        assert_eq!(graph.get_all_edges().len(), 0);
+        let result = generators::random::gnp_random_graph(10, 1.5, None, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_gnm_random_graph_1() {
+        let graph = generators::random::gnm_random_graph(50, 100, Some(1), false).unwrap();
+        assert_eq!(graph.get_all_nodes().len(), 50);
+        assert_eq!(graph.get_all_edges().len(), 100);
+        let graph = generators::random::gnm_random_graph(50, 1000, Some(1), false).unwrap();
+        assert_eq!(graph.get_all_edges().len(), 1000);
+        let graph = generators::random::gnm_random_graph(50, 2000, Some(1), true).unwrap();
+        assert_eq!(graph.get_all_edges().len(), 2000);
+        assert!(graph.get_all_edges().into_iter().all(|e| e.u != e.v));
+    }
+
+    #[test]
+    fn test_gnm_random_graph_2() {
+        // more edges than pairs of nodes
+        let graph = generators::random::gnm_random_graph(5, 100, None, false).unwrap();
+        assert_eq!(graph.get_all_edges().len(), 10);
+        let graph = generators::random::gnm_random_graph(1, 100, None, true).unwrap();
+        assert_eq!(graph.get_all_edges().len(), 0);
+    }
+
+    #[test]
+    fn test_watts_strogatz_graph_1() {
+        // no rewiring gives a ring lattice
+        let graph = generators::random::watts_strogatz_graph(20, 4, 0.0, None).unwrap();
+        assert_eq!(graph.get_all_edges().len(), 40);
+        for node in 0..20 {
+            assert_eq!(graph.get_node_degree(node).unwrap(), 4);
+        }
+        assert!(graph.get_edge(0, 18).is_ok());
+        assert!(graph.get_edge(0, 2).is_ok());
+    }
+
+    #[test]
+    fn test_watts_strogatz_graph_2() {
+        let graph = generators::random::watts_strogatz_graph(100, 6, 0.3, Some(1)).unwrap();
+        assert_eq!(graph.get_all_nodes().len(), 100);
+        assert_eq!(graph.get_all_edges().len(), 300);
+        assert!(graph.get_all_edges().into_iter().all(|e| e.u != e.v));
+        let graph2 = generators::random::watts_strogatz_graph(100, 6, 0.3, Some(1)).unwrap();
+        let edges: HashSet<(usize, usize)> =
+            graph.get_all_edges().into_iter().map(|e| (e.u, e.v)).collect();
+        assert!(graph2.get_all_edges().into_iter().all(|e| edges.contains(&(e.u, e.v))));
+        // k equal to num_nodes gives a complete graph
+        let graph = generators::random::watts_strogatz_graph(6, 6, 0.3, Some(1)).unwrap();
+        assert_eq!(graph.get_all_edges().len(), 15);
+    }
+
+    #[test]
+    fn test_watts_strogatz_graph_3() {
+        // errors
+        let result = generators::random::watts_strogatz_graph(5, 6, 0.3, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = generators::random::watts_strogatz_graph(10, 2, -0.5, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_connected_watts_strogatz_graph() {
+        let graph =
+            generators::random::connected_watts_strogatz_graph(50, 2, 0.8, Some(1000), Some(3))
+                .unwrap();
+        assert_eq!(components::number_connected_components(&graph).unwrap(), 1);
+        // with k = 0 there are no edges, so the graph is never connected
+        let result = generators::random::connected_watts_strogatz_graph(5, 0, 0.5, Some(3), None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
 }
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{Direction, Edge, ErrorKind, Graph, GraphSpecs, MissingNodeStrategy, Node};
+
+    fn get_directed_graph() -> Graph<&'static str, ()> {
+        let mut graph = Graph::new(GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            self_loops: true,
+            ..GraphSpecs::multi_directed()
+        });
+        graph.add_node(Node::from_name("e"));
+        graph
+            .add_edges(vec![
+                Edge::with_weight("a", "b", 1.0),
+                Edge::with_weight("a", "b", 2.0),
+                Edge::with_weight("b", "a", 3.0),
+                Edge::with_weight("b", "c", 4.0),
+                Edge::with_weight("d", "b", 5.0),
+                Edge::with_weight("b", "b", 6.0),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_neighbors_iter_directed() {
+        let graph = get_directed_graph();
+        let mut neighbors: Vec<&&str> = graph.neighbors_iter("b").unwrap().collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec![&"a", &"b", &"c", &"d"]);
+        let mut successors: Vec<&&str> = graph.successors_iter("b").unwrap().collect();
+        successors.sort();
+        assert_eq!(successors, vec![&"a", &"b", &"c"]);
+        let mut predecessors: Vec<&&str> = graph.predecessors_iter("b").unwrap().collect();
+        predecessors.sort();
+        assert_eq!(predecessors, vec![&"a", &"b", &"d"]);
+        assert_eq!(graph.neighbors_iter("e").unwrap().count(), 0);
+        assert_eq!(graph.successors_iter("c").unwrap().count(), 0);
+        assert_eq!(graph.predecessors_iter("d").unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_neighbors_iter_undirected() {
+        let mut graph: Graph<i32, ()> = Graph::new(GraphSpecs::undirected_create_missing());
+        graph.add_edges(vec![Edge::new(1, 2), Edge::new(3, 1), Edge::new(2, 3)]).unwrap();
+        let mut neighbors: Vec<&i32> = graph.neighbors_iter(1).unwrap().collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec![&2, &3]);
+        let result = graph.successors_iter(1);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let result = graph.predecessors_iter(1);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_neighbors_directed_iter() {
+        let graph = get_directed_graph();
+        let mut outgoing: Vec<&&str> =
+            graph.neighbors_directed_iter("b", Direction::Outgoing).unwrap().collect();
+        outgoing.sort();
+        assert_eq!(outgoing, vec![&"a", &"b", &"c"]);
+        let mut incoming: Vec<&&str> =
+            graph.neighbors_directed_iter("b", Direction::Incoming).unwrap().collect();
+        incoming.sort();
+        assert_eq!(incoming, vec![&"a", &"b", &"d"]);
+        let mut graph: Graph<i32, ()> = Graph::new(GraphSpecs::undirected_create_missing());
+        graph.add_edges(vec![Edge::new(1, 2), Edge::new(3, 1)]).unwrap();
+        let mut incoming: Vec<&i32> =
+            graph.neighbors_directed_iter(1, Direction::Incoming).unwrap().collect();
+        incoming.sort();
+        assert_eq!(incoming, vec![&2, &3]);
+        let result = graph.neighbors_directed_iter(9, Direction::Outgoing);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_edges_of_directed() {
+        let graph = get_directed_graph();
+        let mut weights: Vec<f64> = graph.edges_of("b").unwrap().map(|e| e.weight).collect();
+        weights.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(weights, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let out_weights: Vec<f64> = graph.edges_of("d").unwrap().map(|e| e.weight).collect();
+        assert_eq!(out_weights, vec![5.0]);
+        assert!(graph.edges_of("b").unwrap().take(3).all(|e| e.u == "b"));
+        assert_eq!(graph.edges_of("e").unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_edges_of_undirected() {
+        let mut graph: Graph<i32, ()> = Graph::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight(2, 1, 1.0),
+                Edge::with_weight(2, 3, 2.0),
+                Edge::with_weight(2, 2, 4.0),
+                Edge::with_weight(1, 3, 8.0),
+            ])
+            .unwrap();
+        let total: f64 = graph.edges_of(2).unwrap().map(|e| e.weight).sum();
+        assert_eq!(total, 7.0);
+        assert!(graph.edges_of(3).unwrap().all(|e| e.u < e.v));
+    }
+
+    #[test]
+    fn test_adjacency_iter_node_not_found() {
+        let graph = get_directed_graph();
+        let result = graph.neighbors_iter("z");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = graph.successors_iter("z");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = graph.predecessors_iter("z");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = graph.edges_of("z");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{Edge, ErrorKind, Graph, GraphSpecs, MissingNodeStrategy, Node};
+
+    #[test]
+    fn test_set_node_attribute() {
+        let mut graph: Graph<&str, i32> = Graph::new(GraphSpecs::undirected_create_missing());
+        graph.add_edge(Edge::new("n1", "n2")).unwrap();
+        assert_eq!(graph.get_node_attributes("n1"), None);
+        graph.set_node_attribute("n1", 1).unwrap();
+        assert_eq!(graph.get_node_attributes("n1"), Some(&1));
+        graph.set_node_attribute("n1", 2).unwrap();
+        assert_eq!(graph.get_node_attributes("n1"), Some(&2));
+        let result = graph.set_node_attribute("n3", 3);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        assert!(!graph.has_node(&"n3"));
+    }
+
+    #[test]
+    fn test_get_node_attributes_mut() {
+        let mut graph: Graph<&str, i32> = Graph::new(GraphSpecs::directed());
+        graph.add_nodes(vec![
+            Node::from_name_and_attributes("n1", 10),
+            Node::from_name("n2"),
+        ]);
+        *graph.get_node_attributes_mut("n1").unwrap() += 5;
+        assert_eq!(graph.get_node("n1").unwrap().attributes, Some(15));
+        assert!(graph.get_node_attributes_mut("n2").is_none());
+        assert!(graph.get_node_attributes_mut("n3").is_none());
+    }
+
+    #[test]
+    fn test_add_edges_with_node_attributes() {
+        let mut graph: Graph<&str, usize> = Graph::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph.add_node(Node::from_name_and_attributes("n1", 100));
+        graph
+            .add_edges_with_node_attributes(
+                vec![
+                    Edge::new("n1", "n2"),
+                    Edge::new("n2", "n30"),
+                    Edge::new("n4", "n4"),
+                ],
+                |name| name.len(),
+            )
+            .unwrap();
+        assert_eq!(graph.get_node_attributes("n1"), Some(&100));
+        assert_eq!(graph.get_node_attributes("n2"), Some(&2));
+        assert_eq!(graph.get_node_attributes("n30"), Some(&3));
+        assert_eq!(graph.get_node_attributes("n4"), Some(&2));
+        assert_eq!(graph.get_all_edges().len(), 3);
+    }
+
+    #[test]
+    fn test_add_edges_with_node_attributes_missing_node_error() {
+        let mut graph: Graph<&str, usize> = Graph::new(GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Error,
+            ..GraphSpecs::directed()
+        });
+        graph.add_node(Node::from_name("n1"));
+        let result = graph.add_edges_with_node_attributes(vec![Edge::new("n1", "n2")], |_| 0);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        assert_eq!(graph.get_node_attributes("n1"), None);
+    }
+}
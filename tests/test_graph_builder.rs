@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        Edge, EdgeMergeStrategy, ErrorKind, GraphBuilder, GraphSpecs, MissingNodeStrategy, Node,
+    };
+
+    fn get_edges() -> Vec<Edge<&'static str, &'static str>> {
+        vec![
+            Edge {
+                u: "a",
+                v: "b",
+                attributes: Some("first"),
+                weight: 1.0,
+            },
+            Edge::with_weight("b", "a", 2.0),
+            Edge::with_weight("a", "b", 4.0),
+            Edge::with_weight("b", "c", 3.0),
+            Edge::with_weight("c", "c", 5.0),
+        ]
+    }
+
+    #[test]
+    fn test_builder_merge_parallel_edges() {
+        let expected = [
+            (EdgeMergeStrategy::Min, 1.0),
+            (EdgeMergeStrategy::Max, 4.0),
+            (EdgeMergeStrategy::Sum, 7.0),
+            (EdgeMergeStrategy::Mean, 7.0 / 3.0),
+        ];
+        for (merge_strategy, weight) in expected {
+            let mut builder =
+                GraphBuilder::new(GraphSpecs::undirected_create_missing()).drop_self_loops();
+            builder = builder.merge_parallel_edges(merge_strategy);
+            builder.add_edges(get_edges());
+            let graph = builder.build().unwrap();
+            assert_eq!(graph.get_all_edges().len(), 2);
+            let edge = graph.get_edge("a", "b").unwrap();
+            assert_eq!(edge.weight, weight);
+            assert_eq!(edge.attributes, Some("first"));
+        }
+    }
+
+    #[test]
+    fn test_builder_merge_parallel_edges_directed() {
+        let mut builder = GraphBuilder::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        })
+        .merge_parallel_edges(EdgeMergeStrategy::Sum);
+        builder.add_edges(get_edges());
+        let graph = builder.build().unwrap();
+        assert_eq!(graph.get_all_edges().len(), 4);
+        assert_eq!(graph.get_edge("a", "b").unwrap().weight, 5.0);
+        assert_eq!(graph.get_edge("b", "a").unwrap().weight, 2.0);
+        assert_eq!(graph.get_edge("c", "c").unwrap().weight, 5.0);
+    }
+
+    #[test]
+    fn test_builder_symmetrize() {
+        let mut builder = GraphBuilder::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::multi_directed()
+        })
+        .symmetrize();
+        builder.add_nodes(vec![
+            Node::from_name("a"),
+            Node::from_name("b"),
+            Node::from_name("c"),
+        ]);
+        builder.add_edges(get_edges());
+        let graph = builder.build().unwrap();
+        assert_eq!(graph.get_all_edges().len(), 9);
+        assert_eq!(graph.get_edges("c", "b").unwrap()[0].weight, 3.0);
+        assert_eq!(graph.get_edges("b", "a").unwrap().len(), 3);
+
+        let mut builder = GraphBuilder::new(GraphSpecs::directed_create_missing())
+            .symmetrize()
+            .drop_self_loops()
+            .merge_parallel_edges(EdgeMergeStrategy::Max);
+        builder.add_edges(get_edges());
+        let graph = builder.build().unwrap();
+        assert_eq!(graph.get_all_edges().len(), 4);
+        assert_eq!(graph.get_edge("b", "a").unwrap().weight, 4.0);
+        assert_eq!(graph.get_edge("c", "b").unwrap().weight, 3.0);
+    }
+
+    #[test]
+    fn test_builder_nodes() {
+        let mut builder = GraphBuilder::<i32, &str>::new(GraphSpecs::undirected());
+        builder.add_node(Node::from_name_and_attributes(1, "one"));
+        builder.add_node(Node::from_name_and_attributes(1, "uno"));
+        builder.add_nodes(vec![Node::from_name(2), Node::from_name(3)]);
+        builder.add_edge_tuples(vec![(1, 2)]);
+        let graph = builder.build().unwrap();
+        assert_eq!(graph.get_all_nodes().len(), 3);
+        assert_eq!(graph.get_node(1).unwrap().attributes, Some("uno"));
+        assert_eq!(graph.get_all_edges().len(), 1);
+    }
+
+    #[test]
+    fn test_builder_errors() {
+        let specs = GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            ..GraphSpecs::undirected()
+        };
+        let mut builder = GraphBuilder::new(specs.clone());
+        builder.add_edges(get_edges());
+        let result = builder.build();
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::DuplicateEdge
+        ));
+        let mut builder = GraphBuilder::new(specs).merge_parallel_edges(EdgeMergeStrategy::Sum);
+        builder.add_edges(get_edges());
+        let result = builder.build();
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::SelfLoopsFound
+        ));
+        let mut builder = GraphBuilder::new(GraphSpecs::directed()).drop_self_loops();
+        builder.add_edges(get_edges());
+        let result = builder.build();
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+}
@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        generators, Edge, ErrorKind, Graph, GraphSpecs, MissingNodeStrategy, Node, SelfLoopPolicy,
+        SelfLoopsFalseStrategy,
+    };
+
+    fn get_triangle(specs: GraphSpecs) -> Graph<&'static str, ()> {
+        let mut graph = Graph::new(GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            ..specs
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight("a", "b", 1.0),
+                Edge::with_weight("a", "c", 2.0),
+                Edge::with_weight("b", "c", 3.0),
+                Edge::with_weight("c", "d", 4.0),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_contract_edge_remove_self_loops() {
+        let mut graph = get_triangle(GraphSpecs::undirected());
+        graph.contract_edge("b", "a", SelfLoopPolicy::Remove).unwrap();
+        assert!(!graph.has_node(&"a"));
+        assert_eq!(graph.get_all_nodes().len(), 3);
+        assert_eq!(graph.get_all_edges().len(), 2);
+        assert_eq!(graph.get_edge("c", "b").unwrap().weight, 5.0);
+        assert_eq!(graph.get_edge("c", "d").unwrap().weight, 4.0);
+        let neighbors = graph.get_neighbor_nodes("c").unwrap();
+        assert_eq!(neighbors.len(), 2);
+    }
+
+    #[test]
+    fn test_contract_edge_keep_self_loops() {
+        let mut graph = get_triangle(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected()
+        });
+        graph.contract_edge("a", "b", SelfLoopPolicy::Keep).unwrap();
+        assert_eq!(graph.get_all_edges().len(), 3);
+        assert_eq!(graph.get_edge("a", "a").unwrap().weight, 1.0);
+        assert_eq!(graph.get_edge("a", "c").unwrap().weight, 5.0);
+    }
+
+    #[test]
+    fn test_contract_edge_self_loops_not_supported() {
+        let mut graph = get_triangle(GraphSpecs::undirected());
+        let result = graph.contract_edge("a", "b", SelfLoopPolicy::Keep);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::SelfLoopsFound
+        ));
+        assert_eq!(graph.get_all_nodes().len(), 4);
+        assert_eq!(graph.get_all_edges().len(), 4);
+        let mut graph = get_triangle(GraphSpecs {
+            self_loops_false_strategy: SelfLoopsFalseStrategy::Drop,
+            ..GraphSpecs::undirected()
+        });
+        graph.contract_edge("a", "b", SelfLoopPolicy::Keep).unwrap();
+        assert_eq!(graph.get_all_edges().len(), 2);
+    }
+
+    #[test]
+    fn test_contract_edge_directed() {
+        let mut graph = get_triangle(GraphSpecs::directed());
+        let result = graph.contract_edge("b", "a", SelfLoopPolicy::Remove);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::EdgeNotFound));
+        graph.add_edge(Edge::with_weight("d", "b", 5.0)).unwrap();
+        graph.contract_edge("b", "c", SelfLoopPolicy::Remove).unwrap();
+        assert_eq!(graph.get_all_edges().len(), 3);
+        assert_eq!(graph.get_edge("a", "b").unwrap().weight, 3.0);
+        assert_eq!(graph.get_edge("b", "d").unwrap().weight, 4.0);
+        assert_eq!(graph.get_edge("d", "b").unwrap().weight, 5.0);
+        assert_eq!(graph.get_predecessor_nodes("b").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_contract_edge_keeps_attributes() {
+        let mut graph = Graph::<i32, &str>::new(GraphSpecs::undirected_create_missing());
+        graph.add_node(Node::from_name_and_attributes(1, "one"));
+        graph.add_node(Node::from_name_and_attributes(2, "two"));
+        graph
+            .add_edges(vec![
+                Edge::new(1, 2),
+                Edge {
+                    u: 1,
+                    v: 3,
+                    attributes: Some("1-3"),
+                    weight: f64::NAN,
+                },
+                Edge {
+                    u: 2,
+                    v: 3,
+                    attributes: Some("2-3"),
+                    weight: f64::NAN,
+                },
+            ])
+            .unwrap();
+        graph.contract_edge(2, 1, SelfLoopPolicy::Remove).unwrap();
+        assert_eq!(graph.get_node(2).unwrap().attributes, Some("two"));
+        assert_eq!(graph.get_edge(2, 3).unwrap().attributes, Some("2-3"));
+        assert!(!graph.edges_have_weight());
+    }
+
+    #[test]
+    fn test_merge_nodes() {
+        let mut graph = generators::social::karate_club_graph();
+        graph.specs.self_loops_false_strategy = SelfLoopsFalseStrategy::Drop;
+        graph.merge_nodes(&[0, 1, 2, 3], 100, |_nodes| None).unwrap();
+        assert_eq!(graph.get_all_nodes().len(), 31);
+        // the 6 edges between nodes 0 to 3 are dropped, and their 29 edges to the other
+        // nodes are combined into 18
+        assert_eq!(graph.get_all_edges().len(), 78 - 6 - 11);
+        assert_eq!(graph.get_neighbor_nodes(100).unwrap().len(), 18);
+    }
+
+    #[test]
+    fn test_merge_nodes_attributes() {
+        let mut graph = Graph::<&str, String>::new(GraphSpecs::undirected_create_missing());
+        graph.add_nodes(vec![
+            Node::from_name_and_attributes("b", "B".to_string()),
+            Node::from_name_and_attributes("a", "A".to_string()),
+        ]);
+        graph.add_edge(Edge::new("b", "c")).unwrap();
+        graph
+            .merge_nodes(&["b", "a", "b"], "a", |nodes| {
+                Some(nodes.iter().filter_map(|n| n.attributes.clone()).collect())
+            })
+            .unwrap();
+        assert_eq!(graph.get_all_nodes().len(), 2);
+        assert_eq!(
+            graph.get_node("a").unwrap().attributes,
+            Some("AB".to_string())
+        );
+        assert!(graph.get_edge("c", "a").is_ok());
+    }
+
+    #[test]
+    fn test_merge_nodes_multi_edges() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            ..GraphSpecs::multi_directed()
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight(1, 3, 1.0),
+                Edge::with_weight(2, 3, 2.0),
+                Edge::with_weight(1, 2, 3.0),
+            ])
+            .unwrap();
+        graph.merge_nodes(&[1, 2], 4, |_nodes| None).unwrap();
+        let mut weights: Vec<f64> =
+            graph.get_edges(4, 3).unwrap().iter().map(|e| e.weight).collect();
+        weights.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(weights, vec![1.0, 2.0]);
+        assert_eq!(graph.get_edges(4, 4).unwrap()[0].weight, 3.0);
+    }
+
+    #[test]
+    fn test_merge_nodes_errors() {
+        let mut graph = get_triangle(GraphSpecs::undirected());
+        let result = graph.merge_nodes(&[], "x", |_nodes| None);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = graph.merge_nodes(&["a", "x"], "y", |_nodes| None);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::NodeNotFound));
+        let result = graph.merge_nodes(&["a", "b"], "c", |_nodes| None);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = graph.merge_nodes(&["a", "b"], "ab", |_nodes| None);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::SelfLoopsFound
+        ));
+        assert_eq!(graph.get_all_nodes().len(), 4);
+    }
+}
@@ -2,7 +2,11 @@
 #[cfg(test)]
 mod tests {
 
-    use graphrs::{Edge, Graph, GraphSpecs, MissingNodeStrategy};
+    use graphrs::{
+        generators, Edge, EdgeMergeStrategy, ErrorKind, Graph, GraphSpecs, MissingNodeStrategy,
+        Node,
+    };
+    use std::collections::HashMap;
 
     #[test]
     fn test_to_single_edges_1() {
@@ -61,4 +65,166 @@ mod tests {
         let new_graph = graph.set_all_edge_weights(2.0);
         assert_eq!(new_graph.get_edge("n1", "n3").unwrap().weight, 2.0);
     }
+
+    fn get_reciprocal_graph() -> Graph<&'static str, &'static str> {
+        let mut graph: Graph<&str, &str> = Graph::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![
+            Edge {
+                u: "n2",
+                v: "n1",
+                attributes: Some("second"),
+                weight: 3.0,
+            },
+            Edge {
+                u: "n1",
+                v: "n2",
+                attributes: Some("first"),
+                weight: 1.0,
+            },
+            Edge::with_weight("n3", "n2", 2.0),
+        ]);
+        graph
+    }
+
+    #[test]
+    fn test_to_undirected_1() {
+        let graph = get_reciprocal_graph();
+        let expected = vec![
+            (EdgeMergeStrategy::Max, 3.0),
+            (EdgeMergeStrategy::Mean, 2.0),
+            (EdgeMergeStrategy::Min, 1.0),
+            (EdgeMergeStrategy::Sum, 4.0),
+        ];
+        for (merge_strategy, weight) in expected {
+            let new_graph = graph.to_undirected(merge_strategy).unwrap();
+            assert!(!new_graph.specs.directed);
+            assert_eq!(new_graph.get_all_edges().len(), 2);
+            let edge = new_graph.get_edge("n2", "n1").unwrap();
+            assert_eq!(edge.weight, weight);
+            assert_eq!(edge.attributes, Some("first"));
+            assert_eq!(new_graph.get_edge("n2", "n3").unwrap().weight, 2.0);
+        }
+    }
+
+    #[test]
+    fn test_to_undirected_2() {
+        // multi-edge graphs keep all their edges
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            multi_edges: true,
+            ..GraphSpecs::directed()
+        });
+        graph.add_edges(vec![
+            Edge::with_weight("n1", "n2", 1.0),
+            Edge::with_weight("n2", "n1", 2.0),
+            Edge::with_weight("n1", "n2", 3.0),
+        ]);
+        let new_graph = graph.to_undirected(EdgeMergeStrategy::Sum).unwrap();
+        assert_eq!(new_graph.get_edges("n1", "n2").unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_to_undirected_3() {
+        let graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected());
+        let result = graph.to_undirected(EdgeMergeStrategy::Sum);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_to_directed_1() {
+        let mut graph: Graph<&str, &str> = Graph::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected_create_missing()
+        });
+        graph.add_edges(vec![
+            Edge {
+                u: "n2",
+                v: "n1",
+                attributes: Some("a"),
+                weight: 3.0,
+            },
+            Edge::with_weight("n3", "n3", 2.0),
+        ]);
+        let new_graph = graph.to_directed().unwrap();
+        assert!(new_graph.specs.directed);
+        assert_eq!(new_graph.get_all_edges().len(), 3);
+        assert_eq!(
+            new_graph.get_edge("n1", "n2").unwrap().attributes,
+            Some("a")
+        );
+        assert_eq!(new_graph.get_edge("n2", "n1").unwrap().weight, 3.0);
+        assert!(new_graph.get_edge("n3", "n3").is_ok());
+        let undirected = new_graph.to_undirected(EdgeMergeStrategy::Max).unwrap();
+        assert_eq!(undirected.get_all_edges().len(), 2);
+    }
+
+    #[test]
+    fn test_to_directed_2() {
+        let graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed());
+        let result = graph.to_directed();
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_relabel_nodes_1() {
+        let mut graph: Graph<&str, &str> = Graph::new(GraphSpecs::directed_create_missing());
+        graph.add_node(Node::from_name_and_attributes("n1", "first"));
+        graph.add_edges(vec![
+            Edge {
+                u: "n1",
+                v: "n2",
+                attributes: Some("edge"),
+                weight: 1.5,
+            },
+            Edge::with_weight("n2", "n3", 2.5),
+        ]);
+        let mapping: HashMap<&str, i32> =
+            vec![("n1", 10), ("n2", 20), ("n3", 30)].into_iter().collect();
+        let new_graph = graph.relabel_nodes(&mapping).unwrap();
+        assert!(new_graph.specs.directed);
+        assert_eq!(new_graph.get_all_nodes().len(), 3);
+        assert_eq!(new_graph.get_node(10).unwrap().attributes, Some("first"));
+        let edge = new_graph.get_edge(10, 20).unwrap();
+        assert_eq!(edge.weight, 1.5);
+        assert_eq!(edge.attributes, Some("edge"));
+        assert_eq!(new_graph.get_edge(20, 30).unwrap().weight, 2.5);
+    }
+
+    #[test]
+    fn test_relabel_nodes_2() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n2", "n3")]);
+        let mapping: HashMap<&str, i32> = vec![("n1", 1), ("n2", 2)].into_iter().collect();
+        let result = graph.relabel_nodes(&mapping);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_relabel_nodes_3() {
+        // merging two nodes creates a duplicate edge
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::new("n1", "n3"), Edge::new("n2", "n3")]);
+        let mapping: HashMap<&str, &str> =
+            vec![("n1", "a"), ("n2", "a"), ("n3", "b")].into_iter().collect();
+        let result = graph.relabel_nodes(&mapping);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::DuplicateEdge
+        ));
+    }
+
+    #[test]
+    fn test_convert_node_names_to_indexes() {
+        let graph = generators::social::karate_club_graph();
+        let (new_graph, names) = graph.convert_node_names_to_indexes();
+        assert_eq!(names, (0..34).collect::<Vec<i32>>());
+        assert_eq!(new_graph.get_all_nodes().len(), 34);
+        assert_eq!(new_graph.get_all_edges().len(), 78);
+        for edge in graph.get_all_edges() {
+            assert!(new_graph.get_edge(edge.u as usize, edge.v as usize).is_ok());
+        }
+    }
 }
@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        generators, Edge, EdgeDedupeStrategy, ErrorKind, Graph, GraphSpecs, MissingNodeStrategy,
+        Node, SelfLoopsFalseStrategy,
+    };
+
+    fn get_edges(num_nodes: usize, num_edges: usize, directed: bool) -> Vec<Edge<usize, ()>> {
+        let graph = generators::random::gnm_random_graph(num_nodes, num_edges, Some(1), directed);
+        graph
+            .unwrap()
+            .get_all_edges()
+            .into_iter()
+            .map(|e| Edge::with_weight(e.v, e.u, ((e.u + e.v) % 7) as f64))
+            .collect()
+    }
+
+    fn get_sorted_edges(graph: &Graph<usize, ()>) -> Vec<(usize, usize, f64)> {
+        let mut edges: Vec<(usize, usize, f64)> =
+            graph.get_all_edges().into_iter().map(|e| (e.u, e.v, e.weight)).collect();
+        edges.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        edges
+    }
+
+    fn assert_same_graph(graph1: &Graph<usize, ()>, graph2: &Graph<usize, ()>) {
+        let mut nodes1 = graph1.get_all_node_names();
+        let mut nodes2 = graph2.get_all_node_names();
+        nodes1.sort();
+        nodes2.sort();
+        assert_eq!(nodes1, nodes2);
+        assert_eq!(get_sorted_edges(graph1), get_sorted_edges(graph2));
+        assert_eq!(graph1.get_successors_map(), graph2.get_successors_map());
+        assert_eq!(graph1.get_predecessors_map(), graph2.get_predecessors_map());
+    }
+
+    #[test]
+    fn test_add_edges_par_same_as_add_edges() {
+        for specs in [
+            GraphSpecs::directed_create_missing(),
+            GraphSpecs::undirected_create_missing(),
+        ] {
+            let edges = get_edges(500, 3000, specs.directed);
+            let mut graph1 = Graph::new(specs.clone());
+            graph1.add_edges(edges.clone()).unwrap();
+            let mut graph2 = Graph::new(specs);
+            graph2.add_edges_par(edges).unwrap();
+            assert_same_graph(&graph1, &graph2);
+        }
+    }
+
+    #[test]
+    fn test_add_edges_par_to_existing_graph() {
+        let edges = get_edges(200, 1000, true);
+        let (first, second) = edges.split_at(400);
+        let mut graph1 = Graph::new(GraphSpecs::directed_create_missing());
+        graph1.add_node(Node::from_name(1000));
+        graph1.add_edges(first.to_vec()).unwrap();
+        graph1.add_edges(second.to_vec()).unwrap();
+        let mut graph2 = Graph::new(GraphSpecs::directed_create_missing());
+        graph2.add_node(Node::from_name(1000));
+        graph2.add_edges(first.to_vec()).unwrap();
+        graph2.add_edges_par(second.to_vec()).unwrap();
+        assert_same_graph(&graph1, &graph2);
+    }
+
+    #[test]
+    fn test_add_edges_par_multi_edges() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            ..GraphSpecs::multi_undirected()
+        });
+        graph.add_edge(Edge::with_weight("n1", "n2", 1.0)).unwrap();
+        graph
+            .add_edges_par(vec![
+                Edge::with_weight("n2", "n1", 2.0),
+                Edge::with_weight("n2", "n3", 3.0),
+                Edge::with_weight("n1", "n2", 4.0),
+            ])
+            .unwrap();
+        let weights: Vec<f64> =
+            graph.get_edges("n1", "n2").unwrap().iter().map(|e| e.weight).collect();
+        assert_eq!(weights, vec![1.0, 2.0, 4.0]);
+        assert_eq!(graph.get_all_edges().len(), 4);
+    }
+
+    #[test]
+    fn test_add_edges_par_dedupe_strategies() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 2.0),
+            Edge::with_weight("n2", "n3", 3.0),
+            Edge::with_weight("n1", "n2", 4.0),
+        ];
+        for (strategy, expected) in [
+            (EdgeDedupeStrategy::KeepFirst, 1.0),
+            (EdgeDedupeStrategy::KeepLast, 4.0),
+        ] {
+            let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+                edge_dedupe_strategy: strategy,
+                ..GraphSpecs::directed_create_missing()
+            });
+            graph.add_edge(Edge::with_weight("n1", "n2", 1.0)).unwrap();
+            graph.add_edges_par(edges.clone()).unwrap();
+            assert_eq!(graph.get_edge("n1", "n2").unwrap().weight, expected);
+            assert_eq!(graph.get_all_edges().len(), 2);
+        }
+
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        let result = graph.add_edges_par(edges);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::DuplicateEdge
+        ));
+        assert!(graph.get_all_nodes().is_empty());
+        graph.add_edge(Edge::new("n3", "n1")).unwrap();
+        let result = graph.add_edges_par(vec![Edge::new("n1", "n2"), Edge::new("n3", "n1")]);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::DuplicateEdge
+        ));
+        assert_eq!(graph.get_all_edges().len(), 1);
+    }
+
+    #[test]
+    fn test_add_edges_par_self_loops() {
+        let edges = vec![Edge::new("n1", "n2"), Edge::new("n2", "n2")];
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+            self_loops: false,
+            self_loops_false_strategy: SelfLoopsFalseStrategy::Drop,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph.add_edges_par(edges.clone()).unwrap();
+        assert_eq!(graph.get_all_edges().len(), 1);
+
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+            self_loops: false,
+            self_loops_false_strategy: SelfLoopsFalseStrategy::Error,
+            ..GraphSpecs::directed_create_missing()
+        });
+        let result = graph.add_edges_par(edges);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::SelfLoopsFound
+        ));
+        assert!(graph.get_all_edges().is_empty());
+    }
+
+    #[test]
+    fn test_add_edges_par_missing_nodes() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed());
+        graph.add_nodes(vec![Node::from_name("n1"), Node::from_name("n2")]);
+        let result = graph.add_edges_par(vec![Edge::new("n1", "n2"), Edge::new("n2", "n3")]);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        assert!(graph.get_all_edges().is_empty());
+        assert!(graph.add_edges_par(vec![Edge::new("n1", "n2")]).is_ok());
+        assert_eq!(graph.get_all_edges().len(), 1);
+    }
+}
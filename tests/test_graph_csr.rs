@@ -0,0 +1,359 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::{
+            centrality::{betweenness, pagerank},
+            shortest_path::dijkstra,
+            traversal::bfs,
+        },
+        generators, Direction, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+    use std::collections::HashSet;
+
+    fn get_weighted_graph(specs: GraphSpecs) -> Graph<&'static str, ()> {
+        let mut graph = Graph::new(specs);
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n2", "n1", 2.0),
+                Edge::with_weight("n1", "n3", 3.0),
+                Edge::with_weight("n2", "n3", 1.1),
+                Edge::with_weight("n3", "n4", 0.5),
+                Edge::with_weight("n2", "n4", 1.6),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Vec<String>,
+    }
+
+    impl bfs::BfsVisitor<usize> for Recorder {
+        fn on_discover(&mut self, node: &usize, depth: usize) {
+            self.events.push(format!("discover {} {}", node, depth));
+        }
+        fn on_tree_edge(&mut self, u: &usize, v: &usize) {
+            self.events.push(format!("tree {} {}", u, v));
+        }
+        fn on_non_tree_edge(&mut self, u: &usize, v: &usize) {
+            self.events.push(format!("non-tree {} {}", u, v));
+        }
+    }
+
+    #[test]
+    fn test_to_csr_directed() {
+        let graph = get_weighted_graph(GraphSpecs::directed_create_missing());
+        let csr = graph.to_csr();
+        assert!(csr.is_directed());
+        assert_eq!(csr.number_of_nodes(), 4);
+        assert_eq!(csr.get_node_names(), &["n1", "n2", "n3", "n4"]);
+        assert_eq!(csr.get_node_index(&"n3"), Some(2));
+        assert_eq!(csr.get_node_index(&"n5"), None);
+        assert_eq!(csr.get_node_name(3), Some(&"n4"));
+        assert_eq!(csr.get_node_name(4), None);
+        assert_eq!(csr.get_successors_or_neighbors(1), &[0, 2, 3]);
+        assert_eq!(csr.get_weights(1), &[2.0, 1.1, 1.6]);
+        assert!(csr.get_successors_or_neighbors(3).is_empty());
+        assert!(csr.edges_have_weight());
+    }
+
+    #[test]
+    fn test_to_csr_undirected_multi_edges() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::multi_undirected()
+        });
+        graph.add_nodes(vec![
+            Node::from_name("n1"),
+            Node::from_name("n2"),
+            Node::from_name("n3"),
+        ]);
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 3.0),
+                Edge::with_weight("n2", "n1", 2.0),
+                Edge::with_weight("n3", "n2", 4.0),
+                Edge::with_weight("n3", "n3", 1.0),
+            ])
+            .unwrap();
+        let csr = graph.to_csr();
+        assert!(!csr.is_directed());
+        assert_eq!(csr.get_successors_or_neighbors(0), &[1]);
+        assert_eq!(csr.get_weights(0), &[2.0]);
+        assert_eq!(csr.get_successors_or_neighbors(1), &[0, 2]);
+        assert_eq!(csr.get_weights(1), &[2.0, 4.0]);
+        assert_eq!(csr.get_successors_or_neighbors(2), &[1, 2]);
+        let distances =
+            dijkstra::single_source_distances_csr(&csr, true, "n1", None, None).unwrap();
+        assert_eq!(distances.get("n3"), Some(&6.0));
+    }
+
+    #[test]
+    fn test_csr_without_weights() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        graph.add_edges(vec![Edge::new("n1", "n2"), Edge::new("n2", "n3")]).unwrap();
+        let csr = graph.to_csr();
+        assert!(!csr.edges_have_weight());
+        let result = dijkstra::single_source_csr(&csr, true, "n1", None, None, None, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+        let result = pagerank::pagerank_csr(&csr, true, 0.85, None, None, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+        let result = betweenness::betweenness_centrality_csr(&csr, true, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+        let distances =
+            dijkstra::single_source_distances_csr(&csr, false, "n1", None, None).unwrap();
+        assert_eq!(distances.get("n3"), Some(&2.0));
+    }
+
+    #[test]
+    fn test_csr_missing_node() {
+        let graph = get_weighted_graph(GraphSpecs::directed_create_missing());
+        let csr = graph.to_csr();
+        let result = dijkstra::single_source_csr(&csr, true, "n9", None, None, None, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = bfs::breadth_first_nodes_csr(&csr, "n9");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let mut recorder = Recorder::default();
+        let csr = generators::random::gnm_random_graph(10, 20, Some(1), true).unwrap().to_csr();
+        let result = bfs::breadth_first_search_csr(&csr, 10, &mut recorder);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_dijkstra_csr_matches_graph() {
+        let graph = get_weighted_graph(GraphSpecs::directed_create_missing());
+        let csr = graph.to_csr();
+        for weighted in [true, false] {
+            let expected =
+                dijkstra::multi_source(&graph, weighted, vec!["n1", "n3"], None, None, None, false)
+                    .unwrap();
+            let result = dijkstra::multi_source_csr(
+                &csr,
+                weighted,
+                vec!["n1", "n3"],
+                None,
+                None,
+                None,
+                false,
+            )
+            .unwrap();
+            assert_eq!(result.len(), expected.len());
+            for (node, info) in result {
+                assert_eq!(info.distance, expected.get(node).unwrap().distance);
+                assert_eq!(info.paths, expected.get(node).unwrap().paths);
+            }
+        }
+        let result = dijkstra::single_source_csr(&csr, true, "n1", Some("n4"), None, None, false);
+        let info = result.unwrap().remove("n4").unwrap();
+        assert_eq!(info.distance, 2.6);
+        assert_eq!(
+            info.paths,
+            vec![vec!["n1", "n2", "n4"], vec!["n1", "n2", "n3", "n4"]]
+        );
+
+        let graph = generators::social::karate_club_graph();
+        let csr = graph.to_csr();
+        for source in [0, 16, 33] {
+            let expected = dijkstra::single_source(&graph, false, source, None, None, None, false);
+            let result = dijkstra::single_source_csr(&csr, false, source, None, None, None, false);
+            let (expected, result) = (expected.unwrap(), result.unwrap());
+            assert_eq!(result.len(), expected.len());
+            for (node, info) in result {
+                let expected_info = expected.get(&node).unwrap();
+                assert_eq!(info.distance, expected_info.distance);
+                let a: HashSet<&Vec<i32>> = info.paths.iter().collect();
+                let b: HashSet<&Vec<i32>> = expected_info.paths.iter().collect();
+                assert_eq!(a, b);
+            }
+            let expected = dijkstra::single_source_distances(&graph, false, source, None, None);
+            let result = dijkstra::single_source_distances_csr(&csr, false, source, None, None);
+            assert_eq!(result.unwrap(), expected.unwrap());
+        }
+    }
+
+    #[test]
+    fn test_dijkstra_graph_matches_csr_path_order() {
+        for directed in [true, false] {
+            let graph = generators::random::gnm_random_graph(40, 90, Some(2), directed).unwrap();
+            let csr = graph.to_csr();
+            for (sources, hop_cutoff) in
+                [(vec![7], None), (vec![31, 4, 18], None), (vec![7], Some(3))]
+            {
+                let expected = dijkstra::multi_source_csr(
+                    &csr,
+                    false,
+                    sources.clone(),
+                    None,
+                    None,
+                    hop_cutoff,
+                    false,
+                )
+                .unwrap();
+                let result = dijkstra::multi_source(
+                    &graph,
+                    false,
+                    sources.clone(),
+                    None,
+                    None,
+                    hop_cutoff,
+                    false,
+                )
+                .unwrap();
+                assert_eq!(result.len(), expected.len());
+                for (node, info) in result {
+                    assert_eq!(info.distance, expected[&node].distance);
+                    assert_eq!(info.paths, expected[&node].paths);
+                }
+                let targets = vec![2, 25, 39];
+                let result = dijkstra::multi_source_to_targets(
+                    &graph,
+                    false,
+                    sources,
+                    targets.clone(),
+                    None,
+                    hop_cutoff,
+                    false,
+                )
+                .unwrap();
+                let expected_targets = targets.iter().filter(|t| expected.contains_key(t));
+                assert_eq!(result.len(), expected_targets.count());
+                for (node, info) in result {
+                    assert_eq!(info.paths, expected[&node].paths);
+                }
+            }
+            let expected = dijkstra::single_source_distances_csr(
+                &graph.to_csr_in_direction(Direction::Incoming),
+                false,
+                7,
+                None,
+                None,
+            );
+            let result = dijkstra::single_source_distances_in_direction(
+                &graph,
+                false,
+                7,
+                Direction::Incoming,
+                None,
+                None,
+            );
+            assert_eq!(result.unwrap(), expected.unwrap());
+            let expected = dijkstra::single_source_csr(&csr, false, 7, None, None, None, false);
+            let expected = expected.unwrap();
+            for target in [2, 25, 39] {
+                let result = dijkstra::shortest_path(&graph, false, 7, target, None).unwrap();
+                assert_eq!(
+                    result.map(|info| info.paths),
+                    expected.get(&target).map(|info| info.paths.clone())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_bfs_csr_matches_graph() {
+        for directed in [true, false] {
+            let graph = generators::random::gnm_random_graph(50, 120, Some(3), directed).unwrap();
+            let csr = graph.to_csr();
+            let expected: Vec<usize> = bfs::breadth_first_nodes(&graph, 7).unwrap().collect();
+            let result: Vec<usize> = bfs::breadth_first_nodes_csr(&csr, 7).unwrap().collect();
+            assert_eq!(result, expected);
+            let mut expected = Recorder::default();
+            bfs::breadth_first_search(&graph, 7, &mut expected).unwrap();
+            let mut result = Recorder::default();
+            bfs::breadth_first_search_csr(&csr, 7, &mut result).unwrap();
+            assert_eq!(result.events, expected.events);
+        }
+    }
+
+    #[test]
+    fn test_pagerank_csr_matches_graph() {
+        let graph = generators::random::gnm_random_graph(60, 200, Some(5), true).unwrap();
+        let csr = graph.to_csr();
+        let expected = pagerank::pagerank(&graph, false, 0.85, None, None, None).unwrap();
+        let result = pagerank::pagerank_csr(&csr, false, 0.85, None, None, None).unwrap();
+        assert_eq!(result.len(), expected.len());
+        for (node, rank) in result {
+            assert!((rank - expected.get(&node).unwrap()).abs() < 1.0e-12);
+        }
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n1", "n3", 3.0),
+                Edge::with_weight("n2", "n3", 1.1),
+                Edge::with_weight("n3", "n4", 0.5),
+            ])
+            .unwrap();
+        let csr = graph.to_csr();
+        let expected = pagerank::pagerank(&graph, true, 0.85, None, None, None).unwrap();
+        let result = pagerank::pagerank_csr(&csr, true, 0.85, None, None, None).unwrap();
+        for (node, rank) in result {
+            assert!((rank - expected.get(&node).unwrap()).abs() < 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn test_betweenness_csr_matches_graph() {
+        let graph = generators::social::karate_club_graph();
+        let csr = graph.to_csr();
+        let expected = betweenness::betweenness_centrality(&graph, false, true).unwrap();
+        let result = betweenness::betweenness_centrality_csr(&csr, false, true).unwrap();
+        assert_eq!(result.len(), 34);
+        for (node, value) in result {
+            assert!((value - expected.get(&node).unwrap()).abs() < 1.0e-12);
+        }
+        let graph = get_weighted_graph(GraphSpecs::directed_create_missing());
+        let result = betweenness::betweenness_centrality_csr(&graph.to_csr(), true, false);
+        let result = result.unwrap();
+        assert_eq!(result.get("n2"), Some(&2.0));
+        assert_eq!(result.get("n3"), Some(&1.0));
+        assert_eq!(result.get("n4"), Some(&0.0));
+    }
+
+    #[test]
+    fn test_to_csr_in_direction() {
+        let graph = get_weighted_graph(GraphSpecs::directed_create_missing());
+        let csr = graph.to_csr_in_direction(Direction::Incoming);
+        assert!(csr.is_directed());
+        let n3 = csr.get_node_index(&"n3").unwrap();
+        let predecessors: Vec<&str> = csr
+            .get_successors_or_neighbors(n3)
+            .iter()
+            .map(|i| *csr.get_node_name(*i).unwrap())
+            .collect();
+        assert_eq!(predecessors, vec!["n1", "n2"]);
+        assert_eq!(csr.get_weights(n3), &[3.0, 1.1]);
+        let n1 = csr.get_node_index(&"n1").unwrap();
+        assert_eq!(csr.get_weights(n1), &[2.0]);
+        let graph = generators::social::karate_club_graph();
+        let incoming = graph.to_csr_in_direction(Direction::Incoming);
+        let outgoing = graph.to_csr();
+        for index in 0..outgoing.number_of_nodes() as u32 {
+            assert_eq!(
+                incoming.get_successors_or_neighbors(index),
+                outgoing.get_successors_or_neighbors(index)
+            );
+        }
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
 
-    use graphrs::{Edge, Graph, GraphSpecs};
+    use graphrs::{Edge, Graph, GraphSpecs, Node};
 
     #[test]
     fn get_degree_for_all_nodes() {
@@ -357,4 +357,44 @@ mod tests {
         assert_eq!(result.get("n4").unwrap(), &4.0);
         assert_eq!(result.get("n5").unwrap(), &0.0);
     }
+
+    #[test]
+    fn get_degree_iter() {
+        let edges = vec![
+            Edge::new("n1", "n2"),
+            Edge::new("n1", "n3"),
+            Edge::new("n1", "n4"),
+            Edge::new("n4", "n5"),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let mut result: Vec<(&str, usize)> =
+            graph.get_degree_iter().map(|(n, d)| (*n, d)).collect();
+        result.sort();
+        assert_eq!(
+            result,
+            vec![("n1", 3), ("n2", 1), ("n3", 1), ("n4", 2), ("n5", 1)]
+        );
+    }
+
+    #[test]
+    fn get_degree_histogram() {
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected_create_missing()
+        };
+        let mut graph: Graph<&str, ()> = Graph::new(specs);
+        assert!(graph.get_degree_histogram().is_empty());
+        graph.add_node(Node::from_name("n0"));
+        assert_eq!(graph.get_degree_histogram(), vec![1]);
+        graph
+            .add_edges(vec![
+                Edge::new("n1", "n2"),
+                Edge::new("n1", "n3"),
+                Edge::new("n3", "n3"),
+            ])
+            .unwrap();
+        assert_eq!(graph.get_degree_histogram(), vec![1, 1, 1, 1]);
+    }
 }
@@ -0,0 +1,220 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::shortest_path::dijkstra, Edge, ErrorKind, Graph, GraphSpecs};
+
+    fn get_multi_graph(directed: bool) -> Graph<&'static str, &'static str> {
+        let specs = match directed {
+            true => GraphSpecs::directed_create_missing(),
+            false => GraphSpecs::undirected_create_missing(),
+        };
+        let mut graph = Graph::new(GraphSpecs {
+            multi_edges: true,
+            self_loops: true,
+            ..specs
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n1", "n2", 2.0),
+                Edge::with_weight("n1", "n2", 3.0),
+                Edge::with_weight("n2", "n3", 4.0),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_edge_keys_default() {
+        let graph = get_multi_graph(true);
+        assert_eq!(graph.get_edge_keys("n1", "n2").unwrap(), vec![0, 1, 2]);
+        assert_eq!(graph.get_edge_keys("n2", "n3").unwrap(), vec![0]);
+        assert_eq!(graph.get_edge_by_key("n1", "n2", 2).unwrap().weight, 3.0);
+        let result = graph.get_edge_by_key("n1", "n2", 3);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeNotFound
+        ));
+        let result = graph.get_edge_keys("n2", "n1");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_add_edge_with_key() {
+        let mut graph = get_multi_graph(true);
+        graph.add_edge_with_key(Edge::with_weight("n1", "n2", 4.0), 10).unwrap();
+        graph.add_edge(Edge::with_weight("n1", "n2", 5.0)).unwrap();
+        graph.add_edge_with_key(Edge::with_weight("n1", "n2", 6.0), 5).unwrap();
+        graph.add_edge(Edge::with_weight("n1", "n2", 7.0)).unwrap();
+        assert_eq!(
+            graph.get_edge_keys("n1", "n2").unwrap(),
+            vec![0, 1, 2, 10, 4, 5, 6]
+        );
+        assert_eq!(graph.get_edge_by_key("n1", "n2", 10).unwrap().weight, 4.0);
+        assert_eq!(graph.get_edge_by_key("n1", "n2", 6).unwrap().weight, 7.0);
+        graph.add_edge_with_key(Edge::with_weight("n3", "n4", 1.0), 3).unwrap();
+        assert_eq!(graph.get_edge_keys("n3", "n4").unwrap(), vec![3]);
+        assert!(graph.get_edge_by_key("n3", "n4", 0).is_err());
+    }
+
+    #[test]
+    fn test_add_edge_with_key_duplicate() {
+        let mut graph = get_multi_graph(true);
+        let result = graph.add_edge_with_key(Edge::with_weight("n1", "n2", 4.0), 1);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::DuplicateEdge
+        ));
+        assert_eq!(graph.get_edges("n1", "n2").unwrap().len(), 3);
+        graph.add_edge_with_key(Edge::with_weight("n2", "n1", 4.0), 1).unwrap();
+    }
+
+    #[test]
+    fn test_edge_keys_undirected() {
+        let mut graph = get_multi_graph(false);
+        graph.add_edge_with_key(Edge::with_weight("n2", "n1", 4.0), 7).unwrap();
+        let result = graph.add_edge_with_key(Edge::with_weight("n1", "n2", 5.0), 7);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::DuplicateEdge
+        ));
+        assert_eq!(graph.get_edge_keys("n2", "n1").unwrap(), vec![0, 1, 2, 7]);
+        assert_eq!(graph.get_edge_by_key("n1", "n2", 7).unwrap().weight, 4.0);
+        graph.remove_edge_by_key("n2", "n1", 7).unwrap();
+        assert_eq!(graph.get_edge_keys("n1", "n2").unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_remove_edge_by_key() {
+        let mut graph = get_multi_graph(true);
+        graph.remove_edge_by_key("n1", "n2", 1).unwrap();
+        assert_eq!(graph.get_edge_keys("n1", "n2").unwrap(), vec![0, 2]);
+        assert_eq!(graph.get_edge_by_key("n1", "n2", 2).unwrap().weight, 3.0);
+        // as with NetworkX, the new edge gets the lowest key from the number of edges up
+        graph.add_edge(Edge::with_weight("n1", "n2", 4.0)).unwrap();
+        graph.add_edge(Edge::with_weight("n1", "n2", 5.0)).unwrap();
+        assert_eq!(graph.get_edge_keys("n1", "n2").unwrap(), vec![0, 2, 3, 4]);
+        let result = graph.remove_edge_by_key("n1", "n2", 1);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeNotFound
+        ));
+        graph.remove_edge_by_key("n2", "n3", 0).unwrap();
+        assert!(graph.get_edges("n2", "n3").is_err());
+        assert_eq!(graph.get_successor_nodes("n2").unwrap().len(), 0);
+        assert_eq!(graph.get_predecessor_nodes("n3").unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_remove_then_add_edges() {
+        let mut graph = get_multi_graph(true);
+        graph.remove_edge_by_key("n1", "n2", 0).unwrap();
+        graph.remove_edge("n1", "n2").unwrap();
+        graph.add_edge(Edge::with_weight("n1", "n2", 4.0)).unwrap();
+        assert_eq!(graph.get_edge_keys("n1", "n2").unwrap(), vec![0]);
+        graph.remove_edge_by_key("n2", "n3", 0).unwrap();
+        graph.add_edge_with_key(Edge::with_weight("n2", "n3", 5.0), 2).unwrap();
+        graph.remove_node("n3").unwrap();
+        graph.add_edge(Edge::with_weight("n2", "n3", 6.0)).unwrap();
+        assert_eq!(graph.get_edge_keys("n2", "n3").unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_remove_edges_where_keeps_keys() {
+        let mut graph = get_multi_graph(true);
+        graph.add_edge_with_key(Edge::with_weight("n1", "n2", 4.5), 9).unwrap();
+        let num_removed = graph.remove_edges_where(|e| e.weight == 2.0 || e.weight == 4.0);
+        assert_eq!(num_removed, 2);
+        assert_eq!(graph.get_edge_keys("n1", "n2").unwrap(), vec![0, 2, 9]);
+        assert_eq!(graph.get_edge_by_key("n1", "n2", 9).unwrap().weight, 4.5);
+        assert!(graph.get_edge_by_key("n1", "n2", 1).is_err());
+        assert!(graph.get_edges("n2", "n3").is_err());
+    }
+
+    #[test]
+    fn test_subgraph_and_reverse_keep_keys() {
+        let mut graph = get_multi_graph(true);
+        graph.remove_edge("n1", "n2").unwrap();
+        graph.add_edge_with_key(Edge::with_weight("n1", "n2", 1.0), 10).unwrap();
+        graph.add_edge_with_key(Edge::with_weight("n1", "n2", 2.0), 20).unwrap();
+        graph.add_edge(Edge::with_weight("n3", "n4", 5.0)).unwrap();
+
+        let subgraph = graph.get_subgraph(&["n1", "n2", "n3"]);
+        assert_eq!(subgraph.get_edge_keys("n1", "n2").unwrap(), vec![10, 20]);
+        assert_eq!(
+            subgraph.get_edge_by_key("n1", "n2", 20).unwrap().weight,
+            2.0
+        );
+        assert_eq!(subgraph.get_edge_keys("n2", "n3").unwrap(), vec![0]);
+
+        let edge_subgraph = graph.get_edge_subgraph(&[("n1", "n2")]);
+        assert_eq!(
+            edge_subgraph.get_edge_keys("n1", "n2").unwrap(),
+            vec![10, 20]
+        );
+        assert_eq!(
+            edge_subgraph.get_edge_by_key("n1", "n2", 10).unwrap().weight,
+            1.0
+        );
+
+        let reversed = graph.reverse().unwrap();
+        assert_eq!(reversed.get_edge_keys("n2", "n1").unwrap(), vec![10, 20]);
+        assert_eq!(
+            reversed.get_edge_by_key("n2", "n1", 20).unwrap().weight,
+            2.0
+        );
+        assert_eq!(reversed.get_edge_keys("n4", "n3").unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_set_edge_by_key() {
+        let mut graph = get_multi_graph(true);
+        graph.set_edge_weight_by_key("n1", "n2", 1, 9.0).unwrap();
+        graph.set_edge_attributes_by_key("n1", "n2", 1, Some("second")).unwrap();
+        let weights: Vec<f64> =
+            graph.get_edges("n1", "n2").unwrap().iter().map(|e| e.weight).collect();
+        assert_eq!(weights, vec![1.0, 9.0, 3.0]);
+        assert_eq!(
+            graph.get_edge_by_key("n1", "n2", 1).unwrap().attributes,
+            Some("second")
+        );
+        assert_eq!(
+            graph.get_edge_by_key("n1", "n2", 0).unwrap().attributes,
+            None
+        );
+        let result = graph.set_edge_weight_by_key("n1", "n2", 5, 1.0);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_edge_keys_not_multi() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        graph.add_edge(Edge::new("n1", "n2")).unwrap();
+        let result = graph.add_edge_with_key(Edge::new("n2", "n3"), 0);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let result = graph.get_edge_by_key("n1", "n2", 0);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let result = graph.remove_edge_by_key("n1", "n2", 0);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let result = graph.get_edge_keys("n1", "n2");
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_shortest_path_edge_keys() {
+        let mut graph = get_multi_graph(true);
+        graph.add_edge_with_key(Edge::with_weight("n1", "n2", 0.5), 42).unwrap();
+        let info = dijkstra::shortest_path(&graph, true, "n1", "n3", None).unwrap().unwrap();
+        assert_eq!(info.distance, 4.5);
+        assert_eq!(info.edge_keys, Some(vec![vec![42, 0]]));
+        graph.remove_edge_by_key("n1", "n2", 0).unwrap();
+        let info = dijkstra::shortest_path(&graph, false, "n1", "n3", None).unwrap().unwrap();
+        assert_eq!(info.edge_keys, Some(vec![vec![1, 0]]));
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{generators, Edge, ErrorKind, Graph, GraphSpecs};
+
+    fn get_directed_graph() -> Graph<&'static str, ()> {
+        let mut graph = Graph::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::new("n1", "n2"),
+                Edge::new("n2", "n1"),
+                Edge::new("n2", "n3"),
+                Edge::new("n3", "n4"),
+                Edge::new("n2", "n2"),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_remove_node_directed() {
+        let mut graph = get_directed_graph();
+        graph.remove_node("n2").unwrap();
+        let mut names = graph.get_all_node_names();
+        names.sort();
+        assert_eq!(names, vec![&"n1", &"n3", &"n4"]);
+        assert_eq!(graph.get_all_edges().len(), 1);
+        assert!(graph.get_edge("n3", "n4").is_ok());
+        assert_eq!(graph.get_successor_nodes("n1").unwrap().len(), 0);
+        assert_eq!(graph.get_predecessor_nodes("n1").unwrap().len(), 0);
+        assert_eq!(graph.get_predecessor_nodes("n3").unwrap().len(), 0);
+        assert!(!graph.get_successors_map().contains_key("n2"));
+        assert!(!graph.get_predecessors_map().contains_key("n2"));
+        assert!(!graph.get_successors_map().contains_key("n1"));
+    }
+
+    #[test]
+    fn test_remove_node_undirected() {
+        let mut graph = generators::social::karate_club_graph();
+        let degree_of_1 = graph.get_node_degree(1).unwrap();
+        graph.remove_node(0).unwrap();
+        assert_eq!(graph.get_all_nodes().len(), 33);
+        assert_eq!(graph.get_all_edges().len(), 78 - 16);
+        assert_eq!(graph.get_node_degree(1).unwrap(), degree_of_1 - 1);
+        assert!(graph.get_edge(1, 0).is_err());
+        // node 11 was only connected to node 0
+        assert_eq!(graph.get_neighbor_nodes(11).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_remove_node_not_found() {
+        let mut graph = get_directed_graph();
+        let result = graph.remove_node("n9");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_remove_nodes_from() {
+        let mut graph = get_directed_graph();
+        let result = graph.remove_nodes_from(&["n1", "n9"]);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        assert_eq!(graph.get_all_nodes().len(), 4);
+        graph.remove_nodes_from(&["n1", "n3", "n1"]).unwrap();
+        assert_eq!(graph.get_all_nodes().len(), 2);
+        assert_eq!(graph.get_all_edges().len(), 1);
+        assert!(graph.get_edge("n2", "n2").is_ok());
+    }
+
+    #[test]
+    fn test_remove_edge_directed() {
+        let mut graph = get_directed_graph();
+        graph.remove_edge("n1", "n2").unwrap();
+        assert!(graph.get_edge("n1", "n2").is_err());
+        assert!(graph.get_edge("n2", "n1").is_ok());
+        assert_eq!(graph.get_successor_nodes("n1").unwrap().len(), 0);
+        assert_eq!(graph.get_predecessor_nodes("n1").unwrap().len(), 1);
+        assert_eq!(graph.get_all_nodes().len(), 4);
+        let result = graph.remove_edge("n1", "n2");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_remove_edge_undirected() {
+        let mut graph = generators::social::karate_club_graph();
+        graph.remove_edge(1, 0).unwrap();
+        assert!(graph.get_edge(0, 1).is_err());
+        assert_eq!(graph.get_all_edges().len(), 77);
+        assert!(!graph.get_neighbor_nodes(0).unwrap().iter().any(|n| n.name == 1));
+    }
+
+    #[test]
+    fn test_remove_edge_multi() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::undirected_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n2", "n1", 2.0),
+                Edge::with_weight("n2", "n3", 3.0),
+            ])
+            .unwrap();
+        graph.remove_edge("n2", "n1").unwrap();
+        assert!(graph.get_edges("n1", "n2").is_err());
+        assert_eq!(graph.get_all_edges().len(), 1);
+    }
+
+    #[test]
+    fn test_remove_edges_where() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n1", "n2", 5.0),
+                Edge::with_weight("n2", "n3", 6.0),
+                Edge::with_weight("n3", "n1", 0.5),
+            ])
+            .unwrap();
+        let removed = graph.remove_edges_where(|e| e.weight > 2.0);
+        assert_eq!(removed, 2);
+        assert_eq!(graph.get_edges("n1", "n2").unwrap().len(), 1);
+        assert!(graph.get_edges("n2", "n3").is_err());
+        assert_eq!(graph.get_successor_nodes("n2").unwrap().len(), 0);
+        assert_eq!(graph.get_successor_nodes("n1").unwrap().len(), 1);
+        assert_eq!(graph.remove_edges_where(|_e| false), 0);
+    }
+}
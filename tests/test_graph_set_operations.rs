@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{Edge, EdgeDedupeStrategy, ErrorKind, Graph, GraphSpecs, Node};
+
+    fn get_graphs(
+        specs: GraphSpecs,
+    ) -> (
+        Graph<&'static str, &'static str>,
+        Graph<&'static str, &'static str>,
+    ) {
+        let mut graph1 = Graph::new(specs.clone());
+        graph1.add_nodes(vec![
+            Node::from_name_and_attributes("n1", "n1 in graph1"),
+            Node::from_name("n2"),
+            Node::from_name("n5"),
+        ]);
+        graph1
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n2", "n3", 2.0),
+                Edge::with_weight("n3", "n4", 3.0),
+            ])
+            .unwrap();
+        let mut graph2 = Graph::new(specs);
+        graph2.add_nodes(vec![
+            Node::from_name_and_attributes("n1", "n1 in graph2"),
+            Node::from_name_and_attributes("n2", "n2 in graph2"),
+            Node::from_name("n3"),
+        ]);
+        graph2
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 10.0),
+                Edge::with_weight("n3", "n2", 20.0),
+                Edge::with_weight("n3", "n6", 30.0),
+            ])
+            .unwrap();
+        (graph1, graph2)
+    }
+
+    #[test]
+    fn test_union_1() {
+        let (graph1, graph2) = get_graphs(GraphSpecs::directed_create_missing());
+        let result = graph1.union(&graph2);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::DuplicateEdge
+        ));
+    }
+
+    #[test]
+    fn test_union_2() {
+        let (graph1, graph2) = get_graphs(GraphSpecs {
+            edge_dedupe_strategy: EdgeDedupeStrategy::KeepFirst,
+            ..GraphSpecs::directed_create_missing()
+        });
+        let union = graph1.union(&graph2).unwrap();
+        assert_eq!(union.get_all_nodes().len(), 6);
+        assert_eq!(union.get_all_edges().len(), 5);
+        assert_eq!(union.get_edge("n1", "n2").unwrap().weight, 1.0);
+        assert_eq!(union.get_edge("n3", "n2").unwrap().weight, 20.0);
+        assert_eq!(
+            union.get_node("n1").unwrap().attributes,
+            Some("n1 in graph1")
+        );
+        assert_eq!(
+            union.get_node("n2").unwrap().attributes,
+            Some("n2 in graph2")
+        );
+        assert!(union.specs.edge_dedupe_strategy == EdgeDedupeStrategy::KeepFirst);
+    }
+
+    #[test]
+    fn test_union_3() {
+        let (graph1, graph2) = get_graphs(GraphSpecs {
+            edge_dedupe_strategy: EdgeDedupeStrategy::KeepLast,
+            ..GraphSpecs::undirected_create_missing()
+        });
+        let union = graph1.union(&graph2).unwrap();
+        assert_eq!(union.get_all_nodes().len(), 6);
+        assert_eq!(union.get_all_edges().len(), 4);
+        assert_eq!(union.get_edge("n1", "n2").unwrap().weight, 10.0);
+        assert_eq!(union.get_edge("n2", "n3").unwrap().weight, 20.0);
+    }
+
+    #[test]
+    fn test_union_4() {
+        let (graph1, graph2) = get_graphs(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::directed_create_missing()
+        });
+        let union = graph1.union(&graph2).unwrap();
+        assert_eq!(union.get_all_edges().len(), 6);
+        assert_eq!(union.get_edges("n1", "n2").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_union_5() {
+        let (graph1, _graph2) = get_graphs(GraphSpecs::directed_create_missing());
+        let graph2: Graph<&str, &str> = Graph::new(GraphSpecs::undirected());
+        let result = graph1.union(&graph2);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_compose() {
+        let (graph1, graph2) = get_graphs(GraphSpecs::directed_create_missing());
+        let composed = graph1.compose(&graph2).unwrap();
+        assert_eq!(composed.get_all_nodes().len(), 6);
+        assert_eq!(composed.get_all_edges().len(), 5);
+        assert_eq!(composed.get_edge("n1", "n2").unwrap().weight, 10.0);
+        assert_eq!(
+            composed.get_node("n1").unwrap().attributes,
+            Some("n1 in graph2")
+        );
+        assert_eq!(
+            composed.get_node("n2").unwrap().attributes,
+            Some("n2 in graph2")
+        );
+        assert!(composed.specs.edge_dedupe_strategy == EdgeDedupeStrategy::Error);
+    }
+
+    #[test]
+    fn test_intersection_1() {
+        let (graph1, graph2) = get_graphs(GraphSpecs::directed_create_missing());
+        let intersection = graph1.intersection(&graph2).unwrap();
+        let mut names = intersection.get_all_node_names();
+        names.sort();
+        assert_eq!(names, vec![&"n1", &"n2", &"n3"]);
+        assert_eq!(intersection.get_all_edges().len(), 1);
+        assert_eq!(intersection.get_edge("n1", "n2").unwrap().weight, 1.0);
+        assert_eq!(
+            intersection.get_node("n1").unwrap().attributes,
+            Some("n1 in graph1")
+        );
+    }
+
+    #[test]
+    fn test_intersection_2() {
+        let (graph1, graph2) = get_graphs(GraphSpecs::undirected_create_missing());
+        let intersection = graph1.intersection(&graph2).unwrap();
+        assert_eq!(intersection.get_all_edges().len(), 2);
+        assert_eq!(intersection.get_edge("n3", "n2").unwrap().weight, 2.0);
+    }
+
+    #[test]
+    fn test_difference_1() {
+        let (graph1, graph2) = get_graphs(GraphSpecs::directed_create_missing());
+        let difference = graph1.difference(&graph2).unwrap();
+        assert_eq!(difference.get_all_nodes().len(), 5);
+        assert_eq!(difference.get_all_edges().len(), 2);
+        assert!(difference.get_edge("n2", "n3").is_ok());
+        assert!(difference.get_edge("n3", "n4").is_ok());
+    }
+
+    #[test]
+    fn test_difference_2() {
+        let (graph1, graph2) = get_graphs(GraphSpecs::undirected_create_missing());
+        let difference = graph1.difference(&graph2).unwrap();
+        assert_eq!(difference.get_all_edges().len(), 1);
+        assert!(difference.get_edge("n4", "n3").is_ok());
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
 
-    use graphrs::generators;
+    use graphrs::{generators, Edge, ErrorKind, Graph, GraphSpecs, Node};
 
     #[test]
     fn test_get_subgraph() {
@@ -20,4 +20,101 @@ mod tests {
         assert!(subgraph.get_edge(4, 10).is_ok());
         assert!(subgraph.get_edge(4, 6).is_ok());
     }
+
+    #[test]
+    fn test_get_subgraph_keeps_weights_and_attributes() {
+        let mut graph = Graph::<&str, &str>::new(GraphSpecs::directed_create_missing());
+        graph.add_node(Node::from_name_and_attributes("n1", "first"));
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 2.5),
+                Edge::with_weight("n2", "n3", 1.0),
+            ])
+            .unwrap();
+        let subgraph = graph.get_subgraph(&["n1", "n2", "n9"]);
+        assert_eq!(subgraph.get_all_nodes().len(), 2);
+        assert_eq!(subgraph.get_edge("n1", "n2").unwrap().weight, 2.5);
+        assert_eq!(subgraph.get_node("n1").unwrap().attributes, Some("first"));
+    }
+
+    #[test]
+    fn test_get_edge_subgraph_1() {
+        let graph = generators::social::karate_club_graph();
+        let subgraph = graph.get_edge_subgraph(&[(1, 0), (0, 1), (2, 1), (5, 6), (0, 9)]);
+        let mut node_names =
+            subgraph.get_all_nodes().into_iter().map(|n| n.name).collect::<Vec<i32>>();
+        node_names.sort_unstable();
+        assert_eq!(node_names, vec![0, 1, 2, 5, 6]);
+        assert_eq!(subgraph.get_all_edges().len(), 3);
+        assert!(subgraph.get_edge(0, 1).is_ok());
+        assert!(subgraph.get_edge(1, 2).is_ok());
+        assert!(subgraph.get_edge(5, 6).is_ok());
+        assert!(subgraph.get_edge(0, 2).is_err());
+    }
+
+    #[test]
+    fn test_get_edge_subgraph_2() {
+        let specs = GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::directed_create_missing()
+        };
+        let mut graph = Graph::<&str, &str>::new(specs);
+        graph.add_node(Node::from_name_and_attributes("n2", "second"));
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n1", "n2", 2.0),
+                Edge::with_weight("n2", "n1", 3.0),
+                Edge::with_weight("n2", "n3", 4.0),
+            ])
+            .unwrap();
+        let subgraph = graph.get_edge_subgraph(&[("n1", "n2")]);
+        assert_eq!(subgraph.get_all_nodes().len(), 2);
+        assert_eq!(subgraph.size(true), 3.0);
+        assert!(subgraph.get_edges("n2", "n1").is_err());
+        assert_eq!(subgraph.get_node("n2").unwrap().attributes, Some("second"));
+    }
+
+    #[test]
+    fn test_get_ego_graph_1() {
+        let graph = generators::social::karate_club_graph();
+        let ego_graph = graph.get_ego_graph(0, 1.0, false).unwrap();
+        assert_eq!(ego_graph.get_all_nodes().len(), 17);
+        assert_eq!(ego_graph.get_all_edges().len(), 34);
+        let ego_graph = graph.get_ego_graph(0, 2.0, false).unwrap();
+        assert_eq!(ego_graph.get_all_nodes().len(), 26);
+        let ego_graph = graph.get_ego_graph(0, 0.0, false).unwrap();
+        assert_eq!(ego_graph.get_all_nodes().len(), 1);
+    }
+
+    #[test]
+    fn test_get_ego_graph_2() {
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n2", "n3", 1.5),
+                Edge::with_weight("n1", "n3", 3.0),
+                Edge::with_weight("n3", "n4", 0.5),
+                Edge::with_weight("n5", "n1", 0.5),
+            ])
+            .unwrap();
+        let ego_graph = graph.get_ego_graph("n1", 2.5, true).unwrap();
+        let mut node_names = ego_graph.get_all_node_names();
+        node_names.sort();
+        assert_eq!(node_names, vec![&"n1", &"n2", &"n3"]);
+        assert_eq!(ego_graph.get_all_edges().len(), 3);
+        let ego_graph = graph.get_ego_graph("n1", 1.0, false).unwrap();
+        assert_eq!(ego_graph.get_all_nodes().len(), 3);
+    }
+
+    #[test]
+    fn test_get_ego_graph_3() {
+        let graph = generators::social::karate_club_graph();
+        let result = graph.get_ego_graph(99, 1.0, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
 }
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{generators, Edge, ErrorKind, Graph, GraphSpecs};
+
+    fn get_directed_graph() -> Graph<&'static str, ()> {
+        let mut graph = Graph::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n2", "n1", 2.0),
+                Edge::with_weight("n2", "n3", 3.0),
+                Edge::with_weight("n3", "n4", 4.0),
+                Edge::with_weight("n2", "n2", 5.0),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_filter_nodes_undirected() {
+        let graph = generators::social::karate_club_graph();
+        let view = graph.filter_nodes(|n| n.name < 10);
+        assert_eq!(view.get_all_nodes().len(), 10);
+        assert_eq!(view.get_all_edges().len(), 18);
+        assert!(view.has_node(&9));
+        assert!(!view.has_node(&10));
+        assert!(view.get_node(33).is_none());
+        let neighbors: Vec<i32> =
+            view.get_neighbor_nodes(2).unwrap().iter().map(|n| n.name).collect();
+        assert_eq!(neighbors, vec![0, 1, 3, 7, 8, 9]);
+        assert_eq!(view.get_node_degree(2), Some(6));
+        assert_eq!(view.get_node_degree(33), None);
+        let result = view.get_neighbor_nodes(33);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let result = view.get_edge(0, 31);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeNotFound
+        ));
+        let result = view.get_successor_nodes(0);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_filter_edges_directed() {
+        let graph = get_directed_graph();
+        let view = graph.filter_edges(|e| e.weight > 1.5);
+        assert_eq!(view.get_all_nodes().len(), 4);
+        assert_eq!(view.get_all_edges().len(), 4);
+        assert!(view.get_edge("n1", "n2").is_err());
+        assert!(view.get_edge("n2", "n1").is_ok());
+        assert_eq!(view.get_successor_nodes("n1").unwrap().len(), 0);
+        let successors: Vec<&str> =
+            view.get_successor_nodes("n2").unwrap().iter().map(|n| n.name).collect();
+        assert_eq!(successors, vec!["n1", "n2", "n3"]);
+        let predecessors: Vec<&str> =
+            view.get_predecessor_nodes("n2").unwrap().iter().map(|n| n.name).collect();
+        assert_eq!(predecessors, vec!["n2"]);
+        assert_eq!(
+            view.get_node_degree("n2"),
+            view.to_graph().get_node_degree("n2")
+        );
+        assert_eq!(view.size(false), 4.0);
+        assert_eq!(view.size(true), 14.0);
+    }
+
+    #[test]
+    fn test_chained_filters() {
+        let graph = get_directed_graph();
+        let view = graph
+            .filter_nodes(|n| n.name != "n4")
+            .filter_edges(|e| e.u != e.v)
+            .filter_nodes(|n| n.name != "n1");
+        assert_eq!(view.get_all_node_names().len(), 2);
+        let edges = view.get_all_edges();
+        assert_eq!(edges.len(), 1);
+        assert_eq!((edges[0].u, edges[0].v), ("n2", "n3"));
+        assert!(view.get_edges_for_node("n1").is_err());
+        assert_eq!(view.get_successors_or_neighbors("n3").len(), 0);
+    }
+
+    #[test]
+    fn test_filter_edges_multi() {
+        let mut graph: Graph<&str, ()> = Graph::new(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::undirected_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1", "n2", 1.0),
+                Edge::with_weight("n2", "n1", 2.0),
+                Edge::with_weight("n2", "n3", 3.0),
+            ])
+            .unwrap();
+        let view = graph.filter_edges(|e| e.weight < 2.5);
+        assert_eq!(view.get_edges("n1", "n2").unwrap().len(), 2);
+        let result = view.get_edges("n2", "n3");
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeNotFound
+        ));
+        let result = view.get_edge("n1", "n2");
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_to_graph() {
+        let graph = get_directed_graph();
+        let new_graph = graph.filter_nodes(|n| n.name != "n2").to_graph();
+        assert_eq!(new_graph.get_all_nodes().len(), 3);
+        assert_eq!(new_graph.get_all_edges().len(), 1);
+        assert!(new_graph.get_edge("n3", "n4").is_ok());
+        assert!(new_graph.specs.directed);
+        // the original graph is unchanged
+        assert_eq!(graph.get_all_edges().len(), 5);
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{generators, linalg, Edge, ErrorKind, Graph, GraphSpecs, Node};
+
+    fn get_graph() -> Graph<i32, ()> {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected_create_missing()
+        });
+        graph
+            .add_edges(vec![
+                Edge::with_weight(0, 1, 2.0),
+                Edge::with_weight(0, 2, 1.0),
+                Edge::with_weight(2, 1, 3.0),
+                Edge::with_weight(2, 3, 4.0),
+                Edge::with_weight(3, 3, 5.0),
+            ])
+            .unwrap();
+        graph.add_node(Node::from_name(4));
+        graph
+    }
+
+    fn assert_approx(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-12,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_adjacency_matrix_1() {
+        let graph = get_graph();
+        let (nodes, matrix) = linalg::adjacency_matrix(&graph, true).unwrap();
+        assert_eq!(nodes, vec![0, 1, 2, 3, 4]);
+        assert_eq!((matrix.nrows(), matrix.ncols()), (5, 5));
+        assert_eq!(matrix.nnz(), 9);
+        assert_eq!(matrix.get(0, 1), 2.0);
+        assert_eq!(matrix.get(1, 0), 2.0);
+        assert_eq!(matrix.get(1, 2), 3.0);
+        assert_eq!(matrix.get(3, 3), 5.0);
+        assert_eq!(matrix.get(4, 4), 0.0);
+        assert_eq!(matrix.get_row(2), (&[0, 1, 3][..], &[1.0, 3.0, 4.0][..]));
+        let (_nodes, matrix) = linalg::adjacency_matrix(&graph, false).unwrap();
+        assert_eq!(matrix.mul_vec(&[1.0; 5]), vec![2.0, 2.0, 3.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn test_adjacency_matrix_2() {
+        // directed, with multiple edges
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::multi_directed());
+        graph.add_nodes(vec![Node::from_name("a"), Node::from_name("b")]);
+        graph
+            .add_edges(vec![
+                Edge::with_weight("a", "b", 1.0),
+                Edge::with_weight("a", "b", 2.0),
+            ])
+            .unwrap();
+        let (_nodes, matrix) = linalg::adjacency_matrix(&graph, true).unwrap();
+        assert_eq!(matrix.get(0, 1), 3.0);
+        assert_eq!(matrix.get(1, 0), 0.0);
+        let (_nodes, matrix) = linalg::adjacency_matrix(&graph, false).unwrap();
+        assert_eq!(matrix.get(0, 1), 2.0);
+    }
+
+    #[test]
+    fn test_laplacian_matrix_1() {
+        let graph = get_graph();
+        let (nodes, matrix) = linalg::laplacian_matrix(&graph, true).unwrap();
+        assert_eq!(nodes, vec![0, 1, 2, 3, 4]);
+        assert_eq!(matrix.get(0, 0), 3.0);
+        assert_eq!(matrix.get(0, 1), -2.0);
+        assert_eq!(matrix.get(0, 2), -1.0);
+        assert_eq!(matrix.get(1, 1), 5.0);
+        assert_eq!(matrix.get(2, 1), -3.0);
+        assert_eq!(matrix.get(2, 2), 8.0);
+        assert_eq!(matrix.get(3, 2), -4.0);
+        assert_eq!(matrix.get(3, 3), 4.0);
+        assert_eq!(matrix.get_row(4), (&[][..], &[][..]));
+        assert_eq!(matrix.mul_vec(&[1.0; 5]), vec![0.0; 5]);
+    }
+
+    #[test]
+    fn test_laplacian_matrix_2() {
+        // directed graphs use the out-degree
+        let graph = generators::classic::star_graph(3, true);
+        let (_nodes, matrix) = linalg::laplacian_matrix(&graph, false).unwrap();
+        assert_eq!(matrix.get(0, 0), 3.0);
+        assert_eq!(matrix.get(0, 1), -1.0);
+        assert_eq!(matrix.get(1, 0), 0.0);
+        assert_eq!(matrix.get(1, 1), 0.0);
+    }
+
+    #[test]
+    fn test_normalized_laplacian_1() {
+        let graph = get_graph();
+        let (_nodes, matrix) = linalg::normalized_laplacian(&graph, true).unwrap();
+        assert_approx(matrix.get(0, 0), 1.0);
+        assert_approx(matrix.get(0, 1), -2.0 / 15.0_f64.sqrt());
+        assert_approx(matrix.get(1, 0), -2.0 / 15.0_f64.sqrt());
+        assert_approx(matrix.get(2, 3), -4.0 / 72.0_f64.sqrt());
+        assert_approx(matrix.get(3, 3), 4.0 / 9.0);
+        assert_eq!(matrix.get(4, 4), 0.0);
+    }
+
+    #[test]
+    fn test_normalized_laplacian_2() {
+        // the eigenvector of the smallest eigenvalue, 0, is D^(1/2) times a vector of ones
+        let graph = generators::social::karate_club_graph();
+        let (nodes, matrix) = linalg::normalized_laplacian(&graph, false).unwrap();
+        let x: Vec<f64> =
+            nodes.iter().map(|n| (graph.get_node_degree(*n).unwrap() as f64).sqrt()).collect();
+        for value in matrix.mul_vec(&x) {
+            assert_approx(value, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_incidence_matrix_1() {
+        let graph = get_graph();
+        let (nodes, edges, matrix) = linalg::incidence_matrix(&graph, false, false).unwrap();
+        assert_eq!(nodes, vec![0, 1, 2, 3, 4]);
+        assert_eq!(edges, vec![(0, 1), (0, 2), (1, 2), (2, 3), (3, 3)]);
+        assert_eq!((matrix.nrows(), matrix.ncols()), (5, 5));
+        assert_eq!(matrix.get_row(2), (&[1, 2, 3][..], &[1.0, 1.0, 1.0][..]));
+        assert_eq!(matrix.get_row(3), (&[3][..], &[1.0][..]));
+        let (_nodes, _edges, matrix) = linalg::incidence_matrix(&graph, true, true).unwrap();
+        assert_eq!(matrix.get_row(2), (&[1, 2, 3][..], &[1.0, 3.0, -4.0][..]));
+        assert_eq!(matrix.get(1, 2), -3.0);
+    }
+
+    #[test]
+    fn test_incidence_matrix_2() {
+        // the oriented incidence matrix times its transpose is the Laplacian
+        let graph = generators::social::karate_club_graph();
+        let (_nodes, laplacian) = linalg::laplacian_matrix(&graph, false).unwrap();
+        let (nodes, _edges, incidence) = linalg::incidence_matrix(&graph, true, false).unwrap();
+        let n = nodes.len();
+        for i in 0..n {
+            for j in 0..n {
+                let (ci, vi) = incidence.get_row(i);
+                let dot: f64 = ci.iter().zip(vi).map(|(c, v)| v * incidence.get(j, *c)).sum();
+                assert_eq!(dot, laplacian.get(i, j));
+            }
+        }
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_to_dense_1() {
+        let graph = get_graph();
+        let (_nodes, matrix) = linalg::laplacian_matrix(&graph, true).unwrap();
+        let dense = matrix.to_dense();
+        assert_eq!(dense.shape(), &[5, 5]);
+        assert_eq!(dense[[0, 0]], 3.0);
+        assert_eq!(dense[[2, 1]], -3.0);
+        assert_eq!(dense[[4, 4]], 0.0);
+        assert_eq!(dense.sum(), 0.0);
+    }
+
+    #[test]
+    fn test_linalg_errors() {
+        let graph = generators::classic::path_graph(3, false);
+        let result = linalg::adjacency_matrix(&graph, true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+        let result = linalg::incidence_matrix(&graph, false, true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+    }
+}
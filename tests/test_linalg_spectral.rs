@@ -0,0 +1,107 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::round;
+    use graphrs::{generators, linalg, Edge, ErrorKind, Graph, GraphSpecs, Node};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_algebraic_connectivity_1() {
+        for n in [2, 3, 10, 60, 250] {
+            let graph = generators::classic::path_graph(n, false);
+            let result =
+                linalg::algebraic_connectivity(&graph, false, false, None, Some(1)).unwrap();
+            let expected = 2.0 * (1.0 - (PI / n as f64).cos());
+            assert!(
+                (result - expected).abs() < 1.0e-8,
+                "{}: {} != {}",
+                n,
+                result,
+                expected
+            );
+        }
+        let graph = generators::classic::cycle_graph(40, false);
+        let result = linalg::algebraic_connectivity(&graph, false, false, None, Some(1)).unwrap();
+        assert_eq!(
+            round(&result, 8),
+            round(&(2.0 * (1.0 - (PI / 20.0).cos())), 8)
+        );
+    }
+
+    #[test]
+    fn test_algebraic_connectivity_2() {
+        let graph = generators::social::karate_club_graph();
+        let result = linalg::algebraic_connectivity(&graph, false, false, None, None).unwrap();
+        assert_eq!(round(&result, 8), 0.46852523);
+        let result = linalg::algebraic_connectivity(&graph, false, true, None, None).unwrap();
+        assert_eq!(round(&result, 8), 0.13227233);
+    }
+
+    #[test]
+    fn test_algebraic_connectivity_3() {
+        // weighted, and disconnected
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::undirected_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight(0, 1, 2.5),
+                Edge::with_weight(1, 2, 2.5),
+            ])
+            .unwrap();
+        let result = linalg::algebraic_connectivity(&graph, true, false, None, Some(1)).unwrap();
+        assert_eq!(round(&result, 8), 2.5);
+        graph.add_node(Node::from_name(3));
+        let result = linalg::algebraic_connectivity(&graph, true, false, None, Some(1)).unwrap();
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn test_fiedler_vector_1() {
+        let graph = generators::social::karate_club_graph();
+        let result = linalg::fiedler_vector(&graph, false, false, None, Some(3)).unwrap();
+        assert_eq!(result.len(), 34);
+        assert_eq!(round(&result[&0], 6), -0.112137);
+        assert_eq!(round(&result[&1], 6), -0.041288);
+        assert_eq!(round(&result[&2], 6), 0.023219);
+        let length: f64 = result.values().map(|x| x * x).sum();
+        assert_eq!(round(&length, 8), 1.0);
+        let result = linalg::fiedler_vector(&graph, false, true, None, Some(3)).unwrap();
+        assert_eq!(round(&result[&0], 6), -0.2964);
+        assert_eq!(round(&result[&1], 6), -0.113414);
+        assert_eq!(round(&result[&2], 6), 0.008971);
+    }
+
+    #[test]
+    fn test_spectral_bisection_1() {
+        let graph = generators::social::karate_club_graph();
+        let (part1, part2) =
+            linalg::spectral_bisection(&graph, false, false, None, Some(1)).unwrap();
+        assert_eq!(
+            part1,
+            vec![0, 1, 3, 4, 5, 6, 7, 10, 11, 12, 13, 16, 17, 19, 21]
+        );
+        assert_eq!(part1.len() + part2.len(), 34);
+        assert!(part2.contains(&33));
+    }
+
+    #[test]
+    fn test_spectral_errors() {
+        let graph = generators::classic::path_graph(3, true);
+        let result = linalg::algebraic_connectivity(&graph, false, false, None, None);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let graph = generators::classic::path_graph(1, false);
+        let result = linalg::fiedler_vector(&graph, false, false, None, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let mut graph = generators::classic::path_graph(3, false);
+        graph.add_node(Node::from_name(3));
+        let result = linalg::spectral_bisection(&graph, false, false, None, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::GraphNotConnected
+        ));
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{graph, Graph};
+
+    #[test]
+    fn test_graph_macro_directed() {
+        let graph = graph! { directed; "a" -> "b": 1.0, "b" -> "c": 2.5, "c" -> "a", };
+        assert!(graph.specs.directed);
+        assert_eq!(graph.get_all_nodes().len(), 3);
+        assert_eq!(graph.get_all_edges().len(), 3);
+        assert_eq!(graph.get_edge("a", "b").unwrap().weight, 1.0);
+        assert_eq!(graph.get_edge("b", "c").unwrap().weight, 2.5);
+        assert!(graph.get_edge("c", "a").unwrap().weight.is_nan());
+        assert!(graph.get_edge("b", "a").is_err());
+    }
+
+    #[test]
+    fn test_graph_macro_undirected() {
+        let weights = [1.0, 2.0];
+        let (u, v) = (1, 2);
+        let graph = graph! {
+            undirected;
+            u -- v: weights[0],
+            2 -- 3: (weights[1] + 1.0),
+            3 -- 3,
+            4,
+            (u + 4)
+        };
+        assert!(!graph.specs.directed);
+        assert_eq!(graph.get_all_node_names().len(), 5);
+        assert_eq!(graph.get_edge(2, 1).unwrap().weight, 1.0);
+        assert_eq!(graph.get_edge(3, 2).unwrap().weight, 3.0);
+        assert!(graph.get_edge(3, 3).is_ok());
+        assert_eq!(graph.get_node_degree(5), Some(0));
+    }
+
+    #[test]
+    fn test_graph_macro_nodes_only() {
+        let graph: Graph<&str, ()> = graph! { directed; "a", "b" };
+        assert_eq!(graph.get_all_nodes().len(), 2);
+        assert!(graph.get_all_edges().is_empty());
+        let graph: Graph<&str, ()> = graph! { undirected; };
+        assert!(graph.get_all_nodes().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "graph!")]
+    fn test_graph_macro_duplicate_edge() {
+        graph! { undirected; "a" -- "b", "b" -- "a" };
+    }
+}
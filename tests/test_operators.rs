@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        generators,
+        operators::{self, LineGraphNode},
+        Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+    use std::collections::HashSet;
+
+    fn get_directed_graph() -> Graph<i32, ()> {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edge_tuples(vec![
+                (0, 2),
+                (0, 4),
+                (1, 0),
+                (1, 2),
+                (3, 2),
+                (4, 6),
+                (5, 1),
+                (5, 7),
+                (6, 0),
+                (6, 2),
+                (7, 2),
+                (7, 3),
+                (7, 4),
+                (7, 6),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_line_graph_undirected() {
+        let graph = generators::social::karate_club_graph();
+        let line_graph = operators::line_graph(&graph).unwrap();
+        assert!(!line_graph.specs.directed);
+        assert_eq!(line_graph.get_all_nodes().len(), 78);
+        assert_eq!(line_graph.get_all_edges().len(), 528);
+        let node = LineGraphNode { u: 0, v: 1 };
+        // node 0 has 16 neighbors and node 1 has 9, and the (0, 1) edge is counted at both
+        assert_eq!(line_graph.get_neighbor_nodes(node).unwrap().len(), 23);
+    }
+
+    #[test]
+    fn test_line_graph_directed() {
+        let graph = get_directed_graph();
+        let line_graph = operators::line_graph(&graph).unwrap();
+        assert!(line_graph.specs.directed);
+        assert_eq!(line_graph.get_all_nodes().len(), 14);
+        assert_eq!(line_graph.get_all_edges().len(), 17);
+        let successors: Vec<LineGraphNode<i32>> = line_graph
+            .get_successor_nodes(LineGraphNode { u: 4, v: 6 })
+            .unwrap()
+            .into_iter()
+            .map(|n| n.name)
+            .collect();
+        assert_eq!(successors.len(), 2);
+        assert!(successors.contains(&LineGraphNode { u: 6, v: 0 }));
+        assert!(successors.contains(&LineGraphNode { u: 6, v: 2 }));
+        let cycle = generators::classic::cycle_graph(4, true);
+        let line_graph = operators::line_graph(&cycle).unwrap();
+        assert_eq!(line_graph.get_all_edges().len(), 4);
+    }
+
+    #[test]
+    fn test_line_graph_attributes_and_self_loops() {
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        };
+        let mut graph = Graph::<&str, i32>::new(specs.clone());
+        graph
+            .add_edges(vec![
+                Edge {
+                    u: "a",
+                    v: "b",
+                    attributes: Some(5),
+                    weight: 1.0,
+                },
+                Edge::new("b", "b"),
+            ])
+            .unwrap();
+        let line_graph = operators::line_graph(&graph).unwrap();
+        let node = line_graph.get_node(LineGraphNode { u: "a", v: "b" }).unwrap();
+        assert_eq!(node.attributes, Some(5));
+        assert_eq!(node.to_string(), "(a, b)");
+        let loop_node = LineGraphNode { u: "b", v: "b" };
+        assert!(line_graph.get_edge(loop_node, loop_node).is_ok());
+        assert_eq!(line_graph.get_all_edges().len(), 2);
+
+        let mut graph = Graph::<&str, i32>::new(GraphSpecs {
+            directed: false,
+            ..specs
+        });
+        graph.add_edge_tuples(vec![("a", "b"), ("b", "b"), ("b", "c")]).unwrap();
+        let line_graph = operators::line_graph(&graph).unwrap();
+        assert!(!line_graph.specs.self_loops);
+        assert_eq!(line_graph.get_all_edges().len(), 3);
+    }
+
+    #[test]
+    fn test_line_graph_multi_edges() {
+        let graph = Graph::<i32, ()>::new(GraphSpecs::multi_undirected());
+        let result = operators::line_graph(&graph);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_complement() {
+        let graph = generators::social::karate_club_graph();
+        let complement = operators::complement(&graph).unwrap();
+        assert_eq!(complement.get_all_nodes().len(), 34);
+        assert_eq!(complement.get_all_edges().len(), 483);
+        for edge in complement.get_all_edges() {
+            assert!(graph.get_edge(edge.u, edge.v).is_err());
+        }
+        let complement = operators::complement(&get_directed_graph()).unwrap();
+        assert!(complement.specs.directed);
+        assert_eq!(complement.get_all_edges().len(), 42);
+        assert!(complement.get_edge(2, 0).is_ok());
+        assert!(complement.get_edge(0, 2).is_err());
+    }
+
+    #[test]
+    fn test_complement_keeps_nodes() {
+        let mut graph = Graph::<i32, &str>::new(GraphSpecs::undirected_create_missing());
+        graph.add_node(Node::from_name_and_attributes(1, "one"));
+        graph.add_node(Node::from_name(2));
+        let complement = operators::complement(&graph).unwrap();
+        assert_eq!(complement.get_node(1).unwrap().attributes, Some("one"));
+        assert_eq!(complement.get_all_edges().len(), 1);
+        let complement = operators::complement(&complement).unwrap();
+        assert_eq!(complement.get_all_nodes().len(), 2);
+        assert_eq!(complement.get_all_edges().len(), 0);
+        let complete = generators::classic::complete_graph(5, true);
+        let complement = operators::complement(&complete).unwrap();
+        assert_eq!(complement.get_all_edges().len(), 0);
+    }
+
+    #[test]
+    fn test_quotient_graph() {
+        let graph = generators::social::karate_club_graph();
+        let partition: Vec<HashSet<i32>> =
+            vec![(0..17).collect(), (17..34).collect(), HashSet::new()];
+        let quotient = operators::quotient_graph(&graph, &partition, false).unwrap();
+        assert!(!quotient.specs.directed);
+        assert_eq!(quotient.get_all_nodes().len(), 3);
+        assert_eq!(quotient.get_all_edges().len(), 3);
+        assert_eq!(quotient.get_edge(0, 0).unwrap().weight, 30.0);
+        assert_eq!(quotient.get_edge(1, 0).unwrap().weight, 20.0);
+        assert_eq!(quotient.get_edge(1, 1).unwrap().weight, 28.0);
+        assert_eq!(quotient.get_node_degree(2), Some(0));
+    }
+
+    #[test]
+    fn test_quotient_graph_weighted_directed() {
+        let graph = get_directed_graph().set_all_edge_weights(2.0);
+        let partition: Vec<HashSet<i32>> = vec![
+            vec![0, 1, 2, 3].into_iter().collect(),
+            vec![4, 5, 6, 7].into_iter().collect(),
+        ];
+        let quotient = operators::quotient_graph(&graph, &partition, true).unwrap();
+        assert!(quotient.specs.directed);
+        assert_eq!(quotient.get_edge(0, 0).unwrap().weight, 8.0);
+        assert_eq!(quotient.get_edge(0, 1).unwrap().weight, 2.0);
+        assert_eq!(quotient.get_edge(1, 0).unwrap().weight, 10.0);
+        assert_eq!(quotient.get_edge(1, 1).unwrap().weight, 8.0);
+        assert_eq!(quotient.size(true), graph.size(true));
+    }
+
+    #[test]
+    fn test_quotient_graph_errors() {
+        let graph = get_directed_graph();
+        let partition: Vec<HashSet<i32>> = vec![(0..7).collect()];
+        let result = operators::quotient_graph(&graph, &partition, false);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NotAPartition
+        ));
+        let partition: Vec<HashSet<i32>> = vec![(0..8).collect()];
+        let result = operators::quotient_graph(&graph, &partition, true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+    }
+}
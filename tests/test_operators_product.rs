@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        generators,
+        operators::product::{self, ProductNode},
+        Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+
+    fn get_sorted_edges(
+        graph: &product::ProductGraph<i32, i32, (), ()>,
+    ) -> Vec<((i32, i32), (i32, i32))> {
+        let mut edges: Vec<((i32, i32), (i32, i32))> =
+            graph.get_all_edges().into_iter().map(|e| ((e.u.0, e.u.1), (e.v.0, e.v.1))).collect();
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn test_product_sizes_undirected() {
+        let g = generators::classic::path_graph(3, false);
+        let h = generators::classic::path_graph(4, false);
+        let expected = [
+            (product::cartesian_product(&g, &h).unwrap(), 17),
+            (product::cartesian_product(&h, &g).unwrap(), 17),
+            (product::tensor_product(&g, &h).unwrap(), 12),
+            (product::tensor_product(&h, &g).unwrap(), 12),
+            (product::strong_product(&g, &h).unwrap(), 29),
+            (product::strong_product(&h, &g).unwrap(), 29),
+            (product::lexicographic_product(&g, &h).unwrap(), 41),
+            (product::lexicographic_product(&h, &g).unwrap(), 35),
+        ];
+        for (graph, num_edges) in expected {
+            assert!(!graph.specs.directed);
+            assert_eq!(graph.get_all_nodes().len(), 12);
+            assert_eq!(graph.get_all_edges().len(), num_edges);
+        }
+    }
+
+    #[test]
+    fn test_product_edges_directed() {
+        let g = generators::classic::cycle_graph(3, true);
+        let h = generators::classic::path_graph(2, true);
+        let cartesian = product::cartesian_product(&g, &h).unwrap();
+        assert!(cartesian.specs.directed);
+        assert_eq!(cartesian.get_all_edges().len(), 9);
+        let tensor = product::tensor_product(&g, &h).unwrap();
+        assert_eq!(
+            get_sorted_edges(&tensor),
+            vec![((0, 0), (1, 1)), ((1, 0), (2, 1)), ((2, 0), (0, 1))]
+        );
+        let strong = product::strong_product(&g, &h).unwrap();
+        assert_eq!(strong.get_all_edges().len(), 12);
+        let lexicographic = product::lexicographic_product(&g, &h).unwrap();
+        assert_eq!(lexicographic.get_all_edges().len(), 15);
+        assert!(lexicographic.get_edge(ProductNode(0, 1), ProductNode(1, 0)).is_ok());
+        assert!(lexicographic.get_edge(ProductNode(1, 0), ProductNode(0, 1)).is_err());
+    }
+
+    #[test]
+    fn test_cartesian_product_is_grid() {
+        let g = generators::classic::path_graph(3, false);
+        let h = generators::classic::path_graph(2, false);
+        let grid = generators::classic::grid_2d_graph(3, 2, false);
+        let cartesian = product::cartesian_product(&g, &h).unwrap();
+        // grid_2d_graph numbers the node in row `r` and column `c` as `r * num_cols + c`
+        let mut expected: Vec<((i32, i32), (i32, i32))> = grid
+            .get_all_edges()
+            .into_iter()
+            .map(|e| {
+                let (u, v) = ((e.u / 2, e.u % 2), (e.v / 2, e.v % 2));
+                (u.min(v), u.max(v))
+            })
+            .collect();
+        expected.sort();
+        let actual: Vec<((i32, i32), (i32, i32))> =
+            get_sorted_edges(&cartesian).into_iter().map(|(u, v)| (u.min(v), u.max(v))).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_product_weights_and_attributes() {
+        let mut g = Graph::<&str, &str>::new(GraphSpecs::undirected_create_missing());
+        g.add_node(Node::from_name_and_attributes("a", "first"));
+        g.add_edge(Edge::with_weight("a", "b", 2.0)).unwrap();
+        let mut h = Graph::<i32, i32>::new(GraphSpecs::undirected_create_missing());
+        h.add_node(Node::from_name_and_attributes(1, 100));
+        h.add_edge(Edge {
+            u: 1,
+            v: 2,
+            attributes: Some(7),
+            weight: 3.0,
+        })
+        .unwrap();
+        let cartesian = product::cartesian_product(&g, &h).unwrap();
+        let node = cartesian.get_node(ProductNode("a", 1)).unwrap();
+        assert_eq!(node.attributes, Some((Some("first"), Some(100))));
+        let node = cartesian.get_node(ProductNode("b", 2)).unwrap();
+        assert_eq!(node.attributes, Some((None, None)));
+        let edge = cartesian.get_edge(ProductNode("a", 1), ProductNode("b", 1)).unwrap();
+        assert_eq!(edge.weight, 2.0);
+        assert_eq!(edge.attributes, Some((None, None)));
+        let edge = cartesian.get_edge(ProductNode("a", 1), ProductNode("a", 2)).unwrap();
+        assert_eq!(edge.weight, 3.0);
+        assert_eq!(edge.attributes, Some((None, Some(7))));
+        let strong = product::strong_product(&g, &h).unwrap();
+        let edge = strong.get_edge(ProductNode("a", 2), ProductNode("b", 1)).unwrap();
+        assert_eq!(edge.weight, 6.0);
+        assert_eq!(edge.attributes, Some((None, Some(7))));
+        let lexicographic = product::lexicographic_product(&g, &h).unwrap();
+        let edge = lexicographic.get_edge(ProductNode("a", 2), ProductNode("b", 2)).unwrap();
+        assert_eq!(edge.weight, 2.0);
+    }
+
+    #[test]
+    fn test_product_unweighted_stays_unweighted() {
+        let g = generators::classic::path_graph(2, false);
+        let tensor = product::tensor_product(&g, &g).unwrap();
+        assert!(!tensor.edges_have_weight());
+    }
+
+    #[test]
+    fn test_product_self_loops() {
+        let mut g = Graph::<i32, ()>::new(GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected_create_missing()
+        });
+        g.add_edge(Edge::new(0, 0)).unwrap();
+        let h = generators::classic::path_graph(2, false);
+        let tensor = product::tensor_product(&g, &h).unwrap();
+        assert_eq!(get_sorted_edges(&tensor), vec![((0, 0), (0, 1))]);
+        let lexicographic = product::lexicographic_product(&g, &h).unwrap();
+        assert_eq!(
+            get_sorted_edges(&lexicographic),
+            vec![((0, 0), (0, 0)), ((0, 0), (0, 1)), ((0, 1), (0, 1))]
+        );
+    }
+
+    #[test]
+    fn test_product_mixed_directedness() {
+        let g = generators::classic::path_graph(2, false);
+        let h = generators::classic::path_graph(2, true);
+        let result = product::cartesian_product(&g, &h);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = product::lexicographic_product(&h, &g);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+}
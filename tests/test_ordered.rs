@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::{
+            centrality::betweenness, community::louvain, shortest_path::dijkstra,
+            shortest_path::ShortestPathInfo,
+        },
+        generators, Edge, Graph, GraphSpecs, IntoOrdered, ParallelOptions, Parallelism,
+    };
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+    #[test]
+    fn test_into_ordered_hashmap() {
+        let map: HashMap<&str, f64> =
+            vec![("c", 3.0), ("a", 1.0), ("b", 2.0)].into_iter().collect();
+        let ordered: BTreeMap<&str, f64> = map.into_ordered();
+        assert_eq!(
+            ordered.into_iter().collect::<Vec<_>>(),
+            vec![("a", 1.0), ("b", 2.0), ("c", 3.0)]
+        );
+    }
+
+    #[test]
+    fn test_into_ordered_nested() {
+        let graph = generators::classic::cycle_graph(5, false);
+        let all_pairs = dijkstra::all_pairs(&graph, false, None, None, false).unwrap();
+        let ordered = all_pairs.into_ordered();
+        assert_eq!(
+            ordered.keys().cloned().collect::<Vec<i32>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+        for infos in ordered.values() {
+            assert_eq!(
+                infos.keys().cloned().collect::<Vec<i32>>(),
+                vec![0, 1, 2, 3, 4]
+            );
+        }
+        let distances = dijkstra::all_pairs_distances(&graph, false, None, None).unwrap();
+        let ordered: BTreeMap<i32, BTreeMap<i32, f64>> = distances.into_ordered();
+        assert_eq!(
+            ordered[&0].values().cloned().collect::<Vec<f64>>(),
+            vec![0.0, 1.0, 2.0, 2.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_into_ordered_sets() {
+        let set: HashSet<i32> = vec![3, 1, 2].into_iter().collect();
+        assert_eq!(
+            set.into_ordered().into_iter().collect::<Vec<i32>>(),
+            vec![1, 2, 3]
+        );
+        let graph = generators::social::karate_club_graph();
+        let communities = louvain::louvain_communities(&graph, false, None, None, Some(1)).unwrap();
+        let mut ordered: Vec<BTreeSet<i32>> = communities.into_ordered();
+        ordered.sort();
+        assert_eq!(ordered.iter().map(|c| c.len()).sum::<usize>(), 34);
+        assert!(ordered[0].contains(&0));
+    }
+
+    #[test]
+    fn test_into_ordered_shortest_path_info() {
+        let mut info = ShortestPathInfo::new(2.0, vec![vec!["a", "c", "d"], vec!["a", "b", "d"]]);
+        info.edge_keys = Some(vec![vec![0, 0], vec![1, 0]]);
+        let ordered = info.into_ordered();
+        assert_eq!(
+            ordered.paths,
+            vec![vec!["a", "b", "d"], vec!["a", "c", "d"]]
+        );
+        assert_eq!(ordered.edge_keys, Some(vec![vec![1, 0], vec![0, 0]]));
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::new("a", "c"),
+                Edge::new("c", "d"),
+                Edge::new("a", "b"),
+                Edge::new("b", "d"),
+            ])
+            .unwrap();
+        let infos = dijkstra::single_source(&graph, false, "a", Some("d"), None, None, false)
+            .unwrap()
+            .into_ordered();
+        assert_eq!(
+            infos["d"].paths,
+            vec![vec!["a", "b", "d"], vec!["a", "c", "d"]]
+        );
+        assert_eq!(infos["d"].edge_keys, None);
+    }
+
+    #[test]
+    fn test_into_ordered_repeatable() {
+        let graph = generators::random::fast_gnp_random_graph(100, 0.05, false, Some(1)).unwrap();
+        let options = ParallelOptions {
+            parallelism: Parallelism::Sequential,
+            ..ParallelOptions::default()
+        };
+        let get_snapshot = || {
+            let centralities =
+                betweenness::betweenness_centrality_with_options(&graph, false, true, &options)
+                    .unwrap();
+            format!("{:?}", centralities.into_ordered())
+        };
+        assert_eq!(get_snapshot(), get_snapshot());
+    }
+}
@@ -0,0 +1,328 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::{
+            centrality::{
+                betweenness,
+                personalized_pagerank::{
+                    personalized_pagerank, personalized_pagerank_with_options,
+                    PersonalizedPageRankMethod,
+                },
+            },
+            cluster, distance_measures, link_prediction, random_walk,
+            shortest_path::{bellman_ford, delta_stepping, dijkstra, floyd_warshall},
+        },
+        generators, Edge, ErrorKind, Graph, GraphSpecs, ParallelOptions, Parallelism,
+        ProgressHandle,
+    };
+    use std::sync::Arc;
+
+    fn get_all_parallelisms() -> Vec<Parallelism> {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+        vec![
+            Parallelism::GlobalPool,
+            Parallelism::ThreadPool(Arc::new(pool)),
+            Parallelism::MaxThreads(1),
+            Parallelism::MaxThreads(3),
+            Parallelism::Sequential,
+        ]
+    }
+
+    #[test]
+    fn test_all_pairs_with_options() {
+        let graph = generators::social::karate_club_graph();
+        let expected = dijkstra::all_pairs(&graph, false, None, None, false).unwrap();
+        for parallelism in get_all_parallelisms() {
+            let options = ParallelOptions {
+                parallelism,
+                ..ParallelOptions::default()
+            };
+            let result =
+                dijkstra::all_pairs_with_options(&graph, false, None, None, false, &options)
+                    .unwrap();
+            assert_eq!(result.len(), expected.len());
+            for (source, infos) in expected.iter() {
+                for (target, info) in infos {
+                    assert_eq!(result[source][target].distance, info.distance);
+                    assert_eq!(result[source][target].paths, info.paths);
+                }
+            }
+            assert_eq!(options.progress.get_progress().completed, 34);
+        }
+    }
+
+    #[test]
+    fn test_betweenness_with_options() {
+        let graph = generators::social::karate_club_graph();
+        let expected = betweenness::betweenness_centrality(&graph, false, true).unwrap();
+        let expected_edges = betweenness::edge_betweenness_centrality(&graph, false, true).unwrap();
+        for parallelism in get_all_parallelisms() {
+            let options = ParallelOptions {
+                parallelism,
+                ..ParallelOptions::default()
+            };
+            let result =
+                betweenness::betweenness_centrality_with_options(&graph, false, true, &options)
+                    .unwrap();
+            for (node, value) in expected.iter() {
+                assert!((result[node] - value).abs() < 1e-12);
+            }
+            let result = betweenness::edge_betweenness_centrality_with_options(
+                &graph, false, true, &options,
+            )
+            .unwrap();
+            assert_eq!(result.len(), expected_edges.len());
+            for (edge, value) in expected_edges.iter() {
+                assert!((result[edge] - value).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sequential_runs_on_calling_thread() {
+        let graph = generators::social::karate_club_graph();
+        let caller = std::thread::current().id();
+        let progress = ProgressHandle::new().with_callback(move |_p| {
+            assert_eq!(std::thread::current().id(), caller);
+        });
+        let options = ParallelOptions {
+            parallelism: Parallelism::Sequential,
+            progress,
+        };
+        let result =
+            betweenness::betweenness_centrality_with_options(&graph, false, true, &options);
+        assert_eq!(result.unwrap().len(), 34);
+    }
+
+    #[test]
+    fn test_cancelled_with_options() {
+        let graph = generators::social::karate_club_graph();
+        let options = ParallelOptions {
+            parallelism: Parallelism::Sequential,
+            ..ParallelOptions::default()
+        };
+        options.progress.cancel();
+        let result = dijkstra::all_pairs_with_options(&graph, false, None, None, false, &options);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+    }
+
+    #[test]
+    fn test_max_threads_zero() {
+        let graph = generators::social::karate_club_graph();
+        let options = ParallelOptions {
+            parallelism: Parallelism::MaxThreads(0),
+            ..ParallelOptions::default()
+        };
+        let result = dijkstra::all_pairs_with_options(&graph, false, None, None, false, &options);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result =
+            betweenness::betweenness_centrality_with_options(&graph, false, true, &options);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_shortest_paths_with_options() {
+        let graph = generators::social::karate_club_graph();
+        let distances = dijkstra::all_pairs_distances(&graph, false, None, None).unwrap();
+        let bellman_ford = bellman_ford::all_pairs(&graph, false, true).unwrap();
+        let matrix = floyd_warshall::all_pairs(&graph, false, true).unwrap();
+        let delta = delta_stepping::single_source_distances(&graph, false, 0, None).unwrap();
+        for parallelism in get_all_parallelisms() {
+            let options = ParallelOptions {
+                parallelism,
+                ..ParallelOptions::default()
+            };
+            let result =
+                dijkstra::all_pairs_distances_with_options(&graph, false, None, None, &options);
+            assert_eq!(result.unwrap(), distances);
+            assert_eq!(options.progress.get_progress().completed, 34);
+            let result = bellman_ford::all_pairs_with_options(&graph, false, true, &options);
+            let result = result.unwrap();
+            for (source, infos) in bellman_ford.iter() {
+                for (target, info) in infos {
+                    assert_eq!(result[source][target].distance, info.distance);
+                    assert_eq!(result[source][target].paths, info.paths);
+                }
+            }
+            let result = floyd_warshall::all_pairs_with_options(&graph, false, true, &options);
+            let result = result.unwrap();
+            for (u, v) in [(0, 33), (14, 16), (5, 25)] {
+                assert_eq!(result.get_distance(&u, &v), matrix.get_distance(&u, &v));
+                assert_eq!(result.get_path(&u, &v), matrix.get_path(&u, &v));
+            }
+            let result = delta_stepping::single_source_distances_with_options(
+                &graph, false, 0, None, &options,
+            );
+            assert_eq!(result.unwrap(), delta);
+        }
+    }
+
+    #[test]
+    fn test_cluster_with_options() {
+        let graph = generators::social::karate_club_graph();
+        let clustering = cluster::clustering(&graph, false, None).unwrap();
+        let triangles = cluster::triangles(&graph, None).unwrap();
+        let degrees = cluster::generalized_degree(&graph, None).unwrap();
+        let transitivity = cluster::transitivity(&graph).unwrap();
+        for parallelism in get_all_parallelisms() {
+            let options = ParallelOptions {
+                parallelism,
+                ..ParallelOptions::default()
+            };
+            let result = cluster::clustering_with_options(&graph, false, None, &options);
+            assert_eq!(result.unwrap(), clustering);
+            assert_eq!(options.progress.get_progress().completed, 34);
+            let result = cluster::triangles_with_options(&graph, None, &options);
+            assert_eq!(result.unwrap(), triangles);
+            let result = cluster::generalized_degree_with_options(&graph, None, &options);
+            assert_eq!(result.unwrap(), degrees);
+            let result = cluster::transitivity_with_options(&graph, &options);
+            assert_eq!(result.unwrap(), transitivity);
+        }
+    }
+
+    #[test]
+    fn test_distance_measures_with_options() {
+        let graph = generators::social::karate_club_graph();
+        let eccentricities = distance_measures::eccentricity(&graph, false).unwrap();
+        let average = distance_measures::average_shortest_path_length(&graph, false).unwrap();
+        for parallelism in get_all_parallelisms() {
+            let options = ParallelOptions {
+                parallelism,
+                ..ParallelOptions::default()
+            };
+            let result = distance_measures::eccentricity_with_options(&graph, false, &options);
+            assert_eq!(result.unwrap(), eccentricities);
+            let result = distance_measures::average_shortest_path_length_with_options(
+                &graph, false, &options,
+            );
+            assert!((result.unwrap() - average).abs() < 1e-12);
+            let result = distance_measures::diameter_approx_with_options(&graph, None, &options);
+            assert_eq!(result.unwrap(), (5.0, 5.0));
+        }
+    }
+
+    #[test]
+    fn test_link_prediction_and_random_walks_with_options() {
+        let graph = generators::social::karate_club_graph();
+        let scores: Vec<(i32, i32, f64)> =
+            link_prediction::jaccard_coefficient(&graph, None, None).unwrap().collect();
+        let walks = random_walk::biased_walks(&graph, &[0, 0, 33], 10, 0.5, 2.0, false, Some(1));
+        let walks = walks.unwrap();
+        let method = PersonalizedPageRankMethod::MonteCarlo {
+            num_walks: 5_000,
+            seed: Some(1),
+        };
+        let estimate = personalized_pagerank(&graph, false, 0.15, &[33], method).unwrap();
+        for parallelism in get_all_parallelisms() {
+            let options = ParallelOptions {
+                parallelism,
+                ..ParallelOptions::default()
+            };
+            let result =
+                link_prediction::jaccard_coefficient_with_options(&graph, None, None, &options);
+            assert_eq!(result.unwrap().collect::<Vec<_>>(), scores);
+            let result = random_walk::biased_walks_with_options(
+                &graph,
+                &[0, 0, 33],
+                10,
+                0.5,
+                2.0,
+                false,
+                Some(1),
+                &options,
+            );
+            assert_eq!(result.unwrap(), walks);
+            let result =
+                personalized_pagerank_with_options(&graph, false, 0.15, &[33], method, &options);
+            assert_eq!(result.unwrap(), estimate);
+        }
+    }
+
+    #[test]
+    fn test_add_edges_par_with_options() {
+        let edges: Vec<Edge<i32, ()>> = (0..50)
+            .flat_map(|u| {
+                (u + 1..50).filter(move |v| (u * v) % 7 == 3).map(move |v| Edge::new(v, u))
+            })
+            .collect();
+        let mut expected = Graph::new(GraphSpecs::directed_create_missing());
+        expected.add_edges(edges.clone()).unwrap();
+        for parallelism in get_all_parallelisms() {
+            let options = ParallelOptions {
+                parallelism,
+                ..ParallelOptions::default()
+            };
+            let mut graph = Graph::new(GraphSpecs::directed_create_missing());
+            graph.add_edges_par_with_options(edges.clone(), &options).unwrap();
+            assert_eq!(graph.get_all_edges().len(), expected.get_all_edges().len());
+            for node in expected.get_all_node_names() {
+                let mut successors = graph.get_successor_node_names(*node).unwrap();
+                let mut expected_successors = expected.get_successor_node_names(*node).unwrap();
+                successors.sort();
+                expected_successors.sort();
+                assert_eq!(successors, expected_successors);
+                let mut predecessors = graph.get_predecessor_node_names(*node).unwrap();
+                let mut expected_predecessors = expected.get_predecessor_node_names(*node).unwrap();
+                predecessors.sort();
+                expected_predecessors.sort();
+                assert_eq!(predecessors, expected_predecessors);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sequential_runs_on_calling_thread_for_all() {
+        let graph = generators::social::karate_club_graph();
+        let caller = std::thread::current().id();
+        let progress = ProgressHandle::new().with_callback(move |_p| {
+            assert_eq!(std::thread::current().id(), caller);
+        });
+        let options = ParallelOptions {
+            parallelism: Parallelism::Sequential,
+            progress,
+        };
+        let result = cluster::clustering_with_options(&graph, false, None, &options);
+        assert_eq!(result.unwrap().len(), 34);
+        let result = floyd_warshall::all_pairs_with_options(&graph, false, false, &options);
+        assert!(result.is_ok());
+        let result = distance_measures::eccentricity_with_options(&graph, false, &options);
+        assert_eq!(result.unwrap().len(), 34);
+        let result =
+            random_walk::uniform_walks_with_options(&graph, &[0], 5, false, None, &options);
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_cancelled_with_all_options() {
+        let graph = generators::social::karate_club_graph();
+        let options = ParallelOptions::default();
+        options.progress.cancel();
+        let result =
+            dijkstra::all_pairs_distances_with_options(&graph, false, None, None, &options);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+        let result = bellman_ford::all_pairs_with_options(&graph, false, true, &options);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+        let result = floyd_warshall::all_pairs_with_options(&graph, false, false, &options);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+        let result =
+            delta_stepping::single_source_distances_with_options(&graph, false, 0, None, &options);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+        let result = cluster::clustering_with_options(&graph, false, None, &options);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+        let result = distance_measures::diameter_with_options(&graph, false, &options);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+        let result = link_prediction::adamic_adar_index_with_options(&graph, None, None, &options);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+        let result =
+            random_walk::uniform_walks_with_options(&graph, &[0], 5, false, None, &options);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::{centrality::betweenness, community::louvain, shortest_path::dijkstra},
+        generators, ErrorKind, Progress, ProgressHandle,
+    };
+    use std::sync::{Arc, Mutex};
+
+    fn get_recording_handle() -> (ProgressHandle, Arc<Mutex<Vec<Progress>>>) {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let recorded = calls.clone();
+        let progress =
+            ProgressHandle::new().with_callback(move |p| recorded.lock().unwrap().push(p));
+        (progress, calls)
+    }
+
+    #[test]
+    fn test_all_pairs_with_progress() {
+        let graph = generators::social::karate_club_graph();
+        let (progress, calls) = get_recording_handle();
+        let result =
+            dijkstra::all_pairs_with_progress(&graph, false, None, None, false, &progress).unwrap();
+        let expected = dijkstra::all_pairs(&graph, false, None, None, false).unwrap();
+        assert_eq!(result.len(), expected.len());
+        for (source, infos) in expected {
+            for (target, info) in infos {
+                assert_eq!(result[&source][&target].paths, info.paths);
+            }
+        }
+        let mut calls = calls.lock().unwrap().clone();
+        calls.sort_by_key(|p| p.completed);
+        let completed: Vec<usize> = calls.iter().map(|p| p.completed).collect();
+        assert_eq!(completed, (1..=34).collect::<Vec<usize>>());
+        assert!(calls.iter().all(|p| p.total == Some(34)));
+        assert_eq!(
+            progress.get_progress(),
+            Progress {
+                completed: 34,
+                total: Some(34)
+            }
+        );
+    }
+
+    #[test]
+    fn test_betweenness_with_progress() {
+        let graph = generators::social::karate_club_graph();
+        let (progress, calls) = get_recording_handle();
+        let result =
+            betweenness::betweenness_centrality_with_progress(&graph, false, true, &progress)
+                .unwrap();
+        let expected = betweenness::betweenness_centrality(&graph, false, true).unwrap();
+        for (node, value) in expected {
+            assert!((result[&node] - value).abs() < 1e-12);
+        }
+        assert_eq!(calls.lock().unwrap().len(), 34);
+        let progress = ProgressHandle::new();
+        let result =
+            betweenness::edge_betweenness_centrality_with_progress(&graph, false, true, &progress)
+                .unwrap();
+        assert_eq!(result.len(), 78);
+        assert_eq!(progress.get_progress().completed, 34);
+    }
+
+    #[test]
+    fn test_louvain_with_progress() {
+        let graph = generators::social::karate_club_graph();
+        let (progress, calls) = get_recording_handle();
+        let result = louvain::louvain_partitions_with_progress(
+            &graph,
+            false,
+            None,
+            None,
+            Some(1),
+            &progress,
+        )
+        .unwrap();
+        assert!(!result.is_empty());
+        for partition in result {
+            assert_eq!(partition.iter().map(|c| c.len()).sum::<usize>(), 34);
+        }
+        let calls = calls.lock().unwrap();
+        assert!(!calls.is_empty());
+        assert!(calls.iter().all(|p| p.total.is_none()));
+        assert_eq!(progress.get_progress().completed, calls.len());
+    }
+
+    #[test]
+    fn test_cancelled_before_start() {
+        let graph = generators::social::karate_club_graph();
+        let (progress, calls) = get_recording_handle();
+        progress.clone().cancel();
+        assert!(progress.is_cancelled());
+        let result = dijkstra::all_pairs_with_progress(&graph, false, None, None, false, &progress);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+        let result =
+            betweenness::betweenness_centrality_with_progress(&graph, false, true, &progress);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+        let result =
+            betweenness::edge_betweenness_centrality_with_progress(&graph, false, true, &progress);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+        let result =
+            louvain::louvain_communities_with_progress(&graph, false, None, None, None, &progress);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+        assert!(calls.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_cancelled_while_running() {
+        let graph = generators::random::fast_gnp_random_graph(500, 0.02, false, Some(1)).unwrap();
+        let token = ProgressHandle::new();
+        let canceller = token.clone();
+        let progress = token.clone().with_callback(move |p| {
+            if p.completed >= 3 {
+                canceller.cancel();
+            }
+        });
+        let result = dijkstra::all_pairs_with_progress(&graph, false, None, None, false, &progress);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Cancelled));
+        assert!(token.is_cancelled());
+        assert!(token.get_progress().completed < 500);
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{generators, readwrite::binary, Edge, ErrorKind, Graph, GraphSpecs, Node};
+
+    #[test]
+    fn test_save_then_load() {
+        let file = "./tests/karate.grrs";
+
+        // save
+        let graph = generators::social::karate_club_graph();
+        let result = binary::save(&graph, file);
+        assert!(result.is_ok());
+
+        // load
+        let result = binary::load::<i32, ()>(file);
+        assert!(result.is_ok());
+        let new_graph = result.unwrap();
+        assert!(!new_graph.specs.directed);
+        assert_eq!(new_graph.get_all_nodes().len(), 34);
+        assert_eq!(new_graph.get_all_edges().len(), 78);
+        for edge in graph.get_all_edges() {
+            assert!(new_graph.get_edge(edge.u, edge.v).is_ok());
+        }
+
+        assert!(std::fs::remove_file(file).is_ok());
+    }
+
+    #[test]
+    fn test_to_bytes_then_from_bytes_1() {
+        // a multigraph with attributes and self loops
+        let specs = GraphSpecs {
+            multi_edges: true,
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        };
+        let mut graph = Graph::<String, String>::new(specs);
+        graph.add_node(Node::from_name_and_attributes(
+            "n1".to_string(),
+            "first".to_string(),
+        ));
+        graph.add_node(Node::from_name("n2 ü".to_string()));
+        graph
+            .add_edges(vec![
+                Edge::with_weight("n1".to_string(), "n2 ü".to_string(), 1.5),
+                Edge::with_weight("n1".to_string(), "n2 ü".to_string(), -2.5),
+                Edge::new("n2 ü".to_string(), "n2 ü".to_string()),
+                Edge {
+                    u: "n2 ü".to_string(),
+                    v: "n1".to_string(),
+                    attributes: Some("back".to_string()),
+                    weight: 3.0,
+                },
+            ])
+            .unwrap();
+
+        let bytes = binary::to_bytes(&graph);
+        let new_graph = binary::from_bytes::<String, String>(&bytes).unwrap();
+        assert!(new_graph.specs.directed);
+        assert!(new_graph.specs.multi_edges);
+        assert!(new_graph.specs.self_loops);
+        let n1 = new_graph.get_node("n1".to_string()).unwrap();
+        assert_eq!(n1.attributes, Some("first".to_string()));
+        assert!(new_graph.get_node("n2 ü".to_string()).unwrap().attributes.is_none());
+        let mut weights: Vec<f64> = new_graph
+            .get_edges("n1".to_string(), "n2 ü".to_string())
+            .unwrap()
+            .iter()
+            .map(|e| e.weight)
+            .collect();
+        weights.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(weights, vec![-2.5, 1.5]);
+        let self_loops = new_graph.get_edges("n2 ü".to_string(), "n2 ü".to_string()).unwrap();
+        assert!(self_loops[0].weight.is_nan());
+        let back = new_graph.get_edges("n2 ü".to_string(), "n1".to_string()).unwrap()[0];
+        assert_eq!(back.attributes, Some("back".to_string()));
+        assert_eq!(back.weight, 3.0);
+    }
+
+    #[test]
+    fn test_to_bytes_then_from_bytes_edge_keys() {
+        let mut graph = Graph::<u64, ()>::new(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::undirected_create_missing()
+        });
+        graph.add_edge_with_key(Edge::with_weight(1, 2, 1.0), 10).unwrap();
+        graph.add_edge_with_key(Edge::with_weight(2, 1, 2.0), 20).unwrap();
+        graph.add_edge(Edge::with_weight(2, 3, 3.0)).unwrap();
+
+        let bytes = binary::to_bytes(&graph);
+        let new_graph = binary::from_bytes::<u64, ()>(&bytes).unwrap();
+        assert_eq!(new_graph.get_edge_keys(1, 2).unwrap(), vec![10, 20]);
+        assert_eq!(new_graph.get_edge_by_key(1, 2, 20).unwrap().weight, 2.0);
+        assert_eq!(new_graph.get_edge_keys(2, 3).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_to_bytes_is_deterministic() {
+        // each graph's HashMaps have their own random order
+        let bytes = binary::to_bytes(&generators::social::karate_club_graph());
+        for _ in 0..5 {
+            let graph = generators::social::karate_club_graph();
+            assert_eq!(binary::to_bytes(&graph), bytes);
+        }
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_save_compressed_then_load() {
+        let file = "./tests/karate_compressed.grrs";
+
+        let graph = generators::social::karate_club_graph();
+        assert!(binary::save_compressed(&graph, file, 3).is_ok());
+        let bytes = std::fs::read(file).unwrap();
+        assert!(bytes.len() < binary::to_bytes(&graph).len());
+
+        let new_graph = binary::load::<i32, ()>(file).unwrap();
+        assert_eq!(new_graph.get_all_nodes().len(), 34);
+        assert_eq!(new_graph.get_all_edges().len(), 78);
+        for edge in graph.get_all_edges() {
+            assert!(new_graph.get_edge(edge.u, edge.v).is_ok());
+        }
+
+        assert!(std::fs::remove_file(file).is_ok());
+    }
+
+    #[cfg(not(feature = "zstd"))]
+    #[test]
+    fn test_from_bytes_compressed_without_feature() {
+        let graph = Graph::<u64, ()>::new(GraphSpecs::directed());
+        let mut bytes = binary::to_bytes(&graph);
+        // the compression byte follows the magic bytes and the version
+        bytes[8] = 1;
+        let result = binary::from_bytes::<u64, ()>(&bytes);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+    }
+
+    #[test]
+    fn test_from_bytes_errors() {
+        let mut graph = Graph::<u64, ()>::new(GraphSpecs::directed_create_missing());
+        graph.add_edge(Edge::new(1, 2)).unwrap();
+        let bytes = binary::to_bytes(&graph);
+        assert!(binary::from_bytes::<u64, ()>(&bytes).is_ok());
+
+        // not a binary graph
+        let result = binary::from_bytes::<u64, ()>(b"abcdefgh");
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+
+        // unsupported version
+        let mut wrong_version = bytes.clone();
+        wrong_version[4] = 99;
+        let result = binary::from_bytes::<u64, ()>(&wrong_version);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+
+        // unknown compression
+        let mut wrong_compression = bytes.clone();
+        wrong_compression[8] = 99;
+        let result = binary::from_bytes::<u64, ()>(&wrong_compression);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+
+        // truncated
+        let result = binary::from_bytes::<u64, ()>(&bytes[..bytes.len() - 1]);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+
+        // extra data
+        let mut extra = bytes.clone();
+        extra.push(0);
+        let result = binary::from_bytes::<u64, ()>(&extra);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+
+        // missing file
+        let result = binary::load::<u64, ()>("./tests/missing.grrs");
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+    }
+}
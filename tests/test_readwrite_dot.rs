@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        generators,
+        readwrite::dot::{self, DotOptions},
+        Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+
+    #[test]
+    fn test_write_then_read_dot() {
+        let file = "./tests/karate.dot";
+
+        // write
+        let graph = generators::social::karate_club_graph();
+        let result = dot::write_dot(&graph, file, &DotOptions::default());
+        assert!(result.is_ok());
+
+        // read
+        let result = dot::read_dot(file, GraphSpecs::directed());
+        assert!(result.is_ok());
+        let graph = result.unwrap();
+        assert!(!graph.specs.directed);
+        let nodes = graph.get_all_nodes();
+        assert_eq!(nodes.len(), 34);
+        let edges = graph.get_all_edges();
+        assert_eq!(edges.len(), 78);
+
+        assert!(std::fs::remove_file(file).is_ok());
+    }
+
+    #[test]
+    fn test_to_dot_string_1() {
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::undirected_create_missing());
+        graph.add_node(Node::from_name("c"));
+        graph
+            .add_edges(vec![
+                Edge::new("a", "b"),
+                Edge::with_weight("b", "x\"y", 1.5),
+            ])
+            .unwrap();
+        let options = DotOptions {
+            graph_name: Some("g".to_string()),
+            include_weights: false,
+            node_label: Some(Box::new(|n: &Node<&str, ()>| n.name.to_uppercase())),
+            edge_attributes: Some(Box::new(|_e: &Edge<&str, ()>| {
+                vec![("color".to_string(), "red".to_string())]
+            })),
+            ..DotOptions::default()
+        };
+        let dot_string = dot::to_dot_string(&graph, &options);
+        assert_eq!(
+            dot_string,
+            "graph \"g\" {\n  \"a\" [label=\"A\"];\n  \"b\" [label=\"B\"];\n  \"c\" [label=\"C\"];\n  \"x\\\"y\" [label=\"X\\\"Y\"];\n  \"a\" -- \"b\" [color=\"red\"];\n  \"b\" -- \"x\\\"y\" [color=\"red\"];\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_to_dot_string_then_from_dot_string() {
+        let mut graph = Graph::<i32, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight(1, 2, 0.5),
+                Edge::with_weight(2, 3, -2.0),
+                Edge::with_weight(3, 1, 4.0),
+            ])
+            .unwrap();
+        let options = DotOptions {
+            edge_label: Some(Box::new(|e: &Edge<i32, ()>| format!("{}-{}", e.u, e.v))),
+            ..DotOptions::default()
+        };
+        let dot_string = dot::to_dot_string(&graph, &options);
+        let new_graph = dot::from_dot_string(&dot_string, GraphSpecs::undirected()).unwrap();
+        assert!(new_graph.specs.directed);
+        assert_eq!(new_graph.get_all_nodes().len(), 3);
+        assert_eq!(new_graph.size(true), 2.5);
+        let edge = new_graph.get_edge("2".to_string(), "3".to_string()).unwrap();
+        assert_eq!(edge.weight, -2.0);
+        assert_eq!(
+            edge.attributes.as_ref().unwrap().get("label").unwrap(),
+            "2-3"
+        );
+    }
+
+    #[test]
+    fn test_to_dot_string_then_from_dot_string_keywords_and_backslashes() {
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::new("subgraph", "node"),
+                Edge::new("node", "a\\"),
+                Edge::new("a\\", "b\\\"c"),
+            ])
+            .unwrap();
+        let options = DotOptions {
+            node_label: Some(Box::new(|n: &Node<&str, ()>| n.name.to_string())),
+            ..DotOptions::default()
+        };
+        let dot_string = dot::to_dot_string(&graph, &options);
+        let new_graph = dot::from_dot_string(&dot_string, GraphSpecs::directed()).unwrap();
+        let mut names = new_graph.get_all_node_names();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                &"a\\".to_string(),
+                &"b\\\"c".to_string(),
+                &"node".to_string(),
+                &"subgraph".to_string()
+            ]
+        );
+        let node = new_graph.get_node("node".to_string()).unwrap();
+        assert_eq!(
+            node.attributes.as_ref().unwrap().get("label").unwrap(),
+            "node"
+        );
+        let node = new_graph.get_node("a\\".to_string()).unwrap();
+        assert_eq!(
+            node.attributes.as_ref().unwrap().get("label").unwrap(),
+            "a\\"
+        );
+        assert_eq!(new_graph.get_all_edges().len(), 3);
+        assert!(new_graph.get_edge("subgraph".to_string(), "node".to_string()).is_ok());
+        assert!(new_graph.get_edge("a\\".to_string(), "b\\\"c".to_string()).is_ok());
+
+        // unquoted keywords still start attribute statements
+        let graph = dot::from_dot_string("graph { node [shape=box]; a }", GraphSpecs::undirected());
+        assert_eq!(graph.unwrap().get_all_node_names(), vec![&"a".to_string()]);
+    }
+
+    #[test]
+    fn test_from_dot_string_1() {
+        let dot_string = r#"
+            /* a comment */
+            strict digraph "test graph" {
+                graph [rankdir=LR];
+                node [shape=box]
+                label = "ignored";
+                a [color=red, shape=circle];
+                a:n -> b:s:w -> "c d" [weight=3; style=dashed]
+                # a preprocessor line
+                e // a comment
+                a -> <<b>html</b>>
+            }
+        "#;
+        let graph = dot::from_dot_string(dot_string, GraphSpecs::directed()).unwrap();
+        let mut names = graph.get_all_node_names();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                &"<<b>html</b>>".to_string(),
+                &"a".to_string(),
+                &"b".to_string(),
+                &"c d".to_string(),
+                &"e".to_string()
+            ]
+        );
+        let a = graph.get_node("a".to_string()).unwrap();
+        let a_attributes = a.attributes.as_ref().unwrap();
+        assert_eq!(a_attributes.get("color").unwrap(), "red");
+        assert_eq!(a_attributes.get("shape").unwrap(), "circle");
+        let edge = graph.get_edge("b".to_string(), "c d".to_string()).unwrap();
+        assert_eq!(edge.weight, 3.0);
+        assert_eq!(
+            edge.attributes.as_ref().unwrap().get("style").unwrap(),
+            "dashed"
+        );
+        assert_eq!(graph.get_all_edges().len(), 3);
+    }
+
+    #[test]
+    fn test_from_dot_string_2() {
+        // subgraphs aren't supported
+        let result =
+            dot::from_dot_string("graph { a -- b; subgraph { c } }", GraphSpecs::undirected());
+        assert!(result.is_err());
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+    }
+
+    #[test]
+    fn test_from_dot_string_3() {
+        // weights must be numbers
+        let result = dot::from_dot_string("graph { a -- b [weight=x] }", GraphSpecs::undirected());
+        assert!(result.is_err());
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+    }
+
+    #[test]
+    fn test_from_dot_string_4() {
+        // not closed
+        let result = dot::from_dot_string("digraph { a -> \"b }", GraphSpecs::directed());
+        assert!(result.is_err());
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+        let result = dot::from_dot_string("digraph { a -> b", GraphSpecs::directed());
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+    }
+
+    #[test]
+    fn test_read_dot_missing_file() {
+        let result = dot::read_dot("./tests/missing.dot", GraphSpecs::directed());
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        generators,
+        readwrite::edgelist::{self, EdgeListOptions, EdgeListReader},
+        Edge, ErrorKind, Graph, GraphSpecs,
+    };
+
+    #[test]
+    fn test_write_then_read_edgelist_1() {
+        let file = "./tests/karate.csv";
+
+        // write
+        let graph = generators::social::karate_club_graph();
+        let result = edgelist::write_edgelist(&graph, file, &EdgeListOptions::default());
+        assert!(result.is_ok());
+
+        // read
+        let result =
+            edgelist::read_edgelist(file, GraphSpecs::undirected(), EdgeListOptions::default());
+        assert!(result.is_ok());
+        let graph = result.unwrap();
+        assert_eq!(graph.get_all_nodes().len(), 34);
+        assert_eq!(graph.get_all_edges().len(), 78);
+
+        assert!(std::fs::remove_file(file).is_ok());
+    }
+
+    #[test]
+    fn test_write_then_read_edgelist_2() {
+        let file = "./tests/weighted.tsv";
+
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("a", "b", 1.5),
+                Edge::with_weight("b", "c d", -2.0),
+                Edge::with_weight("c d", "a\tb", 4.0),
+            ])
+            .unwrap();
+        let options = EdgeListOptions {
+            delimiter: '\t',
+            has_header: false,
+            weight_column: Some(3),
+            ..EdgeListOptions::default()
+        };
+        assert!(edgelist::write_edgelist(&graph, file, &options).is_ok());
+        let contents = std::fs::read_to_string(file).unwrap();
+        assert_eq!(contents, "a\tb\t\t1.5\nb\tc d\t\t-2\nc d\t\"a\tb\"\t\t4\n");
+
+        let graph = edgelist::read_edgelist(file, GraphSpecs::directed(), options).unwrap();
+        assert_eq!(graph.get_all_nodes().len(), 4);
+        assert_eq!(graph.size(true), 3.5);
+        assert_eq!(
+            graph.get_edge("c d".to_string(), "a\tb".to_string()).unwrap().weight,
+            4.0
+        );
+
+        assert!(std::fs::remove_file(file).is_ok());
+    }
+
+    #[test]
+    fn test_write_then_read_edgelist_line_breaks() {
+        let file = "./tests/line_breaks.csv";
+
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight("a\nb", "c\r\nd", 1.5),
+                Edge::with_weight("c\r\nd", "e\r", 2.0),
+                Edge::with_weight("e\r", "f", 4.0),
+            ])
+            .unwrap();
+        let options = EdgeListOptions {
+            weight_column: Some(2),
+            ..EdgeListOptions::default()
+        };
+        assert!(edgelist::write_edgelist(&graph, file, &options).is_ok());
+
+        let new_graph = edgelist::read_edgelist(file, GraphSpecs::directed(), options).unwrap();
+        let mut names = new_graph.get_all_node_names();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                &"a\nb".to_string(),
+                &"c\r\nd".to_string(),
+                &"e\r".to_string(),
+                &"f".to_string()
+            ]
+        );
+        assert_eq!(new_graph.size(true), 7.5);
+        let edge = new_graph.get_edge("a\nb".to_string(), "c\r\nd".to_string());
+        assert_eq!(edge.unwrap().weight, 1.5);
+
+        assert!(std::fs::remove_file(file).is_ok());
+    }
+
+    #[test]
+    fn test_edge_list_reader_1() {
+        let data = "source;target;color;weight\n\nn1;n2;red;1\nn2;n3;\"blue;green\";2.5\n";
+        let options = EdgeListOptions {
+            delimiter: ';',
+            weight_column: Some(3),
+            attribute_columns: vec![2],
+            ..EdgeListOptions::default()
+        };
+        let edges: Vec<Edge<String, _>> =
+            EdgeListReader::new(data.as_bytes(), options).unwrap().map(|e| e.unwrap()).collect();
+        assert_eq!(edges.len(), 2);
+        assert_eq!(edges[1].u, "n2");
+        assert_eq!(edges[1].v, "n3");
+        assert_eq!(edges[1].weight, 2.5);
+        let attributes = edges[1].attributes.as_ref().unwrap();
+        assert_eq!(attributes.get("color").unwrap(), "blue;green");
+    }
+
+    #[test]
+    fn test_edge_list_reader_2() {
+        // attributes are named by column number when there is no header
+        let data = "n1 n2 x y\n";
+        let options = EdgeListOptions {
+            delimiter: ' ',
+            has_header: false,
+            attribute_columns: vec![3, 2],
+            ..EdgeListOptions::default()
+        };
+        let edge = EdgeListReader::new(data.as_bytes(), options).unwrap().next().unwrap().unwrap();
+        assert!(edge.weight.is_nan());
+        let attributes = edge.attributes.unwrap();
+        assert_eq!(attributes.get("2").unwrap(), "x");
+        assert_eq!(attributes.get("3").unwrap(), "y");
+    }
+
+    #[test]
+    fn test_edge_list_reader_3() {
+        // the weight isn't a number
+        let data = "n1,n2,x\n";
+        let options = EdgeListOptions {
+            has_header: false,
+            weight_column: Some(2),
+            ..EdgeListOptions::default()
+        };
+        let result = EdgeListReader::new(data.as_bytes(), options).unwrap().next().unwrap();
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+    }
+
+    #[test]
+    fn test_edge_list_reader_4() {
+        // missing columns
+        let data = "source,target\nn1\n";
+        let result = EdgeListReader::new(data.as_bytes(), EdgeListOptions::default())
+            .unwrap()
+            .next()
+            .unwrap();
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+        let options = EdgeListOptions {
+            attribute_columns: vec![2],
+            ..EdgeListOptions::default()
+        };
+        let result = EdgeListReader::new(data.as_bytes(), options);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+    }
+
+    #[test]
+    fn test_read_edgelist_missing_file() {
+        let result = edgelist::read_edgelist(
+            "./tests/missing.csv",
+            GraphSpecs::directed(),
+            EdgeListOptions::default(),
+        );
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::ReadError));
+    }
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "serde")]
+
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::shortest_path::ShortestPathInfo, Edge, Graph, GraphSpecs, Node};
+    use serde::{de::DeserializeOwned, Serialize};
+
+    fn assert_serde<S: Serialize + DeserializeOwned>() {}
+
+    #[test]
+    fn test_serde_traits() {
+        assert_serde::<Graph<String, f64>>();
+        assert_serde::<Graph<i32, ()>>();
+        assert_serde::<Node<String, f64>>();
+        assert_serde::<Edge<i32, String>>();
+        assert_serde::<GraphSpecs>();
+        assert_serde::<ShortestPathInfo<String>>();
+    }
+
+    #[test]
+    fn test_serde_json_round_trip_edge_keys() {
+        let mut graph = Graph::<&str, ()>::new(GraphSpecs {
+            multi_edges: true,
+            ..GraphSpecs::directed_create_missing()
+        });
+        graph.add_edge_with_key(Edge::with_weight("a", "b", 1.0), 10).unwrap();
+        graph.add_edge_with_key(Edge::with_weight("a", "b", 2.0), 20).unwrap();
+        graph.add_edge(Edge::with_weight("b", "c", 3.0)).unwrap();
+
+        let json = serde_json::to_string(&graph).unwrap();
+        let new_graph: Graph<String, ()> = serde_json::from_str(&json).unwrap();
+        let (a, b) = ("a".to_string(), "b".to_string());
+        assert_eq!(
+            new_graph.get_edge_keys(a.clone(), b.clone()).unwrap(),
+            vec![10, 20]
+        );
+        assert_eq!(new_graph.get_edge_by_key(a, b, 20).unwrap().weight, 2.0);
+        let keys = new_graph.get_edge_keys("b".to_string(), "c".to_string());
+        assert_eq!(keys.unwrap(), vec![0]);
+
+        // graphs serialized without keys get the default keys
+        let json = json.replace(",\"edge_keys\":[10,20,0]", "");
+        let new_graph: Graph<String, ()> = serde_json::from_str(&json).unwrap();
+        let keys = new_graph.get_edge_keys("a".to_string(), "b".to_string());
+        assert_eq!(keys.unwrap(), vec![0, 1]);
+    }
+}
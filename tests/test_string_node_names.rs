@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::{
+            centrality, components, dag, flow, shortest_path,
+            shortest_path::{contraction_hierarchy::ContractionHierarchy, dijkstra},
+            traversal, tree,
+        },
+        generators, Edge, Graph, GraphSpecs,
+    };
+    use std::collections::HashMap;
+
+    // node names are zero-padded so that they sort in the same order as the integers
+    fn name(i: i32) -> String {
+        format!("n{:02}", i)
+    }
+
+    fn get_karate_graphs() -> (Graph<i32, ()>, Graph<String, ()>) {
+        let graph = generators::social::karate_club_graph();
+        let mapping: HashMap<i32, String> =
+            graph.get_all_node_names().into_iter().map(|n| (*n, name(*n))).collect();
+        let string_graph = graph.relabel_nodes(&mapping).unwrap();
+        (graph, string_graph)
+    }
+
+    fn get_weighted_string_graph() -> Graph<String, ()> {
+        let mut graph = Graph::new(GraphSpecs::directed_create_missing());
+        graph
+            .add_edges(vec![
+                Edge::with_weight(name(1), name(2), 1.0),
+                Edge::with_weight(name(2), name(3), 5.0),
+                Edge::with_weight(name(1), name(4), 2.0),
+                Edge::with_weight(name(4), name(3), 3.0),
+                Edge::with_weight(name(3), name(5), 1.0),
+            ])
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_string_node_names_centrality() {
+        let (graph, string_graph) = get_karate_graphs();
+        let expected =
+            centrality::betweenness::betweenness_centrality(&graph, false, true).unwrap();
+        let result =
+            centrality::betweenness::betweenness_centrality(&string_graph, false, true).unwrap();
+        for (node, value) in expected {
+            assert!((result.get(&name(node)).unwrap() - value).abs() < 1e-10);
+        }
+        let expected = centrality::closeness::closeness_centrality(&graph, false, true).unwrap();
+        let result =
+            centrality::closeness::closeness_centrality(&string_graph, false, true).unwrap();
+        for (node, value) in expected {
+            assert!((result.get(&name(node)).unwrap() - value).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_string_node_names_components_and_traversal() {
+        let (_graph, string_graph) = get_karate_graphs();
+        assert_eq!(
+            components::number_connected_components(&string_graph).unwrap(),
+            1
+        );
+        let nodes: Vec<String> =
+            traversal::bfs::breadth_first_nodes(&string_graph, name(0)).unwrap().collect();
+        assert_eq!(nodes.len(), 34);
+        let dfs = traversal::dfs::depth_first_search(&string_graph, Some(name(0))).unwrap();
+        assert_eq!(dfs.preorder.len(), 34);
+        let mst = tree::minimum_spanning_tree(
+            &string_graph,
+            tree::SpanningTreeAlgorithm::Kruskal,
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(mst.get_all_edges().len(), 33);
+    }
+
+    #[test]
+    fn test_string_node_names_shortest_paths() {
+        let graph = get_weighted_string_graph();
+        let result =
+            dijkstra::single_source(&graph, true, name(1), None, None, None, false).unwrap();
+        assert_eq!(result.get(&name(5)).unwrap().distance, 6.0);
+        assert_eq!(
+            result.get(&name(5)).unwrap().paths,
+            vec![vec![name(1), name(4), name(3), name(5)]]
+        );
+        let matrix = shortest_path::floyd_warshall::all_pairs(&graph, true, false).unwrap();
+        assert_eq!(matrix.get_distance(&name(1), &name(3)), Some(5.0));
+        let paths =
+            shortest_path::yen::k_shortest_paths(&graph, true, name(1), name(5), 2).unwrap();
+        assert_eq!(paths[1].distance, 7.0);
+        let ch = ContractionHierarchy::build(&graph, true).unwrap();
+        assert_eq!(ch.query(name(2), name(5)).unwrap().unwrap().distance, 6.0);
+    }
+
+    #[test]
+    fn test_string_node_names_dag_and_flow() {
+        let graph = get_weighted_string_graph();
+        let order = dag::topological_sort(&graph).unwrap();
+        assert_eq!(order.first(), Some(&name(1)));
+        assert_eq!(order.last(), Some(&name(5)));
+        let max_flow = flow::maximum_flow(&graph, name(1), name(5)).unwrap();
+        assert_eq!(max_flow.flow_value, 1.0);
+    }
+}